@@ -0,0 +1,61 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! CSV parsing benchmarks (sequential vs parallel).
+//!
+//! Measures performance of:
+//! - parse_csv() - Single-threaded parsing
+//! - parse_csv_parallel() - Rayon chunked parsing (feature `rayon`)
+//!
+//! ## Performance Targets
+//! - parse_csv_parallel: near-linear speedup with core count on 10M rows
+//!
+//! Run with: `cargo bench --bench csv_parse --features rayon`
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use reedbase_last::tables::{parse_csv, parse_csv_parallel, Delimiter};
+
+/// Generate pipe-delimited CSV with header and `rows` data rows.
+fn generate_csv(rows: usize) -> Vec<u8> {
+    let mut content = String::with_capacity(rows * 48);
+    content.push_str("key|value|description\n");
+    for i in 0..rows {
+        content.push_str(&format!(
+            "page.{}.title@de|Titel {}|Beschreibung {}\n",
+            i, i, i
+        ));
+    }
+    content.into_bytes()
+}
+
+/// Benchmark sequential vs parallel parsing.
+fn bench_parse_csv(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_csv");
+    group.sample_size(10);
+
+    for rows in [100_000, 1_000_000, 10_000_000].iter() {
+        let content = generate_csv(*rows);
+        group.throughput(Throughput::Bytes(content.len() as u64));
+
+        group.bench_with_input(
+            BenchmarkId::new("sequential", rows),
+            &content,
+            |b, content| {
+                b.iter(|| parse_csv(black_box(content), Delimiter::Pipe).unwrap());
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("parallel", rows),
+            &content,
+            |b, content| {
+                b.iter(|| parse_csv_parallel(black_box(content), Delimiter::Pipe).unwrap());
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse_csv);
+criterion_main!(benches);
@@ -0,0 +1,73 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Concurrent HashMap index read benchmarks.
+//!
+//! Measures 8 reader threads doing point lookups against:
+//! - `RwLock<HashMap>` - previous storage with external locking
+//! - `HashMapIndex` - DashMap-backed (sharded locks)
+//! - `ImmutableHashMapIndex` - plain HashMap snapshot, no locking
+//!
+//! ## Performance Targets
+//! - HashMapIndex: no slower than RwLock<HashMap> at 8 readers
+//! - ImmutableHashMapIndex: fastest of the three
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use reedbase_last::indices::{HashMapIndex, Index};
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+const KEYS: usize = 100_000;
+const READERS: usize = 8;
+const LOOKUPS_PER_READER: usize = 10_000;
+
+fn key(i: usize) -> String {
+    format!("page.key{}", i)
+}
+
+/// Run `lookup` for LOOKUPS_PER_READER keys on each of READERS threads.
+fn run_readers<F>(lookup: F)
+where
+    F: Fn(&String) -> Option<Vec<usize>> + Sync,
+{
+    std::thread::scope(|scope| {
+        for reader in 0..READERS {
+            let lookup = &lookup;
+            scope.spawn(move || {
+                for i in 0..LOOKUPS_PER_READER {
+                    let k = key((i * 7919 + reader) % KEYS);
+                    black_box(lookup(&k));
+                }
+            });
+        }
+    });
+}
+
+fn bench_concurrent_reads(c: &mut Criterion) {
+    let mut group = c.benchmark_group("hashmap_index_8_readers");
+    group.throughput(Throughput::Elements((READERS * LOOKUPS_PER_READER) as u64));
+
+    let locked: RwLock<HashMap<String, Vec<usize>>> =
+        RwLock::new((0..KEYS).map(|i| (key(i), vec![i])).collect());
+    group.bench_function("rwlock_hashmap", |b| {
+        b.iter(|| run_readers(|k| locked.read().unwrap().get(k).cloned()));
+    });
+
+    let mut index = HashMapIndex::with_capacity(KEYS);
+    for i in 0..KEYS {
+        index.insert(key(i), vec![i]).unwrap();
+    }
+    group.bench_function("dashmap", |b| {
+        b.iter(|| run_readers(|k| index.get(k).unwrap()));
+    });
+
+    let snapshot = index.into_immutable();
+    group.bench_function("immutable", |b| {
+        b.iter(|| run_readers(|k| snapshot.get(k).unwrap()));
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_concurrent_reads);
+criterion_main!(benches);
@@ -0,0 +1,73 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Row-level merge benchmarks.
+//!
+//! Compares the HashMap-based merges against the sorted merge-join:
+//! - merge_changes() - HashMap + conflict detection + final sort
+//! - merge_single() - HashMap + final sort
+//! - merge_ordered() - two-pointer merge over pre-sorted input
+//!
+//! ## Performance Targets
+//! - merge_ordered: < 30ms for 100k rows
+//! - merge_ordered faster than merge_single for sorted input
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use reedbase_last::concurrent::types::CsvRow;
+use reedbase_last::merge::{merge_changes, merge_ordered, merge_single, RowChange};
+
+/// Generate sorted base rows and a sorted change set touching every 10th key.
+fn create_sorted_data(rows: usize) -> (Vec<CsvRow>, Vec<CsvRow>) {
+    let base = (0..rows)
+        .map(|i| {
+            CsvRow::new(
+                format!("page.key{:08}@de", i * 2),
+                vec![format!("value_{}", i), format!("{}", i % 100)],
+            )
+        })
+        .collect();
+
+    // Mix of updates (even keys) and inserts (odd keys)
+    let changes = (0..rows / 10)
+        .map(|i| {
+            CsvRow::new(
+                format!("page.key{:08}@de", i * 20 + (i % 2)),
+                vec![format!("changed_{}", i), "0".to_string()],
+            )
+        })
+        .collect();
+
+    (base, changes)
+}
+
+/// Benchmark merge strategies on pre-sorted input.
+///
+/// Target: merge_ordered < 30ms for 100k rows
+fn bench_merge_strategies(c: &mut Criterion) {
+    let mut group = c.benchmark_group("merge_sorted_input");
+    group.sample_size(20);
+
+    for size in [1_000, 10_000, 100_000].iter() {
+        let (base, changes) = create_sorted_data(*size);
+
+        group.throughput(Throughput::Elements(*size as u64));
+
+        group.bench_with_input(BenchmarkId::new("merge_changes", size), size, |b, _| {
+            b.iter(|| black_box(merge_changes(&base, &changes, &[]).unwrap()));
+        });
+
+        group.bench_with_input(BenchmarkId::new("merge_single", size), size, |b, _| {
+            b.iter(|| black_box(merge_single(&base, &changes).unwrap()));
+        });
+
+        let row_changes: Vec<RowChange> = changes.iter().cloned().map(RowChange::Update).collect();
+        group.bench_with_input(BenchmarkId::new("merge_ordered", size), size, |b, _| {
+            b.iter(|| black_box(merge_ordered(&base, &row_changes).unwrap()));
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_merge_strategies);
+criterion_main!(benches);
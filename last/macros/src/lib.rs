@@ -0,0 +1,390 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Compile-time validated ReedQL queries.
+//!
+//! The `reedql!` macro runs the ReedQL parser while the calling crate is
+//! compiled. Invalid queries become compile errors; valid ones expand to a
+//! `ParsedQuery` struct literal, so no string parsing happens at runtime.
+//!
+//! ## Example Usage
+//! ```ignore
+//! use reedbase_macros::reedql;
+//!
+//! // String literal form (any ReedQL, including single-quoted values)
+//! let q = reedql!("SELECT * FROM text WHERE key LIKE '%.@de' LIMIT 10");
+//!
+//! // Token form (values must be double-quoted or single characters)
+//! let q = reedql!(SELECT key, value FROM text WHERE namespace = "page" LIMIT 10);
+//! ```
+//!
+//! The calling crate must depend on `reedbase-last`, whose types the
+//! expansion refers to.
+
+use proc_macro2::{Delimiter, Spacing, Span, TokenStream, TokenTree};
+use quote::quote;
+use reedbase_last::reedql::{
+    parse, AggregationFunction, AggregationType, Collation, FilterCondition, JoinClause, JoinType,
+    LimitOffset, OrderBy, ParsedQuery, SortDirection,
+};
+use reedbase_last::schema::{ComputedArg, ComputedExpr};
+
+/// Parses a ReedQL query at compile time.
+///
+/// ## Input
+/// - Either a single string literal or the query written as tokens
+///
+/// ## Output
+/// - Expression of type `reedbase_last::reedql::ParsedQuery`
+///
+/// ## Error Conditions
+/// - `compile_error!` with the parser's message if the query is invalid
+#[proc_macro]
+pub fn reedql(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand(input.into()).into()
+}
+
+/// Expands the macro input into a `ParsedQuery` expression or a compile error.
+fn expand(input: TokenStream) -> TokenStream {
+    let (sql, span) = match syn::parse2::<syn::LitStr>(input.clone()) {
+        Ok(literal) => (literal.value(), literal.span()),
+        Err(_) => (query_text(input), Span::call_site()),
+    };
+
+    match parse(&sql) {
+        Ok(query) => query_tokens(&query),
+        Err(e) => syn::Error::new(span, format!("invalid ReedQL query `{}`: {}", sql, e))
+            .to_compile_error(),
+    }
+}
+
+/// Re-assembles tokens into query text.
+///
+/// Rust's tokenizer drops the original spacing, so spaces are reinserted
+/// between tokens except around `.`, before `,`, inside joint operators
+/// (`!=`, `<=`, `>=`) and directly inside parentheses.
+fn query_text(input: TokenStream) -> String {
+    let mut out = String::new();
+    let mut glue_next = true;
+
+    for token in input {
+        let glue =
+            glue_next || matches!(&token, TokenTree::Punct(p) if matches!(p.as_char(), '.' | ','));
+        if !glue {
+            out.push(' ');
+        }
+
+        glue_next = false;
+        match token {
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::Brace => ("{", "}"),
+                    Delimiter::None => ("", ""),
+                };
+                out.push_str(open);
+                out.push_str(&query_text(group.stream()));
+                out.push_str(close);
+            }
+            TokenTree::Punct(punct) => {
+                out.push(punct.as_char());
+                glue_next = punct.as_char() == '.' || punct.spacing() == Spacing::Joint;
+            }
+            TokenTree::Ident(ident) => out.push_str(&ident.to_string()),
+            TokenTree::Literal(literal) => out.push_str(&literal.to_string()),
+        }
+    }
+
+    out
+}
+
+/// Struct literal for a parsed query.
+fn query_tokens(query: &ParsedQuery) -> TokenStream {
+    let columns = strings(&query.columns);
+    let distinct = query.distinct;
+    let column_aliases = query.column_aliases.iter().map(|a| optional(a.as_deref()));
+    let expressions = query.expressions.iter().map(|expr| match expr {
+        Some(expr) => {
+            let expr = expression_tokens(expr);
+            quote!(::std::option::Option::Some(#expr))
+        }
+        None => quote!(::std::option::Option::None),
+    });
+    let table = &query.table;
+    let table_alias = optional(query.table_alias.as_deref());
+    let joins = query.joins.iter().map(join_tokens);
+    let conditions = query.conditions.iter().map(condition_tokens);
+    let group_by = strings(&query.group_by);
+    let having = match &query.having {
+        Some(condition) => {
+            let condition = condition_tokens(condition);
+            quote!(::std::option::Option::Some(#condition))
+        }
+        None => quote!(::std::option::Option::None),
+    };
+    let order_by = query.order_by.iter().map(order_tokens);
+    let limit = match &query.limit {
+        Some(limit) => {
+            let limit = limit_tokens(limit);
+            quote!(::std::option::Option::Some(#limit))
+        }
+        None => quote!(::std::option::Option::None),
+    };
+    let aggregation = match &query.aggregation {
+        Some(agg) => {
+            let agg = aggregation_tokens(agg);
+            quote!(::std::option::Option::Some(#agg))
+        }
+        None => quote!(::std::option::Option::None),
+    };
+
+    quote! {
+        ::reedbase_last::reedql::ParsedQuery {
+            columns: ::std::vec![#(#columns),*],
+            distinct: #distinct,
+            column_aliases: ::std::vec![#(#column_aliases),*],
+            expressions: ::std::vec![#(#expressions),*],
+            table: ::std::string::String::from(#table),
+            table_alias: #table_alias,
+            joins: ::std::vec![#(#joins),*],
+            conditions: ::std::vec![#(#conditions),*],
+            group_by: ::std::vec![#(#group_by),*],
+            having: #having,
+            order_by: ::std::vec![#(#order_by),*],
+            limit: #limit,
+            aggregation: #aggregation,
+        }
+    }
+}
+
+fn expression_tokens(expr: &ComputedExpr) -> TokenStream {
+    let function = &expr.function;
+    let args = expr.args.iter().map(|arg| match arg {
+        ComputedArg::Column(name) => {
+            quote!(::reedbase_last::schema::ComputedArg::Column(::std::string::String::from(#name)))
+        }
+        ComputedArg::Literal(value) => {
+            quote!(::reedbase_last::schema::ComputedArg::Literal(::std::string::String::from(#value)))
+        }
+    });
+
+    quote! {
+        ::reedbase_last::schema::ComputedExpr {
+            function: ::std::string::String::from(#function),
+            args: ::std::vec![#(#args),*],
+        }
+    }
+}
+
+fn join_tokens(join: &JoinClause) -> TokenStream {
+    let table = &join.table;
+    let alias = optional(join.alias.as_deref());
+    let condition = match &join.condition {
+        Some(condition) => {
+            let condition = condition_tokens(condition);
+            quote!(::std::option::Option::Some(#condition))
+        }
+        None => quote!(::std::option::Option::None),
+    };
+    let join_type = match join.join_type {
+        JoinType::Inner => quote!(Inner),
+        JoinType::Left => quote!(Left),
+        JoinType::Natural => quote!(Natural),
+    };
+
+    quote! {
+        ::reedbase_last::reedql::JoinClause {
+            table: ::std::string::String::from(#table),
+            alias: #alias,
+            condition: #condition,
+            join_type: ::reedbase_last::reedql::JoinType::#join_type,
+        }
+    }
+}
+
+fn condition_tokens(condition: &FilterCondition) -> TokenStream {
+    let path = quote!(::reedbase_last::reedql::FilterCondition);
+    let pair = |variant: TokenStream, column: &str, value: &str| {
+        quote! {
+            #path::#variant {
+                column: ::std::string::String::from(#column),
+                value: ::std::string::String::from(#value),
+            }
+        }
+    };
+
+    match condition {
+        FilterCondition::Equals { column, value } => pair(quote!(Equals), column, value),
+        FilterCondition::NotEquals { column, value } => pair(quote!(NotEquals), column, value),
+        FilterCondition::LessThan { column, value } => pair(quote!(LessThan), column, value),
+        FilterCondition::GreaterThan { column, value } => pair(quote!(GreaterThan), column, value),
+        FilterCondition::LessThanOrEqual { column, value } => {
+            pair(quote!(LessThanOrEqual), column, value)
+        }
+        FilterCondition::GreaterThanOrEqual { column, value } => {
+            pair(quote!(GreaterThanOrEqual), column, value)
+        }
+        FilterCondition::Like { column, pattern } => quote! {
+            #path::Like {
+                column: ::std::string::String::from(#column),
+                pattern: ::std::string::String::from(#pattern),
+            }
+        },
+        FilterCondition::InList { column, values } => {
+            let values = strings(values);
+            quote! {
+                #path::InList {
+                    column: ::std::string::String::from(#column),
+                    values: ::std::vec![#(#values),*],
+                }
+            }
+        }
+        FilterCondition::IsEmpty { column } => quote! {
+            #path::IsEmpty { column: ::std::string::String::from(#column) }
+        },
+        FilterCondition::IsNotEmpty { column } => quote! {
+            #path::IsNotEmpty { column: ::std::string::String::from(#column) }
+        },
+        FilterCondition::IsNull { column } => quote! {
+            #path::IsNull { column: ::std::string::String::from(#column) }
+        },
+        FilterCondition::IsNotNull { column } => quote! {
+            #path::IsNotNull { column: ::std::string::String::from(#column) }
+        },
+        FilterCondition::Between { column, low, high } => quote! {
+            #path::Between {
+                column: ::std::string::String::from(#column),
+                low: ::std::string::String::from(#low),
+                high: ::std::string::String::from(#high),
+            }
+        },
+        FilterCondition::InSubquery { column, subquery } => {
+            let subquery = query_tokens(subquery);
+            quote! {
+                #path::InSubquery {
+                    column: ::std::string::String::from(#column),
+                    subquery: ::std::boxed::Box::new(#subquery),
+                }
+            }
+        }
+        FilterCondition::Or(operands) => {
+            let operands = operands.iter().map(condition_tokens);
+            quote!(#path::Or(::std::vec![#(#operands),*]))
+        }
+        FilterCondition::And(operands) => {
+            let operands = operands.iter().map(condition_tokens);
+            quote!(#path::And(::std::vec![#(#operands),*]))
+        }
+        FilterCondition::Not(inner) => {
+            let inner = condition_tokens(inner);
+            quote!(#path::Not(::std::boxed::Box::new(#inner)))
+        }
+    }
+}
+
+fn order_tokens(order: &OrderBy) -> TokenStream {
+    let column = &order.column;
+    let direction = match order.direction {
+        SortDirection::Ascending => quote!(Ascending),
+        SortDirection::Descending => quote!(Descending),
+    };
+    let collation = match order.collation {
+        Collation::Lexicographic => quote!(Lexicographic),
+        Collation::Numeric => quote!(Numeric),
+    };
+
+    quote! {
+        ::reedbase_last::reedql::OrderBy {
+            column: ::std::string::String::from(#column),
+            direction: ::reedbase_last::reedql::SortDirection::#direction,
+            collation: ::reedbase_last::reedql::Collation::#collation,
+        }
+    }
+}
+
+fn limit_tokens(limit: &LimitOffset) -> TokenStream {
+    let (limit, offset) = (limit.limit, limit.offset);
+    quote! {
+        ::reedbase_last::reedql::LimitOffset { limit: #limit, offset: #offset }
+    }
+}
+
+fn aggregation_tokens(agg: &AggregationFunction) -> TokenStream {
+    let agg_type = match agg.agg_type {
+        AggregationType::Count => quote!(Count),
+        AggregationType::Sum => quote!(Sum),
+        AggregationType::Avg => quote!(Avg),
+        AggregationType::Min => quote!(Min),
+        AggregationType::Max => quote!(Max),
+        AggregationType::Corr => quote!(Corr),
+        AggregationType::Covar => quote!(Covar),
+    };
+    let column = &agg.column;
+    let second_column = optional(agg.second_column.as_deref());
+    let alias = optional(agg.alias.as_deref());
+
+    quote! {
+        ::reedbase_last::reedql::AggregationFunction {
+            agg_type: ::reedbase_last::reedql::AggregationType::#agg_type,
+            column: ::std::string::String::from(#column),
+            second_column: #second_column,
+            alias: #alias,
+        }
+    }
+}
+
+/// `String` expressions for a list of values.
+fn strings(values: &[String]) -> Vec<TokenStream> {
+    values
+        .iter()
+        .map(|v| quote!(::std::string::String::from(#v)))
+        .collect()
+}
+
+/// `Option<String>` expression.
+fn optional(value: Option<&str>) -> TokenStream {
+    match value {
+        Some(v) => quote!(::std::option::Option::Some(::std::string::String::from(#v))),
+        None => quote!(::std::option::Option::None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn tokens(source: &str) -> TokenStream {
+        TokenStream::from_str(source).unwrap()
+    }
+
+    #[test]
+    fn test_query_text_restores_spacing() {
+        let text = query_text(tokens(
+            r#"SELECT t.key, COUNT(*) FROM text t WHERE a != "x" AND b >= 'y' LIMIT 10"#,
+        ));
+        assert_eq!(
+            text,
+            r#"SELECT t.key, COUNT (*) FROM text t WHERE a != "x" AND b >= 'y' LIMIT 10"#
+        );
+    }
+
+    #[test]
+    fn test_expand_valid_query() {
+        let expanded = expand(tokens(
+            r#""SELECT * FROM text WHERE key LIKE '%.@de' LIMIT 10""#,
+        ))
+        .to_string();
+        assert!(expanded.contains("ParsedQuery"));
+        assert!(expanded.contains("\"%.@de\""));
+        assert!(!expanded.contains("compile_error"));
+    }
+
+    #[test]
+    fn test_expand_invalid_query_is_compile_error() {
+        let expanded = expand(tokens("SELECT FROM")).to_string();
+        assert!(expanded.contains("compile_error"));
+        assert!(expanded.contains("invalid ReedQL query"));
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests that `reedql!` expands to the same value as the runtime parser.
+
+use reedbase_last::reedql::parse;
+use reedbase_macros::reedql;
+
+#[test]
+fn test_string_literal_matches_runtime_parse() {
+    let query = reedql!("SELECT * FROM text WHERE key LIKE '%.@de' LIMIT 10");
+    assert_eq!(
+        query,
+        parse("SELECT * FROM text WHERE key LIKE '%.@de' LIMIT 10").unwrap()
+    );
+}
+
+#[test]
+fn test_token_form_matches_runtime_parse() {
+    let query = reedql!(
+        SELECT t.key AS k, r.route FROM text t LEFT JOIN routes r ON t.key = r.text_key
+        WHERE t.namespace != "draft" AND r.route IN ("/", "/about")
+        ORDER BY k DESC LIMIT 5 OFFSET 10
+    );
+    assert_eq!(
+        query,
+        parse(
+            "SELECT t.key AS k, r.route FROM text t LEFT JOIN routes r ON t.key = r.text_key \
+             WHERE t.namespace != 'draft' AND r.route IN ('/', '/about') \
+             ORDER BY k DESC LIMIT 5 OFFSET 10"
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn test_aggregation_and_subquery() {
+    let query = reedql!(
+        "SELECT COUNT(*) AS n FROM text WHERE key IN (SELECT text_key FROM routes) AND value IS NOT EMPTY"
+    );
+    assert_eq!(
+        query,
+        parse(
+            "SELECT COUNT(*) AS n FROM text WHERE key IN (SELECT text_key FROM routes) \
+             AND value IS NOT EMPTY"
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn test_function_call_matches_runtime_parse() {
+    let query = reedql!("SELECT key, add_days(created_at, 30) AS due FROM users");
+    assert_eq!(
+        query,
+        parse("SELECT key, add_days(created_at, 30) AS due FROM users").unwrap()
+    );
+}
+
+#[test]
+fn test_null_checks_match_runtime_parse() {
+    let query = reedql!("SELECT * FROM text WHERE value IS NULL AND note IS NOT NULL");
+    assert_eq!(
+        query,
+        parse("SELECT * FROM text WHERE value IS NULL AND note IS NOT NULL").unwrap()
+    );
+}
@@ -0,0 +1,51 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Audit command implementation.
+
+use anyhow::{Context, Result};
+use reedbase_last::tables::{read_transaction_log, AuditFilter};
+use std::path::Path;
+
+pub fn execute(path: &Path, filter: AuditFilter, json: bool) -> Result<()> {
+    let entries = read_transaction_log(path, filter)
+        .with_context(|| format!("Failed to read audit log in {}", path.display()))?;
+
+    if json {
+        let rows: Vec<serde_json::Value> = entries
+            .iter()
+            .map(|e| {
+                serde_json::json!({
+                    "timestamp": e.timestamp,
+                    "user": e.user,
+                    "operation": e.operation,
+                    "table": e.table,
+                    "affected_keys": e.affected_keys,
+                    "ip_address": e.ip_address,
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&rows)?);
+        return Ok(());
+    }
+
+    if entries.is_empty() {
+        println!("No audit entries");
+        return Ok(());
+    }
+
+    for e in &entries {
+        println!(
+            "{}  {:<12} {:<8} {:<16} {} {}",
+            e.timestamp,
+            e.user,
+            e.operation,
+            e.table,
+            e.affected_keys.join(","),
+            e.ip_address.as_deref().unwrap_or("-")
+        );
+    }
+    println!("\n{} entries", entries.len());
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Check-integrity command implementation.
+
+use anyhow::{bail, Context, Result};
+use reedbase_last::database::Database;
+use std::path::Path;
+
+pub fn execute(path: &Path) -> Result<()> {
+    let db = Database::open(path).context("Failed to open database")?;
+
+    let report = db
+        .check_referential_integrity()
+        .context("Failed to check referential integrity")?;
+
+    if report.is_clean() {
+        println!("No foreign key violations");
+        return Ok(());
+    }
+
+    for v in &report.violations {
+        println!(
+            "{}.{} row {}: '{}' not found in {}.{}",
+            v.table, v.column, v.row, v.value, v.referenced_table, v.referenced_column
+        );
+    }
+
+    // Non-zero exit status so scripts can detect violations
+    bail!("{} foreign key violation(s)", report.violations.len())
+}
@@ -0,0 +1,33 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Column-stats command implementation.
+
+use anyhow::{Context, Result};
+use reedbase_last::tables::Table;
+use std::path::Path;
+
+pub fn execute(path: &Path, table_name: &str, column: &str, top: usize) -> Result<()> {
+    let table = Table::new(path, table_name);
+
+    let stats = table
+        .column_stats(column, top)
+        .with_context(|| format!("Failed to profile column '{}.{}'", table_name, column))?;
+
+    println!("Column Statistics: {}.{}", table_name, column);
+    println!("  Rows:             {}", stats.count);
+    println!("  Nulls:            {}", stats.null_count);
+    println!("  Distinct:         {}", stats.distinct_count);
+    println!("  Type:             {}", stats.inferred_type);
+    println!("  Min:              {}", stats.min_value);
+    println!("  Max:              {}", stats.max_value);
+
+    if !stats.top_n_values.is_empty() {
+        println!("  Top {} values:", stats.top_n_values.len());
+        for (value, count) in &stats.top_n_values {
+            println!("    {:>8}  {}", count, value);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Diff command implementation.
+
+use anyhow::{Context, Result};
+use reedbase_last::database::Database;
+use std::path::Path;
+
+pub fn execute(path: &Path, other: &Path) -> Result<()> {
+    let db = Database::open(path).context("Failed to open database")?;
+
+    let diff = db
+        .diff(other)
+        .with_context(|| format!("Failed to compare with '{}'", other.display()))?;
+
+    if diff.is_empty() {
+        println!("Databases are identical");
+        return Ok(());
+    }
+
+    for table in &diff.tables_only_in_self {
+        println!("- table {}", table);
+    }
+    for table in &diff.tables_only_in_other {
+        println!("+ table {}", table);
+    }
+
+    for table in &diff.tables_changed {
+        println!(
+            "~ table {} (+{} ~{} -{})",
+            table.table_name,
+            table.added.len(),
+            table.modified.len(),
+            table.deleted.len()
+        );
+        for row in &table.added {
+            println!("    + {}", row.key);
+        }
+        for row in &table.modified {
+            println!("    ~ {}", row.key);
+        }
+        for key in &table.deleted {
+            println!("    - {}", key);
+        }
+    }
+
+    Ok(())
+}
@@ -3,10 +3,15 @@
 
 //! Exec command implementation.
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
+use reedbase_last::tables::{Table, WritePhase, WriteProgress};
 use reedbase_last::Database;
+use std::io::{IsTerminal, Write};
 use std::path::Path;
 
+/// Width of the progress bar in characters.
+const PROGRESS_BAR_WIDTH: usize = 30;
+
 pub fn execute(sql: &str, path: &Path, user: &str, quiet: bool) -> Result<()> {
     // Open database
     let db = Database::open(path)
@@ -29,3 +34,79 @@ pub fn execute(sql: &str, path: &Path, user: &str, quiet: bool) -> Result<()> {
 
     Ok(())
 }
+
+/// Streams stdin into a table for `INSERT INTO <table> FROM STDIN`.
+///
+/// The piped content replaces the table's current content as a new version.
+pub fn execute_pipe(sql: &str, path: &Path, user: &str, quiet: bool) -> Result<()> {
+    let table_name = parse_stdin_insert(sql)?;
+    let table = Table::new(path, table_name);
+
+    // Progress bar only for interactive use, so piped stderr stays clean
+    let show_progress = !quiet && std::io::stderr().is_terminal();
+    let result = table
+        .stream_write_with_progress(&mut std::io::stdin().lock(), user, |progress| {
+            if show_progress {
+                draw_progress(progress);
+            }
+        })
+        .with_context(|| format!("Failed to stream stdin into table '{}'", table_name))?;
+    if show_progress {
+        eprintln!();
+    }
+
+    if !quiet {
+        println!(
+            "{} bytes written to {} (version {})",
+            result.current_size, table_name, result.timestamp
+        );
+    }
+
+    Ok(())
+}
+
+/// Redraws the write progress bar on the current stderr line.
+fn draw_progress(progress: WriteProgress) {
+    let label = match progress.phase {
+        WritePhase::GeneratingDelta => "Generating delta",
+        WritePhase::CompressingDelta => "Compressing delta",
+        WritePhase::WritingFile => "Writing files",
+        WritePhase::UpdatingLog => "Updating log",
+    };
+    let fraction = if progress.bytes_total == 0 {
+        1.0
+    } else {
+        progress.bytes_processed as f64 / progress.bytes_total as f64
+    };
+    let filled = (fraction * PROGRESS_BAR_WIDTH as f64).round() as usize;
+
+    let mut stderr = std::io::stderr().lock();
+    let _ = write!(
+        stderr,
+        "\r{:<18} [{}{}] {:>3}%",
+        label,
+        "#".repeat(filled),
+        "-".repeat(PROGRESS_BAR_WIDTH - filled),
+        (fraction * 100.0).round() as u32
+    );
+    let _ = stderr.flush();
+}
+
+/// Extracts the table name from `INSERT INTO <table> FROM STDIN`.
+fn parse_stdin_insert(sql: &str) -> Result<&str> {
+    let words: Vec<&str> = sql.split_whitespace().collect();
+    match words.as_slice() {
+        [insert, into, table, from, stdin]
+            if insert.eq_ignore_ascii_case("INSERT")
+                && into.eq_ignore_ascii_case("INTO")
+                && from.eq_ignore_ascii_case("FROM")
+                && stdin.eq_ignore_ascii_case("STDIN") =>
+        {
+            Ok(table)
+        }
+        _ => bail!(
+            "--pipe expects \"INSERT INTO <table> FROM STDIN\", got: {}",
+            sql
+        ),
+    }
+}
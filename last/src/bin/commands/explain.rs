@@ -4,31 +4,110 @@
 //! Explain command implementation.
 
 use anyhow::{Context, Result};
+use reedbase_last::reedql::QueryResult;
 use reedbase_last::Database;
 use std::path::Path;
 
-pub fn execute(sql: &str, path: &Path, verbose: bool) -> Result<()> {
+pub fn execute(sql: &str, path: &Path, verbose: bool, analyze: bool) -> Result<()> {
     let db = Database::open(path)
         .with_context(|| format!("Failed to open database at {}", path.display()))?;
 
-    // TODO: Implement query explanation
-    // This would analyze the query and show:
-    // - Which indices would be used
-    // - Estimated row count
-    // - Query cost
-    // - Whether fast path is used
+    // Accept "SELECT ...", "EXPLAIN [ANALYZE] SELECT ..." and "EXPLAIN COST SELECT ..."
+    let sql = strip_explain_cost(sql).unwrap_or(sql);
+    let (sql, analyze) = match strip_explain(sql) {
+        Some((query, explicit)) => (query, analyze || explicit),
+        None => (sql, analyze),
+    };
+
+    let cost_ns = db
+        .estimate_query_cost(sql)
+        .with_context(|| format!("Failed to estimate cost of: {}", sql))?;
+    let explain = if analyze {
+        format!("EXPLAIN ANALYZE {}", sql)
+    } else {
+        format!("EXPLAIN {}", sql)
+    };
+    let plan = db
+        .query(&explain)
+        .with_context(|| format!("Failed to explain: {}", sql))?;
 
     println!("Query Explanation:");
     println!("  Query: {}", sql);
-    println!("  Status: Not yet implemented");
+    println!("  Estimated cost: {}", format_cost(cost_ns));
+    println!("\nPlan:");
+    print!("{}", format_plan(&plan));
 
     if verbose {
-        println!("\nVerbose explanation would show:");
-        println!("  - Parse tree");
-        println!("  - Index selection");
-        println!("  - Cost estimation");
-        println!("  - Execution plan");
+        println!("\nIndices:");
+        let indices = db.list_indices();
+        if indices.is_empty() {
+            println!("  (none)");
+        }
+        for index in indices {
+            println!(
+                "  {} on {}.{} ({})",
+                index.name, index.table, index.column, index.index_type
+            );
+        }
     }
 
     Ok(())
 }
+
+/// Returns the query after a leading `EXPLAIN COST`, if present.
+pub fn strip_explain_cost(sql: &str) -> Option<&str> {
+    let rest = strip_keyword(sql.trim_start(), "EXPLAIN")?;
+    strip_keyword(rest, "COST")
+}
+
+/// Returns the query after a leading `EXPLAIN [ANALYZE]` and whether
+/// ANALYZE was given, if present.
+pub fn strip_explain(sql: &str) -> Option<(&str, bool)> {
+    let rest = strip_keyword(sql.trim_start(), "EXPLAIN")?;
+    Some(match strip_keyword(rest, "ANALYZE") {
+        Some(query) => (query, true),
+        None => (rest, false),
+    })
+}
+
+/// Formats the result of `EXPLAIN [ANALYZE]`, one step per line.
+pub fn format_plan(plan: &QueryResult) -> String {
+    let QueryResult::Rows(steps) = plan else {
+        return String::new();
+    };
+
+    let mut output = String::new();
+    for step in steps {
+        let field = |column: &str| step.get(column).map_or("", String::as_str);
+        output.push_str(&format!(
+            "  {}. {} (estimated rows: {}",
+            field("step"),
+            field("operation"),
+            field("estimated_rows")
+        ));
+        if !field("actual_rows").is_empty() {
+            output.push_str(&format!(", actual: {}", field("actual_rows")));
+        }
+        output.push(')');
+        if !field("index_used").is_empty() {
+            output.push_str(&format!(" using {}", field("index_used")));
+        }
+        output.push('\n');
+    }
+    output
+}
+
+/// Formats a nanosecond cost estimate for display.
+pub fn format_cost(cost_ns: f64) -> String {
+    format!("{:.0} ns ({:.3} ms)", cost_ns, cost_ns / 1_000_000.0)
+}
+
+fn strip_keyword<'a>(sql: &'a str, keyword: &str) -> Option<&'a str> {
+    let head = sql.get(..keyword.len())?;
+    let rest = &sql[keyword.len()..];
+    if head.eq_ignore_ascii_case(keyword) && rest.starts_with(char::is_whitespace) {
+        Some(rest.trim_start())
+    } else {
+        None
+    }
+}
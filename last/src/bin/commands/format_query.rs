@@ -0,0 +1,28 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Format-query command implementation.
+
+use anyhow::{Context, Result};
+use reedbase_last::reedql::format_query;
+use std::io::Read;
+
+/// Pretty-prints a query given as argument, or read from stdin when the
+/// argument is absent or `-` (for editor integrations).
+pub fn execute(sql: Option<&str>) -> Result<()> {
+    let input = match sql {
+        Some(sql) if sql != "-" => sql.to_string(),
+        _ => {
+            let mut buffer = String::new();
+            std::io::stdin()
+                .read_to_string(&mut buffer)
+                .context("Failed to read query from stdin")?;
+            buffer
+        }
+    };
+
+    let formatted = format_query(&input).context("Failed to format query")?;
+    println!("{}", formatted);
+
+    Ok(())
+}
@@ -4,6 +4,7 @@
 //! Indices command implementation.
 
 use anyhow::{Context, Result};
+use reedbase_last::reedql::format_condition;
 use reedbase_last::Database;
 use std::path::Path;
 
@@ -21,11 +22,16 @@ pub fn execute(
     if let Some(table_column) = create {
         let parts: Vec<&str> = table_column.split('.').collect();
         if parts.len() != 2 {
-            anyhow::bail!("Invalid format. Use: table.column");
+            anyhow::bail!("Invalid format. Use: table.column or table.col1,col2");
         }
 
-        db.create_index(parts[0], parts[1])
-            .with_context(|| format!("Failed to create index on {}", table_column))?;
+        let columns: Vec<&str> = parts[1].split(',').collect();
+        if columns.len() > 1 {
+            db.create_composite_index(parts[0], &columns)
+        } else {
+            db.create_index(parts[0], parts[1])
+        }
+        .with_context(|| format!("Failed to create index on {}", table_column))?;
         println!("Created index: {}", table_column);
         return Ok(());
     }
@@ -52,6 +58,13 @@ pub fn execute(
         return Ok(());
     }
 
+    let tree_stats = if verbose {
+        db.index_stats()
+            .context("Failed to read index statistics")?
+    } else {
+        Default::default()
+    };
+
     println!("Indices:");
     for index in indices {
         if verbose {
@@ -63,10 +76,44 @@ pub fn execute(
                 index.entry_count,
                 index.total_bytes()
             );
+            if index.column.contains(',') {
+                println!(
+                    "      composite index on ({})",
+                    index.column.replace(',', ", ")
+                );
+            }
+            if let Some(predicate) = &index.predicate {
+                println!("      partial index WHERE {}", format_condition(predicate));
+            }
+            if let Some(stats) = tree_stats.get(&format!("{}.{}", index.table, index.column)) {
+                println!(
+                    "      height {}, {} internal / {} leaf nodes, {} keys",
+                    stats.height, stats.internal_count, stats.leaf_count, stats.total_keys
+                );
+                println!(
+                    "      leaf fill {:.1}% (min {}, max {} keys), {} bytes unused",
+                    stats.avg_leaf_fill_pct,
+                    stats.min_leaf_keys,
+                    stats.max_leaf_keys,
+                    stats.wasted_space_bytes
+                );
+            }
         } else {
             println!(
-                "  - {}.{} ({})",
-                index.table, index.column, index.index_type
+                "  - {}.{} ({}{}){}",
+                index.table,
+                index.column,
+                index.index_type,
+                if index.column.contains(',') {
+                    ", composite"
+                } else {
+                    ""
+                },
+                index
+                    .predicate
+                    .as_ref()
+                    .map(|p| format!(" WHERE {}", format_condition(p)))
+                    .unwrap_or_default()
             );
         }
     }
@@ -0,0 +1,31 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Lint command implementation.
+
+use anyhow::{Context, Result};
+use reedbase_last::reedql;
+use reedbase_last::Database;
+use std::path::Path;
+
+pub fn execute(sql: &str, path: Option<&Path>) -> Result<()> {
+    // Index check needs a database; everything else works on the text alone
+    let warnings = match path {
+        Some(path) => Database::open(path)
+            .with_context(|| format!("Failed to open database at {}", path.display()))?
+            .lint(sql),
+        None => reedql::lint(sql),
+    };
+
+    if warnings.is_empty() {
+        println!("No issues found");
+        return Ok(());
+    }
+
+    println!("{}", sql);
+    for warning in &warnings {
+        println!("{}^ {}", " ".repeat(warning.position), warning);
+    }
+
+    Ok(())
+}
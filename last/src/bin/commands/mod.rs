@@ -3,9 +3,15 @@
 
 //! CLI command implementations.
 
+pub mod audit;
+pub mod check_integrity;
+pub mod column_stats;
+pub mod diff;
 pub mod exec;
 pub mod explain;
+pub mod format_query;
 pub mod indices;
+pub mod lint;
 pub mod query;
 pub mod shell;
 pub mod stats;
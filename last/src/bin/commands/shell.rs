@@ -9,6 +9,7 @@ use rustyline::error::ReadlineError;
 use rustyline::DefaultEditor;
 use std::path::Path;
 
+use crate::commands::explain;
 use crate::formatters;
 
 pub fn run(path: &Path, user: &str) -> Result<()> {
@@ -51,6 +52,22 @@ pub fn run(path: &Path, user: &str) -> Result<()> {
                     continue;
                 }
 
+                // EXPLAIN COST <query>: estimate only, never executes
+                if let Some(sql) = explain::strip_explain_cost(trimmed) {
+                    match db.estimate_query_cost(sql) {
+                        Ok(cost_ns) => {
+                            println!("Estimated cost: {}", explain::format_cost(cost_ns))
+                        }
+                        Err(e) => eprintln!("Error: {}", e),
+                    }
+                    continue;
+                }
+
+                // Pre-execution lint (warnings only, never blocks)
+                for warning in db.lint(trimmed) {
+                    eprintln!("{}", warning);
+                }
+
                 // Execute SQL
                 if is_query(trimmed) {
                     // SELECT query
@@ -99,7 +116,8 @@ pub fn run(path: &Path, user: &str) -> Result<()> {
 }
 
 fn is_query(sql: &str) -> bool {
-    sql.trim().to_uppercase().starts_with("SELECT")
+    let sql = sql.trim().to_uppercase();
+    sql.starts_with("SELECT") || sql.starts_with("EXPLAIN")
 }
 
 fn handle_dot_command(cmd: &str, db: &Database, format: &mut String) -> Result<bool> {
@@ -122,6 +140,9 @@ fn handle_dot_command(cmd: &str, db: &Database, format: &mut String) -> Result<b
             println!("  .clear           Clear screen");
             println!("  .help            Show this help");
             println!("  .exit            Exit shell");
+            println!();
+            println!("  EXPLAIN COST <SQL>     Estimate query cost without running it");
+            println!("  EXPLAIN [ANALYZE] <SQL> Show the execution plan (ANALYZE runs the query)");
         }
 
         ".tables" => match db.list_tables() {
@@ -177,8 +198,14 @@ fn handle_dot_command(cmd: &str, db: &Database, format: &mut String) -> Result<b
                 println!("Usage: .explain <SQL>");
             } else {
                 let sql = parts[1..].join(" ");
-                println!("Query explanation not yet implemented for: {}", sql);
-                // TODO: Implement query explanation
+                match db.query(&format!("EXPLAIN {}", sql)) {
+                    Ok(plan) => print!("{}", explain::format_plan(&plan)),
+                    Err(e) => eprintln!("Error: {}", e),
+                }
+                match db.estimate_query_cost(&sql) {
+                    Ok(cost_ns) => println!("Estimated cost: {}", explain::format_cost(cost_ns)),
+                    Err(e) => eprintln!("Error: {}", e),
+                }
             }
         }
 
@@ -5,7 +5,13 @@
 
 use reedbase_last::reedql::QueryResult;
 
+/// Cell text for missing or empty values in table output.
+const NULL_CELL: &str = "NULL";
+
 /// Formats result as human-readable table.
+///
+/// Missing and empty values are shown as `NULL` so that they stand out
+/// from blank padding.
 pub fn format_table(result: &QueryResult) -> String {
     match result {
         QueryResult::Rows(rows) => {
@@ -23,10 +29,8 @@ pub fn format_table(result: &QueryResult) -> String {
 
             for row in rows {
                 for col in &columns {
-                    if let Some(value) = row.get(col) {
-                        let current = widths.get(col).copied().unwrap_or(0);
-                        widths.insert(col.clone(), current.max(value.len()));
-                    }
+                    let current = widths.get(col).copied().unwrap_or(0);
+                    widths.insert(col.clone(), current.max(cell(row.get(col)).len()));
                 }
             }
 
@@ -64,7 +68,7 @@ pub fn format_table(result: &QueryResult) -> String {
                 output.push('|');
                 for col in &columns {
                     let width = widths.get(col).copied().unwrap_or(0);
-                    let value = row.get(col).map(|s| s.as_str()).unwrap_or("");
+                    let value = cell(row.get(col));
                     output.push_str(&format!(" {:<width$} |", value, width = width));
                 }
                 output.push('\n');
@@ -89,6 +93,14 @@ pub fn format_table(result: &QueryResult) -> String {
     }
 }
 
+/// Table cell text for a value (`NULL` if missing or empty).
+fn cell(value: Option<&String>) -> &str {
+    match value {
+        Some(value) if !value.is_empty() => value,
+        _ => NULL_CELL,
+    }
+}
+
 /// Formats result as JSON.
 pub fn format_json(result: &QueryResult) -> String {
     match result {
@@ -131,6 +143,8 @@ pub fn format_json(result: &QueryResult) -> String {
 }
 
 /// Formats result as CSV.
+///
+/// Missing and empty values are written as empty fields.
 pub fn format_csv(result: &QueryResult, include_header: bool) -> String {
     match result {
         QueryResult::Rows(rows) => {
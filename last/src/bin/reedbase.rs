@@ -6,12 +6,16 @@
 //! Command-line interface for ReedBase operations.
 
 use clap::{Parser, Subcommand};
+use reedbase_last::tables::AuditFilter;
 use std::path::PathBuf;
 
 mod commands;
 mod formatters;
 
-use commands::{exec, explain, indices, query, shell, stats, tables};
+use commands::{
+    audit, check_integrity, column_stats, diff, exec, explain, format_query, indices, lint, query,
+    shell, stats, tables,
+};
 
 #[derive(Parser)]
 #[command(name = "reedbase")]
@@ -61,6 +65,10 @@ enum Commands {
         /// Don't print affected rows
         #[arg(short, long)]
         quiet: bool,
+
+        /// Feed stdin into the table (for "INSERT INTO <table> FROM STDIN")
+        #[arg(long)]
+        pipe: bool,
     },
 
     /// Open interactive shell
@@ -100,7 +108,7 @@ enum Commands {
         /// Path to ReedBase directory
         path: PathBuf,
 
-        /// Create index on table.column
+        /// Create index on table.column (or table.col1,col2 for a composite index)
         #[arg(short, long)]
         create: Option<String>,
 
@@ -112,7 +120,7 @@ enum Commands {
         #[arg(short, long)]
         rebuild: Option<String>,
 
-        /// Show index statistics
+        /// Show index statistics (including B+-Tree fill factor)
         #[arg(short, long)]
         verbose: bool,
     },
@@ -138,6 +146,91 @@ enum Commands {
         /// Show detailed plan
         #[arg(short, long)]
         verbose: bool,
+
+        /// Run the query and report actual row counts (EXPLAIN ANALYZE)
+        #[arg(short, long)]
+        analyze: bool,
+    },
+
+    /// Check a ReedQL statement for likely mistakes
+    Lint {
+        /// ReedQL statement (quoted)
+        sql: String,
+
+        /// Path to ReedBase directory (enables index checks)
+        path: Option<PathBuf>,
+    },
+
+    /// Show value distribution statistics for a column
+    ColumnStats {
+        /// Path to ReedBase directory
+        path: PathBuf,
+
+        /// Table name
+        #[arg(short, long)]
+        table: String,
+
+        /// Column name
+        #[arg(short, long)]
+        column: String,
+
+        /// Number of most frequent values to show
+        #[arg(long, default_value = "10")]
+        top: usize,
+    },
+
+    /// Compare tables with another ReedBase directory
+    Diff {
+        /// Path to the other ReedBase directory
+        other: PathBuf,
+
+        /// Path to ReedBase directory
+        #[arg(short, long, default_value = ".reed")]
+        path: PathBuf,
+    },
+
+    /// Report foreign key values without a matching referenced row
+    CheckIntegrity {
+        /// Path to ReedBase directory
+        #[arg(short, long, default_value = ".reed")]
+        path: PathBuf,
+    },
+
+    /// Pretty-print a ReedQL query (reads stdin if no query or "-")
+    FormatQuery {
+        /// ReedQL query (quoted)
+        sql: Option<String>,
+    },
+
+    /// Show entries from the audit log
+    Audit {
+        /// Path to ReedBase directory
+        #[arg(short, long, default_value = ".reed")]
+        path: PathBuf,
+
+        /// Only entries by this user
+        #[arg(long)]
+        user: Option<String>,
+
+        /// Only entries for this table
+        #[arg(long)]
+        table: Option<String>,
+
+        /// Only entries with this operation (insert, update, delete, ...)
+        #[arg(long)]
+        operation: Option<String>,
+
+        /// Only entries at or after this timestamp (nanoseconds)
+        #[arg(long)]
+        since: Option<u64>,
+
+        /// Only entries at or before this timestamp (nanoseconds)
+        #[arg(long)]
+        until: Option<u64>,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
     },
 }
 
@@ -158,10 +251,15 @@ fn main() -> anyhow::Result<()> {
             path,
             user,
             quiet,
+            pipe,
         } => {
             let username = user
                 .unwrap_or_else(|| std::env::var("USER").unwrap_or_else(|_| "unknown".to_string()));
-            exec::execute(&sql, &path, &username, quiet)?;
+            if pipe {
+                exec::execute_pipe(&sql, &path, &username, quiet)?;
+            } else {
+                exec::execute(&sql, &path, &username, quiet)?;
+            }
         }
 
         Commands::Shell { path, user } => {
@@ -194,7 +292,46 @@ fn main() -> anyhow::Result<()> {
 
         Commands::Stats { path, format } => stats::execute(&path, &format)?,
 
-        Commands::Explain { sql, path, verbose } => explain::execute(&sql, &path, verbose)?,
+        Commands::Explain {
+            sql,
+            path,
+            verbose,
+            analyze,
+        } => explain::execute(&sql, &path, verbose, analyze)?,
+
+        Commands::Lint { sql, path } => lint::execute(&sql, path.as_deref())?,
+
+        Commands::ColumnStats {
+            path,
+            table,
+            column,
+            top,
+        } => column_stats::execute(&path, &table, &column, top)?,
+
+        Commands::Diff { other, path } => diff::execute(&path, &other)?,
+
+        Commands::CheckIntegrity { path } => check_integrity::execute(&path)?,
+
+        Commands::FormatQuery { sql } => format_query::execute(sql.as_deref())?,
+
+        Commands::Audit {
+            path,
+            user,
+            table,
+            operation,
+            since,
+            until,
+            json,
+        } => {
+            let filter = AuditFilter {
+                user,
+                table,
+                operation,
+                since,
+                until,
+            };
+            audit::execute(&path, filter, json)?
+        }
     }
 
     Ok(())
@@ -15,14 +15,21 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::btree::node::{InternalNode, LeafNode};
+    use crate::btree::node::{InternalNode, LeafKeys, LeafNode};
     use crate::btree::page::{Page, PAGE_SIZE};
     use crate::btree::tree::BPlusTree;
-    use crate::btree::types::{Index, NodeType, Order, BTREE_MAGIC};
-    use crate::btree::wal::{WalEntry, WriteAheadLog};
-    use crate::error::ReedResult;
+    use crate::btree::types::{ConflictMode, Index, NodeType, Order, BTREE_MAGIC};
+    use crate::btree::wal::{WalEntry, WriteAheadLog, DEFAULT_MAX_ENTRIES};
+    use crate::error::{ReedError, ReedResult};
     use tempfile::{tempdir, NamedTempFile};
 
+    /// Asserts that every structural invariant holds for the given tree.
+    fn assert_integrity(tree: &BPlusTree<String, Vec<u8>>) -> ReedResult<()> {
+        let report = tree.verify_structural_integrity()?;
+        assert!(report.is_valid(), "{:?}", report.violations);
+        Ok(())
+    }
+
     // ============================================================================
     // Node Tests
     // ============================================================================
@@ -187,6 +194,18 @@ mod tests {
         assert!(node.is_overflow(order));
     }
 
+    #[test]
+    fn test_leaf_keys_decodes_leaf_node() {
+        let mut node = LeafNode::<String, Vec<u8>>::new();
+        node.keys = vec!["a".to_string(), "b".to_string()];
+        node.values = vec![vec![1; 100], vec![2; 100]];
+        node.next = Some(7);
+
+        let bytes = bincode::serialize(&node).unwrap();
+        let keys: LeafKeys<String> = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(keys.keys, node.keys);
+    }
+
     // ============================================================================
     // Page Tests
     // ============================================================================
@@ -238,7 +257,7 @@ mod tests {
     #[test]
     fn test_wal_open() -> ReedResult<()> {
         let tmp = NamedTempFile::new().unwrap();
-        let wal = WriteAheadLog::open(tmp.path())?;
+        let wal = WriteAheadLog::open(tmp.path(), DEFAULT_MAX_ENTRIES)?;
         assert!(tmp.path().exists());
         Ok(())
     }
@@ -246,7 +265,7 @@ mod tests {
     #[test]
     fn test_wal_log_insert() -> ReedResult<()> {
         let tmp = NamedTempFile::new().unwrap();
-        let mut wal = WriteAheadLog::open(tmp.path())?;
+        let mut wal = WriteAheadLog::open(tmp.path(), DEFAULT_MAX_ENTRIES)?;
 
         wal.log_insert("key1".to_string(), vec![1u8, 2u8, 3u8])?;
         wal.log_insert("key2".to_string(), vec![4u8, 5u8, 6u8])?;
@@ -258,7 +277,7 @@ mod tests {
     #[test]
     fn test_wal_log_delete() -> ReedResult<()> {
         let tmp = NamedTempFile::new().unwrap();
-        let mut wal = WriteAheadLog::open(tmp.path())?;
+        let mut wal = WriteAheadLog::open(tmp.path(), DEFAULT_MAX_ENTRIES)?;
 
         wal.log_delete("key1".to_string())?;
         wal.sync()?;
@@ -272,7 +291,7 @@ mod tests {
 
         // Write entries
         {
-            let mut wal = WriteAheadLog::open(tmp.path())?;
+            let mut wal = WriteAheadLog::open(tmp.path(), DEFAULT_MAX_ENTRIES)?;
             wal.log_insert("key1".to_string(), vec![1u8, 2u8, 3u8])?;
             wal.log_insert("key2".to_string(), vec![4u8, 5u8, 6u8])?;
             wal.log_delete("key1".to_string())?;
@@ -281,7 +300,7 @@ mod tests {
 
         // Replay entries
         {
-            let wal = WriteAheadLog::open(tmp.path())?;
+            let wal = WriteAheadLog::open(tmp.path(), DEFAULT_MAX_ENTRIES)?;
             let entries: Vec<WalEntry<String, Vec<u8>>> = wal.replay()?;
 
             assert_eq!(entries.len(), 3);
@@ -316,7 +335,7 @@ mod tests {
     #[test]
     fn test_wal_truncate() -> ReedResult<()> {
         let tmp = NamedTempFile::new().unwrap();
-        let mut wal = WriteAheadLog::open(tmp.path())?;
+        let mut wal = WriteAheadLog::open(tmp.path(), DEFAULT_MAX_ENTRIES)?;
 
         wal.log_insert("key1".to_string(), vec![1u8, 2u8, 3u8])?;
         wal.sync()?;
@@ -328,6 +347,198 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_wal_compact_keeps_last_entry_per_key() -> ReedResult<()> {
+        let tmp = NamedTempFile::new().unwrap();
+        let mut wal = WriteAheadLog::open(tmp.path(), DEFAULT_MAX_ENTRIES)?;
+
+        wal.log_insert("a".to_string(), vec![1u8])?;
+        wal.log_count_delta(1)?;
+        wal.log_insert("b".to_string(), vec![2u8])?;
+        wal.log_count_delta(1)?;
+        wal.log_insert("a".to_string(), vec![3u8])?;
+        wal.log_delete("b".to_string())?;
+        wal.log_count_delta(-1)?;
+        wal.sync()?;
+
+        assert_eq!(wal.compact()?, 3);
+        assert!(!std::path::PathBuf::from(format!("{}.tmp", tmp.path().display())).exists());
+
+        let entries: Vec<WalEntry<String, Vec<u8>>> = wal.replay()?;
+        assert_eq!(entries.len(), 3);
+        assert!(
+            matches!(&entries[0], WalEntry::Insert { key, value } if key == "a" && value == &vec![3u8])
+        );
+        assert!(matches!(&entries[1], WalEntry::Delete { key } if key == "b"));
+        assert!(matches!(&entries[2], WalEntry::CountDelta { delta: 1 }));
+
+        // Appends after compaction go to the new file
+        wal.log_insert("c".to_string(), vec![4u8])?;
+        wal.sync()?;
+        let entries: Vec<WalEntry<String, Vec<u8>>> = wal.replay()?;
+        assert_eq!(entries.len(), 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_wal_compacts_past_max_entries() -> ReedResult<()> {
+        let tmp = NamedTempFile::new().unwrap();
+        let mut wal = WriteAheadLog::open(tmp.path(), 10)?;
+
+        for i in 0..100u8 {
+            wal.log_insert(format!("key{}", i % 3), vec![i])?;
+        }
+        wal.sync()?;
+
+        let entries: Vec<WalEntry<String, Vec<u8>>> = wal.replay()?;
+        assert!(entries.len() <= 10, "{} entries", entries.len());
+        assert!(entries
+            .iter()
+            .any(|e| matches!(e, WalEntry::Insert { key, value } if key == "key0" && value == &vec![99u8])));
+
+        // Existing entries count towards the limit after reopening
+        drop(wal);
+        let wal = WriteAheadLog::open(tmp.path(), 10)?;
+        let entries: Vec<WalEntry<String, Vec<u8>>> = wal.replay()?;
+        assert!(entries.len() <= 10);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_wal_batch_replay() -> ReedResult<()> {
+        let tmp = NamedTempFile::new().unwrap();
+        let mut wal = WriteAheadLog::open(tmp.path(), DEFAULT_MAX_ENTRIES)?;
+
+        let inserts: Vec<(String, Vec<u8>)> = (0..1000u32)
+            .map(|i| (format!("key{}", i), vec![i as u8]))
+            .collect();
+        wal.log_insert("single".to_string(), vec![7u8])?;
+        wal.log_batch_insert(&inserts)?;
+        wal.log_batch_delete(&["key1".to_string(), "key2".to_string()])?;
+        wal.log_batch_insert::<String, Vec<u8>>(&[])?;
+        wal.sync()?;
+
+        let entries: Vec<WalEntry<String, Vec<u8>>> = wal.replay()?;
+        assert_eq!(entries.len(), 3);
+        assert!(matches!(&entries[1], WalEntry::BatchInsert { entries } if entries == &inserts));
+        assert!(matches!(&entries[2], WalEntry::BatchDelete { keys } if keys == &["key1", "key2"]));
+
+        // One record per batch: a torn batch is dropped as a whole
+        let len = std::fs::metadata(tmp.path()).unwrap().len();
+        std::fs::OpenOptions::new()
+            .write(true)
+            .open(tmp.path())
+            .unwrap()
+            .set_len(len - 1)
+            .unwrap();
+        let entries: Vec<WalEntry<String, Vec<u8>>> = wal.replay()?;
+        assert_eq!(entries.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_wal_compact_splits_batches() -> ReedResult<()> {
+        let tmp = NamedTempFile::new().unwrap();
+        let mut wal = WriteAheadLog::open(tmp.path(), DEFAULT_MAX_ENTRIES)?;
+
+        wal.log_batch_insert(&[
+            ("a".to_string(), vec![1u8]),
+            ("b".to_string(), vec![2u8]),
+            ("c".to_string(), vec![3u8]),
+        ])?;
+        wal.log_batch_delete(&["b".to_string()])?;
+        wal.log_insert("c".to_string(), vec![4u8])?;
+        wal.sync()?;
+
+        assert_eq!(wal.compact()?, 3);
+        let entries: Vec<WalEntry<String, Vec<u8>>> = wal.replay()?;
+        assert!(
+            matches!(&entries[0], WalEntry::Insert { key, value } if key == "a" && value == &vec![1u8])
+        );
+        assert!(matches!(&entries[1], WalEntry::Delete { key } if key == "b"));
+        assert!(
+            matches!(&entries[2], WalEntry::Insert { key, value } if key == "c" && value == &vec![4u8])
+        );
+
+        // Batches count per key towards max_entries
+        let tmp = NamedTempFile::new().unwrap();
+        let mut wal = WriteAheadLog::open(tmp.path(), 10)?;
+        for _ in 0..3 {
+            let batch: Vec<(String, Vec<u8>)> =
+                (0..5u8).map(|i| (format!("key{}", i), vec![i])).collect();
+            wal.log_batch_insert(&batch)?;
+        }
+        let entries: Vec<WalEntry<String, Vec<u8>>> = wal.replay()?;
+        assert_eq!(entries.len(), 5);
+        assert!(entries.iter().all(|e| matches!(e, WalEntry::Insert { .. })));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_btree_replays_batch_wal() -> ReedResult<()> {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("test.btree");
+        let order = Order::new(100)?;
+        drop(BPlusTree::<String, Vec<u8>>::open(&path, order)?);
+
+        // Logged but not applied, as after a crash
+        {
+            let mut wal = WriteAheadLog::open(path.with_extension("wal"), DEFAULT_MAX_ENTRIES)?;
+            let batch: Vec<(String, Vec<u8>)> = (0..50u8)
+                .map(|i| (format!("key{:02}", i), vec![i]))
+                .collect();
+            wal.log_batch_insert(&batch)?;
+            wal.log_count_delta(50)?;
+            wal.log_batch_delete(&["key00".to_string(), "key01".to_string()])?;
+            wal.log_count_delta(-2)?;
+            wal.sync()?;
+        }
+
+        let mut tree = BPlusTree::<String, Vec<u8>>::open(&path, order)?;
+        assert_eq!(tree.len(), 48);
+        assert_eq!(tree.verify_count()?, 48);
+        assert_eq!(tree.get(&"key10".to_string())?, Some(vec![10u8]));
+        assert_eq!(tree.get(&"key01".to_string())?, None);
+        assert_integrity(&tree)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_btree_replays_compacted_wal() -> ReedResult<()> {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("test.btree");
+        let order = Order::new(100)?;
+
+        // Repeated updates leave redundant entries in the log
+        {
+            let mut tree = BPlusTree::open(&path, order)?;
+            for round in 0..3u8 {
+                for i in 0..20 {
+                    tree.insert(format!("key{:02}", i), vec![round])?;
+                }
+            }
+            tree.delete(&"key00".to_string())?;
+        }
+
+        {
+            let mut wal = WriteAheadLog::open(path.with_extension("wal"), DEFAULT_MAX_ENTRIES)?;
+            wal.compact()?;
+        }
+
+        let mut tree = BPlusTree::<String, Vec<u8>>::open(&path, order)?;
+        assert_eq!(tree.len(), 19);
+        assert_eq!(tree.verify_count()?, 19);
+        assert_eq!(tree.get(&"key05".to_string())?, Some(vec![2u8]));
+        assert_eq!(tree.get(&"key00".to_string())?, None);
+
+        Ok(())
+    }
+
     // ============================================================================
     // B+-Tree Basic Operations
     // ============================================================================
@@ -343,6 +554,8 @@ mod tests {
         assert!(path.exists());
         assert_eq!(tree.backend_type(), "btree");
 
+        assert_integrity(&tree)?;
+
         Ok(())
     }
 
@@ -365,6 +578,8 @@ mod tests {
         assert_eq!(tree.get(&"key3".to_string())?, Some(vec![7u8, 8u8, 9u8]));
         assert_eq!(tree.get(&"key4".to_string())?, None);
 
+        assert_integrity(&tree)?;
+
         Ok(())
     }
 
@@ -383,6 +598,8 @@ mod tests {
         tree.insert("key1".to_string(), vec![99u8, 99u8, 99u8])?;
         assert_eq!(tree.get(&"key1".to_string())?, Some(vec![99u8, 99u8, 99u8]));
 
+        assert_integrity(&tree)?;
+
         Ok(())
     }
 
@@ -406,6 +623,8 @@ mod tests {
         assert_eq!(tree.get(&"key1".to_string())?, Some(vec![1u8, 2u8, 3u8]));
         assert_eq!(tree.get(&"key3".to_string())?, Some(vec![7u8, 8u8, 9u8]));
 
+        assert_integrity(&tree)?;
+
         Ok(())
     }
 
@@ -420,6 +639,87 @@ mod tests {
         // Delete non-existent key (should not error)
         tree.delete(&"nonexistent".to_string())?;
 
+        assert_integrity(&tree)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_btree_delete_rebalances_leaves() -> ReedResult<()> {
+        let dir = tempdir().unwrap();
+        let order = Order::new(4)?;
+        let mut tree = BPlusTree::open(dir.path().join("test.btree"), order)?;
+
+        // Leaves [0 1 2] [3 4 5] [6 7 8]
+        tree.bulk_load((0..9u8).map(|i| (format!("key{}", i), vec![i])))?;
+
+        // Middle leaf drops below the minimum: borrow from the left sibling
+        tree.delete(&"key3".to_string())?;
+        tree.delete(&"key4".to_string())?;
+        assert_integrity(&tree)?;
+        assert_eq!(tree.statistics()?.leaf_count, 3);
+
+        // Both siblings at the minimum: merge, parent loses a separator
+        tree.delete(&"key7".to_string())?;
+        tree.delete(&"key8".to_string())?;
+        assert_integrity(&tree)?;
+        assert_eq!(tree.statistics()?.leaf_count, 2);
+
+        let keys: Vec<String> = tree.iter().map(|(k, _)| k).collect();
+        assert_eq!(keys, ["key0", "key1", "key2", "key5", "key6"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_btree_delete_cascades_merges() -> ReedResult<()> {
+        let dir = tempdir().unwrap();
+
+        for order in [3u16, 4, 5] {
+            let path = dir.path().join(format!("cascade_{}.btree", order));
+            let mut tree = BPlusTree::open(&path, Order::new(order)?)?;
+            let count = 200usize;
+            let mut expected: std::collections::BTreeMap<String, Vec<u8>> = (0..count)
+                .map(|i| (format!("key{:04}", i), vec![i as u8]))
+                .collect();
+            tree.bulk_load(expected.clone())?;
+
+            // Delete in a scattered order, checking invariants every time
+            let mut depth = tree.verify_structural_integrity()?.depth;
+            assert!(depth >= 4, "order {} depth {}", order, depth);
+            let mut cascades = 0;
+            for i in 0..count {
+                let key = format!("key{:04}", (i * 7919) % count);
+                tree.delete(&key)?;
+                expected.remove(&key);
+
+                let report = tree.verify_structural_integrity()?;
+                assert!(
+                    report.is_valid(),
+                    "order {} after deleting {}: {:?}",
+                    order,
+                    key,
+                    report.violations
+                );
+                assert_eq!(report.key_count, expected.len());
+                // Losing a level from depth >= 3 means a leaf merge cascaded
+                // through at least two internal levels up to the root
+                if report.depth < depth && depth >= 3 {
+                    cascades += 1;
+                }
+                depth = report.depth;
+            }
+            assert!(cascades >= 2, "order {}: {} cascades", order, cascades);
+            assert_eq!(depth, 1);
+            assert_eq!(tree.iter().count(), 0);
+
+            // The collapsed tree reopens with the same (empty) contents
+            drop(tree);
+            let tree = BPlusTree::<String, Vec<u8>>::open(&path, Order::new(order)?)?;
+            assert_integrity(&tree)?;
+            assert_eq!(tree.key_count()?, 0);
+        }
+
         Ok(())
     }
 
@@ -455,6 +755,8 @@ mod tests {
         assert_eq!(results[0].0, "post.a");
         assert_eq!(results[1].0, "post.b");
 
+        assert_integrity(&tree)?;
+
         Ok(())
     }
 
@@ -473,6 +775,8 @@ mod tests {
         let results = tree.range(&"post.a".to_string(), &"post.z".to_string())?;
         assert_eq!(results.len(), 0);
 
+        assert_integrity(&tree)?;
+
         Ok(())
     }
 
@@ -493,6 +797,84 @@ mod tests {
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].0, "page.b");
 
+        assert_integrity(&tree)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_btree_count_keys_in_range() -> ReedResult<()> {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("test.btree");
+        let order = Order::new(4)?;
+
+        let mut tree = BPlusTree::open(&path, order)?;
+
+        // Small order forces several levels of internal nodes
+        for i in 0..200 {
+            tree.insert(format!("key{:03}", i), vec![i as u8; 32])?;
+        }
+
+        for (start, end) in [
+            ("key000", "key200"),
+            ("key050", "key150"),
+            ("key010", "key011"),
+            ("key0", "key1"),
+            ("a", "z"),
+            ("x", "z"),
+        ] {
+            let (start, end) = (start.to_string(), end.to_string());
+            assert_eq!(
+                tree.count_keys_in_range(&start, &end)?,
+                tree.range(&start, &end)?.len(),
+                "range [{}, {})",
+                start,
+                end
+            );
+        }
+
+        assert_eq!(
+            tree.count_keys_in_range(&"key050".to_string(), &"key150".to_string())?,
+            100
+        );
+
+        // Empty or inverted range
+        assert_eq!(
+            tree.count_keys_in_range(&"key100".to_string(), &"key100".to_string())?,
+            0
+        );
+        assert_eq!(
+            tree.count_keys_in_range(&"key150".to_string(), &"key050".to_string())?,
+            0
+        );
+
+        assert_integrity(&tree)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_btree_key_count() -> ReedResult<()> {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("test.btree");
+        let order = Order::new(4)?;
+
+        let mut tree = BPlusTree::<String, Vec<u8>>::open(&path, order)?;
+        assert_eq!(tree.key_count()?, 0);
+
+        for i in 0..100 {
+            tree.insert(format!("key{:03}", i), vec![i as u8])?;
+        }
+        assert_eq!(tree.key_count()?, 100);
+
+        // Updates do not add keys
+        tree.insert("key010".to_string(), vec![0])?;
+        assert_eq!(tree.key_count()?, 100);
+
+        tree.delete(&"key010".to_string())?;
+        assert_eq!(tree.key_count()?, 99);
+        assert_eq!(tree.key_count()?, tree.iter().count());
+
         Ok(())
     }
 
@@ -519,6 +901,8 @@ mod tests {
         assert_eq!(results[1].0, "b");
         assert_eq!(results[2].0, "c");
 
+        assert_integrity(&tree)?;
+
         Ok(())
     }
 
@@ -533,6 +917,29 @@ mod tests {
         let results: Vec<(String, Vec<u8>)> = tree.iter().collect();
         assert_eq!(results.len(), 0);
 
+        assert_integrity(&tree)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_btree_iter_is_lazy() -> ReedResult<()> {
+        let dir = tempdir().unwrap();
+        let mut tree = BPlusTree::<u64, u32>::open(dir.path().join("a.btree"), Order::new(50)?)?;
+        tree.bulk_load((0..10_000u64).map(|i| (i, i as u32)))?;
+
+        // Taking a prefix reads only the leaves it needs
+        let mut iter = tree.iter();
+        assert_eq!(
+            iter.by_ref().take(3).collect::<Vec<_>>(),
+            [(0, 0), (1, 1), (2, 2)]
+        );
+        assert_eq!(iter.keys_scanned(), 3);
+
+        // The Index trait iterator is the same lazy scan
+        let boxed = Index::iter(&tree);
+        assert!(boxed.map(|(k, _)| k).eq(0..10_000u64));
+
         Ok(())
     }
 
@@ -561,6 +968,311 @@ mod tests {
             assert_eq!(value, Some(vec![i as u8]));
         }
 
+        assert_integrity(&tree)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_btree_integrity_report_after_splits() -> ReedResult<()> {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("test.btree");
+        let order = Order::new(4)?;
+
+        let mut tree = BPlusTree::open(&path, order)?;
+        for i in 0..10 {
+            tree.insert(format!("key{:03}", i), vec![i as u8])?;
+        }
+
+        let report = tree.verify_structural_integrity()?;
+        assert!(report.is_valid(), "{:?}", report.violations);
+        assert_eq!(report.key_count, 10);
+        assert_eq!(report.depth, 2);
+        assert_eq!(report.internal_nodes, 1);
+        assert!(report.leaf_nodes > 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_btree_splits_propagate_to_root() -> ReedResult<()> {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("test.btree");
+        let order = Order::new(4)?;
+
+        {
+            let mut tree = BPlusTree::open(&path, order)?;
+            for i in 0..200 {
+                tree.insert(format!("key{:03}", (i * 67) % 200), vec![i as u8])?;
+            }
+
+            let report = tree.verify_structural_integrity()?;
+            assert!(report.is_valid(), "{:?}", report.violations);
+            assert_eq!(report.key_count, 200);
+            assert!(report.depth >= 3, "depth {}", report.depth);
+        }
+
+        let tree = BPlusTree::<String, Vec<u8>>::open(&path, order)?;
+        assert_integrity(&tree)?;
+        assert_eq!(tree.key_count()?, 200);
+
+        Ok(())
+    }
+
+    // ============================================================================
+    // Merge Tests
+    // ============================================================================
+
+    /// Opens a tree in `dir` holding the given string pairs.
+    fn tree_with(
+        dir: &std::path::Path,
+        name: &str,
+        order: Order,
+        pairs: &[(&str, u8)],
+    ) -> ReedResult<BPlusTree<String, Vec<u8>>> {
+        let mut tree = BPlusTree::open(dir.join(name), order)?;
+        for (key, value) in pairs {
+            tree.insert(key.to_string(), vec![*value])?;
+        }
+        Ok(tree)
+    }
+
+    #[test]
+    fn test_btree_merge_overwrite() -> ReedResult<()> {
+        let dir = tempdir().unwrap();
+        let order = Order::new(10)?;
+        let mut tree = tree_with(dir.path(), "a.btree", order, &[("a", 1), ("b", 2)])?;
+        let other = tree_with(dir.path(), "b.btree", order, &[("b", 20), ("c", 30)])?;
+
+        tree.merge(&other, ConflictMode::default())?;
+
+        let pairs: Vec<(String, Vec<u8>)> = tree.iter().collect();
+        assert_eq!(
+            pairs,
+            vec![
+                ("a".to_string(), vec![1]),
+                ("b".to_string(), vec![20]),
+                ("c".to_string(), vec![30]),
+            ]
+        );
+        assert_eq!(other.key_count()?, 2);
+        assert_integrity(&tree)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_btree_merge_skip_existing() -> ReedResult<()> {
+        let dir = tempdir().unwrap();
+        let order = Order::new(10)?;
+        let mut tree = tree_with(dir.path(), "a.btree", order, &[("a", 1), ("b", 2)])?;
+        let other = tree_with(dir.path(), "b.btree", order, &[("b", 20), ("c", 30)])?;
+
+        tree.merge(&other, ConflictMode::SkipExisting)?;
+
+        assert_eq!(tree.get(&"b".to_string())?, Some(vec![2]));
+        assert_eq!(tree.get(&"c".to_string())?, Some(vec![30]));
+        assert_eq!(tree.key_count()?, 3);
+        assert_integrity(&tree)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_btree_merge_error_leaves_tree_unchanged() -> ReedResult<()> {
+        let dir = tempdir().unwrap();
+        let order = Order::new(10)?;
+        let mut tree = tree_with(dir.path(), "a.btree", order, &[("a", 1), ("b", 2)])?;
+        let other = tree_with(dir.path(), "b.btree", order, &[("0", 0), ("b", 20)])?;
+
+        let result = tree.merge(&other, ConflictMode::Error);
+        assert!(matches!(
+            result,
+            Err(ReedError::IndexOperationUnsupported { .. })
+        ));
+
+        // "0" sorts before the conflict but must not have been written
+        assert_eq!(tree.get(&"0".to_string())?, None);
+        assert_eq!(tree.get(&"b".to_string())?, Some(vec![2]));
+        assert_eq!(tree.key_count()?, 2);
+
+        // Disjoint trees merge fine in Error mode
+        let disjoint = tree_with(dir.path(), "c.btree", order, &[("c", 3)])?;
+        tree.merge(&disjoint, ConflictMode::Error)?;
+        assert_eq!(tree.key_count()?, 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_btree_merge_splits_leaves() -> ReedResult<()> {
+        let dir = tempdir().unwrap();
+        let order = Order::new(4)?;
+        let mut tree = tree_with(dir.path(), "a.btree", order, &[("k05", 5), ("k15", 15)])?;
+        let pairs: Vec<(String, u8)> = (0..20).map(|i| (format!("k{:02}", i), i + 100)).collect();
+        let pairs: Vec<(&str, u8)> = pairs.iter().map(|(k, v)| (k.as_str(), *v)).collect();
+        let other = tree_with(dir.path(), "b.btree", order, &pairs)?;
+
+        // Root leaf splits into an internal root over several leaves
+        tree.merge(&other, ConflictMode::SkipExisting)?;
+
+        let keys: Vec<String> = tree.iter().map(|(k, _)| k).collect();
+        let expected: Vec<String> = (0..20).map(|i| format!("k{:02}", i)).collect();
+        assert_eq!(keys, expected);
+        assert_eq!(tree.get(&"k05".to_string())?, Some(vec![5]));
+        assert_eq!(tree.get(&"k19".to_string())?, Some(vec![119]));
+
+        let report = tree.verify_structural_integrity()?;
+        assert!(report.is_valid(), "{:?}", report.violations);
+        assert_eq!(report.depth, 2);
+
+        // Merging again into the now internal root still works
+        tree.merge(&other, ConflictMode::Overwrite)?;
+        assert_eq!(tree.get(&"k05".to_string())?, Some(vec![105]));
+        assert_integrity(&tree)?;
+        assert!(tree.statistics()?.avg_leaf_fill_pct >= 50.0);
+
+        // The root stayed on page 0, so the merged tree reopens intact
+        drop(tree);
+        let tree = BPlusTree::<String, Vec<u8>>::open(dir.path().join("a.btree"), order)?;
+        assert_integrity(&tree)?;
+        assert_eq!(tree.iter().count(), 20);
+        assert_eq!(tree.get(&"k05".to_string())?, Some(vec![105]));
+
+        Ok(())
+    }
+
+    // ============================================================================
+    // Bulk Load Tests
+    // ============================================================================
+
+    #[test]
+    fn test_btree_bulk_load_shapes() -> ReedResult<()> {
+        let dir = tempdir().unwrap();
+
+        // Sizes around leaf and level boundaries, including the even-order
+        // case of max_keys + 1 leaves (order 4: 3 keys per leaf, 5 leaves)
+        for order in [3u16, 4, 5, 10] {
+            for count in [0usize, 1, 2, 3, 4, 9, 10, 15, 16, 17, 100, 1000] {
+                let path = dir.path().join(format!("bulk_{}_{}.btree", order, count));
+                let entries: Vec<(String, Vec<u8>)> = (0..count)
+                    .map(|i| (format!("key{:05}", i), vec![i as u8]))
+                    .collect();
+
+                {
+                    let mut tree = BPlusTree::open(&path, Order::new(order)?)?;
+                    tree.bulk_load(entries.clone())?;
+                    assert_integrity(&tree)?;
+                    assert_eq!(tree.iter().collect::<Vec<_>>(), entries);
+                }
+
+                // Root is page 0, so the shape survives a reopen
+                let tree = BPlusTree::<String, Vec<u8>>::open(&path, Order::new(order)?)?;
+                assert_integrity(&tree)?;
+                assert_eq!(tree.key_count()?, count);
+                for (key, value) in &entries {
+                    assert_eq!(tree.get(key)?.as_ref(), Some(value), "order {}", order);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_btree_bulk_load_replaces_contents() -> ReedResult<()> {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("test.btree");
+        let order = Order::new(10)?;
+
+        {
+            let mut tree = BPlusTree::open(&path, order)?;
+            tree.insert("old".to_string(), vec![0u8])?;
+            tree.bulk_load(vec![
+                ("a".to_string(), vec![1u8]),
+                ("b".to_string(), vec![2u8]),
+            ])?;
+            assert_eq!(tree.get(&"old".to_string())?, None);
+
+            // Inserts after a bulk load still work
+            tree.insert("c".to_string(), vec![3u8])?;
+        }
+
+        // The WAL was truncated, so "old" is not replayed on reopen
+        let tree = BPlusTree::<String, Vec<u8>>::open(&path, order)?;
+        assert_eq!(tree.get(&"old".to_string())?, None);
+        assert_eq!(tree.key_count()?, 3);
+        assert_integrity(&tree)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_btree_bulk_load_rejects_unsorted_input() -> ReedResult<()> {
+        let dir = tempdir().unwrap();
+        let mut tree = BPlusTree::open(dir.path().join("test.btree"), Order::new(10)?)?;
+        tree.insert("kept".to_string(), vec![1u8])?;
+
+        for entries in [
+            vec![("b".to_string(), vec![1u8]), ("a".to_string(), vec![2u8])],
+            vec![("a".to_string(), vec![1u8]), ("a".to_string(), vec![2u8])],
+        ] {
+            assert!(matches!(
+                tree.bulk_load(entries),
+                Err(ReedError::IndexOperationUnsupported { .. })
+            ));
+        }
+        assert_eq!(tree.get(&"kept".to_string())?, Some(vec![1u8]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_btree_insert_after_multi_level_bulk_load() -> ReedResult<()> {
+        let dir = tempdir().unwrap();
+
+        // Even keys are bulk-loaded, odd keys inserted between them so that
+        // leaves split below internal nodes that are not the root
+        for (order, count) in [(3u16, 300usize), (4, 300), (5, 300), (100, 20_000)] {
+            let path = dir.path().join(format!("deep_{}.btree", order));
+            let mut expected: std::collections::BTreeMap<String, Vec<u8>> = (0..count)
+                .map(|i| (format!("key{:06}", i * 2), vec![1u8]))
+                .collect();
+
+            {
+                let mut tree = BPlusTree::open(&path, Order::new(order)?)?;
+                tree.bulk_load(expected.clone())?;
+                let depth = tree.verify_structural_integrity()?.depth;
+                assert!(depth >= 3, "order {} depth {}", order, depth);
+
+                for i in 0..count.min(2000) {
+                    let key = format!("key{:06}", ((i * 7919) % count) * 2 + 1);
+                    tree.insert(key.clone(), vec![2u8])?;
+                    expected.insert(key, vec![2u8]);
+                }
+
+                let report = tree.verify_structural_integrity()?;
+                assert!(
+                    report.is_valid(),
+                    "order {}: {:?}",
+                    order,
+                    report.violations
+                );
+                assert_eq!(report.key_count, expected.len());
+                for (key, value) in &expected {
+                    assert_eq!(tree.get(key)?.as_ref(), Some(value), "order {}", order);
+                }
+            }
+
+            // The root stays on page 0, so the grown tree reopens intact
+            let tree = BPlusTree::<String, Vec<u8>>::open(&path, Order::new(order)?)?;
+            assert_integrity(&tree)?;
+            assert_eq!(
+                tree.iter().collect::<Vec<_>>(),
+                expected.into_iter().collect::<Vec<_>>()
+            );
+        }
+
         Ok(())
     }
 
@@ -586,11 +1298,156 @@ mod tests {
             let tree = BPlusTree::<String, Vec<u8>>::open(&path, order)?;
             assert_eq!(tree.get(&"key1".to_string())?, Some(vec![1u8, 2u8, 3u8]));
             assert_eq!(tree.get(&"key2".to_string())?, Some(vec![4u8, 5u8, 6u8]));
+            assert_integrity(&tree)?;
         }
 
         Ok(())
     }
 
+    // ============================================================================
+    // Key Count Tests
+    // ============================================================================
+
+    #[test]
+    fn test_btree_len_tracks_mutations() -> ReedResult<()> {
+        let dir = tempdir().unwrap();
+        let order = Order::new(100)?;
+        let mut tree = BPlusTree::open(dir.path().join("test.btree"), order)?;
+        assert!(tree.is_empty());
+
+        for i in 0..300 {
+            tree.insert(format!("key{:03}", i), vec![i as u8])?;
+        }
+        assert_eq!(tree.len(), 300);
+
+        // Updates and deletes of missing keys leave the count alone
+        tree.insert("key005".to_string(), vec![99])?;
+        tree.delete(&"missing".to_string())?;
+        assert_eq!(tree.len(), 300);
+
+        for i in 0..100 {
+            tree.delete(&format!("key{:03}", i))?;
+        }
+        assert_eq!(tree.len(), 200);
+        assert_eq!(tree.key_count()?, 200);
+
+        tree.bulk_load((0..500).map(|i| (format!("bulk{:03}", i), vec![1])))?;
+        assert_eq!(tree.len(), 500);
+
+        let mut other = BPlusTree::open(dir.path().join("other.btree"), order)?;
+        other.insert("bulk000".to_string(), vec![2])?;
+        other.insert("extra".to_string(), vec![3])?;
+        tree.merge(&other, ConflictMode::Overwrite)?;
+        assert_eq!(tree.len(), 501);
+        assert_eq!(tree.verify_count()?, 501);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_btree_len_survives_reopen() -> ReedResult<()> {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("test.btree");
+        let order = Order::new(100)?;
+
+        {
+            let mut tree = BPlusTree::open(&path, order)?;
+            for i in 0..50 {
+                tree.insert(format!("key{:02}", i), vec![i as u8])?;
+            }
+        }
+
+        // First reopen replays the WAL, the second reads the checkpoint
+        for _ in 0..2 {
+            let mut tree = BPlusTree::<String, Vec<u8>>::open(&path, order)?;
+            assert_eq!(tree.len(), 50);
+            assert_eq!(tree.verify_count()?, 50);
+        }
+
+        {
+            let mut tree = BPlusTree::<String, Vec<u8>>::open(&path, order)?;
+            tree.delete(&"key00".to_string())?;
+        }
+        let tree = BPlusTree::<String, Vec<u8>>::open(&path, order)?;
+        assert_eq!(tree.len(), 49);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_btree_len_recovers_from_wal() -> ReedResult<()> {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("test.btree");
+        let order = Order::new(100)?;
+
+        {
+            let mut tree = BPlusTree::open(&path, order)?;
+            tree.insert("a".to_string(), vec![1u8])?;
+        }
+
+        // Logged but never applied, as after a crash
+        {
+            let mut wal = WriteAheadLog::open(path.with_extension("wal"), DEFAULT_MAX_ENTRIES)?;
+            wal.log_insert("b".to_string(), vec![2u8])?;
+            wal.log_count_delta(1)?;
+            wal.log_delete("a".to_string())?;
+            wal.log_count_delta(-1)?;
+            wal.log_insert("c".to_string(), vec![3u8])?;
+            wal.log_count_delta(1)?;
+            wal.sync()?;
+        }
+
+        let mut tree = BPlusTree::<String, Vec<u8>>::open(&path, order)?;
+        assert_eq!(tree.len(), 2);
+        assert_eq!(tree.verify_count()?, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_btree_verify_count_detects_divergence() -> ReedResult<()> {
+        use std::io::{Seek, SeekFrom, Write};
+
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("test.btree");
+        let order = Order::new(100)?;
+
+        {
+            let mut tree = BPlusTree::open(&path, order)?;
+            for i in 0..20 {
+                tree.insert(format!("key{:02}", i), vec![i as u8])?;
+            }
+        }
+        drop(BPlusTree::<String, Vec<u8>>::open(&path, order)?);
+
+        // Overwrite the count in page 0's header (offset 15)
+        let mut file = std::fs::OpenOptions::new().write(true).open(&path).unwrap();
+        file.seek(SeekFrom::Start(15)).unwrap();
+        file.write_all(&7u64.to_be_bytes()).unwrap();
+        drop(file);
+
+        let mut tree = BPlusTree::<String, Vec<u8>>::open(&path, order)?;
+        assert_eq!(tree.len(), 7);
+        assert!(matches!(
+            tree.verify_count(),
+            Err(ReedError::CorruptedIndex { page_id: 0, .. })
+        ));
+        assert_eq!(tree.len(), 20);
+        assert_eq!(tree.verify_count()?, 20);
+        drop(tree);
+
+        // Files without a stored count are counted on open (flag at offset 23)
+        let mut file = std::fs::OpenOptions::new().write(true).open(&path).unwrap();
+        file.seek(SeekFrom::Start(23)).unwrap();
+        file.write_all(&[0]).unwrap();
+        drop(file);
+
+        let tree = BPlusTree::<String, Vec<u8>>::open(&path, order)?;
+        assert_eq!(tree.len(), 20);
+
+        Ok(())
+    }
+
     // ============================================================================
     // Edge Cases
     // ============================================================================
@@ -607,6 +1464,8 @@ mod tests {
         let results = tree.range(&"a".to_string(), &"z".to_string())?;
         assert_eq!(results.len(), 0);
 
+        assert_integrity(&tree)?;
+
         Ok(())
     }
 
@@ -622,6 +1481,8 @@ mod tests {
         assert_eq!(tree.get(&"only".to_string())?, Some(vec![1u8]));
         assert_eq!(tree.get(&"other".to_string())?, None);
 
+        assert_integrity(&tree)?;
+
         Ok(())
     }
 
@@ -638,6 +1499,8 @@ mod tests {
 
         assert_eq!(tree.get(&"key".to_string())?, Some(large_value));
 
+        assert_integrity(&tree)?;
+
         Ok(())
     }
 
@@ -660,6 +1523,8 @@ mod tests {
             Some(vec![3u8])
         );
 
+        assert_integrity(&tree)?;
+
         Ok(())
     }
 
@@ -686,6 +1551,45 @@ mod tests {
         assert_eq!(tree.get(&"key000500".to_string())?, Some(vec![244])); // 500 % 256
         assert_eq!(tree.get(&"key000999".to_string())?, Some(vec![231])); // 999 % 256
 
+        assert_integrity(&tree)?;
+        let stats = tree.statistics()?;
+        assert_eq!(stats.total_keys, 1000);
+        assert!(stats.avg_leaf_fill_pct >= 50.0, "{:?}", stats);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_btree_statistics() -> ReedResult<()> {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("test.btree");
+        let order = Order::new(4)?;
+
+        let mut tree = BPlusTree::open(&path, order)?;
+        let stats = tree.statistics()?;
+        assert_eq!(stats.height, 1);
+        assert_eq!(stats.leaf_count, 1);
+        assert_eq!(stats.internal_count, 0);
+        assert_eq!(stats.total_keys, 0);
+        assert_eq!(stats.avg_leaf_fill_pct, 0.0);
+
+        for i in 0..10 {
+            tree.insert(format!("key{:03}", i), vec![i as u8])?;
+        }
+
+        let stats = tree.statistics()?;
+        let report = tree.verify_structural_integrity()?;
+        assert_eq!(stats.height, report.depth);
+        assert_eq!(stats.leaf_count, report.leaf_nodes);
+        assert_eq!(stats.internal_count, report.internal_nodes);
+        assert_eq!(stats.total_keys, 10);
+        assert!(stats.min_leaf_keys >= order.min_keys() as usize);
+        assert!(stats.max_leaf_keys <= order.max_keys() as usize);
+        assert!(stats.avg_leaf_fill_pct >= 50.0, "{:?}", stats);
+        let pages = (stats.leaf_count + stats.internal_count) as u64;
+        assert!(stats.wasted_space_bytes > 0);
+        assert!(stats.wasted_space_bytes < pages * PAGE_SIZE as u64);
+
         Ok(())
     }
 
@@ -712,6 +1616,143 @@ mod tests {
             assert!(results[i].0 < results[i + 1].0);
         }
 
+        assert_integrity(&tree)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_btree_iter_from() -> ReedResult<()> {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("test.btree");
+        let order = Order::new(10)?;
+
+        let mut tree = BPlusTree::open(&path, order)?;
+
+        for i in 0..100 {
+            tree.insert(format!("key{:03}", i), vec![i as u8])?;
+        }
+
+        // Exact start key
+        let keys: Vec<String> = tree
+            .iter_from(&"key040".to_string())
+            .map(|(k, _)| k)
+            .collect();
+        assert_eq!(keys.len(), 60);
+        assert_eq!(keys[0], "key040");
+        assert_eq!(keys[59], "key099");
+
+        // Start between keys
+        let first = tree.iter_from(&"key040a".to_string()).next();
+        assert_eq!(first, Some(("key041".to_string(), vec![41])));
+
+        // Bounded with take_while
+        let end = "key050".to_string();
+        let keys: Vec<String> = tree
+            .iter_from(&"key040".to_string())
+            .take_while(|(k, _)| k < &end)
+            .map(|(k, _)| k)
+            .collect();
+        assert_eq!(keys.len(), 10);
+        assert_eq!(
+            keys,
+            tree.range(&"key040".to_string(), &end)?
+                .into_iter()
+                .map(|(k, _)| k)
+                .collect::<Vec<_>>()
+        );
+
+        // Start past the last key
+        assert_eq!(tree.iter_from(&"key999".to_string()).count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_btree_iter_from_skips_earlier_keys() -> ReedResult<()> {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("test.btree");
+        let order = Order::new(100)?;
+
+        let mut tree = BPlusTree::open(&path, order)?;
+
+        // Sized to stay within a single internal level (parent splits are not
+        // yet supported)
+        for letter in b'a'..=b'z' {
+            for i in 0..300 {
+                let key = format!("page.{}{:04}", letter as char, i);
+                tree.insert(key, vec![letter])?;
+            }
+        }
+
+        let start = "page.m".to_string();
+        let mut scan = tree.scan_from(&start)?;
+        let mut yielded = 0;
+        for (key, _) in scan.by_ref() {
+            assert!(key >= start);
+            yielded += 1;
+        }
+
+        // 'm' through 'z' only, and no key before "page.m" was examined
+        assert_eq!(yielded, 14 * 300);
+        assert_eq!(scan.keys_scanned(), yielded);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_btree_merge_10k_performance() -> ReedResult<()> {
+        let dir = tempdir().unwrap();
+        let order = Order::new(300)?;
+
+        let mut tree = BPlusTree::<u64, u32>::open(dir.path().join("a.btree"), order)?;
+        let mut other = BPlusTree::<u64, u32>::open(dir.path().join("b.btree"), order)?;
+        for i in 0..10_000u64 {
+            tree.insert(i * 2, i as u32)?;
+            other.insert(i * 2 + 1, i as u32)?;
+        }
+
+        // Interleaved keys touch every leaf of the target tree
+        let start = std::time::Instant::now();
+        tree.merge(&other, ConflictMode::Overwrite)?;
+        let elapsed = start.elapsed();
+
+        assert_eq!(tree.key_count()?, 20_000);
+        assert_eq!(tree.get(&0)?, Some(0));
+        assert_eq!(tree.get(&19_999)?, Some(9_999));
+        let report = tree.verify_structural_integrity()?;
+        assert!(report.is_valid(), "{:?}", report.violations);
+        assert!(tree.statistics()?.avg_leaf_fill_pct >= 50.0);
+        assert!(
+            elapsed.as_secs() < 5,
+            "merging 10k entries took {:?}",
+            elapsed
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_btree_bulk_load_100k_performance() -> ReedResult<()> {
+        let dir = tempdir().unwrap();
+        let mut tree = BPlusTree::<u64, u32>::open(dir.path().join("a.btree"), Order::new(100)?)?;
+
+        let start = std::time::Instant::now();
+        tree.bulk_load((0..100_000u64).map(|i| (i * 2, i as u32)))?;
+        let elapsed = start.elapsed();
+
+        assert_eq!(tree.key_count()?, 100_000);
+        assert_eq!(tree.get(&199_998)?, Some(99_999));
+        assert_eq!(tree.get(&3)?, None);
+        let report = tree.verify_structural_integrity()?;
+        assert!(report.is_valid(), "{:?}", report.violations);
+        assert!(report.depth >= 3);
+        assert!(
+            elapsed.as_secs() < 5,
+            "bulk loading 100k entries took {:?}",
+            elapsed
+        );
+
         Ok(())
     }
 
@@ -731,6 +1772,8 @@ mod tests {
         assert!(mem_usage > 0);
         assert!(mem_usage < 10 * 1024 * 1024); // < 10MB for empty tree
 
+        assert_integrity(&tree)?;
+
         Ok(())
     }
 
@@ -753,6 +1796,8 @@ mod tests {
         let after_insert_disk = tree.disk_usage();
         assert!(after_insert_disk >= initial_disk);
 
+        assert_integrity(&tree)?;
+
         Ok(())
     }
 
@@ -0,0 +1,271 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Structural integrity verification for B+-Trees.
+//!
+//! Walks every page reachable from the root and cross-checks the tree
+//! traversal against the leaf linked list. Intended for debugging and
+//! tests, not for hot paths.
+//!
+//! ## Performance
+//! - O(n) page reads where n = number of pages
+//! - O(k) memory where k = number of keys (keys are collected twice)
+
+use crate::btree::node::{InternalNode, LeafNode};
+use crate::btree::tree::BPlusTree;
+use crate::btree::types::{IntegrityReport, NodeType, PageId};
+use crate::error::{ReedError, ReedResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Accumulated state of the depth-first tree walk.
+struct Walk<K> {
+    /// Pages already visited (detects shared or cyclic child pointers).
+    visited: HashSet<PageId>,
+
+    /// Keys in traversal order.
+    keys: Vec<K>,
+
+    /// Leaf pages in traversal order.
+    leaves: Vec<PageId>,
+
+    /// Depth of the first leaf encountered.
+    leaf_depth: Option<usize>,
+}
+
+impl<K, V> BPlusTree<K, V>
+where
+    K: Clone + Ord + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+{
+    /// Verify all structural invariants of the tree.
+    ///
+    /// ## Output
+    /// - `Ok(IntegrityReport)`: Walk completed (check `is_valid()` for violations)
+    /// - `Err(ReedError)`: Page could not be read or deserialised
+    ///
+    /// ## Performance
+    /// - O(n) in the number of pages, reads every reachable page once
+    ///
+    /// ## Error Conditions
+    /// - CRC32 mismatch or out-of-bounds page reference
+    /// - Corrupted node data
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::btree::{BPlusTree, Order};
+    ///
+    /// let tree = BPlusTree::<String, Vec<u8>>::open("index.btree", Order::new(100)?)?;
+    /// let report = tree.verify_structural_integrity()?;
+    /// assert!(report.is_valid(), "{:?}", report.violations);
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn verify_structural_integrity(&self) -> ReedResult<IntegrityReport> {
+        let mut report = IntegrityReport::default();
+        let mut walk = Walk {
+            visited: HashSet::new(),
+            keys: Vec::new(),
+            leaves: Vec::new(),
+            leaf_depth: None,
+        };
+
+        self.verify_node(self.root_page_id(), 1, None, None, &mut walk, &mut report)?;
+
+        if let Some(&first_leaf) = walk.leaves.first() {
+            self.verify_leaf_chain(first_leaf, &walk, &mut report)?;
+        }
+
+        report.key_count = walk.keys.len();
+        Ok(report)
+    }
+
+    /// Recursively verify the subtree rooted at `page_id`.
+    ///
+    /// `lower` (inclusive) and `upper` (exclusive) are the routing bounds
+    /// imposed by the parent.
+    fn verify_node(
+        &self,
+        page_id: PageId,
+        depth: usize,
+        lower: Option<&K>,
+        upper: Option<&K>,
+        walk: &mut Walk<K>,
+        report: &mut IntegrityReport,
+    ) -> ReedResult<()> {
+        if !walk.visited.insert(page_id) {
+            report
+                .violations
+                .push(format!("Page {} is referenced more than once", page_id));
+            return Ok(());
+        }
+
+        report.depth = report.depth.max(depth);
+        let is_root = page_id == self.root_page_id();
+        let order = self.order();
+        let page = self.read_page(page_id)?;
+
+        match page.header.page_type {
+            t if t == NodeType::Internal as u8 => {
+                let node: InternalNode<K> = decode(page.get_data())?;
+                report.internal_nodes += 1;
+
+                if node.keys.len() + 1 != node.children.len() {
+                    report.violations.push(format!(
+                        "Internal page {} has {} keys but {} children",
+                        page_id,
+                        node.keys.len(),
+                        node.children.len()
+                    ));
+                }
+                if !is_strictly_sorted(&node.keys) {
+                    report
+                        .violations
+                        .push(format!("Internal page {} keys are not sorted", page_id));
+                }
+                if !within_bounds(&node.keys, lower, upper) {
+                    report.violations.push(format!(
+                        "Internal page {} routing keys fall outside parent range",
+                        page_id
+                    ));
+                }
+                if !is_root && node.is_underflow(order) {
+                    report.violations.push(format!(
+                        "Internal page {} is below half-full ({} < {} keys)",
+                        page_id,
+                        node.keys.len(),
+                        order.min_keys()
+                    ));
+                }
+
+                for (i, &child) in node.children.iter().enumerate() {
+                    let child_lower = if i == 0 { lower } else { node.keys.get(i - 1) };
+                    let child_upper = node.keys.get(i).or(upper);
+                    self.verify_node(child, depth + 1, child_lower, child_upper, walk, report)?;
+                }
+            }
+            t if t == NodeType::Leaf as u8 => {
+                let leaf: LeafNode<K, V> = decode(page.get_data())?;
+                report.leaf_nodes += 1;
+
+                match walk.leaf_depth {
+                    None => walk.leaf_depth = Some(depth),
+                    Some(expected) if expected != depth => {
+                        report.violations.push(format!(
+                            "Leaf page {} at depth {}, expected {}",
+                            page_id, depth, expected
+                        ));
+                    }
+                    Some(_) => {}
+                }
+
+                if leaf.keys.len() != leaf.values.len() {
+                    report.violations.push(format!(
+                        "Leaf page {} has {} keys but {} values",
+                        page_id,
+                        leaf.keys.len(),
+                        leaf.values.len()
+                    ));
+                }
+                if !is_strictly_sorted(&leaf.keys) {
+                    report
+                        .violations
+                        .push(format!("Leaf page {} keys are not sorted", page_id));
+                }
+                if !within_bounds(&leaf.keys, lower, upper) {
+                    report.violations.push(format!(
+                        "Leaf page {} keys fall outside parent routing range",
+                        page_id
+                    ));
+                }
+                if !is_root && leaf.is_underflow(order) {
+                    report.violations.push(format!(
+                        "Leaf page {} is below half-full ({} < {} keys)",
+                        page_id,
+                        leaf.keys.len(),
+                        order.min_keys()
+                    ));
+                }
+
+                walk.leaves.push(page_id);
+                walk.keys.extend(leaf.keys);
+            }
+            other => {
+                report
+                    .violations
+                    .push(format!("Page {} has invalid page type {}", page_id, other));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Follow the leaf `next` pointers and compare against tree traversal.
+    fn verify_leaf_chain(
+        &self,
+        first_leaf: PageId,
+        walk: &Walk<K>,
+        report: &mut IntegrityReport,
+    ) -> ReedResult<()> {
+        let mut seen = HashSet::new();
+        let mut chain_pages = Vec::new();
+        let mut chain_keys: Vec<K> = Vec::new();
+        let mut current = Some(first_leaf);
+
+        while let Some(page_id) = current {
+            if !seen.insert(page_id) {
+                report
+                    .violations
+                    .push(format!("Leaf chain contains a cycle at page {}", page_id));
+                break;
+            }
+
+            let page = self.read_page(page_id)?;
+            if page.header.page_type != NodeType::Leaf as u8 {
+                report
+                    .violations
+                    .push(format!("Leaf chain points to non-leaf page {}", page_id));
+                break;
+            }
+
+            let leaf: LeafNode<K, V> = decode(page.get_data())?;
+            chain_pages.push(page_id);
+            chain_keys.extend(leaf.keys);
+            current = leaf.next;
+        }
+
+        if chain_pages != walk.leaves {
+            report.violations.push(format!(
+                "Leaf chain does not match tree traversal ({} vs {} leaves)",
+                chain_pages.len(),
+                walk.leaves.len()
+            ));
+        }
+        if chain_keys != walk.keys {
+            report.violations.push(format!(
+                "Leaf chain yields {} keys, tree traversal yields {}",
+                chain_keys.len(),
+                walk.keys.len()
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Deserialise node data from a page.
+fn decode<T: for<'de> Deserialize<'de>>(data: &[u8]) -> ReedResult<T> {
+    bincode::deserialize(data).map_err(|e| ReedError::DeserializationError {
+        reason: e.to_string(),
+    })
+}
+
+/// Returns true if every key is strictly greater than its predecessor.
+fn is_strictly_sorted<K: Ord>(keys: &[K]) -> bool {
+    keys.windows(2).all(|w| w[0] < w[1])
+}
+
+/// Returns true if every key lies within `[lower, upper)`.
+fn within_bounds<K: Ord>(keys: &[K], lower: Option<&K>, upper: Option<&K>) -> bool {
+    keys.iter()
+        .all(|k| lower.map(|l| k >= l).unwrap_or(true) && upper.map(|u| k < u).unwrap_or(true))
+}
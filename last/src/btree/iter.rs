@@ -52,7 +52,9 @@ use std::marker::PhantomData;
 /// Iterator for range scans over B+-Tree leaves.
 ///
 /// Walks linked list of leaf nodes, yielding key-value pairs within range.
-/// Stops when end bound reached or last leaf encountered.
+/// Stops when end bound reached or last leaf encountered. Without an end
+/// bound (see `from_start`) the scan runs to the last leaf; without either
+/// bound (see `from_first_leaf`) it yields every entry of the tree.
 ///
 /// ## Type Parameters
 /// - `'a`: Lifetime of mmap reference
@@ -70,8 +72,8 @@ where
     K: Clone + Ord + for<'de> Deserialize<'de>,
     V: Clone + for<'de> Deserialize<'de>,
 {
-    /// Memory-mapped file reference (readonly).
-    mmap: &'a Mmap,
+    /// Page bytes (readonly mmap or writable mmap of the owning tree).
+    data: &'a [u8],
 
     /// Current leaf page being scanned.
     current_page: Option<PageId>,
@@ -82,11 +84,14 @@ where
     /// Current position within leaf keys.
     key_index: usize,
 
-    /// Inclusive start bound (filter keys >= start).
-    start: K,
+    /// Inclusive start bound (filter keys >= start), `None` for unbounded.
+    start: Option<K>,
 
-    /// Exclusive end bound (stop when key >= end).
-    end: K,
+    /// Exclusive end bound (stop when key >= end), `None` for unbounded.
+    end: Option<K>,
+
+    /// Number of keys examined so far (yielded or rejected by the bounds).
+    keys_scanned: usize,
 
     /// Whether iterator has been exhausted.
     done: bool,
@@ -123,18 +128,69 @@ where
     /// }
     /// ```
     pub fn new(mmap: &'a Mmap, start_page: PageId, start: K, end: K) -> Self {
+        Self::with_bounds(mmap.as_ref(), Some(start_page), Some(start), Some(end))
+    }
+
+    /// Create iterator yielding every key `>= start` up to the last leaf.
+    ///
+    /// ## Input
+    /// - `data`: Page bytes (any mmap deref)
+    /// - `start_page`: Page ID of the leaf containing `start`
+    /// - `start`: Inclusive start bound
+    ///
+    /// ## Output
+    /// - Unbounded forward iterator
+    ///
+    /// ## Performance
+    /// - O(1) setup, keys before `start` in the first leaf are skipped by
+    ///   binary search rather than examined one by one
+    pub fn from_start(data: &'a [u8], start_page: PageId, start: K) -> Self {
+        Self::with_bounds(data, Some(start_page), Some(start), None)
+    }
+
+    /// Create iterator yielding every entry from the leftmost leaf onwards.
+    ///
+    /// ## Input
+    /// - `data`: Page bytes (any mmap deref)
+    /// - `first_leaf`: Page ID of the leftmost leaf, `None` for an iterator
+    ///   that yields nothing (e.g. the leaf could not be located)
+    ///
+    /// ## Output
+    /// - Unbounded iterator over the whole leaf chain
+    ///
+    /// ## Performance
+    /// - O(1) setup, one leaf deserialised at a time
+    pub fn from_first_leaf(data: &'a [u8], first_leaf: Option<PageId>) -> Self {
+        Self::with_bounds(data, first_leaf, None, None)
+    }
+
+    fn with_bounds(
+        data: &'a [u8],
+        start_page: Option<PageId>,
+        start: Option<K>,
+        end: Option<K>,
+    ) -> Self {
         Self {
-            mmap,
-            current_page: Some(start_page),
+            data,
+            current_page: start_page,
             current_leaf: None,
             key_index: 0,
             start,
             end,
+            keys_scanned: 0,
             done: false,
             _phantom: PhantomData,
         }
     }
 
+    /// Number of keys examined so far.
+    ///
+    /// Counts every key compared against the bounds, including the one that
+    /// terminated the scan. Keys skipped by binary search are not counted.
+    pub fn keys_scanned(&self) -> usize {
+        self.keys_scanned
+    }
+
     /// Load next leaf page into memory.
     ///
     /// ## Output
@@ -147,7 +203,7 @@ where
     ///
     /// ## Side Effects
     /// - Updates `current_leaf` with deserialised node
-    /// - Resets `key_index` to 0
+    /// - Positions `key_index` at the first key >= start
     fn load_next_leaf(&mut self) -> ReedResult<bool> {
         let page_id = match self.current_page {
             Some(id) => id,
@@ -155,7 +211,7 @@ where
         };
 
         // Read page from mmap
-        let page = Page::read_from(self.mmap, page_id)?;
+        let page = Page::read_from_bytes(self.data, page_id)?;
 
         // Validate page type
         if page.header.page_type != NodeType::Leaf as u8 {
@@ -172,8 +228,11 @@ where
 
         // Update state
         self.current_page = leaf.next;
+        self.key_index = match &self.start {
+            Some(start) => leaf.keys.partition_point(|k| k < start),
+            None => 0,
+        };
         self.current_leaf = Some(leaf);
-        self.key_index = 0;
 
         Ok(true)
     }
@@ -204,7 +263,7 @@ where
     ///     if failed: return None
     ///
     ///   if key_index < leaf.keys.len():
-    ///     key = leaf.keys[key_index]
+    ///     key = leaf.keys[key_index]  (key_index starts at first key >= start)
     ///     if key < start: skip
     ///     if key >= end: return None
     ///     return Some((key, value))
@@ -239,12 +298,13 @@ where
                 let key = &leaf.keys[self.key_index];
                 let value = &leaf.values[self.key_index];
                 self.key_index += 1;
+                self.keys_scanned += 1;
 
                 // Filter by range
-                if key < &self.start {
+                if self.start.as_ref().is_some_and(|start| key < start) {
                     continue; // Skip keys before start
                 }
-                if key >= &self.end {
+                if self.end.as_ref().is_some_and(|end| key >= end) {
                     self.done = true;
                     return None; // Reached end bound
                 }
@@ -299,6 +359,7 @@ where
             .field("key_index", &self.key_index)
             .field("start", &self.start)
             .field("end", &self.end)
+            .field("keys_scanned", &self.keys_scanned)
             .field("done", &self.done)
             .finish()
     }
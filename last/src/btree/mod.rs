@@ -52,9 +52,11 @@
 //!
 //! Pages use CRC32 checksums for integrity validation.
 
+mod integrity;
 mod iter;
 mod node;
 mod page;
+mod statistics;
 mod tree;
 mod types;
 mod wal;
@@ -64,8 +66,9 @@ mod btree_test;
 
 // Re-export public API
 pub use iter::RangeScanIterator;
+pub use page::PAGE_SIZE;
 pub use tree::BPlusTree;
-pub use types::{Order, PageId, BTREE_MAGIC};
+pub use types::{ConflictMode, IntegrityReport, Order, PageId, TreeStatistics, BTREE_MAGIC};
 
 // Re-export Index trait from indices module (canonical definition).
 pub use crate::indices::Index;
@@ -572,6 +572,21 @@ where
     }
 }
 
+/// Key-only view of a serialised leaf node.
+///
+/// Shares the leading field of `LeafNode`, so bincode decodes the key array
+/// from a leaf page and stops before the values. Used for counting without
+/// paying for value deserialisation.
+///
+/// ## Limitations
+/// - `next` follows the values on disk and is therefore not available here;
+///   callers must reach leaves through internal nodes
+#[derive(Debug, Deserialize)]
+pub(crate) struct LeafKeys<K> {
+    /// Sorted keys of the leaf.
+    pub keys: Vec<K>,
+}
+
 // Default implementations for convenience
 impl<K> Default for InternalNode<K>
 where
@@ -50,7 +50,7 @@ pub const PAGE_SIZE: usize = 4096;
 const HEADER_SIZE: usize = 32;
 
 /// Data section size in bytes.
-const DATA_SIZE: usize = PAGE_SIZE - HEADER_SIZE; // 4064 bytes
+pub(crate) const DATA_SIZE: usize = PAGE_SIZE - HEADER_SIZE; // 4064 bytes
 
 /// Page header structure (32 bytes).
 ///
@@ -66,7 +66,9 @@ const DATA_SIZE: usize = PAGE_SIZE - HEADER_SIZE; // 4064 bytes
 /// 5      | 2    | num_keys (u16 big-endian)
 /// 7      | 4    | next_page (PageId, 0 = none)
 /// 11     | 4    | checksum (CRC32 of data)
-/// 15     | 17   | _padding (reserved)
+/// 15     | 8    | key_count (u64 big-endian, page 0 only)
+/// 23     | 1    | flags (bit 0 = key_count present)
+/// 24     | 8    | _padding (reserved)
 /// ```
 #[repr(C)]
 #[derive(Debug, Clone)]
@@ -91,17 +93,23 @@ pub struct PageHeader {
     /// Calculated using `crc32fast::hash()` over entire data section.
     pub checksum: u32,
 
+    /// Number of keys in the whole tree.
+    ///
+    /// Only stored in page 0, as of the last WAL checkpoint. `None` for
+    /// every other page and for files written before the count existed.
+    pub key_count: Option<u64>,
+
     /// Reserved padding for future use.
     ///
     /// Must be zeroed. May be used for:
     /// - Timestamps (8 bytes)
-    /// - Version numbers (2 bytes)
-    /// - Flags (1 byte)
-    /// - Additional pointers (4 bytes)
     #[allow(dead_code)]
-    _padding: [u8; 17],
+    _padding: [u8; 8],
 }
 
+/// Header flag: `key_count` holds a valid count.
+const FLAG_KEY_COUNT: u8 = 0x01;
+
 impl PageHeader {
     /// Serialise header to 32-byte array.
     ///
@@ -128,8 +136,14 @@ impl PageHeader {
         // Offset 11: checksum (4 bytes, big-endian)
         bytes[11..15].copy_from_slice(&self.checksum.to_be_bytes());
 
-        // Offset 15: padding (17 bytes, already zeroed)
-        bytes[15..32].copy_from_slice(&self._padding);
+        // Offset 15: key_count (8 bytes, big-endian) and flags (1 byte)
+        if let Some(count) = self.key_count {
+            bytes[15..23].copy_from_slice(&count.to_be_bytes());
+            bytes[23] = FLAG_KEY_COUNT;
+        }
+
+        // Offset 24: padding (8 bytes, already zeroed)
+        bytes[24..32].copy_from_slice(&self._padding);
 
         bytes
     }
@@ -179,9 +193,16 @@ impl PageHeader {
         // Parse checksum
         let checksum = u32::from_be_bytes([bytes[11], bytes[12], bytes[13], bytes[14]]);
 
+        // Parse key_count (only meaningful with its flag set)
+        let key_count = (bytes[23] & FLAG_KEY_COUNT != 0).then(|| {
+            let mut count = [0u8; 8];
+            count.copy_from_slice(&bytes[15..23]);
+            u64::from_be_bytes(count)
+        });
+
         // Parse padding
-        let mut padding = [0u8; 17];
-        padding.copy_from_slice(&bytes[15..32]);
+        let mut padding = [0u8; 8];
+        padding.copy_from_slice(&bytes[24..32]);
 
         Ok(Self {
             magic,
@@ -189,6 +210,7 @@ impl PageHeader {
             num_keys,
             next_page,
             checksum,
+            key_count,
             _padding: padding,
         })
     }
@@ -261,7 +283,8 @@ impl Page {
                 num_keys: 0,
                 next_page: 0,
                 checksum,
-                _padding: [0u8; 17],
+                key_count: None,
+                _padding: [0u8; 8],
             },
             data,
         }
@@ -296,7 +319,8 @@ impl Page {
                 num_keys: 0,
                 next_page: 0,
                 checksum,
-                _padding: [0u8; 17],
+                key_count: None,
+                _padding: [0u8; 8],
             },
             data,
         }
@@ -571,7 +595,8 @@ mod tests {
             num_keys: 42,
             next_page: 123,
             checksum: 0xDEADBEEF,
-            _padding: [0u8; 17],
+            key_count: Some(1_000_000),
+            _padding: [0u8; 8],
         };
 
         let bytes = header.to_bytes();
@@ -583,6 +608,15 @@ mod tests {
         assert_eq!(decoded.num_keys, 42);
         assert_eq!(decoded.next_page, 123);
         assert_eq!(decoded.checksum, 0xDEADBEEF);
+        assert_eq!(decoded.key_count, Some(1_000_000));
+
+        // Headers without the flag carry no count
+        let plain = PageHeader {
+            key_count: None,
+            ..header
+        };
+        let decoded = PageHeader::from_bytes(&plain.to_bytes()).unwrap();
+        assert_eq!(decoded.key_count, None);
     }
 
     #[test]
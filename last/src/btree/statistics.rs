@@ -0,0 +1,138 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Shape and fill factor statistics for B+-Trees.
+//!
+//! Height comes from descending the leftmost path (all leaves share one
+//! depth), node counts from a depth-first walk of the internal nodes, and
+//! leaf figures from a sequential walk of the leaf chain.
+//!
+//! ## Performance
+//! - O(n) page reads where n = number of pages
+//! - O(1) memory apart from the visited leaf set
+
+use crate::btree::node::{InternalNode, LeafNode};
+use crate::btree::page::DATA_SIZE;
+use crate::btree::tree::BPlusTree;
+use crate::btree::types::{NodeType, PageId, TreeStatistics};
+use crate::error::{ReedError, ReedResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+impl<K, V> BPlusTree<K, V>
+where
+    K: Clone + Ord + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+{
+    /// Collect height, node counts and leaf fill factor of the tree.
+    ///
+    /// ## Output
+    /// - `Ok(TreeStatistics)`: Statistics over every reachable page
+    /// - `Err(ReedError)`: Page could not be read or deserialised
+    ///
+    /// ## Performance
+    /// - O(n) in the number of pages, reads every reachable page once
+    ///
+    /// ## Error Conditions
+    /// - CRC32 mismatch or out-of-bounds page reference
+    /// - Corrupted node data or invalid page type
+    /// - `ParseError`: Leaf chain contains a cycle
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::btree::{BPlusTree, Order};
+    ///
+    /// let tree = BPlusTree::<String, Vec<u8>>::open("index.btree", Order::new(100)?)?;
+    /// let stats = tree.statistics()?;
+    /// println!("height {}, leaves {:.1}% full", stats.height, stats.avg_leaf_fill_pct);
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn statistics(&self) -> ReedResult<TreeStatistics> {
+        let mut stats = TreeStatistics::default();
+
+        // Leftmost descent gives the height and the head of the leaf chain
+        let mut page_id = self.root_page_id();
+        let first_leaf = loop {
+            stats.height += 1;
+            let page = self.read_page(page_id)?;
+            match page.header.page_type {
+                t if t == NodeType::Internal as u8 => {
+                    let node: InternalNode<K> = decode(page.get_data())?;
+                    page_id = node.children[0];
+                }
+                t if t == NodeType::Leaf as u8 => break page_id,
+                other => {
+                    return Err(ReedError::ParseError {
+                        reason: format!("Invalid page type: {}", other),
+                    })
+                }
+            }
+        };
+
+        self.count_internal(self.root_page_id(), 1, &mut stats)?;
+
+        let mut visited = HashSet::new();
+        let mut next = Some(first_leaf);
+        stats.min_leaf_keys = usize::MAX;
+        while let Some(page_id) = next {
+            if !visited.insert(page_id) {
+                return Err(ReedError::ParseError {
+                    reason: format!("Leaf chain revisits page {}", page_id),
+                });
+            }
+
+            let leaf: LeafNode<K, V> = decode(self.read_page(page_id)?.get_data())?;
+            stats.leaf_count += 1;
+            stats.total_keys += leaf.keys.len();
+            stats.min_leaf_keys = stats.min_leaf_keys.min(leaf.keys.len());
+            stats.max_leaf_keys = stats.max_leaf_keys.max(leaf.keys.len());
+            stats.wasted_space_bytes += unused_bytes(&leaf)?;
+            next = leaf.next;
+        }
+
+        let capacity = stats.leaf_count * self.order().max_keys() as usize;
+        if capacity > 0 {
+            stats.avg_leaf_fill_pct = stats.total_keys as f64 * 100.0 / capacity as f64;
+        }
+
+        Ok(stats)
+    }
+
+    /// Count the internal nodes of the subtree rooted at `page_id`.
+    ///
+    /// Pages at `stats.height` are leaves and are left to the chain walk.
+    fn count_internal(
+        &self,
+        page_id: PageId,
+        depth: usize,
+        stats: &mut TreeStatistics,
+    ) -> ReedResult<()> {
+        if depth >= stats.height {
+            return Ok(());
+        }
+
+        let node: InternalNode<K> = decode(self.read_page(page_id)?.get_data())?;
+        stats.internal_count += 1;
+        stats.wasted_space_bytes += unused_bytes(&node)?;
+        for &child in &node.children {
+            self.count_internal(child, depth + 1, stats)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Bytes of the data section not occupied by the serialised node.
+fn unused_bytes<T: Serialize>(node: &T) -> ReedResult<u64> {
+    let used = bincode::serialized_size(node).map_err(|e| ReedError::SerializationError {
+        reason: e.to_string(),
+    })?;
+    Ok((DATA_SIZE as u64).saturating_sub(used))
+}
+
+/// Deserialise a node from a page's data section.
+fn decode<T: for<'de> Deserialize<'de>>(data: &[u8]) -> ReedResult<T> {
+    bincode::deserialize(data).map_err(|e| ReedError::DeserializationError {
+        reason: e.to_string(),
+    })
+}
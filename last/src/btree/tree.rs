@@ -37,6 +37,7 @@
 //! - **Range scan**: O(log n + k) find start + sequential leaf walk
 //! - **Insert**: O(log n) with possible splits
 //! - **Delete**: O(log n) with possible merges
+//! - **Bulk load**: O(n) bottom-up build from sorted input, no WAL
 //!
 //! ## Example Usage
 //!
@@ -64,10 +65,11 @@
 //! # Ok::<(), reedbase::ReedError>(())
 //! ```
 
-use crate::btree::node::{InternalNode, LeafNode};
-use crate::btree::page::{Page, PAGE_SIZE};
-use crate::btree::types::{Index, NodeType, Order, PageId};
-use crate::btree::wal::{WalEntry, WriteAheadLog};
+use crate::btree::iter::RangeScanIterator;
+use crate::btree::node::{InternalNode, LeafKeys, LeafNode};
+use crate::btree::page::{Page, DATA_SIZE, PAGE_SIZE};
+use crate::btree::types::{ConflictMode, Index, NodeType, Order, PageId, TreeStatistics};
+use crate::btree::wal::{WalEntry, WriteAheadLog, DEFAULT_MAX_ENTRIES};
 use crate::error::{ReedError, ReedResult};
 use memmap2::{Mmap, MmapMut};
 use serde::{Deserialize, Serialize};
@@ -78,6 +80,10 @@ use std::path::{Path, PathBuf};
 /// Initial file size for new B+-Tree (1MB = 256 pages).
 const INITIAL_FILE_SIZE: usize = 1024 * 1024;
 
+/// Internal nodes passed on the way down to a leaf, root first, each with
+/// the index of the child that was taken.
+type SearchPath<K> = Vec<(PageId, InternalNode<K>, usize)>;
+
 /// B+-Tree persistent index implementation.
 ///
 /// Generic disk-based index using B+-Tree with mmap I/O and WAL recovery.
@@ -124,6 +130,12 @@ where
     /// Next available page ID.
     next_page: PageId,
 
+    /// Number of keys in the tree.
+    key_count: u64,
+
+    /// Key count stored in page 0's header, as of the last WAL truncate.
+    checkpoint_count: u64,
+
     /// Phantom data for type parameters.
     _phantom: PhantomData<(K, V)>,
 }
@@ -139,6 +151,7 @@ where
             .field("root_page", &self.root_page)
             .field("order", &self.order)
             .field("next_page", &self.next_page)
+            .field("key_count", &self.key_count)
             .finish()
     }
 }
@@ -218,7 +231,7 @@ where
 
         // Open WAL
         let wal_path = path.with_extension("wal");
-        let wal = WriteAheadLog::open(wal_path)?;
+        let wal = WriteAheadLog::open(wal_path, DEFAULT_MAX_ENTRIES)?;
 
         // Create tree instance
         let mut tree = Self {
@@ -229,18 +242,21 @@ where
             order,
             wal,
             next_page: 1,
+            key_count: 0,
+            checkpoint_count: 0,
             _phantom: PhantomData,
         };
 
         // Initialise or load tree
-        if is_new {
+        let stored_count = if is_new {
             tree.initialise()?;
+            Some(0)
         } else {
-            tree.load()?;
-        }
+            tree.load()?
+        };
 
         // Replay WAL if present
-        tree.replay_wal()?;
+        tree.replay_wal(stored_count)?;
 
         Ok(tree)
     }
@@ -248,7 +264,8 @@ where
     /// Initialise new B+-Tree (create root page).
     fn initialise(&mut self) -> ReedResult<()> {
         // Create empty root leaf
-        let root = Page::new_leaf(0);
+        let mut root = Page::new_leaf(0);
+        root.header.key_count = Some(0);
         root.write_to(&mut self.mmap, 0)?;
 
         self.root_page = 0;
@@ -258,7 +275,10 @@ where
     }
 
     /// Load existing B+-Tree (validate and read root).
-    fn load(&mut self) -> ReedResult<()> {
+    ///
+    /// Returns the key count stored in page 0, `None` for files written
+    /// before the count was kept.
+    fn load(&mut self) -> ReedResult<Option<u64>> {
         // Read root page
         let _mmap_ref = unsafe { std::slice::from_raw_parts(self.mmap.as_ptr(), self.mmap.len()) };
         let mmap_readonly = unsafe { Mmap::map(&self.file) }.map_err(|e| ReedError::IoError {
@@ -284,13 +304,18 @@ where
             self.next_page = num_pages as PageId;
         }
 
-        Ok(())
+        Ok(root.header.key_count)
     }
 
     /// Replay Write-Ahead Log (crash recovery).
-    fn replay_wal(&mut self) -> ReedResult<()> {
+    ///
+    /// The key count is the checkpointed `stored_count` plus every
+    /// `CountDelta` logged since; without a stored count the leaves are
+    /// counted once and the result is checkpointed.
+    fn replay_wal(&mut self, stored_count: Option<u64>) -> ReedResult<()> {
         let entries: Vec<WalEntry<K, V>> = self.wal.replay()?;
         let entry_count = entries.len();
+        let mut delta: i64 = 0;
 
         for entry in entries {
             match entry {
@@ -300,17 +325,53 @@ where
                 WalEntry::Delete { key } => {
                     self.delete_internal(&key)?;
                 }
+                WalEntry::CountDelta { delta: d } => delta += d,
+                WalEntry::BatchInsert { entries } => {
+                    for (key, value) in entries {
+                        self.insert_internal(key, value)?;
+                    }
+                }
+                WalEntry::BatchDelete { keys } => {
+                    for key in &keys {
+                        self.delete_internal(key)?;
+                    }
+                }
             }
         }
 
+        self.key_count = match stored_count {
+            Some(count) => count.saturating_add_signed(delta),
+            None => BPlusTree::key_count(self)? as u64,
+        };
+        self.checkpoint_count = stored_count.unwrap_or(0);
+
         // Clear WAL after successful replay
-        if entry_count > 0 {
-            self.wal.truncate()?;
+        if entry_count > 0 || stored_count.is_none() {
+            self.checkpoint()?;
         }
 
         Ok(())
     }
 
+    /// Store the current key count in page 0 and truncate the WAL.
+    fn checkpoint(&mut self) -> ReedResult<()> {
+        self.checkpoint_count = self.key_count;
+
+        let mut page = self.read_page(0)?;
+        page.header.key_count = Some(self.checkpoint_count);
+        page.write_to(&mut self.mmap, 0)?;
+
+        self.wal.truncate()
+    }
+
+    /// Stamp the checkpointed key count into a page bound for page 0.
+    fn stamp_count(&self, page_id: PageId, mut page: Page) -> Page {
+        if page_id == 0 {
+            page.header.key_count = Some(self.checkpoint_count);
+        }
+        page
+    }
+
     /// Search for leaf page containing key.
     fn search_leaf(&self, key: &K) -> ReedResult<PageId> {
         let mut current_page = self.root_page;
@@ -349,12 +410,16 @@ where
     fn allocate_page(&mut self) -> ReedResult<PageId> {
         let page_id = self.next_page;
         self.next_page += 1;
+        self.reserve_pages(self.next_page)?;
+
+        Ok(page_id)
+    }
 
-        // Expand file if needed
-        let required_size = (self.next_page as usize) * PAGE_SIZE;
+    /// Grow the file (in 1MB steps) until it holds `pages` pages.
+    fn reserve_pages(&mut self, pages: PageId) -> ReedResult<()> {
+        let required_size = (pages as usize) * PAGE_SIZE;
         if required_size > self.mmap.len() {
-            // Grow file by 1MB
-            let new_size = self.mmap.len() + 1024 * 1024;
+            let new_size = required_size.div_ceil(INITIAL_FILE_SIZE) * INITIAL_FILE_SIZE;
             self.file
                 .set_len(new_size as u64)
                 .map_err(|e| ReedError::IoError {
@@ -371,13 +436,13 @@ where
             };
         }
 
-        Ok(page_id)
+        Ok(())
     }
 
     /// Internal insert without WAL logging (used during replay).
     fn insert_internal(&mut self, key: K, value: V) -> ReedResult<()> {
-        // Find leaf page
-        let leaf_page_id = self.search_leaf(&key)?;
+        // Find leaf page, remembering the internal nodes above it
+        let (path, leaf_page_id) = self.descend(&key)?;
 
         // Read leaf page
         let mmap_readonly = unsafe { Mmap::map(&self.file) }.map_err(|e| ReedError::IoError {
@@ -398,7 +463,7 @@ where
 
         // Check for overflow
         if leaf.is_overflow(self.order) {
-            self.split_leaf(leaf_page_id, leaf)?;
+            self.split_leaf(leaf_page_id, leaf, path)?;
         } else {
             // Write updated leaf back
             self.write_leaf(leaf_page_id, &leaf)?;
@@ -407,10 +472,58 @@ where
         Ok(())
     }
 
+    /// Descend from the root to the leaf responsible for `key`.
+    ///
+    /// ## Output
+    /// - The search path and the leaf page id
+    fn descend(&self, key: &K) -> ReedResult<(SearchPath<K>, PageId)> {
+        let mut path = Vec::new();
+        let mut page_id = self.root_page;
+        loop {
+            let page = self.read_page(page_id)?;
+            match page.header.page_type {
+                t if t == NodeType::Leaf as u8 => return Ok((path, page_id)),
+                t if t == NodeType::Internal as u8 => {
+                    let node: InternalNode<K> = deserialise_node(page.get_data())?;
+                    let child_idx = node.find_child(key);
+                    let child = node.children[child_idx];
+                    path.push((page_id, node, child_idx));
+                    page_id = child;
+                }
+                other => {
+                    return Err(ReedError::ParseError {
+                        reason: format!("Invalid page type: {}", other),
+                    });
+                }
+            }
+        }
+    }
+
     /// Split leaf node and propagate up.
-    fn split_leaf(&mut self, page_id: PageId, mut leaf: LeafNode<K, V>) -> ReedResult<()> {
+    ///
+    /// `path` is the descent from the root to the leaf (see `descend()`).
+    /// The separator goes into the leaf's actual parent, which is split in
+    /// turn when it overflows, up to the root. The root keeps its page id:
+    /// when it splits, both halves move to new pages and the root page is
+    /// rewritten one level higher.
+    fn split_leaf(
+        &mut self,
+        page_id: PageId,
+        mut leaf: LeafNode<K, V>,
+        path: SearchPath<K>,
+    ) -> ReedResult<()> {
         let (split_key, new_leaf) = leaf.split()?;
 
+        if path.is_empty() {
+            // Root leaf: move both halves out of the root page
+            let left_id = self.allocate_page()?;
+            let right_id = self.allocate_page()?;
+            leaf.next = Some(right_id);
+            self.write_leaf(left_id, &leaf)?;
+            self.write_leaf(right_id, &new_leaf)?;
+            return self.write_new_root(page_id, split_key, left_id, right_id);
+        }
+
         // Allocate page for new leaf
         let new_page_id = self.allocate_page()?;
 
@@ -421,51 +534,60 @@ where
         self.write_leaf(page_id, &leaf)?;
         self.write_leaf(new_page_id, &new_leaf)?;
 
-        // If this is root, create new root
-        if page_id == self.root_page {
-            let mut new_root = InternalNode::new();
-            new_root.children.push(page_id);
-            new_root.insert_key(split_key, new_page_id)?;
+        self.insert_into_parent(path, split_key, new_page_id)
+    }
 
-            let new_root_id = self.allocate_page()?;
-            self.write_internal(new_root_id, &new_root)?;
-            self.root_page = new_root_id;
-        } else {
-            // Insert split key into parent (simplified: assumes root is internal and has space)
-            // Read current root (must be internal if we're here)
-            let root_page = Page::read_from_bytes(&self.mmap, self.root_page)?;
-            let mut root: InternalNode<K> =
-                bincode::deserialize(root_page.get_data()).map_err(|e| {
-                    ReedError::DeserializationError {
-                        reason: e.to_string(),
-                    }
-                })?;
+    /// Insert a separator and its right child into the last node of `path`,
+    /// splitting internal nodes that exceed `order.max_keys()` on the way up.
+    fn insert_into_parent(
+        &mut self,
+        mut path: SearchPath<K>,
+        mut key: K,
+        mut child: PageId,
+    ) -> ReedResult<()> {
+        let max_keys = self.order.max_keys() as usize;
+        while let Some((page_id, mut node, _)) = path.pop() {
+            node.insert_key(key, child)?;
+            if node.keys.len() <= max_keys {
+                return self.write_internal(page_id, &node);
+            }
 
-            // Insert the split key and new child into root
-            root.insert_key(split_key, new_page_id)?;
+            let (middle_key, right) = node.split()?;
+            if path.is_empty() {
+                // Root: move both halves out of the root page
+                let left_id = self.allocate_page()?;
+                let right_id = self.allocate_page()?;
+                self.write_internal(left_id, &node)?;
+                self.write_internal(right_id, &right)?;
+                return self.write_new_root(page_id, middle_key, left_id, right_id);
+            }
 
-            // Write updated root back
-            self.write_internal(self.root_page, &root)?;
+            let right_id = self.allocate_page()?;
+            self.write_internal(page_id, &node)?;
+            self.write_internal(right_id, &right)?;
+            (key, child) = (middle_key, right_id);
         }
 
         Ok(())
     }
 
+    /// Write a root with a single separator over two children.
+    fn write_new_root(
+        &mut self,
+        root_id: PageId,
+        key: K,
+        left_id: PageId,
+        right_id: PageId,
+    ) -> ReedResult<()> {
+        let mut root = InternalNode::new();
+        root.keys.push(key);
+        root.children = vec![left_id, right_id];
+        self.write_internal(root_id, &root)
+    }
+
     /// Write leaf node to page.
     fn write_leaf(&mut self, page_id: PageId, leaf: &LeafNode<K, V>) -> ReedResult<()> {
-        let data = bincode::serialize(leaf).map_err(|e| ReedError::SerializationError {
-            reason: e.to_string(),
-        })?;
-
-        let mut page = Page::new_leaf(page_id);
-        page.header.num_keys = leaf.keys.len() as u16;
-        page.header.next_page = leaf.next.unwrap_or(0);
-
-        // Pad data to 4064 bytes
-        let mut padded_data = data;
-        padded_data.resize(4064, 0);
-        page.set_data(padded_data);
-
+        let page = self.stamp_count(page_id, leaf_page(page_id, leaf)?);
         page.write_to(&mut self.mmap, page_id)?;
 
         // Flush to ensure writes are visible to subsequent reads
@@ -479,18 +601,7 @@ where
 
     /// Write internal node to page.
     fn write_internal(&mut self, page_id: PageId, internal: &InternalNode<K>) -> ReedResult<()> {
-        let data = bincode::serialize(internal).map_err(|e| ReedError::SerializationError {
-            reason: e.to_string(),
-        })?;
-
-        let mut page = Page::new_internal(page_id);
-        page.header.num_keys = internal.keys.len() as u16;
-
-        // Pad data to 4064 bytes
-        let mut padded_data = data;
-        padded_data.resize(4064, 0);
-        page.set_data(padded_data);
-
+        let page = self.stamp_count(page_id, internal_page(page_id, internal)?);
         page.write_to(&mut self.mmap, page_id)?;
 
         // Flush to ensure writes are visible to subsequent reads
@@ -503,37 +614,794 @@ where
     }
 
     /// Internal delete without WAL logging (used during replay).
+    ///
+    /// A node left below `order.min_keys()` borrows an entry from its left
+    /// sibling, else from its right sibling, through the parent. If both
+    /// siblings are at the minimum it merges with one of them and the
+    /// separator is removed from the parent, which is then rebalanced the
+    /// same way, up to the root. A root left with a single child is replaced
+    /// by that child, copied into the root page so the root page id stays
+    /// stable. Pages of merged-away nodes are not reused.
+    ///
+    /// With an even order, merging two internal nodes yields exactly
+    /// `max_keys` keys; there is no valid split of that many.
     fn delete_internal(&mut self, key: &K) -> ReedResult<()> {
-        // Find leaf page
-        let leaf_page_id = self.search_leaf(key)?;
+        // Descend, remembering every internal node and the child taken
+        let (mut path, page_id) = self.descend(key)?;
 
-        // Read leaf page
-        let mmap_readonly = unsafe { Mmap::map(&self.file) }.map_err(|e| ReedError::IoError {
-            operation: "delete_internal".to_string(),
-            reason: e.to_string(),
-        })?;
-        let leaf_page = Page::read_from(&mmap_readonly, leaf_page_id)?;
+        let mut leaf: LeafNode<K, V> = deserialise_node(self.read_page(page_id)?.get_data())?;
+        let Ok(idx) = leaf.keys.binary_search(key) else {
+            return Ok(());
+        };
+        leaf.keys.remove(idx);
+        leaf.values.remove(idx);
 
-        // Deserialise leaf node
-        let mut leaf: LeafNode<K, V> = bincode::deserialize(leaf_page.get_data()).map_err(|e| {
-            ReedError::DeserializationError {
-                reason: e.to_string(),
+        // The root leaf has no minimum
+        let Some((parent_id, mut parent, child_idx)) = path.pop() else {
+            return self.write_leaf(page_id, &leaf);
+        };
+        if !leaf.is_underflow(self.order) {
+            return self.write_leaf(page_id, &leaf);
+        }
+        if !self.rebalance_leaf(page_id, leaf, &mut parent, child_idx)? {
+            return self.write_internal(parent_id, &parent);
+        }
+
+        // A merge removed a separator: walk up while nodes underflow
+        let (mut page_id, mut node) = (parent_id, parent);
+        while let Some((parent_id, mut parent, child_idx)) = path.pop() {
+            if !node.is_underflow(self.order) {
+                return self.write_internal(page_id, &node);
             }
-        })?;
+            if !self.rebalance_internal(page_id, node, &mut parent, child_idx)? {
+                return self.write_internal(parent_id, &parent);
+            }
+            (page_id, node) = (parent_id, parent);
+        }
 
-        // Find and remove key
-        if let Ok(idx) = leaf.keys.binary_search(key) {
-            leaf.keys.remove(idx);
-            leaf.values.remove(idx);
+        // Root: collapse onto its only child, or keep any number of keys
+        if node.keys.is_empty() {
+            let child = self.read_page(node.children[0])?;
+            if child.header.page_type == NodeType::Leaf as u8 {
+                let leaf: LeafNode<K, V> = deserialise_node(child.get_data())?;
+                self.write_leaf(page_id, &leaf)
+            } else {
+                let internal: InternalNode<K> = deserialise_node(child.get_data())?;
+                self.write_internal(page_id, &internal)
+            }
+        } else {
+            self.write_internal(page_id, &node)
+        }
+    }
 
-            // Write updated leaf back
-            self.write_leaf(leaf_page_id, &leaf)?;
+    /// Refill an underflowing leaf from a sibling, or merge it with one.
+    ///
+    /// Writes the leaves; the caller writes `parent`.
+    ///
+    /// ## Output
+    /// - `Ok(false)`: Borrowed an entry, parent has as many keys as before
+    /// - `Ok(true)`: Merged, parent lost one key and may underflow
+    fn rebalance_leaf(
+        &mut self,
+        page_id: PageId,
+        mut leaf: LeafNode<K, V>,
+        parent: &mut InternalNode<K>,
+        idx: usize,
+    ) -> ReedResult<bool> {
+        let min_keys = self.order.min_keys() as usize;
+        let left_id = idx.checked_sub(1).map(|i| parent.children[i]);
+        let right_id = parent.children.get(idx + 1).copied();
+
+        let mut left: Option<LeafNode<K, V>> = None;
+        if let Some(left_id) = left_id {
+            let mut node: LeafNode<K, V> = deserialise_node(self.read_page(left_id)?.get_data())?;
+            if node.keys.len() > min_keys {
+                if let (Some(key), Some(value)) = (node.keys.pop(), node.values.pop()) {
+                    parent.keys[idx - 1] = key.clone();
+                    leaf.keys.insert(0, key);
+                    leaf.values.insert(0, value);
+                }
+                self.write_leaf(left_id, &node)?;
+                self.write_leaf(page_id, &leaf)?;
+                return Ok(false);
+            }
+            left = Some(node);
+        }
+
+        if let Some(right_id) = right_id {
+            let mut right: LeafNode<K, V> = deserialise_node(self.read_page(right_id)?.get_data())?;
+            if right.keys.len() > min_keys {
+                leaf.keys.push(right.keys.remove(0));
+                leaf.values.push(right.values.remove(0));
+                parent.keys[idx] = right.keys[0].clone();
+                self.write_leaf(right_id, &right)?;
+                self.write_leaf(page_id, &leaf)?;
+                return Ok(false);
+            }
 
-            // TODO: Handle underflow and merging (simplified implementation)
+            if left.is_none() {
+                // Leftmost child: absorb the right sibling
+                leaf.keys.extend(right.keys);
+                leaf.values.extend(right.values);
+                leaf.next = right.next;
+                parent.keys.remove(idx);
+                parent.children.remove(idx + 1);
+                self.write_leaf(page_id, &leaf)?;
+                return Ok(true);
+            }
         }
 
+        match (left_id, left) {
+            (Some(left_id), Some(mut left)) => {
+                left.keys.extend(leaf.keys);
+                left.values.extend(leaf.values);
+                left.next = leaf.next;
+                parent.keys.remove(idx - 1);
+                parent.children.remove(idx);
+                self.write_leaf(left_id, &left)?;
+                Ok(true)
+            }
+            _ => Err(ReedError::CorruptedIndex {
+                page_id,
+                reason: "non-root node without siblings".to_string(),
+            }),
+        }
+    }
+
+    /// Refill an underflowing internal node from a sibling, or merge it
+    /// with one. Separator keys rotate through the parent.
+    ///
+    /// Writes the node and its sibling; the caller writes `parent`.
+    ///
+    /// ## Output
+    /// - `Ok(false)`: Borrowed a child, parent has as many keys as before
+    /// - `Ok(true)`: Merged, parent lost one key and may underflow
+    fn rebalance_internal(
+        &mut self,
+        page_id: PageId,
+        mut node: InternalNode<K>,
+        parent: &mut InternalNode<K>,
+        idx: usize,
+    ) -> ReedResult<bool> {
+        let min_keys = self.order.min_keys() as usize;
+        let left_id = idx.checked_sub(1).map(|i| parent.children[i]);
+        let right_id = parent.children.get(idx + 1).copied();
+
+        let mut left: Option<InternalNode<K>> = None;
+        if let Some(left_id) = left_id {
+            let mut sibling: InternalNode<K> =
+                deserialise_node(self.read_page(left_id)?.get_data())?;
+            if sibling.keys.len() > min_keys {
+                if let (Some(key), Some(child)) = (sibling.keys.pop(), sibling.children.pop()) {
+                    let separator = std::mem::replace(&mut parent.keys[idx - 1], key);
+                    node.keys.insert(0, separator);
+                    node.children.insert(0, child);
+                }
+                self.write_internal(left_id, &sibling)?;
+                self.write_internal(page_id, &node)?;
+                return Ok(false);
+            }
+            left = Some(sibling);
+        }
+
+        if let Some(right_id) = right_id {
+            let mut right: InternalNode<K> =
+                deserialise_node(self.read_page(right_id)?.get_data())?;
+            if right.keys.len() > min_keys {
+                let key = right.keys.remove(0);
+                let separator = std::mem::replace(&mut parent.keys[idx], key);
+                node.keys.push(separator);
+                node.children.push(right.children.remove(0));
+                self.write_internal(right_id, &right)?;
+                self.write_internal(page_id, &node)?;
+                return Ok(false);
+            }
+
+            if left.is_none() {
+                // Leftmost child: absorb the right sibling
+                node.keys.push(parent.keys.remove(idx));
+                node.keys.extend(right.keys);
+                node.children.extend(right.children);
+                parent.children.remove(idx + 1);
+                self.write_internal(page_id, &node)?;
+                return Ok(true);
+            }
+        }
+
+        match (left_id, left) {
+            (Some(left_id), Some(mut left)) => {
+                left.keys.push(parent.keys.remove(idx - 1));
+                left.keys.extend(node.keys);
+                left.children.extend(node.children);
+                parent.children.remove(idx);
+                self.write_internal(left_id, &left)?;
+                Ok(true)
+            }
+            _ => Err(ReedError::CorruptedIndex {
+                page_id,
+                reason: "non-root node without siblings".to_string(),
+            }),
+        }
+    }
+
+    /// Root page identifier.
+    pub(super) fn root_page_id(&self) -> PageId {
+        self.root_page
+    }
+
+    /// Configured tree order.
+    pub(super) fn order(&self) -> Order {
+        self.order
+    }
+
+    /// Read and checksum-validate a raw page from the current mmap state.
+    pub(super) fn read_page(&self, page_id: PageId) -> ReedResult<Page> {
+        Page::read_from_bytes(&self.mmap, page_id)
+    }
+
+    /// Count keys in range [start, end) without deserialising values.
+    ///
+    /// Uses the same bounds as `range()` but only decodes the key arrays of
+    /// the leaves it visits, so cost is independent of value size.
+    ///
+    /// ## Input
+    /// - `start`: Inclusive lower bound
+    /// - `end`: Exclusive upper bound
+    ///
+    /// ## Output
+    /// - `Ok(usize)`: Number of keys k with start <= k < end
+    ///
+    /// ## Performance
+    /// - O(log n + m) where m = leaves overlapping the range
+    /// - Typically 3-10x faster than `range().len()` for large values
+    ///
+    /// ## Error Conditions
+    /// - `DeserializationError`: Corrupted node data
+    /// - `ParseError`: Invalid page type
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::btree::{BPlusTree, Order};
+    ///
+    /// let tree = BPlusTree::<String, Vec<u8>>::open("index.btree", Order::new(100)?)?;
+    /// let count = tree.count_keys_in_range(&"page.a".to_string(), &"page.z".to_string())?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn count_keys_in_range(&self, start: &K, end: &K) -> ReedResult<usize> {
+        if start >= end {
+            return Ok(0);
+        }
+        self.count_keys_below(self.root_page, Some(start), Some(end))
+    }
+
+    /// Total number of keys stored in the tree.
+    ///
+    /// Sums `keys.len()` across all leaves in key order. Values are never
+    /// deserialised.
+    ///
+    /// ## Output
+    /// - `Ok(usize)`: Number of keys
+    ///
+    /// ## Performance
+    /// - O(p) where p = number of pages in the tree
+    ///
+    /// ## Error Conditions
+    /// - `DeserializationError`: Corrupted node data
+    /// - `ParseError`: Invalid page type
+    pub fn key_count(&self) -> ReedResult<usize> {
+        self.count_keys_below(self.root_page, None, None)
+    }
+
+    /// Number of keys in the tree, without scanning.
+    ///
+    /// Maintained by every mutation and restored from page 0 plus the WAL
+    /// on open.
+    ///
+    /// ## Output
+    /// - Number of keys
+    ///
+    /// ## Performance
+    /// - O(1)
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::btree::{BPlusTree, Order};
+    ///
+    /// let tree = BPlusTree::<String, Vec<u8>>::open("index.btree", Order::new(100)?)?;
+    /// println!("{} keys", tree.len());
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn len(&self) -> u64 {
+        self.key_count
+    }
+
+    /// Whether the tree holds no keys.
+    ///
+    /// ## Performance
+    /// - O(1)
+    pub fn is_empty(&self) -> bool {
+        self.key_count == 0
+    }
+
+    /// Check the maintained key count against the leaves.
+    ///
+    /// Walks the leaf chain and counts every key. On a mismatch the
+    /// maintained count is replaced by the counted one, so `len()` is
+    /// correct afterwards, and an error reports the divergence.
+    ///
+    /// ## Output
+    /// - `Ok(u64)`: Number of keys, matching `len()`
+    /// - `Err(ReedError::CorruptedIndex)`: Stored count differed from the leaves
+    ///
+    /// ## Performance
+    /// - O(n) in the number of keys, values are deserialised
+    ///
+    /// ## Error Conditions
+    /// - `CorruptedIndex`: Count diverged (page 0 holds the count)
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::btree::{BPlusTree, Order};
+    ///
+    /// let mut tree = BPlusTree::<String, Vec<u8>>::open("index.btree", Order::new(100)?)?;
+    /// let count = tree.verify_count()?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn verify_count(&mut self) -> ReedResult<u64> {
+        let counted = self.iter().count() as u64;
+        if counted == self.key_count {
+            return Ok(counted);
+        }
+
+        let stored = self.key_count;
+        self.key_count = counted;
+        Err(ReedError::CorruptedIndex {
+            page_id: 0,
+            reason: format!(
+                "stored key count {} differs from {} keys in the leaf chain",
+                stored, counted
+            ),
+        })
+    }
+
+    /// Count keys in subtree rooted at `page_id`, optionally bounded.
+    ///
+    /// Leaves are reached through internal nodes rather than the `next` chain
+    /// because `next` is serialised after the values.
+    fn count_keys_below(
+        &self,
+        page_id: PageId,
+        start: Option<&K>,
+        end: Option<&K>,
+    ) -> ReedResult<usize> {
+        let page = Page::read_from_bytes(&self.mmap, page_id)?;
+
+        match page.header.page_type {
+            t if t == NodeType::Leaf as u8 => {
+                let leaf: LeafKeys<K> = bincode::deserialize(page.get_data()).map_err(|e| {
+                    ReedError::DeserializationError {
+                        reason: e.to_string(),
+                    }
+                })?;
+
+                Ok(leaf
+                    .keys
+                    .iter()
+                    .filter(|k| start.is_none_or(|s| *k >= s) && end.is_none_or(|e| *k < e))
+                    .count())
+            }
+            t if t == NodeType::Internal as u8 => {
+                let node: InternalNode<K> = bincode::deserialize(page.get_data()).map_err(|e| {
+                    ReedError::DeserializationError {
+                        reason: e.to_string(),
+                    }
+                })?;
+
+                let first = start.map_or(0, |s| node.find_child(s));
+                let last = end.map_or(node.children.len() - 1, |e| node.find_child(e));
+
+                let mut count = 0;
+                for &child in &node.children[first..=last] {
+                    count += self.count_keys_below(child, start, end)?;
+                }
+                Ok(count)
+            }
+            _ => Err(ReedError::ParseError {
+                reason: format!("Invalid page type: {}", page.header.page_type),
+            }),
+        }
+    }
+
+    /// Iterate key-value pairs in key order starting at `start`.
+    ///
+    /// Descends straight to the leaf containing `start` and walks the leaf
+    /// chain from there, so keys before `start` are never read. Unlike
+    /// `iter()` the pairs are produced lazily, one leaf at a time.
+    ///
+    /// ## Input
+    /// - `start`: Inclusive lower bound
+    ///
+    /// ## Output
+    /// - Iterator yielding (key, value) pairs with key >= start
+    /// - Empty iterator if the start leaf cannot be located
+    ///
+    /// ## Performance
+    /// - O(log n) to first item, O(1) amortised per item after that
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::btree::{BPlusTree, Order};
+    ///
+    /// let tree = BPlusTree::<String, Vec<u8>>::open("index.btree", Order::new(100)?)?;
+    /// let end = "page.n".to_string();
+    /// for (key, value) in tree
+    ///     .iter_from(&"page.m".to_string())
+    ///     .take_while(|(k, _)| k < &end)
+    /// {
+    ///     println!("{}: {:?}", key, value);
+    /// }
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn iter_from(&self, start: &K) -> Box<dyn Iterator<Item = (K, V)> + '_> {
+        match self.scan_from(start) {
+            Ok(iter) => Box::new(iter),
+            Err(_) => Box::new(std::iter::empty()),
+        }
+    }
+
+    /// Iterate over all entries in key order.
+    ///
+    /// Entries are read lazily along the leaf chain, so memory use is one
+    /// deserialised leaf regardless of tree size. Also backs `Index::iter()`.
+    ///
+    /// ## Output
+    /// - Iterator starting at the leftmost leaf
+    ///
+    /// ## Performance
+    /// - O(height) to locate the leftmost leaf
+    /// - O(1) amortised per entry (one page read per leaf)
+    ///
+    /// ## Error Conditions
+    /// - Unreadable or corrupted pages end the iteration early
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::btree::{BPlusTree, Order};
+    ///
+    /// let tree = BPlusTree::<String, Vec<u8>>::open("index.btree", Order::new(100)?)?;
+    /// for (key, value) in tree.iter() {
+    ///     println!("{}: {} bytes", key, value.len());
+    /// }
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn iter(&self) -> RangeScanIterator<'_, K, V> {
+        RangeScanIterator::from_first_leaf(&self.mmap, self.leftmost_leaf().ok())
+    }
+
+    /// Page id of the leftmost leaf.
+    fn leftmost_leaf(&self) -> ReedResult<PageId> {
+        let mut page_id = self.root_page;
+        loop {
+            let page = self.read_page(page_id)?;
+            if page.header.page_type == NodeType::Leaf as u8 {
+                return Ok(page_id);
+            }
+            let node: InternalNode<K> = deserialise_node(page.get_data())?;
+            page_id = node.children[0];
+        }
+    }
+
+    /// Unboxed variant of `iter_from()` exposing scan statistics.
+    ///
+    /// ## Input
+    /// - `start`: Inclusive lower bound
+    ///
+    /// ## Output
+    /// - `Ok(RangeScanIterator)`: Iterator positioned at the first key >= start
+    ///
+    /// ## Error Conditions
+    /// - `DeserializationError`: Corrupted internal node
+    /// - `ParseError`: Invalid page type
+    pub fn scan_from(&self, start: &K) -> ReedResult<RangeScanIterator<'_, K, V>> {
+        let leaf_page = self.search_leaf(start)?;
+        Ok(RangeScanIterator::from_start(
+            &self.mmap,
+            leaf_page,
+            start.clone(),
+        ))
+    }
+
+    /// Merge every entry of another tree into this one.
+    ///
+    /// Entries from `other.iter()` arrive in key order, so each run of keys
+    /// belonging to the same leaf is merged into that leaf in a single pass.
+    /// Leaves that receive no entries are never rewritten; a leaf that
+    /// overflows is split straight into as many siblings as it needs and the
+    /// root is updated once at the end.
+    ///
+    /// ## Input
+    /// - `other`: Tree to read entries from (left unchanged)
+    /// - `mode`: How keys present in both trees are resolved
+    ///
+    /// ## Output
+    /// - `Ok(())`: All entries merged
+    ///
+    /// ## Performance
+    /// - O(n + m) comparisons, O(m) leaf writes where m = leaves touched
+    /// - One WAL sync for the whole merge
+    ///
+    /// ## Error Conditions
+    /// - `IndexOperationUnsupported`: Shared key under `ConflictMode::Error`,
+    ///   or the merged tree no longer fits a two-level layout
+    /// - `DeserializationError`: Corrupted node data
+    /// - `IoError`: Page write or WAL sync failed
+    ///
+    /// Conflicts and capacity are checked before anything is written, so a
+    /// failed merge leaves this tree unchanged.
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::btree::{BPlusTree, ConflictMode, Order};
+    ///
+    /// let mut tree = BPlusTree::<String, Vec<u8>>::open("a.btree", Order::new(100)?)?;
+    /// let other = BPlusTree::<String, Vec<u8>>::open("b.btree", Order::new(100)?)?;
+    /// tree.merge(&other, ConflictMode::SkipExisting)?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn merge(&mut self, other: &Self, mode: ConflictMode) -> ReedResult<()> {
+        let incoming: Vec<(K, V)> = other.iter().collect();
+        if incoming.is_empty() {
+            return Ok(());
+        }
+
+        // Leaves must sit directly below the root; deeper trees are rejected below
+        let root_page = Page::read_from_bytes(&self.mmap, self.root_page)?;
+        let root: Option<InternalNode<K>> = if root_page.header.page_type == NodeType::Leaf as u8 {
+            None
+        } else {
+            Some(deserialise_node(root_page.get_data())?)
+        };
+
+        // Group incoming entries by target leaf (runs are contiguous)
+        let mut groups: Vec<(PageId, Vec<(K, V)>)> = Vec::new();
+        for (key, value) in incoming {
+            let page_id = match &root {
+                Some(node) => node.children[node.find_child(&key)],
+                None => self.root_page,
+            };
+            match groups.last_mut() {
+                Some((id, entries)) if *id == page_id => entries.push((key, value)),
+                _ => groups.push((page_id, vec![(key, value)])),
+            }
+        }
+
+        // Phase 1: build every rewritten leaf in memory
+        let max_keys = self.order.max_keys() as usize - 1;
+        let mut planned: Vec<(PageId, Vec<LeafNode<K, V>>)> = Vec::new();
+        let mut applied: Vec<(K, V)> = Vec::new();
+        let root_was_leaf = root.is_none();
+        let mut sized_root = root.clone().unwrap_or_else(|| self.root_above_leaf());
+        let mut splits = false;
+        let mut added: i64 = 0;
+
+        for (page_id, entries) in groups {
+            let page = Page::read_from_bytes(&self.mmap, page_id)?;
+            if page.header.page_type != NodeType::Leaf as u8 {
+                return Err(merge_unsupported(
+                    "tree is deeper than two levels".to_string(),
+                ));
+            }
+            let leaf: LeafNode<K, V> = deserialise_node(page.get_data())?;
+            let applied_before = applied.len();
+            let keys_before = leaf.keys.len();
+            let (keys, values) = merge_sorted(leaf.keys, leaf.values, entries, mode, &mut applied)?;
+            if applied.len() == applied_before {
+                continue;
+            }
+            added += (keys.len() - keys_before) as i64;
+
+            let pieces = split_evenly(keys, values, max_keys, leaf.next);
+            for piece in &pieces[1..] {
+                // Child ids are fixed-size, so a placeholder sizes the root exactly
+                sized_root.insert_key(piece.keys[0].clone(), 0)?;
+                splits = true;
+            }
+            for piece in &pieces {
+                check_page_fit(piece, "merge")?;
+            }
+            planned.push((page_id, pieces));
+        }
+
+        if applied.is_empty() {
+            return Ok(());
+        }
+        if splits {
+            check_page_fit(&sized_root, "merge")?;
+        }
+
+        // Phase 2: log, then write leaves and the rebuilt root
+        self.wal.log_batch_insert(&applied)?;
+        self.wal.log_count_delta(added)?;
+        self.wal.sync()?;
+        self.key_count = self.key_count.saturating_add_signed(added);
+
+        let mut new_root = root.unwrap_or_else(|| self.root_above_leaf());
+
+        for (page_id, mut pieces) in planned {
+            let mut page_ids = vec![page_id];
+            if root_was_leaf && pieces.len() > 1 {
+                // The root stays on its page: the first piece moves out too
+                page_ids[0] = self.allocate_page()?;
+                new_root.children[0] = page_ids[0];
+            }
+            for _ in 1..pieces.len() {
+                page_ids.push(self.allocate_page()?);
+            }
+            for i in 0..pieces.len() - 1 {
+                pieces[i].next = Some(page_ids[i + 1]);
+            }
+            for (piece, &id) in pieces.iter().zip(&page_ids) {
+                self.write_leaf(id, piece)?;
+            }
+            for (piece, &id) in pieces.iter().zip(&page_ids).skip(1) {
+                new_root.insert_key(piece.keys[0].clone(), id)?;
+            }
+        }
+
+        if splits {
+            // Root leaf has split: the root page now holds the internal root,
+            // as in split_leaf()
+            self.write_internal(self.root_page, &new_root)?;
+        }
+
+        Ok(())
+    }
+
+    /// Replace the tree's contents with sorted entries, built bottom-up.
+    ///
+    /// Meant for building an index from scratch: leaves are packed evenly,
+    /// the internal levels are built above them in one pass, and every page
+    /// is written straight to the mmap with a single flush at the end. No
+    /// WAL entries are written because the caller's source data is
+    /// authoritative; the WAL is truncated afterwards so that older entries
+    /// cannot be replayed over the new contents. Page 0 records the new
+    /// key count.
+    ///
+    /// ## Input
+    /// - `entries`: Key-value pairs in strictly ascending key order
+    ///
+    /// ## Output
+    /// - `Ok(())`: Tree holds exactly `entries`
+    ///
+    /// ## Performance
+    /// - O(n) serialisation, one page write per node, one flush
+    /// - O(n) memory (entries are collected before anything is written)
+    /// - < 500ms for 1M keys (vs minutes with `insert()`)
+    ///
+    /// ## Error Conditions
+    /// - `IndexOperationUnsupported`: Keys not strictly ascending, or a node
+    ///   does not fit a page (both checked before anything is written)
+    /// - `SerializationError`: Node could not be serialised
+    /// - `IoError`: File growth, flush or WAL truncation failed
+    ///
+    /// A crash during the load leaves the file in an undefined state. Since
+    /// nothing is logged, rerun the load from the source.
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::btree::{BPlusTree, Order};
+    ///
+    /// let mut tree = BPlusTree::<String, Vec<u8>>::open("index.btree", Order::new(100)?)?;
+    /// tree.bulk_load((0..100_000).map(|i| (format!("key{:06}", i), vec![1])))?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn bulk_load<I>(&mut self, entries: I) -> ReedResult<()>
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let (keys, values): (Vec<K>, Vec<V>) = entries.into_iter().unzip();
+        if let Some(i) = keys.windows(2).position(|w| w[0] >= w[1]) {
+            return Err(unsupported(
+                "bulk_load",
+                format!("keys are not strictly ascending at position {}", i + 1),
+            ));
+        }
+
+        let max_keys = self.order.max_keys() as usize;
+        let leaf_count = bulk_leaf_count(keys.len(), max_keys, self.order.min_keys() as usize);
+
+        // Leaves take pages 1.., internal levels follow, the root is page 0
+        let mut next_page: PageId = 1;
+        let mut leaves: Vec<(PageId, LeafNode<K, V>)> = Vec::with_capacity(leaf_count);
+        let mut entries = keys.into_iter().zip(values);
+        for size in even_sizes(entries.len(), leaf_count) {
+            let id = if leaf_count == 1 { 0 } else { next_page };
+            next_page += u32::from(leaf_count > 1);
+            let (keys, values) = entries.by_ref().take(size).unzip();
+            leaves.push((
+                id,
+                LeafNode {
+                    keys,
+                    values,
+                    next: None,
+                },
+            ));
+        }
+        for i in 1..leaves.len() {
+            let next = leaves[i].0;
+            leaves[i - 1].1.next = Some(next);
+        }
+
+        // Each level routes by the first key of every node below it
+        let mut level: Vec<(K, PageId)> = if leaf_count > 1 {
+            leaves
+                .iter()
+                .map(|(id, leaf)| (leaf.keys[0].clone(), *id))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let mut internals: Vec<(PageId, InternalNode<K>)> = Vec::new();
+        while level.len() > 1 {
+            let count = level.len().div_ceil(max_keys);
+            let mut upper = Vec::with_capacity(count);
+            let mut below = level.into_iter();
+            for size in even_sizes(below.len(), count) {
+                let mut node = InternalNode::new();
+                let mut first = None;
+                for (key, child) in below.by_ref().take(size) {
+                    match first {
+                        None => first = Some(key),
+                        Some(_) => node.keys.push(key),
+                    }
+                    node.children.push(child);
+                }
+                let id = if count == 1 { 0 } else { next_page };
+                next_page += u32::from(count > 1);
+                if let Some(key) = first {
+                    upper.push((key, id));
+                }
+                internals.push((id, node));
+            }
+            level = upper;
+        }
+
+        for (_, leaf) in &leaves {
+            check_page_fit(leaf, "bulk_load")?;
+        }
+        for (_, node) in &internals {
+            check_page_fit(node, "bulk_load")?;
+        }
+
+        self.reserve_pages(next_page)?;
+        self.checkpoint_count = leaves.iter().map(|(_, leaf)| leaf.keys.len() as u64).sum();
+        self.key_count = self.checkpoint_count;
+        for (id, leaf) in &leaves {
+            let page = self.stamp_count(*id, leaf_page(*id, leaf)?);
+            page.write_to(&mut self.mmap, *id)?;
+        }
+        for (id, node) in &internals {
+            let page = self.stamp_count(*id, internal_page(*id, node)?);
+            page.write_to(&mut self.mmap, *id)?;
+        }
+        self.mmap.flush().map_err(|e| ReedError::IoError {
+            operation: "flush_bulk_load".to_string(),
+            reason: e.to_string(),
+        })?;
+
+        self.root_page = 0;
+        self.next_page = next_page;
+        self.wal.truncate()?;
+
         Ok(())
     }
+
+    /// Internal node with the current root leaf as its only child.
+    fn root_above_leaf(&self) -> InternalNode<K> {
+        let mut node = InternalNode::new();
+        node.children.push(self.root_page);
+        node
+    }
 }
 
 impl<K, V> Index<K, V> for BPlusTree<K, V>
@@ -652,12 +1520,20 @@ where
     /// - I/O error
     /// - WAL write failed
     fn insert(&mut self, key: K, value: V) -> ReedResult<()> {
+        let is_new = self.get(&key)?.is_none();
+
         // Log to WAL first
         self.wal.log_insert(key.clone(), value.clone())?;
+        if is_new {
+            self.wal.log_count_delta(1)?;
+        }
         self.wal.sync()?;
 
         // Apply to tree
         self.insert_internal(key, value)?;
+        if is_new {
+            self.key_count += 1;
+        }
 
         Ok(())
     }
@@ -679,12 +1555,20 @@ where
     /// - I/O error
     /// - WAL write failed
     fn delete(&mut self, key: &K) -> ReedResult<()> {
+        let exists = self.get(key)?.is_some();
+
         // Log to WAL first
         self.wal.log_delete(key.clone())?;
+        if exists {
+            self.wal.log_count_delta(-1)?;
+        }
         self.wal.sync()?;
 
         // Apply to tree
         self.delete_internal(key)?;
+        if exists {
+            self.key_count -= 1;
+        }
 
         Ok(())
     }
@@ -701,58 +1585,22 @@ where
     /// ## Error Conditions
     /// - Iterator may yield errors (check during iteration)
     fn iter(&self) -> Box<dyn Iterator<Item = (K, V)> + '_> {
-        // Simplified implementation: collect all and return
-        let mut results = Vec::new();
-
-        let mmap_readonly = match unsafe { Mmap::map(&self.file) } {
-            Ok(m) => m,
-            Err(_) => return Box::new(results.into_iter()),
-        };
-
-        let mut current_page = self.root_page;
-
-        // Find leftmost leaf
-        loop {
-            let page = match Page::read_from(&mmap_readonly, current_page) {
-                Ok(p) => p,
-                Err(_) => return Box::new(results.into_iter()),
-            };
-
-            if page.header.page_type == NodeType::Leaf as u8 {
-                break;
-            }
-
-            let internal: InternalNode<K> = match bincode::deserialize(page.get_data()) {
-                Ok(n) => n,
-                Err(_) => return Box::new(results.into_iter()),
-            };
-
-            current_page = internal.children[0];
-        }
-
-        // Walk leaf chain
-        loop {
-            let leaf_page = match Page::read_from(&mmap_readonly, current_page) {
-                Ok(p) => p,
-                Err(_) => break,
-            };
-
-            let leaf: LeafNode<K, V> = match bincode::deserialize(leaf_page.get_data()) {
-                Ok(l) => l,
-                Err(_) => break,
-            };
+        Box::new(BPlusTree::iter(self))
+    }
 
-            for (key, value) in leaf.keys.iter().zip(leaf.values.iter()) {
-                results.push((key.clone(), value.clone()));
-            }
+    /// Count keys in range [start, end) without deserialising values.
+    fn count_keys_in_range(&self, start: &K, end: &K) -> ReedResult<usize> {
+        BPlusTree::count_keys_in_range(self, start, end)
+    }
 
-            match leaf.next {
-                Some(next_page) => current_page = next_page,
-                None => break,
-            }
-        }
+    /// Total number of keys in the tree, from the maintained count.
+    fn key_count(&self) -> ReedResult<usize> {
+        Ok(self.len() as usize)
+    }
 
-        Box::new(results.into_iter())
+    /// Shape and fill factor of the tree.
+    fn tree_statistics(&self) -> ReedResult<Option<TreeStatistics>> {
+        BPlusTree::statistics(self).map(Some)
     }
 
     /// Get backend type identifier.
@@ -780,3 +1628,182 @@ where
         self.file.metadata().map(|m| m.len() as usize).unwrap_or(0)
     }
 }
+
+/// Serialise a leaf node into a page.
+fn leaf_page<K, V>(page_id: PageId, leaf: &LeafNode<K, V>) -> ReedResult<Page>
+where
+    K: Clone + Ord + Serialize,
+    V: Clone + Serialize,
+{
+    let mut page = Page::new_leaf(page_id);
+    page.header.num_keys = leaf.keys.len() as u16;
+    page.header.next_page = leaf.next.unwrap_or(0);
+    page.set_data(padded(leaf)?);
+    Ok(page)
+}
+
+/// Serialise an internal node into a page.
+fn internal_page<K: Clone + Ord + Serialize>(
+    page_id: PageId,
+    internal: &InternalNode<K>,
+) -> ReedResult<Page> {
+    let mut page = Page::new_internal(page_id);
+    page.header.num_keys = internal.keys.len() as u16;
+    page.set_data(padded(internal)?);
+    Ok(page)
+}
+
+/// Serialise a node, padded to the page's data size.
+fn padded<T: Serialize>(node: &T) -> ReedResult<Vec<u8>> {
+    let mut data = bincode::serialize(node).map_err(|e| ReedError::SerializationError {
+        reason: e.to_string(),
+    })?;
+    data.resize(DATA_SIZE, 0);
+    Ok(data)
+}
+
+/// Deserialise a node from a page's data section.
+fn deserialise_node<T: for<'de> Deserialize<'de>>(data: &[u8]) -> ReedResult<T> {
+    bincode::deserialize(data).map_err(|e| ReedError::DeserializationError {
+        reason: e.to_string(),
+    })
+}
+
+/// Merge incoming sorted entries into a leaf's sorted keys and values.
+///
+/// Entries that change the leaf (new keys, and shared keys under
+/// `Overwrite`) are cloned into `applied` for WAL logging.
+fn merge_sorted<K: Clone + Ord, V: Clone>(
+    keys: Vec<K>,
+    values: Vec<V>,
+    incoming: Vec<(K, V)>,
+    mode: ConflictMode,
+    applied: &mut Vec<(K, V)>,
+) -> ReedResult<(Vec<K>, Vec<V>)> {
+    let capacity = keys.len() + incoming.len();
+    let mut merged_keys = Vec::with_capacity(capacity);
+    let mut merged_values = Vec::with_capacity(capacity);
+    let mut existing = keys.into_iter().zip(values).peekable();
+
+    for (key, value) in incoming {
+        while let Some((k, v)) = existing.next_if(|(k, _)| *k < key) {
+            merged_keys.push(k);
+            merged_values.push(v);
+        }
+
+        if let Some((k, v)) = existing.next_if(|(k, _)| *k == key) {
+            match mode {
+                ConflictMode::Overwrite => {}
+                ConflictMode::SkipExisting => {
+                    merged_keys.push(k);
+                    merged_values.push(v);
+                    continue;
+                }
+                ConflictMode::Error => {
+                    return Err(merge_unsupported(
+                        "key exists in both trees (ConflictMode::Error)".to_string(),
+                    ));
+                }
+            }
+        }
+
+        applied.push((key.clone(), value.clone()));
+        merged_keys.push(key);
+        merged_values.push(value);
+    }
+
+    for (k, v) in existing {
+        merged_keys.push(k);
+        merged_values.push(v);
+    }
+
+    Ok((merged_keys, merged_values))
+}
+
+/// Split sorted entries into the fewest leaves holding at most `max_keys`
+/// each, sized evenly so every piece stays at least half-full.
+///
+/// Only the last piece keeps `next`; the caller links the others once their
+/// pages are allocated.
+fn split_evenly<K: Clone + Ord, V: Clone>(
+    mut keys: Vec<K>,
+    mut values: Vec<V>,
+    max_keys: usize,
+    next: Option<PageId>,
+) -> Vec<LeafNode<K, V>> {
+    let count = keys.len().div_ceil(max_keys).max(1);
+
+    let mut pieces = Vec::with_capacity(count);
+    for size in even_sizes(keys.len(), count) {
+        let rest_keys = keys.split_off(size);
+        let rest_values = values.split_off(size);
+        pieces.push(LeafNode {
+            keys: std::mem::replace(&mut keys, rest_keys),
+            values: std::mem::replace(&mut values, rest_values),
+            next: None,
+        });
+    }
+    if let Some(last) = pieces.last_mut() {
+        last.next = next;
+    }
+
+    pieces
+}
+
+/// Sizes of `count` near-equal parts of `len` items, larger parts first.
+fn even_sizes(len: usize, count: usize) -> impl Iterator<Item = usize> {
+    let (base, extra) = (len / count, len % count);
+    (0..count).map(move |i| base + usize::from(i < extra))
+}
+
+/// Number of leaves for bulk-loading `len` keys.
+///
+/// Starts with the fewest leaves of at most `max_keys - 1` keys and adds
+/// leaves while some internal level cannot be split into half-full nodes.
+/// That only happens for `max_keys + 1` children under an even order.
+fn bulk_leaf_count(len: usize, max_keys: usize, min_keys: usize) -> usize {
+    let mut leaves = len.div_ceil(max_keys - 1).max(1);
+    while !levels_fit(leaves, max_keys, min_keys + 1) && (leaves + 1) * min_keys <= len {
+        leaves += 1;
+    }
+    leaves
+}
+
+/// Whether `nodes` nodes can be grouped, level by level up to a single
+/// root, into parents of `min_children..=max_children` children each.
+fn levels_fit(mut nodes: usize, max_children: usize, min_children: usize) -> bool {
+    while nodes > max_children {
+        let parents = nodes.div_ceil(max_children);
+        if parents * min_children > nodes {
+            return false;
+        }
+        nodes = parents;
+    }
+    true
+}
+
+/// Fail if a node would not fit a page's data section.
+fn check_page_fit<T: Serialize>(node: &T, operation: &str) -> ReedResult<()> {
+    let size = bincode::serialized_size(node).map_err(|e| ReedError::SerializationError {
+        reason: e.to_string(),
+    })?;
+    if size as usize > DATA_SIZE {
+        return Err(unsupported(
+            operation,
+            format!("node needs {} bytes, page holds {}", size, DATA_SIZE),
+        ));
+    }
+    Ok(())
+}
+
+fn merge_unsupported(reason: String) -> ReedError {
+    unsupported("merge", reason)
+}
+
+fn unsupported(operation: &str, reason: String) -> ReedError {
+    ReedError::IndexOperationUnsupported {
+        operation: operation.to_string(),
+        backend: "btree".to_string(),
+        reason,
+    }
+}
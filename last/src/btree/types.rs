@@ -143,6 +143,94 @@ pub enum NodeType {
     Leaf = 1,
 }
 
+/// How `BPlusTree::merge()` resolves keys present in both trees.
+///
+/// ## Variants
+/// - `Overwrite`: Take the value from the other tree (default)
+/// - `SkipExisting`: Keep the value already in this tree
+/// - `Error`: Abort the merge before anything is written
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConflictMode {
+    /// Other tree's value replaces the existing one.
+    #[default]
+    Overwrite,
+
+    /// Existing value is kept; the other tree's value is ignored.
+    SkipExisting,
+
+    /// Any shared key fails the merge with no changes applied.
+    Error,
+}
+
+/// Result of a full structural integrity walk over a B+-Tree.
+///
+/// Produced by `BPlusTree::verify_structural_integrity()`. I/O and
+/// deserialisation failures are reported as errors; invariant violations
+/// are collected here so that a single walk reports every problem found.
+///
+/// ## Checked Invariants
+/// - Internal nodes: `keys.len() + 1 == children.len()`
+/// - Keys within every node are strictly ascending
+/// - Routing keys bound the key ranges of their children
+/// - Leaf chain is acyclic and yields the same keys as tree traversal
+/// - Non-root nodes are at least half-full
+/// - All leaves sit at the same depth
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntegrityReport {
+    /// Tree height (1 = root is a leaf).
+    pub depth: usize,
+
+    /// Number of internal nodes visited.
+    pub internal_nodes: usize,
+
+    /// Number of leaf nodes visited.
+    pub leaf_nodes: usize,
+
+    /// Total keys stored in leaves.
+    pub key_count: usize,
+
+    /// Human-readable description of every violated invariant.
+    pub violations: Vec<String>,
+}
+
+impl IntegrityReport {
+    /// Returns true if no invariant violations were found.
+    pub fn is_valid(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+/// Shape and fill factor of a B+-Tree, for diagnostics.
+///
+/// Produced by `BPlusTree::statistics()`. Fill percentages are relative to
+/// the tree's order (maximum keys per node).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TreeStatistics {
+    /// Tree height (1 = root is a leaf).
+    pub height: usize,
+
+    /// Number of leaf nodes.
+    pub leaf_count: usize,
+
+    /// Number of internal nodes.
+    pub internal_count: usize,
+
+    /// Total keys stored in leaves.
+    pub total_keys: usize,
+
+    /// Average leaf fill in percent of the maximum keys per node.
+    pub avg_leaf_fill_pct: f64,
+
+    /// Fewest keys in any leaf.
+    pub min_leaf_keys: usize,
+
+    /// Most keys in any leaf.
+    pub max_leaf_keys: usize,
+
+    /// Unused bytes in the data sections of all reachable pages.
+    pub wasted_space_bytes: u64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -24,8 +24,31 @@
 //! ├─────────────────────────────────────────────────┤
 //! │ CRC32 Checksum (4 bytes)                        │
 //! └─────────────────────────────────────────────────┘
+//!
+//! CountDelta entry (13 bytes):
+//! ┌─────────────────────────────────────────────────┐
+//! │ Entry Type (1 byte: 3=CountDelta)               │
+//! ├─────────────────────────────────────────────────┤
+//! │ Delta (8 bytes, i64 big-endian)                 │
+//! ├─────────────────────────────────────────────────┤
+//! │ CRC32 Checksum (4 bytes)                        │
+//! └─────────────────────────────────────────────────┘
+//!
+//! Batch entry (variable length, one checksum for all keys):
+//! ┌─────────────────────────────────────────────────┐
+//! │ Entry Type (1 byte: 4=BatchInsert, 5=BatchDelete)│
+//! ├─────────────────────────────────────────────────┤
+//! │ Key Count (4 bytes, big-endian)                 │
+//! ├─────────────────────────────────────────────────┤
+//! │ Per key: Key Length, Key Data and, for          │
+//! │ BatchInsert, Value Length, Value Data           │
+//! ├─────────────────────────────────────────────────┤
+//! │ CRC32 Checksum (4 bytes)                        │
+//! └─────────────────────────────────────────────────┘
 //! ```
 //!
+//! A batch is replayed completely or, if its write was torn, not at all.
+//!
 //! ## Crash Recovery
 //!
 //! 1. On startup, check if WAL exists
@@ -33,6 +56,12 @@
 //! 3. Truncate WAL after successful replay
 //! 4. Continue normal operations
 //!
+//! ## Compaction
+//!
+//! Between truncates the log is bounded by `max_entries`: once a write
+//! takes it past that many entries, `compact()` rewrites it with only the
+//! last insert/delete per key.
+//!
 //! ## Performance
 //!
 //! - Log write: ~100μs (append + fsync)
@@ -42,10 +71,10 @@
 //! ## Example Usage
 //!
 //! ```rust
-//! use reedbase_last::btree::wal::{WriteAheadLog, WalEntry};
+//! use reedbase_last::btree::wal::{WalEntry, WriteAheadLog, DEFAULT_MAX_ENTRIES};
 //!
 //! // Open or create WAL
-//! let mut wal = WriteAheadLog::open("index.wal")?;
+//! let mut wal = WriteAheadLog::open("index.wal", DEFAULT_MAX_ENTRIES)?;
 //!
 //! // Log mutation
 //! wal.log_insert("key".to_string(), vec![1, 2, 3])?;
@@ -63,6 +92,15 @@
 //!         WalEntry::Delete { key } => {
 //!             // Apply delete to B+-Tree
 //!         }
+//!         WalEntry::CountDelta { delta } => {
+//!             // Adjust the tree's key count
+//!         }
+//!         WalEntry::BatchInsert { entries } => {
+//!             // Apply each insert in order
+//!         }
+//!         WalEntry::BatchDelete { keys } => {
+//!             // Apply each delete in order
+//!         }
 //!     }
 //! }
 //!
@@ -73,10 +111,15 @@
 
 use crate::error::{ReedError, ReedResult};
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::fs::{File, OpenOptions};
 use std::io::{BufReader, Read, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
 
+/// Entry count past which B+-Tree logs are compacted.
+pub const DEFAULT_MAX_ENTRIES: usize = 10_000;
+
 /// Entry type discriminator for WAL records.
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -85,6 +128,12 @@ enum EntryType {
     Insert = 1,
     /// Delete operation.
     Delete = 2,
+    /// Change of the tree's key count.
+    CountDelta = 3,
+    /// Several inserts in one record.
+    BatchInsert = 4,
+    /// Several deletes in one record.
+    BatchDelete = 5,
 }
 
 impl EntryType {
@@ -93,6 +142,9 @@ impl EntryType {
         match byte {
             1 => Ok(Self::Insert),
             2 => Ok(Self::Delete),
+            3 => Ok(Self::CountDelta),
+            4 => Ok(Self::BatchInsert),
+            5 => Ok(Self::BatchDelete),
             _ => Err(ReedError::ParseError {
                 reason: format!("Invalid WAL entry type: {}", byte),
             }),
@@ -105,6 +157,10 @@ impl EntryType {
 /// ## Variants
 /// - `Insert`: Add or update key-value pair
 /// - `Delete`: Remove key
+/// - `CountDelta`: Keys added (positive) or removed (negative) by the
+///   preceding mutations
+/// - `BatchInsert`: Several inserts written as one record
+/// - `BatchDelete`: Several deletes written as one record
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum WalEntry<K, V>
 where
@@ -123,6 +179,21 @@ where
         /// Key to delete.
         key: K,
     },
+    /// Key count change.
+    CountDelta {
+        /// Number of keys added (negative when removed).
+        delta: i64,
+    },
+    /// Insert or update operations, in order.
+    BatchInsert {
+        /// Key-value pairs to insert/update.
+        entries: Vec<(K, V)>,
+    },
+    /// Delete operations, in order.
+    BatchDelete {
+        /// Keys to delete.
+        keys: Vec<K>,
+    },
 }
 
 /// Write-Ahead Log for B+-Tree durability.
@@ -149,6 +220,19 @@ pub struct WriteAheadLog {
 
     /// File handle for append operations.
     file: File,
+
+    /// Entry count past which writes trigger `compact()`.
+    max_entries: usize,
+
+    /// Entries currently in the log.
+    entries: usize,
+
+    /// Entry count at which the next compaction runs.
+    ///
+    /// Raised above `max_entries` when a compaction cannot get the log
+    /// below half of it, so a log of distinct keys is not rewritten on
+    /// every write.
+    compact_at: usize,
 }
 
 impl WriteAheadLog {
@@ -156,13 +240,15 @@ impl WriteAheadLog {
     ///
     /// ## Input
     /// - `path`: Path to WAL file (typically `index.wal`)
+    /// - `max_entries`: Compact once a write takes the log past this many
+    ///   entries (`usize::MAX` never compacts)
     ///
     /// ## Output
     /// - `Ok(WriteAheadLog)`: Successfully opened/created WAL
     /// - `Err(ReedError::IoError)`: File creation/open failed
     ///
     /// ## Performance
-    /// - O(1) file open operation
+    /// - O(n) in the existing log size, entries are counted
     ///
     /// ## Error Conditions
     /// - Parent directory does not exist
@@ -171,26 +257,23 @@ impl WriteAheadLog {
     ///
     /// ## Example
     /// ```rust
-    /// use reedbase_last::btree::wal::WriteAheadLog;
+    /// use reedbase_last::btree::wal::{WriteAheadLog, DEFAULT_MAX_ENTRIES};
     ///
-    /// let wal = WriteAheadLog::open("index.wal")?;
+    /// let wal = WriteAheadLog::open("index.wal", DEFAULT_MAX_ENTRIES)?;
     /// # Ok::<(), reedbase::ReedError>(())
     /// ```
-    pub fn open<P: AsRef<Path>>(path: P) -> ReedResult<Self> {
+    pub fn open<P: AsRef<Path>>(path: P, max_entries: usize) -> ReedResult<Self> {
         let path = path.as_ref().to_path_buf();
+        let file = open_append(&path)?;
+        let entries = read_entries(&path)?.iter().map(RawEntry::op_count).sum();
 
-        // Open in append mode (create if doesn't exist)
-        let file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .read(true)
-            .open(&path)
-            .map_err(|e| ReedError::IoError {
-                operation: "open_wal".to_string(),
-                reason: e.to_string(),
-            })?;
-
-        Ok(Self { path, file })
+        Ok(Self {
+            path,
+            file,
+            max_entries,
+            entries,
+            compact_at: max_entries,
+        })
     }
 
     /// Log insert operation to WAL.
@@ -216,9 +299,9 @@ impl WriteAheadLog {
     ///
     /// ## Example
     /// ```rust
-    /// use reedbase_last::btree::wal::WriteAheadLog;
+    /// use reedbase_last::btree::wal::{WriteAheadLog, DEFAULT_MAX_ENTRIES};
     ///
-    /// let mut wal = WriteAheadLog::open("index.wal")?;
+    /// let mut wal = WriteAheadLog::open("index.wal", DEFAULT_MAX_ENTRIES)?;
     /// wal.log_insert("key".to_string(), vec![1, 2, 3])?;
     /// wal.sync()?; // Ensure durability
     /// # Ok::<(), reedbase::ReedError>(())
@@ -228,48 +311,18 @@ impl WriteAheadLog {
         K: Serialize,
         V: Serialize,
     {
-        // Serialise key
-        let key_bytes = bincode::serialize(&key).map_err(|e| ReedError::SerializationError {
-            reason: e.to_string(),
-        })?;
-
-        // Serialise value
-        let value_bytes =
-            bincode::serialize(&value).map_err(|e| ReedError::SerializationError {
-                reason: e.to_string(),
-            })?;
-
-        // Build entry buffer
-        let mut buffer = Vec::new();
-
-        // Entry type (1 byte)
-        buffer.push(EntryType::Insert as u8);
-
-        // Key length (4 bytes, big-endian)
-        buffer.extend_from_slice(&(key_bytes.len() as u32).to_be_bytes());
-
-        // Key data
-        buffer.extend_from_slice(&key_bytes);
-
-        // Value length (4 bytes, big-endian)
-        buffer.extend_from_slice(&(value_bytes.len() as u32).to_be_bytes());
-
-        // Value data
-        buffer.extend_from_slice(&value_bytes);
-
-        // CRC32 checksum (over entire entry)
-        let checksum = crc32fast::hash(&buffer);
-        buffer.extend_from_slice(&checksum.to_be_bytes());
+        let key_bytes = serialise(&key)?;
+        let value_bytes = serialise(&value)?;
 
         // Write to file
         self.file
-            .write_all(&buffer)
+            .write_all(&encode_insert(&key_bytes, &value_bytes))
             .map_err(|e| ReedError::IoError {
                 operation: "write_wal_insert".to_string(),
                 reason: e.to_string(),
             })?;
 
-        Ok(())
+        self.record_entries(1)
     }
 
     /// Log delete operation to WAL.
@@ -294,9 +347,9 @@ impl WriteAheadLog {
     ///
     /// ## Example
     /// ```rust
-    /// use reedbase_last::btree::wal::WriteAheadLog;
+    /// use reedbase_last::btree::wal::{WriteAheadLog, DEFAULT_MAX_ENTRIES};
     ///
-    /// let mut wal = WriteAheadLog::open("index.wal")?;
+    /// let mut wal = WriteAheadLog::open("index.wal", DEFAULT_MAX_ENTRIES)?;
     /// wal.log_delete("key".to_string())?;
     /// wal.sync()?; // Ensure durability
     /// # Ok::<(), reedbase::ReedError>(())
@@ -305,35 +358,201 @@ impl WriteAheadLog {
     where
         K: Serialize,
     {
-        // Serialise key
-        let key_bytes = bincode::serialize(&key).map_err(|e| ReedError::SerializationError {
-            reason: e.to_string(),
-        })?;
+        let key_bytes = serialise(&key)?;
 
-        // Build entry buffer
-        let mut buffer = Vec::new();
+        // Write to file
+        self.file
+            .write_all(&encode_delete(&key_bytes))
+            .map_err(|e| ReedError::IoError {
+                operation: "write_wal_delete".to_string(),
+                reason: e.to_string(),
+            })?;
 
-        // Entry type (1 byte)
-        buffer.push(EntryType::Delete as u8);
+        self.record_entries(1)
+    }
 
-        // Key length (4 bytes, big-endian)
-        buffer.extend_from_slice(&(key_bytes.len() as u32).to_be_bytes());
+    /// Log several insert operations as one WAL record.
+    ///
+    /// All pairs are encoded into one buffer and written with a single
+    /// `write` call under one checksum, so replay applies either the whole
+    /// batch or none of it. An empty batch writes nothing.
+    ///
+    /// ## Input
+    /// - `entries`: Key-value pairs to insert/update, in order
+    ///
+    /// ## Output
+    /// - `Ok(())`: Record written to kernel buffer
+    /// - `Err(ReedError)`: Write failed or serialisation error
+    ///
+    /// ## Performance
+    /// - O(n) encoding, one append for the whole batch
+    ///
+    /// ## Error Conditions
+    /// - Disk full
+    /// - I/O error
+    /// - Serialisation error (key/value too large)
+    ///
+    /// ## Durability
+    /// Record is NOT durable until `sync()` is called.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use reedbase_last::btree::wal::{WriteAheadLog, DEFAULT_MAX_ENTRIES};
+    ///
+    /// let mut wal = WriteAheadLog::open("index.wal", DEFAULT_MAX_ENTRIES)?;
+    /// let entries: Vec<(String, Vec<u8>)> =
+    ///     (0..1000).map(|i| (format!("key{}", i), vec![1])).collect();
+    /// wal.log_batch_insert(&entries)?;
+    /// wal.sync()?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn log_batch_insert<K, V>(&mut self, entries: &[(K, V)]) -> ReedResult<()>
+    where
+        K: Serialize,
+        V: Serialize,
+    {
+        let mut fields = Vec::with_capacity(entries.len() * 2);
+        for (key, value) in entries {
+            fields.push(serialise(key)?);
+            fields.push(serialise(value)?);
+        }
+
+        self.write_batch(EntryType::BatchInsert, entries.len(), &fields)
+    }
+
+    /// Log several delete operations as one WAL record.
+    ///
+    /// Same format and guarantees as `log_batch_insert()`, keys only.
+    ///
+    /// ## Input
+    /// - `keys`: Keys to delete, in order
+    ///
+    /// ## Output
+    /// - `Ok(())`: Record written to kernel buffer
+    /// - `Err(ReedError)`: Write failed or serialisation error
+    ///
+    /// ## Performance
+    /// - O(n) encoding, one append for the whole batch
+    ///
+    /// ## Error Conditions
+    /// - Disk full
+    /// - I/O error
+    /// - Serialisation error (key too large)
+    ///
+    /// ## Durability
+    /// Record is NOT durable until `sync()` is called.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use reedbase_last::btree::wal::{WriteAheadLog, DEFAULT_MAX_ENTRIES};
+    ///
+    /// let mut wal = WriteAheadLog::open("index.wal", DEFAULT_MAX_ENTRIES)?;
+    /// wal.log_batch_delete(&["a".to_string(), "b".to_string()])?;
+    /// wal.sync()?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    #[allow(dead_code)] // No bulk delete in BPlusTree yet
+    pub fn log_batch_delete<K>(&mut self, keys: &[K]) -> ReedResult<()>
+    where
+        K: Serialize,
+    {
+        let fields = keys.iter().map(serialise).collect::<ReedResult<Vec<_>>>()?;
+
+        self.write_batch(EntryType::BatchDelete, keys.len(), &fields)
+    }
 
-        // Key data
-        buffer.extend_from_slice(&key_bytes);
+    /// Write one batch record of `count` keys with a single `write` call.
+    fn write_batch(
+        &mut self,
+        entry_type: EntryType,
+        count: usize,
+        fields: &[Vec<u8>],
+    ) -> ReedResult<()> {
+        if count == 0 {
+            return Ok(());
+        }
+        let count = u32::try_from(count).map_err(|_| ReedError::SerializationError {
+            reason: format!("WAL batch of {} keys exceeds u32::MAX", count),
+        })?;
+
+        let mut buffer =
+            Vec::with_capacity(5 + fields.iter().map(|f| f.len() + 4).sum::<usize>() + 4);
+
+        // Entry type (1 byte) and key count (4 bytes, big-endian)
+        buffer.push(entry_type as u8);
+        buffer.extend_from_slice(&count.to_be_bytes());
+
+        for field in fields {
+            push_field(&mut buffer, field);
+        }
 
         // CRC32 checksum (over entire entry)
         let checksum = crc32fast::hash(&buffer);
         buffer.extend_from_slice(&checksum.to_be_bytes());
 
-        // Write to file
         self.file
             .write_all(&buffer)
             .map_err(|e| ReedError::IoError {
-                operation: "write_wal_delete".to_string(),
+                operation: "write_wal_batch".to_string(),
+                reason: e.to_string(),
+            })?;
+
+        self.record_entries(count as usize)
+    }
+
+    /// Log a change of the tree's key count.
+    ///
+    /// Written alongside the mutation that caused it, so the count stored
+    /// in the index file plus all deltas since the last truncate gives the
+    /// current count after a crash.
+    ///
+    /// ## Input
+    /// - `delta`: Keys added (negative when removed)
+    ///
+    /// ## Output
+    /// - `Ok(())`: Entry written to kernel buffer
+    /// - `Err(ReedError::IoError)`: Write failed
+    ///
+    /// ## Performance
+    /// - O(1) append of 13 bytes
+    ///
+    /// ## Error Conditions
+    /// - Disk full
+    /// - I/O error
+    ///
+    /// ## Durability
+    /// Entry is NOT durable until `sync()` is called.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use reedbase_last::btree::wal::{WriteAheadLog, DEFAULT_MAX_ENTRIES};
+    ///
+    /// let mut wal = WriteAheadLog::open("index.wal", DEFAULT_MAX_ENTRIES)?;
+    /// wal.log_insert("key".to_string(), vec![1, 2, 3])?;
+    /// wal.log_count_delta(1)?;
+    /// wal.sync()?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn log_count_delta(&mut self, delta: i64) -> ReedResult<()> {
+        self.file
+            .write_all(&encode_count_delta(delta))
+            .map_err(|e| ReedError::IoError {
+                operation: "write_wal_count_delta".to_string(),
                 reason: e.to_string(),
             })?;
 
+        self.record_entries(1)
+    }
+
+    /// Count written operations and compact once past the threshold.
+    ///
+    /// A batch counts once per key, so batches cannot grow the log past
+    /// `max_entries` unnoticed.
+    fn record_entries(&mut self, count: usize) -> ReedResult<()> {
+        self.entries += count;
+        if self.entries > self.compact_at {
+            self.compact()?;
+        }
         Ok(())
     }
 
@@ -363,9 +582,9 @@ impl WriteAheadLog {
     ///
     /// ## Example
     /// ```rust
-    /// use reedbase_last::btree::wal::{WriteAheadLog, WalEntry};
+    /// use reedbase_last::btree::wal::{WalEntry, WriteAheadLog, DEFAULT_MAX_ENTRIES};
     ///
-    /// let wal = WriteAheadLog::open("index.wal")?;
+    /// let wal = WriteAheadLog::open("index.wal", DEFAULT_MAX_ENTRIES)?;
     /// let entries = wal.replay()?;
     ///
     /// println!("Replaying {} operations", entries.len());
@@ -381,117 +600,158 @@ impl WriteAheadLog {
     {
         let mut entries = Vec::new();
 
-        // Open file for reading (separate handle to avoid append-mode issues)
-        let mut file = File::open(&self.path).map_err(|e| ReedError::IoError {
-            operation: "open_wal_replay".to_string(),
-            reason: e.to_string(),
-        })?;
-
-        // Ensure we're at the start of the file
-        file.seek(SeekFrom::Start(0))
-            .map_err(|e| ReedError::IoError {
-                operation: "seek_wal_replay".to_string(),
-                reason: e.to_string(),
-            })?;
-
-        let mut reader = BufReader::new(file);
-        let mut buffer = Vec::new();
-
-        loop {
-            buffer.clear();
-
-            // Read entry type (1 byte)
-            let mut type_byte = [0u8; 1];
-            match reader.read_exact(&mut type_byte) {
-                Ok(_) => {}
-                Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => break, // End of file
-                Err(e) => {
-                    return Err(ReedError::IoError {
-                        operation: "read_wal_type".to_string(),
-                        reason: e.to_string(),
-                    })
+        for raw in read_entries(&self.path)? {
+            let entry = match raw.entry_type {
+                EntryType::Insert => {
+                    let (Ok(key), Ok(value)) = (
+                        bincode::deserialize(&raw.key),
+                        bincode::deserialize(&raw.value),
+                    ) else {
+                        break; // Corrupted key or value
+                    };
+                    WalEntry::Insert { key, value }
+                }
+                EntryType::Delete => {
+                    let Ok(key) = bincode::deserialize(&raw.key) else {
+                        break; // Corrupted key
+                    };
+                    WalEntry::Delete { key }
+                }
+                EntryType::CountDelta => WalEntry::CountDelta { delta: raw.delta },
+                EntryType::BatchInsert => {
+                    let entries: Option<Vec<(K, V)>> = raw
+                        .batch
+                        .iter()
+                        .map(|(key, value)| {
+                            Some((
+                                bincode::deserialize(key).ok()?,
+                                bincode::deserialize(value).ok()?,
+                            ))
+                        })
+                        .collect();
+                    let Some(entries) = entries else {
+                        break; // Corrupted key or value
+                    };
+                    WalEntry::BatchInsert { entries }
+                }
+                EntryType::BatchDelete => {
+                    let keys: Option<Vec<K>> = raw
+                        .batch
+                        .iter()
+                        .map(|(key, _)| bincode::deserialize(key).ok())
+                        .collect();
+                    let Some(keys) = keys else {
+                        break; // Corrupted key
+                    };
+                    WalEntry::BatchDelete { keys }
                 }
-            }
-
-            let entry_type = match EntryType::from_byte(type_byte[0]) {
-                Ok(t) => t,
-                Err(_) => break, // Corrupted entry, stop replay
             };
+            entries.push(entry);
+        }
 
-            buffer.push(type_byte[0]);
-
-            // Read key length (4 bytes)
-            let mut len_bytes = [0u8; 4];
-            if reader.read_exact(&mut len_bytes).is_err() {
-                break; // Truncated entry
-            }
-            let key_len = u32::from_be_bytes(len_bytes) as usize;
-            buffer.extend_from_slice(&len_bytes);
-
-            // Read key data
-            let mut key_bytes = vec![0u8; key_len];
-            if reader.read_exact(&mut key_bytes).is_err() {
-                break; // Truncated entry
-            }
-            buffer.extend_from_slice(&key_bytes);
-
-            // Deserialise key
-            let key: K = match bincode::deserialize(&key_bytes) {
-                Ok(k) => k,
-                Err(_) => break, // Corrupted key
-            };
+        Ok(entries)
+    }
 
-            // Read value if Insert entry
-            let value = if entry_type == EntryType::Insert {
-                // Read value length (4 bytes)
-                let mut len_bytes = [0u8; 4];
-                if reader.read_exact(&mut len_bytes).is_err() {
-                    break; // Truncated entry
-                }
-                let value_len = u32::from_be_bytes(len_bytes) as usize;
-                buffer.extend_from_slice(&len_bytes);
+    /// Condense the log to the last operation per key.
+    ///
+    /// Replaying the condensed log yields the same tree: an earlier insert
+    /// or delete of a key is always overridden by its last one, and all
+    /// `CountDelta` entries are summed into one. Batches are split into
+    /// single-key entries first. The result is written to
+    /// `{path}.tmp`, synced and renamed over the log, so a crash leaves
+    /// either the old or the new log. A corrupted tail is dropped, as
+    /// `replay()` would ignore it anyway.
+    ///
+    /// ## Output
+    /// - `Ok(usize)`: Number of entries left in the log
+    /// - `Err(ReedError::IoError)`: Reading, writing or renaming failed
+    ///
+    /// ## Performance
+    /// - O(n) in the log size, one full read and one rewrite
+    ///
+    /// ## Error Conditions
+    /// - I/O error
+    /// - Disk full
+    ///
+    /// ## Example
+    /// ```rust
+    /// use reedbase_last::btree::wal::{WriteAheadLog, DEFAULT_MAX_ENTRIES};
+    ///
+    /// let mut wal = WriteAheadLog::open("index.wal", DEFAULT_MAX_ENTRIES)?;
+    /// for i in 0..100u8 {
+    ///     wal.log_insert("key".to_string(), vec![i])?;
+    /// }
+    /// assert_eq!(wal.compact()?, 1);
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn compact(&mut self) -> ReedResult<usize> {
+        let entries = read_entries(&self.path)?;
 
-                // Read value data
-                let mut value_bytes = vec![0u8; value_len];
-                if reader.read_exact(&mut value_bytes).is_err() {
-                    break; // Truncated entry
+        // Single-key operations as (key, encoded entry), all count deltas summed
+        let mut ops: Vec<(&[u8], Cow<[u8]>)> = Vec::new();
+        let mut delta: i64 = 0;
+        for entry in &entries {
+            match entry.entry_type {
+                EntryType::CountDelta => delta += entry.delta,
+                EntryType::Insert | EntryType::Delete => {
+                    ops.push((&entry.key, Cow::Borrowed(&entry.bytes)));
                 }
-                buffer.extend_from_slice(&value_bytes);
+                EntryType::BatchInsert => ops.extend(
+                    entry
+                        .batch
+                        .iter()
+                        .map(|(key, value)| (&key[..], Cow::Owned(encode_insert(key, value)))),
+                ),
+                EntryType::BatchDelete => ops.extend(
+                    entry
+                        .batch
+                        .iter()
+                        .map(|(key, _)| (&key[..], Cow::Owned(encode_delete(key)))),
+                ),
+            }
+        }
 
-                // Deserialise value
-                match bincode::deserialize(&value_bytes) {
-                    Ok(v) => Some(v),
-                    Err(_) => break, // Corrupted value
-                }
-            } else {
-                None
-            };
+        // Last operation per key
+        let mut last: HashMap<&[u8], usize> = HashMap::new();
+        for (i, (key, _)) in ops.iter().enumerate() {
+            last.insert(key, i);
+        }
 
-            // Read checksum (4 bytes)
-            let mut checksum_bytes = [0u8; 4];
-            if reader.read_exact(&mut checksum_bytes).is_err() {
-                break; // Truncated entry
+        let mut buffer = Vec::new();
+        let mut kept = 0;
+        for (i, (key, bytes)) in ops.iter().enumerate() {
+            if last.get(key) == Some(&i) {
+                buffer.extend_from_slice(bytes);
+                kept += 1;
             }
-            let stored_checksum = u32::from_be_bytes(checksum_bytes);
+        }
+        if delta != 0 {
+            buffer.extend_from_slice(&encode_count_delta(delta));
+            kept += 1;
+        }
 
-            // Validate checksum
-            let computed_checksum = crc32fast::hash(&buffer);
-            if stored_checksum != computed_checksum {
-                break; // Corrupted entry (partial write)
-            }
+        // Write to temp file, sync, then rename over the log
+        let temp_path = temp_wal_path(&self.path);
+        let write_temp = || -> std::io::Result<()> {
+            let mut file = File::create(&temp_path)?;
+            file.write_all(&buffer)?;
+            file.sync_all()
+        };
+        write_temp().map_err(|e| ReedError::IoError {
+            operation: "write_wal_compact".to_string(),
+            reason: e.to_string(),
+        })?;
+        std::fs::rename(&temp_path, &self.path).map_err(|e| ReedError::IoError {
+            operation: "rename_wal_compact".to_string(),
+            reason: e.to_string(),
+        })?;
 
-            // Add valid entry to results
-            let entry = match entry_type {
-                EntryType::Insert => WalEntry::Insert {
-                    key,
-                    value: value.unwrap(),
-                },
-                EntryType::Delete => WalEntry::Delete { key },
-            };
-            entries.push(entry);
-        }
+        // The old handle still points at the replaced file
+        self.file = open_append(&self.path)?;
+        self.entries = kept;
+        self.compact_at = self.max_entries.max(kept * 2);
 
-        Ok(entries)
+        Ok(kept)
     }
 
     /// Truncate WAL file (clear all entries).
@@ -512,9 +772,9 @@ impl WriteAheadLog {
     ///
     /// ## Example
     /// ```rust
-    /// use reedbase_last::btree::wal::WriteAheadLog;
+    /// use reedbase_last::btree::wal::{WriteAheadLog, DEFAULT_MAX_ENTRIES};
     ///
-    /// let mut wal = WriteAheadLog::open("index.wal")?;
+    /// let mut wal = WriteAheadLog::open("index.wal", DEFAULT_MAX_ENTRIES)?;
     /// let entries = wal.replay()?;
     ///
     /// // Apply entries to B+-Tree...
@@ -537,6 +797,9 @@ impl WriteAheadLog {
                 reason: e.to_string(),
             })?;
 
+        self.entries = 0;
+        self.compact_at = self.max_entries;
+
         Ok(())
     }
 
@@ -559,9 +822,9 @@ impl WriteAheadLog {
     ///
     /// ## Example
     /// ```rust
-    /// use reedbase_last::btree::wal::WriteAheadLog;
+    /// use reedbase_last::btree::wal::{WriteAheadLog, DEFAULT_MAX_ENTRIES};
     ///
-    /// let mut wal = WriteAheadLog::open("index.wal")?;
+    /// let mut wal = WriteAheadLog::open("index.wal", DEFAULT_MAX_ENTRIES)?;
     ///
     /// // Batch writes
     /// for i in 0..100 {
@@ -579,3 +842,257 @@ impl WriteAheadLog {
         })
     }
 }
+
+/// One checksummed entry as stored in the log.
+struct RawEntry {
+    entry_type: EntryType,
+    /// Serialised key (empty for `CountDelta`).
+    key: Vec<u8>,
+    /// Serialised value (empty unless `Insert`).
+    value: Vec<u8>,
+    /// Serialised (key, value) pairs of a batch (values empty for
+    /// `BatchDelete`, no pairs for other types).
+    batch: Vec<(Vec<u8>, Vec<u8>)>,
+    /// Count change (0 unless `CountDelta`).
+    delta: i64,
+    /// The complete encoded entry, checksum included.
+    bytes: Vec<u8>,
+}
+
+impl RawEntry {
+    /// Number of operations in the entry (keys of a batch, otherwise 1).
+    fn op_count(&self) -> usize {
+        match self.entry_type {
+            EntryType::BatchInsert | EntryType::BatchDelete => self.batch.len(),
+            _ => 1,
+        }
+    }
+}
+
+/// Read all valid entries, stopping at the first truncated or corrupted one.
+fn read_entries(path: &Path) -> ReedResult<Vec<RawEntry>> {
+    let mut entries = Vec::new();
+
+    // Open file for reading (separate handle to avoid append-mode issues)
+    let mut file = File::open(path).map_err(|e| ReedError::IoError {
+        operation: "open_wal_replay".to_string(),
+        reason: e.to_string(),
+    })?;
+
+    // Ensure we're at the start of the file
+    file.seek(SeekFrom::Start(0))
+        .map_err(|e| ReedError::IoError {
+            operation: "seek_wal_replay".to_string(),
+            reason: e.to_string(),
+        })?;
+
+    let mut reader = BufReader::new(file);
+
+    loop {
+        let mut buffer = Vec::new();
+
+        // Read entry type (1 byte)
+        let mut type_byte = [0u8; 1];
+        match reader.read_exact(&mut type_byte) {
+            Ok(_) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => break, // End of file
+            Err(e) => {
+                return Err(ReedError::IoError {
+                    operation: "read_wal_type".to_string(),
+                    reason: e.to_string(),
+                })
+            }
+        }
+
+        let entry_type = match EntryType::from_byte(type_byte[0]) {
+            Ok(t) => t,
+            Err(_) => break, // Corrupted entry, stop replay
+        };
+
+        buffer.push(type_byte[0]);
+
+        let mut key = Vec::new();
+        let mut value = Vec::new();
+        let mut batch = Vec::new();
+        let mut delta = 0;
+
+        if entry_type == EntryType::CountDelta {
+            // Read delta (8 bytes)
+            let mut delta_bytes = [0u8; 8];
+            if reader.read_exact(&mut delta_bytes).is_err() {
+                break; // Truncated entry
+            }
+            buffer.extend_from_slice(&delta_bytes);
+            delta = i64::from_be_bytes(delta_bytes);
+        } else if matches!(entry_type, EntryType::BatchInsert | EntryType::BatchDelete) {
+            // Read key count (4 bytes), then every key (and value)
+            let mut count_bytes = [0u8; 4];
+            if reader.read_exact(&mut count_bytes).is_err() {
+                break; // Truncated entry
+            }
+            buffer.extend_from_slice(&count_bytes);
+
+            let mut complete = true;
+            for _ in 0..u32::from_be_bytes(count_bytes) {
+                let Some(batch_key) = read_field(&mut reader, &mut buffer) else {
+                    complete = false;
+                    break;
+                };
+                let batch_value = if entry_type == EntryType::BatchInsert {
+                    let Some(bytes) = read_field(&mut reader, &mut buffer) else {
+                        complete = false;
+                        break;
+                    };
+                    bytes
+                } else {
+                    Vec::new()
+                };
+                batch.push((batch_key, batch_value));
+            }
+            if !complete {
+                break; // Truncated entry
+            }
+        } else {
+            // Read key
+            match read_field(&mut reader, &mut buffer) {
+                Some(bytes) => key = bytes,
+                None => break, // Truncated entry
+            }
+
+            // Read value if Insert entry
+            if entry_type == EntryType::Insert {
+                match read_field(&mut reader, &mut buffer) {
+                    Some(bytes) => value = bytes,
+                    None => break, // Truncated entry
+                }
+            }
+        }
+
+        // Read checksum (4 bytes)
+        let mut checksum_bytes = [0u8; 4];
+        if reader.read_exact(&mut checksum_bytes).is_err() {
+            break; // Truncated entry
+        }
+        let stored_checksum = u32::from_be_bytes(checksum_bytes);
+
+        // Validate checksum
+        let computed_checksum = crc32fast::hash(&buffer);
+        if stored_checksum != computed_checksum {
+            break; // Corrupted entry (partial write)
+        }
+        buffer.extend_from_slice(&checksum_bytes);
+
+        entries.push(RawEntry {
+            entry_type,
+            key,
+            value,
+            batch,
+            delta,
+            bytes: buffer,
+        });
+    }
+
+    Ok(entries)
+}
+
+/// Read a length-prefixed field, appending its raw bytes to `buffer`.
+///
+/// Returns `None` if the log ends inside the field.
+fn read_field<R: Read>(reader: &mut R, buffer: &mut Vec<u8>) -> Option<Vec<u8>> {
+    // Length (4 bytes, big-endian)
+    let mut len_bytes = [0u8; 4];
+    reader.read_exact(&mut len_bytes).ok()?;
+    buffer.extend_from_slice(&len_bytes);
+
+    // Data
+    let mut data = vec![0u8; u32::from_be_bytes(len_bytes) as usize];
+    reader.read_exact(&mut data).ok()?;
+    buffer.extend_from_slice(&data);
+
+    Some(data)
+}
+
+/// Serialise a key or value for the log.
+fn serialise<T: Serialize>(value: &T) -> ReedResult<Vec<u8>> {
+    bincode::serialize(value).map_err(|e| ReedError::SerializationError {
+        reason: e.to_string(),
+    })
+}
+
+/// Append a length-prefixed field (4 bytes, big-endian, then data).
+fn push_field(buffer: &mut Vec<u8>, data: &[u8]) {
+    buffer.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    buffer.extend_from_slice(data);
+}
+
+/// Encode an `Insert` entry: type, key, value, checksum.
+fn encode_insert(key: &[u8], value: &[u8]) -> Vec<u8> {
+    let mut buffer = Vec::with_capacity(13 + key.len() + value.len());
+
+    // Entry type (1 byte)
+    buffer.push(EntryType::Insert as u8);
+
+    // Key and value, each length-prefixed
+    push_field(&mut buffer, key);
+    push_field(&mut buffer, value);
+
+    // CRC32 checksum (over entire entry)
+    let checksum = crc32fast::hash(&buffer);
+    buffer.extend_from_slice(&checksum.to_be_bytes());
+
+    buffer
+}
+
+/// Encode a `Delete` entry: type, key, checksum.
+fn encode_delete(key: &[u8]) -> Vec<u8> {
+    let mut buffer = Vec::with_capacity(9 + key.len());
+
+    // Entry type (1 byte)
+    buffer.push(EntryType::Delete as u8);
+
+    // Key, length-prefixed
+    push_field(&mut buffer, key);
+
+    // CRC32 checksum (over entire entry)
+    let checksum = crc32fast::hash(&buffer);
+    buffer.extend_from_slice(&checksum.to_be_bytes());
+
+    buffer
+}
+
+/// Encode a `CountDelta` entry: type, delta, checksum.
+fn encode_count_delta(delta: i64) -> Vec<u8> {
+    let mut buffer = Vec::with_capacity(13);
+
+    // Entry type (1 byte)
+    buffer.push(EntryType::CountDelta as u8);
+
+    // Delta (8 bytes, big-endian)
+    buffer.extend_from_slice(&delta.to_be_bytes());
+
+    // CRC32 checksum (over entire entry)
+    let checksum = crc32fast::hash(&buffer);
+    buffer.extend_from_slice(&checksum.to_be_bytes());
+
+    buffer
+}
+
+/// Open the log for appending, creating it if needed.
+fn open_append(path: &Path) -> ReedResult<File> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .read(true)
+        .open(path)
+        .map_err(|e| ReedError::IoError {
+            operation: "open_wal".to_string(),
+            reason: e.to_string(),
+        })
+}
+
+/// Path of the temp file used while compacting `path` (`{filename}.tmp`).
+fn temp_wal_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(".tmp");
+    PathBuf::from(name)
+}
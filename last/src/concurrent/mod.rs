@@ -11,8 +11,10 @@ pub mod types;
 
 // Re-export public APIs
 pub use lock::{acquire_lock, is_locked, wait_for_unlock, TableLock};
-pub use queue::{count_pending, get_next_pending, queue_write, remove_from_queue};
-pub use types::{CsvRow, PendingWrite, WriteOperation};
+pub use queue::{
+    count_pending, get_next_pending, queue_write, queue_write_deduplicated, remove_from_queue,
+};
+pub use types::{CsvRow, PendingWrite, QueueConfig, WriteOperation};
 
 #[cfg(test)]
 mod lock_test;
@@ -5,8 +5,9 @@
 //!
 //! Queues pending writes when table is locked.
 
-use crate::concurrent::types::PendingWrite;
+use crate::concurrent::types::{PendingWrite, QueueConfig};
 use crate::error::{ReedError, ReedResult};
+use std::collections::BTreeSet;
 use std::fs;
 use std::path::{Path, PathBuf};
 use uuid::Uuid;
@@ -77,6 +78,87 @@ pub fn queue_write(
     Ok(queue_id)
 }
 
+/// Queues a write, collapsing it into a pending write for the same keys.
+///
+/// Looks for the newest pending write that touches any of the new write's
+/// row keys. If that write has the same operation and exactly the same keys,
+/// its rows and timestamp are replaced in place (keeping its queue position)
+/// and its queue ID is returned. Otherwise the write is appended as with
+/// `queue_write()`, so writes to the same key are never reordered.
+///
+/// ## Input
+/// - `base_path`: Path to ReedBase directory
+/// - `table_name`: Table name
+/// - `operation`: Write operation to queue
+/// - `config`: Queue configuration (`deduplication: false` = plain append)
+///
+/// ## Output
+/// - `ReedResult<String>`: Queue ID of the merged or new entry
+///
+/// ## Performance
+/// - O(p) where p = pending writes (each queue file is read once)
+/// - < 20ms typical for a full queue
+///
+/// ## Error Conditions
+/// - QueueFull: Queue has reached maximum size (100 pending) and nothing merged
+/// - IoError: Cannot read or write queue files
+/// - DeserializationError: Corrupted queue file
+///
+/// ## Example Usage
+/// ```no_run
+/// use reedbase_last::concurrent::{queue_write_deduplicated, QueueConfig};
+/// use reedbase_last::concurrent::types::{PendingWrite, WriteOperation, CsvRow};
+/// use std::path::Path;
+///
+/// let write = PendingWrite {
+///     rows: vec![CsvRow::new("user:1", vec!["Alice"])],
+///     timestamp: 1736860900000000000,
+///     operation: WriteOperation::Update,
+/// };
+/// let queue_id =
+///     queue_write_deduplicated(Path::new(".reed"), "users", write, &QueueConfig::default())?;
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn queue_write_deduplicated(
+    base_path: &Path,
+    table_name: &str,
+    operation: PendingWrite,
+    config: &QueueConfig,
+) -> ReedResult<String> {
+    if !config.deduplication || operation.rows.is_empty() {
+        return queue_write(base_path, table_name, operation);
+    }
+
+    let keys: BTreeSet<&str> = operation.rows.iter().map(|r| r.key.as_str()).collect();
+
+    // Newest pending write touching any of these keys
+    let latest = list_pending(base_path, table_name)?
+        .into_iter()
+        .rev()
+        .find(|(_, _, write)| write.rows.iter().any(|r| keys.contains(r.key.as_str())));
+
+    if let Some((queue_id, path, write)) = latest {
+        let pending_keys: BTreeSet<&str> = write.rows.iter().map(|r| r.key.as_str()).collect();
+
+        if write.operation == operation.operation && pending_keys == keys {
+            let json =
+                serde_json::to_string(&operation).map_err(|e| ReedError::SerializationError {
+                    reason: format!("Failed to serialise write: {}", e),
+                })?;
+
+            // Overwrite in place so the entry keeps its queue position
+            fs::write(&path, json).map_err(|e| ReedError::IoError {
+                operation: "write_queue_file".to_string(),
+                reason: e.to_string(),
+            })?;
+
+            return Ok(queue_id);
+        }
+    }
+
+    queue_write(base_path, table_name, operation)
+}
+
 /// Gets next pending write from queue.
 ///
 /// ## Input
@@ -231,6 +313,61 @@ pub fn count_pending(base_path: &Path, table_name: &str) -> ReedResult<usize> {
     Ok(count)
 }
 
+/// Reads all pending writes, oldest first.
+///
+/// ## Output
+/// - `ReedResult<Vec<(String, PathBuf, PendingWrite)>>`: (queue_id, path, write)
+///
+/// ## Performance
+/// - O(p) where p = pending writes
+fn list_pending(
+    base_path: &Path,
+    table_name: &str,
+) -> ReedResult<Vec<(String, PathBuf, PendingWrite)>> {
+    let queue_dir = get_queue_dir(base_path, table_name);
+
+    if !queue_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut entries: Vec<_> = fs::read_dir(&queue_dir)
+        .map_err(|e| ReedError::IoError {
+            operation: "read_queue_dir".to_string(),
+            reason: e.to_string(),
+        })?
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().extension().and_then(|s| s.to_str()) == Some("pending"))
+        .collect();
+
+    // Same ordering as get_next_pending (creation time)
+    entries.sort_by_key(|e| e.metadata().ok().and_then(|m| m.created().ok()));
+
+    let mut pending = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let path = entry.path();
+        let queue_id = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .ok_or_else(|| ReedError::InvalidQueueFile {
+                path: path.to_string_lossy().to_string(),
+            })?
+            .to_string();
+
+        let json = fs::read_to_string(&path).map_err(|e| ReedError::IoError {
+            operation: "read_queue_file".to_string(),
+            reason: e.to_string(),
+        })?;
+        let write: PendingWrite =
+            serde_json::from_str(&json).map_err(|e| ReedError::DeserializationError {
+                reason: format!("Failed to deserialise write: {}", e),
+            })?;
+
+        pending.push((queue_id, path, write));
+    }
+
+    Ok(pending)
+}
+
 /// Gets queue directory path.
 ///
 /// ## Input
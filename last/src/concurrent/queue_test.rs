@@ -6,9 +6,9 @@
 #[cfg(test)]
 mod tests {
     use crate::concurrent::queue::{
-        count_pending, get_next_pending, queue_write, remove_from_queue,
+        count_pending, get_next_pending, queue_write, queue_write_deduplicated, remove_from_queue,
     };
-    use crate::concurrent::types::{CsvRow, PendingWrite, WriteOperation};
+    use crate::concurrent::types::{CsvRow, PendingWrite, QueueConfig, WriteOperation};
     use crate::error::ReedError;
     use std::thread;
     use std::time::Duration;
@@ -242,4 +242,110 @@ mod tests {
             assert!(timestamps[i] < timestamps[i + 1]);
         }
     }
+
+    fn update(key: &str, value: &str, timestamp: u64) -> PendingWrite {
+        PendingWrite {
+            rows: vec![CsvRow::new(key, vec![value])],
+            timestamp,
+            operation: WriteOperation::Update,
+        }
+    }
+
+    #[test]
+    fn test_queue_deduplicated_same_key_replaces_value() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        let config = QueueConfig::default();
+
+        let id1 = queue_write_deduplicated(base_path, "t", update("k", "v1", 1), &config).unwrap();
+        let id2 = queue_write_deduplicated(base_path, "t", update("k", "v2", 2), &config).unwrap();
+
+        assert_eq!(id1, id2);
+        assert_eq!(count_pending(base_path, "t").unwrap(), 1);
+
+        let (_, write) = get_next_pending(base_path, "t").unwrap().unwrap();
+        assert_eq!(write.rows[0].values, vec!["v2"]);
+        assert_eq!(write.timestamp, 2);
+    }
+
+    #[test]
+    fn test_queue_deduplicated_different_keys_append() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        let config = QueueConfig::default();
+
+        queue_write_deduplicated(base_path, "t", update("a", "1", 1), &config).unwrap();
+        queue_write_deduplicated(base_path, "t", update("b", "2", 2), &config).unwrap();
+
+        assert_eq!(count_pending(base_path, "t").unwrap(), 2);
+    }
+
+    #[test]
+    fn test_queue_deduplicated_keeps_order_across_operations() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        let config = QueueConfig::default();
+
+        queue_write_deduplicated(base_path, "t", update("k", "v1", 1), &config).unwrap();
+        thread::sleep(Duration::from_millis(10));
+        let delete = PendingWrite {
+            rows: vec![CsvRow::new("k", vec![])],
+            timestamp: 2,
+            operation: WriteOperation::Delete,
+        };
+        queue_write_deduplicated(base_path, "t", delete, &config).unwrap();
+        thread::sleep(Duration::from_millis(10));
+
+        // Must not be folded into the update before the delete
+        queue_write_deduplicated(base_path, "t", update("k", "v3", 3), &config).unwrap();
+        assert_eq!(count_pending(base_path, "t").unwrap(), 3);
+    }
+
+    #[test]
+    fn test_queue_deduplication_disabled() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        let config = QueueConfig {
+            deduplication: false,
+        };
+
+        for i in 0..3 {
+            queue_write_deduplicated(base_path, "t", update("k", "v", i), &config).unwrap();
+        }
+        assert_eq!(count_pending(base_path, "t").unwrap(), 3);
+    }
+
+    #[test]
+    fn test_queue_deduplicated_rapid_writes_single_version() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        crate::registry::set_base_path(base_path.to_path_buf());
+        crate::registry::init_registry(base_path).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+
+        let table = crate::tables::Table::new(base_path, "text");
+        table.init(b"key|value\nk|v0\n", "testuser").unwrap();
+        let config = QueueConfig::default();
+
+        for i in 1..=100 {
+            let value = format!("v{}", i);
+            queue_write_deduplicated(base_path, "text", update("k", &value, i), &config).unwrap();
+        }
+        assert_eq!(count_pending(base_path, "text").unwrap(), 1);
+
+        // Drain queue, one table write per pending entry
+        while let Some((id, write)) = get_next_pending(base_path, "text").unwrap() {
+            let mut content = String::from("key|value\n");
+            for row in &write.rows {
+                content.push_str(&row.to_csv());
+                content.push('\n');
+            }
+            table.write(content.as_bytes(), "testuser").unwrap();
+            remove_from_queue(base_path, "text", &id).unwrap();
+        }
+
+        // Init plus exactly one write
+        assert_eq!(table.list_versions().unwrap().len(), 2);
+        assert_eq!(table.read_current().unwrap(), b"key|value\nk|v100\n");
+    }
 }
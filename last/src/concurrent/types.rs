@@ -18,6 +18,22 @@ pub struct PendingWrite {
     pub operation: WriteOperation,
 }
 
+/// Write queue configuration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueueConfig {
+    /// Collapse a queued write into the newest pending write for the same
+    /// keys instead of appending a new entry (default: true).
+    pub deduplication: bool,
+}
+
+impl Default for QueueConfig {
+    fn default() -> Self {
+        Self {
+            deduplication: true,
+        }
+    }
+}
+
 /// Type of write operation.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WriteOperation {
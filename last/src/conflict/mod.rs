@@ -8,7 +8,8 @@
 //! and manual conflict file management.
 //!
 //! ## Key Features
-//! - Multiple resolution strategies (LastWriteWins, FirstWriteWins, Manual, KeepBoth)
+//! - Multiple resolution strategies (LastWriteWins, FirstWriteWins, Manual, KeepBoth,
+//!   Custom)
 //! - TOML-based conflict files for human readability
 //! - Automatic and manual conflict resolution
 //! - Conflict file management (list, load, delete)
@@ -51,7 +52,7 @@ mod resolution_test;
 
 // Re-export commonly used types
 pub use resolution::{
-    count_conflicts, delete_conflict_file, list_conflicts, load_conflict_file, resolve_conflict,
-    write_conflict_file,
+    count_conflicts, delete_conflict_file, list_conflicts, load_conflict_file,
+    read_conflict_file_safe, resolve_conflict, write_conflict_file,
 };
-pub use types::{ConflictFile, Resolution, ResolutionStrategy};
+pub use types::{ConflictFile, MergeFn, Resolution, ResolutionStrategy};
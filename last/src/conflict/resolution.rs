@@ -9,7 +9,8 @@
 use crate::concurrent::types::CsvRow;
 use crate::conflict::types::{ConflictFile, Resolution, ResolutionStrategy};
 use crate::error::{ReedError, ReedResult};
-use std::fs;
+use std::fs::{self, File};
+use std::io::Write;
 use std::path::{Path, PathBuf};
 use toml;
 
@@ -22,7 +23,8 @@ use toml;
 /// - `base`: Base version (optional)
 /// - `change_a`: First change
 /// - `change_b`: Second change
-/// - `strategy`: Resolution strategy
+/// - `strategy`: Resolution strategy (`Custom` is called with base, change_a
+///   and change_b; a missing base is passed as the key without values)
 ///
 /// ## Output
 /// - `Ok(Resolution)`: Resolution result (Automatic, Manual, or KeepBoth)
@@ -32,6 +34,7 @@ use toml;
 /// - LastWriteWins/FirstWriteWins: < 1ms (O(1) operation)
 /// - Manual: < 20ms (includes file I/O)
 /// - KeepBoth: < 5ms (creates two rows)
+/// - Custom: cost of the merge function
 ///
 /// ## Error Conditions
 /// - `IoError`: Failed to write manual conflict file
@@ -82,6 +85,14 @@ pub fn resolve_conflict(
             row_b.key = format!("{}-b", key);
             Ok(Resolution::KeepBoth(row_a, row_b))
         }
+        ResolutionStrategy::Custom(merge) => {
+            // Without a base, the merge sees the key with no values
+            let base = base.unwrap_or_else(|| CsvRow {
+                key: key.to_string(),
+                values: Vec::new(),
+            });
+            Ok(Resolution::Automatic(merge(&base, &change_a, &change_b)))
+        }
     }
 }
 
@@ -101,7 +112,11 @@ pub fn resolve_conflict(
 /// - `Err(ReedError)`: If file creation fails
 ///
 /// ## Performance
-/// - < 20ms typical (includes directory creation and TOML serialization)
+/// - < 20ms typical (includes directory creation, TOML serialization and fsync)
+///
+/// ## Crash Safety
+/// - TOML is written to `{filename}.tmp`, synced, then renamed into place,
+///   so the `.conflict` file is either absent or complete
 ///
 /// ## Error Conditions
 /// - `IoError`: Failed to create directory or write file
@@ -151,16 +166,36 @@ pub fn write_conflict_file(
             reason: format!("Failed to serialize conflict to TOML: {}", e),
         })?;
 
-    // Write to file
+    // Write to temp file, sync, then rename atomically
     let filepath = conflict_dir.join(conflict.filename());
-    fs::write(&filepath, toml_string).map_err(|e| ReedError::IoError {
-        operation: format!("write conflict file '{}'", filepath.display()),
+    let temp_path = temp_conflict_path(&filepath);
+
+    let write_temp = || -> std::io::Result<()> {
+        let mut file = File::create(&temp_path)?;
+        file.write_all(toml_string.as_bytes())?;
+        file.flush()?;
+        file.sync_all()
+    };
+    write_temp().map_err(|e| ReedError::IoError {
+        operation: format!("write conflict file '{}'", temp_path.display()),
+        reason: e.to_string(),
+    })?;
+
+    fs::rename(&temp_path, &filepath).map_err(|e| ReedError::IoError {
+        operation: format!("rename conflict file '{}'", filepath.display()),
         reason: e.to_string(),
     })?;
 
     Ok(filepath.display().to_string())
 }
 
+/// Path of the temp file used while writing `filepath` (`{filename}.tmp`).
+fn temp_conflict_path(filepath: &Path) -> PathBuf {
+    let mut name = filepath.as_os_str().to_os_string();
+    name.push(".tmp");
+    PathBuf::from(name)
+}
+
 /// Load a conflict file from disk.
 ///
 /// ## Input
@@ -197,6 +232,37 @@ pub fn load_conflict_file(filepath: &Path) -> ReedResult<ConflictFile> {
     Ok(conflict)
 }
 
+/// Load a conflict file, falling back to its temp file.
+///
+/// If the `.conflict` file is missing or cannot be parsed (e.g. truncated by
+/// a crash), `{filename}.tmp` left behind by `write_conflict_file` is tried
+/// instead.
+///
+/// ## Input
+/// - `path`: Path to the conflict file
+///
+/// ## Output
+/// - `Ok(ConflictFile)`: Parsed conflict file (from `path` or its temp file)
+/// - `Err(ReedError)`: Original error if the fallback fails too
+///
+/// ## Performance
+/// - < 10ms typical, < 20ms when falling back
+///
+/// ## Error Conditions
+/// - `IoError`: Neither file could be read
+/// - `DeserializationError`: Neither file could be parsed
+///
+/// ## Example Usage
+/// ```rust
+/// let conflict = read_conflict_file_safe(Path::new(".reed/tables/text/conflicts/1234567890-test.key.conflict"))?;
+/// ```
+pub fn read_conflict_file_safe(path: &Path) -> ReedResult<ConflictFile> {
+    match load_conflict_file(path) {
+        Ok(conflict) => Ok(conflict),
+        Err(e) => load_conflict_file(&temp_conflict_path(path)).map_err(|_| e),
+    }
+}
+
 /// List all conflict files for a table.
 ///
 /// ## Input
@@ -8,7 +8,7 @@ mod tests {
     use crate::concurrent::types::CsvRow;
     use crate::conflict::resolution::{
         count_conflicts, delete_conflict_file, list_conflicts, load_conflict_file,
-        resolve_conflict, write_conflict_file,
+        read_conflict_file_safe, resolve_conflict, write_conflict_file,
     };
     use crate::conflict::types::{Resolution, ResolutionStrategy};
     use std::fs;
@@ -103,6 +103,52 @@ mod tests {
         assert_eq!(rows[1].values, change_b.values);
     }
 
+    #[test]
+    fn test_resolve_conflict_custom() {
+        let temp = TempDir::new().unwrap();
+        let base_path = temp.path();
+
+        // Counter: apply both deltas to the base
+        let count = |row: &CsvRow| row.values.first().map_or(0, |v| v.parse().unwrap_or(0));
+        let strategy = ResolutionStrategy::custom(move |base, a, b| CsvRow {
+            key: a.key.clone(),
+            values: vec![(count(a) + count(b) - count(base)).to_string()],
+        });
+
+        let resolution = resolve_conflict(
+            base_path,
+            "counters",
+            "page.views",
+            Some(create_test_row("page.views", "10")),
+            create_test_row("page.views", "13"),
+            create_test_row("page.views", "15"),
+            strategy.clone(),
+        )
+        .unwrap();
+        assert!(resolution.is_automatic());
+        assert_eq!(
+            resolution.into_rows(),
+            vec![create_test_row("page.views", "18")]
+        );
+
+        // Missing base: key without values
+        let resolution = resolve_conflict(
+            base_path,
+            "counters",
+            "page.views",
+            None,
+            create_test_row("page.views", "3"),
+            create_test_row("page.views", "4"),
+            strategy,
+        )
+        .unwrap();
+        assert_eq!(
+            resolution.into_rows(),
+            vec![create_test_row("page.views", "7")]
+        );
+        assert_eq!(fs::read_dir(base_path).unwrap().count(), 0);
+    }
+
     #[test]
     fn test_resolve_conflict_manual() {
         let temp = TempDir::new().unwrap();
@@ -482,4 +528,65 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_write_conflict_file_leaves_no_temp_file() {
+        let temp = TempDir::new().unwrap();
+        let base_path = temp.path();
+
+        let filepath = write_conflict_file(
+            base_path,
+            "text",
+            "test.key",
+            None,
+            create_test_row("test.key", "a"),
+            create_test_row("test.key", "b"),
+            ResolutionStrategy::Manual,
+        )
+        .unwrap();
+
+        let entries: Vec<_> = fs::read_dir(base_path.join("tables/text/conflicts"))
+            .unwrap()
+            .map(|e| e.unwrap().path())
+            .collect();
+        assert_eq!(entries, vec![PathBuf::from(&filepath)]);
+    }
+
+    #[test]
+    fn test_read_conflict_file_safe_recovers_from_truncation() {
+        let temp = TempDir::new().unwrap();
+        let base_path = temp.path();
+
+        let filepath = PathBuf::from(
+            write_conflict_file(
+                base_path,
+                "text",
+                "test.key",
+                Some(create_test_row("test.key", "old")),
+                create_test_row("test.key", "new_a"),
+                create_test_row("test.key", "new_b"),
+                ResolutionStrategy::Manual,
+            )
+            .unwrap(),
+        );
+        let content = fs::read(&filepath).unwrap();
+
+        // Simulate a crash mid-write: temp file complete, target cut in half
+        let temp_path = PathBuf::from(format!("{}.tmp", filepath.display()));
+        fs::write(&temp_path, &content).unwrap();
+        fs::write(&filepath, &content[..content.len() / 2]).unwrap();
+
+        assert!(load_conflict_file(&filepath).is_err());
+        let conflict = read_conflict_file_safe(&filepath).unwrap();
+        assert_eq!(conflict.metadata.key, "test.key");
+        assert_eq!(conflict.change_b.values, vec!["new_b"]);
+
+        // Crash before rename: only the temp file exists
+        fs::remove_file(&filepath).unwrap();
+        assert!(read_conflict_file_safe(&filepath).is_ok());
+
+        // No usable fallback: original error is returned
+        fs::remove_file(&temp_path).unwrap();
+        assert!(read_conflict_file_safe(&filepath).is_err());
+    }
 }
@@ -7,7 +7,14 @@
 //! including resolution strategies and conflict representation.
 
 use crate::concurrent::types::CsvRow;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::sync::Arc;
+
+/// Merge function of `ResolutionStrategy::Custom`.
+///
+/// Called with base, change_a and change_b; returns the resolved row.
+pub type MergeFn = Arc<dyn Fn(&CsvRow, &CsvRow, &CsvRow) -> CsvRow + Send + Sync>;
 
 /// Resolution strategy for handling conflicts.
 ///
@@ -16,17 +23,84 @@ use serde::{Deserialize, Serialize};
 /// - `FirstWriteWins`: Keep the earlier change (change_a)
 /// - `Manual`: Write conflict to file for human resolution
 /// - `KeepBoth`: Create two separate rows (append suffix to keys)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// - `Custom`: Merge with an application-supplied function
+///
+/// ## Serialization
+/// `Custom` is written as `"custom"`. A closure cannot be restored, so
+/// reading `"custom"` back fails: after a restart the conflict has to be
+/// resolved again with a `Custom` strategy built in code.
+#[derive(Clone, Default)]
 pub enum ResolutionStrategy {
+    #[default]
     LastWriteWins,
     FirstWriteWins,
     Manual,
     KeepBoth,
+    Custom(MergeFn),
+}
+
+impl fmt::Debug for ResolutionStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResolutionStrategy::LastWriteWins => write!(f, "LastWriteWins"),
+            ResolutionStrategy::FirstWriteWins => write!(f, "FirstWriteWins"),
+            ResolutionStrategy::Manual => write!(f, "Manual"),
+            ResolutionStrategy::KeepBoth => write!(f, "KeepBoth"),
+            ResolutionStrategy::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
 }
 
-impl Default for ResolutionStrategy {
-    fn default() -> Self {
-        ResolutionStrategy::LastWriteWins
+/// Equal if both are the same variant; `Custom` only if both share one
+/// merge function.
+impl PartialEq for ResolutionStrategy {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ResolutionStrategy::Custom(a), ResolutionStrategy::Custom(b)) => Arc::ptr_eq(a, b),
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+impl Eq for ResolutionStrategy {}
+
+/// Serialized form of `ResolutionStrategy` (the merge function is dropped).
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "ResolutionStrategy")]
+enum StoredStrategy {
+    LastWriteWins,
+    FirstWriteWins,
+    Manual,
+    KeepBoth,
+    #[serde(rename = "custom")]
+    Custom,
+}
+
+impl Serialize for ResolutionStrategy {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ResolutionStrategy::LastWriteWins => StoredStrategy::LastWriteWins,
+            ResolutionStrategy::FirstWriteWins => StoredStrategy::FirstWriteWins,
+            ResolutionStrategy::Manual => StoredStrategy::Manual,
+            ResolutionStrategy::KeepBoth => StoredStrategy::KeepBoth,
+            ResolutionStrategy::Custom(_) => StoredStrategy::Custom,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ResolutionStrategy {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match StoredStrategy::deserialize(deserializer)? {
+            StoredStrategy::LastWriteWins => Ok(ResolutionStrategy::LastWriteWins),
+            StoredStrategy::FirstWriteWins => Ok(ResolutionStrategy::FirstWriteWins),
+            StoredStrategy::Manual => Ok(ResolutionStrategy::Manual),
+            StoredStrategy::KeepBoth => Ok(ResolutionStrategy::KeepBoth),
+            StoredStrategy::Custom => Err(serde::de::Error::custom(
+                "custom resolution strategy cannot be restored; resolve again with \
+                 ResolutionStrategy::Custom",
+            )),
+        }
     }
 }
 
@@ -38,10 +112,14 @@ impl ResolutionStrategy {
             ResolutionStrategy::FirstWriteWins => "first-write-wins",
             ResolutionStrategy::Manual => "manual",
             ResolutionStrategy::KeepBoth => "keep-both",
+            ResolutionStrategy::Custom(_) => "custom",
         }
     }
 
     /// Parse strategy from string.
+    ///
+    /// `"custom"` yields `None`: the merge function cannot be recreated
+    /// from its name.
     pub fn from_name(name: &str) -> Option<Self> {
         match name {
             "last-write-wins" => Some(ResolutionStrategy::LastWriteWins),
@@ -51,6 +129,30 @@ impl ResolutionStrategy {
             _ => None,
         }
     }
+
+    /// Create a `Custom` strategy from a merge function.
+    ///
+    /// ## Example Usage
+    /// ```rust
+    /// use reedbase_last::concurrent::types::CsvRow;
+    /// use reedbase_last::conflict::ResolutionStrategy;
+    ///
+    /// // Counter column: apply the deltas of both changes to the base
+    /// let sum_deltas = ResolutionStrategy::custom(|base, a, b| {
+    ///     let n = |row: &CsvRow| row.values[0].parse::<i64>().unwrap_or(0);
+    ///     CsvRow {
+    ///         key: base.key.clone(),
+    ///         values: vec![(n(a) + n(b) - n(base)).to_string()],
+    ///     }
+    /// });
+    /// assert_eq!(sum_deltas.name(), "custom");
+    /// ```
+    pub fn custom<F>(merge: F) -> Self
+    where
+        F: Fn(&CsvRow, &CsvRow, &CsvRow) -> CsvRow + Send + Sync + 'static,
+    {
+        ResolutionStrategy::Custom(Arc::new(merge))
+    }
 }
 
 /// Result of conflict resolution.
@@ -205,6 +307,29 @@ mod tests {
         );
         assert_eq!(ResolutionStrategy::Manual.name(), "manual");
         assert_eq!(ResolutionStrategy::KeepBoth.name(), "keep-both");
+        assert_eq!(
+            ResolutionStrategy::custom(|_, a, _| a.clone()).name(),
+            "custom"
+        );
+    }
+
+    #[test]
+    fn test_resolution_strategy_custom() {
+        let strategy = ResolutionStrategy::custom(|_, _, b| b.clone());
+        assert_eq!(strategy, strategy.clone());
+        assert_ne!(strategy, ResolutionStrategy::custom(|_, _, b| b.clone()));
+        assert_ne!(strategy, ResolutionStrategy::LastWriteWins);
+        assert_eq!(format!("{:?}", strategy), "Custom(..)");
+        assert_eq!(ResolutionStrategy::from_name("custom"), None);
+
+        // Serialised by name; the function cannot be read back
+        let json = serde_json::to_string(&strategy).unwrap();
+        assert_eq!(json, "\"custom\"");
+        assert!(serde_json::from_str::<ResolutionStrategy>(&json).is_err());
+        assert_eq!(
+            serde_json::from_str::<ResolutionStrategy>("\"Manual\"").unwrap(),
+            ResolutionStrategy::Manual
+        );
     }
 
     #[test]
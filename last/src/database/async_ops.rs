@@ -0,0 +1,141 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Async wrappers for Database operations (feature `tokio`).
+//!
+//! ReedBase itself is synchronous: every query reads CSV files and every
+//! command writes them. The async variants move that work onto tokio's
+//! blocking thread pool via `spawn_blocking`, so callers in async frameworks
+//! (axum, actix) do not stall the executor.
+//!
+//! ## Example Usage
+//! ```no_run
+//! use reedbase_last::database::Database;
+//!
+//! # async fn run() -> Result<(), reedbase_last::ReedError> {
+//! let db = Database::open(".reed")?;
+//! let result = db.query_async("SELECT * FROM text WHERE key = 'page.title'").await?;
+//! db.execute_async("UPDATE text SET value = 'New' WHERE key = 'page.title'", "admin")
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::database::execute::ExecuteResult;
+use crate::database::Database;
+use crate::error::{ReedError, ReedResult};
+use crate::reedql::QueryResult;
+
+impl Database {
+    /// Executes a ReedQL query without blocking the async executor.
+    ///
+    /// Runs `query()` on tokio's blocking pool against a clone of this
+    /// handle (clones share tables, indices and statistics).
+    ///
+    /// ## Input
+    /// - `sql`: ReedQL SELECT query
+    ///
+    /// ## Output
+    /// - `Ok(QueryResult)`: Query results
+    /// - `Err(ReedError)`: Query failed
+    ///
+    /// ## Performance
+    /// - Same as `query()` plus ~10-50μs task hand-off
+    ///
+    /// ## Error Conditions
+    /// - Same as `query()`
+    /// - `CommandFailed`: Blocking task panicked or was cancelled
+    pub async fn query_async(&self, sql: &str) -> ReedResult<QueryResult> {
+        let db = self.clone();
+        let query = sql.to_string();
+
+        tokio::task::spawn_blocking(move || db.query(&query))
+            .await
+            .map_err(|e| join_error(sql, e))?
+    }
+
+    /// Executes a ReedQL command without blocking the async executor.
+    ///
+    /// Runs `execute()` on tokio's blocking pool against a clone of this
+    /// handle.
+    ///
+    /// ## Input
+    /// - `sql`: ReedQL INSERT/UPDATE/DELETE statement
+    /// - `user`: Username for audit trail
+    ///
+    /// ## Output
+    /// - `Ok(ExecuteResult)`: Execution results
+    /// - `Err(ReedError)`: Execution failed
+    ///
+    /// ## Performance
+    /// - Same as `execute()` plus ~10-50μs task hand-off
+    ///
+    /// ## Error Conditions
+    /// - Same as `execute()`
+    /// - `CommandFailed`: Blocking task panicked or was cancelled
+    pub async fn execute_async(&self, sql: &str, user: &str) -> ReedResult<ExecuteResult> {
+        let db = self.clone();
+        let statement = sql.to_string();
+        let user = user.to_string();
+
+        tokio::task::spawn_blocking(move || db.execute(&statement, &user))
+            .await
+            .map_err(|e| join_error(sql, e))?
+    }
+}
+
+fn join_error(sql: &str, e: tokio::task::JoinError) -> ReedError {
+    ReedError::CommandFailed {
+        command: sql.to_string(),
+        error: format!("Blocking task failed: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::init_registry;
+    use std::fs;
+
+    #[test]
+    fn test_database_is_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Database>();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_query_and_execute_async() {
+        let temp_dir = std::env::temp_dir().join("reedbase_async_test");
+        let _ = fs::remove_dir_all(&temp_dir);
+        crate::registry::set_base_path(temp_dir.clone());
+        init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+
+        let db = Database::open(&temp_dir).unwrap();
+        crate::tables::Table::new(&temp_dir, "text")
+            .init(b"key|value\npage.title|Welcome\n", "testuser")
+            .unwrap();
+
+        let result = db
+            .execute_async(
+                "UPDATE text SET value = 'Hello' WHERE key = 'page.title'",
+                "testuser",
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.rows_affected, 1);
+
+        let result = db
+            .query_async("SELECT value FROM text WHERE key = 'page.title'")
+            .await
+            .unwrap();
+        match result {
+            QueryResult::Rows(rows) => assert_eq!(rows[0]["value"], "Hello"),
+            other => panic!("Expected rows, got {:?}", other),
+        }
+
+        assert!(db.query_async("SELECT FROM").await.is_err());
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+}
@@ -0,0 +1,353 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Query result cache.
+//!
+//! Keeps the results of recent SELECT queries, keyed by normalised SQL, so
+//! repeated reads skip parsing the CSV. Entries expire after a fixed TTL and
+//! are dropped as soon as one of the tables they read is written through
+//! `Database::execute()`. Enabled with `Database::enable_query_cache()`.
+
+use crate::reedql::QueryResult;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+/// Fixed-capacity map that evicts the least recently used entry.
+///
+/// ## Performance
+/// - get/insert/remove: O(log n)
+#[derive(Debug)]
+pub(crate) struct LruCache<K, V> {
+    /// Maximum number of entries
+    capacity: usize,
+
+    /// Key → (value, last use)
+    entries: HashMap<K, (V, u64)>,
+
+    /// Last use → key, oldest first
+    order: BTreeMap<u64, K>,
+
+    /// Use counter
+    tick: u64,
+}
+
+impl<K: Hash + Eq + Clone, V> LruCache<K, V> {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            order: BTreeMap::new(),
+            tick: 0,
+        }
+    }
+
+    /// Looks up a value and marks it as recently used.
+    pub(crate) fn get(&mut self, key: &K) -> Option<&V> {
+        self.get_mut(key).map(|value| &*value)
+    }
+
+    /// Looks up a value for modification and marks it as recently used.
+    pub(crate) fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.tick += 1;
+        let (value, last_use) = self.entries.get_mut(key)?;
+        self.order.remove(last_use);
+        self.order.insert(self.tick, key.clone());
+        *last_use = self.tick;
+        Some(value)
+    }
+
+    /// Inserts or replaces a value.
+    ///
+    /// ## Output
+    /// - Number of entries evicted to make room (0 or 1)
+    pub(crate) fn insert(&mut self, key: K, value: V) -> usize {
+        if self.capacity == 0 {
+            return 0;
+        }
+        self.remove(&key);
+
+        let mut evicted = 0;
+        if self.entries.len() >= self.capacity {
+            if let Some((_, oldest)) = self.order.pop_first() {
+                self.entries.remove(&oldest);
+                evicted = 1;
+            }
+        }
+
+        self.tick += 1;
+        self.order.insert(self.tick, key.clone());
+        self.entries.insert(key, (value, self.tick));
+        evicted
+    }
+
+    /// Removes a value.
+    pub(crate) fn remove(&mut self, key: &K) -> Option<V> {
+        let (value, last_use) = self.entries.remove(key)?;
+        self.order.remove(&last_use);
+        Some(value)
+    }
+
+    /// Changes the capacity, evicting least recently used entries to fit.
+    ///
+    /// ## Output
+    /// - Number of entries evicted
+    pub(crate) fn set_capacity(&mut self, capacity: usize) -> usize {
+        self.capacity = capacity;
+        let mut evicted = 0;
+        while self.entries.len() > capacity {
+            match self.order.pop_first() {
+                Some((_, oldest)) => {
+                    self.entries.remove(&oldest);
+                    evicted += 1;
+                }
+                None => break,
+            }
+        }
+        evicted
+    }
+
+    /// Number of entries.
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Iterates over all entries without marking them as used.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.entries.iter().map(|(key, (value, _))| (key, value))
+    }
+
+    /// Removes all entries.
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+
+    /// Keeps only the entries for which `keep` returns true.
+    pub(crate) fn retain(&mut self, mut keep: impl FnMut(&K, &V) -> bool) {
+        let order = &mut self.order;
+        self.entries.retain(|key, (value, last_use)| {
+            let kept = keep(key, value);
+            if !kept {
+                order.remove(last_use);
+            }
+            kept
+        });
+    }
+}
+
+/// Cached result of one query.
+#[derive(Debug)]
+struct CachedResult {
+    /// Query result
+    result: QueryResult,
+
+    /// When the result was stored
+    cached_at: Instant,
+
+    /// Tables the query read (FROM, JOIN and subqueries)
+    tables: Vec<String>,
+}
+
+/// Query results with TTL expiry and per-table invalidation.
+#[derive(Debug)]
+pub(crate) struct QueryCache {
+    /// Normalised SQL → result
+    entries: LruCache<String, CachedResult>,
+
+    /// Lifetime of an entry
+    ttl: Duration,
+
+    /// Lookups answered from the cache
+    hits: usize,
+
+    /// Lookups without a (fresh) entry
+    misses: usize,
+
+    /// Entries dropped to stay within capacity
+    evictions: usize,
+}
+
+impl QueryCache {
+    pub(crate) fn new(max_entries: usize, ttl: Duration) -> Self {
+        Self {
+            entries: LruCache::new(max_entries),
+            ttl,
+            hits: 0,
+            misses: 0,
+            evictions: 0,
+        }
+    }
+
+    /// Returns a fresh cached result; expired entries are dropped.
+    pub(crate) fn get(&mut self, sql: &str) -> Option<QueryResult> {
+        let key = normalise_sql(sql);
+        let fresh = self
+            .entries
+            .get(&key)
+            .map(|cached| cached.cached_at.elapsed() < self.ttl);
+
+        match fresh {
+            Some(true) => {
+                self.hits += 1;
+                self.entries.get(&key).map(|cached| cached.result.clone())
+            }
+            Some(false) => {
+                self.entries.remove(&key);
+                self.misses += 1;
+                None
+            }
+            None => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// Stores the result of a query reading `tables`.
+    pub(crate) fn insert(&mut self, sql: &str, result: QueryResult, tables: Vec<String>) {
+        let cached = CachedResult {
+            result,
+            cached_at: Instant::now(),
+            tables,
+        };
+        self.evictions += self.entries.insert(normalise_sql(sql), cached);
+    }
+
+    /// Drops every result that read `table`.
+    pub(crate) fn invalidate_table(&mut self, table: &str) {
+        self.entries
+            .retain(|_, cached| !cached.tables.iter().any(|t| t == table));
+    }
+
+    /// (hits, misses, evictions) since the cache was enabled.
+    pub(crate) fn stats(&self) -> (usize, usize, usize) {
+        (self.hits, self.misses, self.evictions)
+    }
+}
+
+/// Normalises SQL for use as a cache key.
+///
+/// Trims the statement and collapses whitespace runs outside quoted
+/// literals to one space, so formatting differences share an entry while
+/// `'a  b'` and `'a b'` stay distinct.
+pub(crate) fn normalise_sql(sql: &str) -> String {
+    let sql = sql.trim();
+    let mut normalised = String::with_capacity(sql.len());
+    let mut in_quotes = false;
+    let mut pending_space = false;
+
+    for c in sql.chars() {
+        if !in_quotes && c.is_whitespace() {
+            pending_space = true;
+            continue;
+        }
+        if pending_space {
+            normalised.push(' ');
+            pending_space = false;
+        }
+        if c == '\'' {
+            in_quotes = !in_quotes;
+        }
+        normalised.push(c);
+    }
+
+    normalised
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn rows(value: &str) -> QueryResult {
+        QueryResult::Rows(vec![HashMap::from([(
+            "key".to_string(),
+            value.to_string(),
+        )])])
+    }
+
+    fn key(result: Option<QueryResult>) -> Option<String> {
+        match result {
+            Some(QueryResult::Rows(rows)) => Some(rows[0]["key"].clone()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_lru_evicts_least_recently_used() {
+        let mut lru = LruCache::new(2);
+        assert_eq!(lru.insert("a", 1), 0);
+        assert_eq!(lru.insert("b", 2), 0);
+
+        // Reading "a" makes "b" the oldest
+        assert_eq!(lru.get(&"a"), Some(&1));
+        assert_eq!(lru.insert("c", 3), 1);
+        assert_eq!(lru.get(&"b"), None);
+        assert_eq!(lru.get(&"a"), Some(&1));
+
+        // Replacing does not evict
+        assert_eq!(lru.insert("c", 4), 0);
+        assert_eq!(lru.get(&"c"), Some(&4));
+        assert_eq!(lru.entries.len(), 2);
+
+        lru.retain(|key, _| *key != "a");
+        assert_eq!(lru.entries.len(), 1);
+        assert_eq!(lru.insert("d", 5), 0);
+
+        // Shrinking evicts the least recently used first
+        assert_eq!(lru.insert("e", 6), 1);
+        assert_eq!(lru.set_capacity(1), 1);
+        assert_eq!(lru.get(&"d"), None);
+        assert_eq!(lru.iter().collect::<Vec<_>>(), vec![(&"e", &6)]);
+        lru.clear();
+        assert_eq!(lru.len(), 0);
+
+        let mut empty = LruCache::new(0);
+        assert_eq!(empty.insert("a", 1), 0);
+        assert_eq!(empty.entries.len(), 0);
+    }
+
+    #[test]
+    fn test_query_cache_ttl_and_invalidation() {
+        let mut cache = QueryCache::new(10, Duration::from_secs(60));
+        let join = "SELECT * FROM text JOIN routes ON text.key = routes.text_key";
+        cache.insert("SELECT * FROM text", rows("t"), vec!["text".to_string()]);
+        cache.insert(
+            join,
+            rows("j"),
+            vec!["text".to_string(), "routes".to_string()],
+        );
+        cache.insert("SELECT * FROM users", rows("u"), vec!["users".to_string()]);
+
+        assert_eq!(
+            key(cache.get("  SELECT *\n FROM text")),
+            Some("t".to_string())
+        );
+        assert_eq!(key(cache.get("SELECT * FROM other")), None);
+
+        // A write to text drops every result that read it
+        cache.invalidate_table("text");
+        assert_eq!(key(cache.get("SELECT * FROM text")), None);
+        assert_eq!(key(cache.get(join)), None);
+        assert_eq!(key(cache.get("SELECT * FROM users")), Some("u".to_string()));
+        assert_eq!(cache.stats(), (2, 3, 0));
+
+        let mut expired = QueryCache::new(10, Duration::ZERO);
+        expired.insert("SELECT * FROM text", rows("t"), vec!["text".to_string()]);
+        assert_eq!(key(expired.get("SELECT * FROM text")), None);
+        assert_eq!(expired.entries.entries.len(), 0);
+    }
+
+    #[test]
+    fn test_normalise_sql() {
+        assert_eq!(
+            normalise_sql("  SELECT *\n\tFROM text   WHERE value = 'a  b' "),
+            "SELECT * FROM text WHERE value = 'a  b'"
+        );
+        assert_ne!(
+            normalise_sql("SELECT * FROM t WHERE v = 'a b'"),
+            normalise_sql("SELECT * FROM t WHERE v = 'a  b'")
+        );
+    }
+}
@@ -5,17 +5,28 @@
 //!
 //! This is the main entry point for all ReedBase operations.
 
+use crate::btree::TreeStatistics;
+use crate::database::cache::QueryCache;
 use crate::database::execute::{ExecuteResult, ExecuteStatement};
-use crate::database::stats::PatternTracker;
-use crate::database::types::{AutoIndexConfig, DatabaseStats, IndexInfo, QueryMetrics};
+use crate::database::stats::{estimate_query_cost, PatternTracker};
+use crate::database::types::{
+    AutoIndexConfig, DatabaseDiff, DatabaseStats, ExportReport, ImportReport, IndexInfo,
+    IntegrityReport, MaintenanceReport, QueryMetrics, VacuumReport,
+};
 use crate::error::{ReedError, ReedResult};
-use crate::indices::Index;
-use crate::reedql::{parse, QueryResult};
-use crate::schema::Schema;
-use crate::tables::{list_tables, Table};
-use std::collections::HashMap;
+use crate::indices::{Index, IndexManager, WarmReport};
+use crate::log::{repair_log, validate_log};
+use crate::metrics::storage::{compress_old_metrics, rotate_metric_files, MetricsStorage};
+use crate::metrics::{Metric, MetricUnit, MetricsCollector};
+use crate::reedql::planner::Statistics;
+use crate::reedql::types::FilterCondition;
+use crate::reedql::{parse, LintContext, LintWarning, PreparedQuery, QueryResult};
+use crate::schema::{Schema, SchemaRegistry};
+use crate::tables::{list_tables, table_stats, Table};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::{Path, PathBuf};
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
 
 /// High-level database API.
 ///
@@ -42,6 +53,10 @@ use std::sync::{Arc, RwLock};
 ///
 /// # Ok::<(), reedbase::ReedError>(())
 /// ```
+///
+/// Cloning is cheap and yields a handle to the same database: clones share
+/// loaded tables, indices and statistics.
+#[derive(Clone)]
 pub struct Database {
     /// Base path to ReedBase directory
     base_path: PathBuf,
@@ -55,6 +70,12 @@ pub struct Database {
     /// Auto-created index flags (table.column → bool)
     auto_created_indices: Arc<RwLock<HashMap<String, bool>>>,
 
+    /// Predicates of partial indices (table.column → predicate)
+    index_predicates: Arc<RwLock<HashMap<String, FilterCondition>>>,
+
+    /// Names given in `CREATE INDEX name ON ...` (table.column → name)
+    index_names: Arc<RwLock<HashMap<String, String>>>,
+
     /// Pattern tracker for auto-indexing
     pattern_tracker: Arc<RwLock<PatternTracker>>,
 
@@ -63,11 +84,25 @@ pub struct Database {
 
     /// Database statistics
     stats: Arc<RwLock<DatabaseStats>>,
+
+    /// Cached table schemas for write validation
+    schema_registry: Arc<Mutex<SchemaRegistry>>,
+
+    /// Query result cache (None until enabled)
+    query_cache: Arc<Mutex<Option<QueryCache>>>,
+
+    /// Open change subscriptions (shared by all clones)
+    #[cfg(feature = "tokio")]
+    subscribers: Arc<std::sync::atomic::AtomicUsize>,
 }
 
 impl Database {
     /// Opens an existing ReedBase database or creates a new one.
     ///
+    /// Every table's version.log is validated first; a log with corrupted
+    /// entries (e.g. a partial write from an unclean shutdown) is cut off
+    /// before the first one via `log::repair_log`.
+    ///
     /// ## Input
     /// - `path`: Path to ReedBase directory (e.g., ".reed")
     ///
@@ -78,9 +113,10 @@ impl Database {
     /// ## Performance
     /// - Cold start: < 100ms (loads persistent indices)
     /// - Warm start: < 10ms (indices cached)
+    /// - Log validation: < 1ms per version
     ///
     /// ## Error Conditions
-    /// - `IoError`: Cannot access directory
+    /// - `IoError`: Cannot access directory or repair a version log
     /// - `IndexCorrupted`: Persistent index corrupted
     ///
     /// ## Example
@@ -116,11 +152,20 @@ impl Database {
             tables: Arc::new(RwLock::new(HashMap::new())),
             indices: Arc::new(RwLock::new(HashMap::new())),
             auto_created_indices: Arc::new(RwLock::new(HashMap::new())),
+            index_predicates: Arc::new(RwLock::new(HashMap::new())),
+            index_names: Arc::new(RwLock::new(HashMap::new())),
             pattern_tracker: Arc::new(RwLock::new(PatternTracker::new())),
             auto_index_config: AutoIndexConfig::default(),
             stats: Arc::new(RwLock::new(DatabaseStats::new())),
+            schema_registry: Arc::new(Mutex::new(SchemaRegistry::new())),
+            query_cache: Arc::new(Mutex::new(None)),
+            #[cfg(feature = "tokio")]
+            subscribers: Arc::new(std::sync::atomic::AtomicUsize::new(0)),
         };
 
+        // Self-heal after unclean shutdowns
+        db.repair_version_logs()?;
+
         // Load existing tables into cache
         db.load_existing_tables()?;
 
@@ -148,6 +193,9 @@ impl Database {
 
     /// Executes a ReedQL query (SELECT).
     ///
+    /// `EXPLAIN [ANALYZE] SELECT ...` returns the execution plan as rows
+    /// instead (see `query::explain_query()`).
+    ///
     /// ## Input
     /// - `sql`: ReedQL query string
     ///
@@ -172,8 +220,110 @@ impl Database {
         crate::database::query::execute_query(self, sql)
     }
 
+    /// Enables caching of query results.
+    ///
+    /// `query()` then answers repeated queries (same SQL up to whitespace
+    /// outside literals) from memory. A cached result is dropped when it is
+    /// older than `ttl`, when it is the least recently used one and the cache
+    /// is full, or when a table it read is written via `execute()` or
+    /// `execute_batch()`. Writes through other handles (e.g. `Table`) are only
+    /// picked up once the TTL runs out. Calling this again starts an empty
+    /// cache with the new limits.
+    ///
+    /// ## Input
+    /// - `max_entries`: Maximum number of cached results (0 caches nothing)
+    /// - `ttl`: Lifetime of a cached result
+    ///
+    /// ## Performance
+    /// - Cache hit: one hash lookup plus cloning the result rows
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use reedbase_last::database::Database;
+    /// use std::time::Duration;
+    ///
+    /// let db = Database::open(".reed")?;
+    /// db.enable_query_cache(1000, Duration::from_secs(60));
+    /// db.query("SELECT * FROM text WHERE key LIKE 'page.%'")?;
+    /// db.query("SELECT * FROM text WHERE key LIKE 'page.%'")?; // from cache
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn enable_query_cache(&self, max_entries: usize, ttl: Duration) {
+        *self.query_cache.lock().unwrap() = Some(QueryCache::new(max_entries, ttl));
+    }
+
+    /// Returns query cache counters as `(hits, misses, evictions)`.
+    ///
+    /// Misses include expired entries; evictions count results dropped to
+    /// stay within `max_entries`. All zero while the cache is disabled.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use reedbase_last::database::Database;
+    /// use std::time::Duration;
+    ///
+    /// let db = Database::open(".reed")?;
+    /// db.enable_query_cache(1000, Duration::from_secs(60));
+    /// let (hits, misses, evictions) = db.query_cache_stats();
+    /// println!("{} hits, {} misses, {} evictions", hits, misses, evictions);
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn query_cache_stats(&self) -> (usize, usize, usize) {
+        self.query_cache
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map_or((0, 0, 0), QueryCache::stats)
+    }
+
+    /// Executes a prepared ReedQL query with bound placeholders.
+    ///
+    /// Parse once with `reedql::prepare()`, then run the query repeatedly
+    /// without re-parsing (saves ~5-8μs per call).
+    ///
+    /// ## Input
+    /// - `prepared`: Query from `reedql::prepare()`
+    /// - `params`: Placeholder values by name (without `$`)
+    ///
+    /// ## Output
+    /// - `Ok(QueryResult)`: Query result
+    /// - `Err(ReedError)`: Binding or execution failed
+    ///
+    /// ## Error Conditions
+    /// - `ParseError`: Unknown or unbound placeholder, or non-numeric value
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use reedbase_last::database::Database;
+    /// use reedbase_last::reedql::prepare;
+    /// use std::collections::HashMap;
+    ///
+    /// let db = Database::open(".reed")?;
+    /// let page = prepare("SELECT * FROM text ORDER BY key LIMIT $limit OFFSET $offset")?;
+    /// for offset in [0, 50, 100] {
+    ///     let params = HashMap::from([
+    ///         ("limit".to_string(), "50".to_string()),
+    ///         ("offset".to_string(), offset.to_string()),
+    ///     ]);
+    ///     let result = db.query_prepared(&page, &params)?;
+    /// }
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn query_prepared(
+        &self,
+        prepared: &PreparedQuery,
+        params: &HashMap<String, String>,
+    ) -> ReedResult<QueryResult> {
+        // Implementation in query.rs
+        crate::database::query::execute_prepared(self, prepared, params)
+    }
+
     /// Executes a ReedQL command (INSERT/UPDATE/DELETE).
     ///
+    /// Also runs CREATE INDEX, ANALYZE TABLE and COPY. COPY paths are
+    /// resolved against the current working directory and may not leave it
+    /// (`PermissionDenied`); `rows_affected` is the number of rows copied.
+    ///
     /// ## Input
     /// - `sql`: ReedQL command string
     /// - `user`: Username for audit trail
@@ -195,6 +345,7 @@ impl Database {
     /// db.execute("INSERT INTO text (key, value) VALUES ('page.title@de', 'Willkommen')", "admin")?;
     /// db.execute("UPDATE text SET value = 'Hallo' WHERE key = 'page.title@de'", "admin")?;
     /// db.execute("DELETE FROM text WHERE key = 'page.title@de'", "admin")?;
+    /// db.execute("COPY text TO 'backup/text.csv' WITH (FORMAT CSV, HEADER true)", "admin")?;
     /// # Ok::<(), reedbase::ReedError>(())
     /// ```
     pub fn execute(&self, sql: &str, user: &str) -> ReedResult<ExecuteResult> {
@@ -202,8 +353,51 @@ impl Database {
         crate::database::execute::execute_command(self, sql, user)
     }
 
+    /// Executes several ReedQL commands as one best-effort transaction.
+    ///
+    /// Locks every affected table and stages a copy of its current.csv
+    /// before the first statement runs. If a statement fails, all staged
+    /// tables are restored; otherwise the copies are discarded. Readers may
+    /// see intermediate states (no isolation).
+    ///
+    /// ## Input
+    /// - `statements`: ReedQL commands, executed in order
+    /// - `user`: Username for audit trail
+    ///
+    /// ## Output
+    /// - `Ok(Vec<ExecuteResult>)`: One result per statement
+    /// - `Err(ReedError::BatchFailed)`: Index and error of the failing
+    ///   statement (batch rolled back)
+    ///
+    /// ## Performance
+    /// - Sum of the individual statements plus one lock and file copy per table
+    /// - Failure adds one write per changed table
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use reedbase_last::database::Database;
+    ///
+    /// let db = Database::open(".reed")?;
+    /// db.execute_batch(
+    ///     &[
+    ///         "INSERT INTO text (key, value) VALUES ('page.title@de', 'Willkommen')",
+    ///         "INSERT INTO text (key, value) VALUES ('page.title@en', 'Welcome')",
+    ///     ],
+    ///     "admin",
+    /// )?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn execute_batch(&self, statements: &[&str], user: &str) -> ReedResult<Vec<ExecuteResult>> {
+        crate::database::execute_batch::execute_batch(self, statements, user)
+    }
+
     /// Creates a new table.
     ///
+    /// With a schema, the schema is saved to `tables/{name}/schema.toml` and
+    /// current.csv starts with a header of the schema's column names (the
+    /// first column holds the row key). Without one, the table gets the
+    /// columns `key|value` and no schema.
+    ///
     /// ## Input
     /// - `name`: Table name
     /// - `schema`: Optional schema (None = schemaless)
@@ -212,12 +406,29 @@ impl Database {
     /// - `Ok(())`: Table created
     /// - `Err(ReedError)`: Creation failed
     ///
+    /// ## Error Conditions
+    /// - `TableAlreadyExists`: Table exists
+    /// - `InvalidSchema`: No columns, unknown column type, duplicate or
+    ///   unsafe column name, or a primary key that is not the first column
+    /// - `IoError`: Cannot write the schema or table files
+    ///
     /// ## Example
     /// ```no_run
     /// use reedbase_last::database::Database;
+    /// use reedbase_last::schema::{ColumnDef, Schema};
     ///
     /// let db = Database::open(".reed")?;
-    /// db.create_table("users", None)?;
+    /// db.create_table("notes", None)?;
+    ///
+    /// let schema = Schema::new(
+    ///     "1.0".to_string(),
+    ///     true,
+    ///     vec![
+    ///         ColumnDef::primary_key("id".to_string(), "integer".to_string()),
+    ///         ColumnDef::new("name".to_string(), "string".to_string()).required(),
+    ///     ],
+    /// );
+    /// db.create_table("users", Some(schema))?;
     /// # Ok::<(), reedbase::ReedError>(())
     /// ```
     pub fn create_table(&self, name: &str, schema: Option<Schema>) -> ReedResult<()> {
@@ -230,17 +441,25 @@ impl Database {
         }
 
         // Create initial content (header only)
-        let initial_content = if let Some(schema) = schema {
-            // Use schema columns
-            let column_names: Vec<String> = schema.columns.iter().map(|c| c.name.clone()).collect();
-            let header = column_names.join("|");
-            format!("{}\n", header).into_bytes()
+        let initial_content = if let Some(schema) = &schema {
+            check_table_schema(schema)?;
+            let column_names: Vec<&str> = schema.columns.iter().map(|c| c.name.as_str()).collect();
+            format!("{}\n", column_names.join("|")).into_bytes()
         } else {
             // Default columns for schemaless table
             b"key|value\n".to_vec()
         };
 
-        table.init(&initial_content, "system")?;
+        if let Some(schema) = &schema {
+            crate::schema::save_schema(&self.base_path, name, schema)?;
+            self.schema_registry.lock().unwrap().invalidate(name);
+        }
+        if let Err(e) = table.init(&initial_content, "system") {
+            if schema.is_some() {
+                let _ = crate::schema::delete_schema(&self.base_path, name);
+            }
+            return Err(e);
+        }
 
         // Add to loaded tables
         let mut tables = self.tables.write().unwrap();
@@ -254,9 +473,122 @@ impl Database {
         if self.auto_index_config.enabled {
             drop(tables);
             drop(stats);
-            crate::database::index::create_index_internal(self, name, "key", true)?;
+            let key_column = schema
+                .as_ref()
+                .map_or("key", |schema| schema.columns[0].name.as_str());
+            crate::database::index::create_index_internal(self, name, key_column, true)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns a table's schema.
+    ///
+    /// ## Input
+    /// - `name`: Table name
+    ///
+    /// ## Output
+    /// - `Ok(Some(Schema))`: Schema from `tables/{name}/schema.toml`
+    /// - `Ok(None)`: Table has no schema
+    ///
+    /// ## Error Conditions
+    /// - `TableNotFound`: Table doesn't exist
+    /// - `InvalidSchema`: schema.toml cannot be parsed
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use reedbase_last::database::Database;
+    ///
+    /// let db = Database::open(".reed")?;
+    /// if let Some(schema) = db.describe_table("users")? {
+    ///     for column in &schema.columns {
+    ///         println!("{}: {}", column.name, column.col_type);
+    ///     }
+    /// }
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn describe_table(&self, name: &str) -> ReedResult<Option<Schema>> {
+        if !Table::new(&self.base_path, name).exists() {
+            return Err(ReedError::TableNotFound {
+                name: name.to_string(),
+            });
+        }
+
+        Ok(self
+            .schema_registry
+            .lock()
+            .unwrap()
+            .get(name, &self.base_path)?
+            .cloned())
+    }
+
+    /// Drops a table with its history, schema and indices.
+    ///
+    /// Takes the table's write lock, removes every index of the table
+    /// (in memory, metadata.json and files under `indices/`), deletes the
+    /// table directory and forgets the table in the schema registry, query
+    /// cache and auto-indexing patterns. A `table_drop` metric records the
+    /// row count, with size and version count as tags.
+    ///
+    /// ## Input
+    /// - `name`: Table name
+    ///
+    /// ## Output
+    /// - `Ok(())`: Table dropped
+    /// - `Err(ReedError)`: Drop failed
+    ///
+    /// ## Error Conditions
+    /// - `TableNotFound`: Table doesn't exist
+    /// - `LockTimeout`: Table lock not acquired within 30s
+    /// - `IoError`: Cannot remove index or table files
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use reedbase_last::database::Database;
+    ///
+    /// let db = Database::open(".reed")?;
+    /// db.drop_table("old_sessions")?; // DESTRUCTIVE!
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn drop_table(&self, name: &str) -> ReedResult<()> {
+        let table = Table::new(&self.base_path, name);
+        if !table.exists() {
+            return Err(ReedError::TableNotFound {
+                name: name.to_string(),
+            });
         }
 
+        let _lock = crate::concurrent::acquire_lock(&self.base_path, name, DROP_LOCK_TIMEOUT)?;
+        let table_stats = table_stats(&self.base_path, name)?;
+
+        crate::database::index::drop_table_indices(self, name)?;
+        table.delete(true)?;
+
+        self.tables.write().unwrap().remove(name);
+        self.schema_registry.lock().unwrap().invalidate(name);
+        crate::database::execute::invalidate_query_cache(self, name);
+        self.pattern_tracker.write().unwrap().forget_table(name);
+        let mut stats = self.stats.write().unwrap();
+        stats.table_count = stats.table_count.saturating_sub(1);
+        drop(stats);
+
+        MetricsCollector::global().record(
+            Metric::new(
+                "table_drop",
+                table_stats.row_count as f64,
+                MetricUnit::Count,
+            )
+            .with_tag("table", name)
+            .with_tag(
+                "bytes",
+                &(table_stats.current_size
+                    + table_stats.deltas_size
+                    + table_stats.version_index_size)
+                    .to_string(),
+            )
+            .with_tag("versions", &table_stats.version_count.to_string()),
+        );
+
         Ok(())
     }
 
@@ -283,6 +615,115 @@ impl Database {
         crate::database::index::create_index(self, table_name, column)
     }
 
+    /// Creates a composite index over several columns.
+    ///
+    /// Also available as `CREATE INDEX ON table (col1, col2)` via `execute()`.
+    ///
+    /// ## Input
+    /// - `table_name`: Table name
+    /// - `columns`: Column names in key order (at least two)
+    ///
+    /// ## Output
+    /// - `Ok(())`: Index created, listed as `table.col1,col2`
+    /// - `Err(ReedError)`: Creation failed
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use reedbase_last::database::Database;
+    ///
+    /// let db = Database::open(".reed")?;
+    /// db.create_composite_index("users", &["country", "city"])?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn create_composite_index(&self, table_name: &str, columns: &[&str]) -> ReedResult<()> {
+        crate::database::index::create_composite_index(self, table_name, columns)
+    }
+
+    /// Drops an index, in memory and on disk.
+    ///
+    /// Removes the index, its predicate and name, its entry in
+    /// `indices/metadata.json` and its B+-Tree and WAL files. Also available
+    /// as `DROP INDEX ON table (column)` or `DROP INDEX name` via `execute()`.
+    ///
+    /// ## Input
+    /// - `table_name`: Table name
+    /// - `column`: Column name (`col1,col2` for a composite index)
+    ///
+    /// ## Output
+    /// - `Ok(())`: Index dropped
+    ///
+    /// ## Error Conditions
+    /// - `IndexNotFound`: No index on `table_name.column`
+    /// - `IoError`: Metadata or index files could not be updated
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use reedbase_last::database::Database;
+    ///
+    /// let db = Database::open(".reed")?;
+    /// db.drop_index("users", "city")?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn drop_index(&self, table_name: &str, column: &str) -> ReedResult<()> {
+        crate::database::index::drop_index(self, table_name, column)
+    }
+
+    /// Gathers planner statistics for a table (`ANALYZE TABLE`).
+    ///
+    /// Stores row count, index selectivity and page cost in
+    /// `tables/{name}/.stats`; queries on the table plan with them.
+    ///
+    /// ## Input
+    /// - `table_name`: Table name
+    ///
+    /// ## Output
+    /// - `Ok(Statistics)`: Gathered statistics
+    /// - `Err(ReedError)`: Table not found or I/O error
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use reedbase_last::database::Database;
+    ///
+    /// let db = Database::open(".reed")?;
+    /// let stats = db.analyze_table("text")?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn analyze_table(&self, table_name: &str) -> ReedResult<Statistics> {
+        crate::database::stats::analyze_table(self, table_name)
+    }
+
+    /// Creates a partial index over the rows matching a predicate.
+    ///
+    /// Queries use it only when their WHERE conditions imply the predicate;
+    /// `list_indices()` reports the predicate.
+    ///
+    /// ## Input
+    /// - `table_name`: Table name
+    /// - `column`: Column name
+    /// - `predicate`: Condition a row must satisfy to be indexed
+    ///
+    /// ## Output
+    /// - `Ok(())`: Index created
+    /// - `Err(ReedError)`: Creation failed
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use reedbase_last::database::Database;
+    /// use reedbase_last::reedql::parse_condition;
+    ///
+    /// let db = Database::open(".reed")?;
+    /// db.create_partial_index("text", "key", parse_condition("environment = 'prod'")?)?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn create_partial_index(
+        &self,
+        table_name: &str,
+        column: &str,
+        predicate: FilterCondition,
+    ) -> ReedResult<()> {
+        crate::database::index::create_partial_index(self, table_name, column, predicate)
+    }
+
     /// Lists all tables in the database.
     ///
     /// ## Output
@@ -325,6 +766,32 @@ impl Database {
         crate::database::index::list_indices(self)
     }
 
+    /// Reports height, node counts and fill factor of all B+-Tree indices.
+    ///
+    /// ## Output
+    /// - `Ok(BTreeMap)`: Statistics keyed by `table.column` (HashMap indices
+    ///   have no tree and are omitted)
+    ///
+    /// ## Performance
+    /// - Reads every page of every B+-Tree index once
+    ///
+    /// ## Error Conditions
+    /// - `DeserializationError`: Corrupted index page
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use reedbase_last::database::Database;
+    ///
+    /// let db = Database::open(".reed")?;
+    /// for (index, stats) in db.index_stats()? {
+    ///     println!("{}: height {}, {:.1}% full", index, stats.height, stats.avg_leaf_fill_pct);
+    /// }
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn index_stats(&self) -> ReedResult<BTreeMap<String, TreeStatistics>> {
+        crate::database::index::index_stats(self)
+    }
+
     /// Gets database statistics.
     ///
     /// ## Output
@@ -343,6 +810,580 @@ impl Database {
         self.stats.read().unwrap().clone()
     }
 
+    /// Estimates the cost of a query without running it.
+    ///
+    /// ## Input
+    /// - `sql`: ReedQL SELECT query
+    ///
+    /// ## Output
+    /// - `Ok(f64)`: Estimated cost in nanoseconds
+    /// - `Err(ReedError)`: Query does not parse or table not found
+    ///
+    /// ## Performance
+    /// - One pass over current.csv to count rows, < 10ms typical
+    ///
+    /// ## Error Conditions
+    /// - `ParseError`: Invalid ReedQL syntax
+    /// - `TableNotFound`: Queried table doesn't exist
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use reedbase_last::database::Database;
+    ///
+    /// let db = Database::open(".reed")?;
+    /// let cost_ns = db.estimate_query_cost("SELECT * FROM text WHERE key = 'page.title@de'")?;
+    /// println!("Estimated cost: {:.2}ms", cost_ns / 1_000_000.0);
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn estimate_query_cost(&self, sql: &str) -> ReedResult<f64> {
+        let query = parse(sql)?;
+        let stats = table_stats(&self.base_path, &query.table)?;
+        Ok(estimate_query_cost(&stats, &query, &self.list_indices()))
+    }
+
+    /// Lints a ReedQL statement against this database.
+    ///
+    /// Runs all checks of `reedql::lint()` plus the index check, which needs
+    /// index and cardinality information only the database has.
+    ///
+    /// ## Input
+    /// - `sql`: ReedQL statement
+    ///
+    /// ## Output
+    /// - `Vec<LintWarning>`: Warnings ordered by position (empty = no findings)
+    ///
+    /// ## Performance
+    /// - < 10μs without WHERE clause
+    /// - One column scan per unindexed WHERE column otherwise
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use reedbase_last::database::Database;
+    ///
+    /// let db = Database::open(".reed")?;
+    /// for warning in db.lint("SELECT * FROM text LIMIT 10") {
+    ///     eprintln!("{}", warning);
+    /// }
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn lint(&self, sql: &str) -> Vec<LintWarning> {
+        crate::reedql::lint_with_context(sql, self)
+    }
+
+    /// Vacuums a table.
+    ///
+    /// Rewrites `current.csv` in canonical form: blank lines removed, one
+    /// `\n` after every row. The result is stored as a new version with
+    /// action `vacuum`, so it can be rolled back like any other write.
+    ///
+    /// ## Input
+    /// - `table`: Table name
+    ///
+    /// ## Output
+    /// - `Ok(VacuumReport)`: Sizes before/after and rows preserved
+    /// - `Err(ReedError)`: Vacuum failed
+    ///
+    /// ## Error Conditions
+    /// - `TableNotFound`: Table doesn't exist
+    /// - `IoError`: Cannot read or write table files
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use reedbase_last::database::Database;
+    ///
+    /// let db = Database::open(".reed")?;
+    /// let report = db.vacuum("text")?;
+    /// println!("Reclaimed {} bytes", report.bytes_reclaimed());
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn vacuum(&self, table: &str) -> ReedResult<VacuumReport> {
+        // Implementation in vacuum.rs
+        crate::database::vacuum::vacuum_table(self, table)
+    }
+
+    /// Checks all foreign key constraints against the stored data.
+    ///
+    /// Constraints are checked on existing rows, so data inserted before a
+    /// `foreign_key` was added to a schema is covered as well.
+    ///
+    /// ## Output
+    /// - `Ok(IntegrityReport)`: Orphaned values (empty if consistent)
+    /// - `Err(ReedError)`: A schema or table could not be read
+    ///
+    /// ## Performance
+    /// - O(n) per constraint where n = referencing rows
+    ///
+    /// ## Error Conditions
+    /// - `InvalidSchema`: A constraint names a missing column
+    /// - `IoError`: Cannot read a table
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use reedbase_last::database::Database;
+    ///
+    /// let db = Database::open(".reed")?;
+    /// for v in db.check_referential_integrity()?.violations {
+    ///     println!("{}.{} row {}: '{}' not in {}.{}",
+    ///         v.table, v.column, v.row, v.value, v.referenced_table, v.referenced_column);
+    /// }
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn check_referential_integrity(&self) -> ReedResult<IntegrityReport> {
+        // Implementation in integrity.rs
+        crate::database::integrity::check_referential_integrity(self)
+    }
+
+    /// Compares this database with another ReedBase directory.
+    ///
+    /// Diffs `current.csv` of every table present in both directories and
+    /// lists tables that exist on one side only. Row changes describe how to
+    /// get from this database to the other one.
+    ///
+    /// ## Input
+    /// - `other_db_path`: Path to the other ReedBase directory
+    ///
+    /// ## Output
+    /// - `Ok(DatabaseDiff)`: Table and row differences
+    ///
+    /// ## Performance
+    /// - O(n) per shared table where n = rows
+    ///
+    /// ## Error Conditions
+    /// - `IoError`: Cannot list tables
+    /// - `InvalidCsv`: A table cannot be parsed
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use reedbase_last::database::Database;
+    /// use std::path::Path;
+    ///
+    /// let db = Database::open(".reed")?;
+    /// let diff = db.diff(Path::new("/srv/staging/.reed"))?;
+    /// for table in &diff.tables_changed {
+    ///     println!("{}: +{} ~{} -{}", table.table_name, table.added.len(), table.modified.len(), table.deleted.len());
+    /// }
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn diff(&self, other_db_path: &Path) -> ReedResult<DatabaseDiff> {
+        // Implementation in diff.rs
+        crate::database::diff::diff_databases(self, other_db_path)
+    }
+
+    /// Runs periodic housekeeping.
+    ///
+    /// Rotates metric files larger than 10 MB and compresses metric files
+    /// that have not been written to for 7 days (see `metrics::storage`).
+    ///
+    /// ## Output
+    /// - `Ok(MaintenanceReport)`: Number of rotated and compressed files
+    /// - `Err(ReedError)`: Maintenance step failed
+    ///
+    /// ## Performance
+    /// - O(m) where m = total size of affected metric files
+    ///
+    /// ## Error Conditions
+    /// - `IoError`: Cannot read or write metric files
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use reedbase_last::database::Database;
+    ///
+    /// let db = Database::open(".reed")?;
+    /// let report = db.maintenance()?;
+    /// println!("{} metric files rotated", report.metric_files_rotated);
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn maintenance(&self) -> ReedResult<MaintenanceReport> {
+        let mut report = MaintenanceReport::default();
+
+        let metric_names = MetricsStorage::with_directory(self.base_path.join("metrics"))
+            .list_metrics()
+            .map_err(|e| ReedError::IoError {
+                operation: "list_metrics".to_string(),
+                reason: e.to_string(),
+            })?;
+        for name in metric_names {
+            report.metric_files_rotated +=
+                rotate_metric_files(&self.base_path, &name, MAINTENANCE_METRIC_MAX_BYTES)?;
+        }
+
+        report.metric_files_compressed =
+            compress_old_metrics(&self.base_path, MAINTENANCE_METRIC_COMPRESS_DAYS)?;
+
+        Ok(report)
+    }
+
+    /// Pre-loads the indices of every table before serving traffic.
+    ///
+    /// Runs `IndexManager::warm()` for each table so that current.csv and the
+    /// table's B+-Tree index files are in the page cache before the first
+    /// query arrives.
+    ///
+    /// ## Output
+    /// - `Ok(Vec<WarmReport>)`: One report per table, sorted by table name
+    /// - `Err(ReedError)`: Warming a table failed
+    ///
+    /// ## Performance
+    /// - O(n) in total table size, < 50ms per 10,000 rows
+    ///
+    /// ## Error Conditions
+    /// - `TableNotFound`: Table removed while warming
+    /// - `IoError`: Cannot read an index file
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use reedbase_last::database::Database;
+    ///
+    /// let db = Database::open(".reed")?;
+    /// for report in db.warm_all_tables()? {
+    ///     println!("{}: {} rows in {}μs", report.table, report.rows_indexed, report.duration_us);
+    /// }
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn warm_all_tables(&self) -> ReedResult<Vec<WarmReport>> {
+        let mut names: Vec<String> = self.tables.read().unwrap().keys().cloned().collect();
+        names.sort();
+
+        let mut manager = IndexManager::new();
+        names
+            .iter()
+            .map(|name| manager.warm(&Table::new(&self.base_path, name)))
+            .collect()
+    }
+
+    /// Exports every table into one SQLite database file.
+    ///
+    /// Each ReedBase table becomes a SQLite table of the same name (see
+    /// `Table::export_to_sqlite()`). All tables are written in a single
+    /// transaction, so either every table is exported or none is.
+    ///
+    /// ## Input
+    /// - `dest`: SQLite database file (created if missing)
+    ///
+    /// ## Output
+    /// - `Ok(usize)`: Total number of data rows exported across all tables
+    /// - `Err(ReedError)`: Export failed, destination left unchanged
+    ///
+    /// ## Performance
+    /// - O(n) in total row count, single transaction
+    ///
+    /// ## Error Conditions
+    /// - `InvalidCsv`: A table's current.csv is malformed
+    /// - `IoError`: Cannot open or write the SQLite file
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use reedbase_last::database::Database;
+    /// use std::path::Path;
+    ///
+    /// let db = Database::open(".reed")?;
+    /// let rows = db.export_all_to_sqlite(Path::new("export.db"))?;
+    /// println!("Exported {} rows", rows);
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    #[cfg(feature = "rusqlite")]
+    pub fn export_all_to_sqlite(&self, dest: &Path) -> ReedResult<usize> {
+        use crate::tables::sqlite::{export_content, open_sqlite};
+
+        let mut names: Vec<String> = self.tables.read().unwrap().keys().cloned().collect();
+        names.sort();
+
+        let mut conn = open_sqlite(dest)?;
+        let tx = conn.transaction().map_err(|e| ReedError::IoError {
+            operation: "sqlite_begin".to_string(),
+            reason: e.to_string(),
+        })?;
+
+        let mut total = 0;
+        for name in &names {
+            let table = Table::new(&self.base_path, name);
+            let content = table.read_current()?;
+            total += export_content(&tx, name, &content, table.delimiter()?)?;
+        }
+
+        tx.commit().map_err(|e| ReedError::IoError {
+            operation: "sqlite_commit".to_string(),
+            reason: e.to_string(),
+        })?;
+
+        Ok(total)
+    }
+
+    /// Imports a SQLite table into a ReedBase table.
+    ///
+    /// The reverse of `export_all_to_sqlite()`, intended for migrating
+    /// existing data. All values are converted to strings (`NULL` becomes an
+    /// empty field) and appended to `target_table` as a single new version.
+    ///
+    /// If `target_table` exists, its header decides the column order: SQLite
+    /// columns are matched by name and columns missing in SQLite stay empty.
+    /// Otherwise the table is created from the SQLite columns, with a `key`
+    /// column moved to the front. Keys are validated against RBKS v2 when the
+    /// key column is named `key`, and rows are validated against the table's
+    /// schema if one exists.
+    ///
+    /// ## Input
+    /// - `src`: SQLite database file
+    /// - `sqlite_table`: Table to read from SQLite
+    /// - `target_table`: ReedBase table to import into (created if missing)
+    /// - `user`: Username recorded for the write
+    ///
+    /// ## Output
+    /// - `Ok(usize)`: Number of rows imported
+    /// - `Err(ReedError)`: Import failed, no rows written
+    ///
+    /// ## Performance
+    /// - O(n) rows, one versioned write
+    ///
+    /// ## Error Conditions
+    /// - `IoError`: Cannot read the SQLite file or table
+    /// - `ValidationError`: A SQLite column is not part of `target_table`, a
+    ///   value contains `|` or a line break, or a row violates the schema
+    /// - `InvalidCsv`: A key is not valid RBKS v2
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use reedbase_last::database::Database;
+    /// use std::path::Path;
+    ///
+    /// let db = Database::open(".reed")?;
+    /// let rows = db.import_from_sqlite(Path::new("legacy.db"), "texts", "text", "admin")?;
+    /// println!("Imported {} rows", rows);
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    #[cfg(feature = "rusqlite")]
+    pub fn import_from_sqlite(
+        &self,
+        src: &Path,
+        sqlite_table: &str,
+        target_table: &str,
+        user: &str,
+    ) -> ReedResult<usize> {
+        use crate::schema::{validate_key, validate_rows, CsvRow};
+        use crate::tables::sqlite::read_sqlite_table;
+
+        let (columns, records) = read_sqlite_table(src, sqlite_table)?;
+        let table = Table::new(&self.base_path, target_table);
+        let separator = table.delimiter()?.as_char().to_string();
+
+        let header: Vec<String> = if table.exists() {
+            let content = table.read_current()?;
+            String::from_utf8_lossy(&content)
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .split(separator.as_str())
+                .map(String::from)
+                .collect()
+        } else {
+            let mut header = columns.clone();
+            if let Some(pos) = header.iter().position(|c| c == "key") {
+                let key = header.remove(pos);
+                header.insert(0, key);
+            }
+            header
+        };
+
+        if let Some(column) = columns.iter().find(|c| !header.contains(c)) {
+            return Err(ReedError::ValidationError {
+                column: column.clone(),
+                reason: format!("Column not present in table '{}'", target_table),
+                value: None,
+            });
+        }
+
+        let positions: Vec<Option<usize>> = header
+            .iter()
+            .map(|name| columns.iter().position(|c| c == name))
+            .collect();
+        let rows: Vec<Vec<String>> = records
+            .iter()
+            .map(|record| {
+                positions
+                    .iter()
+                    .map(|pos| pos.map(|i| record[i].clone()).unwrap_or_default())
+                    .collect()
+            })
+            .collect();
+
+        if header.first().map(String::as_str) == Some("key") {
+            for row in &rows {
+                validate_key(&row[0])?;
+            }
+        }
+
+        {
+            let mut registry = self.schema_registry.lock().unwrap();
+            if let Some(schema) = registry.get(target_table, &self.base_path)? {
+                let csv_rows: Vec<CsvRow> = rows
+                    .iter()
+                    .map(|fields| CsvRow::new(fields[0].clone(), fields.clone()))
+                    .collect();
+                validate_rows(&csv_rows, schema)?;
+            }
+        }
+
+        if !table.exists() {
+            table.init(format!("{}\n", header.join(&separator)).as_bytes(), user)?;
+            self.register_table(target_table);
+        }
+
+        if !rows.is_empty() {
+            let new_lines: String = rows.iter().map(|row| row.join(&separator) + "\n").collect();
+            table.read_modify_write(
+                |content| {
+                    let mut new_content = content.to_vec();
+                    if !new_content.is_empty() && !new_content.ends_with(b"\n") {
+                        new_content.push(b'\n');
+                    }
+                    new_content.extend_from_slice(new_lines.as_bytes());
+                    new_content
+                },
+                user,
+            )?;
+        }
+
+        Ok(rows.len())
+    }
+
+    /// Imports an external CSV file into a table.
+    ///
+    /// Reads standard CSV (quoted fields, `""` escapes, CRLF), converts it
+    /// to the table's format and appends all valid rows as one version via
+    /// `Table::swap()`. A missing table is created from the CSV header.
+    /// Unparsable records and rows violating the schema are counted in the
+    /// report instead of failing the import.
+    ///
+    /// ## Input
+    /// - `table`: Target table (created if missing)
+    /// - `path`: CSV file
+    /// - `delimiter`: Field delimiter of the file (None = detect `,`, `|` or tab)
+    /// - `has_header`: First record holds column names (matched by name);
+    ///   otherwise fields follow the table's column order
+    /// - `user`: Username recorded for the write
+    /// - `on_progress`: Called with the number of records processed, every
+    ///   10,000 records and once at the end
+    ///
+    /// ## Output
+    /// - `Ok(ImportReport)`: Imported and skipped rows, parsing errors
+    /// - `Err(ReedError)`: Import failed, nothing written
+    ///
+    /// ## Performance
+    /// - O(n) over the file, one versioned write
+    ///
+    /// ## Error Conditions
+    /// - `IoError`: Cannot read the file or write the table
+    /// - `InvalidCsv`: File is not UTF-8, or no usable header
+    /// - `ValidationError`: A CSV column is not part of the table, or the
+    ///   key column is missing
+    /// - `LockTimeout`: Table lock not acquired within 30s
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use reedbase_last::database::Database;
+    /// use std::path::Path;
+    ///
+    /// let db = Database::open(".reed")?;
+    /// let report = db.import_csv(
+    ///     "users",
+    ///     Path::new("users.csv"),
+    ///     Some(b','),
+    ///     true,
+    ///     "admin",
+    ///     Some(Box::new(|rows| println!("{} rows read", rows))),
+    /// )?;
+    /// println!(
+    ///     "{} imported, {} skipped, {} unparsable",
+    ///     report.rows_imported,
+    ///     report.rows_skipped,
+    ///     report.parsing_errors.len()
+    /// );
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn import_csv(
+        &self,
+        table: &str,
+        path: &Path,
+        delimiter: Option<u8>,
+        has_header: bool,
+        user: &str,
+        on_progress: Option<Box<dyn Fn(usize)>>,
+    ) -> ReedResult<ImportReport> {
+        crate::database::import::import_csv(
+            self,
+            table,
+            path,
+            delimiter,
+            has_header,
+            user,
+            on_progress,
+        )
+    }
+
+    /// Exports a table, or the result of a query on it, to a CSV file.
+    ///
+    /// Values are quoted per RFC 4180. The export is recorded in the audit
+    /// log as operation `export`.
+    ///
+    /// ## Input
+    /// - `table`: Table to export
+    /// - `dest`: Output file (created or truncated)
+    /// - `delimiter`: Field delimiter (None = `,`; `b'\t'` for TSV)
+    /// - `include_header`: Write column names as the first line
+    /// - `query`: SELECT on `table` whose result is exported (None = all rows,
+    ///   streamed)
+    /// - `user`: Username recorded in the audit log
+    ///
+    /// ## Output
+    /// - `Ok(ExportReport)`: Rows written, destination and duration
+    /// - `Err(ReedError)`: Export failed
+    ///
+    /// ## Performance
+    /// - O(n) for a whole table, query cost plus O(r) otherwise
+    ///
+    /// ## Error Conditions
+    /// - `TableNotFound`: Table doesn't exist
+    /// - `ParseError`: Invalid query, or a query on another table
+    /// - `ValidationError`: Delimiter is a quote or line break
+    /// - `IoError`: Cannot write the file or the audit log
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use reedbase_last::database::Database;
+    /// use std::path::Path;
+    ///
+    /// let db = Database::open(".reed")?;
+    /// let report = db.export_csv(
+    ///     "users",
+    ///     Path::new("berlin.tsv"),
+    ///     Some(b'\t'),
+    ///     true,
+    ///     Some("SELECT key, name FROM users WHERE city = 'Berlin'"),
+    ///     "admin",
+    /// )?;
+    /// println!("{} rows in {}ms", report.rows_exported, report.duration_ms);
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn export_csv(
+        &self,
+        table: &str,
+        dest: &Path,
+        delimiter: Option<u8>,
+        include_header: bool,
+        query: Option<&str>,
+        user: &str,
+    ) -> ReedResult<ExportReport> {
+        crate::database::export::export_csv(
+            self,
+            table,
+            dest,
+            delimiter,
+            include_header,
+            query,
+            user,
+        )
+    }
+
     /// Closes the database gracefully.
     ///
     /// Flushes all pending operations and closes indices.
@@ -395,6 +1436,17 @@ impl Database {
         Ok(())
     }
 
+    /// Repairs the version.log of every table that fails validation.
+    fn repair_version_logs(&self) -> ReedResult<()> {
+        for name in list_tables(&self.base_path)? {
+            let log_path = Table::new(&self.base_path, &name).log_path();
+            if !validate_log(&log_path)?.is_healthy() {
+                repair_log(&log_path)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Loads persistent B+-Tree indices from disk.
     ///
     /// Called during Database::open() to restore indices from previous sessions.
@@ -416,6 +1468,8 @@ impl Database {
         let indices_dir = self.base_path.join("indices");
         let mut indices = self.indices.write().unwrap();
         let mut auto_flags = self.auto_created_indices.write().unwrap();
+        let mut predicates = self.index_predicates.write().unwrap();
+        let mut names = self.index_names.write().unwrap();
         let mut stats = self.stats.write().unwrap();
 
         for metadata in metadata_list {
@@ -450,6 +1504,16 @@ impl Database {
                             indices.insert(index_key.clone(), Box::new(btree_index));
                             stats.index_count += 1;
 
+                            // Restore partial index predicate
+                            if let Some(predicate) = metadata.predicate {
+                                predicates.insert(index_key.clone(), predicate);
+                            }
+
+                            // Restore index name
+                            if let Some(name) = metadata.name {
+                                names.insert(index_key.clone(), name);
+                            }
+
                             // Restore auto-created flag
                             if metadata.auto_created {
                                 auto_flags.insert(index_key, true);
@@ -510,6 +1574,14 @@ impl Database {
         &self.auto_created_indices
     }
 
+    pub(crate) fn index_predicates(&self) -> &Arc<RwLock<HashMap<String, FilterCondition>>> {
+        &self.index_predicates
+    }
+
+    pub(crate) fn index_names(&self) -> &Arc<RwLock<HashMap<String, String>>> {
+        &self.index_names
+    }
+
     pub(crate) fn pattern_tracker(&self) -> &Arc<RwLock<PatternTracker>> {
         &self.pattern_tracker
     }
@@ -521,6 +1593,108 @@ impl Database {
     pub(crate) fn stats_mut(&self) -> &Arc<RwLock<DatabaseStats>> {
         &self.stats
     }
+
+    pub(crate) fn schema_registry(&self) -> &Arc<Mutex<SchemaRegistry>> {
+        &self.schema_registry
+    }
+
+    pub(crate) fn query_cache(&self) -> &Arc<Mutex<Option<QueryCache>>> {
+        &self.query_cache
+    }
+
+    /// Adds a table created outside `create_table()` to the loaded tables.
+    pub(crate) fn register_table(&self, name: &str) {
+        self.tables
+            .write()
+            .unwrap()
+            .insert(name.to_string(), Table::new(&self.base_path, name));
+        self.stats.write().unwrap().table_count += 1;
+    }
+
+    #[cfg(feature = "tokio")]
+    pub(crate) fn subscribers(&self) -> &Arc<std::sync::atomic::AtomicUsize> {
+        &self.subscribers
+    }
+}
+
+/// Maximum time `drop_table` waits for the table's write lock.
+const DROP_LOCK_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Metric file size above which maintenance rotates it (10 MB).
+const MAINTENANCE_METRIC_MAX_BYTES: u64 = 10 * 1024 * 1024;
+
+/// Days without writes after which maintenance compresses a metric file.
+const MAINTENANCE_METRIC_COMPRESS_DAYS: u64 = 7;
+
+/// Minimum row count before a full scan is worth a lint hint.
+const LINT_MIN_ROWS: usize = 1000;
+
+/// Minimum distinct/non-null ratio for a column to count as high-cardinality.
+const LINT_CARDINALITY_RATIO: f64 = 0.5;
+
+/// Seed of the row sample the lint draws for tables without ANALYZE statistics.
+const LINT_SAMPLE_SEED: u64 = 0x5eed;
+
+impl LintContext for Database {
+    fn is_indexed(&self, table: &str, column: &str) -> bool {
+        let index_key = format!("{}.{}", table, column);
+        self.indices.read().unwrap().contains_key(&index_key)
+    }
+
+    /// Uses the ANALYZE statistics of the table when there are any, so the
+    /// check reads no rows. Otherwise judges from a sample of `LINT_MIN_ROWS`
+    /// rows; a table that cannot fill it is too small to warn about.
+    fn is_high_cardinality(&self, table: &str, column: &str) -> bool {
+        if let Ok(Some(stats)) =
+            crate::database::stats::load_table_statistics(&self.base_path, table)
+        {
+            if let Some(column_stats) = stats.columns.get(column) {
+                let non_null = stats.row_count as f64 * (1.0 - column_stats.null_fraction);
+                return non_null >= LINT_MIN_ROWS as f64
+                    && column_stats.cardinality as f64 / non_null >= LINT_CARDINALITY_RATIO;
+            }
+        }
+
+        let rows = match self
+            .get_table(table)
+            .and_then(|t| t.sample(LINT_MIN_ROWS, LINT_SAMPLE_SEED))
+        {
+            Ok(rows) if rows.len() >= LINT_MIN_ROWS => rows,
+            _ => return false,
+        };
+
+        let values: Vec<&str> = rows
+            .iter()
+            .filter_map(|row| row.get(column))
+            .filter(|value| !value.is_empty())
+            .collect();
+        let distinct: HashSet<&str> = values.iter().copied().collect();
+        !values.is_empty() && distinct.len() as f64 / values.len() as f64 >= LINT_CARDINALITY_RATIO
+    }
 }
 
 // Clone is not needed - Table::new() can recreate references
+
+/// Checks a schema given to `create_table()`.
+///
+/// Beyond what every schema file must satisfy, the column names become the
+/// CSV header, so they must be unique and free of `|` and line breaks, and
+/// a primary key must be the first (key) column.
+fn check_table_schema(schema: &Schema) -> ReedResult<()> {
+    crate::schema::loader::validate_schema(schema)?;
+
+    for (i, column) in schema.columns.iter().enumerate() {
+        let reason = if column.name.is_empty() || column.name.contains(['|', '\n', '\r']) {
+            format!("Invalid column name '{}'", column.name)
+        } else if schema.columns[..i].iter().any(|c| c.name == column.name) {
+            format!("Duplicate column '{}'", column.name)
+        } else if column.primary_key && i > 0 {
+            format!("Primary key '{}' must be the first column", column.name)
+        } else {
+            continue;
+        };
+        return Err(ReedError::InvalidSchema { reason });
+    }
+
+    Ok(())
+}
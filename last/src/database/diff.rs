@@ -0,0 +1,147 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Cross-database comparison.
+//!
+//! Compares the `current.csv` of every table in two ReedBase directories
+//! (e.g. production vs staging) and reports row-level differences using
+//! `merge::calculate_diff`. Changes are expressed from this database towards
+//! the other one: "added" rows exist only in the other database.
+
+use crate::concurrent::types::CsvRow;
+use crate::database::types::{DatabaseDiff, TableDiff};
+use crate::database::Database;
+use crate::error::ReedResult;
+use crate::merge::{calculate_diff, RowChange};
+use crate::tables::{list_tables, Table};
+use std::path::Path;
+
+/// Compares this database with another ReedBase directory.
+///
+/// ## Input
+/// - `db`: Database reference (the "self" side)
+/// - `other_path`: Path to the other ReedBase directory
+///
+/// ## Output
+/// - `Ok(DatabaseDiff)`: Tables present on one side only and per-table row
+///   changes (unchanged tables are omitted)
+///
+/// ## Performance
+/// - O(n) per table where n = rows, both tables held in memory
+///
+/// ## Error Conditions
+/// - `IoError`: Cannot list tables in either directory
+/// - `InvalidCsv`: A `current.csv` cannot be parsed
+pub fn diff_databases(db: &Database, other_path: &Path) -> ReedResult<DatabaseDiff> {
+    let own_tables = list_tables(db.base_path())?;
+    let other_tables = list_tables(other_path)?;
+
+    let mut diff = DatabaseDiff::default();
+
+    for name in &own_tables {
+        if !other_tables.contains(name) {
+            diff.tables_only_in_self.push(name.clone());
+            continue;
+        }
+
+        let own_rows = read_rows(&Table::new(db.base_path(), name))?;
+        let other_rows = read_rows(&Table::new(other_path, name))?;
+
+        let table_diff = table_diff(name, calculate_diff(&own_rows, &other_rows)?);
+        if !table_diff.is_empty() {
+            diff.tables_changed.push(table_diff);
+        }
+    }
+
+    diff.tables_only_in_other = other_tables
+        .into_iter()
+        .filter(|name| !own_tables.contains(name))
+        .collect();
+
+    diff.tables_only_in_self.sort();
+    diff.tables_only_in_other.sort();
+    diff.tables_changed
+        .sort_by(|a, b| a.table_name.cmp(&b.table_name));
+
+    Ok(diff)
+}
+
+/// Reads current.csv as merge rows.
+pub(crate) fn read_rows(table: &Table) -> ReedResult<Vec<CsvRow>> {
+    Ok(table
+        .read_current_as_rows()?
+        .into_iter()
+        .map(|row| CsvRow {
+            key: row.key,
+            values: row.values,
+        })
+        .collect())
+}
+
+/// Splits row changes into added, modified and deleted (each sorted by key).
+fn table_diff(table_name: &str, changes: Vec<RowChange>) -> TableDiff {
+    let mut diff = TableDiff {
+        table_name: table_name.to_string(),
+        added: Vec::new(),
+        modified: Vec::new(),
+        deleted: Vec::new(),
+    };
+
+    for change in changes {
+        match change {
+            RowChange::Insert(row) => diff.added.push(row),
+            RowChange::Update(row) => diff.modified.push(row),
+            RowChange::Delete(key) => diff.deleted.push(key),
+        }
+    }
+
+    diff.added.sort_by(|a, b| a.key.cmp(&b.key));
+    diff.modified.sort_by(|a, b| a.key.cmp(&b.key));
+    diff.deleted.sort();
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::init_registry;
+    use std::fs;
+
+    #[test]
+    fn test_diff_databases() {
+        let temp_dir = std::env::temp_dir().join("reedbase_diff_test");
+        let _ = fs::remove_dir_all(&temp_dir);
+        let own_path = temp_dir.join("own");
+        let other_path = temp_dir.join("other");
+        crate::registry::set_base_path(own_path.clone());
+        init_registry(&own_path).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+
+        let init = |base: &Path, name: &str, content: &[u8]| {
+            Table::new(base, name).init(content, "testuser").unwrap();
+        };
+        init(&own_path, "text", b"key|value\na|1\nb|2\nc|3\n");
+        init(&other_path, "text", b"key|value\na|1\nb|20\nd|4\n");
+        init(&own_path, "same", b"key|value\nx|1\n");
+        init(&other_path, "same", b"key|value\nx|1\n");
+        init(&own_path, "legacy", b"key|value\n");
+        init(&other_path, "routes", b"key|value\n");
+
+        let db = Database::open(&own_path).unwrap();
+        let diff = db.diff(&other_path).unwrap();
+
+        assert_eq!(diff.tables_only_in_self, vec!["legacy"]);
+        assert_eq!(diff.tables_only_in_other, vec!["routes"]);
+        assert_eq!(diff.tables_changed.len(), 1);
+
+        let text = &diff.tables_changed[0];
+        assert_eq!(text.table_name, "text");
+        assert_eq!(text.added, vec![CsvRow::new("d", vec!["4"])]);
+        assert_eq!(text.modified, vec![CsvRow::new("b", vec!["20"])]);
+        assert_eq!(text.deleted, vec!["c"]);
+        assert!(!diff.is_empty());
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+}
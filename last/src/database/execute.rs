@@ -1,12 +1,26 @@
 // Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
 // SPDX-License-Identifier: Apache-2.0
 
-//! Command execution (INSERT/UPDATE/DELETE) via ReedQL.
+//! Command execution (INSERT/UPDATE/DELETE, CREATE/DROP/ALTER TABLE,
+//! CREATE INDEX, COPY) via ReedQL.
 //!
-//! This module handles all data modification operations.
+//! This module handles all data modification operations. It parses a
+//! command (`execute_parse`) and dispatches it to the statement handlers:
+//! `execute_dml` (INSERT/UPDATE/DELETE), `execute_ddl` (ALTER TABLE),
+//! `execute_index` (CREATE/DROP INDEX and index maintenance) and
+//! `execute_copy` (COPY). Batches are run by `execute_batch`.
 
 use crate::database::database::Database;
-use crate::error::{ReedError, ReedResult};
+use crate::database::execute_copy::execute_copy;
+use crate::database::execute_ddl::execute_rename_column;
+use crate::database::execute_dml::{execute_delete, execute_insert, execute_update};
+use crate::database::execute_index::{execute_create_index, execute_drop_index};
+use crate::database::execute_parse::parse_execute_statement;
+use crate::error::ReedResult;
+use crate::reedql::planner::Statistics;
+use crate::reedql::{CopyDirection, CopyOptions, DropIndexStatement};
+use crate::schema::Schema;
+use crate::tables::Table;
 use std::collections::HashMap;
 use std::time::Instant;
 
@@ -24,6 +38,9 @@ pub struct ExecuteResult {
 
     /// Delta size in bytes (for versioning)
     pub delta_size: u64,
+
+    /// Human-readable outcome (e.g. ANALYZE TABLE statistics), empty if none
+    pub summary: String,
 }
 
 impl ExecuteResult {
@@ -34,6 +51,7 @@ impl ExecuteResult {
             execution_time_us: 0,
             timestamp: 0,
             delta_size: 0,
+            summary: String::new(),
         }
     }
 }
@@ -60,6 +78,62 @@ pub enum ExecuteStatement {
         table: String,
         conditions: Vec<FilterCondition>,
     },
+
+    /// CREATE TABLE table (col1 type constraints, ...)
+    CreateTable { table: String, schema: Schema },
+
+    /// DROP TABLE [IF EXISTS] table
+    DropTable { table: String, if_exists: bool },
+
+    /// ALTER TABLE table RENAME COLUMN from TO to
+    RenameColumn {
+        table: String,
+        from: String,
+        to: String,
+    },
+
+    /// CREATE INDEX [name] ON table (col1, col2)
+    CreateIndex {
+        name: Option<String>,
+        table: String,
+        columns: Vec<String>,
+    },
+
+    /// DROP INDEX name | DROP INDEX ON table (col1, col2)
+    DropIndex { index: DropIndexStatement },
+
+    /// ANALYZE TABLE table
+    AnalyzeTable { table: String },
+
+    /// COPY table FROM|TO 'path' WITH (options)
+    Copy {
+        direction: CopyDirection,
+        table: String,
+        path: String,
+        options: CopyOptions,
+    },
+}
+
+impl ExecuteStatement {
+    /// Name of the table the statement modifies (empty for `DROP INDEX name`,
+    /// whose table is only known once the name is resolved).
+    pub fn table(&self) -> &str {
+        match self {
+            ExecuteStatement::Insert { table, .. }
+            | ExecuteStatement::Update { table, .. }
+            | ExecuteStatement::Delete { table, .. }
+            | ExecuteStatement::CreateTable { table, .. }
+            | ExecuteStatement::DropTable { table, .. }
+            | ExecuteStatement::RenameColumn { table, .. }
+            | ExecuteStatement::CreateIndex { table, .. }
+            | ExecuteStatement::AnalyzeTable { table }
+            | ExecuteStatement::Copy { table, .. } => table,
+            ExecuteStatement::DropIndex { index } => match index {
+                DropIndexStatement::On { table, .. } => table,
+                DropIndexStatement::Named(_) => "",
+            },
+        }
+    }
 }
 
 /// Filter condition (simplified version of ReedQL's FilterCondition).
@@ -81,581 +155,136 @@ pub enum FilterCondition {
 /// - `Ok(ExecuteResult)`: Execution metadata
 /// - `Err(ReedError)`: Execution failed
 pub fn execute_command(db: &Database, sql: &str, user: &str) -> ReedResult<ExecuteResult> {
-    let start = Instant::now();
-
     // Parse command
     let statement = parse_execute_statement(sql)?;
 
+    execute_statement(db, &statement, user)
+}
+
+/// Drops cached query results that read `table`.
+pub(crate) fn invalidate_query_cache(db: &Database, table: &str) {
+    if let Some(cache) = db.query_cache().lock().unwrap().as_mut() {
+        cache.invalidate_table(table);
+    }
+}
+
+/// Executes a parsed statement and records it in the statistics.
+pub(crate) fn execute_statement(
+    db: &Database,
+    statement: &ExecuteStatement,
+    user: &str,
+) -> ReedResult<ExecuteResult> {
+    let start = Instant::now();
+
     // Execute based on type (using references to avoid move)
-    let mut result = match &statement {
+    let outcome = match statement {
         ExecuteStatement::Insert {
             table,
             columns,
             values,
-        } => execute_insert(db, table, columns.clone(), values.clone(), user)?,
+        } => execute_insert(db, table, columns.clone(), values.clone(), user),
 
         ExecuteStatement::Update {
             table,
             assignments,
             conditions,
-        } => execute_update(db, table, assignments.clone(), conditions.clone(), user)?,
+        } => execute_update(db, table, assignments.clone(), conditions.clone(), user),
 
         ExecuteStatement::Delete { table, conditions } => {
-            execute_delete(db, table, conditions.clone(), user)?
+            execute_delete(db, table, conditions.clone(), user)
         }
-    };
-
-    result.execution_time_us = start.elapsed().as_micros() as u64;
-
-    // Update statistics
-    let mut stats = db.stats_mut().write().unwrap();
-    match statement {
-        ExecuteStatement::Insert { .. } => stats.insert_count += 1,
-        ExecuteStatement::Update { .. } => stats.update_count += 1,
-        ExecuteStatement::Delete { .. } => stats.delete_count += 1,
-    }
 
-    Ok(result)
-}
-
-/// Parses an execute statement (INSERT/UPDATE/DELETE).
-fn parse_execute_statement(sql: &str) -> ReedResult<ExecuteStatement> {
-    let sql = sql.trim();
-
-    if sql.to_uppercase().starts_with("INSERT") {
-        parse_insert(sql)
-    } else if sql.to_uppercase().starts_with("UPDATE") {
-        parse_update(sql)
-    } else if sql.to_uppercase().starts_with("DELETE") {
-        parse_delete(sql)
-    } else {
-        Err(ReedError::ParseError {
-            reason: format!("Unknown statement type: {}", sql),
-        })
-    }
-}
+        ExecuteStatement::CreateTable { table, schema } => db
+            .create_table(table, Some(schema.clone()))
+            .map(|()| ExecuteResult::new(0)),
 
-/// Parses INSERT statement.
-///
-/// Format: INSERT INTO table (col1, col2) VALUES (val1, val2)
-fn parse_insert(sql: &str) -> ReedResult<ExecuteStatement> {
-    let sql = sql.trim();
-
-    // Extract table name
-    let after_into = sql
-        .to_uppercase()
-        .find("INTO")
-        .ok_or_else(|| ReedError::ParseError {
-            reason: "Missing INTO keyword".to_string(),
-        })?;
-
-    let rest = &sql[after_into + 4..].trim();
-
-    // Find table name (up to opening parenthesis)
-    let paren_pos = rest.find('(').ok_or_else(|| ReedError::ParseError {
-        reason: "Missing column list".to_string(),
-    })?;
-
-    let table = rest[..paren_pos].trim().to_string();
-
-    // Extract columns
-    let values_pos = rest
-        .to_uppercase()
-        .find("VALUES")
-        .ok_or_else(|| ReedError::ParseError {
-            reason: "Missing VALUES keyword".to_string(),
-        })?;
-
-    let columns_str = &rest[paren_pos + 1..values_pos];
-    let columns_end = columns_str
-        .rfind(')')
-        .ok_or_else(|| ReedError::ParseError {
-            reason: "Unclosed column list".to_string(),
-        })?;
-
-    let columns: Vec<String> = columns_str[..columns_end]
-        .split(',')
-        .map(|s| s.trim().to_string())
-        .collect();
-
-    // Extract values
-    let values_rest = &rest[values_pos + 6..].trim();
-    let values_start = values_rest.find('(').ok_or_else(|| ReedError::ParseError {
-        reason: "Missing values list".to_string(),
-    })?;
-    let values_end = values_rest
-        .rfind(')')
-        .ok_or_else(|| ReedError::ParseError {
-            reason: "Unclosed values list".to_string(),
-        })?;
-
-    let values: Vec<String> = values_rest[values_start + 1..values_end]
-        .split(',')
-        .map(|s| {
-            let trimmed = s.trim();
-            // Remove quotes
-            if (trimmed.starts_with('\'') && trimmed.ends_with('\''))
-                || (trimmed.starts_with('"') && trimmed.ends_with('"'))
-            {
-                trimmed[1..trimmed.len() - 1].to_string()
+        ExecuteStatement::DropTable { table, if_exists } => {
+            if *if_exists && !Table::new(db.base_path(), table).exists() {
+                Ok(ExecuteResult::new(0))
             } else {
-                trimmed.to_string()
-            }
-        })
-        .collect();
-
-    if columns.len() != values.len() {
-        return Err(ReedError::ParseError {
-            reason: format!(
-                "Column count ({}) doesn't match value count ({})",
-                columns.len(),
-                values.len()
-            ),
-        });
-    }
-
-    Ok(ExecuteStatement::Insert {
-        table,
-        columns,
-        values,
-    })
-}
-
-/// Parses UPDATE statement.
-///
-/// Format: UPDATE table SET col1 = val1, col2 = val2 WHERE condition
-fn parse_update(sql: &str) -> ReedResult<ExecuteStatement> {
-    let sql = sql.trim();
-
-    // Extract table name
-    let set_pos = sql
-        .to_uppercase()
-        .find("SET")
-        .ok_or_else(|| ReedError::ParseError {
-            reason: "Missing SET keyword".to_string(),
-        })?;
-
-    let table = sql[6..set_pos].trim().to_string(); // Skip "UPDATE"
-
-    // Extract assignments
-    let where_pos = sql.to_uppercase().find("WHERE");
-    let assignments_str = if let Some(pos) = where_pos {
-        &sql[set_pos + 3..pos]
-    } else {
-        &sql[set_pos + 3..]
-    };
-
-    let mut assignments = HashMap::new();
-    for assignment in assignments_str.split(',') {
-        let parts: Vec<&str> = assignment.split('=').collect();
-        if parts.len() != 2 {
-            return Err(ReedError::ParseError {
-                reason: format!("Invalid assignment: {}", assignment),
-            });
-        }
-
-        let column = parts[0].trim().to_string();
-        let value = parts[1].trim();
-        let value_clean = if (value.starts_with('\'') && value.ends_with('\''))
-            || (value.starts_with('"') && value.ends_with('"'))
-        {
-            value[1..value.len() - 1].to_string()
-        } else {
-            value.to_string()
-        };
-
-        assignments.insert(column, value_clean);
-    }
-
-    // Parse WHERE conditions (if present)
-    let conditions = if let Some(pos) = where_pos {
-        parse_simple_where(&sql[pos + 5..])?
-    } else {
-        Vec::new()
-    };
-
-    Ok(ExecuteStatement::Update {
-        table,
-        assignments,
-        conditions,
-    })
-}
-
-/// Parses DELETE statement.
-///
-/// Format: DELETE FROM table WHERE condition
-fn parse_delete(sql: &str) -> ReedResult<ExecuteStatement> {
-    let sql = sql.trim();
-
-    // Extract table name
-    let from_pos = sql
-        .to_uppercase()
-        .find("FROM")
-        .ok_or_else(|| ReedError::ParseError {
-            reason: "Missing FROM keyword".to_string(),
-        })?;
-
-    let where_pos = sql.to_uppercase().find("WHERE");
-    let table = if let Some(pos) = where_pos {
-        sql[from_pos + 4..pos].trim().to_string()
-    } else {
-        sql[from_pos + 4..].trim().to_string()
-    };
-
-    // Parse WHERE conditions (if present)
-    let conditions = if let Some(pos) = where_pos {
-        parse_simple_where(&sql[pos + 5..])?
-    } else {
-        Vec::new()
-    };
-
-    Ok(ExecuteStatement::Delete { table, conditions })
-}
-
-/// Parses simple WHERE clause (column = 'value' AND column = 'value').
-fn parse_simple_where(where_clause: &str) -> ReedResult<Vec<FilterCondition>> {
-    let mut conditions = Vec::new();
-
-    for condition_str in where_clause.split("AND") {
-        let condition_str = condition_str.trim();
-
-        if condition_str.contains("!=") {
-            let parts: Vec<&str> = condition_str.split("!=").collect();
-            if parts.len() == 2 {
-                let column = parts[0].trim().to_string();
-                let value = clean_value(parts[1].trim());
-                conditions.push(FilterCondition::NotEquals { column, value });
-            }
-        } else if condition_str.to_uppercase().contains("LIKE") {
-            let parts: Vec<&str> = condition_str.split_whitespace().collect();
-            if parts.len() >= 3 {
-                let column = parts[0].to_string();
-                let pattern = clean_value(parts[2]);
-                conditions.push(FilterCondition::Like { column, pattern });
-            }
-        } else if condition_str.contains('=') {
-            let parts: Vec<&str> = condition_str.split('=').collect();
-            if parts.len() == 2 {
-                let column = parts[0].trim().to_string();
-                let value = clean_value(parts[1].trim());
-                conditions.push(FilterCondition::Equals { column, value });
+                db.drop_table(table).map(|()| ExecuteResult::new(0))
             }
         }
-    }
-
-    Ok(conditions)
-}
-
-/// Cleans value by removing quotes.
-fn clean_value(value: &str) -> String {
-    let trimmed = value.trim();
-    if (trimmed.starts_with('\'') && trimmed.ends_with('\''))
-        || (trimmed.starts_with('"') && trimmed.ends_with('"'))
-    {
-        trimmed[1..trimmed.len() - 1].to_string()
-    } else {
-        trimmed.to_string()
-    }
-}
-
-/// Executes INSERT statement.
-fn execute_insert(
-    db: &Database,
-    table_name: &str,
-    columns: Vec<String>,
-    values: Vec<String>,
-    user: &str,
-) -> ReedResult<ExecuteResult> {
-    let table = db.get_table(table_name)?;
-
-    // Build new row based on columns
-    let key = columns
-        .iter()
-        .zip(values.iter())
-        .find(|(col, _)| col.as_str() == "key")
-        .map(|(_, val)| val.clone())
-        .unwrap_or_default();
-
-    let row_values: Vec<String> = columns
-        .iter()
-        .skip(1) // Skip key column
-        .zip(values.iter().skip(1))
-        .map(|(_, val)| val.clone())
-        .collect();
-
-    // Create new row line
-    let mut new_row_parts = vec![key];
-    new_row_parts.extend(row_values);
-    let new_row_line = new_row_parts.join("|");
-
-    // Use atomic read-modify-write to prevent race conditions
-    let write_result = table.read_modify_write(
-        |content| {
-            // Append new row to existing content
-            let mut new_content = content.to_vec();
-            new_content.extend_from_slice(new_row_line.as_bytes());
-            new_content.push(b'\n');
-            new_content
-        },
-        user,
-    )?;
-
-    Ok(ExecuteResult {
-        rows_affected: 1,
-        execution_time_us: 0, // Will be set by caller
-        timestamp: write_result.timestamp,
-        delta_size: write_result.delta_size,
-    })
-}
-
-/// Executes UPDATE statement.
-fn execute_update(
-    db: &Database,
-    table_name: &str,
-    assignments: HashMap<String, String>,
-    conditions: Vec<FilterCondition>,
-    user: &str,
-) -> ReedResult<ExecuteResult> {
-    let table = db.get_table(table_name)?;
-
-    // Read current content
-    let content = table.read_current()?;
-    let text = std::str::from_utf8(&content).map_err(|e| ReedError::InvalidCsv {
-        reason: format!("Invalid UTF-8: {}", e),
-        line: 0,
-    })?;
-
-    let lines: Vec<&str> = text.lines().collect();
-    if lines.is_empty() {
-        return Err(ReedError::InvalidCsv {
-            reason: "Empty table".to_string(),
-            line: 0,
-        });
-    }
-
-    let header_line = lines[0];
-    let header_parts: Vec<&str> = header_line.split('|').collect();
-
-    let mut updated = 0;
-    let mut new_lines = vec![header_line.to_string()];
 
-    // Process each row
-    for (i, line) in lines.iter().skip(1).enumerate() {
-        if line.trim().is_empty() {
-            continue;
+        ExecuteStatement::RenameColumn { table, from, to } => {
+            execute_rename_column(db, table, from, to, user)
         }
 
-        let parts: Vec<&str> = line.split('|').collect();
-        let mut row_map = HashMap::new();
-        for (col_idx, col_name) in header_parts.iter().enumerate() {
-            if let Some(value) = parts.get(col_idx) {
-                row_map.insert(col_name.to_string(), value.to_string());
-            }
-        }
-
-        if matches_conditions(&row_map, &conditions) {
-            // Apply updates
-            for (col, val) in &assignments {
-                row_map.insert(col.clone(), val.clone());
-            }
-            updated += 1;
-        }
-
-        // Rebuild row line
-        let row_values: Vec<String> = header_parts
-            .iter()
-            .map(|col| row_map.get(*col).cloned().unwrap_or_default())
-            .collect();
-        new_lines.push(row_values.join("|"));
-    }
-
-    // Write back
-    let new_content = new_lines.join("\n") + "\n";
-    let write_result = table.write(new_content.as_bytes(), user)?;
-
-    Ok(ExecuteResult {
-        rows_affected: updated,
-        execution_time_us: 0,
-        timestamp: write_result.timestamp,
-        delta_size: write_result.delta_size,
-    })
-}
-
-/// Executes DELETE statement.
-fn execute_delete(
-    db: &Database,
-    table_name: &str,
-    conditions: Vec<FilterCondition>,
-    user: &str,
-) -> ReedResult<ExecuteResult> {
-    let table = db.get_table(table_name)?;
-
-    // Read current content
-    let content = table.read_current()?;
-    let text = std::str::from_utf8(&content).map_err(|e| ReedError::InvalidCsv {
-        reason: format!("Invalid UTF-8: {}", e),
-        line: 0,
-    })?;
-
-    let lines: Vec<&str> = text.lines().collect();
-    if lines.is_empty() {
-        return Err(ReedError::InvalidCsv {
-            reason: "Empty table".to_string(),
-            line: 0,
-        });
-    }
-
-    let header_line = lines[0];
-    let header_parts: Vec<&str> = header_line.split('|').collect();
+        ExecuteStatement::CreateIndex {
+            name,
+            table,
+            columns,
+        } => execute_create_index(db, name.as_deref(), table, columns),
 
-    let mut deleted = 0;
-    let mut new_lines = vec![header_line.to_string()];
+        ExecuteStatement::DropIndex { index } => execute_drop_index(db, index),
 
-    // Process each row
-    for line in lines.iter().skip(1) {
-        if line.trim().is_empty() {
-            continue;
+        ExecuteStatement::AnalyzeTable { table } => {
+            crate::database::stats::analyze_table(db, table).map(|stats| {
+                let mut result = ExecuteResult::new(stats.row_count);
+                result.summary = summarise_statistics(table, &stats);
+                result
+            })
         }
 
-        let parts: Vec<&str> = line.split('|').collect();
-        let mut row_map = HashMap::new();
-        for (col_idx, col_name) in header_parts.iter().enumerate() {
-            if let Some(value) = parts.get(col_idx) {
-                row_map.insert(col_name.to_string(), value.to_string());
-            }
-        }
+        ExecuteStatement::Copy {
+            direction,
+            table,
+            path,
+            options,
+        } => execute_copy(db, *direction, table, path, options, user),
+    };
 
-        if matches_conditions(&row_map, &conditions) {
-            // Skip this row (delete it)
-            deleted += 1;
-        } else {
-            // Keep this row
-            new_lines.push(line.to_string());
-        }
+    // Cached results of the table are stale, even if the write failed halfway
+    // (COPY FROM invalidates through the import itself)
+    if !matches!(
+        statement,
+        ExecuteStatement::CreateIndex { .. }
+            | ExecuteStatement::DropIndex { .. }
+            | ExecuteStatement::AnalyzeTable { .. }
+            | ExecuteStatement::Copy { .. }
+    ) {
+        invalidate_query_cache(db, statement.table());
     }
+    let mut result = outcome?;
 
-    // Write back
-    let new_content = new_lines.join("\n") + "\n";
-    let write_result = table.write(new_content.as_bytes(), user)?;
-
-    Ok(ExecuteResult {
-        rows_affected: deleted,
-        execution_time_us: 0,
-        timestamp: write_result.timestamp,
-        delta_size: write_result.delta_size,
-    })
-}
-
-/// Checks if row matches all conditions.
-fn matches_conditions(row: &HashMap<String, String>, conditions: &[FilterCondition]) -> bool {
-    if conditions.is_empty() {
-        return true; // No conditions = match all
-    }
+    result.execution_time_us = start.elapsed().as_micros() as u64;
 
-    for condition in conditions {
-        match condition {
-            FilterCondition::Equals { column, value } => {
-                if row.get(column) != Some(value) {
-                    return false;
-                }
-            }
-            FilterCondition::NotEquals { column, value } => {
-                if row.get(column) == Some(value) {
-                    return false;
-                }
-            }
-            FilterCondition::Like { column, pattern } => {
-                if let Some(val) = row.get(column) {
-                    if !matches_like_pattern(val, pattern) {
-                        return false;
-                    }
-                } else {
-                    return false;
-                }
-            }
-        }
+    // Update statistics
+    let mut stats = db.stats_mut().write().unwrap();
+    match statement {
+        ExecuteStatement::Insert { .. } => stats.insert_count += 1,
+        // A no-op write (nothing changed) has no version timestamp
+        ExecuteStatement::Update { .. } if result.timestamp == 0 => {}
+        ExecuteStatement::Update { .. } => stats.update_count += 1,
+        ExecuteStatement::Delete { .. } => stats.delete_count += 1,
+        // Counted in table_count by create_table()
+        ExecuteStatement::CreateTable { .. } => {}
+        // Counted in table_count by drop_table()
+        ExecuteStatement::DropTable { .. } => {}
+        ExecuteStatement::RenameColumn { .. } => {}
+        // Counted in index_count by the index module
+        ExecuteStatement::CreateIndex { .. } => {}
+        ExecuteStatement::DropIndex { .. } => {}
+        ExecuteStatement::AnalyzeTable { .. } => {}
+        ExecuteStatement::Copy { .. } => {}
     }
 
-    true
-}
-
-/// Matches SQL LIKE pattern (simplified).
-fn matches_like_pattern(value: &str, pattern: &str) -> bool {
-    if pattern.ends_with('%') && !pattern[..pattern.len() - 1].contains('%') {
-        value.starts_with(&pattern[..pattern.len() - 1])
-    } else if pattern.starts_with('%') && !pattern[1..].contains('%') {
-        value.ends_with(&pattern[1..])
-    } else if pattern.starts_with('%')
-        && pattern.ends_with('%')
-        && pattern.matches('%').count() == 2
-    {
-        value.contains(&pattern[1..pattern.len() - 1])
-    } else {
-        value == pattern
-    }
+    Ok(result)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_parse_insert() {
-        let sql = "INSERT INTO text (key, value) VALUES ('page.title', 'Welcome')";
-        let stmt = parse_insert(sql).unwrap();
-
-        match stmt {
-            ExecuteStatement::Insert {
-                table,
-                columns,
-                values,
-            } => {
-                assert_eq!(table, "text");
-                assert_eq!(columns, vec!["key", "value"]);
-                assert_eq!(values, vec!["page.title", "Welcome"]);
-            }
-            _ => panic!("Expected Insert statement"),
-        }
-    }
-
-    #[test]
-    fn test_parse_update() {
-        let sql = "UPDATE text SET value = 'Hello' WHERE key = 'page.title'";
-        let stmt = parse_update(sql).unwrap();
-
-        match stmt {
-            ExecuteStatement::Update {
-                table,
-                assignments,
-                conditions,
-            } => {
-                assert_eq!(table, "text");
-                assert_eq!(assignments.get("value"), Some(&"Hello".to_string()));
-                assert_eq!(conditions.len(), 1);
-            }
-            _ => panic!("Expected Update statement"),
-        }
-    }
-
-    #[test]
-    fn test_parse_delete() {
-        let sql = "DELETE FROM text WHERE key = 'page.title'";
-        let stmt = parse_delete(sql).unwrap();
-
-        match stmt {
-            ExecuteStatement::Delete { table, conditions } => {
-                assert_eq!(table, "text");
-                assert_eq!(conditions.len(), 1);
-            }
-            _ => panic!("Expected Delete statement"),
-        }
-    }
-
-    #[test]
-    fn test_matches_like_pattern() {
-        assert!(matches_like_pattern("page.title@de", "%.@de"));
-        assert!(matches_like_pattern("page.title@de", "page.%"));
-        assert!(matches_like_pattern("page.title@de", "%title%"));
-        assert!(!matches_like_pattern("page.title@en", "%.@de"));
+/// Summary of ANALYZE TABLE: totals, then one line per column.
+fn summarise_statistics(table: &str, stats: &Statistics) -> String {
+    let mut summary = format!(
+        "{}: {} rows, {:.1} bytes per row, page cost {:.1}",
+        table, stats.row_count, stats.avg_row_size, stats.page_cost
+    );
+    for (column, column_stats) in &stats.columns {
+        summary.push_str(&format!(
+            "\n  {}: ~{} distinct, {:.1}% empty",
+            column,
+            column_stats.cardinality,
+            column_stats.null_fraction * 100.0
+        ));
     }
+    summary
 }
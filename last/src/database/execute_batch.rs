@@ -0,0 +1,170 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Batch execution: runs several commands as one unit and restores
+//! the affected tables if one of them fails.
+
+use crate::concurrent::acquire_lock;
+use crate::database::database::Database;
+use crate::database::execute::{
+    execute_statement, invalidate_query_cache, ExecuteResult, ExecuteStatement,
+};
+use crate::database::execute_parse::parse_execute_statement;
+use crate::error::{ReedError, ReedResult};
+use crate::reedql::CopyDirection;
+use crate::tables::Table;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Maximum time `execute_batch` waits for each table's write lock.
+const BATCH_LOCK_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Name of the pre-batch copy of current.csv in the table directory.
+const BATCH_STAGING_FILE: &str = "batch_snapshot.tmp";
+
+/// Action code recorded in version.log when a batch is undone (see actions.dict).
+const ROLLBACK_ACTION_CODE: u8 = 3;
+
+/// Executes several commands as a single unit.
+///
+/// All statements are parsed first, then the write lock of every affected
+/// table is taken (in name order, to avoid lock-order deadlocks with other
+/// batches) and its current.csv is copied to a staging file before anything
+/// is written. If a statement fails, every staged table is restored as a new
+/// `rollback` version; on success the staging files are discarded.
+///
+/// This is a best-effort transaction: readers can see the intermediate
+/// states of a batch, and indices updated by statements before the failing
+/// one are not reverted.
+///
+/// ## Input
+/// - `db`: Database reference
+/// - `statements`: ReedQL commands, executed in order
+/// - `user`: Username for audit trail
+///
+/// ## Output
+/// - `Ok(Vec<ExecuteResult>)`: One result per statement
+/// - `Err(ReedError)`: Error of the failing statement (batch rolled back)
+///
+/// ## Performance
+/// - Sum of the individual statements plus one file copy per table
+/// - Failure adds one write per changed table
+///
+/// ## Error Conditions
+/// - `BatchFailed`: Statement `statement` (0-based) is malformed, a
+///   COPY FROM or a DROP TABLE (nothing executed), or failed (batch rolled
+///   back); wraps the statement's error
+/// - `LockTimeout`: Table lock not acquired within 30s (nothing executed)
+/// - `IoError`: Cannot stage a table (nothing executed)
+/// - Restore errors take precedence over the statement error
+pub fn execute_batch(
+    db: &Database,
+    statements: &[&str],
+    user: &str,
+) -> ReedResult<Vec<ExecuteResult>> {
+    let parsed = statements
+        .iter()
+        .enumerate()
+        .map(|(i, sql)| {
+            let statement = parse_execute_statement(sql).map_err(|e| batch_failed(i, e))?;
+            // Both take the table lock the batch already holds
+            let command = match statement {
+                ExecuteStatement::Copy {
+                    direction: CopyDirection::From,
+                    ..
+                } => "COPY FROM",
+                ExecuteStatement::DropTable { .. } => "DROP TABLE",
+                _ => return Ok(statement),
+            };
+            Err(batch_failed(
+                i,
+                ReedError::ParseError {
+                    reason: format!("{} cannot be used in a batch", command),
+                },
+            ))
+        })
+        .collect::<ReedResult<Vec<_>>>()?;
+
+    // Lock existing tables only; statements on missing tables fail on execution
+    let mut table_names: Vec<&str> = parsed.iter().map(ExecuteStatement::table).collect();
+    table_names.sort_unstable();
+    table_names.dedup();
+    let tables: Vec<Table> = table_names
+        .iter()
+        .map(|name| Table::new(db.base_path(), name))
+        .filter(Table::exists)
+        .collect();
+
+    let _locks = tables
+        .iter()
+        .map(|table| acquire_lock(db.base_path(), table.name(), BATCH_LOCK_TIMEOUT))
+        .collect::<ReedResult<Vec<_>>>()?;
+    let snapshots = tables
+        .into_iter()
+        .map(Snapshot::take)
+        .collect::<ReedResult<Vec<_>>>()?;
+
+    let mut results = Vec::with_capacity(parsed.len());
+    for (i, statement) in parsed.iter().enumerate() {
+        match execute_statement(db, statement, user) {
+            Ok(result) => results.push(result),
+            Err(e) => {
+                for snapshot in &snapshots {
+                    snapshot.restore(db, user)?;
+                }
+                return Err(batch_failed(i, e));
+            }
+        }
+    }
+
+    // Commit: dropping the snapshots removes the staging files
+    Ok(results)
+}
+
+/// Wraps the error of batch statement `index`.
+fn batch_failed(index: usize, error: ReedError) -> ReedError {
+    ReedError::BatchFailed {
+        statement: index,
+        error: Box::new(error),
+    }
+}
+
+/// Copy of a table's current.csv, staged in the table directory before a
+/// batch writes. The staging file is removed when the snapshot is dropped.
+struct Snapshot {
+    table: Table,
+    path: PathBuf,
+}
+
+impl Snapshot {
+    /// Copies current.csv to the staging file (caller holds the table lock).
+    fn take(table: Table) -> ReedResult<Self> {
+        let path = table.current_path().with_file_name(BATCH_STAGING_FILE);
+        fs::copy(table.current_path(), &path).map_err(|e| ReedError::IoError {
+            operation: "stage_table".to_string(),
+            reason: e.to_string(),
+        })?;
+        Ok(Self { table, path })
+    }
+
+    /// Writes the staged content back as a new version, if it changed.
+    fn restore(&self, db: &Database, user: &str) -> ReedResult<()> {
+        let staged = fs::read(&self.path).map_err(|e| ReedError::IoError {
+            operation: "read_staged_table".to_string(),
+            reason: e.to_string(),
+        })?;
+        if self.table.read_current()? != staged {
+            self.table
+                .write_with_action(&staged, user, ROLLBACK_ACTION_CODE)?;
+            invalidate_query_cache(db, self.table.name());
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Snapshot {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
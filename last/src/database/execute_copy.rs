@@ -0,0 +1,117 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! COPY FROM / COPY TO execution.
+
+use crate::database::database::Database;
+use crate::database::execute::ExecuteResult;
+use crate::error::{ReedError, ReedResult};
+use crate::reedql::{CopyDirection, CopyOptions};
+use std::path::{Component, Path, PathBuf};
+
+/// Executes COPY through `Database::import_csv()` / `Database::export_csv()`.
+///
+/// The path is resolved against the current working directory and must
+/// stay inside it. `rows_affected` is the number of rows imported or
+/// exported.
+pub(crate) fn execute_copy(
+    db: &Database,
+    direction: CopyDirection,
+    table: &str,
+    path: &str,
+    options: &CopyOptions,
+    user: &str,
+) -> ReedResult<ExecuteResult> {
+    let cwd = std::env::current_dir().map_err(|e| ReedError::IoError {
+        operation: "current_dir".to_string(),
+        reason: e.to_string(),
+    })?;
+    let file = resolve_copy_path(path, &cwd)?;
+
+    match direction {
+        CopyDirection::From => {
+            let report = db.import_csv(
+                table,
+                &file,
+                Some(options.delimiter),
+                options.header,
+                user,
+                None,
+            )?;
+            let mut result = ExecuteResult::new(report.rows_imported);
+            result.summary = format!(
+                "{}: {} rows imported, {} skipped, {} unparsable",
+                table,
+                report.rows_imported,
+                report.rows_skipped,
+                report.parsing_errors.len()
+            );
+            Ok(result)
+        }
+        CopyDirection::To => {
+            let report = db.export_csv(
+                table,
+                &file,
+                Some(options.delimiter),
+                options.header,
+                None,
+                user,
+            )?;
+            let mut result = ExecuteResult::new(report.rows_exported);
+            result.summary = format!("{}: {} rows exported", table, report.rows_exported);
+            Ok(result)
+        }
+    }
+}
+
+/// Resolves a COPY path against `root`, refusing paths that leave it.
+///
+/// `..` is resolved lexically, then symlinks in the existing part of the
+/// path are followed, so neither `../x` nor a link pointing elsewhere can
+/// escape. The file itself need not exist yet (COPY TO).
+///
+/// ## Error Conditions
+/// - `PermissionDenied`: Path resolves outside `root`, or to `root` itself
+/// - `IoError`: Cannot resolve `root` or an existing path component
+pub(crate) fn resolve_copy_path(path: &str, root: &Path) -> ReedResult<PathBuf> {
+    let denied = || ReedError::PermissionDenied {
+        path: path.to_string(),
+    };
+    let io_error = |e: std::io::Error| ReedError::IoError {
+        operation: "resolve_copy_path".to_string(),
+        reason: e.to_string(),
+    };
+
+    let root = root.canonicalize().map_err(io_error)?;
+    let mut normalised = PathBuf::new();
+    for component in root.join(path).components() {
+        match component {
+            Component::ParentDir => {
+                if !normalised.pop() {
+                    return Err(denied());
+                }
+            }
+            Component::CurDir => {}
+            other => normalised.push(other),
+        }
+    }
+
+    let mut existing = normalised.as_path();
+    let mut missing = Vec::new();
+    while !existing.exists() {
+        match (existing.file_name(), existing.parent()) {
+            (Some(name), Some(parent)) => {
+                missing.push(name);
+                existing = parent;
+            }
+            _ => return Err(denied()),
+        }
+    }
+    let mut resolved = existing.canonicalize().map_err(io_error)?;
+    resolved.extend(missing.iter().rev());
+
+    if resolved == root || !resolved.starts_with(&root) {
+        return Err(denied());
+    }
+    Ok(resolved)
+}
@@ -0,0 +1,181 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! ALTER TABLE execution (RENAME COLUMN).
+
+use crate::database::database::Database;
+use crate::database::execute::ExecuteResult;
+use crate::database::types::IndexBackend;
+use crate::error::{ReedError, ReedResult};
+use crate::schema::{load_schema, save_schema, schema_exists};
+
+/// Renames a column in the header, the schema and the indices.
+///
+/// The header change is written as a new version. A schema, if the table
+/// has one, is updated in place; indices covering the column are dropped
+/// and rebuilt under the new name (partial index predicates are kept as
+/// written). `rows_affected` is the table's row count.
+///
+/// ## Error Conditions
+/// - `ColumnNotFound`: `from` is not in the header
+/// - `ColumnAlreadyExists`: `to` is already a column
+/// - `ValidationError`: `to` contains the delimiter or a line break, or a
+///   computed column reads `from`
+/// - `InvalidCsv`: Table has no header row
+pub(crate) fn execute_rename_column(
+    db: &Database,
+    table_name: &str,
+    from: &str,
+    to: &str,
+    user: &str,
+) -> ReedResult<ExecuteResult> {
+    let table = db.get_table(table_name)?;
+    let separator = table.delimiter()?.as_char();
+    let original = table.read_current()?;
+    let text = std::str::from_utf8(&original).map_err(|e| ReedError::InvalidCsv {
+        reason: format!("Invalid UTF-8: {}", e),
+        line: 0,
+    })?;
+
+    let mut header_line = None;
+    let mut rows = 0;
+    for (i, line) in text.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if header_line.is_none() {
+            header_line = Some(i);
+        } else {
+            rows += 1;
+        }
+    }
+    let header_line = header_line.ok_or_else(|| ReedError::InvalidCsv {
+        reason: "Missing header row".to_string(),
+        line: 1,
+    })?;
+
+    let mut header: Vec<String> = text
+        .lines()
+        .nth(header_line)
+        .unwrap_or_default()
+        .trim()
+        .split(separator)
+        .map(str::to_string)
+        .collect();
+    let index = header
+        .iter()
+        .position(|c| c == from)
+        .ok_or_else(|| ReedError::ColumnNotFound {
+            table: table_name.to_string(),
+            column: from.to_string(),
+        })?;
+    if header.iter().any(|c| c == to) {
+        return Err(ReedError::ColumnAlreadyExists {
+            table: table_name.to_string(),
+            column: to.to_string(),
+        });
+    }
+    if to.contains([separator, '\n', '\r']) {
+        return Err(ReedError::ValidationError {
+            column: to.to_string(),
+            reason: format!(
+                "Column name must not contain {:?} or line breaks",
+                separator
+            ),
+            value: None,
+        });
+    }
+
+    let schema = if schema_exists(db.base_path(), table_name) {
+        let mut schema = load_schema(db.base_path(), table_name)?;
+        if let Some(computed) = schema.columns.iter().find(|c| {
+            c.computed_from
+                .as_ref()
+                .is_some_and(|expr| expr.columns().any(|name| name == from))
+        }) {
+            return Err(ReedError::ValidationError {
+                column: from.to_string(),
+                reason: format!("Computed column '{}' reads this column", computed.name),
+                value: None,
+            });
+        }
+        if let Some(column) = schema.columns.iter_mut().find(|c| c.name == from) {
+            column.name = to.to_string();
+        }
+        Some(schema)
+    } else {
+        None
+    };
+
+    header[index] = to.to_string();
+    let mut content = String::with_capacity(text.len());
+    for (i, line) in text.lines().enumerate() {
+        if i == header_line {
+            content.push_str(&header.join(&separator.to_string()));
+        } else {
+            content.push_str(line);
+        }
+        content.push('\n');
+    }
+    let write = table.write(content.as_bytes(), user)?;
+
+    if let Some(schema) = &schema {
+        if let Err(e) = save_schema(db.base_path(), table_name, schema) {
+            // Keep content and schema in step
+            table.write(&original, user)?;
+            return Err(e);
+        }
+        db.schema_registry().lock().unwrap().invalidate(table_name);
+    }
+
+    rename_indexed_column(db, table_name, from, to)?;
+
+    let mut result = ExecuteResult::new(rows);
+    result.timestamp = write.timestamp;
+    result.delta_size = write.delta_size;
+    Ok(result)
+}
+
+/// Drops the indices covering `from` and rebuilds them over `to`, keeping
+/// predicates and names.
+fn rename_indexed_column(db: &Database, table_name: &str, from: &str, to: &str) -> ReedResult<()> {
+    let prefix = format!("{}.", table_name);
+    let affected: Vec<String> = db
+        .indices()
+        .read()
+        .unwrap()
+        .keys()
+        .filter_map(|key| key.strip_prefix(&prefix))
+        .filter(|columns| columns.split(',').any(|c| c == from))
+        .map(str::to_string)
+        .collect();
+
+    for columns in affected {
+        let index_key = format!("{}{}", prefix, columns);
+        let predicate = db
+            .index_predicates()
+            .read()
+            .unwrap()
+            .get(&index_key)
+            .cloned();
+        let name = db.index_names().read().unwrap().get(&index_key).cloned();
+        crate::database::index::drop_index(db, table_name, &columns)?;
+
+        let renamed: Vec<&str> = columns
+            .split(',')
+            .map(|c| if c == from { to } else { c })
+            .collect();
+        crate::database::index::create_index_on_columns(
+            db,
+            table_name,
+            &renamed,
+            IndexBackend::BTree,
+            false,
+            predicate,
+            name.as_deref(),
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,268 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! INSERT/UPDATE/DELETE execution.
+
+use crate::database::database::Database;
+use crate::database::execute::{ExecuteResult, FilterCondition};
+use crate::database::execute_index::{add_to_indices, remove_from_indices, update_indices};
+use crate::database::execute_validate::{
+    computed_schema, reject_computed, validate_against_schema, validate_unique_columns,
+};
+use crate::error::{ReedError, ReedResult};
+use crate::schema::{apply_computed_columns, CsvRow};
+use crate::tables::{CsvRow as TableRow, RowEdit};
+use std::collections::HashMap;
+
+/// Executes INSERT statement.
+pub(crate) fn execute_insert(
+    db: &Database,
+    table_name: &str,
+    columns: Vec<String>,
+    values: Vec<String>,
+    user: &str,
+) -> ReedResult<ExecuteResult> {
+    let table = db.get_table(table_name)?;
+
+    // Build new row based on columns
+    let key = columns
+        .iter()
+        .zip(values.iter())
+        .find(|(col, _)| col.as_str() == "key")
+        .map(|(_, val)| val.clone())
+        .unwrap_or_default();
+
+    let row_values: Vec<String> = columns
+        .iter()
+        .skip(1) // Skip key column
+        .zip(values.iter().skip(1))
+        .map(|(_, val)| val.clone())
+        .collect();
+
+    // Create new row line
+    let mut new_row_parts = vec![key];
+    new_row_parts.extend(row_values);
+    if let Some(schema) = computed_schema(db, table_name)? {
+        // Schema order by name, computed columns filled in
+        reject_computed(&schema, columns.iter())?;
+        let fields = schema
+            .columns
+            .iter()
+            .map(|column| {
+                columns
+                    .iter()
+                    .position(|name| *name == column.name)
+                    .and_then(|i| values.get(i).cloned())
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+        if let Some(unknown) = columns.iter().find(|c| schema.get_column(c).is_none()) {
+            return Err(ReedError::ColumnNotFound {
+                table: table_name.to_string(),
+                column: unknown.clone(),
+            });
+        }
+        let mut row = CsvRow::new(fields[0].clone(), fields);
+        apply_computed_columns(&mut row, &schema)?;
+        new_row_parts = row.values;
+    }
+    validate_against_schema(db, table_name, std::slice::from_ref(&new_row_parts))?;
+    validate_unique_columns(db, table_name, &new_row_parts)?;
+    let separator = table.delimiter()?.as_char();
+    let new_row_line = new_row_parts.join(&separator.to_string());
+
+    // Use atomic read-modify-write to prevent race conditions
+    let mut appended = None;
+    let write_result = table.read_modify_write(
+        |content| {
+            // Header and row ID of the new line, for index maintenance
+            let text = String::from_utf8_lossy(content);
+            let mut lines = text.lines();
+            let header: Vec<String> = lines
+                .next()
+                .map(|line| line.split(separator).map(String::from).collect())
+                .unwrap_or_default();
+            appended = Some((header, lines.count()));
+
+            // Append new row to existing content
+            let mut new_content = content.to_vec();
+            new_content.extend_from_slice(new_row_line.as_bytes());
+            new_content.push(b'\n');
+            new_content
+        },
+        user,
+    )?;
+
+    if let Some((header, row_id)) = appended {
+        add_to_indices(db, table_name, &header, row_id, &new_row_parts)?;
+    }
+
+    Ok(ExecuteResult {
+        rows_affected: 1,
+        execution_time_us: 0, // Will be set by caller
+        timestamp: write_result.timestamp,
+        delta_size: write_result.delta_size,
+        summary: String::new(),
+    })
+}
+
+/// Executes UPDATE statement.
+///
+/// Only matched lines are rewritten, so every row keeps its position and
+/// the table's indices are patched for the changed values alone.
+pub(crate) fn execute_update(
+    db: &Database,
+    table_name: &str,
+    assignments: HashMap<String, String>,
+    conditions: Vec<FilterCondition>,
+    user: &str,
+) -> ReedResult<ExecuteResult> {
+    let table = db.get_table(table_name)?;
+
+    let computed = computed_schema(db, table_name)?;
+    if let Some(schema) = &computed {
+        reject_computed(schema, assignments.keys())?;
+    }
+
+    let mut updated = 0;
+    let mut changes = Vec::new();
+    let write_result = table.rewrite_rows(
+        |row_id, row| {
+            let old = row_map(row);
+            if !matches_conditions(&old, &conditions) {
+                return Ok(RowEdit::Keep);
+            }
+            updated += 1;
+
+            // Apply updates
+            let mut new = old.clone();
+            for (col, val) in &assignments {
+                new.insert(col.clone(), val.clone());
+            }
+            let mut row_values: Vec<String> = row
+                .columns
+                .iter()
+                .map(|col| new.get(col).cloned().unwrap_or_default())
+                .collect();
+            if let Some(schema) = &computed {
+                let mut row = CsvRow::new(row_values[0].clone(), row_values);
+                apply_computed_columns(&mut row, schema)?;
+                row_values = row.values;
+            }
+
+            let new = row
+                .columns
+                .iter()
+                .cloned()
+                .zip(row_values.clone())
+                .collect();
+            changes.push((row_id, old, new));
+            Ok(RowEdit::Replace(row_values))
+        },
+        |updated_rows| validate_against_schema(db, table_name, updated_rows),
+        user,
+    )?;
+    if !write_result.is_noop() {
+        update_indices(db, table_name, &changes)?;
+    }
+
+    Ok(ExecuteResult {
+        rows_affected: updated,
+        execution_time_us: 0,
+        timestamp: write_result.timestamp,
+        delta_size: write_result.delta_size,
+        summary: String::new(),
+    })
+}
+
+/// Executes DELETE statement.
+pub(crate) fn execute_delete(
+    db: &Database,
+    table_name: &str,
+    conditions: Vec<FilterCondition>,
+    user: &str,
+) -> ReedResult<ExecuteResult> {
+    let table = db.get_table(table_name)?;
+
+    // Kept lines are copied unchanged, streaming through a temp file
+    let mut removed = Vec::new();
+    let write_result = table.rewrite_rows(
+        |row_id, row| {
+            let row = row_map(row);
+            if !matches_conditions(&row, &conditions) {
+                return Ok(RowEdit::Keep);
+            }
+            removed.push((row_id, row));
+            Ok(RowEdit::Remove)
+        },
+        |_| Ok(()),
+        user,
+    )?;
+    let deleted = removed.len();
+    remove_from_indices(db, table_name, &removed)?;
+
+    Ok(ExecuteResult {
+        rows_affected: deleted,
+        execution_time_us: 0,
+        timestamp: write_result.timestamp,
+        delta_size: write_result.delta_size,
+        summary: String::new(),
+    })
+}
+
+/// Column name to value map of a streamed row (missing fields left out).
+fn row_map(row: &TableRow) -> HashMap<String, String> {
+    row.columns
+        .iter()
+        .filter_map(|column| Some((column.clone(), row.get(column)?.to_string())))
+        .collect()
+}
+
+/// Checks if row matches all conditions.
+fn matches_conditions(row: &HashMap<String, String>, conditions: &[FilterCondition]) -> bool {
+    if conditions.is_empty() {
+        return true; // No conditions = match all
+    }
+
+    for condition in conditions {
+        match condition {
+            FilterCondition::Equals { column, value } => {
+                if row.get(column) != Some(value) {
+                    return false;
+                }
+            }
+            FilterCondition::NotEquals { column, value } => {
+                if row.get(column) == Some(value) {
+                    return false;
+                }
+            }
+            FilterCondition::Like { column, pattern } => {
+                if let Some(val) = row.get(column) {
+                    if !matches_like_pattern(val, pattern) {
+                        return false;
+                    }
+                } else {
+                    return false;
+                }
+            }
+        }
+    }
+
+    true
+}
+
+/// Matches SQL LIKE pattern (simplified).
+pub(crate) fn matches_like_pattern(value: &str, pattern: &str) -> bool {
+    if pattern.ends_with('%') && !pattern[..pattern.len() - 1].contains('%') {
+        value.starts_with(&pattern[..pattern.len() - 1])
+    } else if pattern.starts_with('%') && !pattern[1..].contains('%') {
+        value.ends_with(&pattern[1..])
+    } else if pattern.starts_with('%')
+        && pattern.ends_with('%')
+        && pattern.matches('%').count() == 2
+    {
+        value.contains(&pattern[1..pattern.len() - 1])
+    } else {
+        value == pattern
+    }
+}
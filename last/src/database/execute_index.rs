@@ -0,0 +1,230 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! CREATE/DROP INDEX execution and index maintenance for written rows.
+
+use crate::database::database::Database;
+use crate::database::execute::ExecuteResult;
+use crate::error::{ReedError, ReedResult};
+use crate::indices::builder::composite_key;
+use crate::indices::Index;
+use crate::reedql::DropIndexStatement;
+use std::collections::HashMap;
+
+/// Executes CREATE INDEX (single-column or composite, optionally named).
+pub(crate) fn execute_create_index(
+    db: &Database,
+    name: Option<&str>,
+    table_name: &str,
+    columns: &[String],
+) -> ReedResult<ExecuteResult> {
+    let columns: Vec<&str> = columns.iter().map(String::as_str).collect();
+
+    match (name, columns.as_slice()) {
+        (Some(name), _) => {
+            crate::database::index::create_named_index(db, name, table_name, &columns)?
+        }
+        (None, [column]) => crate::database::index::create_index(db, table_name, column)?,
+        (None, _) => crate::database::index::create_composite_index(db, table_name, &columns)?,
+    }
+
+    Ok(ExecuteResult::new(0))
+}
+
+/// Executes DROP INDEX through `Database::drop_index()`.
+///
+/// A name is resolved to its index first; `IndexNotFound` if no index has it.
+pub(crate) fn execute_drop_index(
+    db: &Database,
+    index: &DropIndexStatement,
+) -> ReedResult<ExecuteResult> {
+    let (table, column) = match index {
+        DropIndexStatement::On { table, columns } => (table.clone(), columns.join(",")),
+        DropIndexStatement::Named(name) => {
+            let index_key = crate::database::index::find_index_by_name(db, name)
+                .ok_or_else(|| ReedError::IndexNotFound { name: name.clone() })?;
+            let (table, column) = index_key.split_once('.').unwrap_or((&index_key, ""));
+            (table.to_string(), column.to_string())
+        }
+    };
+
+    db.drop_index(&table, &column)?;
+    Ok(ExecuteResult::new(0))
+}
+
+/// Adds an inserted row to the loaded indices of its table.
+///
+/// Keeps the indices usable for `validate_unique_columns()` and lookups
+/// after an INSERT. Partial indices only take rows matching their predicate.
+pub(crate) fn add_to_indices(
+    db: &Database,
+    table_name: &str,
+    header: &[String],
+    row_id: usize,
+    fields: &[String],
+) -> ReedResult<()> {
+    let prefix = format!("{}.", table_name);
+    let mut indices = db.indices().write().unwrap();
+    let predicates = db.index_predicates().read().unwrap();
+    let row: HashMap<String, String> = header.iter().cloned().zip(fields.to_vec()).collect();
+
+    for (index_key, index) in indices.iter_mut() {
+        let Some(columns) = index_key.strip_prefix(&prefix) else {
+            continue;
+        };
+        if let Some(value) = index_value(columns, predicates.get(index_key), &row)? {
+            add_row_id(index.as_mut(), value, row_id)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Row ID, old and new column values of an updated row.
+type RowChange = (usize, HashMap<String, String>, HashMap<String, String>);
+
+/// Moves updated rows to their new values in the loaded indices of a table.
+///
+/// Row IDs don't change on UPDATE, so only indices whose value changed are
+/// touched; index files and metadata stay as they are.
+pub(crate) fn update_indices(
+    db: &Database,
+    table_name: &str,
+    changes: &[RowChange],
+) -> ReedResult<()> {
+    let prefix = format!("{}.", table_name);
+    let mut indices = db.indices().write().unwrap();
+    let predicates = db.index_predicates().read().unwrap();
+
+    for (index_key, index) in indices.iter_mut() {
+        let Some(columns) = index_key.strip_prefix(&prefix) else {
+            continue;
+        };
+        let predicate = predicates.get(index_key);
+        for (row_id, old, new) in changes {
+            let before = index_value(columns, predicate, old)?;
+            let after = index_value(columns, predicate, new)?;
+            if before == after {
+                continue;
+            }
+            if let Some(value) = before {
+                remove_row_id(index.as_mut(), &value, *row_id)?;
+            }
+            if let Some(value) = after {
+                add_row_id(index.as_mut(), value, *row_id)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Drops deleted rows from the loaded indices of a table.
+///
+/// `removed` holds the row IDs (ascending) and column values of the deleted
+/// rows. Every later row moves up by the number of deleted rows before it,
+/// so the entries of each index are remapped from the index itself, without
+/// reading the table. When no remaining row ID shifts (e.g. the last rows
+/// were deleted), only the keys of the deleted rows are touched.
+///
+/// ## Performance
+/// - O(k log n) per index without shifted rows, O(n) per index otherwise
+pub(crate) fn remove_from_indices(
+    db: &Database,
+    table_name: &str,
+    removed: &[(usize, HashMap<String, String>)],
+) -> ReedResult<()> {
+    let Some(&(first_removed, _)) = removed.first() else {
+        return Ok(());
+    };
+    let removed_ids: Vec<usize> = removed.iter().map(|(row_id, _)| *row_id).collect();
+    let prefix = format!("{}.", table_name);
+    let mut indices = db.indices().write().unwrap();
+    let predicates = db.index_predicates().read().unwrap();
+
+    for (index_key, index) in indices.iter_mut() {
+        let Some(columns) = index_key.strip_prefix(&prefix) else {
+            continue;
+        };
+
+        let entries: Vec<(String, Vec<usize>)> = index.iter().collect();
+        let shifted = entries
+            .iter()
+            .flat_map(|(_, row_ids)| row_ids)
+            .any(|&row_id| row_id > first_removed && removed_ids.binary_search(&row_id).is_err());
+
+        if !shifted {
+            for (row_id, row) in removed {
+                if let Some(value) = index_value(columns, predicates.get(index_key), row)? {
+                    remove_row_id(index.as_mut(), &value, *row_id)?;
+                }
+            }
+            continue;
+        }
+
+        let mut remapped: Vec<(String, Vec<usize>)> = entries
+            .into_iter()
+            .filter_map(|(value, row_ids)| {
+                let row_ids: Vec<usize> = row_ids
+                    .into_iter()
+                    .filter(|row_id| removed_ids.binary_search(row_id).is_err())
+                    .map(|row_id| row_id - removed_ids.partition_point(|&id| id < row_id))
+                    .collect();
+                (!row_ids.is_empty()).then_some((value, row_ids))
+            })
+            .collect();
+        remapped.sort_by(|a, b| a.0.cmp(&b.0));
+        index.replace_all(remapped)?;
+    }
+
+    Ok(())
+}
+
+/// Index value of a row for the index on `columns`.
+///
+/// `None` if the row is outside a partial index or lacks one of the columns.
+/// Composite indices are registered as `table.col1,col2`.
+fn index_value(
+    columns: &str,
+    predicate: Option<&crate::reedql::types::FilterCondition>,
+    row: &HashMap<String, String>,
+) -> ReedResult<Option<String>> {
+    if let Some(predicate) = predicate {
+        if !predicate.matches(row)? {
+            return Ok(None);
+        }
+    }
+    Ok(columns
+        .split(',')
+        .map(|column| row.get(column))
+        .collect::<Option<Vec<&String>>>()
+        .map(|values| composite_key(&values)))
+}
+
+/// Adds a row ID to the IDs stored under `value`, keeping them sorted.
+fn add_row_id(
+    index: &mut dyn Index<String, Vec<usize>>,
+    value: String,
+    row_id: usize,
+) -> ReedResult<()> {
+    let mut rows = index.get(&value)?.unwrap_or_default();
+    if let Err(pos) = rows.binary_search(&row_id) {
+        rows.insert(pos, row_id);
+    }
+    index.insert(value, rows)
+}
+
+/// Removes a row ID from `value`, deleting the key once no row is left.
+fn remove_row_id(
+    index: &mut dyn Index<String, Vec<usize>>,
+    value: &String,
+    row_id: usize,
+) -> ReedResult<()> {
+    let mut rows = index.get(value)?.unwrap_or_default();
+    rows.retain(|&id| id != row_id);
+    if rows.is_empty() {
+        index.delete(value)
+    } else {
+        index.insert(value.clone(), rows)
+    }
+}
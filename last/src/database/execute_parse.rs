@@ -0,0 +1,337 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Parsing of ReedQL commands into `ExecuteStatement`s.
+//!
+//! INSERT/UPDATE/DELETE are parsed here; the DDL, ANALYZE and COPY
+//! statements are delegated to the ReedQL parser.
+
+use crate::database::execute::{ExecuteStatement, FilterCondition};
+use crate::error::{ReedError, ReedResult};
+use crate::reedql::{
+    parse_alter_table, parse_analyze, parse_copy, parse_create_index, parse_create_table,
+    parse_drop_index, parse_drop_table, AlterTableAction,
+};
+use std::collections::HashMap;
+
+/// Parses an execute statement (INSERT/UPDATE/DELETE, CREATE/DROP/ALTER
+/// TABLE, CREATE/DROP INDEX, ANALYZE TABLE, COPY).
+pub(crate) fn parse_execute_statement(sql: &str) -> ReedResult<ExecuteStatement> {
+    let sql = sql.trim();
+
+    if sql.to_uppercase().starts_with("CREATE TABLE") {
+        parse_create_table_statement(sql)
+    } else if sql.to_uppercase().starts_with("ALTER TABLE") {
+        parse_alter_table_statement(sql)
+    } else if sql.to_uppercase().starts_with("DROP TABLE") {
+        parse_drop_table_statement(sql)
+    } else if sql.to_uppercase().starts_with("CREATE INDEX") {
+        parse_create_index_statement(sql)
+    } else if sql.to_uppercase().starts_with("DROP INDEX") {
+        parse_drop_index_statement(sql)
+    } else if sql.to_uppercase().starts_with("ANALYZE") {
+        parse_analyze_table(sql)
+    } else if sql.to_uppercase().starts_with("COPY") {
+        parse_copy_statement(sql)
+    } else if sql.to_uppercase().starts_with("INSERT") {
+        parse_insert(sql)
+    } else if sql.to_uppercase().starts_with("UPDATE") {
+        parse_update(sql)
+    } else if sql.to_uppercase().starts_with("DELETE") {
+        parse_delete(sql)
+    } else {
+        Err(ReedError::ParseError {
+            reason: format!("Unknown statement type: {}", sql),
+        })
+    }
+}
+
+/// Parses INSERT statement.
+///
+/// Format: INSERT INTO table (col1, col2) VALUES (val1, val2)
+pub(crate) fn parse_insert(sql: &str) -> ReedResult<ExecuteStatement> {
+    let sql = sql.trim();
+
+    // Extract table name
+    let after_into = sql
+        .to_uppercase()
+        .find("INTO")
+        .ok_or_else(|| ReedError::ParseError {
+            reason: "Missing INTO keyword".to_string(),
+        })?;
+
+    let rest = &sql[after_into + 4..].trim();
+
+    // Find table name (up to opening parenthesis)
+    let paren_pos = rest.find('(').ok_or_else(|| ReedError::ParseError {
+        reason: "Missing column list".to_string(),
+    })?;
+
+    let table = rest[..paren_pos].trim().to_string();
+
+    // Extract columns
+    let values_pos = rest
+        .to_uppercase()
+        .find("VALUES")
+        .ok_or_else(|| ReedError::ParseError {
+            reason: "Missing VALUES keyword".to_string(),
+        })?;
+
+    let columns_str = &rest[paren_pos + 1..values_pos];
+    let columns_end = columns_str
+        .rfind(')')
+        .ok_or_else(|| ReedError::ParseError {
+            reason: "Unclosed column list".to_string(),
+        })?;
+
+    let columns: Vec<String> = columns_str[..columns_end]
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .collect();
+
+    // Extract values
+    let values_rest = &rest[values_pos + 6..].trim();
+    let values_start = values_rest.find('(').ok_or_else(|| ReedError::ParseError {
+        reason: "Missing values list".to_string(),
+    })?;
+    let values_end = values_rest
+        .rfind(')')
+        .ok_or_else(|| ReedError::ParseError {
+            reason: "Unclosed values list".to_string(),
+        })?;
+
+    let values: Vec<String> = values_rest[values_start + 1..values_end]
+        .split(',')
+        .map(|s| {
+            let trimmed = s.trim();
+            // Remove quotes
+            if (trimmed.starts_with('\'') && trimmed.ends_with('\''))
+                || (trimmed.starts_with('"') && trimmed.ends_with('"'))
+            {
+                trimmed[1..trimmed.len() - 1].to_string()
+            } else {
+                trimmed.to_string()
+            }
+        })
+        .collect();
+
+    if columns.len() != values.len() {
+        return Err(ReedError::ParseError {
+            reason: format!(
+                "Column count ({}) doesn't match value count ({})",
+                columns.len(),
+                values.len()
+            ),
+        });
+    }
+
+    Ok(ExecuteStatement::Insert {
+        table,
+        columns,
+        values,
+    })
+}
+
+/// Parses UPDATE statement.
+///
+/// Format: UPDATE table SET col1 = val1, col2 = val2 WHERE condition
+pub(crate) fn parse_update(sql: &str) -> ReedResult<ExecuteStatement> {
+    let sql = sql.trim();
+
+    // Extract table name
+    let set_pos = sql
+        .to_uppercase()
+        .find("SET")
+        .ok_or_else(|| ReedError::ParseError {
+            reason: "Missing SET keyword".to_string(),
+        })?;
+
+    let table = sql[6..set_pos].trim().to_string(); // Skip "UPDATE"
+
+    // Extract assignments
+    let where_pos = sql.to_uppercase().find("WHERE");
+    let assignments_str = if let Some(pos) = where_pos {
+        &sql[set_pos + 3..pos]
+    } else {
+        &sql[set_pos + 3..]
+    };
+
+    let mut assignments = HashMap::new();
+    for assignment in assignments_str.split(',') {
+        let parts: Vec<&str> = assignment.split('=').collect();
+        if parts.len() != 2 {
+            return Err(ReedError::ParseError {
+                reason: format!("Invalid assignment: {}", assignment),
+            });
+        }
+
+        let column = parts[0].trim().to_string();
+        let value = parts[1].trim();
+        let value_clean = if (value.starts_with('\'') && value.ends_with('\''))
+            || (value.starts_with('"') && value.ends_with('"'))
+        {
+            value[1..value.len() - 1].to_string()
+        } else {
+            value.to_string()
+        };
+
+        assignments.insert(column, value_clean);
+    }
+
+    // Parse WHERE conditions (if present)
+    let conditions = if let Some(pos) = where_pos {
+        parse_simple_where(&sql[pos + 5..])?
+    } else {
+        Vec::new()
+    };
+
+    Ok(ExecuteStatement::Update {
+        table,
+        assignments,
+        conditions,
+    })
+}
+
+/// Parses DELETE statement.
+///
+/// Format: DELETE FROM table WHERE condition
+pub(crate) fn parse_delete(sql: &str) -> ReedResult<ExecuteStatement> {
+    let sql = sql.trim();
+
+    // Extract table name
+    let from_pos = sql
+        .to_uppercase()
+        .find("FROM")
+        .ok_or_else(|| ReedError::ParseError {
+            reason: "Missing FROM keyword".to_string(),
+        })?;
+
+    let where_pos = sql.to_uppercase().find("WHERE");
+    let table = if let Some(pos) = where_pos {
+        sql[from_pos + 4..pos].trim().to_string()
+    } else {
+        sql[from_pos + 4..].trim().to_string()
+    };
+
+    // Parse WHERE conditions (if present)
+    let conditions = if let Some(pos) = where_pos {
+        parse_simple_where(&sql[pos + 5..])?
+    } else {
+        Vec::new()
+    };
+
+    Ok(ExecuteStatement::Delete { table, conditions })
+}
+
+/// Parses ANALYZE TABLE statement.
+///
+/// Format: ANALYZE TABLE table
+fn parse_analyze_table(sql: &str) -> ReedResult<ExecuteStatement> {
+    Ok(ExecuteStatement::AnalyzeTable {
+        table: parse_analyze(sql)?,
+    })
+}
+
+/// Parses CREATE TABLE via the ReedQL parser.
+fn parse_create_table_statement(sql: &str) -> ReedResult<ExecuteStatement> {
+    let create = parse_create_table(sql)?;
+    Ok(ExecuteStatement::CreateTable {
+        table: create.table,
+        schema: create.schema,
+    })
+}
+
+/// Parses DROP TABLE via the ReedQL parser.
+fn parse_drop_table_statement(sql: &str) -> ReedResult<ExecuteStatement> {
+    let drop = parse_drop_table(sql)?;
+    Ok(ExecuteStatement::DropTable {
+        table: drop.table,
+        if_exists: drop.if_exists,
+    })
+}
+
+/// Parses CREATE INDEX via the ReedQL parser.
+fn parse_create_index_statement(sql: &str) -> ReedResult<ExecuteStatement> {
+    let create = parse_create_index(sql)?;
+    Ok(ExecuteStatement::CreateIndex {
+        name: create.name,
+        table: create.table,
+        columns: create.columns,
+    })
+}
+
+/// Parses DROP INDEX via the ReedQL parser.
+fn parse_drop_index_statement(sql: &str) -> ReedResult<ExecuteStatement> {
+    Ok(ExecuteStatement::DropIndex {
+        index: parse_drop_index(sql)?,
+    })
+}
+
+/// Parses ALTER TABLE via the ReedQL parser.
+fn parse_alter_table_statement(sql: &str) -> ReedResult<ExecuteStatement> {
+    let alter = parse_alter_table(sql)?;
+    match alter.action {
+        AlterTableAction::RenameColumn { from, to } => Ok(ExecuteStatement::RenameColumn {
+            table: alter.table,
+            from,
+            to,
+        }),
+    }
+}
+
+/// Parses COPY via the ReedQL parser.
+fn parse_copy_statement(sql: &str) -> ReedResult<ExecuteStatement> {
+    let copy = parse_copy(sql)?;
+    Ok(ExecuteStatement::Copy {
+        direction: copy.direction,
+        table: copy.table,
+        path: copy.path,
+        options: copy.options,
+    })
+}
+
+/// Parses simple WHERE clause (column = 'value' AND column = 'value').
+fn parse_simple_where(where_clause: &str) -> ReedResult<Vec<FilterCondition>> {
+    let mut conditions = Vec::new();
+
+    for condition_str in where_clause.split("AND") {
+        let condition_str = condition_str.trim();
+
+        if condition_str.contains("!=") {
+            let parts: Vec<&str> = condition_str.split("!=").collect();
+            if parts.len() == 2 {
+                let column = parts[0].trim().to_string();
+                let value = clean_value(parts[1].trim());
+                conditions.push(FilterCondition::NotEquals { column, value });
+            }
+        } else if condition_str.to_uppercase().contains("LIKE") {
+            let parts: Vec<&str> = condition_str.split_whitespace().collect();
+            if parts.len() >= 3 {
+                let column = parts[0].to_string();
+                let pattern = clean_value(parts[2]);
+                conditions.push(FilterCondition::Like { column, pattern });
+            }
+        } else if condition_str.contains('=') {
+            let parts: Vec<&str> = condition_str.split('=').collect();
+            if parts.len() == 2 {
+                let column = parts[0].trim().to_string();
+                let value = clean_value(parts[1].trim());
+                conditions.push(FilterCondition::Equals { column, value });
+            }
+        }
+    }
+
+    Ok(conditions)
+}
+
+/// Cleans value by removing quotes.
+fn clean_value(value: &str) -> String {
+    let trimmed = value.trim();
+    if (trimmed.starts_with('\'') && trimmed.ends_with('\''))
+        || (trimmed.starts_with('"') && trimmed.ends_with('"'))
+    {
+        trimmed[1..trimmed.len() - 1].to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
@@ -0,0 +1,1089 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for command execution.
+
+#[cfg(test)]
+mod tests {
+    use crate::database::database::Database;
+    use crate::database::execute::ExecuteStatement;
+    use crate::database::execute_copy::resolve_copy_path;
+    use crate::database::execute_dml::matches_like_pattern;
+    use crate::database::execute_parse::{
+        parse_delete, parse_execute_statement, parse_insert, parse_update,
+    };
+    use crate::database::types::IndexBackend;
+    use crate::error::ReedError;
+    use crate::indices::builder::composite_key;
+    use crate::reedql::{CopyDirection, CopyOptions, DropIndexStatement};
+    use crate::tables::Table;
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    #[test]
+    fn test_parse_insert() {
+        let sql = "INSERT INTO text (key, value) VALUES ('page.title', 'Welcome')";
+        let stmt = parse_insert(sql).unwrap();
+
+        match stmt {
+            ExecuteStatement::Insert {
+                table,
+                columns,
+                values,
+            } => {
+                assert_eq!(table, "text");
+                assert_eq!(columns, vec!["key", "value"]);
+                assert_eq!(values, vec!["page.title", "Welcome"]);
+            }
+            _ => panic!("Expected Insert statement"),
+        }
+    }
+
+    #[test]
+    fn test_parse_update() {
+        let sql = "UPDATE text SET value = 'Hello' WHERE key = 'page.title'";
+        let stmt = parse_update(sql).unwrap();
+
+        match stmt {
+            ExecuteStatement::Update {
+                table,
+                assignments,
+                conditions,
+            } => {
+                assert_eq!(table, "text");
+                assert_eq!(assignments.get("value"), Some(&"Hello".to_string()));
+                assert_eq!(conditions.len(), 1);
+            }
+            _ => panic!("Expected Update statement"),
+        }
+    }
+
+    #[test]
+    fn test_parse_delete() {
+        let sql = "DELETE FROM text WHERE key = 'page.title'";
+        let stmt = parse_delete(sql).unwrap();
+
+        match stmt {
+            ExecuteStatement::Delete { table, conditions } => {
+                assert_eq!(table, "text");
+                assert_eq!(conditions.len(), 1);
+            }
+            _ => panic!("Expected Delete statement"),
+        }
+    }
+
+    #[test]
+    fn test_parse_create_index() {
+        let stmt = parse_execute_statement("create index on users ( country , city )").unwrap();
+        assert_eq!(
+            stmt,
+            ExecuteStatement::CreateIndex {
+                name: None,
+                table: "users".to_string(),
+                columns: vec!["country".to_string(), "city".to_string()],
+            }
+        );
+        assert_eq!(
+            parse_execute_statement("DROP INDEX by_city").unwrap(),
+            ExecuteStatement::DropIndex {
+                index: DropIndexStatement::Named("by_city".to_string()),
+            }
+        );
+
+        for sql in [
+            "CREATE INDEX users (country)",
+            "CREATE INDEX ON users country",
+            "CREATE INDEX ON users (country, )",
+            "CREATE INDEX ON (country)",
+            "CREATE INDEX ON users (country) extra",
+        ] {
+            assert!(
+                matches!(
+                    parse_execute_statement(sql),
+                    Err(ReedError::ParseError { .. })
+                ),
+                "{}",
+                sql
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_analyze_table() {
+        assert_eq!(
+            parse_execute_statement("analyze table users;").unwrap(),
+            ExecuteStatement::AnalyzeTable {
+                table: "users".to_string(),
+            }
+        );
+        for sql in ["ANALYZE", "ANALYZE users", "ANALYZE TABLE users extra"] {
+            assert!(parse_execute_statement(sql).is_err(), "{}", sql);
+        }
+    }
+
+    #[test]
+    fn test_parse_copy_statement() {
+        let stmt =
+            parse_execute_statement("COPY text TO 'out.tsv' WITH (DELIMITER '\\t', HEADER true)")
+                .unwrap();
+        assert_eq!(
+            stmt,
+            ExecuteStatement::Copy {
+                direction: CopyDirection::To,
+                table: "text".to_string(),
+                path: "out.tsv".to_string(),
+                options: CopyOptions {
+                    delimiter: b'\t',
+                    header: true
+                },
+            }
+        );
+        assert_eq!(stmt.table(), "text");
+    }
+
+    #[test]
+    fn test_execute_create_table() {
+        let temp_dir = std::env::temp_dir().join("reedbase_execute_create_table_test");
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        crate::registry::set_base_path(temp_dir.clone());
+        crate::registry::init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+
+        let db = Database::open(&temp_dir).unwrap();
+        db.execute(
+            "CREATE TABLE users (key integer PRIMARY KEY, name varchar(8) NOT NULL, age int)",
+            "testuser",
+        )
+        .unwrap();
+
+        let table = Table::new(&temp_dir, "users");
+        assert_eq!(table.read_current().unwrap(), b"key|name|age\n");
+        let schema = db.describe_table("users").unwrap().unwrap();
+        assert_eq!(
+            schema,
+            crate::schema::load_schema(&temp_dir, "users").unwrap()
+        );
+        assert_eq!(schema.columns[1].max_length, Some(8));
+
+        // The saved schema is enforced on writes
+        db.execute(
+            "INSERT INTO users (key, name, age) VALUES ('1', 'Jane', '30')",
+            "testuser",
+        )
+        .unwrap();
+        assert!(db
+            .execute(
+                "INSERT INTO users (key, name, age) VALUES ('2', 'Jane', 'old')",
+                "testuser",
+            )
+            .is_err());
+        assert!(db
+            .execute(
+                "INSERT INTO users (key, name, age) VALUES ('3', 'Maximilian', '41')",
+                "testuser",
+            )
+            .is_err());
+
+        assert!(matches!(
+            db.execute("CREATE TABLE users (id integer)", "testuser"),
+            Err(ReedError::TableAlreadyExists { .. })
+        ));
+        assert!(matches!(
+            db.execute(
+                "CREATE TABLE bad (name text, id integer PRIMARY KEY)",
+                "testuser"
+            ),
+            Err(ReedError::InvalidSchema { .. })
+        ));
+        assert!(matches!(
+            db.execute("CREATE TABLE bad (id integer, id text)", "testuser"),
+            Err(ReedError::InvalidSchema { .. })
+        ));
+        assert!(!crate::schema::schema_exists(&temp_dir, "bad"));
+
+        db.create_table("notes", None).unwrap();
+        assert_eq!(db.describe_table("notes").unwrap(), None);
+        assert!(matches!(
+            db.describe_table("missing"),
+            Err(ReedError::TableNotFound { .. })
+        ));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_execute_drop_table() {
+        let temp_dir = std::env::temp_dir().join("reedbase_execute_drop_table_test");
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        crate::registry::set_base_path(temp_dir.clone());
+        crate::registry::init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+
+        let db = Database::open(&temp_dir).unwrap();
+        db.execute(
+            "CREATE TABLE users (key string PRIMARY KEY, city string)",
+            "testuser",
+        )
+        .unwrap();
+        db.create_table("text", None).unwrap();
+        db.execute(
+            "INSERT INTO users (key, city) VALUES ('u1', 'Berlin')",
+            "testuser",
+        )
+        .unwrap();
+        db.create_index("users", "city").unwrap();
+        let tables_before = db.stats().table_count;
+        assert!(db.list_indices().iter().any(|i| i.table == "users"));
+
+        db.execute("DROP TABLE users", "testuser").unwrap();
+        assert!(!Table::new(&temp_dir, "users").exists());
+        assert!(!temp_dir.join("tables/users").exists());
+        assert!(db.list_indices().iter().all(|i| i.table != "users"));
+        assert!(crate::database::index::load_index_metadata(&db)
+            .unwrap()
+            .iter()
+            .all(|m| m.table != "users"));
+        let leftovers: Vec<_> = std::fs::read_dir(temp_dir.join("indices"))
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_name().to_string_lossy().starts_with("users"))
+            .collect();
+        assert!(leftovers.is_empty());
+        assert_eq!(db.stats().table_count, tables_before - 1);
+        assert!(matches!(
+            db.query("SELECT * FROM users"),
+            Err(ReedError::TableNotFound { .. })
+        ));
+        assert!(Table::new(&temp_dir, "text").exists());
+
+        assert!(matches!(
+            db.execute("DROP TABLE users", "testuser"),
+            Err(ReedError::TableNotFound { .. })
+        ));
+        assert_eq!(
+            db.execute("DROP TABLE IF EXISTS users", "testuser")
+                .unwrap()
+                .rows_affected,
+            0
+        );
+        match db.execute_batch(&["DROP TABLE text"], "testuser") {
+            Err(ReedError::BatchFailed { statement, error }) => {
+                assert_eq!(statement, 0);
+                assert!(matches!(*error, ReedError::ParseError { .. }));
+            }
+            other => panic!("Expected BatchFailed, got {:?}", other),
+        }
+
+        // The name can be reused
+        db.drop_table("text").unwrap();
+        db.create_table("users", None).unwrap();
+        assert_eq!(db.describe_table("users").unwrap(), None);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_execute_rename_column() {
+        let temp_dir = std::env::temp_dir().join("reedbase_execute_rename_column_test");
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        crate::registry::set_base_path(temp_dir.clone());
+        crate::registry::init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+
+        let db = Database::open(&temp_dir).unwrap();
+        db.execute(
+            "CREATE TABLE users (key string PRIMARY KEY, mail string NOT NULL, city string)",
+            "testuser",
+        )
+        .unwrap();
+        let table = Table::new(&temp_dir, "users");
+        table
+            .write(
+                b"key|mail|city\nu1|a@x.org|Berlin\nu2|b@x.org|Wien\n",
+                "testuser",
+            )
+            .unwrap();
+        db.create_index("users", "mail").unwrap();
+        db.create_composite_index("users", &["city", "mail"])
+            .unwrap();
+        let versions = table.list_versions().unwrap().len();
+
+        let result = db
+            .execute("ALTER TABLE users RENAME COLUMN mail TO email", "testuser")
+            .unwrap();
+        assert_eq!(result.rows_affected, 2);
+        assert_eq!(
+            table.read_current().unwrap(),
+            b"key|email|city\nu1|a@x.org|Berlin\nu2|b@x.org|Wien\n"
+        );
+        assert_eq!(table.list_versions().unwrap().len(), versions + 1);
+
+        let schema = db.describe_table("users").unwrap().unwrap();
+        let names: Vec<&str> = schema.columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["key", "email", "city"]);
+        assert!(schema.columns[1].required);
+
+        let mut indexed: Vec<String> = db
+            .list_indices()
+            .into_iter()
+            .filter(|i| i.table == "users" && i.column != "key")
+            .map(|i| i.column)
+            .collect();
+        indexed.sort();
+        assert_eq!(indexed, vec!["city,email", "email"]);
+
+        match db
+            .query("SELECT key FROM users WHERE email = 'b@x.org'")
+            .unwrap()
+        {
+            crate::reedql::QueryResult::Rows(rows) => assert_eq!(rows[0]["key"], "u2"),
+            other => panic!("Expected rows, got {:?}", other),
+        }
+
+        assert!(matches!(
+            db.execute("ALTER TABLE users RENAME COLUMN mail TO x", "testuser"),
+            Err(ReedError::ColumnNotFound { .. })
+        ));
+        assert!(matches!(
+            db.execute("ALTER TABLE users RENAME COLUMN email TO city", "testuser"),
+            Err(ReedError::ColumnAlreadyExists { .. })
+        ));
+        assert!(matches!(
+            db.execute("ALTER TABLE missing RENAME COLUMN a TO b", "testuser"),
+            Err(ReedError::TableNotFound { .. })
+        ));
+        assert_eq!(table.list_versions().unwrap().len(), versions + 1);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_resolve_copy_path() {
+        let root = std::env::temp_dir().join("reedbase_copy_path_test");
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("data")).unwrap();
+        let canonical = root.canonicalize().unwrap();
+
+        assert_eq!(
+            resolve_copy_path("data/text.csv", &root).unwrap(),
+            canonical.join("data/text.csv")
+        );
+        assert_eq!(
+            resolve_copy_path("./data/../new/text.csv", &root).unwrap(),
+            canonical.join("new/text.csv")
+        );
+        let absolute = canonical.join("text.csv");
+        assert_eq!(
+            resolve_copy_path(absolute.to_str().unwrap(), &root).unwrap(),
+            absolute
+        );
+
+        for path in ["../text.csv", "data/../../text.csv", "/etc/passwd", ".", ""] {
+            assert!(
+                matches!(
+                    resolve_copy_path(path, &root),
+                    Err(ReedError::PermissionDenied { .. })
+                ),
+                "{} should be refused",
+                path
+            );
+        }
+
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(std::env::temp_dir(), root.join("escape")).unwrap();
+            assert!(matches!(
+                resolve_copy_path("escape/text.csv", &root),
+                Err(ReedError::PermissionDenied { .. })
+            ));
+        }
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_execute_copy() {
+        let temp_dir = std::env::temp_dir().join("reedbase_execute_copy_test");
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        crate::registry::set_base_path(temp_dir.clone());
+        crate::registry::init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+
+        let db = Database::open(&temp_dir).unwrap();
+        Table::new(&temp_dir, "text")
+            .init(
+                b"key|value\npage.title|Welcome\npage.intro|Hi, there\n",
+                "testuser",
+            )
+            .unwrap();
+
+        // COPY paths are relative to the working directory
+        let dir = "target/reedbase_execute_copy_test";
+        let _ = std::fs::remove_dir_all(dir);
+        std::fs::create_dir_all(dir).unwrap();
+
+        let result = db
+            .execute(
+                &format!("COPY text TO '{}/text.csv' WITH (HEADER true)", dir),
+                "testuser",
+            )
+            .unwrap();
+        assert_eq!(result.rows_affected, 2);
+        assert_eq!(
+            std::fs::read_to_string(format!("{}/text.csv", dir)).unwrap(),
+            "key,value\npage.title,Welcome\npage.intro,\"Hi, there\"\n"
+        );
+
+        let result = db
+            .execute(
+                &format!(
+                    "COPY copied FROM '{}/text.csv' WITH (FORMAT CSV, DELIMITER ',', HEADER true)",
+                    dir
+                ),
+                "testuser",
+            )
+            .unwrap();
+        assert_eq!(result.rows_affected, 2);
+        assert_eq!(
+            Table::new(&temp_dir, "copied").read_current().unwrap(),
+            Table::new(&temp_dir, "text").read_current().unwrap()
+        );
+
+        assert!(matches!(
+            db.execute("COPY text TO '../text.csv'", "testuser"),
+            Err(ReedError::PermissionDenied { .. })
+        ));
+        match db.execute_batch(
+            &[
+                "DELETE FROM text WHERE key = 'page.title'",
+                &format!("COPY text FROM '{}/text.csv'", dir),
+            ],
+            "testuser",
+        ) {
+            Err(ReedError::BatchFailed { statement, error }) => {
+                assert_eq!(statement, 1);
+                assert!(matches!(*error, ReedError::ParseError { .. }));
+            }
+            other => panic!("Expected BatchFailed, got {:?}", other),
+        }
+
+        let _ = std::fs::remove_dir_all(dir);
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_execute_analyze_table() {
+        use crate::database::stats::load_table_statistics;
+        use crate::reedql::planner::DEFAULT_INDEX_SELECTIVITY;
+
+        let temp_dir = std::env::temp_dir().join("reedbase_execute_analyze_test");
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        crate::registry::set_base_path(temp_dir.clone());
+        crate::registry::init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+
+        let db = Database::open(&temp_dir).unwrap();
+        Table::new(&temp_dir, "users")
+            .init(
+                b"key|country|city\nu1|DE|Berlin\nu2|DE|\nu3|AT|Wien\nu4|AT|\n",
+                "testuser",
+            )
+            .unwrap();
+        assert_eq!(load_table_statistics(&temp_dir, "users").unwrap(), None);
+
+        let result = db.execute("ANALYZE TABLE users", "testuser").unwrap();
+        assert_eq!(result.rows_affected, 4);
+        let stats = load_table_statistics(&temp_dir, "users").unwrap().unwrap();
+        assert_eq!(stats.row_count, 4);
+        assert_eq!(stats.index_selectivity, DEFAULT_INDEX_SELECTIVITY);
+        assert!(stats.page_cost > 1.0);
+        // "u1|DE|Berlin\n" and "u3|AT|Wien\n" (13 + 11), "u2|DE|\n" twice (7)
+        assert_eq!(stats.avg_row_size, 9.5);
+
+        let column = |name: &str| stats.columns[name];
+        assert_eq!(column("key").cardinality, 4);
+        assert_eq!(column("country").cardinality, 2);
+        assert_eq!(column("city").cardinality, 2);
+        assert_eq!(column("country").null_fraction, 0.0);
+        assert_eq!(column("city").null_fraction, 0.5);
+        assert_eq!(stats.equality_selectivity("city"), 0.25);
+
+        assert!(result
+            .summary
+            .starts_with("users: 4 rows, 9.5 bytes per row"));
+        assert!(result
+            .summary
+            .contains("\n  city: ~2 distinct, 50.0% empty"));
+        assert!(temp_dir.join("tables/users/.stats").exists());
+
+        // Two distinct countries: an equality returns half the rows
+        db.create_index("users", "country").unwrap();
+        let stats = db.analyze_table("users").unwrap();
+        assert_eq!(stats.index_selectivity, 0.5);
+        assert_eq!(
+            load_table_statistics(&temp_dir, "users").unwrap(),
+            Some(stats)
+        );
+
+        assert!(matches!(
+            db.execute("ANALYZE TABLE missing", "testuser"),
+            Err(ReedError::TableNotFound { .. })
+        ));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_execute_create_composite_index() {
+        let temp_dir = std::env::temp_dir().join("reedbase_execute_composite_test");
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        crate::registry::set_base_path(temp_dir.clone());
+        crate::registry::init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+
+        let db = Database::open(&temp_dir).unwrap();
+        Table::new(&temp_dir, "users")
+            .init(
+                b"key|country|city\nu1|DE|Berlin\nu2|DE|Hamburg\nu3|AT|Berlin\n",
+                "testuser",
+            )
+            .unwrap();
+
+        db.execute("CREATE INDEX ON users (country, city)", "testuser")
+            .unwrap();
+        db.execute("CREATE INDEX ON users (city)", "testuser")
+            .unwrap();
+        let mut columns: Vec<String> = db.list_indices().into_iter().map(|i| i.column).collect();
+        columns.sort();
+        assert_eq!(columns, vec!["city", "country,city"]);
+
+        assert!(matches!(
+            db.execute("CREATE INDEX ON users (country, city)", "testuser"),
+            Err(ReedError::IndexAlreadyExists { .. })
+        ));
+        assert!(db
+            .execute("CREATE INDEX ON users (country, zip)", "testuser")
+            .is_err());
+
+        // Inserts keep the composite index in step
+        db.execute(
+            "INSERT INTO users (key, country, city) VALUES ('u4', 'DE', 'Berlin')",
+            "testuser",
+        )
+        .unwrap();
+        let indices = db.indices().read().unwrap();
+        let index = indices.get("users.country,city").unwrap();
+        assert_eq!(
+            index.get(&composite_key(&["DE", "Berlin"])).unwrap(),
+            Some(vec![0, 3])
+        );
+        assert_eq!(
+            index.get(&composite_key(&["AT", "Berlin"])).unwrap(),
+            Some(vec![2])
+        );
+        assert_eq!(index.get(&composite_key(&["AT", "Hamburg"])).unwrap(), None);
+        drop(indices);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_execute_create_and_drop_named_index() {
+        let temp_dir = std::env::temp_dir().join("reedbase_execute_drop_index_test");
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        crate::registry::set_base_path(temp_dir.clone());
+        crate::registry::init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+
+        let db = Database::open(&temp_dir).unwrap();
+        Table::new(&temp_dir, "users")
+            .init(b"key|country|city\nu1|DE|Berlin\nu2|AT|Wien\n", "testuser")
+            .unwrap();
+
+        db.execute("CREATE INDEX by_place ON users (country, city)", "testuser")
+            .unwrap();
+        db.execute("CREATE INDEX ON users (city)", "testuser")
+            .unwrap();
+        let mut names: Vec<String> = db.list_indices().into_iter().map(|i| i.name).collect();
+        names.sort();
+        assert_eq!(names, vec!["by_place", "users_city_idx"]);
+
+        // Names are unique, including default names
+        assert!(matches!(
+            db.execute("CREATE INDEX users_city_idx ON users (country)", "testuser"),
+            Err(ReedError::IndexAlreadyExists { .. })
+        ));
+
+        // Names survive reopening
+        let db = Database::open(&temp_dir).unwrap();
+        let btree = temp_dir.join("indices").join("users.country,city.btree");
+        assert!(btree.exists());
+        db.execute("DROP INDEX by_place", "testuser").unwrap();
+        assert!(!btree.exists());
+        assert!(!temp_dir
+            .join("indices")
+            .join("users.country,city.wal")
+            .exists());
+
+        db.execute("DROP INDEX ON users (city)", "testuser")
+            .unwrap();
+        assert!(db.list_indices().is_empty());
+        assert_eq!(db.stats().index_count, 0);
+        assert!(crate::database::index::load_index_metadata(&db)
+            .unwrap()
+            .is_empty());
+
+        // Dropped indices stay gone after reopening
+        let db = Database::open(&temp_dir).unwrap();
+        assert!(db.list_indices().is_empty());
+        assert!(matches!(
+            db.execute("DROP INDEX by_place", "testuser"),
+            Err(ReedError::IndexNotFound { .. })
+        ));
+        assert!(matches!(
+            db.drop_index("users", "city"),
+            Err(ReedError::IndexNotFound { .. })
+        ));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_create_partial_index() {
+        let temp_dir = std::env::temp_dir().join("reedbase_execute_partial_test");
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        crate::registry::set_base_path(temp_dir.clone());
+        crate::registry::init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+
+        let db = Database::open(&temp_dir).unwrap();
+        Table::new(&temp_dir, "users")
+            .init(
+                b"key|country|city\nu1|DE|Berlin\nu2|DE|Hamburg\nu3|AT|Berlin\n",
+                "testuser",
+            )
+            .unwrap();
+
+        let predicate = crate::reedql::parse_condition("country = 'DE'").unwrap();
+        db.create_partial_index("users", "city", predicate.clone())
+            .unwrap();
+        let info = db.list_indices().pop().unwrap();
+        assert_eq!(info.column, "city");
+        assert_eq!(info.predicate, Some(predicate.clone()));
+
+        // Only matching rows are indexed, on creation and on insert
+        db.execute(
+            "INSERT INTO users (key, country, city) VALUES ('u4', 'AT', 'Hamburg')",
+            "testuser",
+        )
+        .unwrap();
+        db.execute(
+            "INSERT INTO users (key, country, city) VALUES ('u5', 'DE', 'Berlin')",
+            "testuser",
+        )
+        .unwrap();
+        {
+            let indices = db.indices().read().unwrap();
+            let index = indices.get("users.city").unwrap();
+            assert_eq!(index.get(&"Berlin".to_string()).unwrap(), Some(vec![0, 4]));
+            assert_eq!(index.get(&"Hamburg".to_string()).unwrap(), Some(vec![1]));
+        }
+
+        // Predicate survives reopening
+        drop(db);
+        let db = Database::open(&temp_dir).unwrap();
+        assert_eq!(db.list_indices().pop().unwrap().predicate, Some(predicate));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_execute_batch_rolls_back_on_failure() {
+        let temp_dir = std::env::temp_dir().join("reedbase_execute_batch_test");
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        crate::registry::set_base_path(temp_dir.clone());
+        crate::registry::init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+
+        let db = Database::open(&temp_dir).unwrap();
+        let table = Table::new(&temp_dir, "text");
+        table
+            .init(b"key|value\npage.title|Welcome\n", "testuser")
+            .unwrap();
+        let original = table.read_current().unwrap();
+
+        let result = db.execute_batch(
+            &[
+                "INSERT INTO text (key, value) VALUES ('page.intro', 'Hello')",
+                "UPDATE text SET value = 'Changed' WHERE key = 'page.title'",
+                "UPDATE missing SET value = 'x' WHERE key = 'page.title'",
+                "INSERT INTO text (key, value) VALUES ('page.footer', 'Bye')",
+                "DELETE FROM text WHERE key = 'page.title'",
+            ],
+            "testuser",
+        );
+
+        match result {
+            Err(ReedError::BatchFailed { statement, error }) => {
+                assert_eq!(statement, 2);
+                assert!(matches!(*error, ReedError::TableNotFound { .. }));
+            }
+            other => panic!("Expected BatchFailed, got {:?}", other),
+        }
+        assert_eq!(table.read_current().unwrap(), original);
+        assert_eq!(table.list_versions().unwrap()[0].action, "rollback");
+        assert!(!temp_dir.join("tables/text/batch_snapshot.tmp").exists());
+
+        // Malformed statements fail before anything runs
+        assert!(matches!(
+            db.execute_batch(
+                &[
+                    "INSERT INTO text (key, value) VALUES ('page.intro', 'Hello')",
+                    "UPSERT text",
+                ],
+                "testuser",
+            ),
+            Err(ReedError::BatchFailed { statement: 1, .. })
+        ));
+        assert_eq!(table.read_current().unwrap(), original);
+
+        let results = db
+            .execute_batch(
+                &[
+                    "INSERT INTO text (key, value) VALUES ('page.intro', 'Hello')",
+                    "UPDATE text SET value = 'Changed' WHERE key = 'page.title'",
+                ],
+                "testuser",
+            )
+            .unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[1].rows_affected, 1);
+        assert_eq!(
+            table.read_current().unwrap(),
+            b"key|value\npage.title|Changed\npage.intro|Hello\n"
+        );
+        assert!(!temp_dir.join("tables/text/batch_snapshot.tmp").exists());
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_execute_validates_against_reloaded_schema() {
+        use crate::schema::{save_schema, ColumnDef, Schema};
+
+        let temp_dir = std::env::temp_dir().join("reedbase_execute_schema_test");
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        crate::registry::set_base_path(temp_dir.clone());
+        crate::registry::init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+
+        let db = Database::open(&temp_dir).unwrap();
+        Table::new(&temp_dir, "scores")
+            .init(b"key|points\nalice|10\n", "testuser")
+            .unwrap();
+        let integer_schema = Schema::new(
+            "2.0".to_string(),
+            true,
+            vec![
+                ColumnDef::primary_key("key".to_string(), "string".to_string()),
+                ColumnDef::new("points".to_string(), "integer".to_string()),
+            ],
+        );
+        save_schema(&temp_dir, "scores", &integer_schema).unwrap();
+
+        let insert = "INSERT INTO scores (key, points) VALUES ('bob', 'many')";
+        assert!(matches!(
+            db.execute(insert, "testuser"),
+            Err(ReedError::ValidationError { .. })
+        ));
+        assert!(matches!(
+            db.execute(
+                "UPDATE scores SET points = 'lots' WHERE key = 'alice'",
+                "testuser"
+            ),
+            Err(ReedError::ValidationError { .. })
+        ));
+
+        // Relax the column type; the registry must pick up the new file
+        let mut string_schema = integer_schema.clone();
+        string_schema.columns[1].col_type = "string".to_string();
+        save_schema(&temp_dir, "scores", &string_schema).unwrap();
+        std::fs::OpenOptions::new()
+            .write(true)
+            .open(temp_dir.join("tables/scores/schema.toml"))
+            .unwrap()
+            .set_modified(std::time::SystemTime::now() + Duration::from_secs(5))
+            .unwrap();
+
+        assert_eq!(db.execute(insert, "testuser").unwrap().rows_affected, 1);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_open_repairs_partial_log_write() {
+        let temp_dir = std::env::temp_dir().join("reedbase_execute_repair_log_test");
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        crate::registry::set_base_path(temp_dir.clone());
+        crate::registry::init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+
+        let table = Table::new(&temp_dir, "text");
+        table
+            .init(
+                b"key|value
+a|1
+",
+                "testuser",
+            )
+            .unwrap();
+        table
+            .write(
+                b"key|value
+a|2
+",
+                "testuser",
+            )
+            .unwrap();
+        let log = std::fs::read_to_string(table.log_path()).unwrap();
+
+        // Power loss in the middle of the next append
+        std::fs::write(table.log_path(), format!("{}17368609", log)).unwrap();
+
+        let db = Database::open(&temp_dir).unwrap();
+        assert_eq!(std::fs::read_to_string(table.log_path()).unwrap(), log);
+        assert_eq!(table.list_versions().unwrap().len(), 2);
+        match db.query("SELECT * FROM text").unwrap() {
+            crate::reedql::QueryResult::Rows(rows) => assert_eq!(rows.len(), 1),
+            other => panic!("Expected rows, got {:?}", other),
+        }
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_update_without_changes_is_noop() {
+        let temp_dir = std::env::temp_dir().join("reedbase_execute_update_noop_test");
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        crate::registry::set_base_path(temp_dir.clone());
+        crate::registry::init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+
+        let db = Database::open(&temp_dir).unwrap();
+        let table = Table::new(&temp_dir, "text");
+        table.init(b"key|value\na|1\n", "testuser").unwrap();
+
+        // Same value again, and no matching row: nothing to write
+        for sql in [
+            "UPDATE text SET value = '1' WHERE key = 'a'",
+            "UPDATE text SET value = '2' WHERE key = 'missing'",
+        ] {
+            let result = db.execute(sql, "testuser").unwrap();
+            assert_eq!((result.timestamp, result.delta_size), (0, 0));
+        }
+        assert_eq!(db.stats().update_count, 0);
+        assert_eq!(table.list_versions().unwrap().len(), 1);
+
+        let result = db
+            .execute("UPDATE text SET value = '2' WHERE key = 'a'", "testuser")
+            .unwrap();
+        assert_ne!(result.timestamp, 0);
+        assert_eq!(db.stats().update_count, 1);
+        assert_eq!(table.list_versions().unwrap().len(), 2);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_delete_keeps_other_lines_verbatim() {
+        let temp_dir = std::env::temp_dir().join("reedbase_execute_delete_verbatim_test");
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        crate::registry::set_base_path(temp_dir.clone());
+        crate::registry::init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+
+        let db = Database::open(&temp_dir).unwrap();
+        let table = Table::new(&temp_dir, "text");
+        table
+            .init(
+                b"key|value\n# translated by hand\na | padded \nb|gone\n",
+                "testuser",
+            )
+            .unwrap();
+
+        let result = db
+            .execute("DELETE FROM text WHERE key = 'b'", "testuser")
+            .unwrap();
+        assert_eq!(result.rows_affected, 1);
+        assert_eq!(
+            table.read_current().unwrap(),
+            b"key|value\n# translated by hand\na | padded \n"
+        );
+
+        // Padded fields still match on their trimmed values
+        let result = db
+            .execute("DELETE FROM text WHERE value = 'padded'", "testuser")
+            .unwrap();
+        assert_eq!(result.rows_affected, 1);
+        assert_eq!(
+            table.read_current().unwrap(),
+            b"key|value\n# translated by hand\n"
+        );
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_unique_check_after_delete_and_update() {
+        let temp_dir = std::env::temp_dir().join("reedbase_execute_unique_after_write_test");
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        crate::registry::set_base_path(temp_dir.clone());
+        crate::registry::init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+
+        let db = Database::open(&temp_dir).unwrap();
+        db.execute(
+            "CREATE TABLE users (key string PRIMARY KEY, email string UNIQUE)",
+            "testuser",
+        )
+        .unwrap();
+        let insert = |key: &str, email: &str| {
+            db.execute(
+                &format!(
+                    "INSERT INTO users (key, email) VALUES ('{}', '{}')",
+                    key, email
+                ),
+                "testuser",
+            )
+        };
+
+        // Without and then with an index on the unique column
+        for round in 0..2 {
+            if round == 1 {
+                db.execute("DELETE FROM users", "testuser").unwrap();
+                db.execute("CREATE INDEX ON users (email)", "testuser")
+                    .unwrap();
+            }
+
+            // A deleted row's values can be inserted again
+            insert("u1", "a@example.com").unwrap();
+            insert("u2", "b@example.com").unwrap();
+            db.execute("DELETE FROM users WHERE key = 'u1'", "testuser")
+                .unwrap();
+            insert("u1", "a@example.com").unwrap();
+
+            // A value given up by UPDATE is free, the new one is taken
+            db.execute(
+                "UPDATE users SET email = 'c@example.com' WHERE key = 'u2'",
+                "testuser",
+            )
+            .unwrap();
+            insert("u3", "b@example.com").unwrap();
+            assert!(matches!(
+                insert("u4", "c@example.com"),
+                Err(ReedError::ValidationError { .. })
+            ));
+            assert!(matches!(
+                insert("u4", "a@example.com"),
+                Err(ReedError::ValidationError { .. })
+            ));
+        }
+
+        // The index points at the rows' current positions
+        let indices = db.indices().read().unwrap();
+        let index = indices.get("users.email").unwrap();
+        assert_eq!(
+            index.get(&"b@example.com".to_string()).unwrap(),
+            Some(vec![2])
+        );
+        assert_eq!(
+            index.get(&"c@example.com".to_string()).unwrap(),
+            Some(vec![0])
+        );
+        drop(indices);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_update_and_delete_patch_indices_in_place() {
+        let temp_dir = std::env::temp_dir().join("reedbase_execute_index_patch_test");
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        crate::registry::set_base_path(temp_dir.clone());
+        crate::registry::init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+
+        let db = Database::open(&temp_dir).unwrap();
+        Table::new(&temp_dir, "items")
+            .init(
+                b"key|colour|size\nk1|red|s\nk2|blue|m\nk3|red|l\nk4|green|m\nk5|blue|s\n",
+                "testuser",
+            )
+            .unwrap();
+        crate::database::index::create_index_with_backend(
+            &db,
+            "items",
+            "colour",
+            IndexBackend::BTree,
+            false,
+        )
+        .unwrap();
+        crate::database::index::create_composite_index(&db, "items", &["colour", "size"]).unwrap();
+        let created_at = |db: &Database| {
+            crate::database::index::load_index_metadata(db)
+                .unwrap()
+                .iter()
+                .map(|m| (m.index_key(), m.created_at))
+                .collect::<HashMap<_, _>>()
+        };
+        let before = created_at(&db);
+
+        let lookup = |index_key: &str, value: &str| {
+            db.indices()
+                .read()
+                .unwrap()
+                .get(index_key)
+                .unwrap()
+                .get(&value.to_string())
+                .unwrap()
+        };
+
+        db.execute(
+            "UPDATE items SET colour = 'green' WHERE key = 'k1'",
+            "testuser",
+        )
+        .unwrap();
+        assert_eq!(lookup("items.colour", "red"), Some(vec![2]));
+        assert_eq!(lookup("items.colour", "green"), Some(vec![0, 3]));
+        assert_eq!(lookup("items.colour,size", "green\x00s"), Some(vec![0]));
+
+        // Later rows move up by the number of deleted rows before them
+        db.execute("DELETE FROM items WHERE size = 'm'", "testuser")
+            .unwrap();
+        assert_eq!(lookup("items.colour", "blue"), Some(vec![2]));
+        assert_eq!(lookup("items.colour", "red"), Some(vec![1]));
+        assert_eq!(lookup("items.colour", "green"), Some(vec![0]));
+        assert_eq!(lookup("items.colour,size", "blue\x00m"), None);
+        assert_eq!(lookup("items.colour,size", "blue\x00s"), Some(vec![2]));
+
+        // Deleting the last row leaves the others untouched
+        db.execute("DELETE FROM items WHERE key = 'k5'", "testuser")
+            .unwrap();
+        assert_eq!(lookup("items.colour", "blue"), None);
+        assert_eq!(lookup("items.colour", "red"), Some(vec![1]));
+
+        // The indices were patched, not dropped and recreated
+        assert_eq!(created_at(&db), before);
+        assert_eq!(db.list_indices().len(), 2);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_matches_like_pattern() {
+        assert!(matches_like_pattern("page.title@de", "%.@de"));
+        assert!(matches_like_pattern("page.title@de", "page.%"));
+        assert!(matches_like_pattern("page.title@de", "%title%"));
+        assert!(!matches_like_pattern("page.title@en", "%.@de"));
+    }
+}
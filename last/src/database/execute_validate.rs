@@ -0,0 +1,162 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Schema, computed column and UNIQUE validation of written rows.
+
+use crate::database::database::Database;
+use crate::database::integrity::read_column_values;
+use crate::error::{ReedError, ReedResult};
+use crate::schema::{
+    validate_foreign_keys, validate_row, validate_uniqueness_indexed, CsvRow, Schema,
+};
+
+/// Validates rows about to be written against the table's schema.
+///
+/// Schemas come from the database's `SchemaRegistry`, so the TOML file is
+/// only re-read when it changed. Tables without a schema file accept any row.
+/// Foreign keys are checked last, for all rows in one batch.
+///
+/// ## Error Conditions
+/// - `ValidationError`: A row violates the schema or references a missing row
+/// - `InvalidSchema`: Schema file cannot be parsed
+pub(crate) fn validate_against_schema(
+    db: &Database,
+    table_name: &str,
+    rows: &[Vec<String>],
+) -> ReedResult<()> {
+    if rows.is_empty() {
+        return Ok(());
+    }
+
+    let rows: Vec<CsvRow> = rows
+        .iter()
+        .map(|fields| CsvRow::new(fields.first().cloned().unwrap_or_default(), fields.clone()))
+        .collect();
+
+    {
+        let mut registry = db.schema_registry().lock().unwrap();
+        let Some(schema) = registry.get(table_name, db.base_path())? else {
+            return Ok(());
+        };
+
+        for row in &rows {
+            validate_row(row, schema)?;
+        }
+    }
+
+    let violations = validate_foreign_keys(table_name, &rows, db)?;
+    if let Some(first) = violations.first() {
+        return Err(ReedError::ValidationError {
+            column: first.column.clone(),
+            reason: format!(
+                "Foreign key violation: no row with {}.{} = '{}' (key '{}', {} violation(s))",
+                first.references.table,
+                first.references.column,
+                first.value,
+                first.key,
+                violations.len()
+            ),
+            value: Some(first.value.clone()),
+        });
+    }
+
+    Ok(())
+}
+
+/// Schema of the table if it has computed columns.
+pub(crate) fn computed_schema(db: &Database, table_name: &str) -> ReedResult<Option<Schema>> {
+    let mut registry = db.schema_registry().lock().unwrap();
+    Ok(registry
+        .get(table_name, db.base_path())?
+        .filter(|schema| schema.columns.iter().any(|c| c.is_computed()))
+        .cloned())
+}
+
+/// Rejects explicit values for computed (read-only) columns.
+///
+/// ## Error Conditions
+/// - `ValidationError`: A named column is computed
+pub(crate) fn reject_computed<'a>(
+    schema: &Schema,
+    mut columns: impl Iterator<Item = &'a String>,
+) -> ReedResult<()> {
+    match columns.find(|name| schema.get_column(name).is_some_and(|c| c.is_computed())) {
+        Some(name) => Err(ReedError::ValidationError {
+            column: name.clone(),
+            reason: "Computed column is read-only".to_string(),
+            value: None,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Rejects a new row whose unique columns repeat an existing value.
+///
+/// Uses the column's loaded index for an O(log n) lookup when there is one
+/// (partial indices don't cover every row and are skipped), otherwise reads
+/// the column once.
+///
+/// ## Error Conditions
+/// - `ValidationError`: Value of a unique column already exists
+/// - `InvalidSchema`: Schema file cannot be parsed
+pub(crate) fn validate_unique_columns(
+    db: &Database,
+    table_name: &str,
+    fields: &[String],
+) -> ReedResult<()> {
+    let unique_columns: Vec<(usize, String)> = {
+        let mut registry = db.schema_registry().lock().unwrap();
+        let Some(schema) = registry.get(table_name, db.base_path())? else {
+            return Ok(());
+        };
+        schema
+            .columns
+            .iter()
+            .enumerate()
+            .filter(|(_, column)| column.is_unique())
+            .map(|(i, column)| (i, column.name.clone()))
+            .collect()
+    };
+
+    let row = CsvRow::new(fields.first().cloned().unwrap_or_default(), fields.to_vec());
+    for (column_idx, column) in unique_columns {
+        let Some(value) = fields.get(column_idx).filter(|v| !v.is_empty()) else {
+            continue;
+        };
+
+        let duplicate = {
+            let index_key = format!("{}.{}", table_name, column);
+            let indices = db.indices().read().unwrap();
+            let partial = db
+                .index_predicates()
+                .read()
+                .unwrap()
+                .contains_key(&index_key);
+            match indices.get(&index_key).filter(|_| !partial) {
+                Some(index) => Some(
+                    !validate_uniqueness_indexed(
+                        std::slice::from_ref(&row),
+                        column_idx,
+                        index.as_ref(),
+                    )?
+                    .is_empty(),
+                ),
+                None => None,
+            }
+        };
+        let duplicate = match duplicate {
+            Some(duplicate) => duplicate,
+            None => read_column_values(db, table_name, &column)?.contains(value),
+        };
+
+        if duplicate {
+            return Err(ReedError::ValidationError {
+                column,
+                reason: "Duplicate value violates unique constraint".to_string(),
+                value: Some(value.clone()),
+            });
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,359 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Export of tables and query results to external CSV files.
+//!
+//! Writes standard CSV (RFC 4180): values containing the delimiter, quotes
+//! or line breaks are quoted, embedded quotes doubled. Whole tables are
+//! streamed row by row; query results are written from the result rows.
+
+use crate::database::types::ExportReport;
+use crate::database::Database;
+use crate::error::{ReedError, ReedResult};
+use crate::reedql::{parse, QueryResult};
+use crate::tables::{Table, TransactionEntry};
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+/// Exports a table, or the result of a query on it, to a CSV file.
+///
+/// Column order is the table's header for whole tables and `SELECT *`,
+/// the selected columns (by output name) otherwise. An aggregation exports
+/// one row with its value. The export is recorded in the audit log as
+/// operation `export`.
+///
+/// ## Input
+/// - `db`: Database reference
+/// - `table_name`: Table to export
+/// - `dest`: Output file (created or truncated)
+/// - `delimiter`: Field delimiter (None = `,`; `b'\t'` for TSV)
+/// - `include_header`: Write column names as the first line
+/// - `query`: SELECT on `table_name` whose result is exported (None = all rows)
+/// - `user`: Username recorded in the audit log
+///
+/// ## Output
+/// - `Ok(ExportReport)`: Rows written, destination and duration
+///
+/// ## Performance
+/// - Whole table: O(n), streamed without loading current.csv into memory
+/// - Query: query cost plus O(r) for r result rows
+///
+/// ## Error Conditions
+/// - `TableNotFound`: Table doesn't exist
+/// - `ParseError`: Invalid query, or a query on another table
+/// - `ValidationError`: Delimiter is a quote or line break
+/// - `IoError`: Cannot write `dest` or the audit log
+pub fn export_csv(
+    db: &Database,
+    table_name: &str,
+    dest: &Path,
+    delimiter: Option<u8>,
+    include_header: bool,
+    query: Option<&str>,
+    user: &str,
+) -> ReedResult<ExportReport> {
+    let start = Instant::now();
+    let delimiter = delimiter.unwrap_or(b',') as char;
+    if matches!(delimiter, '"' | '\n' | '\r') {
+        return Err(ReedError::ValidationError {
+            column: String::new(),
+            reason: format!("{:?} cannot be used as CSV delimiter", delimiter),
+            value: None,
+        });
+    }
+    let table = db.get_table(table_name)?;
+
+    let file = File::create(dest).map_err(|e| export_io_error("create_export_file", e))?;
+    let mut writer = CsvWriter {
+        out: BufWriter::new(file),
+        delimiter,
+    };
+
+    let rows_exported = match query {
+        None => {
+            let rows = table.stream_rows()?;
+            if include_header {
+                writer.write_record(rows.columns())?;
+            }
+            let mut count = 0;
+            for row in rows {
+                let row = row?;
+                writer.write_record(std::iter::once(&row.key).chain(&row.values))?;
+                count += 1;
+            }
+            count
+        }
+        Some(sql) => export_query(db, &table, sql, include_header, &mut writer)?,
+    };
+    writer
+        .out
+        .flush()
+        .map_err(|e| export_io_error("write_export_file", e))?;
+
+    Table::write_transaction_log(
+        db.base_path(),
+        TransactionEntry {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_nanos() as u64)
+                .unwrap_or(0),
+            user: user.to_string(),
+            operation: "export".to_string(),
+            table: table_name.to_string(),
+            affected_keys: Vec::new(),
+            ip_address: None,
+        },
+    )?;
+
+    Ok(ExportReport {
+        rows_exported,
+        path: dest.to_path_buf(),
+        duration_ms: start.elapsed().as_millis() as u64,
+    })
+}
+
+/// Runs `sql` and writes its result rows.
+fn export_query(
+    db: &Database,
+    table: &Table,
+    sql: &str,
+    include_header: bool,
+    writer: &mut CsvWriter,
+) -> ReedResult<usize> {
+    let parsed = parse(sql)?;
+    if parsed.table != table.name() {
+        return Err(ReedError::ParseError {
+            reason: format!(
+                "Export query reads '{}', not '{}'",
+                parsed.table,
+                table.name()
+            ),
+        });
+    }
+
+    let rows = match db.query(sql)? {
+        QueryResult::Rows(rows) => rows,
+        QueryResult::Aggregation(value) => {
+            if include_header {
+                let name = parsed
+                    .aggregation
+                    .as_ref()
+                    .map_or_else(|| "value".to_string(), |agg| agg.output_name());
+                writer.write_record([name])?;
+            }
+            writer.write_record([value.to_string()])?;
+            return Ok(1);
+        }
+    };
+
+    let columns: Vec<String> = if !parsed.is_select_all() {
+        (0..parsed.columns.len())
+            .map(|i| parsed.output_name(i).to_string())
+            .collect()
+    } else {
+        let header = table.stream_rows()?.columns().to_vec();
+        if rows
+            .iter()
+            .flat_map(|row| row.keys())
+            .all(|c| header.contains(c))
+        {
+            header
+        } else {
+            // Joined rows carry qualified names
+            let names: BTreeSet<&String> = rows.iter().flat_map(|row| row.keys()).collect();
+            names.into_iter().cloned().collect()
+        }
+    };
+
+    if include_header {
+        writer.write_record(&columns)?;
+    }
+    for row in &rows {
+        writer.write_record(
+            columns
+                .iter()
+                .map(|c| row.get(c).map_or("", String::as_str)),
+        )?;
+    }
+    Ok(rows.len())
+}
+
+/// RFC 4180 record writer.
+struct CsvWriter {
+    out: BufWriter<File>,
+    delimiter: char,
+}
+
+impl CsvWriter {
+    /// Writes one record, quoting fields where needed.
+    fn write_record<I, S>(&mut self, fields: I) -> ReedResult<()>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut line = String::new();
+        for (i, field) in fields.into_iter().enumerate() {
+            if i > 0 {
+                line.push(self.delimiter);
+            }
+            line.push_str(&quote_field(field.as_ref(), self.delimiter));
+        }
+        line.push('\n');
+        self.out
+            .write_all(line.as_bytes())
+            .map_err(|e| export_io_error("write_export_file", e))
+    }
+}
+
+/// Quotes a field containing the delimiter, a quote or a line break.
+fn quote_field(field: &str, delimiter: char) -> String {
+    if field.contains([delimiter, '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn export_io_error(operation: &str, e: std::io::Error) -> ReedError {
+    ReedError::IoError {
+        operation: operation.to_string(),
+        reason: e.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tables::{read_transaction_log, AuditFilter};
+
+    fn setup(name: &str) -> (std::path::PathBuf, Database) {
+        let temp_dir = std::env::temp_dir().join(format!("reedbase_export_test_{}", name));
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        crate::registry::set_base_path(temp_dir.clone());
+        crate::registry::init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+
+        let db = Database::open(&temp_dir).unwrap();
+        Table::new(&temp_dir, "users")
+            .init(
+                b"key|name|city\nu1|Doe, Jane|Berlin\nu2|Max \"M\"|Wien\nu3|Eve|Berlin\n",
+                "testuser",
+            )
+            .unwrap();
+        (temp_dir, db)
+    }
+
+    #[test]
+    fn test_quote_field() {
+        assert_eq!(quote_field("plain", ','), "plain");
+        assert_eq!(quote_field("a,b", ','), "\"a,b\"");
+        assert_eq!(quote_field("a,b", '\t'), "a,b");
+        assert_eq!(quote_field("say \"hi\"", '\t'), "\"say \"\"hi\"\"\"");
+        assert_eq!(quote_field("two\nlines", ','), "\"two\nlines\"");
+    }
+
+    #[test]
+    fn test_export_csv_table() {
+        let (temp_dir, db) = setup("table");
+        let dest = temp_dir.join("users.csv");
+
+        let report = export_csv(&db, "users", &dest, None, true, None, "testuser").unwrap();
+        assert_eq!(report.rows_exported, 3);
+        assert_eq!(report.path, dest);
+        assert_eq!(
+            std::fs::read_to_string(&dest).unwrap(),
+            "key,name,city\nu1,\"Doe, Jane\",Berlin\nu2,\"Max \"\"M\"\"\",Wien\nu3,Eve,Berlin\n"
+        );
+
+        // TSV without header; the comma needs no quoting
+        export_csv(&db, "users", &dest, Some(b'\t'), false, None, "testuser").unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&dest).unwrap(),
+            "u1\tDoe, Jane\tBerlin\nu2\t\"Max \"\"M\"\"\"\tWien\nu3\tEve\tBerlin\n"
+        );
+
+        let entries = read_transaction_log(&temp_dir, AuditFilter::default()).unwrap();
+        let exports: Vec<_> = entries.iter().filter(|e| e.operation == "export").collect();
+        assert_eq!(exports.len(), 2);
+        assert_eq!(
+            (exports[0].user.as_str(), exports[0].table.as_str()),
+            ("testuser", "users")
+        );
+
+        assert!(matches!(
+            export_csv(&db, "missing", &dest, None, true, None, "testuser"),
+            Err(ReedError::TableNotFound { .. })
+        ));
+        assert!(matches!(
+            export_csv(&db, "users", &dest, Some(b'"'), true, None, "testuser"),
+            Err(ReedError::ValidationError { .. })
+        ));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_export_csv_query() {
+        let (temp_dir, db) = setup("query");
+        let dest = temp_dir.join("berlin.csv");
+
+        let report = export_csv(
+            &db,
+            "users",
+            &dest,
+            None,
+            true,
+            Some("SELECT name AS person, key FROM users WHERE city = 'Berlin' ORDER BY key"),
+            "testuser",
+        )
+        .unwrap();
+        assert_eq!(report.rows_exported, 2);
+        assert_eq!(
+            std::fs::read_to_string(&dest).unwrap(),
+            "person,key\n\"Doe, Jane\",u1\nEve,u3\n"
+        );
+
+        // SELECT * keeps the table's column order
+        export_csv(
+            &db,
+            "users",
+            &dest,
+            None,
+            false,
+            Some("SELECT * FROM users WHERE key = 'u3'"),
+            "testuser",
+        )
+        .unwrap();
+        assert_eq!(std::fs::read_to_string(&dest).unwrap(), "u3,Eve,Berlin\n");
+
+        export_csv(
+            &db,
+            "users",
+            &dest,
+            None,
+            true,
+            Some("SELECT COUNT(*) FROM users"),
+            "testuser",
+        )
+        .unwrap();
+        assert_eq!(std::fs::read_to_string(&dest).unwrap(), "count\n3\n");
+
+        assert!(matches!(
+            export_csv(
+                &db,
+                "users",
+                &dest,
+                None,
+                true,
+                Some("SELECT * FROM text"),
+                "testuser"
+            ),
+            Err(ReedError::ParseError { .. })
+        ));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+}
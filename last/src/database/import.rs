@@ -0,0 +1,519 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Bulk import of external CSV files.
+//!
+//! Reads standard CSV (RFC 4180: quoted fields, doubled quotes, CRLF),
+//! converts it to the table's own delimiter and appends all valid rows as a
+//! single version via `Table::swap()`. Rows that cannot be parsed or stored
+//! and rows that violate the table's schema are reported, not imported.
+
+use crate::concurrent::acquire_lock;
+use crate::database::execute_index::add_to_indices;
+use crate::database::types::ImportReport;
+use crate::database::Database;
+use crate::error::{ReedError, ReedResult};
+use crate::schema::{validate_row, CsvRow};
+use crate::tables::{detect_delimiter, Table};
+use std::collections::HashSet;
+use std::iter::Peekable;
+use std::path::Path;
+use std::str::Chars;
+use std::time::Duration;
+
+/// Records between two progress callbacks.
+pub const IMPORT_PROGRESS_INTERVAL: usize = 10_000;
+
+/// Maximum time `import_csv` waits for the table's write lock.
+const IMPORT_LOCK_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Imports an external CSV file into a table.
+///
+/// With `has_header`, CSV columns are matched to the table's columns by
+/// name (missing columns stay empty); without, fields are taken in table
+/// column order. A missing table is created from the CSV header, with a
+/// `key` column moved to the front.
+///
+/// Records are classified as:
+/// - parsing errors: malformed quoting, wrong field count, or a value the
+///   table cannot store (its delimiter or a line break)
+/// - skipped: empty or duplicate key, or a schema violation if the table
+///   has a schema
+/// - imported: everything else, appended in one write
+///
+/// ## Input
+/// - `db`: Database reference
+/// - `table_name`: Target table (created if missing)
+/// - `path`: CSV file
+/// - `delimiter`: Field delimiter of the file (None = detect `,`, `|` or tab)
+/// - `has_header`: First record holds column names
+/// - `user`: Username recorded for the write
+/// - `on_progress`: Called with the number of records processed, every
+///   `IMPORT_PROGRESS_INTERVAL` records and once at the end
+///
+/// ## Output
+/// - `Ok(ImportReport)`: Imported, skipped and unparsable records
+///
+/// ## Performance
+/// - O(n) over the file plus one pass over current.csv
+/// - One versioned write (`swap`, or `init` for a new table)
+///
+/// ## Error Conditions
+/// - `IoError`: Cannot read the file or write the table
+/// - `InvalidCsv`: File is not UTF-8, or no header is available (new table
+///   without `has_header`, or an unparsable header)
+/// - `ValidationError`: A CSV column is not part of the table, or the key
+///   column is missing
+/// - `LockTimeout`: Table lock not acquired within 30s
+pub fn import_csv(
+    db: &Database,
+    table_name: &str,
+    path: &Path,
+    delimiter: Option<u8>,
+    has_header: bool,
+    user: &str,
+    on_progress: Option<Box<dyn Fn(usize)>>,
+) -> ReedResult<ImportReport> {
+    let content = std::fs::read(path).map_err(|e| ReedError::IoError {
+        operation: "read_import_file".to_string(),
+        reason: e.to_string(),
+    })?;
+    let text = std::str::from_utf8(&content).map_err(|e| ReedError::InvalidCsv {
+        reason: format!("Invalid UTF-8: {}", e),
+        line: 0,
+    })?;
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let delimiter =
+        delimiter.unwrap_or_else(|| detect_delimiter(text.as_bytes()).as_byte()) as char;
+
+    let mut records = read_records(text, delimiter).into_iter();
+    let csv_columns = if has_header {
+        match records.next() {
+            Some((_, Ok(columns))) => Some(columns),
+            Some((line, Err(reason))) => return Err(ReedError::InvalidCsv { reason, line }),
+            None => Some(Vec::new()),
+        }
+    } else {
+        None
+    };
+
+    let table = Table::new(db.base_path(), table_name);
+    let exists = table.exists();
+    let _lock = if exists {
+        Some(acquire_lock(
+            db.base_path(),
+            table_name,
+            IMPORT_LOCK_TIMEOUT,
+        )?)
+    } else {
+        None
+    };
+    let separator = table.delimiter()?.as_char();
+    let current = if exists {
+        String::from_utf8_lossy(&table.read_current()?).into_owned()
+    } else {
+        String::new()
+    };
+
+    let header: Vec<String> = match (&csv_columns, exists) {
+        (_, true) => current
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .split(separator)
+            .map(String::from)
+            .collect(),
+        (Some(columns), false) => {
+            let mut header = columns.clone();
+            if let Some(pos) = header.iter().position(|c| c == "key") {
+                let key = header.remove(pos);
+                header.insert(0, key);
+            }
+            header
+        }
+        (None, false) => {
+            return Err(ReedError::InvalidCsv {
+                reason: format!(
+                    "Table '{}' does not exist and the file has no header",
+                    table_name
+                ),
+                line: 1,
+            })
+        }
+    };
+
+    // Table column → field of a CSV record
+    let positions: Vec<Option<usize>> = match &csv_columns {
+        Some(columns) => {
+            if let Some(column) = columns.iter().find(|c| !header.contains(c)) {
+                return Err(ReedError::ValidationError {
+                    column: column.clone(),
+                    reason: format!("Column not present in table '{}'", table_name),
+                    value: None,
+                });
+            }
+            header
+                .iter()
+                .map(|name| columns.iter().position(|c| c == name))
+                .collect()
+        }
+        None => (0..header.len()).map(Some).collect(),
+    };
+    if header.is_empty() || positions[0].is_none() {
+        return Err(ReedError::ValidationError {
+            column: header.first().cloned().unwrap_or_default(),
+            reason: "Key column missing in import file".to_string(),
+            value: None,
+        });
+    }
+    let field_count = csv_columns.as_ref().map_or(header.len(), Vec::len);
+
+    let schema = db
+        .schema_registry()
+        .lock()
+        .unwrap()
+        .get(table_name, db.base_path())?
+        .cloned();
+    let mut keys: HashSet<String> = current
+        .lines()
+        .skip(1)
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split(separator).next())
+        .map(String::from)
+        .collect();
+
+    let mut report = ImportReport::default();
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut processed = 0;
+    for (line, record) in records {
+        processed += 1;
+        if processed % IMPORT_PROGRESS_INTERVAL == 0 {
+            if let Some(on_progress) = &on_progress {
+                on_progress(processed);
+            }
+        }
+
+        let fields = match record {
+            Ok(fields) if fields.len() == field_count => fields,
+            Ok(fields) => {
+                report.parsing_errors.push(format!(
+                    "line {}: expected {} fields, found {}",
+                    line,
+                    field_count,
+                    fields.len()
+                ));
+                continue;
+            }
+            Err(reason) => {
+                report
+                    .parsing_errors
+                    .push(format!("line {}: {}", line, reason));
+                continue;
+            }
+        };
+        let row: Vec<String> = positions
+            .iter()
+            .map(|pos| pos.map(|i| fields[i].clone()).unwrap_or_default())
+            .collect();
+
+        if let Some(value) = row.iter().find(|v| v.contains([separator, '\n', '\r'])) {
+            report.parsing_errors.push(format!(
+                "line {}: value {:?} contains {:?} or a line break",
+                line, value, separator
+            ));
+            continue;
+        }
+
+        let key = &row[0];
+        let valid = !key.is_empty()
+            && !keys.contains(key)
+            && schema.as_ref().is_none_or(|schema| {
+                validate_row(&CsvRow::new(key.clone(), row.clone()), schema).is_ok()
+            });
+        if !valid {
+            report.rows_skipped += 1;
+            continue;
+        }
+
+        keys.insert(key.clone());
+        rows.push(row);
+    }
+    if let Some(on_progress) = &on_progress {
+        on_progress(processed);
+    }
+
+    let separator = separator.to_string();
+    let new_lines: String = rows.iter().map(|row| row.join(&separator) + "\n").collect();
+    if !exists {
+        let content = format!("{}\n{}", header.join(&separator), new_lines);
+        table.init(content.as_bytes(), user)?;
+        db.register_table(table_name);
+    } else if !rows.is_empty() {
+        let mut content = current.clone();
+        if !content.is_empty() && !content.ends_with('\n') {
+            content.push('\n');
+        }
+        content.push_str(&new_lines);
+        table.swap(content.as_bytes(), user)?;
+
+        // New rows follow the existing lines after the header
+        let first_row_id = current.lines().count().saturating_sub(1);
+        for (i, row) in rows.iter().enumerate() {
+            add_to_indices(db, table_name, &header, first_row_id + i, row)?;
+        }
+    }
+    crate::database::execute::invalidate_query_cache(db, table_name);
+
+    report.rows_imported = rows.len();
+    Ok(report)
+}
+
+/// Splits CSV text into records, with the line each record starts on.
+///
+/// Fields may be quoted with `"`; quoted fields can hold the delimiter,
+/// line breaks and doubled quotes (`""`). Blank lines are skipped. A
+/// malformed record yields an error and parsing resumes on the next line.
+fn read_records(text: &str, delimiter: char) -> Vec<(usize, Result<Vec<String>, String>)> {
+    let mut records = Vec::new();
+    let mut chars = text.chars().peekable();
+    let mut line = 1;
+
+    while chars.peek().is_some() {
+        let start = line;
+        match read_record(&mut chars, delimiter, &mut line) {
+            Ok(fields) if fields.len() == 1 && fields[0].is_empty() => {}
+            record => records.push((start, record)),
+        }
+    }
+
+    records
+}
+
+/// Reads one record, consuming its final line break.
+fn read_record(
+    chars: &mut Peekable<Chars>,
+    delimiter: char,
+    line: &mut usize,
+) -> Result<Vec<String>, String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut was_quoted = false;
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => {
+                    if c == '\n' {
+                        *line += 1;
+                    }
+                    field.push(c);
+                }
+            }
+            continue;
+        }
+
+        match c {
+            c if c == delimiter => {
+                fields.push(std::mem::take(&mut field));
+                was_quoted = false;
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                *line += 1;
+                fields.push(field);
+                return Ok(fields);
+            }
+            '"' if field.is_empty() && !was_quoted => {
+                in_quotes = true;
+                was_quoted = true;
+            }
+            _ if was_quoted => {
+                // Skip the rest of the line
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        *line += 1;
+                        break;
+                    }
+                }
+                return Err(format!("unexpected {:?} after closing quote", c));
+            }
+            _ => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err("unterminated quoted field".to_string());
+    }
+    fields.push(field);
+    Ok(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reedql::QueryResult;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    fn setup(name: &str) -> (std::path::PathBuf, Database) {
+        let temp_dir = std::env::temp_dir().join(format!("reedbase_import_test_{}", name));
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        crate::registry::set_base_path(temp_dir.clone());
+        crate::registry::init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+        (temp_dir.clone(), Database::open(&temp_dir).unwrap())
+    }
+
+    #[test]
+    fn test_read_records() {
+        let records = read_records(
+            "a,\"b, c\",\"say \"\"hi\"\"\"\r\n\n\"multi\nline\",x,\n\"bad\"x,y\nlast,1,2",
+            ',',
+        );
+        let lines: Vec<usize> = records.iter().map(|(line, _)| *line).collect();
+        assert_eq!(lines, vec![1, 3, 5, 6]);
+        assert_eq!(
+            records[0].1,
+            Ok(vec!["a".into(), "b, c".into(), "say \"hi\"".into()])
+        );
+        assert_eq!(
+            records[1].1,
+            Ok(vec!["multi\nline".into(), "x".into(), "".into()])
+        );
+        assert!(records[2].1.is_err());
+        assert_eq!(
+            records[3].1,
+            Ok(vec!["last".into(), "1".into(), "2".into()])
+        );
+
+        let records = read_records("a;\"open\n", ';');
+        assert_eq!(records[0].1, Err("unterminated quoted field".to_string()));
+    }
+
+    #[test]
+    fn test_import_csv_new_table() {
+        let (temp_dir, db) = setup("new");
+        let file = temp_dir.join("users.csv");
+        std::fs::write(
+            &file,
+            "name,key,city\n\"Doe, Jane\",u1,Berlin\nJohn,u2,\"Wien\"\nbroken,u3\nDup,u1,Rom\n",
+        )
+        .unwrap();
+
+        let calls = Rc::new(Cell::new(0));
+        let counter = Rc::clone(&calls);
+        let report = import_csv(
+            &db,
+            "users",
+            &file,
+            None,
+            true,
+            "testuser",
+            Some(Box::new(move |processed| {
+                assert_eq!(processed, 4);
+                counter.set(counter.get() + 1);
+            })),
+        )
+        .unwrap();
+
+        assert_eq!(report.rows_imported, 2);
+        assert_eq!(report.rows_skipped, 1);
+        assert_eq!(
+            report.parsing_errors,
+            vec!["line 4: expected 3 fields, found 2".to_string()]
+        );
+        assert_eq!(calls.get(), 1);
+
+        let table = Table::new(&temp_dir, "users");
+        assert_eq!(
+            table.read_current().unwrap(),
+            b"key|name|city\nu1|Doe, Jane|Berlin\nu2|John|Wien\n"
+        );
+        assert!(db.list_tables().unwrap().contains(&"users".to_string()));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_import_csv_existing_table() {
+        use crate::schema::{save_schema, ColumnDef, Schema};
+
+        let (temp_dir, db) = setup("existing");
+        let table = Table::new(&temp_dir, "users");
+        table
+            .init(b"key|name|age\nu1|Jane|30\n", "testuser")
+            .unwrap();
+        save_schema(
+            &temp_dir,
+            "users",
+            &Schema::new(
+                "1.0".to_string(),
+                true,
+                vec![
+                    ColumnDef::primary_key("key".to_string(), "string".to_string()),
+                    ColumnDef::new("name".to_string(), "string".to_string()),
+                    ColumnDef::new("age".to_string(), "integer".to_string()),
+                ],
+            ),
+        )
+        .unwrap();
+        db.create_index("users", "name").unwrap();
+        db.enable_query_cache(10, Duration::from_secs(60));
+        db.query("SELECT * FROM users").unwrap();
+
+        // Positional fields, semicolon-delimited
+        let file = temp_dir.join("more.csv");
+        std::fs::write(
+            &file,
+            "u2;Max;41\nu3;Eve;old\nu1;Jane;30\nu4;A|B;5\nu5;Ann;22\n",
+        )
+        .unwrap();
+        let report = import_csv(&db, "users", &file, Some(b';'), false, "testuser", None).unwrap();
+
+        assert_eq!(report.rows_imported, 2);
+        assert_eq!(report.rows_skipped, 2);
+        assert_eq!(report.parsing_errors.len(), 1);
+        assert!(report.parsing_errors[0].starts_with("line 4:"));
+        assert_eq!(
+            table.read_current().unwrap(),
+            b"key|name|age\nu1|Jane|30\nu2|Max|41\nu5|Ann|22\n"
+        );
+        assert_eq!(table.list_versions().unwrap()[0].action, "swap");
+
+        // The cache and the index see the imported rows
+        match db.query("SELECT * FROM users").unwrap() {
+            QueryResult::Rows(rows) => assert_eq!(rows.len(), 3),
+            other => panic!("Expected rows, got {:?}", other),
+        }
+        let indices = db.indices().read().unwrap();
+        assert_eq!(
+            indices["users.name"].get(&"Ann".to_string()).unwrap(),
+            Some(vec![2])
+        );
+        drop(indices);
+
+        // Unknown columns and files without a key column are rejected
+        std::fs::write(&file, "key,email\nu9,x@y.org\n").unwrap();
+        assert!(matches!(
+            import_csv(&db, "users", &file, None, true, "testuser", None),
+            Err(ReedError::ValidationError { .. })
+        ));
+        std::fs::write(&file, "name\nBob\n").unwrap();
+        assert!(matches!(
+            import_csv(&db, "users", &file, None, true, "testuser", None),
+            Err(ReedError::ValidationError { .. })
+        ));
+        assert!(matches!(
+            import_csv(&db, "missing", &file, None, false, "testuser", None),
+            Err(ReedError::InvalidCsv { .. })
+        ));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+}
@@ -5,11 +5,14 @@
 //!
 //! Handles index creation, listing, and statistics.
 
-use crate::btree::Order;
+use crate::btree::{Order, TreeStatistics};
 use crate::database::database::Database;
 use crate::database::types::{IndexBackend, IndexInfo, IndexMetadata};
 use crate::error::{ReedError, ReedResult};
+use crate::indices::builder::composite_key;
 use crate::indices::{BTreeIndex, HashMapIndex, Index};
+use crate::reedql::types::FilterCondition;
+use std::collections::{BTreeMap, HashMap};
 
 /// Creates an index on a table column with specified backend.
 ///
@@ -33,21 +36,163 @@ pub fn create_index_with_backend(
     column: &str,
     backend: IndexBackend,
     auto_created: bool,
+) -> ReedResult<()> {
+    create_index_on_columns(db, table_name, &[column], backend, auto_created, None, None)
+}
+
+/// Creates a composite index over several columns of a table.
+///
+/// The index is registered as `table.col1,col2` and keyed on the column
+/// values joined by `indices::builder::COMPOSITE_SEPARATOR`. Inserts keep it
+/// up to date like single-column indices.
+///
+/// ## Input
+/// - `db`: Database reference
+/// - `table_name`: Table name
+/// - `columns`: Column names in key order (at least two)
+///
+/// ## Output
+/// - `Ok(())`: Index created successfully
+/// - `Err(ReedError)`: Creation failed
+///
+/// ## Performance
+/// - < 50ms for 10k rows (B+-Tree, persistent to disk)
+///
+/// ## Error Conditions
+/// - `ParseError`: Fewer than two columns
+/// - `IndexAlreadyExists`: Same columns already indexed in this order
+/// - `InvalidCsv`: A column is not in the table header
+pub fn create_composite_index(db: &Database, table_name: &str, columns: &[&str]) -> ReedResult<()> {
+    if columns.len() < 2 {
+        return Err(ReedError::ParseError {
+            reason: "Composite index requires at least two columns".to_string(),
+        });
+    }
+
+    create_index_on_columns(
+        db,
+        table_name,
+        columns,
+        IndexBackend::BTree,
+        false,
+        None,
+        None,
+    )
+}
+
+/// Creates an index with an explicit name (`CREATE INDEX name ON ...`).
+///
+/// The name identifies the index in `DROP INDEX name` and is kept in the
+/// index metadata. Indices created without a name are known by
+/// `IndexInfo::default_name()`.
+///
+/// ## Input
+/// - `db`: Database reference
+/// - `name`: Index name, unique across the database
+/// - `table_name`: Table name
+/// - `columns`: Column names in key order (more than one = composite index)
+///
+/// ## Output
+/// - `Ok(())`: Index created successfully
+/// - `Err(ReedError)`: Creation failed
+///
+/// ## Error Conditions
+/// - `IndexAlreadyExists`: Same columns already indexed, or the name is
+///   taken (reports the index holding it)
+/// - `InvalidCsv`: A column is not in the table header
+pub fn create_named_index(
+    db: &Database,
+    name: &str,
+    table_name: &str,
+    columns: &[&str],
+) -> ReedResult<()> {
+    create_index_on_columns(
+        db,
+        table_name,
+        columns,
+        IndexBackend::BTree,
+        false,
+        None,
+        Some(name),
+    )
+}
+
+/// Creates a partial index over the rows of a table matching a predicate.
+///
+/// Rows failing the predicate are left out of the index, and inserts only
+/// add rows that match. The predicate is stored in the index metadata and
+/// reported by `list_indices()`.
+///
+/// ## Input
+/// - `db`: Database reference
+/// - `table_name`: Table name
+/// - `column`: Column name
+/// - `predicate`: Condition a row must satisfy to be indexed
+///
+/// ## Output
+/// - `Ok(())`: Index created successfully
+/// - `Err(ReedError)`: Creation failed
+///
+/// ## Performance
+/// - < 50ms for 10k rows (B+-Tree, persistent to disk)
+///
+/// ## Error Conditions
+/// - `IndexAlreadyExists`: Column already indexed
+/// - `InvalidCsv`: Column is not in the table header
+/// - `ParseError`: Predicate cannot be evaluated (e.g., invalid LIKE pattern)
+pub fn create_partial_index(
+    db: &Database,
+    table_name: &str,
+    column: &str,
+    predicate: FilterCondition,
+) -> ReedResult<()> {
+    create_index_on_columns(
+        db,
+        table_name,
+        &[column],
+        IndexBackend::BTree,
+        false,
+        Some(predicate),
+        None,
+    )
+}
+
+/// Builds and registers an index on one or more columns.
+///
+/// With a predicate, only rows matching it are indexed; without a name, the
+/// index is known by `IndexInfo::default_name()`.
+pub(crate) fn create_index_on_columns(
+    db: &Database,
+    table_name: &str,
+    columns: &[&str],
+    backend: IndexBackend,
+    auto_created: bool,
+    predicate: Option<FilterCondition>,
+    name: Option<&str>,
 ) -> ReedResult<()> {
     // Check if index already exists
+    let column = columns.join(",");
     let index_key = format!("{}.{}", table_name, column);
     {
         let indices = db.indices().read().unwrap();
         if indices.contains_key(&index_key) {
             return Err(ReedError::IndexAlreadyExists {
                 table: table_name.to_string(),
-                column: column.to_string(),
+                column,
             });
         }
     }
+    if let Some(existing) = name.and_then(|name| find_index_by_name(db, name)) {
+        let (table, column) = existing.split_once('.').unwrap_or((&existing, ""));
+        return Err(ReedError::IndexAlreadyExists {
+            table: table.to_string(),
+            column: column.to_string(),
+        });
+    }
 
     // Load table data
     let table = db.get_table(table_name)?;
+    let separator = table.delimiter()?.as_char();
     let content = table.read_current()?;
     let text = std::str::from_utf8(&content).map_err(|e| ReedError::IoError {
         operation: "parse_table".to_string(),
@@ -64,14 +209,40 @@ pub fn create_index_with_backend(
     }
 
     let header_line = lines[0];
-    let header_parts: Vec<&str> = header_line.split('|').collect();
-    let column_index = header_parts
+    let header_parts: Vec<&str> = header_line.split(separator).collect();
+    let column_indices = columns
         .iter()
-        .position(|&col| col == column)
-        .ok_or_else(|| ReedError::InvalidCsv {
-            reason: format!("Column '{}' not found", column),
-            line: 0,
-        })?;
+        .map(|column| {
+            header_parts
+                .iter()
+                .position(|col| col == column)
+                .ok_or_else(|| ReedError::InvalidCsv {
+                    reason: format!("Column '{}' not found", column),
+                    line: 0,
+                })
+        })
+        .collect::<ReedResult<Vec<usize>>>()?;
+
+    // Index key of a row (the value itself for single-column indices);
+    // rows outside a partial index have none
+    let row_key = |line: &str| -> ReedResult<Option<String>> {
+        let parts: Vec<&str> = line.split(separator).collect();
+        if let Some(predicate) = &predicate {
+            let row: HashMap<String, String> = header_parts
+                .iter()
+                .zip(&parts)
+                .map(|(column, value)| (column.to_string(), value.to_string()))
+                .collect();
+            if !predicate.matches(&row)? {
+                return Ok(None);
+            }
+        }
+        let values = column_indices
+            .iter()
+            .map(|&i| parts.get(i).copied())
+            .collect::<Option<Vec<&str>>>();
+        Ok(values.map(|values| composite_key(&values)))
+    };
 
     // Build index based on backend type
     let index: Box<dyn Index<String, Vec<usize>>> = match backend {
@@ -84,9 +255,7 @@ pub fn create_index_with_backend(
                     continue;
                 }
 
-                let parts: Vec<&str> = line.split('|').collect();
-                if let Some(&value) = parts.get(column_index) {
-                    let value_str = value.to_string();
+                if let Some(value_str) = row_key(line)? {
                     if let Ok(Some(mut existing)) = hash_index.get(&value_str) {
                         existing.push(row_id);
                         let _ = hash_index.insert(value_str, existing);
@@ -118,22 +287,18 @@ pub fn create_index_with_backend(
             let mut btree_index: BTreeIndex<String, Vec<usize>> =
                 BTreeIndex::open(&index_path, order)?;
 
+            // Group row IDs in key order, then bulk-load (no per-key WAL writes)
+            let mut entries: BTreeMap<String, Vec<usize>> = BTreeMap::new();
             for (row_id, line) in lines.iter().skip(1).enumerate() {
                 if line.trim().is_empty() {
                     continue;
                 }
 
-                let parts: Vec<&str> = line.split('|').collect();
-                if let Some(&value) = parts.get(column_index) {
-                    let value_str = value.to_string();
-                    if let Ok(Some(mut existing)) = btree_index.get(&value_str) {
-                        existing.push(row_id);
-                        btree_index.insert(value_str, existing)?;
-                    } else {
-                        btree_index.insert(value_str, vec![row_id])?;
-                    }
+                if let Some(value) = row_key(line)? {
+                    entries.entry(value).or_default().push(row_id);
                 }
             }
+            btree_index.tree_mut().bulk_load(entries)?;
 
             Box::new(btree_index)
         }
@@ -149,9 +314,23 @@ pub fn create_index_with_backend(
         auto_flags.insert(index_key.clone(), true);
     }
 
+    // Store partial index predicate
+    if let Some(predicate) = &predicate {
+        let mut predicates = db.index_predicates().write().unwrap();
+        predicates.insert(index_key.clone(), predicate.clone());
+    }
+
+    // Store explicit name
+    if let Some(name) = name {
+        let mut names = db.index_names().write().unwrap();
+        names.insert(index_key.clone(), name.to_string());
+    }
+
     // Save metadata
-    let mut metadata = IndexMetadata::new(table_name.to_string(), column.to_string(), backend);
+    let mut metadata = IndexMetadata::new(table_name.to_string(), column, backend);
     metadata.auto_created = auto_created;
+    metadata.predicate = predicate;
+    metadata.name = name.map(str::to_string);
     save_index_metadata(db, metadata)?;
 
     // Update statistics
@@ -251,6 +430,7 @@ fn create_index_internal_legacy(
 
     // Load table data
     let table = db.get_table(table_name)?;
+    let separator = table.delimiter()?.as_char();
     let content = table.read_current()?;
     let text = std::str::from_utf8(&content).map_err(|e| ReedError::IoError {
         operation: "parse_table".to_string(),
@@ -267,7 +447,7 @@ fn create_index_internal_legacy(
     }
 
     let header_line = lines[0];
-    let header_parts: Vec<&str> = header_line.split('|').collect();
+    let header_parts: Vec<&str> = header_line.split(separator).collect();
     let column_index = header_parts
         .iter()
         .position(|&col| col == column)
@@ -284,7 +464,7 @@ fn create_index_internal_legacy(
             continue;
         }
 
-        let parts: Vec<&str> = line.split('|').collect();
+        let parts: Vec<&str> = line.split(separator).collect();
         if let Some(&value) = parts.get(column_index) {
             let value_str = value.to_string();
             // Insert into index: key → [row_id]
@@ -337,6 +517,8 @@ pub fn create_index(db: &Database, table_name: &str, column: &str) -> ReedResult
 pub fn list_indices(db: &Database) -> Vec<IndexInfo> {
     let indices = db.indices().read().unwrap();
     let auto_flags = db.auto_created_indices().read().unwrap();
+    let predicates = db.index_predicates().read().unwrap();
+    let names = db.index_names().read().unwrap();
 
     // Load metadata for usage tracking
     let metadata_map: std::collections::HashMap<String, IndexMetadata> = load_index_metadata(db)
@@ -376,6 +558,10 @@ pub fn list_indices(db: &Database) -> Vec<IndexInfo> {
             info.memory_bytes = memory_bytes;
             info.disk_bytes = disk_bytes;
             info.usage_count = usage_count;
+            info.predicate = predicates.get(key).cloned();
+            if let Some(name) = names.get(key) {
+                info.name = name.clone();
+            }
             // entry_count would require iterating the index - skip for performance
 
             result.push(info);
@@ -385,16 +571,49 @@ pub fn list_indices(db: &Database) -> Vec<IndexInfo> {
     result
 }
 
-/// Drops an index.
+/// Collects tree statistics for all B+-Tree indices.
+///
+/// ## Input
+/// - `db`: Database reference
+///
+/// ## Output
+/// - `Ok(BTreeMap)`: Statistics keyed by `table.column`; HashMap indices are
+///   omitted
+/// - `Err(ReedError)`: An index file could not be read
+///
+/// ## Performance
+/// - O(p) per B+-Tree index where p = number of pages
+pub fn index_stats(db: &Database) -> ReedResult<BTreeMap<String, TreeStatistics>> {
+    let indices = db.indices().read().unwrap();
+    let mut result = BTreeMap::new();
+
+    for (key, index) in indices.iter() {
+        if let Some(stats) = index.tree_statistics()? {
+            result.insert(key.clone(), stats);
+        }
+    }
+
+    Ok(result)
+}
+
+/// Drops an index, in memory and on disk.
+///
+/// Removes the index with its auto-created flag, predicate and name, its
+/// entry in metadata.json and its B+-Tree and WAL files
+/// (`indices/{table}.{column}.btree`/`.wal`).
 ///
 /// ## Input
 /// - `db`: Database reference
 /// - `table_name`: Table name
-/// - `column`: Column name
+/// - `column`: Column name (`col1,col2` for a composite index)
 ///
 /// ## Output
 /// - `Ok(())`: Index dropped successfully
 /// - `Err(ReedError)`: Drop failed
+///
+/// ## Error Conditions
+/// - `IndexNotFound`: No index on `table_name.column`
+/// - `IoError`: Metadata or index files could not be updated
 pub fn drop_index(db: &Database, table_name: &str, column: &str) -> ReedResult<()> {
     let index_key = format!("{}.{}", table_name, column);
 
@@ -404,16 +623,129 @@ pub fn drop_index(db: &Database, table_name: &str, column: &str) -> ReedResult<(
             name: index_key.clone(),
         });
     }
+    drop(indices);
+    let auto_created = db
+        .auto_created_indices()
+        .write()
+        .unwrap()
+        .remove(&index_key)
+        .is_some();
+    db.index_predicates().write().unwrap().remove(&index_key);
+    db.index_names().write().unwrap().remove(&index_key);
 
     // Update statistics
     let mut stats = db.stats_mut().write().unwrap();
     stats.index_count = stats.index_count.saturating_sub(1);
+    if auto_created {
+        stats.auto_index_count = stats.auto_index_count.saturating_sub(1);
+    }
+    drop(stats);
+
+    remove_index_metadata(db, |m| m.index_key() == index_key)?;
+
+    let indices_dir = db.base_path().join("indices");
+    for extension in ["btree", "wal"] {
+        let path = indices_dir.join(format!("{}.{}", index_key, extension));
+        if path.exists() {
+            std::fs::remove_file(&path).map_err(|e| ReedError::IoError {
+                operation: "drop_index".to_string(),
+                reason: e.to_string(),
+            })?;
+        }
+    }
 
     Ok(())
 }
 
+/// Finds the index known by `name`.
+///
+/// ## Output
+/// - `Some(String)`: Index key (`table.column`)
+/// - `None`: No index has this name
+pub(crate) fn find_index_by_name(db: &Database, name: &str) -> Option<String> {
+    let indices = db.indices().read().unwrap();
+    let names = db.index_names().read().unwrap();
+
+    indices
+        .keys()
+        .find(|key| match names.get(*key) {
+            Some(explicit) => explicit == name,
+            None => key
+                .split_once('.')
+                .is_some_and(|(table, column)| IndexInfo::default_name(table, column) == name),
+        })
+        .cloned()
+}
+
+/// Drops every index of a table, in memory and on disk.
+///
+/// Removes the table's entries from metadata.json, its B+-Tree and WAL
+/// files (`indices/{table}.{column}.btree`/`.wal`) and the directory of
+/// persisted indices (`indices/{table}/`).
+///
+/// ## Input
+/// - `db`: Database reference
+/// - `table_name`: Table name
+///
+/// ## Output
+/// - `Ok(usize)`: Number of in-memory indices dropped
+/// - `Err(ReedError)`: Index files could not be removed
+pub(crate) fn drop_table_indices(db: &Database, table_name: &str) -> ReedResult<usize> {
+    let prefix = format!("{}.", table_name);
+
+    let mut indices = db.indices().write().unwrap();
+    let before = indices.len();
+    indices.retain(|key, _| !key.starts_with(&prefix));
+    let dropped = before - indices.len();
+    db.auto_created_indices()
+        .write()
+        .unwrap()
+        .retain(|key, _| !key.starts_with(&prefix));
+    db.index_predicates()
+        .write()
+        .unwrap()
+        .retain(|key, _| !key.starts_with(&prefix));
+    db.index_names()
+        .write()
+        .unwrap()
+        .retain(|key, _| !key.starts_with(&prefix));
+    drop(indices);
+
+    let mut stats = db.stats_mut().write().unwrap();
+    stats.index_count = stats.index_count.saturating_sub(dropped);
+    drop(stats);
+
+    let io_error = |e: std::io::Error| ReedError::IoError {
+        operation: "drop_table_indices".to_string(),
+        reason: e.to_string(),
+    };
+    let indices_dir = db.base_path().join("indices");
+    if !indices_dir.exists() {
+        return Ok(dropped);
+    }
+
+    remove_index_metadata(db, |m| m.table == table_name)?;
+
+    for entry in std::fs::read_dir(&indices_dir).map_err(io_error)? {
+        let path = entry.map_err(io_error)?.path();
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        if name.starts_with(&prefix) && (name.ends_with(".btree") || name.ends_with(".wal")) {
+            std::fs::remove_file(&path).map_err(io_error)?;
+        }
+    }
+
+    let persisted = indices_dir.join(table_name);
+    if persisted.is_dir() {
+        std::fs::remove_dir_all(&persisted).map_err(io_error)?;
+    }
+
+    Ok(dropped)
+}
+
 /// Rebuilds an index (useful after bulk updates).
 ///
+/// Partial indices keep their predicate, named indices their name.
+///
 /// ## Input
 /// - `db`: Database reference
 /// - `table_name`: Table name
@@ -423,11 +755,29 @@ pub fn drop_index(db: &Database, table_name: &str, column: &str) -> ReedResult<(
 /// - `Ok(())`: Index rebuilt successfully
 /// - `Err(ReedError)`: Rebuild failed
 pub fn rebuild_index(db: &Database, table_name: &str, column: &str) -> ReedResult<()> {
+    let index_key = format!("{}.{}", table_name, column);
+    let predicate = db
+        .index_predicates()
+        .read()
+        .unwrap()
+        .get(&index_key)
+        .cloned();
+    let name = db.index_names().read().unwrap().get(&index_key).cloned();
+
     // Drop existing index
     let _ = drop_index(db, table_name, column);
 
     // Recreate index
-    create_index(db, table_name, column)
+    let columns: Vec<&str> = column.split(',').collect();
+    create_index_on_columns(
+        db,
+        table_name,
+        &columns,
+        IndexBackend::BTree,
+        false,
+        predicate,
+        name.as_deref(),
+    )
 }
 
 /// Saves index metadata to .reed/indices/metadata.json
@@ -482,6 +832,31 @@ fn save_index_metadata(db: &Database, metadata: IndexMetadata) -> ReedResult<()>
     Ok(())
 }
 
+/// Removes the metadata.json entries matching `remove`.
+///
+/// ## Output
+/// - `Ok(())`: Entries removed (the file is only rewritten if one matched)
+/// - `Err(ReedError)`: Metadata could not be read or written
+fn remove_index_metadata(db: &Database, remove: impl Fn(&IndexMetadata) -> bool) -> ReedResult<()> {
+    let mut metadata = load_index_metadata(db)?;
+    let count = metadata.len();
+    metadata.retain(|m| !remove(m));
+    if metadata.len() == count {
+        return Ok(());
+    }
+
+    let json = serde_json::to_string_pretty(&metadata).map_err(|e| ReedError::IoError {
+        operation: "serialize_metadata".to_string(),
+        reason: e.to_string(),
+    })?;
+    std::fs::write(db.base_path().join("indices").join("metadata.json"), json).map_err(|e| {
+        ReedError::IoError {
+            operation: "write_metadata".to_string(),
+            reason: e.to_string(),
+        }
+    })
+}
+
 /// Loads all index metadata from .reed/indices/metadata.json
 ///
 /// ## Input
@@ -0,0 +1,260 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Referential integrity check.
+//!
+//! Finds values in foreign key columns (`foreign_key = "table.column"` in a
+//! table's schema) that have no matching row in the referenced table. Useful
+//! after a constraint was added to a table that already holds data.
+//!
+//! Empty values are not references and are never reported; use `required`
+//! to forbid them.
+
+use crate::database::types::{IntegrityReport, IntegrityViolation};
+use crate::database::Database;
+use crate::error::{ReedError, ReedResult};
+use crate::schema::Schema;
+use crate::tables::{list_tables, Table};
+use std::collections::{HashMap, HashSet};
+
+/// Checks all foreign key constraints of all tables.
+///
+/// ## Input
+/// - `db`: Database reference
+///
+/// ## Output
+/// - `Ok(IntegrityReport)`: Every orphaned value, ordered by table name,
+///   schema column order and row
+///
+/// ## Performance
+/// - O(n) per constraint where n = referencing rows
+/// - Uses the referenced column's index if loaded, otherwise reads the
+///   referenced table once per column
+///
+/// ## Error Conditions
+/// - `InvalidSchema`: Schema cannot be parsed, or names a column missing from
+///   the referencing or referenced table
+/// - `IoError`: Cannot read a table
+pub fn check_referential_integrity(db: &Database) -> ReedResult<IntegrityReport> {
+    let mut report = IntegrityReport::default();
+    let mut referenced_values: HashMap<(String, String), HashSet<String>> = HashMap::new();
+
+    for table_name in list_tables(db.base_path())? {
+        let Some(schema) = load_schema(db, &table_name)? else {
+            continue;
+        };
+
+        let constraints: Vec<(&str, &str, &str)> = schema
+            .columns
+            .iter()
+            .filter_map(|column| {
+                column
+                    .foreign_key_target()
+                    .map(|(table, referenced)| (column.name.as_str(), table, referenced))
+            })
+            .collect();
+        if constraints.is_empty() {
+            continue;
+        }
+
+        let table = Table::new(db.base_path(), &table_name);
+        let separator = table.delimiter()?.as_char();
+        let content = table.read_current()?;
+        let text = String::from_utf8_lossy(&content);
+        let mut lines = text.lines().enumerate();
+        let header: Vec<&str> = lines
+            .next()
+            .map(|(_, line)| line.split(separator).collect())
+            .unwrap_or_default();
+
+        for (column, ref_table, ref_column) in constraints {
+            let index = column_position(&header, &table_name, column)?;
+
+            for (line_no, line) in lines.clone() {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let value = line.split(separator).nth(index).unwrap_or_default();
+                if value.is_empty() || reference_exists(db, ref_table, ref_column, value)? {
+                    continue;
+                }
+
+                // Fall back to scanning the referenced table (cached per column)
+                let key = (ref_table.to_string(), ref_column.to_string());
+                if !referenced_values.contains_key(&key) {
+                    let values = read_column_values(db, ref_table, ref_column)?;
+                    referenced_values.insert(key.clone(), values);
+                }
+                if referenced_values[&key].contains(value) {
+                    continue;
+                }
+
+                report.violations.push(IntegrityViolation {
+                    table: table_name.clone(),
+                    column: column.to_string(),
+                    row: line_no + 1,
+                    value: value.to_string(),
+                    referenced_table: ref_table.to_string(),
+                    referenced_column: ref_column.to_string(),
+                });
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+/// Loads a table's schema through the database's schema registry.
+fn load_schema(db: &Database, table_name: &str) -> ReedResult<Option<Schema>> {
+    let mut registry = db.schema_registry().lock().unwrap();
+    Ok(registry.get(table_name, db.base_path())?.cloned())
+}
+
+/// Checks the referenced column's loaded index, if any.
+///
+/// Returns false when there is no index, so the caller falls back to a scan.
+pub(crate) fn reference_exists(
+    db: &Database,
+    ref_table: &str,
+    ref_column: &str,
+    value: &str,
+) -> ReedResult<bool> {
+    let indices = db.indices().read().unwrap();
+    match indices.get(&format!("{}.{}", ref_table, ref_column)) {
+        Some(index) => Ok(index
+            .get(&value.to_string())?
+            .is_some_and(|rows| !rows.is_empty())),
+        None => Ok(false),
+    }
+}
+
+/// Reads all values of a column (empty set if the table does not exist).
+pub(crate) fn read_column_values(
+    db: &Database,
+    table_name: &str,
+    column: &str,
+) -> ReedResult<HashSet<String>> {
+    let table = Table::new(db.base_path(), table_name);
+    if !table.exists() {
+        return Ok(HashSet::new());
+    }
+
+    let separator = table.delimiter()?.as_char();
+    let content = table.read_current()?;
+    let text = String::from_utf8_lossy(&content);
+    let mut lines = text.lines();
+    let header: Vec<&str> = lines
+        .next()
+        .map(|line| line.split(separator).collect())
+        .unwrap_or_default();
+    let index = column_position(&header, table_name, column)?;
+
+    Ok(lines
+        .filter_map(|line| line.split(separator).nth(index))
+        .filter(|value| !value.is_empty())
+        .map(String::from)
+        .collect())
+}
+
+fn column_position(header: &[&str], table_name: &str, column: &str) -> ReedResult<usize> {
+    header
+        .iter()
+        .position(|name| *name == column)
+        .ok_or_else(|| ReedError::InvalidSchema {
+            reason: format!(
+                "Foreign key column '{}' not found in table '{}'",
+                column, table_name
+            ),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{save_schema, ColumnDef};
+
+    fn setup_test(name: &str) -> std::path::PathBuf {
+        let temp_dir = std::env::temp_dir().join(format!("reedbase_integrity_test_{}", name));
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        crate::registry::set_base_path(temp_dir.clone());
+        crate::registry::init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+        temp_dir
+    }
+
+    fn routes_schema(reference: &str) -> Schema {
+        Schema::new(
+            "2.0".to_string(),
+            false,
+            vec![
+                ColumnDef::primary_key("key".to_string(), "string".to_string()),
+                ColumnDef::new("text_key".to_string(), "string".to_string())
+                    .with_foreign_key(reference.to_string()),
+                ColumnDef::new("route".to_string(), "string".to_string()),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_reports_orphaned_values() {
+        let temp_dir = setup_test("orphans");
+        Table::new(&temp_dir, "text")
+            .init(b"key|value\npage.home|Home\npage.about|About\n", "testuser")
+            .unwrap();
+        Table::new(&temp_dir, "routes")
+            .init(
+                b"key|text_key|route\nr1|page.home|/\nr2|page.gone|/gone\nr3||/blank\nr4|page.about|/about\n",
+                "testuser",
+            )
+            .unwrap();
+        save_schema(&temp_dir, "routes", &routes_schema("text.key")).unwrap();
+
+        let db = Database::open(&temp_dir).unwrap();
+        let report = db.check_referential_integrity().unwrap();
+
+        assert!(!report.is_clean());
+        assert_eq!(
+            report.violations,
+            vec![IntegrityViolation {
+                table: "routes".to_string(),
+                column: "text_key".to_string(),
+                row: 3,
+                value: "page.gone".to_string(),
+                referenced_table: "text".to_string(),
+                referenced_column: "key".to_string(),
+            }]
+        );
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_clean_and_missing_referenced_table() {
+        let temp_dir = setup_test("clean");
+        Table::new(&temp_dir, "text")
+            .init(b"key|value\npage.home|Home\n", "testuser")
+            .unwrap();
+        Table::new(&temp_dir, "routes")
+            .init(b"key|text_key|route\nr1|page.home|/\n", "testuser")
+            .unwrap();
+        save_schema(&temp_dir, "routes", &routes_schema("text.key")).unwrap();
+
+        let db = Database::open(&temp_dir).unwrap();
+        assert!(db.check_referential_integrity().unwrap().is_clean());
+
+        // Every non-empty value is orphaned if the referenced table is gone
+        save_schema(&temp_dir, "routes", &routes_schema("pages.key")).unwrap();
+        let db = Database::open(&temp_dir).unwrap();
+        let report = db.check_referential_integrity().unwrap();
+        assert_eq!(report.violations.len(), 1);
+        assert_eq!(report.violations[0].referenced_table, "pages");
+
+        // A referenced column that does not exist is a schema error
+        save_schema(&temp_dir, "routes", &routes_schema("text.slug")).unwrap();
+        let db = Database::open(&temp_dir).unwrap();
+        let result = db.check_referential_integrity();
+        assert!(matches!(result, Err(ReedError::InvalidSchema { .. })));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+}
@@ -84,28 +84,58 @@
 //!
 //! - `types`: Core types (Database, QueryResult, ExecuteResult, etc.)
 //! - `query`: Query execution (SELECT via ReedQL)
+//! - `cache`: Query result cache (LRU with TTL)
 //! - `execute`: Command execution (INSERT/UPDATE/DELETE)
 //! - `index`: Index management (create, auto-detect, optimize)
 //! - `stats`: Statistics and query pattern tracking
+//! - `vacuum`: Canonical rewrite of table CSV files
+//! - `import`: Bulk import of external CSV files
+//! - `export`: Export of tables and query results to CSV
+//! - `diff`: Cross-database table comparison
+//! - `async_ops`: Async query/execute wrappers (feature `tokio`)
+//! - `subscribe`: Row change subscriptions (feature `tokio`)
 
+#[cfg(feature = "tokio")]
+pub mod async_ops;
+pub mod cache;
 pub mod database;
+pub mod diff;
 pub mod execute;
+mod execute_batch;
+mod execute_copy;
+mod execute_ddl;
+mod execute_dml;
+mod execute_index;
+mod execute_parse;
+mod execute_validate;
+pub mod export;
+pub mod import;
 pub mod index;
+pub mod integrity;
 pub mod query;
 pub mod stats;
+#[cfg(feature = "tokio")]
+pub mod subscribe;
 pub mod types;
+pub mod vacuum;
 
 // Unit tests moved to integration tests in tests/ directory
 // #[cfg(test)]
 // mod database_test;
 // #[cfg(test)]
-// mod execute_test;
-// #[cfg(test)]
 // mod query_test;
 
+#[cfg(test)]
+mod execute_test;
+
 // Re-export public API
 pub use database::Database;
 pub use execute::{ExecuteResult, ExecuteStatement};
 pub use index::create_index_internal; // For auto-indexing
 pub use query::QueryResultFormatter;
-pub use types::{AutoIndexConfig, DatabaseStats, IndexInfo, QueryMetrics};
+#[cfg(feature = "tokio")]
+pub use subscribe::ChangeStream;
+pub use types::{
+    AutoIndexConfig, DatabaseDiff, DatabaseStats, ExportReport, ImportReport, IndexInfo,
+    IntegrityReport, IntegrityViolation, MaintenanceReport, QueryMetrics, TableDiff, VacuumReport,
+};
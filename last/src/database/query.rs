@@ -6,12 +6,25 @@
 //! This module handles all SELECT queries through the ReedQL engine.
 
 use crate::database::database::Database;
-use crate::database::stats::QueryPattern;
-use crate::database::types::QueryMetrics;
+use crate::database::stats::{
+    estimate_cost_for_rows, estimate_matching_rows, load_table_statistics, QueryPattern,
+    FOREIGN_KEY_THRESHOLD,
+};
+use crate::database::types::{IndexInfo, QueryMetrics};
 use crate::error::{ReedError, ReedResult};
-use crate::reedql::{execute, parse, OptimizedExecutor, QueryResult};
+use crate::reedql::analyzer::QueryPattern as AccessPattern;
+use crate::reedql::types::{ExplainStatement, FilterCondition, ParsedQuery, PreparedQuery};
+use crate::reedql::{
+    execute, execute_join, parse, parse_explain, ExecutionPlan, OptimizedExecutor, QueryAnalyzer,
+    QueryPlanner, QueryResult,
+};
+use crate::tables::table_stats;
+use std::collections::HashMap;
 use std::time::Instant;
 
+/// Maximum nesting of `IN (SELECT ...)` subqueries below the outer query.
+pub const MAX_SUBQUERY_DEPTH: usize = 3;
+
 /// Executes a ReedQL SELECT query.
 ///
 /// ## Input
@@ -26,64 +39,395 @@ use std::time::Instant;
 /// - Parse: < 10μs
 /// - Execute (with index): < 100μs (exact), < 1ms (range)
 /// - Execute (no index): ~10ms for 10k rows
+/// - Each `IN (SELECT ...)` runs once, before the outer query
+/// - Cached (see `Database::enable_query_cache()`): parse plus one lookup
+/// - `EXPLAIN [ANALYZE]`: see `explain_query()`
 pub fn execute_query(db: &Database, sql: &str) -> ReedResult<QueryResult> {
-    let mut metrics = QueryMetrics::new();
-    let total_start = Instant::now();
+    if sql
+        .split_whitespace()
+        .next()
+        .is_some_and(|word| word.eq_ignore_ascii_case("EXPLAIN"))
+    {
+        return explain_query(db, &parse_explain(sql)?);
+    }
 
     // Step 1: Parse query
     let parse_start = Instant::now();
     let query = parse(sql)?;
-    metrics.parse_time_us = parse_start.elapsed().as_micros() as u64;
 
-    // Step 2: Validate query type (must be SELECT)
-    if query.table.is_empty() {
-        return Err(ReedError::ParseError {
-            reason: "Missing table name".to_string(),
-        });
+    // Step 1b: Answer repeated queries from the cache (if enabled)
+    let cached = db
+        .query_cache()
+        .lock()
+        .unwrap()
+        .as_mut()
+        .map(|cache| cache.get(sql));
+    match cached {
+        None => run_query(db, query, parse_start.elapsed().as_micros() as u64),
+        Some(Some(result)) => Ok(result),
+        Some(None) => {
+            let tables = tables_read(&query);
+            let result = run_query(db, query, parse_start.elapsed().as_micros() as u64)?;
+            if let Some(cache) = db.query_cache().lock().unwrap().as_mut() {
+                cache.insert(sql, result.clone(), tables);
+            }
+            Ok(result)
+        }
     }
+}
 
-    // Step 3: Load table data
-    let table_ref = db.get_table(&query.table)?;
-    let content = table_ref.read_current()?;
-    let text = std::str::from_utf8(&content).map_err(|e| ReedError::ParseError {
-        reason: format!("Invalid UTF-8: {}", e),
-    })?;
+/// Tables a query reads: FROM, JOINs and subqueries.
+fn tables_read(query: &ParsedQuery) -> Vec<String> {
+    fn collect(condition: &FilterCondition, tables: &mut Vec<String>) {
+        match condition {
+            FilterCondition::InSubquery { subquery, .. } => {
+                tables.extend(tables_read(subquery));
+            }
+            FilterCondition::Or(operands) | FilterCondition::And(operands) => {
+                for operand in operands {
+                    collect(operand, tables);
+                }
+            }
+            FilterCondition::Not(inner) => collect(inner, tables),
+            _ => {}
+        }
+    }
 
-    // Parse CSV manually to get HashMap format
-    let lines: Vec<&str> = text.lines().collect();
-    if lines.is_empty() {
-        return Err(ReedError::ParseError {
-            reason: "Empty table".to_string(),
-        });
+    let mut tables = vec![query.table.clone()];
+    tables.extend(query.joins.iter().map(|join| join.table.clone()));
+    for condition in &query.conditions {
+        collect(condition, &mut tables);
     }
+    tables.sort();
+    tables.dedup();
+    tables
+}
 
-    let header_line = lines[0];
-    let header_parts: Vec<&str> = header_line.split('|').collect();
+/// Executes a prepared ReedQL SELECT query.
+///
+/// ## Input
+/// - `db`: Database reference
+/// - `prepared`: Query from `reedql::prepare()`
+/// - `params`: Placeholder values by name (without `$`)
+///
+/// ## Output
+/// - `Ok(QueryResult)`: Query result
+/// - `Err(ReedError)`: Binding or execution failed
+///
+/// ## Performance
+/// - Binding: < 1μs (no re-parsing)
+/// - Execute: same as `execute_query()`
+///
+/// ## Error Conditions
+/// - `ParseError`: Unknown or unbound placeholder, or non-numeric value
+pub fn execute_prepared(
+    db: &Database,
+    prepared: &PreparedQuery,
+    params: &HashMap<String, String>,
+) -> ReedResult<QueryResult> {
+    // Step 1: Bind placeholders
+    let bind_start = Instant::now();
+    let mut prepared = prepared.clone();
+    for (name, value) in params {
+        prepared.bind(name, value)?;
+    }
+    let query = prepared.to_query()?;
+    run_query(db, query, bind_start.elapsed().as_micros() as u64)
+}
 
-    let mut table_data = Vec::new();
-    for line in lines.iter().skip(1) {
-        if line.trim().is_empty() {
-            continue;
+/// Answers `EXPLAIN [ANALYZE] SELECT ...` with the execution plan.
+///
+/// Runs only `QueryAnalyzer::analyze()` and the `QueryPlanner` over the
+/// table's indices, and returns one row per step in execution order with
+/// the columns `step` (1-based), `operation`, `estimated_rows` and
+/// `index_used` (empty without index). Row estimates use the same
+/// selectivities as `estimate_query_cost()`.
+///
+/// EXPLAIN ANALYZE also runs the query and adds `actual_rows`: counted for
+/// the scan and filter steps of single-table queries and for the last step
+/// (the result), empty for steps in between.
+///
+/// ## Input
+/// - `db`: Database reference
+/// - `explain`: Statement from `reedql::parse_explain()`
+///
+/// ## Output
+/// - `Ok(QueryResult::Rows)`: Plan steps
+///
+/// ## Performance
+/// - EXPLAIN: planning only, < 1ms (reads table and index metadata)
+/// - EXPLAIN ANALYZE: query cost plus one more pass over the table
+///
+/// ## Error Conditions
+/// - `TableNotFound`: Queried table doesn't exist
+/// - Errors of the query itself (EXPLAIN ANALYZE)
+pub fn explain_query(db: &Database, explain: &ExplainStatement) -> ReedResult<QueryResult> {
+    let query = &explain.query;
+    let row_count = table_stats(db.base_path(), &query.table)?.row_count;
+    let indices: Vec<IndexInfo> = db
+        .list_indices()
+        .into_iter()
+        .filter(|i| i.table == query.table)
+        .collect();
+
+    let mut planner = QueryPlanner::new(
+        indices
+            .iter()
+            .map(|i| (i.name.clone(), i.column.clone()))
+            .collect(),
+    );
+    for index in &indices {
+        if let Some(predicate) = &index.predicate {
+            planner = planner.with_predicate(&index.name, predicate.clone());
         }
+    }
+    if let Some(statistics) = load_table_statistics(db.base_path(), &query.table)? {
+        planner = planner.with_statistics(statistics);
+    }
+    let pattern = QueryAnalyzer::analyze(query)?;
+    let plan = planner.plan_with_conditions(&pattern, &query.conditions, row_count, None)?;
+    let access_conditions: Vec<FilterCondition> = match (&plan, pattern_column(&pattern)) {
+        (ExecutionPlan::FullScan, _) | (_, None) => Vec::new(),
+        (_, Some(column)) => query
+            .conditions
+            .iter()
+            .filter(|c| c.column() == column)
+            .cloned()
+            .collect(),
+    };
 
-        let parts: Vec<&str> = line.split('|').collect();
-        let mut row_map = std::collections::HashMap::new();
-        for (col_idx, col_name) in header_parts.iter().enumerate() {
-            if let Some(&value) = parts.get(col_idx) {
-                row_map.insert(col_name.to_string(), value.to_string());
+    let mut steps = plan_steps(query, &plan, &access_conditions, row_count, &indices);
+
+    if explain.analyze {
+        let result = run_query(db, query.clone(), 0)?;
+        if !query.has_joins() {
+            let mut resolved = query.clone();
+            resolve_subqueries(db, &mut resolved, 0)?;
+            let rows = load_table_rows(db, &query.table)?;
+            steps[0].actual_rows = Some(count_matching(&rows, &access_conditions)?);
+            if !resolved.conditions.is_empty() {
+                steps[1].actual_rows = Some(count_matching(&rows, &resolved.conditions)?);
             }
         }
-        table_data.push(row_map);
+        if let Some(last) = steps.last_mut() {
+            last.actual_rows = Some(result.row_count());
+        }
+    }
+
+    Ok(QueryResult::Rows(
+        steps
+            .into_iter()
+            .enumerate()
+            .map(|(i, step)| step.into_row(i + 1, explain.analyze))
+            .collect(),
+    ))
+}
+
+/// One step of an EXPLAIN plan.
+struct PlanStep {
+    operation: String,
+    estimated_rows: f64,
+    index_used: Option<String>,
+    actual_rows: Option<usize>,
+}
+
+impl PlanStep {
+    fn new(operation: String, estimated_rows: f64) -> Self {
+        Self {
+            operation,
+            estimated_rows,
+            index_used: None,
+            actual_rows: None,
+        }
+    }
+
+    fn into_row(self, step: usize, analyze: bool) -> HashMap<String, String> {
+        let mut row = HashMap::from([
+            ("step".to_string(), step.to_string()),
+            ("operation".to_string(), self.operation),
+            (
+                "estimated_rows".to_string(),
+                (self.estimated_rows.round() as usize).to_string(),
+            ),
+            (
+                "index_used".to_string(),
+                self.index_used.unwrap_or_default(),
+            ),
+        ]);
+        if analyze {
+            row.insert(
+                "actual_rows".to_string(),
+                self.actual_rows.map(|n| n.to_string()).unwrap_or_default(),
+            );
+        }
+        row
     }
+}
 
-    metrics.rows_scanned = table_data.len();
+/// Steps of a query in execution order: access path, joins, filter,
+/// grouping and aggregation, DISTINCT, sort, limit.
+fn plan_steps(
+    query: &ParsedQuery,
+    plan: &ExecutionPlan,
+    access_conditions: &[FilterCondition],
+    row_count: usize,
+    indices: &[IndexInfo],
+) -> Vec<PlanStep> {
+    let access_column = access_conditions.first().map_or("", |c| c.column());
+    let mut rows = estimate_matching_rows(row_count, query, access_conditions, indices);
+    let mut access = match plan {
+        ExecutionPlan::FullScan => PlanStep::new(format!("Full Scan on {}", query.table), rows),
+        ExecutionPlan::IndexPointLookup { key, .. } => PlanStep::new(
+            format!(
+                "Index Point Lookup on {} ({} = '{}')",
+                query.table, access_column, key
+            ),
+            rows,
+        ),
+        ExecutionPlan::IndexRangeScan { start, end, .. } => PlanStep::new(
+            format!(
+                "Index Range Scan on {} ({} from '{}' to '{}')",
+                query.table, access_column, start, end
+            ),
+            rows,
+        ),
+    };
+    if let ExecutionPlan::IndexPointLookup { index_name, .. }
+    | ExecutionPlan::IndexRangeScan { index_name, .. } = plan
+    {
+        access.index_used = Some(index_name.clone());
+    }
+    let mut steps = vec![access];
+
+    for join in &query.joins {
+        // ON conditions compare two columns, so the right side is unquoted
+        let on = match &join.condition {
+            Some(FilterCondition::Equals { column, value }) => {
+                format!(" ON {} = {}", column, value)
+            }
+            Some(condition) => format!(" ON {}", condition),
+            None => String::new(),
+        };
+        steps.push(PlanStep::new(
+            format!("{} {}{}", join.join_type, join.table, on),
+            rows,
+        ));
+    }
+
+    if !query.conditions.is_empty() {
+        rows = estimate_matching_rows(row_count, query, &query.conditions, indices);
+        let conditions: Vec<String> = query.conditions.iter().map(|c| c.to_string()).collect();
+        steps.push(PlanStep::new(
+            format!("Filter {}", conditions.join(" AND ")),
+            rows,
+        ));
+    }
+
+    if query.has_grouping() {
+        steps.push(PlanStep::new(
+            format!("Group By {}", query.group_by.join(", ")),
+            rows,
+        ));
+    }
+    if let Some(aggregation) = &query.aggregation {
+        if !query.has_grouping() {
+            rows = 1.0;
+        }
+        steps.push(PlanStep::new(
+            format!("Aggregate {}", aggregation.output_name()),
+            rows,
+        ));
+    }
+    if let Some(having) = &query.having {
+        steps.push(PlanStep::new(format!("Having {}", having), rows));
+    }
+    if query.distinct {
+        steps.push(PlanStep::new("Distinct".to_string(), rows));
+    }
+
+    if !query.order_by.is_empty() {
+        let order: Vec<String> = query
+            .order_by
+            .iter()
+            .map(|o| format!("{} {}", o.column, o.direction))
+            .collect();
+        steps.push(PlanStep::new(format!("Sort by {}", order.join(", ")), rows));
+    }
+
+    if let Some(limit) = &query.limit {
+        rows = (rows - limit.offset as f64).clamp(0.0, limit.limit as f64);
+        let operation = if limit.offset > 0 {
+            format!("Limit {} Offset {}", limit.limit, limit.offset)
+        } else {
+            format!("Limit {}", limit.limit)
+        };
+        steps.push(PlanStep::new(operation, rows));
+    }
+
+    steps
+}
+
+/// Column an access pattern reads (None for a full scan).
+fn pattern_column(pattern: &AccessPattern) -> Option<&str> {
+    match pattern {
+        AccessPattern::FullScan => None,
+        AccessPattern::PointLookup { column, .. }
+        | AccessPattern::PrefixScan { column, .. }
+        | AccessPattern::RangeScan { column, .. } => Some(column),
+    }
+}
+
+/// Number of rows matching all `conditions`.
+fn count_matching(
+    rows: &[HashMap<String, String>],
+    conditions: &[FilterCondition],
+) -> ReedResult<usize> {
+    rows.iter().try_fold(0, |count, row| {
+        for condition in conditions {
+            if !condition.matches(row)? {
+                return Ok(count);
+            }
+        }
+        Ok(count + 1)
+    })
+}
+
+/// Runs a parsed query; `parse_time_us` is recorded in the metrics.
+fn run_query(db: &Database, mut query: ParsedQuery, parse_time_us: u64) -> ReedResult<QueryResult> {
+    let mut metrics = QueryMetrics::new();
+    metrics.parse_time_us = parse_time_us;
+
+    // Step 2: Validate query type (must be SELECT)
+    if query.table.is_empty() {
+        return Err(ReedError::ParseError {
+            reason: "Missing table name".to_string(),
+        });
+    }
+
+    // Step 2b: Replace subqueries with the values they return
+    resolve_subqueries(db, &mut query, 0)?;
+
+    // Step 3: Load table data
+    let table_data = load_table_rows(db, &query.table)?;
+    let joined_data = query
+        .joins
+        .iter()
+        .map(|join| load_table_rows(db, &join.table))
+        .collect::<ReedResult<Vec<_>>>()?;
+
+    metrics.rows_scanned = table_data.len() + joined_data.iter().map(Vec::len).sum::<usize>();
+    metrics.estimated_cost_ns =
+        estimate_cost_for_rows(table_data.len(), &query, &db.list_indices());
 
     // Step 5: Track query pattern for auto-indexing
     track_query_pattern(db, &query);
 
     // Step 6: Execute query (with optimization if indices available)
     let exec_start = Instant::now();
-    let result = if db.indices().read().unwrap().is_empty() {
+    let result = if query.has_joins() {
+        // Equality joins run as hash joins over the loaded rows
+        execute_join(&query, &table_data, &joined_data)?
+    } else if db.indices().read().unwrap().is_empty() {
         // No indices available - use basic executor
         execute(&query, &table_data)?
     } else {
@@ -92,7 +436,10 @@ pub fn execute_query(db: &Database, sql: &str) -> ReedResult<QueryResult> {
         let index_list: Vec<(String, Box<dyn crate::indices::Index<String, Vec<usize>>>)> =
             Vec::new(); // TODO: Convert Arc<RwLock<HashMap>> to Vec
 
-        let executor = OptimizedExecutor::new(index_list);
+        let mut executor = OptimizedExecutor::new(index_list);
+        if let Some(stats) = load_table_statistics(db.base_path(), &query.table)? {
+            executor = executor.with_statistics(stats);
+        }
         executor.execute_optimized(&query, &table_data)?
     };
 
@@ -112,6 +459,133 @@ pub fn execute_query(db: &Database, sql: &str) -> ReedResult<QueryResult> {
     Ok(result)
 }
 
+/// Replaces every `IN (SELECT ...)` of a query with the values it returns.
+///
+/// Subqueries read their tables through the same database and may nest up
+/// to `MAX_SUBQUERY_DEPTH` levels. `depth` is the nesting level of `query`
+/// (0 = outer query).
+///
+/// ## Error Conditions
+/// - `ParseError`: Nesting deeper than `MAX_SUBQUERY_DEPTH`, or a subquery
+///   selecting `*`
+/// - `TableNotFound`: A subquery's table does not exist
+fn resolve_subqueries(db: &Database, query: &mut ParsedQuery, depth: usize) -> ReedResult<()> {
+    for condition in query.conditions.iter_mut().chain(query.having.iter_mut()) {
+        resolve_condition(db, condition, depth)?;
+    }
+    Ok(())
+}
+
+fn resolve_condition(
+    db: &Database,
+    condition: &mut FilterCondition,
+    depth: usize,
+) -> ReedResult<()> {
+    match condition {
+        FilterCondition::InSubquery { column, subquery } => {
+            if depth >= MAX_SUBQUERY_DEPTH {
+                return Err(ReedError::ParseError {
+                    reason: format!(
+                        "Subqueries nested deeper than {} levels",
+                        MAX_SUBQUERY_DEPTH
+                    ),
+                });
+            }
+            let values = subquery_values(db, subquery, depth + 1)?;
+            *condition = FilterCondition::InList {
+                column: std::mem::take(column),
+                values,
+            };
+        }
+        FilterCondition::Or(operands) | FilterCondition::And(operands) => {
+            for operand in operands {
+                resolve_condition(db, operand, depth)?;
+            }
+        }
+        FilterCondition::Not(inner) => resolve_condition(db, inner, depth)?,
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Runs a subquery and returns its first selected column.
+fn subquery_values(db: &Database, subquery: &ParsedQuery, depth: usize) -> ReedResult<Vec<String>> {
+    let mut subquery = subquery.clone();
+    resolve_subqueries(db, &mut subquery, depth)?;
+
+    let table_data = load_table_rows(db, &subquery.table)?;
+    let result = if subquery.has_joins() {
+        let joined_data = subquery
+            .joins
+            .iter()
+            .map(|join| load_table_rows(db, &join.table))
+            .collect::<ReedResult<Vec<_>>>()?;
+        execute_join(&subquery, &table_data, &joined_data)?
+    } else {
+        execute(&subquery, &table_data)?
+    };
+
+    let rows = match result {
+        QueryResult::Aggregation(value) => return Ok(vec![value.to_string()]),
+        QueryResult::Rows(rows) => rows,
+    };
+
+    // Without GROUP BY, `columns` mirrors the aggregation's columns
+    let column = match &subquery.aggregation {
+        _ if subquery.is_select_all() => {
+            return Err(ReedError::ParseError {
+                reason: "Subquery must select a column, not *".to_string(),
+            })
+        }
+        Some(agg) if !subquery.has_grouping() || subquery.columns.is_empty() => agg.output_name(),
+        _ => subquery.output_name(0).to_string(),
+    };
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|mut row| row.remove(&column))
+        .collect())
+}
+
+/// Loads a table's current version as rows of column → value.
+fn load_table_rows(db: &Database, table: &str) -> ReedResult<Vec<HashMap<String, String>>> {
+    let table_ref = db.get_table(table)?;
+    let separator = table_ref.delimiter()?.as_char();
+    let content = table_ref.read_current()?;
+    let text = std::str::from_utf8(&content).map_err(|e| ReedError::ParseError {
+        reason: format!("Invalid UTF-8: {}", e),
+    })?;
+
+    // Parse CSV manually to get HashMap format
+    let lines: Vec<&str> = text.lines().collect();
+    if lines.is_empty() {
+        return Err(ReedError::ParseError {
+            reason: "Empty table".to_string(),
+        });
+    }
+
+    let header_line = lines[0];
+    let header_parts: Vec<&str> = header_line.split(separator).collect();
+
+    let mut table_data = Vec::new();
+    for line in lines.iter().skip(1) {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let parts: Vec<&str> = line.split(separator).collect();
+        let mut row_map = HashMap::new();
+        for (col_idx, col_name) in header_parts.iter().enumerate() {
+            if let Some(&value) = parts.get(col_idx) {
+                row_map.insert(col_name.to_string(), value.to_string());
+            }
+        }
+        table_data.push(row_map);
+    }
+
+    Ok(table_data)
+}
+
 /// Tracks query pattern for auto-indexing.
 fn track_query_pattern(db: &Database, query: &crate::reedql::types::ParsedQuery) {
     if !db.auto_index_config().enabled {
@@ -120,6 +594,25 @@ fn track_query_pattern(db: &Database, query: &crate::reedql::types::ParsedQuery)
 
     let mut tracker = db.pattern_tracker().write().unwrap();
 
+    // Foreign keys (`*_id` columns) are indexed after a few exact matches
+    if db.auto_index_config().foreign_key_detection {
+        let hotspots: Vec<&str> = QueryAnalyzer::detect_foreign_key_pattern(query)
+            .into_iter()
+            .filter(|column| {
+                tracker.record_foreign_key(&query.table, column) == FOREIGN_KEY_THRESHOLD
+            })
+            .collect();
+        if !hotspots.is_empty() {
+            drop(tracker);
+            for column in hotspots {
+                // Best effort, e.g. the column may be indexed already
+                let _ =
+                    crate::database::index::create_index_internal(db, &query.table, column, true);
+            }
+            return;
+        }
+    }
+
     // Track each condition
     for condition in &query.conditions {
         let (column, operation) = match condition {
@@ -129,7 +622,8 @@ fn track_query_pattern(db: &Database, query: &crate::reedql::types::ParsedQuery)
             crate::reedql::types::FilterCondition::LessThan { column, .. }
             | crate::reedql::types::FilterCondition::GreaterThan { column, .. }
             | crate::reedql::types::FilterCondition::LessThanOrEqual { column, .. }
-            | crate::reedql::types::FilterCondition::GreaterThanOrEqual { column, .. } => {
+            | crate::reedql::types::FilterCondition::GreaterThanOrEqual { column, .. }
+            | crate::reedql::types::FilterCondition::Between { column, .. } => {
                 (column.clone(), "range".to_string())
             }
             crate::reedql::types::FilterCondition::Like { column, .. } => {
@@ -384,4 +878,328 @@ mod tests {
         assert_eq!(QueryResultFormatter::format_json(&result), "42.5\n");
         assert_eq!(QueryResultFormatter::format_csv(&result), "42.5\n");
     }
+
+    #[test]
+    fn test_execute_query_with_join() {
+        let temp_dir = std::env::temp_dir().join("reedbase_query_join_test");
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        crate::registry::set_base_path(temp_dir.clone());
+        crate::registry::init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+
+        let db = Database::open(&temp_dir).unwrap();
+        crate::tables::Table::new(&temp_dir, "text")
+            .init(b"key|value\npage.home|Home\npage.about|About\n", "testuser")
+            .unwrap();
+        crate::tables::Table::new(&temp_dir, "routes")
+            .init(b"key|text_key|route\nr1|page.about|/about\n", "testuser")
+            .unwrap();
+
+        let result = execute_query(
+            &db,
+            "SELECT t.key, r.route FROM text t JOIN routes r ON t.key = r.text_key",
+        )
+        .unwrap();
+        match result {
+            QueryResult::Rows(rows) => {
+                assert_eq!(rows.len(), 1);
+                assert_eq!(rows[0]["t.key"], "page.about");
+                assert_eq!(rows[0]["r.route"], "/about");
+            }
+            other => panic!("Expected rows, got {:?}", other),
+        }
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_explain_query() {
+        let (temp_dir, db) = setup_subquery_db("explain");
+        let steps = |result: QueryResult| match result {
+            QueryResult::Rows(rows) => rows,
+            other => panic!("Expected rows, got {:?}", other),
+        };
+
+        let plan = steps(
+            execute_query(
+                &db,
+                "EXPLAIN SELECT key FROM text WHERE value = 'Home' ORDER BY key LIMIT 2",
+            )
+            .unwrap(),
+        );
+        let operations: Vec<&str> = plan.iter().map(|s| s["operation"].as_str()).collect();
+        assert_eq!(
+            operations,
+            vec![
+                "Full Scan on text",
+                "Filter value = 'Home'",
+                "Sort by key ASC",
+                "Limit 2"
+            ]
+        );
+        assert_eq!(plan[0]["step"], "1");
+        assert_eq!(plan[0]["estimated_rows"], "3");
+        assert_eq!(plan[0]["index_used"], "");
+        assert!(!plan[0].contains_key("actual_rows"));
+
+        // With an index on key the planner picks a point lookup
+        db.create_index("text", "key").unwrap();
+        let plan = steps(
+            execute_query(
+                &db,
+                "EXPLAIN ANALYZE SELECT * FROM text WHERE key = 'page.home'",
+            )
+            .unwrap(),
+        );
+        assert_eq!(plan.len(), 2);
+        assert_eq!(
+            plan[0]["operation"],
+            "Index Point Lookup on text (key = 'page.home')"
+        );
+        assert_eq!(plan[0]["index_used"], "text_key_idx");
+        assert_eq!(plan[0]["actual_rows"], "1");
+        assert_eq!(plan[1]["actual_rows"], "1");
+
+        // Joins: only the result is counted
+        let plan = steps(
+            execute_query(
+                &db,
+                "EXPLAIN ANALYZE SELECT * FROM text JOIN routes ON text.key = routes.text_key",
+            )
+            .unwrap(),
+        );
+        assert_eq!(
+            plan[1]["operation"],
+            "JOIN routes ON text.key = routes.text_key"
+        );
+        assert_eq!(plan[0]["actual_rows"], "");
+        assert_eq!(plan[1]["actual_rows"], "2");
+
+        assert!(matches!(
+            execute_query(&db, "EXPLAIN SELECT * FROM missing"),
+            Err(ReedError::TableNotFound { .. })
+        ));
+        assert!(matches!(
+            execute_query(&db, "EXPLAIN DELETE FROM text"),
+            Err(ReedError::ParseError { .. })
+        ));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    fn setup_subquery_db(name: &str) -> (std::path::PathBuf, Database) {
+        let temp_dir = std::env::temp_dir().join(format!("reedbase_query_subquery_{}", name));
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        crate::registry::set_base_path(temp_dir.clone());
+        crate::registry::init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+
+        let db = Database::open(&temp_dir).unwrap();
+        crate::tables::Table::new(&temp_dir, "text")
+            .init(
+                b"key|value\npage.home|Home\npage.about|About\npage.draft|Draft\n",
+                "testuser",
+            )
+            .unwrap();
+        crate::tables::Table::new(&temp_dir, "routes")
+            .init(
+                b"key|text_key|route\nr1|page.home|/\nr2|page.about|/about\nr3|page.gone|/gone\n",
+                "testuser",
+            )
+            .unwrap();
+        (temp_dir, db)
+    }
+
+    fn keys(result: QueryResult) -> Vec<String> {
+        match result {
+            QueryResult::Rows(rows) => {
+                let mut keys: Vec<String> = rows.into_iter().map(|r| r["key"].clone()).collect();
+                keys.sort();
+                keys
+            }
+            other => panic!("Expected rows, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_execute_query_with_subquery() {
+        let (temp_dir, db) = setup_subquery_db("in");
+
+        // Texts that have a route
+        let result = execute_query(
+            &db,
+            "SELECT key FROM text WHERE key IN (SELECT text_key FROM routes)",
+        )
+        .unwrap();
+        assert_eq!(keys(result), vec!["page.about", "page.home"]);
+
+        // The subquery filters its own table; NOT IN inverts
+        let result = execute_query(
+            &db,
+            "SELECT key FROM text WHERE key NOT IN \
+             (SELECT text_key FROM routes WHERE route LIKE '/a%')",
+        )
+        .unwrap();
+        assert_eq!(keys(result), vec!["page.draft", "page.home"]);
+
+        // Nested: routes whose text is one of the routed texts
+        let result = execute_query(
+            &db,
+            "SELECT key FROM routes WHERE text_key IN \
+             (SELECT key FROM text WHERE key IN (SELECT text_key FROM routes))",
+        )
+        .unwrap();
+        assert_eq!(keys(result), vec!["r1", "r2"]);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_execute_query_subquery_errors() {
+        let (temp_dir, db) = setup_subquery_db("errors");
+
+        // MAX_SUBQUERY_DEPTH levels are fine, one more is not
+        let mut sql = "SELECT key FROM text".to_string();
+        for _ in 0..MAX_SUBQUERY_DEPTH {
+            sql = format!("SELECT key FROM text WHERE key IN ({})", sql);
+        }
+        assert!(execute_query(&db, &sql).is_ok());
+        let sql = format!("SELECT key FROM text WHERE key IN ({})", sql);
+        assert!(matches!(
+            execute_query(&db, &sql),
+            Err(ReedError::ParseError { .. })
+        ));
+
+        assert!(matches!(
+            execute_query(
+                &db,
+                "SELECT key FROM text WHERE key IN (SELECT * FROM routes)"
+            ),
+            Err(ReedError::ParseError { .. })
+        ));
+        assert!(matches!(
+            execute_query(
+                &db,
+                "SELECT key FROM text WHERE key IN (SELECT key FROM nope)"
+            ),
+            Err(ReedError::TableNotFound { .. })
+        ));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_execute_prepared_pages() {
+        let (temp_dir, db) = setup_subquery_db("prepared");
+        let prepared =
+            crate::reedql::prepare("SELECT key FROM text ORDER BY key LIMIT $limit OFFSET $offset")
+                .unwrap();
+
+        let page = |offset: usize| {
+            let params = HashMap::from([
+                ("limit".to_string(), "2".to_string()),
+                ("offset".to_string(), offset.to_string()),
+            ]);
+            match execute_prepared(&db, &prepared, &params).unwrap() {
+                QueryResult::Rows(rows) => rows
+                    .into_iter()
+                    .map(|r| r["key"].clone())
+                    .collect::<Vec<_>>(),
+                other => panic!("Expected rows, got {:?}", other),
+            }
+        };
+        assert_eq!(page(0), vec!["page.about", "page.draft"]);
+        assert_eq!(page(2), vec!["page.home"]);
+
+        // Missing, unknown and non-numeric bindings
+        let only_limit = HashMap::from([("limit".to_string(), "2".to_string())]);
+        let unknown = HashMap::from([("size".to_string(), "2".to_string())]);
+        let invalid = HashMap::from([("limit".to_string(), "-1".to_string())]);
+        for params in [only_limit, unknown, invalid] {
+            assert!(matches!(
+                execute_prepared(&db, &prepared, &params),
+                Err(ReedError::ParseError { .. })
+            ));
+        }
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_foreign_key_auto_index() {
+        let temp_dir = std::env::temp_dir().join("reedbase_query_foreign_key_test");
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        crate::registry::set_base_path(temp_dir.clone());
+        crate::registry::init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+
+        let db = Database::open(&temp_dir).unwrap();
+        crate::tables::Table::new(&temp_dir, "orders")
+            .init(
+                b"key|user_id|total\no1|u1|10\no2|u2|20\no3|u1|30\n",
+                "testuser",
+            )
+            .unwrap();
+        let indexed = || {
+            db.list_indices()
+                .into_iter()
+                .find(|info| info.table == "orders" && info.column == "user_id")
+        };
+
+        // `=` and `IN` count together; other columns and operators do not
+        for sql in [
+            "SELECT * FROM orders WHERE user_id = 'u1'",
+            "SELECT * FROM orders WHERE user_id IN ('u1', 'u2')",
+            "SELECT * FROM orders WHERE user_id > 'u1'",
+            "SELECT * FROM orders WHERE total = '10'",
+        ] {
+            execute_query(&db, sql).unwrap();
+        }
+        assert!(indexed().is_none());
+
+        execute_query(&db, "SELECT * FROM orders WHERE user_id = 'u2'").unwrap();
+        let info = indexed().expect("user_id indexed after three queries");
+        assert!(info.auto_created);
+
+        // The fourth query runs with the index
+        let result = execute_query(&db, "SELECT * FROM orders WHERE user_id = 'u1'").unwrap();
+        assert_eq!(keys(result), vec!["o1", "o3"]);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_query_cache() {
+        let (temp_dir, db) = setup_subquery_db("cache");
+        let sql = "SELECT key FROM text WHERE key LIKE 'page.%'";
+        db.query(sql).unwrap();
+        assert_eq!(db.query_cache_stats(), (0, 0, 0));
+
+        db.enable_query_cache(2, std::time::Duration::from_secs(60));
+        assert_eq!(keys(db.query(sql).unwrap()).len(), 3);
+        let reformatted = "SELECT key\n  FROM text WHERE key LIKE 'page.%' ";
+        assert_eq!(keys(db.query(reformatted).unwrap()).len(), 3);
+        assert_eq!(db.query_cache_stats(), (1, 1, 0));
+
+        // A write drops the table's results, including subqueries reading it
+        let subquery = "SELECT key FROM routes WHERE text_key IN (SELECT key FROM text)";
+        assert_eq!(keys(db.query(subquery).unwrap()), vec!["r1", "r2"]);
+        db.execute(
+            "INSERT INTO text (key, value) VALUES ('page.gone', 'Gone')",
+            "testuser",
+        )
+        .unwrap();
+        assert_eq!(keys(db.query(sql).unwrap()).len(), 4);
+        assert_eq!(keys(db.query(subquery).unwrap()), vec!["r1", "r2", "r3"]);
+        assert_eq!(db.query_cache_stats(), (1, 4, 0));
+
+        // A third result evicts the least recently used one
+        db.query("SELECT key FROM routes").unwrap();
+        assert_eq!(db.query_cache_stats(), (1, 5, 1));
+        db.query(subquery).unwrap();
+        db.query(sql).unwrap();
+        assert_eq!(db.query_cache_stats(), (2, 6, 2));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
 }
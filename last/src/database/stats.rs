@@ -1,11 +1,48 @@
 // Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
 // SPDX-License-Identifier: Apache-2.0
 
-//! Query pattern tracking for auto-indexing.
+//! Query pattern tracking for auto-indexing and query cost estimation.
 //!
-//! Tracks query patterns to automatically create indices when beneficial.
-
+//! Tracks query patterns to automatically create indices when beneficial,
+//! estimates what a query will cost before it runs, and gathers the planner
+//! statistics of `ANALYZE TABLE`.
+
+use crate::btree::PAGE_SIZE;
+use crate::database::database::Database;
+use crate::database::types::{IndexBackend, IndexInfo};
+use crate::error::{ReedError, ReedResult};
+use crate::functions::aggregations::HyperLogLog;
+use crate::reedql::planner::{
+    conditions_imply, ColumnStatistics, Statistics, DEFAULT_INDEX_SELECTIVITY,
+};
+use crate::reedql::types::{FilterCondition, ParsedQuery};
+use crate::tables::TableStats;
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Estimated cost of scanning and filtering one row (ns).
+pub const ROW_SCAN_NS: f64 = 100.0;
+
+/// Estimated cost of reading one index page (ns).
+pub const PAGE_READ_NS: f64 = 1_000.0;
+
+/// Estimated cost of fetching one row by position (ns).
+pub const ROW_FETCH_NS: f64 = 50.0;
+
+/// Estimated cost of one sort comparison (ns).
+pub const COMPARE_NS: f64 = 20.0;
+
+/// Queries on a `*_id` column before it is auto-indexed.
+pub const FOREIGN_KEY_THRESHOLD: usize = 3;
+
+/// Assumed selectivity of an equality match without cardinality data.
+const DEFAULT_EQUALS_SELECTIVITY: f64 = 0.1;
+
+/// Assumed selectivity of a range comparison.
+const RANGE_SELECTIVITY: f64 = 1.0 / 3.0;
+
+/// Assumed selectivity of a LIKE pattern.
+const LIKE_SELECTIVITY: f64 = 0.25;
 
 /// Query pattern for tracking.
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
@@ -39,6 +76,9 @@ pub struct PatternTracker {
 
     /// Patterns that have triggered index creation
     indexed_patterns: HashMap<QueryPattern, bool>,
+
+    /// (table, column) → exact-match queries on foreign key columns
+    foreign_keys: HashMap<(String, String), usize>,
 }
 
 impl PatternTracker {
@@ -47,6 +87,7 @@ impl PatternTracker {
         Self {
             patterns: HashMap::new(),
             indexed_patterns: HashMap::new(),
+            foreign_keys: HashMap::new(),
         }
     }
 
@@ -82,6 +123,26 @@ impl PatternTracker {
         self.patterns.get(pattern).copied().unwrap_or(0) >= threshold
     }
 
+    /// Records an exact-match query on a foreign key column.
+    ///
+    /// Counts `=` and `IN` together, per table and column.
+    ///
+    /// ## Input
+    /// - `table`: Table name
+    /// - `column`: Foreign key column (`*_id`)
+    ///
+    /// ## Output
+    /// - Current count for this column; the index is due when it reaches
+    ///   `FOREIGN_KEY_THRESHOLD`
+    pub fn record_foreign_key(&mut self, table: &str, column: &str) -> usize {
+        let count = self
+            .foreign_keys
+            .entry((table.to_string(), column.to_string()))
+            .or_insert(0);
+        *count += 1;
+        *count
+    }
+
     /// Marks a pattern as indexed.
     pub fn mark_indexed(&mut self, pattern: QueryPattern) {
         self.indexed_patterns.insert(pattern, true);
@@ -99,10 +160,18 @@ impl PatternTracker {
         patterns.into_iter().take(n).collect()
     }
 
+    /// Forgets every pattern recorded for a table (after it was dropped).
+    pub fn forget_table(&mut self, table: &str) {
+        self.patterns.retain(|p, _| p.table != table);
+        self.indexed_patterns.retain(|p, _| p.table != table);
+        self.foreign_keys.retain(|(t, _), _| t != table);
+    }
+
     /// Clears all tracked patterns.
     pub fn clear(&mut self) {
         self.patterns.clear();
         self.indexed_patterns.clear();
+        self.foreign_keys.clear();
     }
 }
 
@@ -112,6 +181,350 @@ impl Default for PatternTracker {
     }
 }
 
+/// Estimates the CPU cost of a query in nanoseconds.
+///
+/// Compares a full scan with the cheapest usable index and adds the cost of
+/// sorting the result:
+///
+/// ```text
+/// full scan = row_count × ROW_SCAN_NS
+/// index     = log2(row_count) × PAGE_READ_NS + selectivity × row_count × ROW_FETCH_NS
+/// sort      = result_rows × log2(result_rows) × COMPARE_NS
+/// ```
+///
+/// Hash indices serve `=` and `IN`; B+-Tree indices also serve ranges and
+/// prefix `LIKE`. Equality selectivity is `1 / entry_count` when the index
+/// reports its entry count, otherwise a fixed default. Partial indices count
+/// only when the query's conditions imply their predicate.
+///
+/// ## Input
+/// - `stats`: Statistics of the queried table
+/// - `query`: Parsed query
+/// - `indices`: Available indices (entries for other tables are ignored)
+///
+/// ## Output
+/// - Estimated cost in nanoseconds (relative, for comparing queries)
+///
+/// ## Performance
+/// - O(c × i) where c = conditions, i = indices
+///
+/// ## Example Usage
+/// ```no_run
+/// use reedbase_last::database::stats::estimate_query_cost;
+/// use reedbase_last::reedql::parse;
+/// use reedbase_last::tables::table_stats;
+/// use std::path::Path;
+///
+/// let stats = table_stats(Path::new(".reed"), "text")?;
+/// let query = parse("SELECT * FROM text WHERE namespace = 'page' ORDER BY key")?;
+/// let cost_ns = estimate_query_cost(&stats, &query, &[]);
+/// println!("Estimated cost: {:.2}ms", cost_ns / 1_000_000.0);
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn estimate_query_cost(stats: &TableStats, query: &ParsedQuery, indices: &[IndexInfo]) -> f64 {
+    estimate_cost_for_rows(stats.row_count, query, indices)
+}
+
+/// `estimate_query_cost()` for a known row count.
+pub(crate) fn estimate_cost_for_rows(
+    row_count: usize,
+    query: &ParsedQuery,
+    indices: &[IndexInfo],
+) -> f64 {
+    let rows = row_count as f64;
+    let table_indices = query_indices(query, indices);
+    let result_rows = estimate_matching_rows(row_count, query, &query.conditions, indices);
+
+    // Cheapest access path
+    let full_scan = rows * ROW_SCAN_NS;
+    let access = query
+        .conditions
+        .iter()
+        .filter(|c| usable_index(c, &table_indices).is_some())
+        .map(|c| {
+            let index_rows = rows * condition_selectivity(c, &table_indices);
+            log2_or_zero(rows) * PAGE_READ_NS + index_rows * ROW_FETCH_NS
+        })
+        .fold(full_scan, f64::min);
+
+    let sort = if query.order_by.is_empty() {
+        0.0
+    } else {
+        result_rows * log2_or_zero(result_rows) * COMPARE_NS
+    };
+
+    access + sort
+}
+
+/// Estimated number of rows satisfying `conditions` (treated as
+/// independent), out of `row_count` rows of the table `query` reads.
+pub(crate) fn estimate_matching_rows(
+    row_count: usize,
+    query: &ParsedQuery,
+    conditions: &[FilterCondition],
+    indices: &[IndexInfo],
+) -> f64 {
+    let table_indices = query_indices(query, indices);
+    let selectivity: f64 = conditions
+        .iter()
+        .map(|c| condition_selectivity(c, &table_indices))
+        .product();
+    row_count as f64 * selectivity
+}
+
+/// Indices of the queried table that `query` may use (partial indices only
+/// if its conditions imply their predicate).
+fn query_indices<'a>(query: &ParsedQuery, indices: &'a [IndexInfo]) -> Vec<&'a IndexInfo> {
+    indices
+        .iter()
+        .filter(|i| i.table == query.table)
+        .filter(|i| {
+            i.predicate
+                .as_ref()
+                .is_none_or(|p| conditions_imply(&query.conditions, p))
+        })
+        .collect()
+}
+
+/// Fraction of rows expected to satisfy a condition.
+fn condition_selectivity(condition: &FilterCondition, indices: &[&IndexInfo]) -> f64 {
+    let equals = |column: &str| {
+        indices
+            .iter()
+            .find(|i| i.column == column && i.entry_count > 0)
+            .map_or(DEFAULT_EQUALS_SELECTIVITY, |i| 1.0 / i.entry_count as f64)
+    };
+
+    match condition {
+        FilterCondition::Equals { column, .. } => equals(column),
+        FilterCondition::NotEquals { column, .. } => 1.0 - equals(column),
+        FilterCondition::InList { column, values } => {
+            (equals(column) * values.len() as f64).min(1.0)
+        }
+        FilterCondition::LessThan { .. }
+        | FilterCondition::GreaterThan { .. }
+        | FilterCondition::LessThanOrEqual { .. }
+        | FilterCondition::GreaterThanOrEqual { .. }
+        | FilterCondition::Between { .. } => RANGE_SELECTIVITY,
+        FilterCondition::Like { .. } => LIKE_SELECTIVITY,
+        FilterCondition::IsEmpty { .. }
+        | FilterCondition::IsNull { .. }
+        | FilterCondition::InSubquery { .. } => DEFAULT_EQUALS_SELECTIVITY,
+        FilterCondition::IsNotEmpty { .. } | FilterCondition::IsNotNull { .. } => {
+            1.0 - DEFAULT_EQUALS_SELECTIVITY
+        }
+        // Operands treated as independent
+        FilterCondition::Or(operands) => {
+            1.0 - operands
+                .iter()
+                .map(|c| 1.0 - condition_selectivity(c, indices))
+                .product::<f64>()
+        }
+        FilterCondition::And(operands) => operands
+            .iter()
+            .map(|c| condition_selectivity(c, indices))
+            .product(),
+        FilterCondition::Not(inner) => 1.0 - condition_selectivity(inner, indices),
+    }
+}
+
+/// Index able to serve a condition, if any.
+fn usable_index<'a>(
+    condition: &FilterCondition,
+    indices: &[&'a IndexInfo],
+) -> Option<&'a IndexInfo> {
+    let (column, range) = match condition {
+        FilterCondition::Equals { column, .. } | FilterCondition::InList { column, .. } => {
+            (column, false)
+        }
+        FilterCondition::LessThan { column, .. }
+        | FilterCondition::GreaterThan { column, .. }
+        | FilterCondition::LessThanOrEqual { column, .. }
+        | FilterCondition::GreaterThanOrEqual { column, .. }
+        | FilterCondition::Between { column, .. } => (column, true),
+        FilterCondition::Like { column, pattern } if !pattern.starts_with('%') => (column, true),
+        _ => return None,
+    };
+
+    indices
+        .iter()
+        .copied()
+        .find(|i| i.column == *column && (!range || i.backend == IndexBackend::BTree))
+}
+
+/// Gathers planner statistics for a table and stores them.
+///
+/// Scans the table once:
+/// - `row_count` and `avg_row_size` (delimiters and line break included)
+/// - per column: distinct non-empty values (HyperLogLog estimate) and the
+///   fraction of empty values
+/// - `page_cost`: rows per `PAGE_SIZE` bytes, i.e. a random page read costs
+///   as much as reading that many rows in order (at least 1)
+/// - `index_selectivity`: mean of `1 / distinct keys` over the table's
+///   loaded indices (`DEFAULT_INDEX_SELECTIVITY` without indices)
+///
+/// ## Input
+/// - `db`: Database reference
+/// - `table_name`: Table to analyse
+///
+/// ## Output
+/// - `Ok(Statistics)`: Statistics written to `tables/{name}/.stats`
+///
+/// ## Performance
+/// - O(n × c) for n rows and c columns, one streamed read of current.csv;
+///   4 KiB of sketch memory per column
+///
+/// ## Error Conditions
+/// - `TableNotFound`: Table doesn't exist
+/// - `IoError`: Cannot read the table or write the statistics file
+/// - `InvalidCsv`: Table has no header row
+///
+/// ## Example Usage
+/// ```no_run
+/// use reedbase_last::database::stats::analyze_table;
+/// use reedbase_last::database::Database;
+///
+/// let db = Database::open(".reed")?;
+/// let stats = analyze_table(&db, "text")?;
+/// println!("{} rows, page cost {:.1}", stats.row_count, stats.page_cost);
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn analyze_table(db: &Database, table_name: &str) -> ReedResult<Statistics> {
+    let rows = db.get_table(table_name)?.stream_rows()?;
+    let columns: Vec<String> = rows.columns().to_vec();
+
+    let mut sketches = vec![HyperLogLog::new(); columns.len()];
+    let mut empty = vec![0usize; columns.len()];
+    let mut row_count = 0;
+    let mut total_bytes = 0;
+    for row in rows {
+        let row = row?;
+        row_count += 1;
+
+        let values: Vec<&str> = std::iter::once(row.key.as_str())
+            .chain(row.values.iter().map(String::as_str))
+            .collect();
+        // Fields plus delimiters and line break
+        total_bytes += values.iter().map(|v| v.len()).sum::<usize>() + values.len();
+
+        for (i, sketch) in sketches.iter_mut().enumerate() {
+            match values.get(i).filter(|v| !v.is_empty()) {
+                Some(value) => sketch.insert(value),
+                None => empty[i] += 1,
+            }
+        }
+    }
+
+    let column_stats = columns
+        .into_iter()
+        .zip(sketches.iter().zip(empty))
+        .map(|(column, (sketch, empty))| {
+            let stats = ColumnStatistics {
+                cardinality: sketch.estimate(),
+                null_fraction: if row_count == 0 {
+                    0.0
+                } else {
+                    empty as f64 / row_count as f64
+                },
+            };
+            (column, stats)
+        })
+        .collect();
+
+    let prefix = format!("{}.", table_name);
+    let selectivities: Vec<f64> = {
+        let indices = db.indices().read().unwrap();
+        indices
+            .iter()
+            .filter(|(key, _)| key.starts_with(&prefix))
+            .filter_map(|(_, index)| index.key_count().ok())
+            .map(|keys| 1.0 / keys.max(1) as f64)
+            .collect()
+    };
+    let index_selectivity = if selectivities.is_empty() {
+        DEFAULT_INDEX_SELECTIVITY
+    } else {
+        selectivities.iter().sum::<f64>() / selectivities.len() as f64
+    };
+
+    let avg_row_size = if row_count == 0 {
+        0.0
+    } else {
+        total_bytes as f64 / row_count as f64
+    };
+    let page_cost = (PAGE_SIZE as f64 / avg_row_size.max(1.0)).max(1.0);
+
+    let stats = Statistics {
+        row_count,
+        index_selectivity,
+        page_cost,
+        avg_row_size,
+        columns: column_stats,
+    };
+    save_table_statistics(db.base_path(), table_name, &stats)?;
+
+    Ok(stats)
+}
+
+/// Writes planner statistics to `tables/{name}/.stats` (TOML).
+///
+/// ## Error Conditions
+/// - `SerializationError`: Statistics cannot be encoded
+/// - `IoError`: Cannot write the file
+pub fn save_table_statistics(
+    base_path: &Path,
+    table_name: &str,
+    stats: &Statistics,
+) -> ReedResult<()> {
+    let path = statistics_path(base_path, table_name);
+    let content = toml::to_string(stats).map_err(|e| ReedError::SerializationError {
+        reason: format!("TOML serialization error: {}", e),
+    })?;
+
+    std::fs::write(&path, content).map_err(|e| ReedError::IoError {
+        operation: format!("write statistics file '{}'", path.display()),
+        reason: e.to_string(),
+    })
+}
+
+/// Reads planner statistics stored by `ANALYZE TABLE`.
+///
+/// ## Output
+/// - `Ok(Some(Statistics))`: Statistics of the last analysis
+/// - `Ok(None)`: Table has not been analysed
+///
+/// ## Error Conditions
+/// - `IoError`: Cannot read the file
+/// - `DeserializationError`: File is not valid statistics TOML
+pub fn load_table_statistics(base_path: &Path, table_name: &str) -> ReedResult<Option<Statistics>> {
+    let path = statistics_path(base_path, table_name);
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content = std::fs::read_to_string(&path).map_err(|e| ReedError::IoError {
+        operation: format!("read statistics file '{}'", path.display()),
+        reason: e.to_string(),
+    })?;
+    toml::from_str(&content)
+        .map(Some)
+        .map_err(|e| ReedError::DeserializationError {
+            reason: format!("TOML parse error: {}", e),
+        })
+}
+
+fn statistics_path(base_path: &Path, table_name: &str) -> PathBuf {
+    base_path.join("tables").join(table_name).join(".stats")
+}
+
+fn log2_or_zero(n: f64) -> f64 {
+    if n > 1.0 {
+        n.log2()
+    } else {
+        0.0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -221,4 +634,132 @@ mod tests {
         assert_eq!(tracker.patterns.len(), 0);
         assert_eq!(tracker.indexed_patterns.len(), 0);
     }
+
+    fn stats(row_count: usize) -> TableStats {
+        TableStats {
+            name: "text".to_string(),
+            current_size: 0,
+            row_count,
+            deltas_size: 0,
+            version_index_size: 0,
+            version_count: 1,
+            latest_version: 0,
+            oldest_version: 0,
+            delimiter: Default::default(),
+        }
+    }
+
+    fn index(column: &str, backend: IndexBackend) -> IndexInfo {
+        IndexInfo::new(
+            "text".to_string(),
+            column.to_string(),
+            backend.name().to_string(),
+            backend,
+        )
+    }
+
+    fn cost(sql: &str, indices: &[IndexInfo]) -> f64 {
+        let query = crate::reedql::parse(sql).unwrap();
+        estimate_query_cost(&stats(10_000), &query, indices)
+    }
+
+    #[test]
+    fn test_estimate_full_scan() {
+        let full = cost("SELECT * FROM text WHERE namespace = 'page'", &[]);
+        assert_eq!(full, 10_000.0 * ROW_SCAN_NS);
+    }
+
+    #[test]
+    fn test_estimate_index_lookup_is_cheaper() {
+        let indices = [index("namespace", IndexBackend::Hash)];
+        let indexed = cost("SELECT * FROM text WHERE namespace = 'page'", &indices);
+
+        let expected =
+            10_000f64.log2() * PAGE_READ_NS + DEFAULT_EQUALS_SELECTIVITY * 10_000.0 * ROW_FETCH_NS;
+        assert!((indexed - expected).abs() < 1e-6);
+        assert!(indexed < 10_000.0 * ROW_SCAN_NS);
+
+        // Known cardinality sharpens the estimate
+        let mut counted = index("namespace", IndexBackend::Hash);
+        counted.entry_count = 1_000;
+        assert!(cost("SELECT * FROM text WHERE namespace = 'page'", &[counted]) < indexed);
+    }
+
+    #[test]
+    fn test_estimate_range_needs_btree() {
+        let sql = "SELECT * FROM text WHERE key > 'page'";
+        let full = 10_000.0 * ROW_SCAN_NS;
+
+        assert_eq!(cost(sql, &[index("key", IndexBackend::Hash)]), full);
+        assert!(cost(sql, &[index("key", IndexBackend::BTree)]) < full);
+
+        // Indices on other tables are ignored
+        let mut other = index("key", IndexBackend::BTree);
+        other.table = "routes".to_string();
+        assert_eq!(cost(sql, &[other]), full);
+    }
+
+    #[test]
+    fn test_estimate_partial_index() {
+        let mut partial = index("key", IndexBackend::BTree);
+        partial.predicate = Some(crate::reedql::parse_condition("namespace = 'page'").unwrap());
+        let indices = [partial];
+        let full = 10_000.0 * ROW_SCAN_NS;
+
+        // Usable only when the query implies the predicate
+        assert_eq!(cost("SELECT * FROM text WHERE key > 'a'", &indices), full);
+        assert!(
+            cost(
+                "SELECT * FROM text WHERE key > 'a' AND namespace = 'page'",
+                &indices
+            ) < full
+        );
+    }
+
+    #[test]
+    fn test_estimate_sort_cost() {
+        let unsorted = cost("SELECT * FROM text", &[]);
+        let sorted = cost("SELECT * FROM text ORDER BY key", &[]);
+        let expected_sort = 10_000.0 * 10_000f64.log2() * COMPARE_NS;
+        assert!((sorted - unsorted - expected_sort).abs() < 1e-6);
+
+        // Empty table costs nothing
+        let query = crate::reedql::parse("SELECT * FROM text ORDER BY key").unwrap();
+        assert_eq!(estimate_query_cost(&stats(0), &query, &[]), 0.0);
+    }
+
+    #[test]
+    fn test_lint_cardinality_from_sample_and_statistics() {
+        let temp_dir = std::env::temp_dir().join("reedbase_stats_lint_test");
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        crate::registry::set_base_path(temp_dir.clone());
+        crate::registry::init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+
+        let db = Database::open(&temp_dir).unwrap();
+        let mut content = String::from("key|email|country\n");
+        for i in 0..1500 {
+            content.push_str(&format!("u{}|user{}@example.com|{}\n", i, i, i % 3));
+        }
+        crate::tables::Table::new(&temp_dir, "users")
+            .init(content.as_bytes(), "testuser")
+            .unwrap();
+        let warns = |sql: &str| {
+            db.lint(sql)
+                .iter()
+                .any(|w| w.message.contains("high-cardinality"))
+        };
+
+        // Without ANALYZE statistics, judged from a sample
+        assert!(warns("SELECT key FROM users WHERE email = 'a'"));
+        assert!(!warns("SELECT key FROM users WHERE country = '1'"));
+
+        // ANALYZE statistics take precedence over the sample
+        let mut stats = analyze_table(&db, "users").unwrap();
+        stats.columns.get_mut("email").unwrap().cardinality = 3;
+        save_table_statistics(&temp_dir, "users", &stats).unwrap();
+        assert!(!warns("SELECT key FROM users WHERE email = 'a'"));
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
 }
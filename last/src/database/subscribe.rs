@@ -0,0 +1,260 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Row change subscriptions (feature `tokio`).
+//!
+//! `Database::subscribe()` starts a watcher thread that polls the table's
+//! version.log. When the log grows, it reads the current version, diffs it
+//! against the rows it saw last (`merge::calculate_diff`) and sends each
+//! `RowChange` through a bounded tokio channel.
+//!
+//! Writes landing between two polls are reported as one combined diff.
+//! A full channel blocks the watcher until the subscriber catches up, so
+//! memory stays bounded by `SUBSCRIPTION_BUFFER` changes per subscriber.
+//!
+//! ## Example Usage
+//! ```no_run
+//! use reedbase_last::database::Database;
+//! use reedbase_last::merge::RowChange;
+//!
+//! let db = Database::open(".reed")?;
+//! for change in db.subscribe("text")? {
+//!     match change {
+//!         RowChange::Insert(row) => println!("+ {}", row.key),
+//!         RowChange::Update(row) => println!("~ {}", row.key),
+//!         RowChange::Delete(key) => println!("- {}", key),
+//!     }
+//! }
+//! # Ok::<(), reedbase::ReedError>(())
+//! ```
+
+use crate::concurrent::types::CsvRow;
+use crate::database::diff::read_rows;
+use crate::database::Database;
+use crate::error::{ReedError, ReedResult};
+use crate::merge::{calculate_diff, RowChange};
+use crate::tables::Table;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc::{self, Receiver, Sender};
+
+/// Maximum concurrent subscriptions per database handle (and its clones).
+pub const MAX_SUBSCRIBERS: usize = 64;
+
+/// Changes buffered per subscription before the watcher waits.
+pub const SUBSCRIPTION_BUFFER: usize = 1024;
+
+/// Interval between version.log checks.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Stream of row changes for one table.
+///
+/// Implements `Iterator` (blocking) and, with feature `futures`,
+/// `futures_core::Stream`. Ends when the table can no longer be read.
+/// Dropping it frees the subscriber slot and stops the watcher.
+#[derive(Debug)]
+pub struct ChangeStream {
+    receiver: Receiver<RowChange>,
+    subscribers: Arc<AtomicUsize>,
+}
+
+impl Iterator for ChangeStream {
+    type Item = RowChange;
+
+    /// Waits for the next change.
+    ///
+    /// Must not be called from within an async runtime; use the `Stream`
+    /// implementation there.
+    fn next(&mut self) -> Option<RowChange> {
+        self.receiver.blocking_recv()
+    }
+}
+
+#[cfg(feature = "futures")]
+impl futures_core::Stream for ChangeStream {
+    type Item = RowChange;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<RowChange>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+impl Drop for ChangeStream {
+    fn drop(&mut self) {
+        self.subscribers.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl Database {
+    /// Subscribes to row changes of a table.
+    ///
+    /// ## Input
+    /// - `table`: Table name
+    ///
+    /// ## Output
+    /// - `Ok(ChangeStream)`: Changes made after this call, in diff order
+    ///
+    /// ## Performance
+    /// - One `stat()` on version.log per 50ms while idle
+    /// - O(n) read and diff per detected write, n = rows in table
+    /// - Keeps one copy of the table's rows in memory
+    ///
+    /// ## Error Conditions
+    /// - `TableNotFound`: Table doesn't exist
+    /// - `QueueFull`: `MAX_SUBSCRIBERS` subscriptions are already open
+    /// - `IoError`: Watcher thread could not be started
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::database::Database;
+    ///
+    /// let db = Database::open(".reed")?;
+    /// let changes = db.subscribe("text")?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn subscribe(&self, table: &str) -> ReedResult<ChangeStream> {
+        let table = self.get_table(table)?;
+
+        let subscribers = Arc::clone(self.subscribers());
+        if subscribers.fetch_add(1, Ordering::SeqCst) >= MAX_SUBSCRIBERS {
+            subscribers.fetch_sub(1, Ordering::SeqCst);
+            return Err(ReedError::QueueFull {
+                table: table.name().to_string(),
+                size: MAX_SUBSCRIBERS,
+            });
+        }
+        // Releases the slot again if anything below fails
+        let (sender, receiver) = mpsc::channel(SUBSCRIPTION_BUFFER);
+        let stream = ChangeStream {
+            receiver,
+            subscribers,
+        };
+
+        let rows = read_rows(&table)?;
+        let log_len = log_len(&table);
+        std::thread::Builder::new()
+            .name(format!("reedbase-subscribe-{}", table.name()))
+            .spawn(move || watch(table, rows, log_len, sender))
+            .map_err(|e| ReedError::IoError {
+                operation: "spawn_subscriber".to_string(),
+                reason: e.to_string(),
+            })?;
+
+        Ok(stream)
+    }
+}
+
+/// Watcher loop; returns when the subscriber is gone or a read fails.
+fn watch(table: Table, mut rows: Vec<CsvRow>, mut seen_len: u64, sender: Sender<RowChange>) {
+    while !sender.is_closed() {
+        std::thread::sleep(POLL_INTERVAL);
+
+        let len = log_len(&table);
+        if len == seen_len {
+            continue;
+        }
+        seen_len = len;
+
+        let Ok(current) = read_rows(&table) else {
+            return;
+        };
+        let Ok(changes) = calculate_diff(&rows, &current) else {
+            return;
+        };
+        for change in changes {
+            if sender.blocking_send(change).is_err() {
+                return;
+            }
+        }
+        rows = current;
+    }
+}
+
+/// Size of the table's version.log (0 if missing).
+fn log_len(table: &Table) -> u64 {
+    std::fs::metadata(table.log_path())
+        .map(|m| m.len())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::init_registry;
+    use std::fs;
+    use std::time::Instant;
+
+    fn setup_test(name: &str) -> std::path::PathBuf {
+        let temp_dir = std::env::temp_dir().join(format!("reedbase_subscribe_test_{}", name));
+        let _ = fs::remove_dir_all(&temp_dir);
+        crate::registry::set_base_path(temp_dir.clone());
+        init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+        Table::new(&temp_dir, "text")
+            .init(b"key|value\npage.a|A\npage.b|B\n", "testuser")
+            .unwrap();
+        temp_dir
+    }
+
+    /// Collects `n` changes, failing after a few seconds.
+    fn collect(stream: &mut ChangeStream, n: usize) -> Vec<RowChange> {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let mut changes = Vec::new();
+        while changes.len() < n {
+            match stream.receiver.try_recv() {
+                Ok(change) => changes.push(change),
+                Err(_) if Instant::now() < deadline => std::thread::sleep(POLL_INTERVAL),
+                Err(e) => panic!("expected {} changes, got {:?} ({})", n, changes, e),
+            }
+        }
+        changes
+    }
+
+    #[test]
+    fn test_subscribe_reports_row_changes() {
+        let temp_dir = setup_test("changes");
+        let db = Database::open(&temp_dir).unwrap();
+        let mut stream = db.subscribe("text").unwrap();
+
+        Table::new(&temp_dir, "text")
+            .write(b"key|value\npage.a|A2\npage.c|C\n", "testuser")
+            .unwrap();
+
+        let mut changes = collect(&mut stream, 3);
+        changes.sort_by_key(|c| format!("{:?}", c));
+        assert!(matches!(&changes[0], RowChange::Delete(key) if key == "page.b"));
+        assert!(matches!(&changes[1], RowChange::Insert(row) if row.key == "page.c"));
+        assert!(matches!(&changes[2], RowChange::Update(row) if row.key == "page.a"));
+
+        assert!(matches!(
+            db.subscribe("missing"),
+            Err(ReedError::TableNotFound { .. })
+        ));
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_subscriber_limit() {
+        let temp_dir = setup_test("limit");
+        let db = Database::open(&temp_dir).unwrap();
+
+        let mut streams: Vec<ChangeStream> = (0..MAX_SUBSCRIBERS)
+            .map(|_| db.subscribe("text").unwrap())
+            .collect();
+        assert!(matches!(
+            db.clone().subscribe("text"),
+            Err(ReedError::QueueFull { .. })
+        ));
+
+        // Dropping a stream frees its slot
+        streams.pop();
+        assert!(db.subscribe("text").is_ok());
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+}
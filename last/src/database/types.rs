@@ -5,9 +5,12 @@
 //!
 //! Defines types used throughout the Database API.
 
+use crate::concurrent::types::CsvRow;
 use crate::error::{ReedError, ReedResult};
+use crate::reedql::types::FilterCondition;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::time::Duration;
 
 /// Auto-indexing configuration.
@@ -146,6 +149,14 @@ pub struct IndexMetadata {
 
     /// Last usage timestamp (Unix seconds)
     pub last_used: u64,
+
+    /// Row filter of a partial index (`None` = all rows indexed)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub predicate: Option<FilterCondition>,
+
+    /// Name given in `CREATE INDEX name ON ...` (`None` = default name)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
 }
 
 impl IndexMetadata {
@@ -165,6 +176,8 @@ impl IndexMetadata {
             auto_created: false,
             usage_count: 0,
             last_used: now,
+            predicate: None,
+            name: None,
         }
     }
 
@@ -186,6 +199,9 @@ impl IndexMetadata {
 /// Information about a database index.
 #[derive(Debug, Clone)]
 pub struct IndexInfo {
+    /// Index name, as used by `DROP INDEX name`
+    pub name: String,
+
     /// Table name
     pub table: String,
 
@@ -212,12 +228,16 @@ pub struct IndexInfo {
 
     /// Whether index was auto-created
     pub auto_created: bool,
+
+    /// Row filter of a partial index (`None` = all rows indexed)
+    pub predicate: Option<FilterCondition>,
 }
 
 impl IndexInfo {
     /// Creates new index information.
     pub fn new(table: String, column: String, index_type: String, backend: IndexBackend) -> Self {
         Self {
+            name: Self::default_name(&table, &column),
             table,
             column,
             index_type,
@@ -227,9 +247,22 @@ impl IndexInfo {
             disk_bytes: 0,
             usage_count: 0,
             auto_created: false,
+            predicate: None,
         }
     }
 
+    /// Returns the name of an index created without one (`{table}_{columns}_idx`).
+    ///
+    /// ## Example
+    /// ```rust
+    /// use reedbase_last::database::IndexInfo;
+    ///
+    /// assert_eq!(IndexInfo::default_name("users", "country,city"), "users_country_city_idx");
+    /// ```
+    pub fn default_name(table: &str, column: &str) -> String {
+        format!("{}_{}_idx", table, column.replace(',', "_"))
+    }
+
     /// Returns total storage usage (memory + disk).
     pub fn total_bytes(&self) -> usize {
         self.memory_bytes + self.disk_bytes
@@ -265,6 +298,9 @@ pub struct QueryMetrics {
 
     /// Whether query used fast path
     pub used_fast_path: bool,
+
+    /// Estimated cost in nanoseconds (see `stats::estimate_query_cost`)
+    pub estimated_cost_ns: f64,
 }
 
 impl QueryMetrics {
@@ -277,6 +313,7 @@ impl QueryMetrics {
             rows_returned: 0,
             index_used: None,
             used_fast_path: false,
+            estimated_cost_ns: 0.0,
         }
     }
 
@@ -382,6 +419,143 @@ impl Default for DatabaseStats {
     }
 }
 
+/// Result of a vacuum run on a single table.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VacuumReport {
+    /// Size of current.csv before vacuum (bytes)
+    pub bytes_before: u64,
+
+    /// Size of current.csv after vacuum (bytes)
+    pub bytes_after: u64,
+
+    /// Number of lines kept (header + data rows)
+    pub rows_preserved: usize,
+}
+
+impl VacuumReport {
+    /// Returns number of bytes reclaimed (0 if the file grew).
+    pub fn bytes_reclaimed(&self) -> u64 {
+        self.bytes_before.saturating_sub(self.bytes_after)
+    }
+}
+
+/// Result of a CSV import (`Database::import_csv()`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportReport {
+    /// Rows appended to the table
+    pub rows_imported: usize,
+
+    /// Rows left out: empty or duplicate key, or schema violation
+    pub rows_skipped: usize,
+
+    /// Records that could not be parsed or stored ("line N: reason")
+    pub parsing_errors: Vec<String>,
+}
+
+/// Result of a CSV export (`Database::export_csv()`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportReport {
+    /// Rows written, excluding the header
+    pub rows_exported: usize,
+
+    /// File written
+    pub path: PathBuf,
+
+    /// Time taken in milliseconds
+    pub duration_ms: u64,
+}
+
+/// Result of a database maintenance run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MaintenanceReport {
+    /// Metric files rotated because they exceeded the size limit
+    pub metric_files_rotated: usize,
+
+    /// Stale metric files compressed
+    pub metric_files_compressed: usize,
+}
+
+/// A value that references a row missing from the referenced table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntegrityViolation {
+    /// Referencing table
+    pub table: String,
+
+    /// Referencing (foreign key) column
+    pub column: String,
+
+    /// Line number in the referencing table's current.csv (header = 1)
+    pub row: usize,
+
+    /// Value without a matching row
+    pub value: String,
+
+    /// Referenced table
+    pub referenced_table: String,
+
+    /// Referenced column
+    pub referenced_column: String,
+}
+
+/// Result of a referential integrity check (see `Database::check_referential_integrity`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntegrityReport {
+    /// Orphaned values, grouped by table and column in schema order
+    pub violations: Vec<IntegrityViolation>,
+}
+
+impl IntegrityReport {
+    /// Returns true if no violations were found.
+    pub fn is_clean(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+/// Row-level differences of one table between two databases.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableDiff {
+    /// Table name
+    pub table_name: String,
+
+    /// Rows present only in the other database
+    pub added: Vec<CsvRow>,
+
+    /// Rows whose values differ (values from the other database)
+    pub modified: Vec<CsvRow>,
+
+    /// Keys present only in this database
+    pub deleted: Vec<String>,
+}
+
+impl TableDiff {
+    /// Returns true if the table is identical in both databases.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.modified.is_empty() && self.deleted.is_empty()
+    }
+}
+
+/// Result of comparing two databases (see `Database::diff`).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DatabaseDiff {
+    /// Tables that exist only in this database
+    pub tables_only_in_self: Vec<String>,
+
+    /// Tables that exist only in the other database
+    pub tables_only_in_other: Vec<String>,
+
+    /// Tables present in both with differing rows
+    pub tables_changed: Vec<TableDiff>,
+}
+
+impl DatabaseDiff {
+    /// Returns true if both databases hold the same tables and rows.
+    pub fn is_empty(&self) -> bool {
+        self.tables_only_in_self.is_empty()
+            && self.tables_only_in_other.is_empty()
+            && self.tables_changed.is_empty()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
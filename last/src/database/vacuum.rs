@@ -0,0 +1,174 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Table vacuum (canonical rewrite of current.csv).
+//!
+//! Hand edits and interrupted writes can leave blank lines, CRLF endings or a
+//! missing final newline in `current.csv`. Vacuum rewrites the file in
+//! canonical form (one `\n` after every row, no blank lines) and records the
+//! result as a new version so the change stays reversible via rollback.
+
+use crate::database::types::VacuumReport;
+use crate::database::Database;
+use crate::error::ReedResult;
+use crate::registry::get_action_code;
+
+/// Action code of `vacuum` in new actions.dict files. Older dictionaries
+/// may hold it under another code, so writes resolve it by name.
+pub const VACUUM_ACTION_CODE: u8 = 10;
+
+/// Vacuums a table.
+///
+/// ## Input
+/// - `db`: Database reference
+/// - `table_name`: Table to vacuum
+///
+/// ## Output
+/// - `Ok(VacuumReport)`: Sizes before/after and number of rows kept
+///
+/// ## Performance
+/// - O(n) where n = file size
+/// - < 10ms for typical tables (< 100 KB), plus one versioned write
+///
+/// ## Error Conditions
+/// - `TableNotFound`: Table doesn't exist
+/// - `IoError`: Cannot read or write table files
+pub fn vacuum_table(db: &Database, table_name: &str) -> ReedResult<VacuumReport> {
+    let table = db.get_table(table_name)?;
+
+    // Canonicalise under the table lock, so no write lands in between
+    let mut report = VacuumReport::default();
+    table.read_modify_write_with_action(
+        |content| {
+            let (canonical, rows_preserved) = canonicalise_csv(content);
+            report = VacuumReport {
+                bytes_before: content.len() as u64,
+                bytes_after: canonical.len() as u64,
+                rows_preserved,
+            };
+            canonical
+        },
+        "system",
+        get_action_code("vacuum")?,
+    )?;
+
+    Ok(report)
+}
+
+/// Rewrites CSV content in canonical form.
+///
+/// Drops blank (whitespace-only) lines, strips `\r` line endings and
+/// terminates every remaining line with a single `\n`. Row content is kept
+/// byte-for-byte otherwise.
+///
+/// ## Input
+/// - `content`: Raw CSV bytes
+///
+/// ## Output
+/// - `(Vec<u8>, usize)`: Canonical bytes and number of data rows kept
+///   (header excluded)
+///
+/// ## Performance
+/// - O(n) where n = content length
+///
+/// ## Example Usage
+/// ```rust
+/// use reedbase_last::database::vacuum::canonicalise_csv;
+///
+/// let (out, rows) = canonicalise_csv(b"key|value\r\n\r\na|1\n\nb|2");
+/// assert_eq!(out, b"key|value\na|1\nb|2\n");
+/// assert_eq!(rows, 2);
+/// ```
+pub fn canonicalise_csv(content: &[u8]) -> (Vec<u8>, usize) {
+    let mut output = Vec::with_capacity(content.len());
+    let mut lines_kept: usize = 0;
+
+    for line in content.split(|&b| b == b'\n') {
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+
+        if line.iter().all(|b| b.is_ascii_whitespace()) {
+            continue;
+        }
+
+        output.extend_from_slice(line);
+        output.push(b'\n');
+        lines_kept += 1;
+    }
+
+    (output, lines_kept.saturating_sub(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::init_registry;
+    use std::fs;
+
+    #[test]
+    fn test_canonicalise_csv_removes_blank_lines() {
+        let (out, rows) = canonicalise_csv(b"key|value\n\na|1\n   \nb|2\n\n");
+        assert_eq!(out, b"key|value\na|1\nb|2\n");
+        assert_eq!(rows, 2);
+    }
+
+    #[test]
+    fn test_canonicalise_csv_normalises_line_endings() {
+        let (out, rows) = canonicalise_csv(b"key|value\r\na|1\r\nb|2");
+        assert_eq!(out, b"key|value\na|1\nb|2\n");
+        assert_eq!(rows, 2);
+    }
+
+    #[test]
+    fn test_canonicalise_csv_is_idempotent() {
+        let (once, _) = canonicalise_csv(b"key|value\n\na|1\r\n");
+        let (twice, _) = canonicalise_csv(&once);
+        assert_eq!(once, twice);
+    }
+
+    #[test]
+    fn test_canonicalise_csv_empty() {
+        let (out, rows) = canonicalise_csv(b"\n\n");
+        assert!(out.is_empty());
+        assert_eq!(rows, 0);
+    }
+
+    #[test]
+    fn test_vacuum_table() {
+        let temp_dir = std::env::temp_dir().join("reedbase_vacuum_test");
+        let _ = fs::remove_dir_all(&temp_dir);
+        crate::registry::set_base_path(temp_dir.clone());
+        init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+
+        let db = Database::open(&temp_dir).unwrap();
+        let table = crate::tables::Table::new(&temp_dir, "text");
+        table
+            .init(b"key|value\r\n\r\na|1\n\n\nb|2", "testuser")
+            .unwrap();
+
+        let report = db.vacuum("text").unwrap();
+        assert_eq!(report.bytes_before, 22);
+        assert_eq!(report.bytes_after, 18);
+        assert_eq!(report.bytes_reclaimed(), 4);
+        assert_eq!(report.rows_preserved, 2);
+
+        assert_eq!(table.read_current().unwrap(), b"key|value\na|1\nb|2\n");
+
+        let versions = table.list_versions().unwrap();
+        assert_eq!(versions.len(), 2);
+        assert_eq!(versions[0].action, "vacuum");
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_vacuum_table_not_found() {
+        let temp_dir = std::env::temp_dir().join("reedbase_vacuum_test_missing");
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        let db = Database::open(&temp_dir).unwrap();
+        assert!(db.vacuum("missing").is_err());
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+}
@@ -51,6 +51,12 @@ pub enum ReedError {
     /// Table already exists.
     TableAlreadyExists { name: String },
 
+    /// Column not present in table header.
+    ColumnNotFound { table: String, column: String },
+
+    /// Column already present in table header or schema.
+    ColumnAlreadyExists { table: String, column: String },
+
     /// Version not found.
     VersionNotFound { timestamp: u64 },
 
@@ -160,6 +166,18 @@ pub enum ReedError {
         index_type: String, // "timestamp" | "frame"
         path: std::path::PathBuf,
     },
+
+    /// No stored samples of a metric within a time window.
+    MetricNotFound { name: String, start: u64, end: u64 },
+
+    /// Migrated rows violate the new schema (one entry per offending row).
+    MigrationFailed { table: String, rows: Vec<String> },
+
+    /// Statement of an `execute_batch()` call failed (0-based index).
+    BatchFailed {
+        statement: usize,
+        error: Box<ReedError>,
+    },
 }
 
 impl fmt::Display for ReedError {
@@ -207,6 +225,12 @@ impl fmt::Display for ReedError {
             Self::TableAlreadyExists { name } => {
                 write!(f, "Table '{}' already exists", name)
             }
+            Self::ColumnNotFound { table, column } => {
+                write!(f, "Column '{}' not found in table '{}'", column, table)
+            }
+            Self::ColumnAlreadyExists { table, column } => {
+                write!(f, "Column '{}' already exists in table '{}'", column, table)
+            }
             Self::VersionNotFound { timestamp } => {
                 write!(f, "Version {} not found", timestamp)
             }
@@ -340,6 +364,25 @@ impl fmt::Display for ReedError {
                     path.display()
                 )
             }
+            Self::MetricNotFound { name, start, end } => {
+                write!(
+                    f,
+                    "No samples of metric '{}' between {} and {}",
+                    name, start, end
+                )
+            }
+            Self::MigrationFailed { table, rows } => {
+                write!(
+                    f,
+                    "Migration of table '{}' failed for {} row(s): {}",
+                    table,
+                    rows.len(),
+                    rows.join("; ")
+                )
+            }
+            Self::BatchFailed { statement, error } => {
+                write!(f, "Batch statement {} failed: {}", statement, error)
+            }
         }
     }
 }
@@ -3,7 +3,8 @@
 
 //! Aggregation functions for dataset-level operations.
 //!
-//! Provides count, sum, avg, min, max, and group_by operations with automatic caching.
+//! Provides count, sum, avg, min, max, group_by, percentile, histogram,
+//! correlation and covariance operations with automatic caching.
 //! First call scans the CSV file (O(n)), subsequent calls return cached results (<100ns).
 //!
 //! ## Performance
@@ -29,6 +30,14 @@
 //! // Min/Max
 //! let youngest = min("users", "age")?; // "18"
 //! let oldest = max("users", "age")?; // "89"
+//!
+//! // Distribution
+//! let p95 = percentile("requests", "latency_ms", 95.0)?; // "412.50"
+//! let buckets = histogram("requests", "latency_ms", &[0.0, 10.0, 50.0])?; // {"0-10":5,"10-50":23}
+//!
+//! // Relationship between two columns
+//! let r = correlation("users", "age", "income")?; // "0.8124"
+//! let cov = covariance("users", "age", "income")?; // "1523.40"
 //! ```
 
 use crate::error::{ReedError, ReedResult};
@@ -36,6 +45,7 @@ use crate::functions::cache::{get_cache, CacheKey};
 use crate::tables::{parse_csv, Table};
 use std::collections::HashMap;
 use std::path::Path;
+use std::time::UNIX_EPOCH;
 
 /// Get table path from base directory.
 ///
@@ -100,7 +110,7 @@ pub fn count(table: &str) -> ReedResult<String> {
         name: table.to_string(),
     })?;
 
-    let rows = parse_csv(&content)?;
+    let rows = parse_csv(&content, tbl.delimiter()?)?;
 
     // Exclude header row (first row)
     let count = if rows.is_empty() { 0 } else { rows.len() - 1 };
@@ -149,7 +159,7 @@ pub fn sum(table: &str, column: &str) -> ReedResult<String> {
         name: table.to_string(),
     })?;
 
-    let rows = parse_csv(&content)?;
+    let rows = parse_csv(&content, tbl.delimiter()?)?;
 
     if rows.is_empty() {
         return Ok("0.00".to_string());
@@ -214,7 +224,7 @@ pub fn avg(table: &str, column: &str) -> ReedResult<String> {
         name: table.to_string(),
     })?;
 
-    let rows = parse_csv(&content)?;
+    let rows = parse_csv(&content, tbl.delimiter()?)?;
 
     if rows.is_empty() {
         return Ok("0.00".to_string());
@@ -280,7 +290,7 @@ pub fn min(table: &str, column: &str) -> ReedResult<String> {
         name: table.to_string(),
     })?;
 
-    let rows = parse_csv(&content)?;
+    let rows = parse_csv(&content, tbl.delimiter()?)?;
 
     if rows.is_empty() {
         return Ok("0.00".to_string());
@@ -341,7 +351,7 @@ pub fn max(table: &str, column: &str) -> ReedResult<String> {
         name: table.to_string(),
     })?;
 
-    let rows = parse_csv(&content)?;
+    let rows = parse_csv(&content, tbl.delimiter()?)?;
 
     if rows.is_empty() {
         return Ok("0.00".to_string());
@@ -404,7 +414,7 @@ pub fn group_by(table: &str, column: &str) -> ReedResult<String> {
         name: table.to_string(),
     })?;
 
-    let rows = parse_csv(&content)?;
+    let rows = parse_csv(&content, tbl.delimiter()?)?;
 
     if rows.is_empty() {
         return Ok("{}".to_string());
@@ -430,3 +440,411 @@ pub fn group_by(table: &str, column: &str) -> ReedResult<String> {
 
     Ok(result)
 }
+
+/// Running co-moments of two variables (Welford's online algorithm).
+///
+/// Numerically stable single-pass accumulation of means, variances and
+/// covariance. Shared by `correlation`/`covariance` and ReedQL's
+/// `CORR`/`COVAR`.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct CoMoments {
+    /// Number of pairs seen.
+    n: usize,
+    mean_a: f64,
+    mean_b: f64,
+    /// Sum of squared deviations of a.
+    m2_a: f64,
+    /// Sum of squared deviations of b.
+    m2_b: f64,
+    /// Sum of co-deviations of a and b.
+    c_ab: f64,
+}
+
+impl CoMoments {
+    /// Adds one (a, b) pair.
+    pub(crate) fn push(&mut self, a: f64, b: f64) {
+        self.n += 1;
+        let n = self.n as f64;
+
+        let delta_a = a - self.mean_a;
+        let delta_b = b - self.mean_b;
+        self.mean_a += delta_a / n;
+        self.mean_b += delta_b / n;
+
+        self.m2_a += delta_a * (a - self.mean_a);
+        self.m2_b += delta_b * (b - self.mean_b);
+        self.c_ab += delta_a * (b - self.mean_b);
+    }
+
+    /// Sample covariance (n - 1 denominator), 0.0 for fewer than 2 pairs.
+    pub(crate) fn covariance(&self) -> f64 {
+        if self.n < 2 {
+            0.0
+        } else {
+            self.c_ab / (self.n - 1) as f64
+        }
+    }
+
+    /// Pearson correlation coefficient, 0.0 if either column is constant.
+    pub(crate) fn correlation(&self) -> f64 {
+        let denominator = (self.m2_a * self.m2_b).sqrt();
+        if self.n < 2 || denominator == 0.0 {
+            0.0
+        } else {
+            (self.c_ab / denominator).clamp(-1.0, 1.0)
+        }
+    }
+}
+
+/// HyperLogLog sketch estimating the number of distinct values.
+///
+/// 2^`HLL_PRECISION` one-byte registers (4 KiB), standard error about
+/// 1.04 / √4096 ≈ 1.6%. Small counts use linear counting, so they are
+/// close to exact. Used by `ANALYZE TABLE` for column cardinalities.
+#[derive(Debug, Clone)]
+pub(crate) struct HyperLogLog {
+    registers: Vec<u8>,
+}
+
+/// Bits of the hash selecting a register.
+const HLL_PRECISION: u32 = 12;
+
+impl HyperLogLog {
+    pub(crate) fn new() -> Self {
+        Self {
+            registers: vec![0; 1 << HLL_PRECISION],
+        }
+    }
+
+    /// Adds one value.
+    pub(crate) fn insert(&mut self, value: &str) {
+        use std::hash::{DefaultHasher, Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let index = (hash >> (64 - HLL_PRECISION)) as usize;
+        // Sentinel bit bounds the rank for an all-zero remainder
+        let remainder = (hash << HLL_PRECISION) | (1 << (HLL_PRECISION - 1));
+        let rank = remainder.leading_zeros() as u8 + 1;
+
+        if rank > self.registers[index] {
+            self.registers[index] = rank;
+        }
+    }
+
+    /// Estimated number of distinct values added.
+    pub(crate) fn estimate(&self) -> u64 {
+        let m = self.registers.len() as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let sum: f64 = self.registers.iter().map(|&r| 2f64.powi(-(r as i32))).sum();
+        let raw = alpha * m * m / sum;
+
+        let zeros = self.registers.iter().filter(|&&r| r == 0).count();
+        let estimate = if raw <= 2.5 * m && zeros > 0 {
+            // Linear counting for small cardinalities
+            m * (m / zeros as f64).ln()
+        } else {
+            raw
+        };
+
+        estimate.round() as u64
+    }
+}
+
+/// Scan two numeric columns into running co-moments.
+///
+/// Rows where either value is missing or non-numeric are skipped.
+fn scan_column_pair(tbl: &Table, table: &str, col_a: &str, col_b: &str) -> ReedResult<CoMoments> {
+    let content = tbl.read_current().map_err(|_| ReedError::TableNotFound {
+        name: table.to_string(),
+    })?;
+
+    let rows = parse_csv(&content, tbl.delimiter()?)?;
+    let mut moments = CoMoments::default();
+
+    if rows.is_empty() {
+        return Ok(moments);
+    }
+
+    let header = &rows[0].values;
+    let idx_a = get_column_index(header, col_a)?;
+    let idx_b = get_column_index(header, col_b)?;
+
+    for row in &rows[1..] {
+        let a = row.values.get(idx_a).and_then(|v| v.parse::<f64>().ok());
+        let b = row.values.get(idx_b).and_then(|v| v.parse::<f64>().ok());
+        if let (Some(a), Some(b)) = (a, b) {
+            moments.push(a, b);
+        }
+    }
+
+    Ok(moments)
+}
+
+/// Scan one numeric column, skipping missing and non-numeric values.
+fn scan_column(tbl: &Table, table: &str, column: &str) -> ReedResult<Vec<f64>> {
+    let content = tbl.read_current().map_err(|_| ReedError::TableNotFound {
+        name: table.to_string(),
+    })?;
+
+    let rows = parse_csv(&content, tbl.delimiter()?)?;
+    if rows.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let col_idx = get_column_index(&rows[0].values, column)?;
+    Ok(rows[1..]
+        .iter()
+        .filter_map(|row| row.values.get(col_idx)?.parse::<f64>().ok())
+        .filter(|value| !value.is_nan())
+        .collect())
+}
+
+/// Modification time of current.csv in nanoseconds (cache key component).
+fn table_mtime(tbl: &Table, table: &str) -> ReedResult<String> {
+    let modified = std::fs::metadata(tbl.current_path())
+        .and_then(|m| m.modified())
+        .map_err(|_| ReedError::TableNotFound {
+            name: table.to_string(),
+        })?;
+
+    Ok(modified
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0)
+        .to_string())
+}
+
+/// Calculate Pearson correlation between two numeric columns.
+///
+/// Single pass over the table using Welford-style running sums. The cached
+/// result is keyed on the table's modification time, so any write to the
+/// table invalidates it.
+///
+/// ## Input
+/// - `table` - Table name
+/// - `col_a` - First numeric column
+/// - `col_b` - Second numeric column
+///
+/// ## Output
+/// - Coefficient in [-1, 1] as string with 4 decimal places
+///
+/// ## Performance
+/// - First call: 5-10ms (10k rows)
+/// - Cached: < 1μs (one metadata lookup)
+///
+/// ## Error Conditions
+/// - Table not found → ReedError::TableNotFound
+/// - Column not found → ReedError::ParseError
+/// - Rows with a non-numeric value in either column are skipped
+/// - Fewer than 2 pairs or a constant column → Returns "0.0000"
+///
+/// ## Example Usage
+/// ```rust
+/// let r = correlation("users", "age", "income")?; // "0.8124"
+/// ```
+pub fn correlation(table: &str, col_a: &str, col_b: &str) -> ReedResult<String> {
+    let tbl = get_table(table)?;
+    let mtime = table_mtime(&tbl, table)?;
+    let key = CacheKey::new("correlation", vec![table, col_a, col_b, mtime.as_str()]);
+
+    if let Some(cached) = get_cache().get(&key) {
+        return Ok(cached);
+    }
+
+    let moments = scan_column_pair(&tbl, table, col_a, col_b)?;
+    let result = format!("{:.4}", moments.correlation());
+
+    get_cache().insert(key, result.clone());
+
+    Ok(result)
+}
+
+/// Calculate sample covariance between two numeric columns.
+///
+/// Uses the n - 1 denominator. Cached like `correlation()`.
+///
+/// ## Input
+/// - `table` - Table name
+/// - `col_a` - First numeric column
+/// - `col_b` - Second numeric column
+///
+/// ## Output
+/// - Covariance as string with 2 decimal places
+///
+/// ## Performance
+/// - First call: 5-10ms (10k rows)
+/// - Cached: < 1μs (one metadata lookup)
+///
+/// ## Error Conditions
+/// - Table not found → ReedError::TableNotFound
+/// - Column not found → ReedError::ParseError
+/// - Rows with a non-numeric value in either column are skipped
+/// - Fewer than 2 pairs → Returns "0.00"
+///
+/// ## Example Usage
+/// ```rust
+/// let cov = covariance("users", "age", "income")?; // "1523.40"
+/// ```
+pub fn covariance(table: &str, col_a: &str, col_b: &str) -> ReedResult<String> {
+    let tbl = get_table(table)?;
+    let mtime = table_mtime(&tbl, table)?;
+    let key = CacheKey::new("covariance", vec![table, col_a, col_b, mtime.as_str()]);
+
+    if let Some(cached) = get_cache().get(&key) {
+        return Ok(cached);
+    }
+
+    let moments = scan_column_pair(&tbl, table, col_a, col_b)?;
+    let result = format!("{:.2}", moments.covariance());
+
+    get_cache().insert(key, result.clone());
+
+    Ok(result)
+}
+
+/// Calculate a percentile of a numeric column.
+///
+/// Sorts the column's values and interpolates linearly between the two
+/// nearest ranks (see `metrics::aggregator::percentile`). Cached like
+/// `correlation()`, per percentile.
+///
+/// ## Input
+/// - `table` - Table name
+/// - `column` - Numeric column
+/// - `pct` - Percentile in [0, 100] (50 = median)
+///
+/// ## Output
+/// - Percentile value as string with 2 decimal places
+///
+/// ## Performance
+/// - First call: O(n log n), 5-15ms (10k rows)
+/// - Cached: < 1μs (one metadata lookup)
+///
+/// ## Error Conditions
+/// - Table not found → ReedError::TableNotFound
+/// - Column not found → ReedError::ParseError
+/// - `pct` outside [0, 100] → ReedError::ValidationError
+/// - Non-numeric values are skipped; none left → Returns "0.00"
+///
+/// ## Example Usage
+/// ```rust
+/// let p50 = percentile("requests", "latency_ms", 50.0)?; // "120.00"
+/// let p99 = percentile("requests", "latency_ms", 99.0)?; // "870.25"
+/// ```
+pub fn percentile(table: &str, column: &str, pct: f64) -> ReedResult<String> {
+    if !(0.0..=100.0).contains(&pct) {
+        return Err(ReedError::ValidationError {
+            column: column.to_string(),
+            reason: "Percentile must be between 0 and 100".to_string(),
+            value: Some(pct.to_string()),
+        });
+    }
+
+    let tbl = get_table(table)?;
+    let mtime = table_mtime(&tbl, table)?;
+    let pct_str = pct.to_string();
+    let key = CacheKey::new(
+        "percentile",
+        vec![table, column, pct_str.as_str(), mtime.as_str()],
+    );
+
+    if let Some(cached) = get_cache().get(&key) {
+        return Ok(cached);
+    }
+
+    let mut values = scan_column(&tbl, table, column)?;
+    values.sort_by(f64::total_cmp);
+    let result = format!(
+        "{:.2}",
+        crate::metrics::aggregator::percentile(&values, pct)
+    );
+
+    get_cache().insert(key, result.clone());
+
+    Ok(result)
+}
+
+/// Count the values of a numeric column per bucket.
+///
+/// `buckets` are ascending boundaries; each adjacent pair forms one bucket
+/// `[lower, upper)`, the last one also including its upper bound. Values
+/// outside all buckets are not counted. Cached like `correlation()`, per
+/// set of boundaries.
+///
+/// ## Input
+/// - `table` - Table name
+/// - `column` - Numeric column
+/// - `buckets` - At least two strictly ascending, finite boundaries
+///
+/// ## Output
+/// - JSON object as string, buckets in ascending order:
+///   `{"0-10": count, "10-50": count, ...}`
+///
+/// ## Performance
+/// - First call: O(n log b), 5-10ms (10k rows)
+/// - Cached: < 1μs (one metadata lookup)
+///
+/// ## Error Conditions
+/// - Table not found → ReedError::TableNotFound
+/// - Column not found → ReedError::ParseError
+/// - Fewer than two boundaries, or not ascending → ReedError::ValidationError
+///
+/// ## Example Usage
+/// ```rust
+/// let latency = histogram("requests", "latency_ms", &[0.0, 10.0, 50.0, 100.0])?;
+/// // Output: {"0-10":5,"10-50":23,"50-100":4}
+/// ```
+pub fn histogram(table: &str, column: &str, buckets: &[f64]) -> ReedResult<String> {
+    if buckets.len() < 2
+        || buckets.iter().any(|b| !b.is_finite())
+        || buckets.windows(2).any(|pair| pair[0] >= pair[1])
+    {
+        return Err(ReedError::ValidationError {
+            column: column.to_string(),
+            reason: "Histogram needs at least two ascending, finite bucket boundaries".to_string(),
+            value: Some(format!("{:?}", buckets)),
+        });
+    }
+
+    let tbl = get_table(table)?;
+    let mtime = table_mtime(&tbl, table)?;
+    let bounds: Vec<String> = buckets.iter().map(f64::to_string).collect();
+    let bounds = bounds.join(",");
+    let key = CacheKey::new(
+        "histogram",
+        vec![table, column, bounds.as_str(), mtime.as_str()],
+    );
+
+    if let Some(cached) = get_cache().get(&key) {
+        return Ok(cached);
+    }
+
+    let last = buckets.len() - 2;
+    let mut counts = vec![0usize; last + 1];
+    for value in scan_column(&tbl, table, column)? {
+        // Index of the first boundary above the value, minus one
+        let upper = buckets.partition_point(|&b| b <= value);
+        if upper == 0 {
+            continue;
+        }
+        if upper <= last + 1 {
+            counts[upper - 1] += 1;
+        } else if value == buckets[last + 1] {
+            counts[last] += 1;
+        }
+    }
+
+    let entries: Vec<String> = counts
+        .iter()
+        .enumerate()
+        .map(|(i, count)| format!("\"{}-{}\":{}", buckets[i], buckets[i + 1], count))
+        .collect();
+    let result = format!("{{{}}}", entries.join(","));
+
+    get_cache().insert(key, result.clone());
+
+    Ok(result)
+}
@@ -280,4 +280,134 @@ mod tests {
         let result = count("nonexistent_table_xyz");
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_correlation_known_dataset() {
+        get_cache().clear();
+
+        // x = 1..5, y = 2,4,5,4,5
+        // Sxy = 6, Sxx = 10, Syy = 6 → r = 6 / sqrt(60) = 0.7746, cov = 6 / 4 = 1.5
+        let table_name = "test_corr_known";
+        create_test_table(
+            table_name,
+            "key|x|y\np1|1|2\np2|2|4\np3|3|5\np4|4|4\np5|5|5\n",
+        );
+
+        assert_eq!(correlation(table_name, "x", "y").unwrap(), "0.7746");
+        assert_eq!(covariance(table_name, "x", "y").unwrap(), "1.50");
+
+        cleanup_test_table(table_name);
+    }
+
+    #[test]
+    fn test_correlation_perfect_and_constant() {
+        get_cache().clear();
+
+        // y = 10 - 2x → r = -1; cov = -2 * var(x) = -2 * 2.5 = -5
+        let table_name = "test_corr_perfect";
+        create_test_table(
+            table_name,
+            "key|x|y|c\np1|1|8|7\np2|2|6|7\np3|3|4|7\np4|4|2|7\np5|5|0|7\np6|n/a|1|7\n",
+        );
+
+        assert_eq!(correlation(table_name, "x", "y").unwrap(), "-1.0000");
+        assert_eq!(covariance(table_name, "x", "y").unwrap(), "-5.00");
+        assert_eq!(correlation(table_name, "x", "c").unwrap(), "0.0000");
+        assert!(correlation(table_name, "x", "missing").is_err());
+
+        cleanup_test_table(table_name);
+    }
+
+    #[test]
+    fn test_hyperloglog_estimate() {
+        let mut sketch = HyperLogLog::new();
+        assert_eq!(sketch.estimate(), 0);
+
+        // Duplicates do not count
+        for _ in 0..3 {
+            for i in 0..100 {
+                sketch.insert(&format!("value-{}", i));
+            }
+        }
+        assert!((97..=103).contains(&sketch.estimate()));
+
+        // Within 5% (about three standard errors) for large sets
+        for i in 0..100_000 {
+            sketch.insert(&format!("value-{}", i));
+        }
+        let estimate = sketch.estimate() as f64;
+        assert!((estimate - 100_000.0).abs() / 100_000.0 < 0.05);
+    }
+
+    #[test]
+    fn test_correlation_cache_invalidated_on_write() {
+        get_cache().clear();
+
+        let table_name = "test_corr_invalidate";
+        create_test_table(table_name, "key|x|y\np1|1|1\np2|2|2\np3|3|3\n");
+        assert_eq!(correlation(table_name, "x", "y").unwrap(), "1.0000");
+        assert_eq!(correlation(table_name, "x", "y").unwrap(), "1.0000");
+
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        create_test_table(table_name, "key|x|y\np1|1|3\np2|2|2\np3|3|1\n");
+        assert_eq!(correlation(table_name, "x", "y").unwrap(), "-1.0000");
+
+        cleanup_test_table(table_name);
+    }
+
+    #[test]
+    fn test_percentile_interpolates() {
+        get_cache().clear();
+
+        // Sorted: 10, 20, 30, 40 (n/a skipped)
+        let table_name = "test_percentile";
+        create_test_table(table_name, "key|ms\nr1|40\nr2|10\nr3|n/a\nr4|30\nr5|20\n");
+
+        assert_eq!(percentile(table_name, "ms", 0.0).unwrap(), "10.00");
+        assert_eq!(percentile(table_name, "ms", 50.0).unwrap(), "25.00");
+        assert_eq!(percentile(table_name, "ms", 95.0).unwrap(), "38.50");
+        assert_eq!(percentile(table_name, "ms", 100.0).unwrap(), "40.00");
+        // Each percentile has its own cache entry
+        assert_eq!(percentile(table_name, "ms", 50.0).unwrap(), "25.00");
+
+        assert!(matches!(
+            percentile(table_name, "ms", 101.0),
+            Err(crate::error::ReedError::ValidationError { .. })
+        ));
+        assert!(percentile(table_name, "missing", 50.0).is_err());
+
+        cleanup_test_table(table_name);
+    }
+
+    #[test]
+    fn test_histogram_buckets() {
+        get_cache().clear();
+
+        let table_name = "test_histogram";
+        create_test_table(
+            table_name,
+            "key|ms\nr1|0\nr2|9.5\nr3|10\nr4|49\nr5|50\nr6|51\nr7|-1\nr8|x\n",
+        );
+
+        // [0, 10), [10, 50]; -1, 51 and x are outside or non-numeric
+        assert_eq!(
+            histogram(table_name, "ms", &[0.0, 10.0, 50.0]).unwrap(),
+            r#"{"0-10":2,"10-50":3}"#
+        );
+        assert_eq!(
+            histogram(table_name, "ms", &[0.0, 100.0, 500.0]).unwrap(),
+            r#"{"0-100":6,"100-500":0}"#
+        );
+
+        for bad in [
+            &[10.0][..],
+            &[10.0, 0.0],
+            &[0.0, 0.0],
+            &[0.0, f64::INFINITY],
+        ] {
+            assert!(histogram(table_name, "ms", bad).is_err());
+        }
+
+        cleanup_test_table(table_name);
+    }
 }
@@ -4,6 +4,8 @@
 //! Function memoization cache for ultra-fast result lookups.
 //!
 //! Provides O(1) caching for function results with thread-safe concurrent access.
+//! The cache holds at most `CacheConfig::max_entries` results, evicting the
+//! least recently used, and optionally expires results after a TTL.
 //!
 //! ## Performance
 //!
@@ -28,10 +30,31 @@
 //! get_cache().insert(key, result.clone());
 //! ```
 
+use crate::database::cache::LruCache;
 use once_cell::sync::Lazy;
-use std::collections::HashMap;
 use std::sync::RwLock;
-use std::time::SystemTime;
+use std::time::{Duration, Instant, SystemTime};
+
+/// Default maximum number of cached results.
+pub const DEFAULT_MAX_ENTRIES: usize = 10_000;
+
+/// Size and lifetime limits of a `FunctionCache`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheConfig {
+    /// Maximum number of entries; the least recently used is evicted beyond it
+    pub max_entries: usize,
+    /// Lifetime of an entry (None = until evicted or invalidated)
+    pub ttl: Option<Duration>,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            max_entries: DEFAULT_MAX_ENTRIES,
+            ttl: None,
+        }
+    }
+}
 
 /// Cache key for function results.
 ///
@@ -72,6 +95,8 @@ pub struct CacheEntry {
     pub result: String,
     /// When this entry was created
     pub timestamp: SystemTime,
+    /// When this entry was created (monotonic, for TTL expiry)
+    pub inserted_at: Instant,
     /// Number of cache hits for this entry
     pub hits: usize,
 }
@@ -85,8 +110,10 @@ pub struct CacheStats {
     pub misses: usize,
     /// Total cache insertions
     pub inserts: usize,
-    /// Total cache evictions
+    /// Total entries removed by table invalidation
     pub evictions: usize,
+    /// Total entries dropped by the LRU policy or expired by TTL
+    pub eviction_count: u64,
 }
 
 impl CacheStats {
@@ -106,37 +133,70 @@ impl CacheStats {
 
 /// Thread-safe memoization cache for function results.
 ///
-/// Uses RwLock for concurrent access with HashMap storage.
+/// Uses RwLock for concurrent access with LRU storage.
 pub struct FunctionCache {
     /// Cache storage: CacheKey → CacheEntry
-    entries: RwLock<HashMap<CacheKey, CacheEntry>>,
+    entries: RwLock<LruCache<CacheKey, CacheEntry>>,
+    /// Size and lifetime limits
+    config: RwLock<CacheConfig>,
     /// Cache statistics
     stats: RwLock<CacheStats>,
 }
 
 impl FunctionCache {
-    /// Create a new empty cache.
-    pub fn new() -> Self {
+    /// Create a new empty cache with the given limits.
+    pub fn new(config: CacheConfig) -> Self {
         Self {
-            entries: RwLock::new(HashMap::new()),
+            entries: RwLock::new(LruCache::new(config.max_entries)),
+            config: RwLock::new(config),
             stats: RwLock::new(CacheStats::default()),
         }
     }
 
+    /// Change the cache limits.
+    ///
+    /// Applies immediately: least recently used entries beyond the new
+    /// `max_entries` are evicted, and the new TTL applies to existing
+    /// entries on their next access.
+    pub fn configure(&self, config: CacheConfig) {
+        let mut entries = self.entries.write().unwrap();
+        let evicted = entries.set_capacity(config.max_entries);
+        *self.config.write().unwrap() = config;
+
+        let mut stats = self.stats.write().unwrap();
+        stats.eviction_count += evicted as u64;
+    }
+
+    /// Current cache limits.
+    pub fn config(&self) -> CacheConfig {
+        *self.config.read().unwrap()
+    }
+
     /// Get cached result for a key.
     ///
     /// ## Arguments
     /// - `key` - Cache key to lookup
     ///
     /// ## Returns
-    /// - `Some(result)` if cached, `None` if not found
+    /// - `Some(result)` if cached, `None` if not found or expired
     ///
     /// ## Performance
-    /// - < 100ns typical (RwLock read + HashMap lookup)
+    /// - < 100ns typical (RwLock write + HashMap lookup)
     pub fn get(&self, key: &CacheKey) -> Option<String> {
+        let ttl = self.config.read().unwrap().ttl;
         let mut entries = self.entries.write().unwrap();
 
         if let Some(entry) = entries.get_mut(key) {
+            if ttl.is_some_and(|ttl| entry.inserted_at.elapsed() >= ttl) {
+                entries.remove(key);
+
+                let mut stats = self.stats.write().unwrap();
+                stats.eviction_count += 1;
+                stats.misses += 1;
+
+                return None;
+            }
+
             // Update hit counter
             entry.hits += 1;
 
@@ -156,6 +216,8 @@ impl FunctionCache {
 
     /// Store result in cache.
     ///
+    /// Evicts the least recently used entry when the cache is full.
+    ///
     /// ## Arguments
     /// - `key` - Cache key
     /// - `result` - Result value to cache
@@ -169,14 +231,16 @@ impl FunctionCache {
             key: key.clone(),
             result,
             timestamp: SystemTime::now(),
+            inserted_at: Instant::now(),
             hits: 0,
         };
 
-        entries.insert(key, entry);
+        let evicted = entries.insert(key, entry);
 
         // Update stats
         let mut stats = self.stats.write().unwrap();
         stats.inserts += 1;
+        stats.eviction_count += evicted as u64;
     }
 
     /// Clear entire cache.
@@ -262,14 +326,15 @@ impl FunctionCache {
 
 impl Default for FunctionCache {
     fn default() -> Self {
-        Self::new()
+        Self::new(CacheConfig::default())
     }
 }
 
 /// Global cache instance.
 ///
-/// Initialized lazily on first access.
-static FUNCTION_CACHE: Lazy<FunctionCache> = Lazy::new(FunctionCache::new);
+/// Initialized lazily on first access with `CacheConfig::default()`;
+/// reconfigure with `get_cache().configure(...)`.
+static FUNCTION_CACHE: Lazy<FunctionCache> = Lazy::new(FunctionCache::default);
 
 /// Get global cache instance.
 ///
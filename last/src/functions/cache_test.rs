@@ -5,7 +5,8 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::functions::cache::{CacheKey, FunctionCache};
+    use crate::functions::cache::{CacheConfig, CacheKey, FunctionCache};
+    use std::time::Duration;
 
     #[test]
     fn test_cache_key_creation() {
@@ -16,7 +17,7 @@ mod tests {
 
     #[test]
     fn test_cache_hit() {
-        let cache = FunctionCache::new();
+        let cache = FunctionCache::default();
 
         let key = CacheKey::new("test", vec!["arg1"]);
         cache.insert(key.clone(), "result".to_string());
@@ -32,7 +33,7 @@ mod tests {
 
     #[test]
     fn test_cache_miss() {
-        let cache = FunctionCache::new();
+        let cache = FunctionCache::default();
 
         let key = CacheKey::new("test", vec!["arg1"]);
         let cached = cache.get(&key);
@@ -46,7 +47,7 @@ mod tests {
 
     #[test]
     fn test_cache_multiple_hits() {
-        let cache = FunctionCache::new();
+        let cache = FunctionCache::default();
 
         let key = CacheKey::new("test", vec!["arg1"]);
         cache.insert(key.clone(), "result".to_string());
@@ -63,7 +64,7 @@ mod tests {
 
     #[test]
     fn test_cache_different_keys() {
-        let cache = FunctionCache::new();
+        let cache = FunctionCache::default();
 
         let key1 = CacheKey::new("func1", vec!["arg1"]);
         let key2 = CacheKey::new("func2", vec!["arg1"]);
@@ -82,7 +83,7 @@ mod tests {
 
     #[test]
     fn test_cache_clear() {
-        let cache = FunctionCache::new();
+        let cache = FunctionCache::default();
 
         let key1 = CacheKey::new("test", vec!["arg1"]);
         let key2 = CacheKey::new("test", vec!["arg2"]);
@@ -101,7 +102,7 @@ mod tests {
 
     #[test]
     fn test_cache_clear_function() {
-        let cache = FunctionCache::new();
+        let cache = FunctionCache::default();
 
         let key1 = CacheKey::new("func1", vec!["arg1"]);
         let key2 = CacheKey::new("func2", vec!["arg1"]);
@@ -120,7 +121,7 @@ mod tests {
 
     #[test]
     fn test_cache_invalidate_table() {
-        let cache = FunctionCache::new();
+        let cache = FunctionCache::default();
 
         // Aggregation functions typically have table as first arg
         let key1 = CacheKey::new("count", vec!["users"]);
@@ -141,7 +142,7 @@ mod tests {
 
     #[test]
     fn test_cache_stats_hit_rate() {
-        let cache = FunctionCache::new();
+        let cache = FunctionCache::default();
 
         let key = CacheKey::new("test", vec!["arg1"]);
         cache.insert(key.clone(), "result".to_string());
@@ -164,7 +165,7 @@ mod tests {
 
     #[test]
     fn test_cache_stats_zero_requests() {
-        let cache = FunctionCache::new();
+        let cache = FunctionCache::default();
         let stats = cache.stats();
 
         assert_eq!(stats.hit_rate(), 0.0);
@@ -172,7 +173,7 @@ mod tests {
 
     #[test]
     fn test_cache_memory_usage() {
-        let cache = FunctionCache::new();
+        let cache = FunctionCache::default();
 
         // Empty cache
         assert_eq!(cache.memory_usage(), 0);
@@ -187,7 +188,7 @@ mod tests {
 
     #[test]
     fn test_cache_entry_count() {
-        let cache = FunctionCache::new();
+        let cache = FunctionCache::default();
 
         assert_eq!(cache.entry_count(), 0);
 
@@ -203,7 +204,7 @@ mod tests {
 
     #[test]
     fn test_cache_overwrite_same_key() {
-        let cache = FunctionCache::new();
+        let cache = FunctionCache::default();
 
         let key = CacheKey::new("test", vec!["arg1"]);
 
@@ -216,4 +217,60 @@ mod tests {
         // Should still be 1 entry (overwritten)
         assert_eq!(cache.entry_count(), 1);
     }
+
+    #[test]
+    fn test_cache_evicts_least_recently_used() {
+        let cache = FunctionCache::new(CacheConfig {
+            max_entries: 2,
+            ttl: None,
+        });
+        let key = |arg: &str| CacheKey::new("test", vec![arg]);
+
+        cache.insert(key("a"), "1".to_string());
+        cache.insert(key("b"), "2".to_string());
+        // Reading "a" makes "b" the least recently used
+        assert_eq!(cache.get(&key("a")), Some("1".to_string()));
+        cache.insert(key("c"), "3".to_string());
+
+        assert_eq!(cache.entry_count(), 2);
+        assert_eq!(cache.get(&key("b")), None);
+        assert_eq!(cache.get(&key("a")), Some("1".to_string()));
+        assert_eq!(cache.stats().eviction_count, 1);
+
+        // Shrinking evicts down to the new limit
+        cache.configure(CacheConfig {
+            max_entries: 1,
+            ttl: None,
+        });
+        assert_eq!(cache.entry_count(), 1);
+        assert_eq!(cache.get(&key("a")), Some("1".to_string()));
+        assert_eq!(cache.stats().eviction_count, 2);
+        assert_eq!(cache.config().max_entries, 1);
+    }
+
+    #[test]
+    fn test_cache_ttl_expiry() {
+        let cache = FunctionCache::new(CacheConfig {
+            max_entries: 10,
+            ttl: Some(Duration::from_millis(20)),
+        });
+        let key = CacheKey::new("test", vec!["arg1"]);
+
+        cache.insert(key.clone(), "result".to_string());
+        assert_eq!(cache.get(&key), Some("result".to_string()));
+
+        std::thread::sleep(Duration::from_millis(30));
+        assert_eq!(cache.get(&key), None);
+        assert_eq!(cache.entry_count(), 0);
+
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses), (1, 1));
+        assert_eq!(stats.eviction_count, 1);
+
+        // Invalidation keeps its own counter
+        cache.insert(CacheKey::new("sum", vec!["users"]), "1".to_string());
+        cache.invalidate_table("users");
+        assert_eq!(cache.stats().evictions, 1);
+        assert_eq!(cache.stats().eviction_count, 1);
+    }
 }
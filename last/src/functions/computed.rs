@@ -295,3 +295,128 @@ pub fn calculate_discount(price: &str, percentage: &str) -> ReedResult<String> {
 
     Ok(result)
 }
+
+/// Check whether a string is a valid calendar date.
+///
+/// ## Input
+/// - `date_str` - Candidate date string
+///
+/// ## Output
+/// - `true` for a real date in YYYY-MM-DD form, `false` otherwise
+///   (including impossible dates such as "2025-02-30")
+///
+/// ## Performance
+/// - First call: < 1μs
+/// - Cached: < 100ns
+///
+/// ## Error Conditions
+/// - None (invalid input yields `false`)
+///
+/// ## Example Usage
+/// ```rust
+/// let ok = validate_date("2024-02-29")?; // true (leap year)
+/// let bad = validate_date("2025-02-29")?; // false
+/// ```
+pub fn validate_date(date_str: &str) -> ReedResult<bool> {
+    let key = CacheKey::new("validate_date", vec![date_str]);
+
+    if let Some(cached) = get_cache().get(&key) {
+        return Ok(cached == "true");
+    }
+
+    let result = parse_date(date_str).is_ok();
+
+    get_cache().insert(key, result.to_string());
+
+    Ok(result)
+}
+
+/// Add a number of days to a date.
+///
+/// ## Input
+/// - `date_str` - ISO 8601 date string (YYYY-MM-DD)
+/// - `days` - Days to add (negative to go back)
+///
+/// ## Output
+/// - Resulting date as YYYY-MM-DD
+///
+/// ## Performance
+/// - First call: < 1μs
+/// - Cached: < 100ns
+///
+/// ## Error Conditions
+/// - Invalid date format → ReedError::ParseError
+/// - Result outside the supported date range → ReedError::ParseError
+///
+/// ## Example Usage
+/// ```rust
+/// let due = add_days("2025-01-30", 30)?; // "2025-03-01"
+/// let before = add_days("2025-01-01", -1)?; // "2024-12-31"
+/// ```
+pub fn add_days(date_str: &str, days: i64) -> ReedResult<String> {
+    let days_str = days.to_string();
+    let key = CacheKey::new("add_days", vec![date_str, days_str.as_str()]);
+
+    if let Some(cached) = get_cache().get(&key) {
+        return Ok(cached);
+    }
+
+    let date = parse_date(date_str)?;
+    let result = chrono::Duration::try_days(days)
+        .and_then(|delta| date.checked_add_signed(delta))
+        .ok_or_else(|| ReedError::ParseError {
+            reason: format!("Date '{}' plus {} days is out of range", date_str, days),
+        })?
+        .format("%Y-%m-%d")
+        .to_string();
+
+    get_cache().insert(key, result.clone());
+
+    Ok(result)
+}
+
+/// Calculate the number of days between two dates.
+///
+/// ## Input
+/// - `a` - ISO 8601 date string (YYYY-MM-DD)
+/// - `b` - ISO 8601 date string (YYYY-MM-DD)
+///
+/// ## Output
+/// - `a - b` in days (negative if `a` is before `b`)
+///
+/// ## Performance
+/// - First call: < 1μs
+/// - Cached: < 100ns
+///
+/// ## Error Conditions
+/// - Invalid date format in either argument → ReedError::ParseError
+///
+/// ## Example Usage
+/// ```rust
+/// let days = subtract_dates("2025-03-01", "2025-01-30")?; // 30
+/// let back = subtract_dates("2025-01-30", "2025-03-01")?; // -30
+/// ```
+pub fn subtract_dates(a: &str, b: &str) -> ReedResult<i64> {
+    let key = CacheKey::new("subtract_dates", vec![a, b]);
+
+    if let Some(cached) = get_cache().get(&key) {
+        if let Ok(days) = cached.parse() {
+            return Ok(days);
+        }
+    }
+
+    let days = parse_date(a)?
+        .signed_duration_since(parse_date(b)?)
+        .num_days();
+
+    get_cache().insert(key, days.to_string());
+
+    Ok(days)
+}
+
+/// Parse a YYYY-MM-DD date.
+fn parse_date(date: &str) -> ReedResult<NaiveDate> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|e| ReedError::ParseError {
+        reason: format!("Invalid date '{}' (expected YYYY-MM-DD): {}", date, e),
+    })
+}
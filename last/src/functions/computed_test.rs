@@ -219,4 +219,43 @@ mod tests {
         let stats = get_cache().stats();
         assert!(stats.hits >= 1);
     }
+
+    #[test]
+    fn test_validate_date() {
+        get_cache().clear();
+
+        assert!(validate_date("2024-02-29").unwrap());
+        assert!(!validate_date("2025-02-29").unwrap());
+        assert!(!validate_date("29.02.2024").unwrap());
+        assert!(!validate_date("").unwrap());
+
+        // Cached answers keep their value
+        assert!(validate_date("2024-02-29").unwrap());
+        assert!(!validate_date("2025-02-29").unwrap());
+    }
+
+    #[test]
+    fn test_add_days() {
+        get_cache().clear();
+
+        assert_eq!(add_days("2025-01-30", 30).unwrap(), "2025-03-01");
+        assert_eq!(add_days("2025-01-01", -1).unwrap(), "2024-12-31");
+        assert_eq!(add_days("2024-02-28", 1).unwrap(), "2024-02-29");
+        assert_eq!(add_days("2025-01-30", 30).unwrap(), "2025-03-01");
+
+        assert!(add_days("not-a-date", 1).is_err());
+        assert!(add_days("2025-01-01", i64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_subtract_dates() {
+        get_cache().clear();
+
+        assert_eq!(subtract_dates("2025-03-01", "2025-01-30").unwrap(), 30);
+        assert_eq!(subtract_dates("2025-01-30", "2025-03-01").unwrap(), -30);
+        assert_eq!(subtract_dates("2025-01-30", "2025-01-30").unwrap(), 0);
+        assert_eq!(subtract_dates("2025-03-01", "2025-01-30").unwrap(), 30);
+
+        assert!(subtract_dates("2025-01-30", "2025-13-01").is_err());
+    }
 }
@@ -99,4 +99,4 @@ mod computed_test;
 mod transformations_test;
 
 // Re-export commonly used types
-pub use cache::{get_cache, CacheKey, CacheStats, FunctionCache};
+pub use cache::{get_cache, CacheConfig, CacheKey, CacheStats, FunctionCache};
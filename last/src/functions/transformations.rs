@@ -3,7 +3,8 @@
 
 //! Data transformation functions with automatic caching.
 //!
-//! Provides string cleaning, normalization, and formatting operations.
+//! Provides string cleaning, normalization, and formatting operations, plus
+//! JSON normalisation and field extraction for values holding JSON.
 //! All results are automatically cached for instant repeated access.
 //!
 //! ## Performance
@@ -29,7 +30,7 @@
 //! let slug = slugify("Hello World!")?; // "hello-world"
 //! ```
 
-use crate::error::ReedResult;
+use crate::error::{ReedError, ReedResult};
 use crate::functions::cache::{get_cache, CacheKey};
 
 /// Normalize email address (lowercase, trim whitespace).
@@ -397,3 +398,98 @@ pub fn reverse(value: &str) -> ReedResult<String> {
 
     Ok(result)
 }
+
+/// Validate and normalise a JSON value.
+///
+/// ## Input
+/// - `value` - JSON text
+///
+/// ## Output
+/// - Compact JSON with object keys sorted, e.g. `{"a":1,"b":[true,null]}`
+///
+/// ## Performance
+/// - First call: O(n), < 5μs for small documents
+/// - Cached: < 100ns
+///
+/// ## Error Conditions
+/// - Invalid JSON → ReedError::ParseError
+///
+/// ## Example Usage
+/// ```rust
+/// let json = parse_json(r#"{ "b": 1, "a": [true, null] }"#)?; // {"a":[true,null],"b":1}
+/// ```
+pub fn parse_json(value: &str) -> ReedResult<String> {
+    let key = CacheKey::new("parse_json", vec![value]);
+
+    if let Some(cached) = get_cache().get(&key) {
+        return Ok(cached);
+    }
+
+    // serde_json's default map is ordered by key, so this sorts objects
+    let result = read_json(value)?.to_string();
+
+    get_cache().insert(key, result.clone());
+
+    Ok(result)
+}
+
+/// Extract a field from a JSON value by dot path.
+///
+/// Each path segment names an object key; a numeric segment may also index
+/// an array (`items.0.sku`).
+///
+/// ## Input
+/// - `json` - JSON text
+/// - `path` - Dot-separated path, e.g. `"user.address.city"`
+///
+/// ## Output
+/// - Strings unquoted, other values as compact JSON (sorted keys)
+/// - Empty string if the path does not exist or leads to `null`
+///
+/// ## Performance
+/// - First call: O(n) parse plus O(d) for path depth d
+/// - Cached: < 100ns
+///
+/// ## Error Conditions
+/// - Invalid JSON → ReedError::ParseError
+///
+/// ## Example Usage
+/// ```rust
+/// let json = r#"{"user":{"name":"Ada","tags":["admin"]}}"#;
+/// let name = extract_json_field(json, "user.name")?; // "Ada"
+/// let tags = extract_json_field(json, "user.tags")?; // ["admin"]
+/// let none = extract_json_field(json, "user.email")?; // ""
+/// ```
+pub fn extract_json_field(json: &str, path: &str) -> ReedResult<String> {
+    let key = CacheKey::new("extract_json_field", vec![json, path]);
+
+    if let Some(cached) = get_cache().get(&key) {
+        return Ok(cached);
+    }
+
+    let root = read_json(json)?;
+    let field = path
+        .split('.')
+        .try_fold(&root, |value, segment| match value {
+            serde_json::Value::Object(map) => map.get(segment),
+            serde_json::Value::Array(items) => items.get(segment.parse::<usize>().ok()?),
+            _ => None,
+        });
+
+    let result = match field {
+        None | Some(serde_json::Value::Null) => String::new(),
+        Some(serde_json::Value::String(text)) => text.clone(),
+        Some(other) => other.to_string(),
+    };
+
+    get_cache().insert(key, result.clone());
+
+    Ok(result)
+}
+
+/// Parse JSON text.
+fn read_json(value: &str) -> ReedResult<serde_json::Value> {
+    serde_json::from_str(value).map_err(|e| ReedError::ParseError {
+        reason: format!("Invalid JSON: {}", e),
+    })
+}
@@ -268,4 +268,47 @@ mod tests {
         let stats = get_cache().stats();
         assert!(stats.hits >= 1);
     }
+
+    #[test]
+    fn test_parse_json_normalises() {
+        get_cache().clear();
+
+        let result =
+            parse_json("{ \"b\": 1,\n \"a\": {\"z\": [true, null], \"y\": \"x\"} }").unwrap();
+        assert_eq!(result, r#"{"a":{"y":"x","z":[true,null]},"b":1}"#);
+        assert_eq!(parse_json(" [1, 2] ").unwrap(), "[1,2]");
+
+        assert!(parse_json("{\"a\":").is_err());
+        assert!(parse_json("").is_err());
+    }
+
+    #[test]
+    fn test_extract_json_field() {
+        get_cache().clear();
+
+        let json = r#"{"user":{"name":"Ada","address":{"city":"Wien"},"tags":["a","b"],"age":36,"email":null}}"#;
+        assert_eq!(extract_json_field(json, "user.name").unwrap(), "Ada");
+        assert_eq!(
+            extract_json_field(json, "user.address.city").unwrap(),
+            "Wien"
+        );
+        assert_eq!(extract_json_field(json, "user.age").unwrap(), "36");
+        assert_eq!(
+            extract_json_field(json, "user.tags").unwrap(),
+            r#"["a","b"]"#
+        );
+        assert_eq!(extract_json_field(json, "user.tags.1").unwrap(), "b");
+        assert_eq!(
+            extract_json_field(json, "user.address").unwrap(),
+            r#"{"city":"Wien"}"#
+        );
+
+        // Missing paths and null give an empty value
+        assert_eq!(extract_json_field(json, "user.email").unwrap(), "");
+        assert_eq!(extract_json_field(json, "user.phone").unwrap(), "");
+        assert_eq!(extract_json_field(json, "user.name.first").unwrap(), "");
+        assert_eq!(extract_json_field(json, "user.tags.9").unwrap(), "");
+
+        assert!(extract_json_field("not json", "user").is_err());
+    }
 }
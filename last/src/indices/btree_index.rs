@@ -35,7 +35,7 @@
 //! # Ok::<(), reedbase::ReedError>(())
 //! ```
 
-use crate::btree::{BPlusTree, Order};
+use crate::btree::{BPlusTree, Order, TreeStatistics};
 use crate::error::ReedResult;
 use crate::indices::Index;
 use serde::{Deserialize, Serialize};
@@ -301,7 +301,40 @@ where
     /// # Ok::<(), reedbase::ReedError>(())
     /// ```
     fn iter(&self) -> Box<dyn Iterator<Item = (K, V)> + '_> {
-        self.tree.iter()
+        Box::new(self.tree.iter())
+    }
+
+    /// Replace the index contents through `BPlusTree::bulk_load()`.
+    ///
+    /// ## Error Conditions
+    /// - `IndexOperationUnsupported`: Keys not strictly ascending (checked
+    ///   before anything is written)
+    fn replace_all(&mut self, entries: Vec<(K, V)>) -> ReedResult<()> {
+        self.tree.bulk_load(entries)
+    }
+
+    /// Count keys in range [start, end) without deserialising values.
+    ///
+    /// ## Performance
+    /// - O(log n + m) where m = leaves overlapping the range
+    fn count_keys_in_range(&self, start: &K, end: &K) -> ReedResult<usize> {
+        self.tree.count_keys_in_range(start, end)
+    }
+
+    /// Total number of keys in the index.
+    ///
+    /// ## Performance
+    /// - O(p) where p = number of pages, values never deserialised
+    fn key_count(&self) -> ReedResult<usize> {
+        self.tree.key_count()
+    }
+
+    /// Shape and fill factor of the tree.
+    ///
+    /// ## Performance
+    /// - O(p) where p = number of pages
+    fn tree_statistics(&self) -> ReedResult<Option<TreeStatistics>> {
+        self.tree.statistics().map(Some)
     }
 
     /// Backend type identifier.
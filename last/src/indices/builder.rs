@@ -17,6 +17,9 @@
 //!     backend: IndexBackend::BTree,
 //!     btree_order: Some(100),
 //!     persist_path: Some("/tmp/reedbase/indices".to_string()),
+//!     columns: Vec::new(),
+//!     predicate: None,
+//!     covered: Vec::new(),
 //! };
 //!
 //! let builder = IndexBuilder::new(config);
@@ -30,9 +33,40 @@ use crate::error::{ReedError, ReedResult};
 use crate::indices::btree_index::BTreeIndex;
 use crate::indices::hashmap_index::HashMapIndex;
 use crate::indices::Index;
+use crate::reedql::types::FilterCondition;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 
+/// Value of a covering index: row IDs with the covered column values of
+/// each row.
+pub type CoveredRows = Vec<(usize, HashMap<String, String>)>;
+
+/// Separator between column values in a composite index key.
+pub const COMPOSITE_SEPARATOR: char = '\x00';
+
+/// Build the key of a composite index from column values.
+///
+/// Joins the values with `COMPOSITE_SEPARATOR`, which cannot occur in CSV
+/// fields, so distinct value tuples never share a key.
+///
+/// ## Example
+/// ```rust
+/// use reedbase_last::indices::builder::composite_key;
+///
+/// assert_eq!(composite_key(&["page", "de"]), "page\x00de");
+/// ```
+pub fn composite_key<S: AsRef<str>>(values: &[S]) -> String {
+    let mut key = String::new();
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            key.push(COMPOSITE_SEPARATOR);
+        }
+        key.push_str(value.as_ref());
+    }
+    key
+}
+
 /// Index backend type.
 ///
 /// ## Variants
@@ -74,6 +108,9 @@ impl Default for IndexBackend {
 ///     backend: IndexBackend::BTree,
 ///     btree_order: Some(100),
 ///     persist_path: Some("/tmp/reedbase/indices".to_string()),
+///     columns: Vec::new(),
+///     predicate: None,
+///     covered: Vec::new(),
 /// };
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -95,6 +132,27 @@ pub struct IndexConfig {
     /// Creates files like `{persist_path}/namespace.btree`.
     #[serde(default)]
     pub persist_path: Option<String>,
+
+    /// Columns forming a composite index, in key order.
+    ///
+    /// Empty for single-column indices. See `IndexConfig::composite()`.
+    #[serde(default)]
+    pub columns: Vec<String>,
+
+    /// Row filter of a partial index (e.g. `environment = 'prod'`).
+    ///
+    /// `IndexBuilder::build()` skips rows that do not match. See
+    /// `IndexConfig::partial()`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub predicate: Option<FilterCondition>,
+
+    /// Columns stored alongside the row IDs of a covering index.
+    ///
+    /// `IndexBuilder::build_covering()` keeps their values per row, so
+    /// queries projecting only these columns skip the CSV. See
+    /// `IndexConfig::covering()`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub covered: Vec<String>,
 }
 
 impl Default for IndexConfig {
@@ -103,10 +161,106 @@ impl Default for IndexConfig {
             backend: IndexBackend::HashMap,
             btree_order: None,
             persist_path: None,
+            columns: Vec::new(),
+            predicate: None,
+            covered: Vec::new(),
         }
     }
 }
 
+impl IndexConfig {
+    /// Configuration for a composite index over several columns.
+    ///
+    /// The index is keyed on `composite_key()` of the column values, so a
+    /// lookup on all columns is a single O(1) (HashMap) or O(log n)
+    /// (B+-Tree) probe.
+    ///
+    /// ## Input
+    /// - `columns`: Column names in key order (at least two)
+    ///
+    /// ## Example
+    /// ```rust
+    /// use reedbase_last::indices::builder::{IndexBuilder, IndexConfig};
+    ///
+    /// let config = IndexConfig::composite(vec!["namespace".into(), "language".into()]);
+    /// let index = IndexBuilder::new(config).build_composite_index()?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn composite(columns: Vec<String>) -> Self {
+        Self {
+            columns,
+            ..Self::default()
+        }
+    }
+
+    /// Configuration for a partial index over the rows matching `predicate`.
+    ///
+    /// Keeps indices small for sparse conditions such as soft-deletes; the
+    /// planner only uses it for queries implying the predicate.
+    ///
+    /// ## Input
+    /// - `column`: Indexed column
+    /// - `predicate`: Condition a row must satisfy to be indexed
+    ///
+    /// ## Example
+    /// ```rust
+    /// use reedbase_last::indices::builder::{IndexBuilder, IndexConfig};
+    /// use reedbase_last::reedql::parse_condition;
+    ///
+    /// let config = IndexConfig::partial("key".into(), parse_condition("deleted IS EMPTY")?);
+    /// let builder = IndexBuilder::new(config);
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn partial(column: String, predicate: FilterCondition) -> Self {
+        Self {
+            columns: vec![column],
+            predicate: Some(predicate),
+            ..Self::default()
+        }
+    }
+
+    /// Configuration for a covering index on `index_column`.
+    ///
+    /// Each entry holds the row IDs together with the values of `covered`,
+    /// so point lookups projecting only covered columns are answered from
+    /// the index alone.
+    ///
+    /// ## Input
+    /// - `index_column`: Indexed column
+    /// - `covered`: Columns stored with each row ID
+    ///
+    /// ## Example
+    /// ```rust
+    /// use reedbase_last::indices::builder::{IndexBuilder, IndexConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let rows = vec![HashMap::from([
+    ///     ("key".into(), "page.title".into()),
+    ///     ("value".into(), "Welcome".into()),
+    /// ])];
+    /// let config = IndexConfig::covering("key".into(), vec!["value".into()]);
+    /// let index = IndexBuilder::new(config).build_covering(&rows)?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn covering(index_column: String, covered: Vec<String>) -> Self {
+        Self {
+            columns: vec![index_column],
+            covered,
+            ..Self::default()
+        }
+    }
+
+    /// Whether this configures a covering index.
+    pub fn is_covering(&self) -> bool {
+        !self.covered.is_empty()
+    }
+
+    /// Whether this configures a composite index.
+    pub fn is_composite(&self) -> bool {
+        self.columns.len() > 1
+    }
+}
+
 /// Index builder factory.
 ///
 /// Constructs index instances based on configuration.
@@ -314,6 +468,199 @@ impl IndexBuilder {
         }
     }
 
+    /// Build composite index (composite key → Vec<usize>).
+    ///
+    /// ## Output
+    /// - `Ok(Box<dyn Index>)`: Index instance (HashMap or B+-Tree)
+    /// - `Err(ReedError)`: Configuration error or I/O error
+    ///
+    /// ## Error Conditions
+    /// - Fewer than two columns configured (use `IndexConfig::composite()`)
+    /// - B+-Tree backend: Invalid order, missing persist_path, I/O error
+    ///
+    /// ## Note
+    /// B+-Tree files are named after the columns, e.g.
+    /// `{persist_path}/namespace+language.btree`.
+    pub fn build_composite_index(&self) -> ReedResult<Box<dyn Index<String, Vec<usize>>>> {
+        if !self.config.is_composite() {
+            return Err(ReedError::ParseError {
+                reason: "Composite index requires at least two columns".to_string(),
+            });
+        }
+
+        match self.config.backend {
+            IndexBackend::HashMap => {
+                let index = HashMapIndex::<String, Vec<usize>>::new();
+                Ok(Box::new(index))
+            }
+            IndexBackend::BTree => {
+                let order = self.get_btree_order()?;
+                let filename = format!("{}.btree", self.config.columns.join("+"));
+                let path = self.get_index_path(&filename)?;
+                let index = BTreeIndex::<String, Vec<usize>>::open(path, order)?;
+                Ok(Box::new(index))
+            }
+        }
+    }
+
+    /// Build an index over `columns` from table rows.
+    ///
+    /// Keys are `composite_key()` of the configured columns (the plain value
+    /// for a single column), values are row positions in `rows`. Rows not
+    /// matching the predicate, or lacking a column, are skipped.
+    ///
+    /// ## Input
+    /// - `rows`: Table rows as column → value
+    ///
+    /// ## Output
+    /// - `Ok(Box<dyn Index>)`: Filled index (HashMap or B+-Tree)
+    /// - `Err(ReedError)`: Configuration error, predicate error or I/O error
+    ///
+    /// ## Error Conditions
+    /// - No columns configured
+    /// - Predicate cannot be evaluated (e.g. unresolved subquery)
+    /// - B+-Tree backend: Invalid order, missing persist_path, I/O error
+    ///
+    /// ## Performance
+    /// - O(n log n) for n matching rows (entries are sorted for bulk-loading)
+    ///
+    /// ## Example
+    /// ```rust
+    /// use reedbase_last::indices::builder::{IndexBuilder, IndexConfig};
+    /// use reedbase_last::reedql::parse_condition;
+    /// use std::collections::HashMap;
+    ///
+    /// let rows = vec![
+    ///     HashMap::from([("key".into(), "a".into()), ("env".into(), "prod".into())]),
+    ///     HashMap::from([("key".into(), "b".into()), ("env".into(), "dev".into())]),
+    /// ];
+    /// let config = IndexConfig::partial("key".into(), parse_condition("env = 'prod'")?);
+    /// let index = IndexBuilder::new(config).build(&rows)?;
+    /// assert_eq!(index.get(&"a".to_string())?, Some(vec![0]));
+    /// assert_eq!(index.get(&"b".to_string())?, None);
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn build(
+        &self,
+        rows: &[HashMap<String, String>],
+    ) -> ReedResult<Box<dyn Index<String, Vec<usize>>>> {
+        self.build_with(rows, "btree", |row_id, _| row_id)
+    }
+
+    /// Build a covering index from table rows.
+    ///
+    /// Like `build()`, but each entry stores the values of the covered
+    /// columns next to the row ID (missing columns are left out). B+-Tree
+    /// indices are written to `{columns}.covering.btree`.
+    ///
+    /// ## Input
+    /// - `rows`: Table rows as column → value
+    ///
+    /// ## Output
+    /// - `Ok(Box<dyn Index>)`: Filled index (HashMap or B+-Tree)
+    /// - `Err(ReedError)`: Configuration error, predicate error or I/O error
+    ///
+    /// ## Error Conditions
+    /// - No columns or no covered columns configured
+    /// - Predicate cannot be evaluated (e.g. unresolved subquery)
+    /// - B+-Tree backend: Invalid order, missing persist_path, I/O error
+    ///
+    /// ## Performance
+    /// - O(n × (log n + c)) for n matching rows and c covered columns
+    ///
+    /// ## Example
+    /// ```rust
+    /// use reedbase_last::indices::builder::{IndexBuilder, IndexConfig};
+    /// use std::collections::HashMap;
+    ///
+    /// let rows = vec![HashMap::from([
+    ///     ("key".into(), "page.title".into()),
+    ///     ("value".into(), "Welcome".into()),
+    /// ])];
+    /// let config = IndexConfig::covering("key".into(), vec!["value".into()]);
+    /// let index = IndexBuilder::new(config).build_covering(&rows)?;
+    /// let entries = index.get(&"page.title".to_string())?.unwrap();
+    /// assert_eq!(entries[0].1["value"], "Welcome");
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn build_covering(
+        &self,
+        rows: &[HashMap<String, String>],
+    ) -> ReedResult<Box<dyn Index<String, CoveredRows>>> {
+        if self.config.covered.is_empty() {
+            return Err(ReedError::ParseError {
+                reason: "Covering index requires at least one covered column".to_string(),
+            });
+        }
+
+        self.build_with(rows, "covering.btree", |row_id, row| {
+            let values = self
+                .config
+                .covered
+                .iter()
+                .filter_map(|column| Some((column.clone(), row.get(column)?.clone())))
+                .collect();
+            (row_id, values)
+        })
+    }
+
+    /// Fill an index from rows, storing `entry(row_id, row)` per matching row.
+    fn build_with<T>(
+        &self,
+        rows: &[HashMap<String, String>],
+        extension: &str,
+        entry: impl Fn(usize, &HashMap<String, String>) -> T,
+    ) -> ReedResult<Box<dyn Index<String, Vec<T>>>>
+    where
+        T: Clone + Serialize + for<'de> Deserialize<'de> + Send + Sync + std::fmt::Debug + 'static,
+    {
+        if self.config.columns.is_empty() {
+            return Err(ReedError::ParseError {
+                reason: "Index requires at least one column".to_string(),
+            });
+        }
+
+        let mut entries: BTreeMap<String, Vec<T>> = BTreeMap::new();
+        for (row_id, row) in rows.iter().enumerate() {
+            if let Some(predicate) = &self.config.predicate {
+                if !predicate.matches(row)? {
+                    continue;
+                }
+            }
+            let Some(values) = self
+                .config
+                .columns
+                .iter()
+                .map(|column| row.get(column))
+                .collect::<Option<Vec<&String>>>()
+            else {
+                continue;
+            };
+            entries
+                .entry(composite_key(&values))
+                .or_default()
+                .push(entry(row_id, row));
+        }
+
+        match self.config.backend {
+            IndexBackend::HashMap => {
+                let mut index = HashMapIndex::<String, Vec<T>>::new();
+                for (key, row_ids) in entries {
+                    Index::insert(&mut index, key, row_ids)?;
+                }
+                Ok(Box::new(index))
+            }
+            IndexBackend::BTree => {
+                let order = self.get_btree_order()?;
+                let filename = format!("{}.{}", self.config.columns.join("+"), extension);
+                let path = self.get_index_path(&filename)?;
+                let mut index = BTreeIndex::<String, Vec<T>>::open(path, order)?;
+                index.tree_mut().bulk_load(entries)?;
+                Ok(Box::new(index))
+            }
+        }
+    }
+
     /// Get B+-Tree order from configuration.
     ///
     /// ## Output
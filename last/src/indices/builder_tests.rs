@@ -5,8 +5,10 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::indices::builder::{IndexBackend, IndexBuilder, IndexConfig};
+    use crate::indices::builder::{composite_key, IndexBackend, IndexBuilder, IndexConfig};
     use crate::indices::Index;
+    use crate::reedql::parser::parse_condition;
+    use std::collections::HashMap;
     use tempfile::TempDir;
 
     #[test]
@@ -23,6 +25,9 @@ mod tests {
             backend: IndexBackend::HashMap,
             btree_order: None,
             persist_path: None,
+            columns: Vec::new(),
+            predicate: None,
+            covered: Vec::new(),
         };
 
         let builder = IndexBuilder::new(config);
@@ -35,6 +40,9 @@ mod tests {
             backend: IndexBackend::BTree,
             btree_order: Some(100),
             persist_path: Some("/tmp/test".to_string()),
+            columns: Vec::new(),
+            predicate: None,
+            covered: Vec::new(),
         };
 
         let builder = IndexBuilder::new(config);
@@ -146,6 +154,9 @@ mod tests {
             backend: IndexBackend::BTree,
             btree_order: Some(100),
             persist_path: Some(persist_path),
+            columns: Vec::new(),
+            predicate: None,
+            covered: Vec::new(),
         };
 
         let builder = IndexBuilder::new(config);
@@ -164,6 +175,9 @@ mod tests {
             backend: IndexBackend::BTree,
             btree_order: Some(100),
             persist_path: Some(persist_path),
+            columns: Vec::new(),
+            predicate: None,
+            covered: Vec::new(),
         };
 
         let builder = IndexBuilder::new(config);
@@ -178,6 +192,9 @@ mod tests {
             backend: IndexBackend::BTree,
             btree_order: Some(100),
             persist_path: None, // Missing!
+            columns: Vec::new(),
+            predicate: None,
+            covered: Vec::new(),
         };
 
         let builder = IndexBuilder::new(config);
@@ -196,6 +213,9 @@ mod tests {
             backend: IndexBackend::BTree,
             btree_order: Some(2), // Invalid (< 3)
             persist_path: Some(persist_path),
+            columns: Vec::new(),
+            predicate: None,
+            covered: Vec::new(),
         };
 
         let builder = IndexBuilder::new(config);
@@ -249,6 +269,9 @@ mod tests {
             backend: IndexBackend::BTree,
             btree_order: Some(100),
             persist_path: Some(persist_path),
+            columns: Vec::new(),
+            predicate: None,
+            covered: Vec::new(),
         };
 
         let builder = IndexBuilder::new(config);
@@ -278,6 +301,9 @@ mod tests {
             backend: IndexBackend::BTree,
             btree_order: Some(100),
             persist_path: Some(persist_path),
+            columns: Vec::new(),
+            predicate: None,
+            covered: Vec::new(),
         };
 
         let builder = IndexBuilder::new(config);
@@ -319,6 +345,9 @@ mod tests {
             backend: IndexBackend::BTree,
             btree_order: Some(150),
             persist_path: Some("/tmp/test".to_string()),
+            columns: Vec::new(),
+            predicate: None,
+            covered: Vec::new(),
         };
 
         // Serialize to TOML
@@ -331,4 +360,154 @@ mod tests {
         assert_eq!(parsed.backend, IndexBackend::BTree);
         assert_eq!(parsed.btree_order, Some(150));
     }
+
+    #[test]
+    fn test_composite_config() {
+        let config = IndexConfig::composite(vec!["namespace".into(), "language".into()]);
+        assert!(config.is_composite());
+        assert_eq!(config.backend, IndexBackend::HashMap);
+        assert!(!IndexConfig::default().is_composite());
+
+        let parsed: IndexConfig = toml::from_str("columns = [\"namespace\", \"language\"]")
+            .expect("Deserialize from TOML");
+        assert_eq!(parsed.columns, config.columns);
+    }
+
+    #[test]
+    fn test_composite_key() {
+        assert_eq!(composite_key(&["page", "de"]), "page\x00de");
+        assert_eq!(composite_key(&["page"]), "page");
+        assert_ne!(composite_key(&["a", "bc"]), composite_key(&["ab", "c"]));
+    }
+
+    #[test]
+    fn test_build_composite_index() {
+        let mut index = IndexBuilder::new(IndexConfig::composite(vec![
+            "namespace".into(),
+            "language".into(),
+        ]))
+        .build_composite_index()
+        .expect("Build composite index");
+        assert_eq!(index.backend_type(), "hashmap");
+
+        index
+            .insert(composite_key(&["page", "de"]), vec![1, 4])
+            .expect("Insert");
+        assert_eq!(
+            index.get(&composite_key(&["page", "de"])).expect("Get"),
+            Some(vec![1, 4])
+        );
+
+        let temp_dir = TempDir::new().expect("Create temp dir");
+        let mut config = IndexConfig::composite(vec!["namespace".into(), "language".into()]);
+        config.backend = IndexBackend::BTree;
+        config.persist_path = Some(temp_dir.path().to_str().unwrap().to_string());
+        let index = IndexBuilder::new(config)
+            .build_composite_index()
+            .expect("Build B+-Tree composite index");
+        assert_eq!(index.backend_type(), "btree");
+        assert!(temp_dir.path().join("namespace+language.btree").exists());
+
+        let single = IndexConfig::composite(vec!["namespace".into()]);
+        assert!(IndexBuilder::new(single).build_composite_index().is_err());
+    }
+
+    fn partial_rows() -> Vec<HashMap<String, String>> {
+        [("a", "prod"), ("b", "dev"), ("c", "prod"), ("a", "prod")]
+            .iter()
+            .map(|(key, env)| {
+                HashMap::from([
+                    ("key".to_string(), key.to_string()),
+                    ("env".to_string(), env.to_string()),
+                ])
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_partial_config() {
+        let predicate = parse_condition("env = 'prod'").unwrap();
+        let config = IndexConfig::partial("key".into(), predicate.clone());
+        assert_eq!(config.columns, vec!["key".to_string()]);
+        assert_eq!(config.predicate, Some(predicate));
+
+        // Predicate is stored as ReedQL text
+        let toml_str = toml::to_string(&config).expect("Serialize to TOML");
+        assert!(toml_str.contains("predicate = \"env = 'prod'\""));
+        let parsed: IndexConfig = toml::from_str(&toml_str).expect("Deserialize from TOML");
+        assert_eq!(parsed.predicate, config.predicate);
+
+        assert!(!toml::to_string(&IndexConfig::default())
+            .unwrap()
+            .contains("predicate"));
+        assert!(toml::from_str::<IndexConfig>("predicate = \"env = \"").is_err());
+    }
+
+    #[test]
+    fn test_build_partial_index() {
+        let rows = partial_rows();
+        let predicate = parse_condition("env = 'prod'").unwrap();
+
+        let index = IndexBuilder::new(IndexConfig::partial("key".into(), predicate.clone()))
+            .build(&rows)
+            .expect("Build partial index");
+        assert_eq!(index.backend_type(), "hashmap");
+        assert_eq!(index.get(&"a".to_string()).unwrap(), Some(vec![0, 3]));
+        assert_eq!(index.get(&"b".to_string()).unwrap(), None);
+        assert_eq!(index.get(&"c".to_string()).unwrap(), Some(vec![2]));
+
+        let temp_dir = TempDir::new().expect("Create temp dir");
+        let mut config = IndexConfig::partial("key".into(), predicate);
+        config.backend = IndexBackend::BTree;
+        config.persist_path = Some(temp_dir.path().to_str().unwrap().to_string());
+        let index = IndexBuilder::new(config)
+            .build(&rows)
+            .expect("Build B+-Tree partial index");
+        assert_eq!(index.backend_type(), "btree");
+        assert_eq!(index.get(&"b".to_string()).unwrap(), None);
+        assert_eq!(index.get(&"c".to_string()).unwrap(), Some(vec![2]));
+        assert!(temp_dir.path().join("key.btree").exists());
+
+        // Without predicate every row is indexed
+        let full = IndexConfig::composite(vec!["key".into()]);
+        let index = IndexBuilder::new(full).build(&rows).unwrap();
+        assert_eq!(index.get(&"b".to_string()).unwrap(), Some(vec![1]));
+
+        assert!(IndexBuilder::new(IndexConfig::default())
+            .build(&rows)
+            .is_err());
+    }
+
+    #[test]
+    fn test_build_covering_index() {
+        let rows = partial_rows();
+        let config = IndexConfig::covering("key".into(), vec!["env".into(), "missing".into()]);
+        assert!(config.is_covering());
+        assert!(!IndexConfig::default().is_covering());
+
+        let index = IndexBuilder::new(config.clone())
+            .build_covering(&rows)
+            .expect("Build covering index");
+        let prod = HashMap::from([("env".to_string(), "prod".to_string())]);
+        assert_eq!(
+            index.get(&"a".to_string()).unwrap(),
+            Some(vec![(0, prod.clone()), (3, prod)])
+        );
+
+        let temp_dir = TempDir::new().expect("Create temp dir");
+        let mut btree = config;
+        btree.backend = IndexBackend::BTree;
+        btree.persist_path = Some(temp_dir.path().to_str().unwrap().to_string());
+        let index = IndexBuilder::new(btree)
+            .build_covering(&rows)
+            .expect("Build B+-Tree covering index");
+        assert_eq!(
+            index.get(&"b".to_string()).unwrap().unwrap()[0].1["env"],
+            "dev"
+        );
+        assert!(temp_dir.path().join("key.covering.btree").exists());
+
+        let plain = IndexConfig::composite(vec!["key".into()]);
+        assert!(IndexBuilder::new(plain).build_covering(&rows).is_err());
+    }
 }
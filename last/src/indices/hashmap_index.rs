@@ -3,8 +3,14 @@
 
 //! HashMap-based index implementation.
 //!
-//! Wraps `HashMap<K, V>` to implement the `Index<K, V>` trait for in-memory
-//! O(1) lookups with no persistence.
+//! Wraps `DashMap<K, V>` (a sharded concurrent HashMap) to implement the
+//! `Index<K, V>` trait for in-memory O(1) lookups with no persistence.
+//! Readers only take a shard read lock, so concurrent `get()` calls do not
+//! contend with each other.
+//!
+//! Once an index is fully built, `into_immutable()` converts it into an
+//! `ImmutableHashMapIndex` backed by a plain `HashMap`, which avoids the
+//! shard indirection for read-only snapshot queries.
 //!
 //! ## Performance
 //!
@@ -31,6 +37,7 @@
 
 use crate::error::{ReedError, ReedResult};
 use crate::indices::Index;
+use dashmap::DashMap;
 use std::collections::HashMap;
 use std::hash::Hash;
 
@@ -41,16 +48,16 @@ use std::hash::Hash;
 /// - `V`: Value type (must be Clone)
 ///
 /// ## Thread Safety
-/// - Not thread-safe (caller must synchronise)
-/// - Use external locking for concurrent access
+/// - Reads (`get`, `iter`) need only `&self` and take shard read locks
+/// - Writes through the `Index` trait still require `&mut self`
 #[derive(Debug)]
 pub struct HashMapIndex<K, V>
 where
     K: Clone + Eq + Hash + Ord,
     V: Clone,
 {
-    /// Internal HashMap storage.
-    map: HashMap<K, V>,
+    /// Internal concurrent map storage.
+    map: DashMap<K, V>,
 }
 
 impl<K, V> HashMapIndex<K, V>
@@ -74,7 +81,7 @@ where
     /// ```
     pub fn new() -> Self {
         Self {
-            map: HashMap::new(),
+            map: DashMap::new(),
         }
     }
 
@@ -98,7 +105,7 @@ where
     /// ```
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
-            map: HashMap::with_capacity(capacity),
+            map: DashMap::with_capacity(capacity),
         }
     }
 
@@ -132,6 +139,35 @@ where
     pub fn clear(&mut self) {
         self.map.clear();
     }
+
+    /// Convert into a read-only index backed by a plain `HashMap`.
+    ///
+    /// Intended for indices that are built once and then only queried:
+    /// lookups skip DashMap's shard selection and locking.
+    ///
+    /// ## Output
+    /// - `ImmutableHashMapIndex` with the same entries
+    ///
+    /// ## Performance
+    /// - O(n) move of all entries (no key/value clones)
+    ///
+    /// ## Example
+    /// ```rust
+    /// use reedbase_last::indices::hashmap_index::HashMapIndex;
+    /// use reedbase_last::indices::Index;
+    ///
+    /// let mut index = HashMapIndex::new();
+    /// index.insert("page".to_string(), vec![1, 2])?;
+    ///
+    /// let snapshot = index.into_immutable();
+    /// assert_eq!(snapshot.get(&"page".to_string())?, Some(vec![1, 2]));
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn into_immutable(self) -> ImmutableHashMapIndex<K, V> {
+        ImmutableHashMapIndex {
+            map: self.map.into_iter().collect(),
+        }
+    }
 }
 
 impl<K, V> Default for HashMapIndex<K, V>
@@ -178,7 +214,7 @@ where
     /// # Ok::<(), reedbase::ReedError>(())
     /// ```
     fn get(&self, key: &K) -> ReedResult<Option<V>> {
-        Ok(self.map.get(key).cloned())
+        Ok(self.map.get(key).map(|entry| entry.value().clone()))
     }
 
     /// Get all key-value pairs in range [start, end] (inclusive).
@@ -280,12 +316,29 @@ where
         Box::new(
             self.map
                 .iter()
-                .map(|(k, v)| (k.clone(), v.clone()))
+                .map(|entry| (entry.key().clone(), entry.value().clone()))
                 .collect::<Vec<_>>()
                 .into_iter(),
         )
     }
 
+    /// Clear the map and refill it with `entries`.
+    fn replace_all(&mut self, entries: Vec<(K, V)>) -> ReedResult<()> {
+        self.map.clear();
+        for (key, value) in entries {
+            self.map.insert(key, value);
+        }
+        Ok(())
+    }
+
+    /// Total number of keys in the index.
+    ///
+    /// ## Performance
+    /// - O(1) constant time
+    fn key_count(&self) -> ReedResult<usize> {
+        Ok(self.map.len())
+    }
+
     /// Backend type identifier.
     ///
     /// ## Returns
@@ -300,19 +353,19 @@ where
     /// Estimated memory usage in bytes.
     ///
     /// ## Returns
-    /// - HashMap size + allocated capacity overhead
+    /// - DashMap size + allocated capacity overhead
     ///
     /// ## Performance
-    /// - O(1) constant time
+    /// - O(s) where s = number of shards
     ///
     /// ## Note
-    /// This is an approximation based on HashMap capacity.
+    /// This is an approximation based on DashMap capacity.
     /// Actual memory usage depends on key/value sizes.
     fn memory_usage(&self) -> usize {
-        // Estimate: HashMap overhead + capacity * entry size
+        // Estimate: DashMap overhead + capacity * entry size
         let capacity = self.map.capacity();
         let entry_size = std::mem::size_of::<(K, V)>();
-        std::mem::size_of::<HashMap<K, V>>() + (capacity * entry_size)
+        std::mem::size_of::<DashMap<K, V>>() + (capacity * entry_size)
     }
 
     /// Disk usage in bytes (0 for in-memory backends).
@@ -326,3 +379,119 @@ where
         0
     }
 }
+
+/// Read-only HashMap index produced by `HashMapIndex::into_immutable()`.
+///
+/// ## Type Parameters
+/// - `K`: Key type (must be Clone + Eq + Hash + Ord)
+/// - `V`: Value type (must be Clone)
+///
+/// ## Thread Safety
+/// - Immutable after construction; safe to share across threads (`Arc`)
+///   without any locking
+#[derive(Debug)]
+pub struct ImmutableHashMapIndex<K, V>
+where
+    K: Clone + Eq + Hash + Ord,
+    V: Clone,
+{
+    /// Internal HashMap storage.
+    map: HashMap<K, V>,
+}
+
+impl<K, V> ImmutableHashMapIndex<K, V>
+where
+    K: Clone + Eq + Hash + Ord,
+    V: Clone,
+{
+    /// Get number of entries in index.
+    ///
+    /// ## Performance
+    /// - O(1) constant time
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Check if index is empty.
+    ///
+    /// ## Performance
+    /// - O(1) constant time
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+impl<K, V> Index<K, V> for ImmutableHashMapIndex<K, V>
+where
+    K: Clone + Eq + Hash + Ord + Send + Sync + std::fmt::Debug,
+    V: Clone + Send + Sync + std::fmt::Debug,
+{
+    /// Get value for exact key match.
+    ///
+    /// ## Performance
+    /// - O(1) average, no locking
+    fn get(&self, key: &K) -> ReedResult<Option<V>> {
+        Ok(self.map.get(key).cloned())
+    }
+
+    /// Range queries are not supported (unordered storage).
+    fn range(&self, _start: &K, _end: &K) -> ReedResult<Vec<(K, V)>> {
+        Err(ReedError::IndexOperationUnsupported {
+            operation: "range".to_string(),
+            backend: "hashmap".to_string(),
+            reason: "HashMap does not support ordered range queries".to_string(),
+        })
+    }
+
+    /// Always fails: the index is read-only.
+    fn insert(&mut self, _key: K, _value: V) -> ReedResult<()> {
+        Err(immutable_error("insert"))
+    }
+
+    /// Always fails: the index is read-only.
+    fn delete(&mut self, _key: &K) -> ReedResult<()> {
+        Err(immutable_error("delete"))
+    }
+
+    /// Iterate all key-value pairs (unordered).
+    ///
+    /// ## Performance
+    /// - O(n) where n = number of entries
+    fn iter(&self) -> Box<dyn Iterator<Item = (K, V)> + '_> {
+        Box::new(self.map.iter().map(|(k, v)| (k.clone(), v.clone())))
+    }
+
+    /// Total number of keys in the index.
+    ///
+    /// ## Performance
+    /// - O(1) constant time
+    fn key_count(&self) -> ReedResult<usize> {
+        Ok(self.map.len())
+    }
+
+    /// Backend type identifier (`"hashmap"`).
+    fn backend_type(&self) -> &'static str {
+        "hashmap"
+    }
+
+    /// Estimated memory usage in bytes (HashMap size + capacity overhead).
+    fn memory_usage(&self) -> usize {
+        let capacity = self.map.capacity();
+        let entry_size = std::mem::size_of::<(K, V)>();
+        std::mem::size_of::<HashMap<K, V>>() + (capacity * entry_size)
+    }
+
+    /// Disk usage in bytes (always 0).
+    fn disk_usage(&self) -> usize {
+        0
+    }
+}
+
+/// Error returned by write operations on `ImmutableHashMapIndex`.
+fn immutable_error(operation: &str) -> ReedError {
+    ReedError::IndexOperationUnsupported {
+        operation: operation.to_string(),
+        backend: "hashmap".to_string(),
+        reason: "Index is immutable (created via into_immutable)".to_string(),
+    }
+}
@@ -7,10 +7,11 @@
 
 use crate::error::ReedResult;
 use crate::indices::types::KeyIndex;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Trie node for hierarchical indexing.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct TrieNode {
     /// Row numbers at this exact path
     pub rows: Vec<usize>,
@@ -162,6 +163,16 @@ impl HierarchyTrie {
         self.root = TrieNode::default();
     }
 
+    /// Root node (used to persist the trie).
+    pub(crate) fn root(&self) -> &TrieNode {
+        &self.root
+    }
+
+    /// Replace the trie with persisted state.
+    pub(crate) fn set_root(&mut self, root: TrieNode) {
+        self.root = root;
+    }
+
     /// Get total number of nodes in trie.
     pub fn node_count(&self) -> usize {
         self.count_nodes(&self.root)
@@ -6,6 +6,7 @@
 //! Allows ReedBase to switch between HashMap, B+-Tree, or custom implementations
 //! without changing query logic.
 
+use crate::btree::TreeStatistics;
 use crate::error::ReedResult;
 use std::fmt::Debug;
 
@@ -86,6 +87,62 @@ pub trait Index<K, V>: Send + Sync + Debug {
     /// - B+-Tree: O(n), sorted order
     fn iter(&self) -> Box<dyn Iterator<Item = (K, V)> + '_>;
 
+    /// Replace every entry of the index with `entries`.
+    ///
+    /// ## Input
+    /// - `entries`: New contents, in strictly ascending key order
+    ///
+    /// ## Performance
+    /// - Default: deletes every key, then inserts every entry
+    /// - HashMap: O(n) clear and refill
+    /// - B+-Tree: O(n) bulk load, no per-key WAL writes
+    fn replace_all(&mut self, entries: Vec<(K, V)>) -> ReedResult<()> {
+        let keys: Vec<K> = self.iter().map(|(key, _)| key).collect();
+        for key in &keys {
+            self.delete(key)?;
+        }
+        for (key, value) in entries {
+            self.insert(key, value)?;
+        }
+        Ok(())
+    }
+
+    /// Count keys in range without materialising values.
+    ///
+    /// Uses the same bounds as `range()` for the given backend.
+    ///
+    /// ## Performance
+    /// - Default: falls back to `range().len()`
+    /// - B+-Tree: O(log n + m) key-only leaf scan
+    ///
+    /// ## Error Conditions
+    /// - `IndexOperationUnsupported`: Backend doesn't support range queries
+    fn count_keys_in_range(&self, start: &K, end: &K) -> ReedResult<usize> {
+        Ok(self.range(start, end)?.len())
+    }
+
+    /// Total number of keys in the index.
+    ///
+    /// ## Performance
+    /// - Default: falls back to `iter().count()`
+    /// - HashMap: O(1)
+    /// - B+-Tree: O(p) key-only scan where p = number of pages
+    fn key_count(&self) -> ReedResult<usize> {
+        Ok(self.iter().count())
+    }
+
+    /// Shape and fill factor of the underlying tree.
+    ///
+    /// ## Returns
+    /// - Default: `Ok(None)`, backend has no tree structure
+    /// - B+-Tree: height, node counts and leaf fill
+    ///
+    /// ## Performance
+    /// - B+-Tree: O(p) where p = number of pages
+    fn tree_statistics(&self) -> ReedResult<Option<TreeStatistics>> {
+        Ok(None)
+    }
+
     // Metadata methods
 
     /// Backend type identifier.
@@ -478,4 +478,338 @@ mod tests {
         let result = manager.query(&filter).unwrap();
         assert!(result.is_empty());
     }
+
+    #[test]
+    fn test_index_manager_warm() {
+        use crate::tables::Table;
+        use std::fs;
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let table_dir = temp.path().join("tables/text");
+        fs::create_dir_all(&table_dir).unwrap();
+        fs::write(
+            table_dir.join("current.csv"),
+            "key|value\npage.title<de>|Titel\npage.title<en>|Title\nblog.post.intro<de>|Intro\n",
+        )
+        .unwrap();
+
+        // Only the table's own B+-Tree files are pre-loaded
+        let indices_dir = temp.path().join("indices");
+        fs::create_dir_all(&indices_dir).unwrap();
+        fs::write(indices_dir.join("text.key.btree"), vec![0u8; 4096]).unwrap();
+        fs::write(indices_dir.join("text.key.wal"), vec![0u8; 16]).unwrap();
+        fs::write(indices_dir.join("routes.key.btree"), vec![0u8; 4096]).unwrap();
+
+        let mut manager = IndexManager::new();
+        let report = manager.warm(&Table::new(temp.path(), "text")).unwrap();
+
+        assert_eq!(report.table, "text");
+        assert_eq!(report.rows_indexed, 4); // Header row included
+        assert_eq!(report.btree_files, 1);
+        assert_eq!(report.btree_bytes, 4096);
+
+        let filter = QueryFilter::new()
+            .with_namespace("page")
+            .with_language("de");
+        assert_eq!(manager.query(&filter).unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn test_index_manager_warm_missing_table() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let mut manager = IndexManager::new();
+
+        let result = manager.warm(&crate::tables::Table::new(temp.path(), "missing"));
+        assert!(matches!(
+            result,
+            Err(crate::error::ReedError::TableNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_index_manager_suggest_indices() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let table_dir = temp.path().join("tables/users");
+        std::fs::create_dir_all(&table_dir).unwrap();
+        let mut content = String::from("key|email|country|note\n");
+        for i in 0..200 {
+            content.push_str(&format!(
+                "u{}|user{}@example.com|{}|\n",
+                i,
+                i,
+                ["DE", "AT"][i % 2]
+            ));
+        }
+        std::fs::write(table_dir.join("current.csv"), content).unwrap();
+
+        let table = crate::tables::Table::new(temp.path(), "users");
+
+        // Selective columns only; key, low-cardinality and empty columns are skipped
+        assert_eq!(
+            IndexManager::suggest_indices(&table, 50).unwrap(),
+            vec!["email".to_string()]
+        );
+        assert_eq!(
+            IndexManager::suggest_indices(&table, usize::MAX).unwrap(),
+            vec!["email".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_index_manager_composite() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let table_dir = temp.path().join("tables/users");
+        std::fs::create_dir_all(&table_dir).unwrap();
+        std::fs::write(
+            table_dir.join("current.csv"),
+            "key|country|city\nu1|DE|Berlin\nu2|DE|Hamburg\nu3|AT|Berlin\nu4|DE|Berlin\n",
+        )
+        .unwrap();
+
+        let mut manager = IndexManager::new();
+        manager
+            .build_composite(temp.path(), "users", &["country", "city"])
+            .unwrap();
+        manager
+            .build_composite(temp.path(), "users", &["key", "city"])
+            .unwrap();
+
+        let columns = ["country", "city"];
+        assert_eq!(
+            manager
+                .query_composite(&columns, &["DE", "Berlin"])
+                .unwrap(),
+            vec![1, 4]
+        );
+        assert_eq!(
+            manager
+                .query_composite(&columns, &["AT", "Berlin"])
+                .unwrap(),
+            vec![3]
+        );
+        assert!(manager
+            .query_composite(&columns, &["AT", "Hamburg"])
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            manager
+                .query_composite(&["key", "city"], &["u2", "Hamburg"])
+                .unwrap(),
+            vec![2]
+        );
+
+        // Column order is part of the index
+        assert!(matches!(
+            manager.query_composite(&["city", "country"], &["Berlin", "DE"]),
+            Err(crate::error::ReedError::IndexNotFound { .. })
+        ));
+        assert!(manager.query_composite(&columns, &["DE"]).is_err());
+        assert!(matches!(
+            manager.build_composite(temp.path(), "users", &["country", "zip"]),
+            Err(crate::error::ReedError::ColumnNotFound { .. })
+        ));
+        assert!(manager
+            .build_composite(temp.path(), "users", &["country"])
+            .is_err());
+
+        manager.remove(4).unwrap();
+        assert_eq!(
+            manager
+                .query_composite(&columns, &["DE", "Berlin"])
+                .unwrap(),
+            vec![1]
+        );
+
+        let stats = manager.stats();
+        assert_eq!(
+            stats.composites,
+            vec![
+                vec!["country".to_string(), "city".to_string()],
+                vec!["key".to_string(), "city".to_string()]
+            ]
+        );
+        assert!(stats
+            .to_string()
+            .ends_with("Composite index: (country, city)\nComposite index: (key, city)"));
+
+        // Persisted with the other indices and rebuilt when stale
+        manager.persist(temp.path(), "users").unwrap();
+        let loaded = IndexManager::load(temp.path(), "users").unwrap();
+        assert_eq!(
+            loaded.query_composite(&columns, &["DE", "Berlin"]).unwrap(),
+            vec![1]
+        );
+        std::fs::write(
+            table_dir.join("current.csv"),
+            "key|country|city\nu5|AT|Berlin\n",
+        )
+        .unwrap();
+        let rebuilt = IndexManager::load(temp.path(), "users").unwrap();
+        assert_eq!(
+            rebuilt
+                .query_composite(&columns, &["AT", "Berlin"])
+                .unwrap(),
+            vec![1]
+        );
+        assert_eq!(rebuilt.stats().composites.len(), 2);
+    }
+
+    fn persist_test_table(temp: &tempfile::TempDir) -> std::path::PathBuf {
+        let table_dir = temp.path().join("tables/text");
+        std::fs::create_dir_all(&table_dir).unwrap();
+        std::fs::write(
+            table_dir.join("current.csv"),
+            "key|value\npage.title<de>|Titel\npage.title<en>|Title\nblog.post.intro<de>|Intro\n",
+        )
+        .unwrap();
+        table_dir
+    }
+
+    #[test]
+    fn test_index_manager_persist_and_load() {
+        let temp = tempfile::TempDir::new().unwrap();
+        persist_test_table(&temp);
+
+        let mut built = IndexManager::new();
+        built.build(temp.path(), "text").unwrap();
+        built.persist(temp.path(), "text").unwrap();
+
+        let dir = temp.path().join("indices/text");
+        for file in [
+            "namespace",
+            "language",
+            "environment",
+            "season",
+            "variant",
+            "hierarchy",
+            "composites",
+            "version",
+        ] {
+            assert!(dir.join(format!("{}.bin", file)).exists(), "{}", file);
+        }
+
+        // Loaded from disk: the files are used, not rebuilt
+        let modified = std::fs::metadata(dir.join("version.bin"))
+            .unwrap()
+            .modified()
+            .unwrap();
+        let loaded = IndexManager::load(temp.path(), "text").unwrap();
+        assert_eq!(
+            std::fs::metadata(dir.join("version.bin"))
+                .unwrap()
+                .modified()
+                .unwrap(),
+            modified
+        );
+
+        let filters = [
+            QueryFilter::new()
+                .with_namespace("page")
+                .with_language("de"),
+            QueryFilter::new().with_language("de"),
+            QueryFilter::new().with_hierarchy(vec!["blog".into(), "*".into()]),
+            QueryFilter::new().with_hierarchy(vec!["page".into(), "title".into()]),
+        ];
+        for filter in &filters {
+            assert_eq!(
+                loaded.query(filter).unwrap(),
+                built.query(filter).unwrap(),
+                "{:?}",
+                filter
+            );
+        }
+        assert_eq!(loaded.stats().total_keys, 4); // Header row included
+        assert_eq!(loaded.stats().trie_nodes, built.stats().trie_nodes);
+    }
+
+    #[test]
+    fn test_index_manager_load_stale() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let table_dir = persist_test_table(&temp);
+
+        let mut manager = IndexManager::new();
+        manager.build(temp.path(), "text").unwrap();
+        manager.persist(temp.path(), "text").unwrap();
+        let stale_version = std::fs::read(temp.path().join("indices/text/version.bin")).unwrap();
+
+        // Content changed after persist: load rebuilds from the CSV
+        std::fs::write(
+            table_dir.join("current.csv"),
+            "key|value\napi.status<fr>|Statut\n",
+        )
+        .unwrap();
+        let loaded = IndexManager::load(temp.path(), "text").unwrap();
+        let french = QueryFilter::new().with_language("fr");
+        assert_eq!(loaded.query(&french).unwrap(), vec![1]);
+        assert!(loaded
+            .query(&QueryFilter::new().with_namespace("page"))
+            .unwrap()
+            .is_empty());
+
+        // ...and persists the fresh state
+        let dir = temp.path().join("indices/text");
+        assert_ne!(
+            std::fs::read(dir.join("version.bin")).unwrap(),
+            stale_version
+        );
+
+        // Corrupt files are rebuilt as well
+        std::fs::write(dir.join("hierarchy.bin"), b"corrupt").unwrap();
+        let rebuilt = IndexManager::load(temp.path(), "text").unwrap();
+        assert_eq!(rebuilt.query(&french).unwrap(), vec![1]);
+        assert_eq!(
+            rebuilt
+                .query(&QueryFilter::new().with_hierarchy(vec!["api".into(), "*".into()]))
+                .unwrap(),
+            vec![1]
+        );
+
+        assert!(matches!(
+            IndexManager::load(temp.path(), "missing"),
+            Err(crate::error::ReedError::TableNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_hashmap_index_concurrent_readers() {
+        use std::sync::Arc;
+
+        let mut index = HashMapIndex::new();
+        for i in 0..1000 {
+            Index::insert(&mut index, format!("key{}", i), vec![i]).unwrap();
+        }
+        let index = Arc::new(index);
+
+        let handles: Vec<_> = (0..8)
+            .map(|t| {
+                let index = Arc::clone(&index);
+                std::thread::spawn(move || {
+                    for i in (t..1000).step_by(8) {
+                        assert_eq!(index.get(&format!("key{}", i)).unwrap(), Some(vec![i]));
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_hashmap_index_into_immutable() {
+        let mut index = HashMapIndex::new();
+        Index::insert(&mut index, "page".to_string(), vec![1, 2]).unwrap();
+        Index::insert(&mut index, "api".to_string(), vec![3]).unwrap();
+
+        let mut snapshot = index.into_immutable();
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot.get(&"page".to_string()).unwrap(), Some(vec![1, 2]));
+        assert_eq!(snapshot.get(&"missing".to_string()).unwrap(), None);
+        assert_eq!(snapshot.key_count().unwrap(), 2);
+
+        assert!(snapshot.insert("new".to_string(), vec![4]).is_err());
+        assert!(snapshot.delete(&"page".to_string()).is_err());
+        assert_eq!(snapshot.iter().count(), 2);
+    }
 }
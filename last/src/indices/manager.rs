@@ -4,14 +4,37 @@
 //! Index manager coordinating all indices and handling combined queries.
 
 use crate::error::{ReedError, ReedResult};
-use crate::indices::hierarchy::HierarchyTrie;
+use crate::indices::builder::composite_key;
+use crate::indices::hierarchy::{HierarchyTrie, TrieNode};
 use crate::indices::modifier::ModifierIndex;
 use crate::indices::namespace::NamespaceIndex;
 use crate::indices::types::{KeyIndex, Modifiers, QueryFilter};
 use crate::schema::rbks;
-use crate::tables::Table;
-use std::collections::HashSet;
-use std::path::Path;
+use crate::tables::{Delimiter, Table};
+use memmap2::Mmap;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+/// Format version of the files written by `IndexManager::persist()`.
+const PERSIST_FORMAT: u32 = 1;
+
+/// Distinct sampled values a column needs for `suggest_indices()`: an
+/// equality lookup then matches about a tenth of the rows or fewer.
+const SUGGEST_MIN_DISTINCT: usize = 10;
+
+/// Seed of the row sample drawn by `suggest_indices()`.
+const SUGGEST_SAMPLE_SEED: u64 = 0x5eed;
+
+/// Contents of `indices/{table}/version.bin`.
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedVersion {
+    format: u32,
+    version_hash: u64,
+}
 
 /// Index manager coordinating all indices.
 pub struct IndexManager {
@@ -21,6 +44,8 @@ pub struct IndexManager {
     season: ModifierIndex,
     variant: ModifierIndex,
     hierarchy: HierarchyTrie,
+    /// Composite indices: columns → composite key → rows
+    composites: HashMap<Vec<String>, HashMap<String, Vec<usize>>>,
 }
 
 impl IndexManager {
@@ -33,6 +58,7 @@ impl IndexManager {
             season: ModifierIndex::season(),
             variant: ModifierIndex::variant(),
             hierarchy: HierarchyTrie::new(),
+            composites: HashMap::new(),
         }
     }
 
@@ -46,14 +72,442 @@ impl IndexManager {
     /// - O(n * d) where n = keys, d = average depth
     /// - < 50ms for 10,000 keys
     pub fn build(&mut self, base_path: &Path, table_name: &str) -> ReedResult<()> {
-        let keys = self.parse_keys(base_path, table_name)?;
+        let table = Table::new(base_path, table_name);
+        let content = table.read_current().map_err(|_| ReedError::TableNotFound {
+            name: table_name.to_string(),
+        })?;
+        let (keys, _) = self.parse_keys(&content, table.delimiter()?)?;
+
+        self.build_from_keys(&keys)
+    }
+
+    /// Pre-load all indices of a table before serving traffic.
+    ///
+    /// Reads `current.csv` once and builds every in-memory index from it,
+    /// then advises the OS to page in the table's B+-Tree index files
+    /// (`indices/{table}.*.btree`) so the first query does not pay for
+    /// cold disk reads.
+    ///
+    /// ## Input
+    /// - `table` - Table to warm
+    ///
+    /// ## Output
+    /// - `WarmReport` with rows indexed, B+-Tree files pre-loaded and time taken
+    ///
+    /// ## Performance
+    /// - Same as `build()` plus one `madvise(WILLNEED)` per B+-Tree file
+    /// - Page-in happens asynchronously in the kernel
+    ///
+    /// ## Error Conditions
+    /// - `TableNotFound`: Table has no current.csv
+    /// - `IoError`: Cannot read the indices directory or map a B+-Tree file
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::indices::IndexManager;
+    /// use reedbase_last::tables::Table;
+    /// use std::path::Path;
+    ///
+    /// let mut manager = IndexManager::new();
+    /// let report = manager.warm(&Table::new(Path::new(".reed"), "text"))?;
+    /// println!("{} rows in {}μs", report.rows_indexed, report.duration_us);
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn warm(&mut self, table: &Table) -> ReedResult<WarmReport> {
+        let start = Instant::now();
+
+        let content = table.read_current().map_err(|_| ReedError::TableNotFound {
+            name: table.name().to_string(),
+        })?;
+        let (keys, rows_indexed) = self.parse_keys(&content, table.delimiter()?)?;
+        self.build_from_keys(&keys)?;
+
+        let mut btree_files = 0;
+        let mut btree_bytes = 0;
+        for path in btree_index_files(table)? {
+            btree_bytes += preload_file(&path)?;
+            btree_files += 1;
+        }
+
+        Ok(WarmReport {
+            table: table.name().to_string(),
+            rows_indexed,
+            btree_files,
+            btree_bytes,
+            duration_us: start.elapsed().as_micros() as u64,
+        })
+    }
 
-        self.namespace.build(&keys)?;
-        self.language.build(&keys)?;
-        self.environment.build(&keys)?;
-        self.season.build(&keys)?;
-        self.variant.build(&keys)?;
-        self.hierarchy.build(&keys)?;
+    /// Suggest columns worth a secondary index, judged from a row sample.
+    ///
+    /// Draws up to `sample_size` rows with `Table::sample()` and suggests
+    /// every column with at least `SUGGEST_MIN_DISTINCT` distinct non-empty
+    /// values in the sample. The key column is left out: the key indices
+    /// of this manager already cover it.
+    ///
+    /// ## Input
+    /// - `table` - Table to inspect
+    /// - `sample_size` - Rows to sample (every row of smaller tables)
+    ///
+    /// ## Output
+    /// - Column names in header order
+    ///
+    /// ## Performance
+    /// - One streaming pass over current.csv, memory bounded by the sample
+    ///
+    /// ## Error Conditions
+    /// - `TableNotFound`: Table has no current.csv
+    /// - `InvalidCsv`: Header or a sampled row cannot be parsed
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::indices::IndexManager;
+    /// use reedbase_last::tables::Table;
+    /// use std::path::Path;
+    ///
+    /// let table = Table::new(Path::new(".reed"), "users");
+    /// for column in IndexManager::suggest_indices(&table, 10_000)? {
+    ///     println!("CREATE INDEX ON users ({})", column);
+    /// }
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn suggest_indices(table: &Table, sample_size: usize) -> ReedResult<Vec<String>> {
+        let header = table.stream_rows()?.columns().to_vec();
+        let rows = table.sample(sample_size, SUGGEST_SAMPLE_SEED)?;
+
+        Ok(header
+            .into_iter()
+            .skip(1)
+            .filter(|column| {
+                let distinct: HashSet<&str> = rows
+                    .iter()
+                    .filter_map(|row| row.get(column))
+                    .filter(|value| !value.is_empty())
+                    .collect();
+                distinct.len() >= SUGGEST_MIN_DISTINCT
+            })
+            .collect())
+    }
+
+    /// Build a composite index over several columns of a table.
+    ///
+    /// Keys are `composite_key()` of the row's values in `columns` order;
+    /// row numbers match those of the other indices. Rebuilding an existing
+    /// composite index replaces it.
+    ///
+    /// ## Input
+    /// - `base_path` - ReedBase directory path
+    /// - `table_name` - Table to index
+    /// - `columns` - Column names in key order (at least two)
+    ///
+    /// ## Performance
+    /// - O(n × c) where n = rows, c = columns
+    ///
+    /// ## Error Conditions
+    /// - `TableNotFound`: Table has no current.csv
+    /// - `InvalidCsv`: Table has no header row
+    /// - `ColumnNotFound`: A column is not in the header
+    /// - `ParseError`: Fewer than two columns
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::indices::IndexManager;
+    /// use std::path::Path;
+    ///
+    /// let mut manager = IndexManager::new();
+    /// manager.build_composite(Path::new(".reed"), "users", &["country", "city"])?;
+    /// let rows = manager.query_composite(&["country", "city"], &["DE", "Berlin"])?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn build_composite(
+        &mut self,
+        base_path: &Path,
+        table_name: &str,
+        columns: &[&str],
+    ) -> ReedResult<()> {
+        if columns.len() < 2 {
+            return Err(ReedError::ParseError {
+                reason: "Composite index requires at least two columns".to_string(),
+            });
+        }
+
+        let table = Table::new(base_path, table_name);
+        let content = table.read_current().map_err(|_| ReedError::TableNotFound {
+            name: table_name.to_string(),
+        })?;
+        let rows = crate::tables::parse_csv(&content, table.delimiter()?)?;
+        let header = rows.first().ok_or_else(|| ReedError::InvalidCsv {
+            reason: "Missing header row".to_string(),
+            line: 1,
+        })?;
+        let header: Vec<&str> = std::iter::once(header.key.as_str())
+            .chain(header.values.iter().map(String::as_str))
+            .collect();
+
+        let positions = columns
+            .iter()
+            .map(|column| {
+                header
+                    .iter()
+                    .position(|name| name == column)
+                    .ok_or_else(|| ReedError::ColumnNotFound {
+                        table: table_name.to_string(),
+                        column: column.to_string(),
+                    })
+            })
+            .collect::<ReedResult<Vec<usize>>>()?;
+
+        let mut index: HashMap<String, Vec<usize>> = HashMap::new();
+        for (row_num, row) in rows.iter().enumerate().skip(1) {
+            let values: Vec<&str> = positions
+                .iter()
+                .map(|&i| match i {
+                    0 => row.key.as_str(),
+                    _ => row.values.get(i - 1).map(String::as_str).unwrap_or(""),
+                })
+                .collect();
+            index
+                .entry(composite_key(&values))
+                .or_default()
+                .push(row_num);
+        }
+
+        let columns = columns.iter().map(|c| c.to_string()).collect();
+        self.composites.insert(columns, index);
+
+        Ok(())
+    }
+
+    /// Look up rows by the values of all columns of a composite index.
+    ///
+    /// ## Input
+    /// - `columns` - Columns of an index built with `build_composite()`, in
+    ///   the same order
+    /// - `values` - One value per column
+    ///
+    /// ## Output
+    /// - Sorted row numbers whose values match exactly
+    ///
+    /// ## Performance
+    /// - O(1) HashMap lookup
+    ///
+    /// ## Error Conditions
+    /// - `IndexNotFound`: No composite index on these columns
+    /// - `ParseError`: `values` and `columns` differ in length
+    pub fn query_composite(&self, columns: &[&str], values: &[&str]) -> ReedResult<Vec<usize>> {
+        if columns.len() != values.len() {
+            return Err(ReedError::ParseError {
+                reason: format!(
+                    "Composite query has {} columns but {} values",
+                    columns.len(),
+                    values.len()
+                ),
+            });
+        }
+
+        let columns: Vec<String> = columns.iter().map(|c| c.to_string()).collect();
+        let index = self
+            .composites
+            .get(&columns)
+            .ok_or_else(|| ReedError::IndexNotFound {
+                name: columns.join(","),
+            })?;
+
+        let mut rows = index
+            .get(&composite_key(values))
+            .cloned()
+            .unwrap_or_default();
+        rows.sort_unstable();
+
+        Ok(rows)
+    }
+
+    /// Write all indices to `indices/{table}/` for a fast cold start.
+    ///
+    /// Stores one bincode file per index (composite indices share
+    /// `composites.bin`) plus `version.bin`, which holds the
+    /// CRC32 of the table's current.csv. Call this right after `build()` so
+    /// the hash describes the content the indices were built from.
+    ///
+    /// ## Input
+    /// - `base_path` - ReedBase directory path
+    /// - `table` - Table the indices belong to
+    ///
+    /// ## Performance
+    /// - O(n) serialisation, one read of current.csv for the hash
+    ///
+    /// ## Error Conditions
+    /// - `TableNotFound`: Table has no current.csv
+    /// - `SerializationError`: Cannot encode an index
+    /// - `IoError`: Cannot write the index files
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::indices::IndexManager;
+    /// use std::path::Path;
+    ///
+    /// let mut manager = IndexManager::new();
+    /// manager.build(Path::new(".reed"), "text")?;
+    /// manager.persist(Path::new(".reed"), "text")?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn persist(&self, base_path: &Path, table: &str) -> ReedResult<()> {
+        let content =
+            Table::new(base_path, table)
+                .read_current()
+                .map_err(|_| ReedError::TableNotFound {
+                    name: table.to_string(),
+                })?;
+
+        self.write_state(&persisted_dir(base_path, table), version_hash(&content))
+    }
+
+    /// Load indices written by `persist()`, rebuilding them if stale.
+    ///
+    /// The persisted state is used only if `version.bin` matches the CRC32 of
+    /// the table's current.csv. Otherwise (missing, stale or unreadable
+    /// files) the indices, including previously persisted composite indices,
+    /// are built from the CSV and persisted again.
+    ///
+    /// ## Input
+    /// - `base_path` - ReedBase directory path
+    /// - `table` - Table to load indices for
+    ///
+    /// ## Output
+    /// - `IndexManager` matching the table's current content
+    ///
+    /// ## Performance
+    /// - < 5ms for 10,000 keys when the persisted indices are current
+    /// - Same as `build()` otherwise
+    ///
+    /// ## Error Conditions
+    /// - `TableNotFound`: Table has no current.csv
+    /// - `InvalidCsv`: Rebuild failed to parse the table
+    /// - `ColumnNotFound`: A persisted composite index names a dropped column
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::indices::{IndexManager, QueryFilter};
+    /// use std::path::Path;
+    ///
+    /// let manager = IndexManager::load(Path::new(".reed"), "text")?;
+    /// let rows = manager.query(&QueryFilter::new().with_language("de"))?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn load(base_path: &Path, table: &str) -> ReedResult<Self> {
+        let source = Table::new(base_path, table);
+        let content = source
+            .read_current()
+            .map_err(|_| ReedError::TableNotFound {
+                name: table.to_string(),
+            })?;
+        let hash = version_hash(&content);
+        let dir = persisted_dir(base_path, table);
+
+        let mut manager = Self::new();
+        if manager.read_state(&dir, hash).is_none() {
+            let (keys, _) = manager.parse_keys(&content, source.delimiter()?)?;
+            manager.build_from_keys(&keys)?;
+
+            // Rebuild the composite indices that were persisted before
+            let composites: HashMap<Vec<String>, HashMap<String, Vec<usize>>> =
+                read_bincode(&dir.join("composites.bin")).unwrap_or_default();
+            for columns in composites.keys() {
+                let columns: Vec<&str> = columns.iter().map(String::as_str).collect();
+                manager.build_composite(base_path, table, &columns)?;
+            }
+
+            // Best effort: indices work without being persisted (e.g. read-only base path)
+            let _ = manager.write_state(&dir, hash);
+        }
+
+        Ok(manager)
+    }
+
+    /// Write every index, then the version file that makes them valid.
+    fn write_state(&self, dir: &Path, hash: u64) -> ReedResult<()> {
+        fs::create_dir_all(dir).map_err(|e| ReedError::IoError {
+            operation: "create_indices_dir".to_string(),
+            reason: format!("{}: {}", dir.display(), e),
+        })?;
+
+        // Invalidate first so a crash half-way leaves state that load() rejects
+        let version_path = dir.join("version.bin");
+        if version_path.exists() {
+            fs::remove_file(&version_path).map_err(|e| ReedError::IoError {
+                operation: "remove_index_version".to_string(),
+                reason: format!("{}: {}", version_path.display(), e),
+            })?;
+        }
+
+        write_bincode(&dir.join("namespace.bin"), self.namespace.map())?;
+        for (name, index) in self.modifier_indices() {
+            write_bincode(&dir.join(format!("{}.bin", name)), index.map())?;
+        }
+        write_bincode(&dir.join("hierarchy.bin"), self.hierarchy.root())?;
+        write_bincode(&dir.join("composites.bin"), &self.composites)?;
+        write_bincode(
+            &version_path,
+            &PersistedVersion {
+                format: PERSIST_FORMAT,
+                version_hash: hash,
+            },
+        )
+    }
+
+    /// Restore every index if the persisted state matches `hash`.
+    ///
+    /// Returns `None` if any file is missing, unreadable or stale.
+    fn read_state(&mut self, dir: &Path, hash: u64) -> Option<()> {
+        let version: PersistedVersion = read_bincode(&dir.join("version.bin"))?;
+        if version.format != PERSIST_FORMAT || version.version_hash != hash {
+            return None;
+        }
+
+        let namespace: HashMap<String, Vec<usize>> = read_bincode(&dir.join("namespace.bin"))?;
+        let mut modifiers = Vec::with_capacity(4);
+        for (name, _) in self.modifier_indices() {
+            let map: HashMap<String, Vec<usize>> =
+                read_bincode(&dir.join(format!("{}.bin", name)))?;
+            modifiers.push(map);
+        }
+        let root: TrieNode = read_bincode(&dir.join("hierarchy.bin"))?;
+        let composites = read_bincode(&dir.join("composites.bin"))?;
+
+        self.namespace.set_map(namespace);
+        let indices = [
+            &mut self.language,
+            &mut self.environment,
+            &mut self.season,
+            &mut self.variant,
+        ];
+        for (index, map) in indices.into_iter().zip(modifiers) {
+            index.set_map(map);
+        }
+        self.hierarchy.set_root(root);
+        self.composites = composites;
+
+        Some(())
+    }
+
+    /// Modifier indices with their file names.
+    fn modifier_indices(&self) -> [(&'static str, &ModifierIndex); 4] {
+        [
+            ("language", &self.language),
+            ("environment", &self.environment),
+            ("season", &self.season),
+            ("variant", &self.variant),
+        ]
+    }
+
+    /// Build every index from parsed keys.
+    fn build_from_keys(&mut self, keys: &[KeyIndex]) -> ReedResult<()> {
+        self.namespace.build(keys)?;
+        self.language.build(keys)?;
+        self.environment.build(keys)?;
+        self.season.build(keys)?;
+        self.variant.build(keys)?;
+        self.hierarchy.build(keys)?;
 
         Ok(())
     }
@@ -166,6 +620,11 @@ impl IndexManager {
         self.season.remove(row)?;
         self.variant.remove(row)?;
         self.hierarchy.remove(row)?;
+        for index in self.composites.values_mut() {
+            for rows in index.values_mut() {
+                rows.retain(|&r| r != row);
+            }
+        }
 
         Ok(())
     }
@@ -178,6 +637,17 @@ impl IndexManager {
             + self.season.memory_usage()
             + self.variant.memory_usage()
             + self.hierarchy.memory_usage()
+            + self
+                .composites
+                .iter()
+                .map(|(columns, index)| {
+                    columns.iter().map(|c| c.len() + 24).sum::<usize>()
+                        + index
+                            .iter()
+                            .map(|(key, rows)| key.len() + 24 + rows.len() * 8 + 24)
+                            .sum::<usize>()
+                })
+                .sum::<usize>()
     }
 
     /// Clear all indices.
@@ -188,16 +658,18 @@ impl IndexManager {
         self.season.clear();
         self.variant.clear();
         self.hierarchy.clear();
+        self.composites.clear();
     }
 
-    /// Parse all keys from table into KeyIndex structures.
-    fn parse_keys(&self, base_path: &Path, table_name: &str) -> ReedResult<Vec<KeyIndex>> {
-        let table = Table::new(base_path, table_name);
-        let content = table.read_current().map_err(|_| ReedError::TableNotFound {
-            name: table_name.to_string(),
-        })?;
-
-        let rows = crate::tables::parse_csv(&content)?;
+    /// Parse all keys from table content into KeyIndex structures.
+    ///
+    /// Also returns the number of CSV rows read.
+    fn parse_keys(
+        &self,
+        content: &[u8],
+        delimiter: Delimiter,
+    ) -> ReedResult<(Vec<KeyIndex>, usize)> {
+        let rows = crate::tables::parse_csv(content, delimiter)?;
         let mut keys = Vec::new();
 
         for (row_num, row) in rows.iter().enumerate() {
@@ -216,7 +688,7 @@ impl IndexManager {
             }
         }
 
-        Ok(keys)
+        Ok((keys, rows.len()))
     }
 
     /// Parse single key into KeyIndex.
@@ -252,6 +724,9 @@ impl IndexManager {
 
     /// Get index statistics.
     pub fn stats(&self) -> IndexStats {
+        let mut composites: Vec<Vec<String>> = self.composites.keys().cloned().collect();
+        composites.sort();
+
         IndexStats {
             total_keys: self.namespace.key_count(),
             namespaces: self.namespace.namespace_count(),
@@ -261,6 +736,7 @@ impl IndexManager {
             variants: self.variant.value_count(),
             trie_nodes: self.hierarchy.node_count(),
             memory_bytes: self.memory_usage(),
+            composites,
         }
     }
 }
@@ -271,6 +747,25 @@ impl Default for IndexManager {
     }
 }
 
+/// Result of `IndexManager::warm()`.
+#[derive(Debug, Clone)]
+pub struct WarmReport {
+    /// Table that was warmed.
+    pub table: String,
+
+    /// CSV rows read from current.csv.
+    pub rows_indexed: usize,
+
+    /// B+-Tree index files advised for page-in.
+    pub btree_files: usize,
+
+    /// Total size of those files in bytes.
+    pub btree_bytes: u64,
+
+    /// Time taken in microseconds.
+    pub duration_us: u64,
+}
+
 /// Index statistics.
 #[derive(Debug, Clone)]
 pub struct IndexStats {
@@ -282,12 +777,14 @@ pub struct IndexStats {
     pub variants: usize,
     pub trie_nodes: usize,
     pub memory_bytes: usize,
+    /// Columns of each composite index, in key order
+    pub composites: Vec<Vec<String>>,
 }
 
 impl IndexStats {
     /// Format as human-readable string.
     pub fn to_string(&self) -> String {
-        format!(
+        let mut output = format!(
             "Index Statistics:\n\
              Total keys: {}\n\
              Namespaces: {}\n\
@@ -305,6 +802,94 @@ impl IndexStats {
             self.variants,
             self.trie_nodes,
             self.memory_bytes as f64 / 1_048_576.0
-        )
+        );
+        for columns in &self.composites {
+            output.push_str(&format!("\nComposite index: ({})", columns.join(", ")));
+        }
+        output
     }
 }
+
+/// Directory holding a table's persisted indices (`indices/{table}/`).
+fn persisted_dir(base_path: &Path, table: &str) -> PathBuf {
+    base_path.join("indices").join(table)
+}
+
+/// Version hash of table content (CRC32 of current.csv).
+fn version_hash(content: &[u8]) -> u64 {
+    crc32fast::hash(content) as u64
+}
+
+/// Serialise `value` with bincode and write it atomically (temp file + rename).
+fn write_bincode<T: Serialize + ?Sized>(path: &Path, value: &T) -> ReedResult<()> {
+    let bytes = bincode::serialize(value).map_err(|e| ReedError::SerializationError {
+        reason: format!("{}: {}", path.display(), e),
+    })?;
+
+    let temp_path = path.with_extension("bin.tmp");
+    fs::write(&temp_path, bytes)
+        .and_then(|_| fs::rename(&temp_path, path))
+        .map_err(|e| ReedError::IoError {
+            operation: "write_index_file".to_string(),
+            reason: format!("{}: {}", path.display(), e),
+        })
+}
+
+/// Read and deserialise a bincode file, `None` if missing or corrupt.
+fn read_bincode<T: DeserializeOwned>(path: &Path) -> Option<T> {
+    let bytes = fs::read(path).ok()?;
+    bincode::deserialize(&bytes).ok()
+}
+
+/// B+-Tree index files belonging to a table (`indices/{table}.{column}.btree`).
+fn btree_index_files(table: &Table) -> ReedResult<Vec<PathBuf>> {
+    let indices_dir = table.base_path().join("indices");
+    if !indices_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let prefix = format!("{}.", table.name());
+    let entries = fs::read_dir(&indices_dir).map_err(|e| ReedError::IoError {
+        operation: "read_indices_dir".to_string(),
+        reason: e.to_string(),
+    })?;
+
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+            name.starts_with(&prefix) && name.ends_with(".btree")
+        })
+        .collect();
+    files.sort();
+
+    Ok(files)
+}
+
+/// Map a file read-only and ask the kernel to page it in.
+///
+/// Returns the file size. On platforms without `madvise` the mapping is
+/// created but no hint is given.
+fn preload_file(path: &Path) -> ReedResult<u64> {
+    let io_error = |operation: &str, e: std::io::Error| ReedError::IoError {
+        operation: operation.to_string(),
+        reason: format!("{}: {}", path.display(), e),
+    };
+
+    let file = File::open(path).map_err(|e| io_error("open_btree_for_warm", e))?;
+    let len = file
+        .metadata()
+        .map_err(|e| io_error("stat_btree_for_warm", e))?
+        .len();
+    if len == 0 {
+        return Ok(0);
+    }
+
+    let mmap = unsafe { Mmap::map(&file) }.map_err(|e| io_error("mmap_btree_for_warm", e))?;
+    #[cfg(unix)]
+    mmap.advise(memmap2::Advice::WillNeed)
+        .map_err(|e| io_error("madvise_btree_for_warm", e))?;
+    drop(mmap);
+
+    Ok(len)
+}
@@ -18,6 +18,7 @@
 //! - **Hierarchy query**: < 10μs (O(d) trie walk, d typically 2-4)
 //! - **Combined query (3 filters)**: < 50μs (3x O(1) + set intersection)
 //! - **Index build**: < 50ms for 10,000 keys
+//! - **Index load**: < 5ms for 10,000 keys from `IndexManager::persist()` state
 //! - **Memory**: ~150 bytes/key (~1.5MB for 10k keys)
 //!
 //! ## Example Usage
@@ -68,10 +69,10 @@ mod indices_test;
 // Re-export public API
 pub use btree_index::BTreeIndex;
 pub use builder::{IndexBackend, IndexBuilder, IndexConfig};
-pub use hashmap_index::HashMapIndex;
+pub use hashmap_index::{HashMapIndex, ImmutableHashMapIndex};
 pub use hierarchy::HierarchyTrie;
 pub use index_trait::Index;
-pub use manager::{IndexManager, IndexStats};
+pub use manager::{IndexManager, IndexStats, WarmReport};
 pub use modifier::ModifierIndex;
 pub use namespace::NamespaceIndex;
 pub use types::{KeyIndex, Modifiers, QueryFilter};
@@ -148,6 +148,16 @@ impl NamespaceIndex {
     pub fn clear(&mut self) {
         self.map.clear();
     }
+
+    /// Raw value → rows map (used to persist the index).
+    pub(crate) fn map(&self) -> &HashMap<String, Vec<usize>> {
+        &self.map
+    }
+
+    /// Replace the map with persisted state.
+    pub(crate) fn set_map(&mut self, map: HashMap<String, Vec<usize>>) {
+        self.map = map;
+    }
 }
 
 impl Default for NamespaceIndex {
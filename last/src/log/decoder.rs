@@ -6,7 +6,10 @@
 //! Decodes log entries from integer codes to human-readable format with CRC32 validation.
 
 use crate::error::{ReedError, ReedResult};
-use crate::log::types::LogEntry;
+use crate::log::types::{
+    LogEntry, BINARY_BLOCK_ENTRIES, BINARY_FLAG_CHAINED, BINARY_FLAG_FRAME, BINARY_FLAG_SHA256,
+    BINARY_LOG_MAGIC, SHA256_PREFIX,
+};
 use crate::registry;
 use crc32fast::Hasher;
 use uuid::Uuid;
@@ -43,19 +46,23 @@ const MAGIC: &str = "REED";
 pub fn decode_log_entry(line: &str) -> ReedResult<LogEntry> {
     let parts: Vec<&str> = line.split('|').collect();
 
+    // Chained format: REED|length|...|frame_id|prev_hash|crc32 (12 fields)
     // New format: REED|length|timestamp|action|user|base|size|rows|hash|frame_id|crc32 (11 fields)
     // Old format: timestamp|action|user|base|size|rows|hash|frame_id (8 fields)
     // Older format: timestamp|action|user|base|size|rows|hash (7 fields)
+    // Table format: timestamp|action|user|delta_size (4 fields, tables/*/version.log)
 
-    if parts.len() == 11 {
+    if parts.len() == 11 || parts.len() == 12 {
         // New format with CRC32 validation
         decode_new_format(line, &parts)
     } else if parts.len() == 8 || parts.len() == 7 {
         // Old format without CRC32 (backward compatibility)
         decode_old_format(&parts)
+    } else if parts.len() == 4 {
+        decode_table_format(&parts)
     } else {
         Err(ReedError::ParseError {
-            reason: format!("Expected 7, 8, or 11 fields, got {}", parts.len()),
+            reason: format!("Expected 4, 7, 8, 11 or 12 fields, got {}", parts.len()),
         })
     }
 }
@@ -91,12 +98,14 @@ fn decode_new_format(line: &str, parts: &[&str]) -> ReedResult<LogEntry> {
     }
 
     // Extract CRC32 (last field)
-    let expected_crc = u32::from_str_radix(parts[10], 16).map_err(|e| ReedError::ParseError {
-        reason: format!("Invalid CRC32 field: {}", e),
-    })?;
+    let crc_idx = parts.len() - 1;
+    let expected_crc =
+        u32::from_str_radix(parts[crc_idx], 16).map_err(|e| ReedError::ParseError {
+            reason: format!("Invalid CRC32 field: {}", e),
+        })?;
 
-    // Calculate CRC32 of data portion (fields 2-9)
-    let data = parts[2..10].join("|");
+    // Calculate CRC32 of data portion (all fields between length and CRC32)
+    let data = parts[2..crc_idx].join("|");
     let mut hasher = Hasher::new();
     hasher.update(data.as_bytes());
     let actual_crc = hasher.finalize();
@@ -153,6 +162,13 @@ fn decode_new_format(line: &str, parts: &[&str]) -> ReedResult<LogEntry> {
         ),
     };
 
+    // Parse prev_hash (chained format only)
+    let prev_hash = if parts.len() == 12 {
+        decode_prev_hash(parts[10])?
+    } else {
+        [0; 32]
+    };
+
     // Decode codes to names
     let action = registry::get_action_name(action_code)?;
     let user = registry::get_username(user_code)?;
@@ -166,9 +182,30 @@ fn decode_new_format(line: &str, parts: &[&str]) -> ReedResult<LogEntry> {
         rows,
         hash,
         frame_id,
+        prev_hash,
     })
 }
 
+/// Decode 64-character hex prev_hash field.
+fn decode_prev_hash(field: &str) -> ReedResult<[u8; 32]> {
+    if field.len() != 64 || !field.is_ascii() {
+        return Err(ReedError::ParseError {
+            reason: format!("Invalid prev_hash: expected 64 hex chars, got '{}'", field),
+        });
+    }
+
+    let mut prev_hash = [0u8; 32];
+    for (i, byte) in prev_hash.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&field[i * 2..i * 2 + 2], 16).map_err(|e| {
+            ReedError::ParseError {
+                reason: format!("Invalid prev_hash: {}", e),
+            }
+        })?;
+    }
+
+    Ok(prev_hash)
+}
+
 /// Decode old format without CRC32 (backward compatibility).
 fn decode_old_format(parts: &[&str]) -> ReedResult<LogEntry> {
     let timestamp = parts[0].parse::<u64>().map_err(|e| ReedError::ParseError {
@@ -228,9 +265,42 @@ fn decode_old_format(parts: &[&str]) -> ReedResult<LogEntry> {
         rows,
         hash,
         frame_id,
+        prev_hash: [0; 32],
     })
 }
 
+/// Decode the table version.log format (no base version, rows or hash).
+fn decode_table_format(parts: &[&str]) -> ReedResult<LogEntry> {
+    let timestamp = parts[0].parse::<u64>().map_err(|e| ReedError::ParseError {
+        reason: format!("Invalid timestamp: {}", e),
+    })?;
+
+    let action_code = parts[1].parse::<u8>().map_err(|e| ReedError::ParseError {
+        reason: format!("Invalid action code: {}", e),
+    })?;
+
+    let user_code = parts[2].parse::<u32>().map_err(|e| ReedError::ParseError {
+        reason: format!("Invalid user code: {}", e),
+    })?;
+
+    let size = parts[3]
+        .parse::<usize>()
+        .map_err(|e| ReedError::ParseError {
+            reason: format!("Invalid size: {}", e),
+        })?;
+
+    Ok(LogEntry::new(
+        timestamp,
+        registry::get_action_name(action_code)?,
+        registry::get_username(user_code)?,
+        0,
+        size,
+        0,
+        String::new(),
+        None,
+    ))
+}
+
 /// Decode multiple log entries.
 ///
 /// ## Input
@@ -284,6 +354,290 @@ pub fn decode_log_entries(content: &str) -> ReedResult<Vec<LogEntry>> {
     Ok(entries)
 }
 
+/// Decode a standalone binary record (from `encode_log_entry_binary`).
+///
+/// ## Input
+/// - `record`: Binary record
+///
+/// ## Output
+/// - `ReedResult<LogEntry>`: Decoded log entry
+///
+/// ## Performance
+/// - < 1ms typical (2 dictionary lookups + varint decoding)
+///
+/// ## Error Conditions
+/// - ParseError: Truncated record or trailing bytes
+/// - UnknownActionCode: Action code not found
+/// - UnknownUserCode: User code not found
+///
+/// ## Example Usage
+/// ```no_run
+/// use reedbase_last::log::{decode_log_entry_binary, encode_log_entry_binary};
+///
+/// let record = encode_log_entry_binary(&entry)?;
+/// let decoded = decode_log_entry_binary(&record)?;
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn decode_log_entry_binary(record: &[u8]) -> ReedResult<LogEntry> {
+    let mut reader = BinaryReader {
+        data: record,
+        pos: 0,
+    };
+    let raw = reader.read_record(0)?;
+    if reader.pos != record.len() {
+        return Err(ReedError::ParseError {
+            reason: format!(
+                "{} trailing bytes after binary record",
+                record.len() - reader.pos
+            ),
+        });
+    }
+    raw.into_entry()
+}
+
+/// Decode a binary log file (from `encode_log_entries_binary`).
+///
+/// ## Input
+/// - `data`: Binary log content, starting with `BINARY_LOG_MAGIC`
+///
+/// ## Output
+/// - `ReedResult<Vec<LogEntry>>`: Decoded log entries (empty input = no entries)
+///
+/// ## Performance
+/// - O(n) where n = number of entries
+/// - < 50ms for 1000 entries
+///
+/// ## Error Conditions
+/// - CorruptedLogEntry: Missing magic byte, CRC32 mismatch, truncated or
+///   malformed block (`line` = number of the first affected entry)
+/// - UnknownActionCode: Action code not found
+/// - UnknownUserCode: User code not found
+///
+/// ## Example Usage
+/// ```no_run
+/// use reedbase_last::log::decode_log_entries_binary;
+///
+/// let data = std::fs::read("version.log")?;
+/// let entries = decode_log_entries_binary(&data)?;
+/// println!("{} entries", entries.len());
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn decode_log_entries_binary(data: &[u8]) -> ReedResult<Vec<LogEntry>> {
+    match data.first() {
+        None => return Ok(Vec::new()),
+        Some(&BINARY_LOG_MAGIC) => {}
+        Some(byte) => {
+            return Err(ReedError::CorruptedLogEntry {
+                line: 0,
+                reason: format!(
+                    "Invalid magic byte: expected {:#04X}, got {:#04X}",
+                    BINARY_LOG_MAGIC, byte
+                ),
+            })
+        }
+    }
+
+    scan_binary_log(data)
+        .into_iter()
+        .map(|(_, result)| result)
+        .collect()
+}
+
+/// Decode a binary log entry by entry, without stopping at the first error.
+///
+/// Expects `data` to start with the magic byte. Every entry of a damaged
+/// block yields a `CorruptedLogEntry`; a damaged block ends the scan, since
+/// the blocks after it cannot be located reliably. Numbering (`line`) is
+/// 1-based over entries.
+///
+/// Each result comes with the byte offset of its block; truncating the file
+/// there drops the block and keeps every block before it intact.
+pub(crate) fn scan_binary_log(data: &[u8]) -> Vec<(usize, ReedResult<LogEntry>)> {
+    let mut results = Vec::new();
+    let mut reader = BinaryReader { data, pos: 1 };
+    let mut reference = 0;
+
+    while reader.pos < data.len() {
+        let start = reader.pos;
+        let first = results.len() + 1;
+        let corrupted = |count: usize, reason: String| {
+            (0..count.max(1)).map(move |i| {
+                let error = ReedError::CorruptedLogEntry {
+                    line: first + i,
+                    reason: reason.clone(),
+                };
+                (start, Err(error))
+            })
+        };
+
+        let count = data[start] as usize;
+        if count == 0 || count > BINARY_BLOCK_ENTRIES {
+            results.extend(corrupted(1, format!("Invalid block size {}", count)));
+            break;
+        }
+        reader.pos += 1;
+
+        let mut block = Vec::with_capacity(count);
+        let mut block_reference = reference;
+        let parsed = (0..count).try_for_each(|_| {
+            let raw = reader.read_record(block_reference)?;
+            block_reference = raw.timestamp;
+            block.push(raw);
+            Ok(())
+        });
+        let checked = parsed.and_then(|_| {
+            let records_end = reader.pos;
+            let expected = u32::from_le_bytes(reader.read_bytes(4)?.try_into().unwrap());
+            let mut hasher = Hasher::new();
+            hasher.update(&data[start..records_end]);
+            let actual = hasher.finalize();
+            if actual != expected {
+                return Err(ReedError::ParseError {
+                    reason: format!(
+                        "CRC32 mismatch: expected {:08X}, got {:08X}",
+                        expected, actual
+                    ),
+                });
+            }
+            Ok(())
+        });
+        if let Err(e) = checked {
+            let reason = match e {
+                ReedError::ParseError { reason } => reason,
+                other => other.to_string(),
+            };
+            results.extend(corrupted(count, reason));
+            break;
+        }
+
+        reference = block_reference;
+        results.extend(block.into_iter().map(|raw| (start, raw.into_entry())));
+    }
+
+    results
+}
+
+/// Binary record with action and user still as codes.
+struct BinaryRecord {
+    timestamp: u64,
+    action_code: u8,
+    user_code: u32,
+    base_version: u64,
+    size: usize,
+    rows: usize,
+    hash: String,
+    frame_id: Option<Uuid>,
+    prev_hash: [u8; 32],
+}
+
+impl BinaryRecord {
+    /// Resolve codes to names.
+    fn into_entry(self) -> ReedResult<LogEntry> {
+        Ok(LogEntry {
+            timestamp: self.timestamp,
+            action: registry::get_action_name(self.action_code)?,
+            user: registry::get_username(self.user_code)?,
+            base_version: self.base_version,
+            size: self.size,
+            rows: self.rows,
+            hash: self.hash,
+            frame_id: self.frame_id,
+            prev_hash: self.prev_hash,
+        })
+    }
+}
+
+/// Cursor over binary log data.
+struct BinaryReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> BinaryReader<'a> {
+    fn read_bytes(&mut self, len: usize) -> ReedResult<&'a [u8]> {
+        let bytes =
+            self.data
+                .get(self.pos..self.pos + len)
+                .ok_or_else(|| ReedError::ParseError {
+                    reason: format!("Unexpected end of binary log at byte {}", self.data.len()),
+                })?;
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    /// Read a LEB128 varint.
+    fn read_varint(&mut self) -> ReedResult<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.read_bytes(1)?[0];
+            value |= ((byte & 0x7F) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(ReedError::ParseError {
+            reason: format!("Varint too long at byte {}", self.pos),
+        })
+    }
+
+    /// Read a zigzag delta and apply it to `reference`.
+    fn read_delta(&mut self, reference: u64) -> ReedResult<u64> {
+        let value = self.read_varint()?;
+        let delta = ((value >> 1) as i64) ^ -((value & 1) as i64);
+        Ok(reference.wrapping_add(delta as u64))
+    }
+
+    fn read_record(&mut self, reference: u64) -> ReedResult<BinaryRecord> {
+        let invalid = |field: &str| ReedError::ParseError {
+            reason: format!("Invalid {} in binary record", field),
+        };
+
+        let flags = self.read_bytes(1)?[0];
+        let timestamp = self.read_delta(reference)?;
+        let action_code = u8::try_from(self.read_varint()?).map_err(|_| invalid("action code"))?;
+        let user_code = u32::try_from(self.read_varint()?).map_err(|_| invalid("user code"))?;
+        let base_version = self.read_delta(reference)?;
+        let size = self.read_varint()? as usize;
+        let rows = self.read_varint()? as usize;
+
+        let hash = if flags & BINARY_FLAG_SHA256 != 0 {
+            let digest = self.read_bytes(32)?;
+            let mut hash = String::with_capacity(SHA256_PREFIX.len() + 64);
+            hash.push_str(SHA256_PREFIX);
+            for byte in digest {
+                hash.push_str(&format!("{:02x}", byte));
+            }
+            hash
+        } else {
+            let len = self.read_varint()? as usize;
+            String::from_utf8(self.read_bytes(len)?.to_vec()).map_err(|_| invalid("hash"))?
+        };
+
+        let frame_id = if flags & BINARY_FLAG_FRAME != 0 {
+            Some(Uuid::from_slice(self.read_bytes(16)?).map_err(|_| invalid("frame_id"))?)
+        } else {
+            None
+        };
+
+        let mut prev_hash = [0u8; 32];
+        if flags & BINARY_FLAG_CHAINED != 0 {
+            prev_hash.copy_from_slice(self.read_bytes(32)?);
+        }
+
+        Ok(BinaryRecord {
+            timestamp,
+            action_code,
+            user_code,
+            base_version,
+            size,
+            rows,
+            hash,
+            frame_id,
+            prev_hash,
+        })
+    }
+}
+
 /// Filter log entries by action.
 ///
 /// ## Input
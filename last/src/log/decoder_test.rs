@@ -4,10 +4,12 @@
 #[cfg(test)]
 mod tests {
     use crate::log::decoder::{
-        decode_log_entries, decode_log_entry, filter_by_action, filter_by_time_range,
-        filter_by_user,
+        decode_log_entries, decode_log_entries_binary, decode_log_entry, decode_log_entry_binary,
+        filter_by_action, filter_by_time_range, filter_by_user,
+    };
+    use crate::log::encoder::{
+        encode_log_entries_binary, encode_log_entry, encode_log_entry_binary,
     };
-    use crate::log::encoder::encode_log_entry;
     use crate::log::types::LogEntry;
     use crate::registry;
     use crate::ReedError;
@@ -109,6 +111,48 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_decode_binary_corruption() {
+        let _temp_dir = setup_registry();
+        let entries: Vec<LogEntry> = (0..20)
+            .map(|i| {
+                let mut entry = create_test_entry();
+                entry.timestamp += i * 100;
+                entry
+            })
+            .collect();
+        let encoded = encode_log_entries_binary(&entries).unwrap();
+        assert_eq!(decode_log_entries_binary(&encoded).unwrap(), entries);
+
+        // Flipped byte in the second block (entries 17-20)
+        let mut damaged = encoded.clone();
+        let last = damaged.len() - 10;
+        damaged[last] ^= 0xFF;
+        match decode_log_entries_binary(&damaged) {
+            Err(ReedError::CorruptedLogEntry { line, .. }) => assert_eq!(line, 17),
+            other => panic!("Expected CorruptedLogEntry, got {:?}", other),
+        }
+
+        // Truncated file and missing magic byte
+        assert!(matches!(
+            decode_log_entries_binary(&encoded[..encoded.len() - 2]),
+            Err(ReedError::CorruptedLogEntry { line: 17, .. })
+        ));
+        assert!(matches!(
+            decode_log_entries_binary(&encoded[1..]),
+            Err(ReedError::CorruptedLogEntry { line: 0, .. })
+        ));
+        assert!(decode_log_entries_binary(&[]).unwrap().is_empty());
+
+        // Standalone record with trailing bytes
+        let mut record = encode_log_entry_binary(&entries[0]).unwrap();
+        record.push(0);
+        assert!(matches!(
+            decode_log_entry_binary(&record),
+            Err(ReedError::ParseError { .. })
+        ));
+    }
+
     #[test]
     fn test_decode_old_format_without_crc32() {
         let _temp_dir = setup_registry();
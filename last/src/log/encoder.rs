@@ -4,9 +4,31 @@
 //! Log encoding for version history.
 //!
 //! Encodes log entries using integer codes from registries with CRC32 validation.
+//!
+//! Two formats are available: pipe-delimited text lines (one per entry) and
+//! a compact binary format for high-frequency logs.
+//!
+//! ## Binary Format
+//! ```text
+//! 0xBE                                   magic byte (BINARY_LOG_MAGIC)
+//! [count:u8][record × count][crc32:u32 LE]   block, count ≤ 16, repeated
+//! ```
+//! The CRC32 covers the count byte and the records of its block. Records:
+//! ```text
+//! flags:u8 | timestamp | action_code | user_code | base_version | size | rows
+//!          | hash | [frame_id:16] | [prev_hash:32]
+//! ```
+//! Numbers are LEB128 varints. `timestamp` and `base_version` are zigzag
+//! deltas to the previous entry's timestamp (0 for the first entry), so a
+//! typical entry needs a few bytes for both. A `sha256:` hash with 64
+//! lowercase hex digits is stored as its 32 raw bytes, any other hash as
+//! varint length + UTF-8.
 
 use crate::error::ReedResult;
-use crate::log::types::LogEntry;
+use crate::log::types::{
+    LogEntry, BINARY_BLOCK_ENTRIES, BINARY_FLAG_CHAINED, BINARY_FLAG_FRAME, BINARY_FLAG_SHA256,
+    BINARY_LOG_MAGIC, SHA256_PREFIX,
+};
 use crate::registry;
 use crc32fast::Hasher;
 
@@ -20,6 +42,7 @@ const MAGIC: &str = "REED";
 /// ## Output
 /// - `ReedResult<String>`: Encoded log line with format:
 ///   `REED|{length}|{timestamp}|{action_code}|{user_code}|{base_version}|{size}|{rows}|{hash}|{frame_id}|{crc32}`
+///   Chained entries carry `|{prev_hash}` (64 hex chars) before the CRC32.
 ///
 /// ## Performance
 /// - < 150μs typical (2 dictionary lookups + CRC32 + string formatting)
@@ -55,7 +78,7 @@ pub fn encode_log_entry(entry: &LogEntry) -> ReedResult<String> {
         .unwrap_or_else(|| "n/a".to_string());
 
     // Build data portion (everything except magic, length, and CRC32)
-    let data = format!(
+    let mut data = format!(
         "{}|{}|{}|{}|{}|{}|{}|{}",
         entry.timestamp,
         action_code,
@@ -66,6 +89,12 @@ pub fn encode_log_entry(entry: &LogEntry) -> ReedResult<String> {
         entry.hash,
         frame_id_str
     );
+    if entry.is_chained() {
+        data.push('|');
+        for byte in entry.prev_hash {
+            data.push_str(&format!("{:02x}", byte));
+        }
+    }
 
     // Calculate CRC32 of data portion
     let mut hasher = Hasher::new();
@@ -118,6 +147,179 @@ pub fn encode_log_entries(entries: &[LogEntry]) -> ReedResult<String> {
     Ok(lines.join("\n"))
 }
 
+/// Encode log entry as a standalone binary record.
+///
+/// Timestamps are written relative to 0, i.e. in full; inside a log file
+/// (`encode_log_entries_binary`) they are deltas to the previous entry.
+///
+/// ## Input
+/// - `entry`: Log entry to encode
+///
+/// ## Output
+/// - `ReedResult<Vec<u8>>`: Binary record (no magic byte, no CRC32)
+///
+/// ## Performance
+/// - < 150μs typical (2 dictionary lookups + varint encoding)
+///
+/// ## Error Conditions
+/// - UnknownAction: Action name not found in actions.dict
+///
+/// ## Example Usage
+/// ```no_run
+/// use reedbase_last::log::{decode_log_entry_binary, encode_log_entry_binary};
+///
+/// let record = encode_log_entry_binary(&entry)?;
+/// assert_eq!(decode_log_entry_binary(&record)?, entry);
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn encode_log_entry_binary(entry: &LogEntry) -> ReedResult<Vec<u8>> {
+    let mut record = Vec::new();
+    encode_binary_record(entry, 0, &mut record)?;
+    Ok(record)
+}
+
+/// Encode multiple log entries as a binary log file.
+///
+/// ## Input
+/// - `entries`: Log entries in log order
+///
+/// ## Output
+/// - `ReedResult<Vec<u8>>`: Magic byte followed by CRC32-checked blocks of
+///   up to `BINARY_BLOCK_ENTRIES` records
+///
+/// ## Performance
+/// - O(n) where n = number of entries
+/// - ~3.5x smaller than `encode_log_entries` for unchained logs with
+///   `sha256:` hashes (~43 vs ~150 bytes per entry, 32 of them the digest)
+///
+/// ## Error Conditions
+/// - UnknownAction: Action name not found
+///
+/// ## Example Usage
+/// ```no_run
+/// use reedbase_last::log::encode_log_entries_binary;
+///
+/// let encoded = encode_log_entries_binary(&entries)?;
+/// std::fs::write("version.log", encoded)?;
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn encode_log_entries_binary(entries: &[LogEntry]) -> ReedResult<Vec<u8>> {
+    let mut output = vec![BINARY_LOG_MAGIC];
+    let mut reference = 0;
+
+    for block in entries.chunks(BINARY_BLOCK_ENTRIES) {
+        let start = output.len();
+        output.push(block.len() as u8);
+        for entry in block {
+            encode_binary_record(entry, reference, &mut output)?;
+            reference = entry.timestamp;
+        }
+
+        let mut hasher = Hasher::new();
+        hasher.update(&output[start..]);
+        output.extend_from_slice(&hasher.finalize().to_le_bytes());
+    }
+
+    Ok(output)
+}
+
+/// Append one binary record, timestamps relative to `reference`.
+fn encode_binary_record(entry: &LogEntry, reference: u64, out: &mut Vec<u8>) -> ReedResult<()> {
+    let action_code = registry::get_action_code(&entry.action)?;
+    let user_code = registry::get_or_create_user_code(&entry.user)?;
+    let sha256 = compact_sha256(&entry.hash);
+
+    let mut flags = 0;
+    if entry.frame_id.is_some() {
+        flags |= BINARY_FLAG_FRAME;
+    }
+    if entry.is_chained() {
+        flags |= BINARY_FLAG_CHAINED;
+    }
+    if sha256.is_some() {
+        flags |= BINARY_FLAG_SHA256;
+    }
+    out.push(flags);
+
+    write_varint(out, zigzag(entry.timestamp.wrapping_sub(reference)));
+    write_varint(out, action_code as u64);
+    write_varint(out, user_code as u64);
+    write_varint(out, zigzag(entry.base_version.wrapping_sub(reference)));
+    write_varint(out, entry.size as u64);
+    write_varint(out, entry.rows as u64);
+
+    match sha256 {
+        Some(digest) => out.extend_from_slice(&digest),
+        None => {
+            write_varint(out, entry.hash.len() as u64);
+            out.extend_from_slice(entry.hash.as_bytes());
+        }
+    }
+    if let Some(frame_id) = entry.frame_id {
+        out.extend_from_slice(frame_id.as_bytes());
+    }
+    if entry.is_chained() {
+        out.extend_from_slice(&entry.prev_hash);
+    }
+
+    Ok(())
+}
+
+/// Raw digest of a `sha256:` hash that decodes back to the same string.
+fn compact_sha256(hash: &str) -> Option<[u8; 32]> {
+    let hex = hash.strip_prefix(SHA256_PREFIX)?;
+    if hex.len() != 64 || !hex.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f')) {
+        return None;
+    }
+
+    let mut digest = [0u8; 32];
+    for (i, byte) in digest.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).ok()?;
+    }
+    Some(digest)
+}
+
+/// Map a wrapped difference to an unsigned value, small for small |delta|.
+fn zigzag(delta: u64) -> u64 {
+    let delta = delta as i64;
+    ((delta << 1) ^ (delta >> 63)) as u64
+}
+
+/// Append `value` as LEB128 varint (7 bits per byte, low bits first).
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// Link entries into a SHA-256 hash chain.
+///
+/// Sets every entry's `prev_hash` in order: the first entry gets the genesis
+/// hash, each following entry is linked to its predecessor.
+///
+/// ## Input
+/// - `entries`: Entries in log order (modified in place)
+///
+/// ## Performance
+/// - O(n) where n = number of entries, < 5μs per entry
+///
+/// ## Example Usage
+/// ```no_run
+/// use reedbase_last::log::{chain_log_entries, encode_log_entries};
+///
+/// chain_log_entries(&mut entries);
+/// let encoded = encode_log_entries(&entries)?;
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn chain_log_entries(entries: &mut [LogEntry]) {
+    for i in 0..entries.len() {
+        let (before, rest) = entries.split_at_mut(i);
+        rest[0].prev_hash = rest[0].compute_prev_hash(before.last());
+    }
+}
+
 /// Calculate encoded size vs plain text size.
 ///
 /// ## Input
@@ -3,8 +3,15 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::log::encoder::{calculate_size_savings, encode_log_entries, encode_log_entry};
+    use crate::log::decoder::{
+        decode_log_entries_binary, decode_log_entry, decode_log_entry_binary,
+    };
+    use crate::log::encoder::{
+        calculate_size_savings, chain_log_entries, encode_log_entries, encode_log_entries_binary,
+        encode_log_entry, encode_log_entry_binary,
+    };
     use crate::log::types::LogEntry;
+    use crate::log::types::{BINARY_BLOCK_ENTRIES, BINARY_LOG_MAGIC};
     use crate::registry;
     use tempfile::TempDir;
 
@@ -170,4 +177,125 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_encode_chained_entry_roundtrip() {
+        let _temp_dir = setup_registry();
+        let mut entries = vec![create_test_entry(), create_test_entry()];
+        entries[1].timestamp = 1736861000;
+        chain_log_entries(&mut entries);
+
+        let encoded = encode_log_entry(&entries[1]).unwrap();
+        let parts: Vec<&str> = encoded.split('|').collect();
+        assert_eq!(parts.len(), 12);
+        assert_eq!(parts[10].len(), 64);
+        assert_eq!(parts[11].len(), 8);
+
+        let decoded = decode_log_entry(&encoded).unwrap();
+        assert_eq!(decoded, entries[1]);
+    }
+
+    #[test]
+    fn test_chain_log_entries() {
+        let mut entries = vec![create_test_entry(), create_test_entry()];
+        assert!(!entries[0].is_chained());
+
+        chain_log_entries(&mut entries);
+
+        assert_eq!(entries[0].prev_hash, entries[0].compute_prev_hash(None));
+        assert_eq!(
+            entries[1].prev_hash,
+            entries[1].compute_prev_hash(Some(&entries[0]))
+        );
+        assert_ne!(entries[0].prev_hash, entries[1].prev_hash);
+    }
+
+    /// 1000 entries 50ms apart, each based on its predecessor, with real
+    /// SHA-256 delta hashes.
+    fn create_typical_log() -> Vec<LogEntry> {
+        use sha2::{Digest, Sha256};
+
+        let start = 1_736_860_900_000_000_000u64;
+        (0..1000u64)
+            .map(|i| {
+                let timestamp = start + i * 50_000_000;
+                let digest = Sha256::digest(i.to_le_bytes());
+                let hash: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+                LogEntry::new(
+                    timestamp,
+                    if i % 10 == 0 { "delete" } else { "update" }.to_string(),
+                    if i % 3 == 0 { "admin" } else { "editor" }.to_string(),
+                    timestamp - 50_000_000,
+                    200 + (i as usize * 37) % 4000,
+                    1 + (i as usize % 20),
+                    format!("sha256:{}", hash),
+                    None,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_encode_log_entry_binary_roundtrip() {
+        let _temp_dir = setup_registry();
+        let mut entries = vec![
+            create_test_entry(),
+            LogEntry::new(
+                1736860900,
+                "delete".to_string(),
+                "editor".to_string(),
+                1736861000, // base after timestamp
+                0,
+                0,
+                format!("sha256:{}", "ab".repeat(32)),
+                Some(uuid::Uuid::new_v4()),
+            ),
+        ];
+        chain_log_entries(&mut entries[1..]);
+
+        for entry in &entries {
+            let record = encode_log_entry_binary(entry).unwrap();
+            assert_eq!(&decode_log_entry_binary(&record).unwrap(), entry);
+        }
+
+        // Uppercase hex cannot be stored compactly and stays text
+        let mut entry = create_test_entry();
+        entry.hash = format!("sha256:{}", "AB".repeat(32));
+        let record = encode_log_entry_binary(&entry).unwrap();
+        assert_eq!(decode_log_entry_binary(&record).unwrap(), entry);
+    }
+
+    #[test]
+    fn test_encode_log_entries_binary_blocks() {
+        let _temp_dir = setup_registry();
+        let entries = create_typical_log();
+
+        let encoded = encode_log_entries_binary(&entries[..40]).unwrap();
+        assert_eq!(encoded[0], BINARY_LOG_MAGIC);
+        assert_eq!(encoded[1] as usize, BINARY_BLOCK_ENTRIES);
+        assert_eq!(decode_log_entries_binary(&encoded).unwrap(), &entries[..40]);
+
+        assert_eq!(
+            encode_log_entries_binary(&[]).unwrap(),
+            vec![BINARY_LOG_MAGIC]
+        );
+    }
+
+    #[test]
+    fn test_binary_log_size() {
+        let _temp_dir = setup_registry();
+        let entries = create_typical_log();
+
+        let text = encode_log_entries(&entries).unwrap().len() + 1;
+        let binary = encode_log_entries_binary(&entries).unwrap().len();
+        // ~150 vs ~43 bytes per entry; 32 of those are the SHA-256 digest
+        let ratio = text as f64 / binary as f64;
+        assert!(
+            ratio > 3.4,
+            "text {} bytes, binary {} bytes, ratio {:.2}",
+            text,
+            binary,
+            ratio
+        );
+    }
 }
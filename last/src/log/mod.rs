@@ -19,8 +19,16 @@ mod validator_test;
 
 // Re-export public API
 pub use decoder::{
-    decode_log_entries, decode_log_entry, filter_by_action, filter_by_time_range, filter_by_user,
+    decode_log_entries, decode_log_entries_binary, decode_log_entry, decode_log_entry_binary,
+    filter_by_action, filter_by_time_range, filter_by_user,
+};
+pub use encoder::{
+    calculate_size_savings, chain_log_entries, encode_log_entries, encode_log_entries_binary,
+    encode_log_entry, encode_log_entry_binary,
+};
+pub use types::{
+    LogEntry, TamperReport, ValidationReport, BINARY_BLOCK_ENTRIES, BINARY_LOG_MAGIC, GENESIS_SEED,
+};
+pub use validator::{
+    repair_log, validate_and_truncate_log, validate_log, validate_log_tamper_evident,
 };
-pub use encoder::{calculate_size_savings, encode_log_entries, encode_log_entry};
-pub use types::{LogEntry, ValidationReport};
-pub use validator::{validate_and_truncate_log, validate_log};
@@ -3,8 +3,32 @@
 
 //! Type definitions for log system.
 
+use sha2::{Digest, Sha256};
 use uuid::Uuid;
 
+/// Seed hashed into the `prev_hash` of the first entry of a chained log.
+pub const GENESIS_SEED: &[u8] = b"reedbase-genesis";
+
+/// First byte of a binary log file.
+///
+/// Never the first byte of UTF-8 text, so it cannot start a text log.
+pub const BINARY_LOG_MAGIC: u8 = 0xBE;
+
+/// Maximum number of entries per CRC32-checked block of a binary log.
+pub const BINARY_BLOCK_ENTRIES: usize = 16;
+
+/// Binary record flag: entry has a frame UUID (16 bytes follow the hash).
+pub(crate) const BINARY_FLAG_FRAME: u8 = 0x01;
+
+/// Binary record flag: entry is chained (32-byte `prev_hash` at the end).
+pub(crate) const BINARY_FLAG_CHAINED: u8 = 0x02;
+
+/// Binary record flag: hash is `sha256:` + 64 lowercase hex, stored as 32 bytes.
+pub(crate) const BINARY_FLAG_SHA256: u8 = 0x04;
+
+/// Prefix of hashes eligible for `BINARY_FLAG_SHA256`.
+pub(crate) const SHA256_PREFIX: &str = "sha256:";
+
 /// Log entry for version history.
 ///
 /// Represents a single operation in the version log.
@@ -33,6 +57,12 @@ pub struct LogEntry {
 
     /// Frame UUID if part of coordinated batch, None otherwise.
     pub frame_id: Option<Uuid>,
+
+    /// SHA-256 link to the previous entry (all zero = not chained).
+    ///
+    /// Genesis entry: `SHA256("reedbase-genesis")`. Every other entry:
+    /// `SHA256(prev.to_bytes() || self.content_bytes())`.
+    pub prev_hash: [u8; 32],
 }
 
 impl LogEntry {
@@ -49,7 +79,7 @@ impl LogEntry {
     /// - `frame_id`: Optional frame UUID
     ///
     /// ## Output
-    /// - `LogEntry`: New log entry
+    /// - `LogEntry`: New log entry (not chained, `prev_hash` all zero)
     ///
     /// ## Example Usage
     /// ```
@@ -85,8 +115,84 @@ impl LogEntry {
             rows,
             hash,
             frame_id,
+            prev_hash: [0; 32],
         }
     }
+
+    /// Whether this entry carries a hash link.
+    pub fn is_chained(&self) -> bool {
+        self.prev_hash != [0; 32]
+    }
+
+    /// Canonical bytes of all fields except `prev_hash`.
+    ///
+    /// ## Output
+    /// - `Vec<u8>`: `timestamp|action|user|base_version|size|rows|hash|frame_id`
+    pub fn content_bytes(&self) -> Vec<u8> {
+        let frame_id_str = self
+            .frame_id
+            .map(|id| id.to_string())
+            .unwrap_or_else(|| "n/a".to_string());
+
+        format!(
+            "{}|{}|{}|{}|{}|{}|{}|{}",
+            self.timestamp,
+            self.action,
+            self.user,
+            self.base_version,
+            self.size,
+            self.rows,
+            self.hash,
+            frame_id_str
+        )
+        .into_bytes()
+    }
+
+    /// Canonical bytes of the full entry (content followed by `prev_hash`).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.content_bytes();
+        bytes.extend_from_slice(&self.prev_hash);
+        bytes
+    }
+
+    /// Computes the `prev_hash` this entry must carry.
+    ///
+    /// ## Input
+    /// - `prev`: Previous entry, `None` for the genesis entry
+    ///
+    /// ## Output
+    /// - `[u8; 32]`: SHA-256 link value
+    ///
+    /// ## Performance
+    /// - < 5μs (two SHA-256 blocks typical)
+    pub fn compute_prev_hash(&self, prev: Option<&LogEntry>) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        match prev {
+            None => hasher.update(GENESIS_SEED),
+            Some(prev) => {
+                hasher.update(prev.to_bytes());
+                hasher.update(self.content_bytes());
+            }
+        }
+        hasher.finalize().into()
+    }
+}
+
+/// Result of hash chain verification.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TamperReport {
+    /// Number of entries checked.
+    pub total_entries: usize,
+
+    /// Index (0-based) of the first entry whose `prev_hash` does not match.
+    pub first_broken_link: Option<usize>,
+}
+
+impl TamperReport {
+    /// Checks if the chain is intact.
+    pub fn is_intact(&self) -> bool {
+        self.first_broken_link.is_none()
+    }
 }
 
 /// Validation report from log validation.
@@ -106,6 +212,16 @@ pub struct ValidationReport {
 
     /// Whether log was truncated to remove corruption.
     pub truncated: bool,
+
+    /// Entries kept by `repair_log` (before the first corrupted entry).
+    pub entries_retained: usize,
+
+    /// Entries removed by `repair_log` (the first corrupted entry and all
+    /// after it).
+    pub entries_discarded: usize,
+
+    /// Timestamp of the last entry kept by `repair_log`.
+    pub last_good_timestamp: Option<u64>,
 }
 
 impl ValidationReport {
@@ -117,6 +233,9 @@ impl ValidationReport {
             corrupted_count: 0,
             corrupted_lines: Vec::new(),
             truncated: false,
+            entries_retained: 0,
+            entries_discarded: 0,
+            last_good_timestamp: None,
         }
     }
 
@@ -3,17 +3,27 @@
 
 //! Log validation and crash recovery.
 //!
-//! Provides CRC32 validation and automatic truncation of corrupted entries.
+//! Provides CRC32 validation, hash chain verification and automatic
+//! truncation of corrupted entries.
 
 use crate::error::{ReedError, ReedResult};
-use crate::log::decoder::decode_log_entry;
-use crate::log::types::ValidationReport;
+use crate::log::decoder::{decode_log_entry, scan_binary_log};
+use crate::log::encoder::encode_log_entries_binary;
+use crate::log::types::{LogEntry, TamperReport, ValidationReport, BINARY_LOG_MAGIC};
 use std::fs;
 use std::io::Write;
 use std::path::Path;
 
 /// Validate log file and return detailed report.
 ///
+/// Chained logs (first readable entry has a `prev_hash`) are also checked
+/// for hash links; the first entry with a broken link counts as corrupted.
+///
+/// Text and binary logs are told apart by the first byte
+/// (`BINARY_LOG_MAGIC`). For binary logs, `corrupted_lines` holds entry
+/// numbers (1-based); a damaged CRC32 block marks all its entries and ends
+/// the scan.
+///
 /// ## Input
 /// - `log_path`: Path to version.log file
 ///
@@ -46,38 +56,121 @@ pub fn validate_log(log_path: &Path) -> ReedResult<ValidationReport> {
         return Ok(report); // Empty log is valid
     }
 
-    let content = fs::read_to_string(log_path).map_err(|e| ReedError::IoError {
+    let content = fs::read(log_path).map_err(|e| ReedError::IoError {
         operation: "read_log".to_string(),
         reason: e.to_string(),
     })?;
 
-    for (line_num, line) in content.lines().enumerate() {
-        if line.trim().is_empty() {
-            continue;
-        }
+    // Successfully decoded entries with their line numbers
+    let mut decoded: Vec<(usize, LogEntry)> = Vec::new();
 
+    for (line_num, _, result) in read_log_entries(&content) {
         report.total_entries += 1;
 
-        match decode_log_entry(line) {
-            Ok(_) => {
+        match result {
+            Ok(entry) => {
                 report.valid_entries += 1;
+                decoded.push((line_num, entry));
             }
             Err(ReedError::CorruptedLogEntry { .. }) | Err(ReedError::ParseError { .. }) => {
                 report.corrupted_count += 1;
-                report.corrupted_lines.push(line_num + 1);
+                report.corrupted_lines.push(line_num);
             }
             Err(e) => {
                 // Other errors (like UnknownActionCode) are also treated as corruption
                 report.corrupted_count += 1;
-                report.corrupted_lines.push(line_num + 1);
-                eprintln!("Warning: Line {}: {}", line_num + 1, e);
+                report.corrupted_lines.push(line_num);
+                eprintln!("Warning: Line {}: {}", line_num, e);
             }
         }
     }
 
+    // Verify hash chain for chained logs
+    if decoded.first().is_some_and(|(_, entry)| entry.is_chained()) {
+        let entries: Vec<LogEntry> = decoded.iter().map(|(_, e)| e.clone()).collect();
+        if let Some(idx) = validate_log_tamper_evident(&entries)?.first_broken_link {
+            report.valid_entries -= 1;
+            report.corrupted_count += 1;
+            report.corrupted_lines.push(decoded[idx].0);
+            report.corrupted_lines.sort_unstable();
+        }
+    }
+
     Ok(report)
 }
 
+/// Decode every entry of a text or binary log.
+///
+/// ## Output
+/// - `(number, offset, result)` per entry: 1-based line (text, blank lines
+///   skipped) or entry number (binary), and the byte offset at which the
+///   log can be cut to drop this entry and everything after it
+fn read_log_entries(content: &[u8]) -> Vec<(usize, usize, ReedResult<LogEntry>)> {
+    if content.first() == Some(&BINARY_LOG_MAGIC) {
+        return scan_binary_log(content)
+            .into_iter()
+            .enumerate()
+            .map(|(i, (offset, result))| (i + 1, offset, result))
+            .collect();
+    }
+
+    let mut entries = Vec::new();
+    let mut offset = 0;
+    for (line_num, line) in content.split_inclusive(|&b| b == b'\n').enumerate() {
+        let start = offset;
+        offset += line.len();
+
+        let result = match std::str::from_utf8(line) {
+            Ok(line) if line.trim().is_empty() => continue,
+            Ok(line) => decode_log_entry(line.trim_end_matches(['\n', '\r'])),
+            Err(e) => Err(ReedError::ParseError {
+                reason: format!("Invalid UTF-8: {}", e),
+            }),
+        };
+        entries.push((line_num + 1, start, result));
+    }
+    entries
+}
+
+/// Verify the SHA-256 hash chain of decoded log entries.
+///
+/// Recomputes every `prev_hash` from its predecessor (genesis hash for the
+/// first entry) and reports the first entry that does not match. Changing
+/// any field of an entry breaks the link of that entry (unless it is the
+/// genesis entry) and of the entry after it.
+///
+/// ## Input
+/// - `log`: Entries in log order
+///
+/// ## Output
+/// - `ReedResult<TamperReport>`: Index of first broken link, if any
+///
+/// ## Performance
+/// - O(n) where n = number of entries, < 5μs per entry
+///
+/// ## Example Usage
+/// ```no_run
+/// use reedbase_last::log::{decode_log_entries, validate_log_tamper_evident};
+///
+/// let entries = decode_log_entries(&std::fs::read_to_string("version.log")?)?;
+/// let report = validate_log_tamper_evident(&entries)?;
+/// if let Some(idx) = report.first_broken_link {
+///     println!("Log tampered at entry {}", idx);
+/// }
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn validate_log_tamper_evident(log: &[LogEntry]) -> ReedResult<TamperReport> {
+    let first_broken_link = (0..log.len()).find(|&i| {
+        let prev = if i == 0 { None } else { Some(&log[i - 1]) };
+        log[i].prev_hash != log[i].compute_prev_hash(prev)
+    });
+
+    Ok(TamperReport {
+        total_entries: log.len(),
+        first_broken_link,
+    })
+}
+
 /// Validate log and truncate corrupted entries (crash recovery).
 ///
 /// ## Input
@@ -121,11 +214,36 @@ pub fn validate_and_truncate_log(log_path: &Path) -> ReedResult<ValidationReport
     }
 
     // Read log content
-    let content = fs::read_to_string(log_path).map_err(|e| ReedError::IoError {
+    let content = fs::read(log_path).map_err(|e| ReedError::IoError {
         operation: "read_log_for_truncation".to_string(),
         reason: e.to_string(),
     })?;
 
+    if content.first() == Some(&BINARY_LOG_MAGIC) {
+        // Re-encode the entries before the first corruption
+        let valid_entries: Vec<LogEntry> = scan_binary_log(&content)
+            .into_iter()
+            .take(first_corruption - 1)
+            .map(|(_, result)| result)
+            .collect::<ReedResult<_>>()?;
+        if valid_entries.is_empty() {
+            let _ = fs::remove_file(log_path);
+        } else {
+            fs::write(log_path, encode_log_entries_binary(&valid_entries)?).map_err(|e| {
+                ReedError::IoError {
+                    operation: "write_truncated_log".to_string(),
+                    reason: e.to_string(),
+                }
+            })?;
+        }
+
+        report.truncated = true;
+        report.valid_entries = valid_entries.len();
+        report.total_entries = valid_entries.len();
+        return Ok(report);
+    }
+    let content = String::from_utf8_lossy(&content);
+
     // Keep only valid lines before first corruption
     let valid_lines: Vec<&str> = content
         .lines()
@@ -158,6 +276,103 @@ pub fn validate_and_truncate_log(log_path: &Path) -> ReedResult<ValidationReport
     Ok(report)
 }
 
+/// Repair a log after an unclean shutdown.
+///
+/// Reads entries until the first one that fails its CRC32 check or cannot
+/// be parsed (typically a partial write at the end) and cuts the log off
+/// there. The rewrite goes through a temp file and a rename, so a crash
+/// during repair leaves either the old or the repaired log. Entries that
+/// only fail to resolve (e.g. an unknown user code) and broken hash links
+/// are kept; `validate_log` still reports them.
+///
+/// ## Input
+/// - `log_path`: Path to a text or binary log file
+///
+/// ## Output
+/// - `ReedResult<ValidationReport>`: `entries_retained`,
+///   `entries_discarded` and `last_good_timestamp` describe the repair;
+///   `truncated` is true if anything was cut
+///
+/// ## Performance
+/// - < 1ms per entry validation
+/// - One temp file write + rename if the log is cut
+///
+/// ## Error Conditions
+/// - IoError: Cannot read the log, write the temp file or rename it
+///
+/// ## Example Usage
+/// ```no_run
+/// use reedbase_last::log::repair_log;
+/// use std::path::Path;
+///
+/// let report = repair_log(Path::new(".reedbase/tables/text/version.log"))?;
+/// if report.truncated {
+///     println!(
+///         "Dropped {} entries after {:?}",
+///         report.entries_discarded, report.last_good_timestamp
+///     );
+/// }
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn repair_log(log_path: &Path) -> ReedResult<ValidationReport> {
+    let mut report = ValidationReport::new();
+    if !log_path.exists() {
+        return Ok(report);
+    }
+
+    let content = fs::read(log_path).map_err(|e| ReedError::IoError {
+        operation: "read_log_for_repair".to_string(),
+        reason: e.to_string(),
+    })?;
+
+    let mut cut = None;
+    for (line_num, offset, result) in read_log_entries(&content) {
+        report.total_entries += 1;
+        if cut.is_some() {
+            report.entries_discarded += 1;
+            if result.is_err() {
+                report.corrupted_count += 1;
+                report.corrupted_lines.push(line_num);
+            }
+            continue;
+        }
+
+        match result {
+            Err(ReedError::CorruptedLogEntry { .. }) | Err(ReedError::ParseError { .. }) => {
+                cut = Some(offset);
+                report.entries_discarded += 1;
+                report.corrupted_count += 1;
+                report.corrupted_lines.push(line_num);
+            }
+            Ok(entry) => {
+                report.valid_entries += 1;
+                report.entries_retained += 1;
+                report.last_good_timestamp = Some(entry.timestamp);
+            }
+            Err(_) => report.entries_retained += 1,
+        }
+    }
+
+    let Some(cut) = cut else {
+        return Ok(report);
+    };
+
+    // A binary log keeps its magic byte even if no block survives
+    let temp_path = log_path.with_extension("log.repair");
+    fs::write(&temp_path, &content[..cut])
+        .and_then(|_| fs::rename(&temp_path, log_path))
+        .map_err(|e| {
+            let _ = fs::remove_file(&temp_path);
+            ReedError::IoError {
+                operation: "write_repaired_log".to_string(),
+                reason: e.to_string(),
+            }
+        })?;
+
+    report.truncated = true;
+    Ok(report)
+}
+
 /// Append validated entry to log file.
 ///
 /// ## Input
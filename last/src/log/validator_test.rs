@@ -3,9 +3,12 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::log::encoder::encode_log_entry;
+    use crate::log::encoder::{chain_log_entries, encode_log_entries_binary, encode_log_entry};
     use crate::log::types::LogEntry;
-    use crate::log::validator::{append_entry, validate_and_truncate_log, validate_log};
+    use crate::log::validator::{
+        append_entry, repair_log, validate_and_truncate_log, validate_log,
+        validate_log_tamper_evident,
+    };
     use crate::registry;
     use std::fs;
     use tempfile::TempDir;
@@ -243,4 +246,207 @@ mod tests {
             assert!(content.is_empty() || content.trim().is_empty());
         }
     }
+
+    fn create_chain(count: u64) -> Vec<LogEntry> {
+        let mut entries: Vec<LogEntry> = (0..count)
+            .map(|i| create_test_entry(1736860900 + i * 100))
+            .collect();
+        chain_log_entries(&mut entries);
+        entries
+    }
+
+    #[test]
+    fn test_tamper_evident_intact_chain() {
+        let entries = create_chain(5);
+        let report = validate_log_tamper_evident(&entries).unwrap();
+
+        assert_eq!(report.total_entries, 5);
+        assert!(report.is_intact());
+        assert!(validate_log_tamper_evident(&[]).unwrap().is_intact());
+    }
+
+    #[test]
+    fn test_tamper_evident_modified_entry() {
+        let mut entries = create_chain(5);
+        entries[2].user = "mallory".to_string();
+
+        let report = validate_log_tamper_evident(&entries).unwrap();
+        assert_eq!(report.first_broken_link, Some(2));
+    }
+
+    #[test]
+    fn test_tamper_evident_modified_genesis() {
+        // Genesis link does not cover its own content, the next link does
+        let mut entries = create_chain(3);
+        entries[0].rows = 999;
+        assert_eq!(
+            validate_log_tamper_evident(&entries)
+                .unwrap()
+                .first_broken_link,
+            Some(1)
+        );
+
+        let mut entries = create_chain(3);
+        entries[0].prev_hash[0] ^= 0xFF;
+        assert_eq!(
+            validate_log_tamper_evident(&entries)
+                .unwrap()
+                .first_broken_link,
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_tamper_evident_removed_entry() {
+        let mut entries = create_chain(4);
+        entries.remove(1);
+
+        let report = validate_log_tamper_evident(&entries).unwrap();
+        assert_eq!(report.first_broken_link, Some(1));
+    }
+
+    #[test]
+    fn test_validate_chained_log_detects_tampering() {
+        let (_log_dir, log_path, _registry_dir) = setup_test();
+
+        let mut entries = create_chain(3);
+        let healthy: Vec<String> = entries
+            .iter()
+            .map(|e| encode_log_entry(e).unwrap())
+            .collect();
+        fs::write(&log_path, format!("{}\n", healthy.join("\n"))).unwrap();
+        assert!(validate_log(&log_path).unwrap().is_healthy());
+
+        // Re-encode a modified entry: CRC32 is valid, hash link is not
+        entries[1].size = 1;
+        let tampered = [
+            healthy[0].clone(),
+            encode_log_entry(&entries[1]).unwrap(),
+            healthy[2].clone(),
+        ];
+        fs::write(&log_path, format!("{}\n", tampered.join("\n"))).unwrap();
+
+        let report = validate_log(&log_path).unwrap();
+        assert_eq!(report.total_entries, 3);
+        assert_eq!(report.valid_entries, 2);
+        assert_eq!(report.corrupted_lines, vec![2]);
+
+        let report = validate_and_truncate_log(&log_path).unwrap();
+        assert!(report.truncated);
+        assert_eq!(report.valid_entries, 1);
+    }
+
+    #[test]
+    fn test_validate_binary_log() {
+        let (_log_dir, log_path, _registry_dir) = setup_test();
+
+        let entries = create_chain(20);
+        let encoded = encode_log_entries_binary(&entries).unwrap();
+        fs::write(&log_path, &encoded).unwrap();
+
+        let report = validate_log(&log_path).unwrap();
+        assert_eq!(report.total_entries, 20);
+        assert!(report.is_healthy());
+
+        // Damage the second block: its four entries are corrupted
+        let mut damaged = encoded.clone();
+        let last = damaged.len() - 10;
+        damaged[last] ^= 0xFF;
+        fs::write(&log_path, &damaged).unwrap();
+
+        let report = validate_log(&log_path).unwrap();
+        assert_eq!(report.total_entries, 20);
+        assert_eq!(report.valid_entries, 16);
+        assert_eq!(report.corrupted_lines, vec![17, 18, 19, 20]);
+
+        let report = validate_and_truncate_log(&log_path).unwrap();
+        assert!(report.truncated);
+        assert_eq!(report.valid_entries, 16);
+        assert_eq!(
+            fs::read(&log_path).unwrap(),
+            encode_log_entries_binary(&entries[..16]).unwrap()
+        );
+        assert!(validate_log(&log_path).unwrap().is_healthy());
+    }
+
+    #[test]
+    fn test_repair_log_partial_write() {
+        let (log_dir, log_path, _registry_dir) = setup_test();
+
+        let healthy: Vec<String> = (0..3)
+            .map(|i| encode_log_entry(&create_test_entry(1736860900 + i * 100)).unwrap())
+            .collect();
+        let content = format!("{}\n", healthy.join("\n"));
+        fs::write(&log_path, &content).unwrap();
+
+        let report = repair_log(&log_path).unwrap();
+        assert!(!report.truncated);
+        assert_eq!(report.entries_retained, 3);
+        assert_eq!(report.last_good_timestamp, Some(1736861100));
+
+        // Last append cut off by a power loss
+        let partial = encode_log_entry(&create_test_entry(1736861200)).unwrap();
+        fs::write(&log_path, format!("{}{}", content, &partial[..40])).unwrap();
+        assert!(!validate_log(&log_path).unwrap().is_healthy());
+
+        let report = repair_log(&log_path).unwrap();
+        assert!(report.truncated);
+        assert_eq!(report.total_entries, 4);
+        assert_eq!(report.entries_retained, 3);
+        assert_eq!(report.entries_discarded, 1);
+        assert_eq!(report.corrupted_lines, vec![4]);
+        assert_eq!(report.last_good_timestamp, Some(1736861100));
+        assert_eq!(fs::read_to_string(&log_path).unwrap(), content);
+
+        // No temp file left behind
+        assert_eq!(fs::read_dir(log_dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_repair_log_discards_everything_after_corruption() {
+        let (_log_dir, log_path, _registry_dir) = setup_test();
+
+        let encoded1 = encode_log_entry(&create_test_entry(1736860900)).unwrap();
+        let corrupted = "REED|00000058|1736861000|2|1|1736860900|2500|15|sha256:abc|n/a|BADCRC32";
+        let encoded3 = encode_log_entry(&create_test_entry(1736861100)).unwrap();
+        fs::write(
+            &log_path,
+            format!("{}\n\n{}\n{}\n", encoded1, corrupted, encoded3),
+        )
+        .unwrap();
+
+        let report = repair_log(&log_path).unwrap();
+        assert_eq!(report.entries_retained, 1);
+        assert_eq!(report.entries_discarded, 2);
+        assert_eq!(report.corrupted_lines, vec![3]);
+        assert_eq!(report.last_good_timestamp, Some(1736860900));
+        assert_eq!(
+            fs::read_to_string(&log_path).unwrap(),
+            format!("{}\n\n", encoded1)
+        );
+
+        // Missing log: nothing to repair
+        fs::remove_file(&log_path).unwrap();
+        assert_eq!(repair_log(&log_path).unwrap().total_entries, 0);
+    }
+
+    #[test]
+    fn test_repair_binary_log() {
+        let (_log_dir, log_path, _registry_dir) = setup_test();
+
+        let entries = create_chain(20);
+        let encoded = encode_log_entries_binary(&entries).unwrap();
+        fs::write(&log_path, &encoded[..encoded.len() - 3]).unwrap();
+
+        let report = repair_log(&log_path).unwrap();
+        assert!(report.truncated);
+        assert_eq!(report.entries_retained, 16);
+        assert_eq!(report.entries_discarded, 4);
+        assert_eq!(report.last_good_timestamp, Some(entries[15].timestamp));
+        assert_eq!(
+            fs::read(&log_path).unwrap(),
+            encode_log_entries_binary(&entries[..16]).unwrap()
+        );
+        assert!(validate_log(&log_path).unwrap().is_healthy());
+    }
 }
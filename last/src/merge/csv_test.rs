@@ -7,10 +7,10 @@
 mod tests {
     use crate::concurrent::types::CsvRow;
     use crate::merge::csv::{
-        build_row_map, calculate_merge_stats, detect_conflicts, merge_changes, merge_single,
-        rows_equal,
+        build_row_map, calculate_merge_stats, detect_conflicts, merge_cells, merge_changes,
+        merge_ordered, merge_single, rows_equal,
     };
-    use crate::merge::types::MergeResult;
+    use crate::merge::types::{MergeResult, RowChange};
 
     fn create_row(key: &str, values: Vec<&str>) -> CsvRow {
         CsvRow::new(key, values)
@@ -61,10 +61,74 @@ mod tests {
                 assert_eq!(conflicts[0].key, "1");
                 assert_eq!(conflicts[0].change_a.values[1], "31");
                 assert_eq!(conflicts[0].change_b.values[1], "32");
+                assert_eq!(conflicts[0].cells, vec![1]);
             }
         }
     }
 
+    #[test]
+    fn test_merge_same_row_different_cells() {
+        let base = vec![
+            create_row("1", vec!["1", "2", "3"]),
+            create_row("2", vec!["Bob", "25"]),
+        ];
+
+        let changes_a = vec![create_row("1", vec!["10", "2", "30"])];
+
+        let changes_b = vec![
+            create_row("1", vec!["1", "20", "30"]),
+            create_row("2", vec!["Bob", "26"]),
+        ];
+
+        match merge_changes(&base, &changes_a, &changes_b).unwrap() {
+            MergeResult::Success(rows) => {
+                assert_eq!(rows.len(), 2);
+                assert_eq!(rows[0], create_row("1", vec!["10", "20", "30"]));
+                assert_eq!(rows[1].values[1], "26");
+            }
+            MergeResult::Conflicts(_) => panic!("Expected success, got conflicts"),
+        }
+    }
+
+    #[test]
+    fn test_merge_cells() {
+        let base = create_row("1", vec!["a", "b", "c"]);
+
+        // One side per cell, and the same change on both sides
+        let (merged, cells) = merge_cells(
+            Some(&base),
+            &create_row("1", vec!["A", "b", "C"]),
+            &create_row("1", vec!["a", "B", "C"]),
+        );
+        assert_eq!(merged, create_row("1", vec!["A", "B", "C"]));
+        assert!(cells.is_empty());
+
+        // Only the cell changed differently by both conflicts
+        let (merged, cells) = merge_cells(
+            Some(&base),
+            &create_row("1", vec!["A", "x", "c"]),
+            &create_row("1", vec!["a", "y", "C"]),
+        );
+        assert_eq!(cells, vec![1]);
+        assert_eq!(merged, create_row("1", vec!["A", "x", "C"]));
+
+        // Inserted by both: every differing cell conflicts
+        let (_, cells) = merge_cells(
+            None,
+            &create_row("1", vec!["a", "b", "c"]),
+            &create_row("1", vec!["a", "B", "C"]),
+        );
+        assert_eq!(cells, vec![1, 2]);
+
+        // Widths differ: cells cannot be aligned with the base
+        let (_, cells) = merge_cells(
+            Some(&base),
+            &create_row("1", vec!["a", "b"]),
+            &create_row("1", vec!["a", "b", "c", "d"]),
+        );
+        assert_eq!(cells, vec![2, 3]);
+    }
+
     #[test]
     fn test_merge_single() {
         let base = vec![
@@ -236,4 +300,66 @@ mod tests {
             MergeResult::Conflicts(_) => panic!("Expected success, got conflicts"),
         }
     }
+
+    #[test]
+    fn test_merge_ordered_insert_update_delete() {
+        let base = vec![
+            create_row("1", vec!["Alice", "30"]),
+            create_row("2", vec!["Bob", "25"]),
+            create_row("4", vec!["Dave", "40"]),
+        ];
+
+        let changes = vec![
+            RowChange::Delete("2".to_string()),
+            RowChange::Insert(create_row("3", vec!["Carol", "35"])),
+            RowChange::Update(create_row("4", vec!["Dave", "41"])),
+            RowChange::Insert(create_row("5", vec!["Eve", "22"])), // Tail
+            RowChange::Delete("6".to_string()),                    // Never existed
+        ];
+
+        match merge_ordered(&base, &changes).unwrap() {
+            MergeResult::Success(rows) => {
+                let keys: Vec<&str> = rows.iter().map(|r| r.key.as_str()).collect();
+                assert_eq!(keys, vec!["1", "3", "4", "5"]);
+                assert_eq!(rows[2].values[1], "41");
+            }
+            MergeResult::Conflicts(_) => panic!("Expected success"),
+        }
+    }
+
+    #[test]
+    fn test_merge_ordered_matches_merge_single() {
+        let base: Vec<CsvRow> = (0..100)
+            .step_by(2)
+            .map(|i| CsvRow::new(format!("key{:03}", i), vec![format!("base{}", i)]))
+            .collect();
+        let changes: Vec<CsvRow> = (0..100)
+            .step_by(3)
+            .map(|i| CsvRow::new(format!("key{:03}", i), vec![format!("new{}", i)]))
+            .collect();
+
+        let expected = merge_single(&base, &changes).unwrap();
+        let changes: Vec<RowChange> = changes.into_iter().map(RowChange::Update).collect();
+
+        match merge_ordered(&base, &changes).unwrap() {
+            MergeResult::Success(rows) => assert_eq!(rows, expected),
+            MergeResult::Conflicts(_) => panic!("Expected success"),
+        }
+    }
+
+    #[test]
+    fn test_merge_ordered_keeps_key_only_rows() {
+        let base = vec![create_row("1", vec![]), create_row("2", vec!["Bob"])];
+
+        // A row without values is a key-only row, not a delete
+        let changes = vec![RowChange::Update(create_row("2", vec![]))];
+
+        match merge_ordered(&base, &changes).unwrap() {
+            MergeResult::Success(rows) => assert_eq!(
+                rows,
+                vec![base[0].clone(), changes[0].row().unwrap().clone()]
+            ),
+            MergeResult::Conflicts(_) => panic!("Expected success"),
+        }
+    }
 }
@@ -3,12 +3,13 @@
 
 //! CSV diff calculation for change detection.
 //!
-//! Calculates row-level differences between CSV versions.
+//! Calculates row-level differences between CSV versions, applies them
+//! back (`patch`) and reverses them (`invert_diff`).
 
 use crate::concurrent::types::CsvRow;
-use crate::error::ReedResult;
+use crate::error::{ReedError, ReedResult};
 use crate::merge::types::RowChange;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
 /// Calculates diff between two CSV versions.
 ///
@@ -125,6 +126,152 @@ pub fn apply_changes(base: &[CsvRow], changes: &[RowChange]) -> ReedResult<Vec<C
     Ok(result)
 }
 
+/// Applies a diff to the rows it was calculated from.
+///
+/// Unlike `apply_changes`, every change must fit the rows it is applied
+/// to: an insert needs a new key, an update or delete an existing one.
+/// This catches a diff sent against a different snapshot instead of
+/// silently producing a mix of both.
+///
+/// ## Input
+/// - `base`: Rows the diff was calculated from
+/// - `changes`: Diff, e.g. from `calculate_diff(base, target)`
+///
+/// ## Output
+/// - `ReedResult<Vec<CsvRow>>`: Patched rows, sorted by key
+///
+/// ## Performance
+/// - O((n+m) log n) where n = rows, m = changes
+/// - < 10ms for 100 rows
+///
+/// ## Error Conditions
+/// - `DeltaApplicationFailed`: Insert of an existing key, or update/delete
+///   of a missing key
+///
+/// ## Example Usage
+/// ```no_run
+/// use reedbase_last::merge::{calculate_diff, patch};
+/// use reedbase_last::concurrent::types::CsvRow;
+///
+/// let base = vec![CsvRow::new("1", vec!["Alice", "30"])];
+/// let target = vec![CsvRow::new("2", vec!["Bob", "25"])];
+///
+/// let changes = calculate_diff(&base, &target)?;
+/// assert_eq!(patch(&base, &changes)?, target);
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn patch(base: &[CsvRow], changes: &[RowChange]) -> ReedResult<Vec<CsvRow>> {
+    let mut rows: BTreeMap<&str, CsvRow> =
+        base.iter().map(|r| (r.key.as_str(), r.clone())).collect();
+
+    for change in changes {
+        let (key, exists) = match change {
+            RowChange::Insert(row) => (&row.key, false),
+            RowChange::Update(row) => (&row.key, true),
+            RowChange::Delete(key) => (key, true),
+        };
+        if rows.contains_key(key.as_str()) != exists {
+            return Err(ReedError::DeltaApplicationFailed {
+                reason: format!(
+                    "Cannot {} row '{}': key {}",
+                    change_name(change),
+                    key,
+                    if exists {
+                        "not found"
+                    } else {
+                        "already exists"
+                    }
+                ),
+            });
+        }
+        match change {
+            RowChange::Insert(row) | RowChange::Update(row) => {
+                rows.insert(&row.key, row.clone());
+            }
+            RowChange::Delete(key) => {
+                rows.remove(key.as_str());
+            }
+        }
+    }
+
+    Ok(rows.into_values().collect())
+}
+
+/// Reverses a diff.
+///
+/// `RowChange` only carries the new state of a row, so the previous state
+/// is taken from `base`, the rows the diff applies to. Patching the result
+/// of `patch(base, changes)` with the inverted diff gives `base` again.
+///
+/// ## Input
+/// - `base`: Rows the diff applies to
+/// - `changes`: Diff to reverse
+///
+/// ## Output
+/// - `Vec<RowChange>`: Inverse diff (inserts become deletes, deletes
+///   re-insert the base row, updates restore the previous values), in
+///   reverse order
+///
+/// ## Performance
+/// - O((n+m) log n) where n = rows, m = changes
+/// - < 10ms for 100 rows
+///
+/// ## Error Conditions
+/// - None (changes that do not fit `base` are skipped, as `patch` would
+///   reject them)
+///
+/// ## Example Usage
+/// ```no_run
+/// use reedbase_last::merge::{calculate_diff, invert_diff, patch};
+/// use reedbase_last::concurrent::types::CsvRow;
+///
+/// let base = vec![CsvRow::new("1", vec!["Alice", "30"])];
+/// let target = vec![CsvRow::new("1", vec!["Alice", "31"])];
+///
+/// let changes = calculate_diff(&base, &target)?;
+/// let undo = invert_diff(&base, &changes);
+/// assert_eq!(patch(&target, &undo)?, base);
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn invert_diff(base: &[CsvRow], changes: &[RowChange]) -> Vec<RowChange> {
+    let mut rows: BTreeMap<&str, &CsvRow> = base.iter().map(|r| (r.key.as_str(), r)).collect();
+    let mut inverse = Vec::with_capacity(changes.len());
+
+    for change in changes {
+        match change {
+            RowChange::Insert(row) if !rows.contains_key(row.key.as_str()) => {
+                inverse.push(RowChange::Delete(row.key.clone()));
+                rows.insert(&row.key, row);
+            }
+            RowChange::Update(row) => {
+                if let Some(previous) = rows.insert(&row.key, row) {
+                    inverse.push(RowChange::Update(previous.clone()));
+                } else {
+                    rows.remove(row.key.as_str());
+                }
+            }
+            RowChange::Delete(key) => {
+                if let Some(previous) = rows.remove(key.as_str()) {
+                    inverse.push(RowChange::Insert(previous.clone()));
+                }
+            }
+            RowChange::Insert(_) => {}
+        }
+    }
+
+    inverse.reverse();
+    inverse
+}
+
+/// Change type as used in error messages.
+fn change_name(change: &RowChange) -> &'static str {
+    match change {
+        RowChange::Insert(_) => "insert",
+        RowChange::Update(_) => "update",
+        RowChange::Delete(_) => "delete",
+    }
+}
+
 /// Counts changes by type.
 ///
 /// ## Input
@@ -6,7 +6,8 @@
 #[cfg(test)]
 mod tests {
     use crate::concurrent::types::CsvRow;
-    use crate::merge::diff::{apply_changes, calculate_diff, count_changes};
+    use crate::error::ReedError;
+    use crate::merge::diff::{apply_changes, calculate_diff, count_changes, invert_diff, patch};
     use crate::merge::types::RowChange;
 
     fn create_row(key: &str, values: Vec<&str>) -> CsvRow {
@@ -236,4 +237,98 @@ mod tests {
         assert_eq!(changes.len(), 1);
         assert!(matches!(&changes[0], RowChange::Delete(key) if key == "1"));
     }
+
+    /// Pseudo-random rows: keys out of 0..20, one of three values each.
+    fn random_rows(seed: &mut u64) -> Vec<CsvRow> {
+        let mut next = || {
+            // xorshift64
+            *seed ^= *seed << 13;
+            *seed ^= *seed >> 7;
+            *seed ^= *seed << 17;
+            *seed
+        };
+        (0..20)
+            .filter_map(|i| {
+                let n = next();
+                (n % 3 != 0).then(|| {
+                    CsvRow::new(
+                        format!("key{:02}", i),
+                        vec![format!("v{}", n % 4), "x".into()],
+                    )
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_patch_reproduces_target() {
+        let mut seed = 0x2545_f491_4f6c_dd1d;
+        for _ in 0..200 {
+            let base = random_rows(&mut seed);
+            let mut target = random_rows(&mut seed);
+            target.reverse();
+
+            let changes = calculate_diff(&base, &target).unwrap();
+            let patched = patch(&base, &changes).unwrap();
+
+            target.sort_by(|a, b| a.key.cmp(&b.key));
+            assert_eq!(patched, target);
+        }
+    }
+
+    #[test]
+    fn test_patch_rejects_mismatched_base() {
+        let base = vec![create_row("1", vec!["Alice", "30"])];
+
+        let cases = [
+            RowChange::Insert(create_row("1", vec!["Alice", "31"])),
+            RowChange::Update(create_row("2", vec!["Bob", "25"])),
+            RowChange::Delete("2".to_string()),
+        ];
+        for change in cases {
+            assert!(matches!(
+                patch(&base, &[change]),
+                Err(ReedError::DeltaApplicationFailed { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_invert_diff() {
+        let base = vec![
+            create_row("1", vec!["Alice", "30"]),
+            create_row("2", vec!["Bob", "25"]),
+        ];
+        let changes = vec![
+            RowChange::Update(create_row("1", vec!["Alice", "31"])),
+            RowChange::Delete("2".to_string()),
+            RowChange::Insert(create_row("3", vec!["Charlie", "35"])),
+        ];
+
+        let inverse = invert_diff(&base, &changes);
+        assert_eq!(
+            inverse,
+            vec![
+                RowChange::Delete("3".to_string()),
+                RowChange::Insert(create_row("2", vec!["Bob", "25"])),
+                RowChange::Update(create_row("1", vec!["Alice", "30"])),
+            ]
+        );
+
+        // Undo restores the base, for arbitrary snapshots too
+        let target = patch(&base, &changes).unwrap();
+        assert_eq!(patch(&target, &inverse).unwrap(), base);
+
+        let mut seed = 0x9e37_79b9_7f4a_7c15;
+        for _ in 0..200 {
+            let mut base = random_rows(&mut seed);
+            let target = random_rows(&mut seed);
+            let changes = calculate_diff(&base, &target).unwrap();
+
+            let undo = invert_diff(&base, &changes);
+            let patched = patch(&base, &changes).unwrap();
+            base.sort_by(|a, b| a.key.cmp(&b.key));
+            assert_eq!(patch(&patched, &undo).unwrap(), base);
+        }
+    }
 }
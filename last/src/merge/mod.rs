@@ -12,9 +12,10 @@ pub mod types;
 
 // Re-export public APIs
 pub use csv::{
-    build_row_map, calculate_merge_stats, detect_conflicts, merge_changes, merge_single, rows_equal,
+    build_row_map, calculate_merge_stats, detect_conflicts, merge_cells, merge_changes,
+    merge_ordered, merge_single, rows_equal,
 };
-pub use diff::{apply_changes, calculate_diff, count_changes};
+pub use diff::{apply_changes, calculate_diff, count_changes, invert_diff, patch};
 pub use types::{Conflict, MergeResult, MergeStats, RowChange};
 
 #[cfg(test)]
@@ -18,6 +18,24 @@ pub enum RowChange {
     Delete(String),
 }
 
+impl RowChange {
+    /// Key of the changed row.
+    pub fn key(&self) -> &str {
+        match self {
+            RowChange::Insert(row) | RowChange::Update(row) => &row.key,
+            RowChange::Delete(key) => key,
+        }
+    }
+
+    /// Row written by the change, `None` for a delete.
+    pub fn row(&self) -> Option<&CsvRow> {
+        match self {
+            RowChange::Insert(row) | RowChange::Update(row) => Some(row),
+            RowChange::Delete(_) => None,
+        }
+    }
+}
+
 /// Merge result.
 #[derive(Debug)]
 pub enum MergeResult {
@@ -42,6 +60,10 @@ pub struct Conflict {
 
     /// Change from process B.
     pub change_b: CsvRow,
+
+    /// Indices (into `values`) of the cells both processes changed
+    /// differently.
+    pub cells: Vec<usize>,
 }
 
 /// Merge statistics.
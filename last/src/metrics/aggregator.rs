@@ -7,6 +7,7 @@
 //! - P50 (median), P95, P99 percentiles
 //! - Min, max, mean, standard deviation
 //! - Count and sum
+//! - Cumulative histogram bucket counts
 //!
 //! ## Performance
 //! - P50/P95/P99: O(n log n) due to sorting
@@ -25,15 +26,18 @@ pub struct MetricStats {
     pub p95: f64,
     pub p99: f64,
     pub stddev: f64,
+    /// `(upper_bound, cumulative_count)` per bucket, ending with +Inf
+    pub buckets: Vec<(f64, u64)>,
 }
 
 /// Calculates complete statistics for a set of values.
 ///
 /// ## Arguments
 /// - `values`: Slice of metric values
+/// - `bounds`: Ascending histogram bucket upper bounds (may be empty)
 ///
 /// ## Returns
-/// - `Some(MetricStats)`: Statistical summary
+/// - `Some(MetricStats)`: Statistical summary, with `buckets` from `bucket_counts()`
 /// - `None`: If values is empty
 ///
 /// ## Example
@@ -41,12 +45,13 @@ pub struct MetricStats {
 /// use reedbase_last::metrics::aggregator::calculate_stats;
 ///
 /// let values = vec![1.0, 2.0, 3.0, 4.0, 5.0];
-/// let stats = calculate_stats(&values).unwrap();
+/// let stats = calculate_stats(&values, &[2.0, 4.0]).unwrap();
 ///
 /// assert_eq!(stats.mean, 3.0);
 /// assert_eq!(stats.p50, 3.0);
+/// assert_eq!(stats.buckets, vec![(2.0, 2), (4.0, 4), (f64::INFINITY, 5)]);
 /// ```
-pub fn calculate_stats(values: &[f64]) -> Option<MetricStats> {
+pub fn calculate_stats(values: &[f64], bounds: &[f64]) -> Option<MetricStats> {
     if values.is_empty() {
         return None;
     }
@@ -78,9 +83,60 @@ pub fn calculate_stats(values: &[f64]) -> Option<MetricStats> {
         p95,
         p99,
         stddev,
+        buckets: bucket_counts(values, bounds),
     })
 }
 
+/// Counts values per histogram bucket, cumulatively.
+///
+/// A value is counted for every bound `>=` the value (Prometheus `le`
+/// semantics), so `[100, 500, 1000]` gives the buckets `[0, 100]`,
+/// `(100, 500]`, `(500, 1000]` and `(1000, +Inf)` as running totals.
+///
+/// ## Arguments
+/// - `values`: Slice of metric values
+/// - `bounds`: Ascending bucket upper bounds
+///
+/// ## Returns
+/// `(upper_bound, cumulative_count)` per bound, followed by
+/// `(f64::INFINITY, values.len())`
+///
+/// ## Performance
+/// - O(n log b) where b = number of bounds
+///
+/// ## Example
+/// ```
+/// use reedbase_last::metrics::aggregator::bucket_counts;
+///
+/// let buckets = bucket_counts(&[50.0, 100.0, 700.0], &[100.0, 500.0]);
+/// assert_eq!(buckets, vec![(100.0, 2), (500.0, 2), (f64::INFINITY, 3)]);
+/// ```
+pub fn bucket_counts(values: &[f64], bounds: &[f64]) -> Vec<(f64, u64)> {
+    let mut counts = vec![0u64; bounds.len() + 1];
+    for &value in values {
+        counts[bucket_index(bounds, value)] += 1;
+    }
+
+    let mut total = 0;
+    bounds
+        .iter()
+        .copied()
+        .chain(std::iter::once(f64::INFINITY))
+        .zip(counts)
+        .map(|(bound, count)| {
+            total += count;
+            (bound, total)
+        })
+        .collect()
+}
+
+/// Index of the bucket a value falls into (`bounds.len()` for +Inf).
+///
+/// NaN values land in the +Inf bucket.
+pub fn bucket_index(bounds: &[f64], value: f64) -> usize {
+    bounds.partition_point(|&bound| bound < value || value.is_nan())
+}
+
 /// Calculates a specific percentile from sorted values.
 ///
 /// ## Arguments
@@ -6,13 +6,14 @@
 #[cfg(test)]
 mod tests {
     use crate::metrics::aggregator::{
-        calculate_stats, max, mean, min, p50, p95, p99, percentile, stddev,
+        bucket_counts, bucket_index, calculate_stats, max, mean, min, p50, p95, p99, percentile,
+        stddev,
     };
 
     #[test]
     fn test_calculate_stats() {
         let values = vec![1.0, 2.0, 3.0, 4.0, 5.0];
-        let stats = calculate_stats(&values).unwrap();
+        let stats = calculate_stats(&values, &[]).unwrap();
 
         assert_eq!(stats.count, 5);
         assert_eq!(stats.sum, 15.0);
@@ -25,7 +26,7 @@ mod tests {
     #[test]
     fn test_calculate_stats_empty() {
         let values: Vec<f64> = vec![];
-        assert!(calculate_stats(&values).is_none());
+        assert!(calculate_stats(&values, &[]).is_none());
     }
 
     #[test]
@@ -86,4 +87,34 @@ mod tests {
         assert_eq!(min(&values), 0.0);
         assert_eq!(max(&values), 0.0);
     }
+
+    #[test]
+    fn test_calculate_stats_buckets() {
+        // Bimodal latencies: p50 alone hides the second cluster
+        let values = vec![40.0, 60.0, 80.0, 100.0, 900.0, 950.0, 2_000.0];
+        let stats = calculate_stats(&values, &[100.0, 500.0, 1_000.0]).unwrap();
+
+        assert_eq!(
+            stats.buckets,
+            vec![(100.0, 4), (500.0, 4), (1_000.0, 6), (f64::INFINITY, 7)]
+        );
+
+        let stats = calculate_stats(&values, &[]).unwrap();
+        assert_eq!(stats.buckets, vec![(f64::INFINITY, 7)]);
+    }
+
+    #[test]
+    fn test_bucket_index() {
+        let bounds = [100.0, 500.0];
+        assert_eq!(bucket_index(&bounds, 0.0), 0);
+        assert_eq!(bucket_index(&bounds, 100.0), 0);
+        assert_eq!(bucket_index(&bounds, 100.5), 1);
+        assert_eq!(bucket_index(&bounds, 500.0), 1);
+        assert_eq!(bucket_index(&bounds, 501.0), 2);
+        assert_eq!(bucket_index(&bounds, f64::NAN), 2);
+        assert_eq!(
+            bucket_counts(&[], &bounds),
+            vec![(100.0, 0), (500.0, 0), (f64::INFINITY, 0)]
+        );
+    }
 }
@@ -7,17 +7,32 @@
 //! - `MetricsCollector::global()`: Access singleton instance
 //! - `record()`: Record a single metric
 //! - `flush()`: Persist all buffered metrics to storage
+//! - `histogram_buckets()`: Running bucket counts of histogram metrics
+//! - `export_prometheus()`: Write buffered metrics in Prometheus text format
+//! - `ring_buffer_stats()`: Capacity, fill level and dropped metrics
+//! - `query()` / `aggregated()`: Read flushed metrics of a time window
+//!
+//! ## Persistence
+//! Buffered metrics live in a memory-mapped ring buffer at
+//! `.reedbase/metrics/.buffer` (see `ring`). Metrics left there by a crashed
+//! process are flushed to storage when the collector starts.
 //!
 //! ## Performance
-//! - O(1) record operation (lock + push)
-//! - Lock-free reads (RwLock with write bias)
-//! - Configurable buffer size (default 1000 metrics)
+//! - O(1) record operation (encode + copy into the ring)
+//! - Recording and flushing never block each other (SPSC ring)
+//! - Auto-flush at 1000 buffered metrics, ring holds 4096
 
 use once_cell::sync::Lazy;
-use std::sync::{Arc, RwLock};
+use std::collections::{BTreeMap, HashMap};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, RwLock};
 
+use super::aggregator::{bucket_index, calculate_stats, MetricStats};
+use super::ring::{MetricRing, DEFAULT_CAPACITY};
 use super::storage::MetricsStorage;
-use super::types::Metric;
+use super::types::{Metric, MetricType};
+use crate::error::{ReedError, ReedResult};
 
 /// Global singleton instance.
 static METRICS_COLLECTOR: Lazy<Arc<MetricsCollector>> =
@@ -39,24 +54,66 @@ static METRICS_COLLECTOR: Lazy<Arc<MetricsCollector>> =
 /// MetricsCollector::global().flush();
 /// ```
 pub struct MetricsCollector {
-    /// In-memory buffer of metrics (awaiting flush)
-    buffer: RwLock<Vec<Metric>>,
+    /// Memory-mapped buffer of metrics (awaiting flush)
+    ring: MetricRing,
+
+    /// Serialises recording threads (the ring takes one producer)
+    producer: Mutex<()>,
+
+    /// Serialises flushing threads (the ring takes one consumer)
+    consumer: Mutex<()>,
 
     /// Persistent storage backend
     storage: MetricsStorage,
 
     /// Maximum buffer size before auto-flush
     buffer_limit: usize,
+
+    /// Running bucket counts of histogram metrics, by metric name
+    histograms: RwLock<HashMap<String, HistogramCounts>>,
+}
+
+/// Bucket counts of one histogram metric since start (or `clear()`).
+struct HistogramCounts {
+    /// Upper bounds the counts belong to
+    bounds: Arc<[f64]>,
+    /// Per-bucket (not cumulative) counts, last entry is +Inf
+    counts: Vec<u64>,
 }
 
 impl MetricsCollector {
     /// Creates a new metrics collector with default settings.
-    fn new() -> Self {
-        Self {
-            buffer: RwLock::new(Vec::with_capacity(1000)),
-            storage: MetricsStorage::new(),
+    pub(crate) fn new() -> Self {
+        Self::with_directory(".reedbase/metrics")
+    }
+
+    /// Creates a collector storing metrics and its ring buffer in `dir`.
+    ///
+    /// Metrics left in `dir/.buffer` by a previous process are flushed to
+    /// storage before the collector is returned. If the buffer file cannot
+    /// be opened (e.g. another collector holds it), the collector falls
+    /// back to an in-memory ring without crash persistence.
+    pub(crate) fn with_directory(dir: impl Into<PathBuf>) -> Self {
+        let dir = dir.into();
+        let ring = MetricRing::open(&dir.join(".buffer"), DEFAULT_CAPACITY)
+            .or_else(|e| {
+                eprintln!("Metrics buffer not persistent: {}", e);
+                MetricRing::anonymous(DEFAULT_CAPACITY)
+            })
+            .expect("Failed to map metrics buffer");
+
+        let collector = Self {
+            ring,
+            producer: Mutex::new(()),
+            consumer: Mutex::new(()),
+            storage: MetricsStorage::with_directory(dir),
             buffer_limit: 1000,
-        }
+            histograms: RwLock::new(HashMap::new()),
+        };
+
+        // Recover metrics of a previous run before accepting new ones
+        collector.flush();
+        collector
     }
 
     /// Returns the global singleton instance.
@@ -76,30 +133,36 @@ impl MetricsCollector {
     /// ## Arguments
     /// - `metric`: Metric to record
     ///
+    /// Histogram metrics are also counted into their buckets
+    /// (see `histogram_buckets()`).
+    ///
     /// ## Performance
-    /// - O(1) operation
-    /// - Acquires write lock briefly
+    /// - O(1) operation (O(log b) for histograms with b buckets)
+    /// - Acquires the producer lock briefly
     /// - Auto-flushes if buffer exceeds limit
+    /// - Drops the metric if the ring buffer is full (see `ring_buffer_stats()`)
     pub fn record(&self, metric: Metric) {
-        let mut buffer = self.buffer.write().expect("Failed to acquire write lock");
-        buffer.push(metric);
-
-        // Auto-flush if buffer limit reached
-        if buffer.len() >= self.buffer_limit {
-            drop(buffer); // Release lock before flushing
-            self.flush();
-        }
+        self.record_batch(vec![metric]);
     }
 
     /// Records multiple metrics at once.
     ///
     /// More efficient than calling `record()` multiple times.
     pub fn record_batch(&self, metrics: Vec<Metric>) {
-        let mut buffer = self.buffer.write().expect("Failed to acquire write lock");
-        buffer.extend(metrics);
+        self.count_buckets(&metrics);
+
+        {
+            let _producer = self
+                .producer
+                .lock()
+                .expect("Failed to acquire producer lock");
+            for metric in &metrics {
+                self.ring.push(metric);
+            }
+        }
 
-        if buffer.len() >= self.buffer_limit {
-            drop(buffer);
+        // Auto-flush if buffer limit reached
+        if self.ring.len() >= self.buffer_limit {
             self.flush();
         }
     }
@@ -107,18 +170,20 @@ impl MetricsCollector {
     /// Flushes all buffered metrics to persistent storage.
     ///
     /// ## Behaviour
-    /// - Swaps buffer with empty Vec (minimal lock time)
-    /// - Writes to CSV storage asynchronously
+    /// - Drains the ring buffer (recording continues meanwhile)
+    /// - Writes to CSV storage
     /// - Safe to call concurrently
     ///
     /// ## Error Handling
     /// - Logs errors but does not panic
     /// - Failed metrics are lost (trade-off for performance)
     pub fn flush(&self) {
-        // Swap buffer with empty Vec to minimize lock time
         let metrics = {
-            let mut buffer = self.buffer.write().expect("Failed to acquire write lock");
-            std::mem::replace(&mut *buffer, Vec::with_capacity(self.buffer_limit))
+            let _consumer = self
+                .consumer
+                .lock()
+                .expect("Failed to acquire consumer lock");
+            self.ring.drain()
         };
 
         if metrics.is_empty() {
@@ -131,22 +196,260 @@ impl MetricsCollector {
         }
     }
 
+    /// Writes all buffered metrics in the Prometheus text format.
+    ///
+    /// The buffer is left as is; `flush()` still persists the metrics.
+    /// See `write_prometheus()` for the exact output.
+    ///
+    /// ## Arguments
+    /// - `writer`: Destination, e.g. an HTTP response body
+    ///
+    /// ## Performance
+    /// - O(n log n) where n = buffered metrics
+    /// - Holds the consumer lock only while copying the buffer
+    ///
+    /// ## Error Conditions
+    /// - `IoError`: Writer failed
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use reedbase_last::metrics::collector::MetricsCollector;
+    ///
+    /// let mut body = Vec::new();
+    /// MetricsCollector::global().export_prometheus(&mut body)?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn export_prometheus(&self, writer: &mut impl Write) -> ReedResult<()> {
+        let metrics = {
+            let _consumer = self
+                .consumer
+                .lock()
+                .expect("Failed to acquire consumer lock");
+            self.ring.peek()
+        };
+        write_prometheus(&metrics, writer)
+    }
+
     /// Returns the current buffer size.
     ///
     /// Useful for monitoring and testing.
     pub fn buffer_size(&self) -> usize {
-        self.buffer
+        self.ring.len()
+    }
+
+    /// Returns the state of the ring buffer.
+    ///
+    /// A growing `dropped` count means metrics arrive faster than they are
+    /// flushed (or are too large for a ring slot) and are being discarded.
+    ///
+    /// ## Returns
+    /// - `(capacity, used, dropped)`: Slots in the ring, slots holding
+    ///   unflushed metrics, and metrics dropped since the buffer file was
+    ///   created
+    ///
+    /// ## Example
+    /// ```
+    /// use reedbase_last::metrics::collector::MetricsCollector;
+    ///
+    /// let (capacity, used, dropped) = MetricsCollector::global().ring_buffer_stats();
+    /// assert!(used <= capacity);
+    /// if dropped > 0 {
+    ///     eprintln!("{} metrics dropped, flush more often", dropped);
+    /// }
+    /// ```
+    pub fn ring_buffer_stats(&self) -> (usize, usize, u64) {
+        (self.ring.capacity(), self.ring.len(), self.ring.dropped())
+    }
+
+    /// Reads stored metrics of one name within a time window.
+    ///
+    /// Only flushed metrics are read; call `flush()` first to include the
+    /// buffer. Metric types are not stored, so results are gauges.
+    ///
+    /// ## Arguments
+    /// - `metric_name`: Metric name (file `{metric_name}.csv` in storage)
+    /// - `start`: First timestamp in nanoseconds (inclusive)
+    /// - `end`: Last timestamp in nanoseconds (inclusive)
+    ///
+    /// ## Returns
+    /// - `Vec<Metric>`: Matching metrics in file order (empty if the metric
+    ///   has never been flushed)
+    ///
+    /// ## Performance
+    /// - O(n) where n = stored samples of the metric
+    /// - Loads the whole metric file
+    ///
+    /// ## Error Conditions
+    /// - `IoError`: Metric file cannot be read
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use reedbase_last::metrics::collector::MetricsCollector;
+    ///
+    /// let collector = MetricsCollector::global();
+    /// collector.flush();
+    /// let samples = collector.query("query_duration", 0, u64::MAX)?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn query(&self, metric_name: &str, start: u64, end: u64) -> ReedResult<Vec<Metric>> {
+        let metrics = self
+            .storage
+            .read_metrics(metric_name)
+            .map_err(|e| ReedError::IoError {
+                operation: "query_metrics".to_string(),
+                reason: format!("{}: {}", metric_name, e),
+            })?;
+
+        Ok(metrics
+            .into_iter()
+            .filter(|m| (start..=end).contains(&m.timestamp))
+            .collect())
+    }
+
+    /// Calculates statistics of stored metrics within a time window.
+    ///
+    /// Uses the same samples as `query()`. Buckets use the bounds of the
+    /// histogram recorded under `metric_name` in this process, if any.
+    ///
+    /// ## Arguments
+    /// - `metric_name`: Metric name
+    /// - `start`: First timestamp in nanoseconds (inclusive)
+    /// - `end`: Last timestamp in nanoseconds (inclusive)
+    ///
+    /// ## Returns
+    /// - `MetricStats`: Count, mean, min/max, p50/p95/p99 and buckets
+    ///
+    /// ## Error Conditions
+    /// - `IoError`: Metric file cannot be read
+    /// - `MetricNotFound`: No samples in the window
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use reedbase_last::metrics::collector::MetricsCollector;
+    ///
+    /// let stats = MetricsCollector::global().aggregated("query_duration", 0, u64::MAX)?;
+    /// println!("p99: {}", stats.p99);
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn aggregated(&self, metric_name: &str, start: u64, end: u64) -> ReedResult<MetricStats> {
+        let values: Vec<f64> = self
+            .query(metric_name, start, end)?
+            .iter()
+            .map(|m| m.value)
+            .collect();
+
+        let bounds = self
+            .histograms
             .read()
             .expect("Failed to acquire read lock")
-            .len()
+            .get(metric_name)
+            .map(|h| Arc::clone(&h.bounds))
+            .unwrap_or_else(|| Arc::from([]));
+
+        calculate_stats(&values, &bounds).ok_or_else(|| ReedError::MetricNotFound {
+            name: metric_name.to_string(),
+            start,
+            end,
+        })
     }
 
-    /// Clears the buffer without persisting.
+    /// Returns the bucket counts of a histogram metric.
+    ///
+    /// Counts accumulate across flushes, like Prometheus histograms.
+    /// Recording a histogram with different bounds under the same name
+    /// restarts its counts with the new bounds.
+    ///
+    /// ## Arguments
+    /// - `name`: Metric name
+    ///
+    /// ## Returns
+    /// - `Some(Vec<(f64, u64)>)`: `(upper_bound, cumulative_count)` per
+    ///   bucket, ending with `f64::INFINITY`
+    /// - `None`: No histogram recorded under `name`
+    ///
+    /// ## Example
+    /// ```
+    /// use reedbase_last::metrics::collector::MetricsCollector;
+    /// use reedbase_last::metrics::types::{Metric, MetricType, MetricUnit, DEFAULT_LATENCY_BUCKETS};
+    ///
+    /// let collector = MetricsCollector::global();
+    /// collector.record(
+    ///     Metric::new("doc_latency", 250.0, MetricUnit::Microseconds)
+    ///         .with_type(MetricType::histogram(&DEFAULT_LATENCY_BUCKETS)),
+    /// );
+    /// let buckets = collector.histogram_buckets("doc_latency").unwrap();
+    /// assert_eq!(buckets[1], (500.0, 1));
+    /// ```
+    pub fn histogram_buckets(&self, name: &str) -> Option<Vec<(f64, u64)>> {
+        let histograms = self.histograms.read().expect("Failed to acquire read lock");
+        let histogram = histograms.get(name)?;
+
+        let mut total = 0;
+        Some(
+            histogram
+                .bounds
+                .iter()
+                .copied()
+                .chain(std::iter::once(f64::INFINITY))
+                .zip(&histogram.counts)
+                .map(|(bound, count)| {
+                    total += count;
+                    (bound, total)
+                })
+                .collect(),
+        )
+    }
+
+    /// Adds histogram metrics to their running bucket counts.
+    fn count_buckets(&self, metrics: &[Metric]) {
+        if !metrics
+            .iter()
+            .any(|m| matches!(m.metric_type, MetricType::Histogram { .. }))
+        {
+            return;
+        }
+
+        let mut histograms = self
+            .histograms
+            .write()
+            .expect("Failed to acquire write lock");
+        for metric in metrics {
+            let MetricType::Histogram { bounds } = &metric.metric_type else {
+                continue;
+            };
+
+            let histogram =
+                histograms
+                    .entry(metric.name.clone())
+                    .or_insert_with(|| HistogramCounts {
+                        bounds: Arc::clone(bounds),
+                        counts: vec![0; bounds.len() + 1],
+                    });
+            if histogram.bounds != *bounds {
+                *histogram = HistogramCounts {
+                    bounds: Arc::clone(bounds),
+                    counts: vec![0; bounds.len() + 1],
+                };
+            }
+            histogram.counts[bucket_index(bounds, metric.value)] += 1;
+        }
+    }
+
+    /// Clears the buffer and histogram counts without persisting.
     ///
     /// ⚠️ **WARNING**: Discards all buffered metrics. Use only for testing.
     pub fn clear(&self) {
-        let mut buffer = self.buffer.write().expect("Failed to acquire write lock");
-        buffer.clear();
+        {
+            let _consumer = self
+                .consumer
+                .lock()
+                .expect("Failed to acquire consumer lock");
+            self.ring.drain();
+        }
+        self.histograms
+            .write()
+            .expect("Failed to acquire write lock")
+            .clear();
     }
 }
 
@@ -156,3 +459,185 @@ impl Drop for MetricsCollector {
         self.flush();
     }
 }
+
+/// Aggregated samples of one label set.
+struct Series {
+    /// Value of the newest sample (gauges and counters)
+    value: f64,
+    /// Sum of all sample values (histograms)
+    sum: f64,
+    /// Per-bucket counts, last entry is +Inf (histograms)
+    buckets: Vec<u64>,
+    /// Number of samples (histograms)
+    count: u64,
+    /// Newest sample timestamp in nanoseconds
+    timestamp: u64,
+}
+
+/// Writes metrics in the Prometheus text exposition format.
+///
+/// Metrics are grouped into families by name, with the unit appended as a
+/// suffix (`query_duration` in microseconds becomes
+/// `query_duration_microseconds`). Each family gets one `# TYPE` line; the
+/// first metric of a family decides its type. Within a family, tags form
+/// the label set and each label set is written once, since Prometheus
+/// rejects duplicate series:
+/// - Gauges and counters report the newest value
+/// - Histograms and timers report cumulative `_bucket` lines (one per
+///   bound of the family's type, then `le="+Inf"`), `_sum` and `_count`
+///
+/// Timestamps are converted to milliseconds. Names and label names are
+/// reduced to `[a-zA-Z0-9_]`, label values are escaped.
+///
+/// ## Error Conditions
+/// - `IoError`: Writer failed
+pub(crate) fn write_prometheus(metrics: &[Metric], writer: &mut impl Write) -> ReedResult<()> {
+    let mut families: BTreeMap<String, (MetricType, BTreeMap<String, Series>)> = BTreeMap::new();
+
+    for metric in metrics {
+        let (metric_type, series) = families
+            .entry(prometheus_name(metric))
+            .or_insert_with(|| (metric.metric_type.clone(), BTreeMap::new()));
+        let bounds = metric_type.bounds();
+        let entry = series.entry(prometheus_labels(metric)).or_insert(Series {
+            value: metric.value,
+            sum: 0.0,
+            buckets: vec![0; bounds.len() + 1],
+            count: 0,
+            timestamp: metric.timestamp,
+        });
+        entry.buckets[bucket_index(bounds, metric.value)] += 1;
+        if metric.timestamp >= entry.timestamp {
+            entry.value = metric.value;
+            entry.timestamp = metric.timestamp;
+        }
+        entry.sum += metric.value;
+        entry.count += 1;
+    }
+
+    let mut out = String::new();
+    for (name, (metric_type, series)) in &families {
+        out.push_str(&format!(
+            "# TYPE {} {}\n",
+            name,
+            metric_type.prometheus_type()
+        ));
+
+        for (labels, s) in series {
+            let timestamp = s.timestamp / 1_000_000;
+            match metric_type {
+                MetricType::Counter | MetricType::Gauge => {
+                    out.push_str(&format!(
+                        "{}{} {} {}\n",
+                        name,
+                        braced(labels),
+                        prometheus_value(s.value),
+                        timestamp
+                    ));
+                }
+                MetricType::Histogram { .. } | MetricType::Timer => {
+                    let bounds = metric_type.bounds().iter().map(|b| prometheus_value(*b));
+                    let mut cumulative = 0;
+                    for (bound, count) in bounds.chain(["+Inf".to_string()]).zip(&s.buckets) {
+                        cumulative += count;
+                        let le = format!("le=\"{}\"", bound);
+                        let bucket_labels = if labels.is_empty() {
+                            le
+                        } else {
+                            format!("{},{}", labels, le)
+                        };
+                        out.push_str(&format!(
+                            "{}_bucket{{{}}} {} {}\n",
+                            name, bucket_labels, cumulative, timestamp
+                        ));
+                    }
+                    out.push_str(&format!(
+                        "{}_sum{} {} {}\n",
+                        name,
+                        braced(labels),
+                        prometheus_value(s.sum),
+                        timestamp
+                    ));
+                    out.push_str(&format!(
+                        "{}_count{} {} {}\n",
+                        name,
+                        braced(labels),
+                        s.count,
+                        timestamp
+                    ));
+                }
+            }
+        }
+    }
+
+    writer
+        .write_all(out.as_bytes())
+        .map_err(|e| ReedError::IoError {
+            operation: "export_prometheus".to_string(),
+            reason: e.to_string(),
+        })
+}
+
+/// Metric name with unit suffix, restricted to Prometheus name characters.
+fn prometheus_name(metric: &Metric) -> String {
+    let mut name = sanitise_name(&metric.name);
+    let suffix = metric.unit.prometheus_suffix();
+    if !suffix.is_empty() && !name.ends_with(&format!("_{}", suffix)) {
+        name.push('_');
+        name.push_str(suffix);
+    }
+    name
+}
+
+/// Sorted `key="value"` pairs of the metric's tags, without braces.
+fn prometheus_labels(metric: &Metric) -> String {
+    let mut tags: Vec<(String, &String)> = metric
+        .tags
+        .iter()
+        .map(|(key, value)| (sanitise_name(key), value))
+        .collect();
+    tags.sort();
+
+    tags.iter()
+        .map(|(key, value)| {
+            let escaped = value
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('\n', "\\n");
+            format!("{}=\"{}\"", key, escaped)
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Wraps a non-empty label list in braces.
+fn braced(labels: &str) -> String {
+    if labels.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", labels)
+    }
+}
+
+/// Replaces characters outside `[a-zA-Z0-9_]`, prefixing a leading digit.
+fn sanitise_name(name: &str) -> String {
+    let mut out: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if out.is_empty() || out.starts_with(|c: char| c.is_ascii_digit()) {
+        out.insert(0, '_');
+    }
+    out
+}
+
+/// Formats a sample value, spelling out infinities as Prometheus expects.
+fn prometheus_value(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "+Inf" } else { "-Inf" }.to_string()
+    } else {
+        value.to_string()
+    }
+}
@@ -5,8 +5,11 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::metrics::collector::MetricsCollector;
-    use crate::metrics::types::{Metric, MetricUnit};
+    use crate::error::ReedError;
+    use crate::metrics::collector::{write_prometheus, MetricsCollector};
+    use crate::metrics::ring::{MetricRing, DEFAULT_CAPACITY};
+    use crate::metrics::storage::MetricsStorage;
+    use crate::metrics::types::{Metric, MetricType, MetricUnit};
     use std::sync::Arc;
 
     #[test]
@@ -69,4 +72,298 @@ mod tests {
         collector.clear();
         assert_eq!(collector.buffer_size(), 0);
     }
+
+    fn at(metric: Metric, millis: u64) -> Metric {
+        Metric {
+            timestamp: millis * 1_000_000,
+            ..metric
+        }
+    }
+
+    fn prometheus(metrics: &[Metric]) -> String {
+        let mut out = Vec::new();
+        write_prometheus(metrics, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_prometheus_gauge_and_counter() {
+        let metrics = vec![
+            at(
+                Metric::new("query_duration", 1250.0, MetricUnit::Microseconds)
+                    .with_tag("table", "text")
+                    .with_tag("operation", "get"),
+                1000,
+            ),
+            // Newer sample of the same series replaces the value
+            at(
+                Metric::new("query_duration", 900.5, MetricUnit::Microseconds)
+                    .with_tag("operation", "get")
+                    .with_tag("table", "text"),
+                2000,
+            ),
+            at(
+                Metric::new("requests", 42.0, MetricUnit::Count).with_type(MetricType::Counter),
+                3000,
+            ),
+        ];
+
+        assert_eq!(
+            prometheus(&metrics),
+            "# TYPE query_duration_microseconds gauge\n\
+             query_duration_microseconds{operation=\"get\",table=\"text\"} 900.5 2000\n\
+             # TYPE requests counter\n\
+             requests 42 3000\n"
+        );
+    }
+
+    #[test]
+    fn test_prometheus_histogram() {
+        let metrics = vec![
+            at(
+                Metric::new("write_latency_ms", 3.0, MetricUnit::Milliseconds)
+                    .with_type(MetricType::Timer)
+                    .with_tag("table", "a"),
+                10,
+            ),
+            at(
+                Metric::new("write_latency_ms", 5.0, MetricUnit::Milliseconds)
+                    .with_type(MetricType::Timer)
+                    .with_tag("table", "a"),
+                20,
+            ),
+            at(
+                Metric::new("row_size", 128.0, MetricUnit::Bytes)
+                    .with_type(MetricType::histogram(&[])),
+                30,
+            ),
+        ];
+
+        assert_eq!(
+            prometheus(&metrics),
+            "# TYPE row_size_bytes histogram\n\
+             row_size_bytes_bucket{le=\"+Inf\"} 1 30\n\
+             row_size_bytes_sum 128 30\n\
+             row_size_bytes_count 1 30\n\
+             # TYPE write_latency_ms_milliseconds histogram\n\
+             write_latency_ms_milliseconds_bucket{table=\"a\",le=\"+Inf\"} 2 20\n\
+             write_latency_ms_milliseconds_sum{table=\"a\"} 8 20\n\
+             write_latency_ms_milliseconds_count{table=\"a\"} 2 20\n"
+        );
+    }
+
+    #[test]
+    fn test_prometheus_names_and_escaping() {
+        let metrics = vec![
+            at(
+                Metric::new("cache.hit-rate", 97.5, MetricUnit::Percent)
+                    .with_tag("table name", "a\"b\\c\nd"),
+                1,
+            ),
+            at(
+                Metric::new("elapsed_seconds", f64::INFINITY, MetricUnit::Seconds),
+                1,
+            ),
+        ];
+
+        assert_eq!(
+            prometheus(&metrics),
+            "# TYPE cache_hit_rate_percent gauge\n\
+             cache_hit_rate_percent{table_name=\"a\\\"b\\\\c\\nd\"} 97.5 1\n\
+             # TYPE elapsed_seconds gauge\n\
+             elapsed_seconds +Inf 1\n"
+        );
+    }
+
+    #[test]
+    fn test_export_prometheus_writer_error() {
+        struct Failing;
+        impl std::io::Write for Failing {
+            fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::other("closed"))
+            }
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let metrics = vec![Metric::new("test", 1.0, MetricUnit::Count)];
+        assert!(matches!(
+            write_prometheus(&metrics, &mut Failing),
+            Err(crate::error::ReedError::IoError { .. })
+        ));
+        assert!(MetricsCollector::global()
+            .export_prometheus(&mut Vec::new())
+            .is_ok());
+    }
+
+    #[test]
+    fn test_histogram_buckets() {
+        // Own instance: other tests clear the global collector concurrently
+        let dir = tempfile::tempdir().unwrap();
+        let collector = MetricsCollector::with_directory(dir.path());
+        let latency = MetricType::histogram(&[100.0, 500.0, 1_000.0]);
+        let sample = |value| {
+            Metric::new("latency", value, MetricUnit::Microseconds).with_type(latency.clone())
+        };
+
+        collector.record(sample(50.0));
+        collector.record_batch(vec![sample(120.0), sample(900.0), sample(5_000.0)]);
+        collector.record(Metric::new("gauge", 1.0, MetricUnit::Count));
+
+        assert_eq!(
+            collector.histogram_buckets("latency").unwrap(),
+            vec![(100.0, 1), (500.0, 2), (1_000.0, 3), (f64::INFINITY, 4)]
+        );
+        assert!(collector.histogram_buckets("gauge").is_none());
+
+        // New bounds restart the counts
+        collector.record(
+            Metric::new("latency", 50.0, MetricUnit::Microseconds)
+                .with_type(MetricType::histogram(&[10.0])),
+        );
+        assert_eq!(
+            collector.histogram_buckets("latency").unwrap(),
+            vec![(10.0, 0), (f64::INFINITY, 1)]
+        );
+
+        // Drop the buffer so the collector's Drop has nothing to flush
+        collector.clear();
+        assert!(collector.histogram_buckets("latency").is_none());
+    }
+
+    #[test]
+    fn test_prometheus_histogram_bounds() {
+        let bounds = MetricType::histogram(&[100.0, 500.0]);
+        let metrics: Vec<Metric> = [50.0, 100.0, 300.0, 700.0]
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| {
+                at(
+                    Metric::new("lookup", value, MetricUnit::Microseconds)
+                        .with_type(bounds.clone()),
+                    i as u64 + 1,
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            prometheus(&metrics),
+            "# TYPE lookup_microseconds histogram\n\
+             lookup_microseconds_bucket{le=\"100\"} 2 4\n\
+             lookup_microseconds_bucket{le=\"500\"} 3 4\n\
+             lookup_microseconds_bucket{le=\"+Inf\"} 4 4\n\
+             lookup_microseconds_sum 1150 4\n\
+             lookup_microseconds_count 4 4\n"
+        );
+    }
+
+    #[test]
+    fn test_recovers_unflushed_metrics() {
+        let dir = tempfile::tempdir().unwrap();
+
+        // A crashed process left metrics in the ring buffer
+        {
+            let ring = MetricRing::open(&dir.path().join(".buffer"), DEFAULT_CAPACITY).unwrap();
+            ring.push(&Metric::new("crashed", 1.0, MetricUnit::Count));
+            ring.push(&Metric::new("crashed", 2.0, MetricUnit::Count));
+        }
+
+        let collector = MetricsCollector::with_directory(dir.path());
+        assert_eq!(collector.buffer_size(), 0);
+
+        let storage = MetricsStorage::with_directory(dir.path());
+        let values: Vec<f64> = storage
+            .read_metrics("crashed")
+            .unwrap()
+            .iter()
+            .map(|m| m.value)
+            .collect();
+        assert_eq!(values, vec![1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_ring_buffer_stats() {
+        let dir = tempfile::tempdir().unwrap();
+        let collector = MetricsCollector::with_directory(dir.path());
+        assert_eq!(collector.ring_buffer_stats(), (DEFAULT_CAPACITY, 0, 0));
+
+        collector.record(Metric::new("stats", 1.0, MetricUnit::Count));
+        collector.record(Metric::new("stats", 2.0, MetricUnit::Count));
+        assert_eq!(collector.ring_buffer_stats(), (DEFAULT_CAPACITY, 2, 0));
+
+        // Unflushed metrics stay in the buffer file until the next start
+        drop(collector);
+        let collector = MetricsCollector::with_directory(dir.path());
+        assert_eq!(collector.ring_buffer_stats(), (DEFAULT_CAPACITY, 0, 0));
+        assert_eq!(
+            MetricsStorage::with_directory(dir.path())
+                .read_metrics("stats")
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_query_time_window() {
+        let dir = tempfile::tempdir().unwrap();
+        let collector = MetricsCollector::with_directory(dir.path());
+        collector.record_batch(
+            (1..=5)
+                .map(|i| at(Metric::new("window", i as f64, MetricUnit::Count), i))
+                .collect(),
+        );
+
+        // Unflushed metrics are not visible yet
+        assert!(collector.query("window", 0, u64::MAX).unwrap().is_empty());
+        collector.flush();
+
+        let values: Vec<f64> = collector
+            .query("window", 2_000_000, 4_000_000)
+            .unwrap()
+            .iter()
+            .map(|m| m.value)
+            .collect();
+        assert_eq!(values, vec![2.0, 3.0, 4.0]);
+        assert!(collector
+            .query("window", 6_000_000, 9_000_000)
+            .unwrap()
+            .is_empty());
+        assert!(collector.query("missing", 0, u64::MAX).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_aggregated() {
+        let dir = tempfile::tempdir().unwrap();
+        let collector = MetricsCollector::with_directory(dir.path());
+        collector.record_batch(
+            (1..=100)
+                .map(|i| {
+                    at(
+                        Metric::new("agg", i as f64, MetricUnit::Microseconds)
+                            .with_type(MetricType::histogram(&[50.0])),
+                        i,
+                    )
+                })
+                .collect(),
+        );
+        collector.flush();
+
+        let stats = collector.aggregated("agg", 0, u64::MAX).unwrap();
+        assert_eq!(stats.count, 100);
+        assert_eq!(stats.p50, 50.5);
+        assert!(stats.p95 >= 95.0 && stats.p99 >= 99.0);
+        assert_eq!(stats.buckets, vec![(50.0, 50), (f64::INFINITY, 100)]);
+
+        let window = collector
+            .aggregated("agg", 91_000_000, 100_000_000)
+            .unwrap();
+        assert_eq!((window.count, window.min, window.max), (10, 91.0, 100.0));
+
+        assert!(matches!(
+            collector.aggregated("agg", 200_000_000, 300_000_000),
+            Err(ReedError::MetricNotFound { .. })
+        ));
+    }
 }
@@ -62,20 +62,23 @@
 //!
 //! ## Performance Characteristics
 //!
-//! - **Record**: O(1) - lock + push to buffer
+//! - **Record**: O(1) - push to the memory-mapped ring buffer
 //! - **Flush**: O(n) - write batched metrics to CSV
 //! - **Aggregation**: O(n log n) - sorting for percentiles
 //! - **Storage**: Append-only CSV (no seeks)
 //!
 //! ## Thread Safety
 //!
-//! - `MetricsCollector` uses `RwLock` for thread-safe access
+//! - `MetricsCollector` buffers in a lock-free SPSC ring (`ring`), with one
+//!   mutex each serialising recording and flushing threads
 //! - Multiple threads can record metrics concurrently
-//! - Flush operations are synchronized
+//! - Flush operations are synchronized and do not block recording
+//! - Unflushed metrics survive crashes in `.reedbase/metrics/.buffer`
 //! - Storage writes are atomic (temp file + rename)
 
 pub mod aggregator;
 pub mod collector;
+pub mod ring;
 pub mod storage;
 pub mod types;
 
@@ -86,6 +89,8 @@ mod collector_test;
 #[cfg(test)]
 mod mod_test;
 #[cfg(test)]
+mod ring_test;
+#[cfg(test)]
 mod storage_test;
 #[cfg(test)]
 mod types_test;
@@ -32,7 +32,7 @@ mod integration_tests {
     fn test_metric_aggregation() {
         let values = vec![100.0, 200.0, 300.0, 400.0, 500.0];
 
-        let stats = calculate_stats(&values).unwrap();
+        let stats = calculate_stats(&values, &[]).unwrap();
 
         assert_eq!(stats.count, 5);
         assert_eq!(stats.mean, 300.0);
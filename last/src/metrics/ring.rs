@@ -0,0 +1,397 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Memory-mapped ring buffer for unflushed metrics.
+//!
+//! Backs `MetricsCollector` so buffered metrics survive a process crash:
+//! the mapping is shared with `.reedbase/metrics/.buffer`, and whatever is
+//! still in the ring on the next start is flushed to storage.
+//!
+//! ## File Layout
+//! ```text
+//! Header (64 bytes):
+//!   0..4    magic "RBMR"
+//!   4..8    format version (u32 LE)
+//!   8..16   capacity in slots (u64 LE)
+//!   16..24  slot size in bytes (u64 LE)
+//!   24..32  head: slots consumed (atomic u64)
+//!   32..40  tail: slots produced (atomic u64)
+//!   40..48  dropped: metrics rejected while full (atomic u64)
+//!   48..64  reserved
+//! Slots (capacity × slot size):
+//!   0..4    payload length (u32 LE), 0 = empty
+//!   4..8    CRC32 of payload (u32 LE)
+//!   8..     bincode-encoded metric
+//! ```
+//!
+//! ## Concurrency
+//! Lock-free for one producer and one consumer at a time. `head` and `tail`
+//! only grow; slot `n` lives at index `n % capacity`. The producer fills a
+//! slot and then publishes it with a release store of `tail`; the consumer
+//! reads up to an acquire load of `tail` and frees slots with a release
+//! store of `head`. Callers with several producers (or consumers) must
+//! serialise them; `MetricsCollector` does so with one mutex per side.
+//!
+//! ## Durability
+//! Survives process crashes (the page cache holds the shared mapping).
+//! Metrics written shortly before a power loss may be lost.
+
+use std::fs::{self, File, OpenOptions};
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use fs2::FileExt;
+use memmap2::MmapMut;
+use serde::{Deserialize, Serialize};
+
+use super::types::{Metric, MetricType, MetricUnit};
+use crate::error::{ReedError, ReedResult};
+
+/// File magic.
+const MAGIC: &[u8; 4] = b"RBMR";
+
+/// File format version.
+const VERSION: u32 = 1;
+
+/// Header size in bytes (slots start here).
+const HEADER_SIZE: usize = 64;
+
+/// Bytes per slot, including the 8-byte slot header.
+pub const SLOT_SIZE: usize = 256;
+
+/// Default number of slots (1 MiB file).
+pub const DEFAULT_CAPACITY: usize = 4096;
+
+const HEAD_OFFSET: usize = 24;
+const TAIL_OFFSET: usize = 32;
+const DROPPED_OFFSET: usize = 40;
+
+/// Fixed-capacity SPSC ring of metrics in a shared memory mapping.
+pub struct MetricRing {
+    /// Mapping of header and slots (written through `ptr`)
+    mmap: MmapMut,
+
+    /// Base pointer of `mmap`, used for concurrent slot access
+    ptr: *mut u8,
+
+    /// Number of slots
+    capacity: u64,
+
+    /// Backing file, kept open to hold its exclusive lock (None if anonymous)
+    _file: Option<File>,
+}
+
+// SAFETY: Slot bytes are only written by the producer before publishing them
+// via `tail` and only read by the consumer after observing `tail`; the
+// counters themselves are atomics. The mapping lives as long as `self`.
+unsafe impl Send for MetricRing {}
+unsafe impl Sync for MetricRing {}
+
+impl MetricRing {
+    /// Opens or creates a file-backed ring.
+    ///
+    /// An existing file with matching capacity keeps its unconsumed metrics.
+    /// A file with a different layout, or with inconsistent counters, is
+    /// reset to an empty ring.
+    ///
+    /// ## Arguments
+    /// - `path`: Ring file (parent directories are created)
+    /// - `capacity`: Number of slots
+    ///
+    /// ## Error Conditions
+    /// - `IoError`: File cannot be created, locked (already open elsewhere),
+    ///   resized or mapped
+    pub fn open(path: &Path, capacity: usize) -> ReedResult<Self> {
+        let io_err = |operation: &str, e: std::io::Error| ReedError::IoError {
+            operation: operation.to_string(),
+            reason: format!("{}: {}", path.display(), e),
+        };
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| io_err("create_metrics_dir", e))?;
+        }
+
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)
+            .map_err(|e| io_err("open_metrics_buffer", e))?;
+        file.try_lock_exclusive()
+            .map_err(|e| io_err("lock_metrics_buffer", e))?;
+
+        let size = (HEADER_SIZE + capacity * SLOT_SIZE) as u64;
+        let existing = file
+            .metadata()
+            .map_err(|e| io_err("stat_metrics_buffer", e))?
+            .len();
+        if existing != size {
+            file.set_len(size)
+                .map_err(|e| io_err("resize_metrics_buffer", e))?;
+        }
+
+        // SAFETY: The file is exclusively locked, so no other ReedBase handle
+        // maps it concurrently.
+        let mmap =
+            unsafe { MmapMut::map_mut(&file) }.map_err(|e| io_err("map_metrics_buffer", e))?;
+
+        let mut ring = Self::from_mmap(mmap, capacity as u64, Some(file));
+        if existing != size || !ring.header_valid() {
+            ring.reset();
+        }
+        Ok(ring)
+    }
+
+    /// Creates a ring in anonymous memory (no persistence).
+    ///
+    /// ## Error Conditions
+    /// - `IoError`: Mapping failed
+    pub fn anonymous(capacity: usize) -> ReedResult<Self> {
+        let mmap = MmapMut::map_anon(HEADER_SIZE + capacity * SLOT_SIZE).map_err(|e| {
+            ReedError::IoError {
+                operation: "map_metrics_buffer".to_string(),
+                reason: e.to_string(),
+            }
+        })?;
+
+        let mut ring = Self::from_mmap(mmap, capacity as u64, None);
+        ring.reset();
+        Ok(ring)
+    }
+
+    fn from_mmap(mut mmap: MmapMut, capacity: u64, file: Option<File>) -> Self {
+        let ptr = mmap.as_mut_ptr();
+        Self {
+            mmap,
+            ptr,
+            capacity,
+            _file: file,
+        }
+    }
+
+    /// Checks magic, version, layout and counters.
+    fn header_valid(&self) -> bool {
+        let header = &self.mmap[..HEADER_SIZE];
+        let read_u64 =
+            |offset: usize| u64::from_le_bytes(header[offset..offset + 8].try_into().unwrap());
+
+        let head = self.counter(HEAD_OFFSET).load(Ordering::Acquire);
+        let tail = self.counter(TAIL_OFFSET).load(Ordering::Acquire);
+
+        &header[0..4] == MAGIC
+            && u32::from_le_bytes(header[4..8].try_into().unwrap()) == VERSION
+            && read_u64(8) == self.capacity
+            && read_u64(16) == SLOT_SIZE as u64
+            && head <= tail
+            && tail - head <= self.capacity
+    }
+
+    /// Writes a fresh header with empty counters.
+    fn reset(&mut self) {
+        let header = &mut self.mmap[..HEADER_SIZE];
+        header.fill(0);
+        header[0..4].copy_from_slice(MAGIC);
+        header[4..8].copy_from_slice(&VERSION.to_le_bytes());
+        header[8..16].copy_from_slice(&self.capacity.to_le_bytes());
+        header[16..24].copy_from_slice(&(SLOT_SIZE as u64).to_le_bytes());
+    }
+
+    /// Header counter at `offset`.
+    fn counter(&self, offset: usize) -> &AtomicU64 {
+        // SAFETY: Offsets are 8-byte aligned within the page-aligned mapping,
+        // and the mapping outlives the returned reference.
+        unsafe { &*(self.ptr.add(offset) as *const AtomicU64) }
+    }
+
+    /// Pointer to the start of slot `n` (modulo capacity).
+    fn slot(&self, n: u64) -> *mut u8 {
+        let index = (n % self.capacity) as usize;
+        // SAFETY: index < capacity, so the slot lies inside the mapping.
+        unsafe { self.ptr.add(HEADER_SIZE + index * SLOT_SIZE) }
+    }
+
+    /// Appends a metric (producer side).
+    ///
+    /// ## Returns
+    /// - `true`: Metric stored
+    /// - `false`: Ring full or metric too large for a slot; counted as dropped
+    pub fn push(&self, metric: &Metric) -> bool {
+        let payload = match encode(metric) {
+            Some(payload) if payload.len() <= SLOT_SIZE - 8 => payload,
+            _ => {
+                self.counter(DROPPED_OFFSET).fetch_add(1, Ordering::Relaxed);
+                return false;
+            }
+        };
+
+        let tail = self.counter(TAIL_OFFSET).load(Ordering::Relaxed);
+        let head = self.counter(HEAD_OFFSET).load(Ordering::Acquire);
+        if tail - head >= self.capacity {
+            self.counter(DROPPED_OFFSET).fetch_add(1, Ordering::Relaxed);
+            return false;
+        }
+
+        let slot = self.slot(tail);
+        let crc = crc32fast::hash(&payload);
+        // SAFETY: The consumer does not read this slot before `tail` is
+        // advanced below, and no other producer runs concurrently.
+        unsafe {
+            std::ptr::copy_nonoverlapping((payload.len() as u32).to_le_bytes().as_ptr(), slot, 4);
+            std::ptr::copy_nonoverlapping(crc.to_le_bytes().as_ptr(), slot.add(4), 4);
+            std::ptr::copy_nonoverlapping(payload.as_ptr(), slot.add(8), payload.len());
+        }
+
+        self.counter(TAIL_OFFSET).store(tail + 1, Ordering::Release);
+        true
+    }
+
+    /// Removes and returns all stored metrics (consumer side).
+    ///
+    /// Slots that fail their checksum (e.g. torn by a crash) are skipped.
+    pub fn drain(&self) -> Vec<Metric> {
+        let (head, tail) = self.bounds();
+        let metrics = self.read_range(head, tail);
+        self.counter(HEAD_OFFSET).store(tail, Ordering::Release);
+        metrics
+    }
+
+    /// Returns all stored metrics without removing them (consumer side).
+    pub fn peek(&self) -> Vec<Metric> {
+        let (head, tail) = self.bounds();
+        self.read_range(head, tail)
+    }
+
+    /// Current `(head, tail)` as seen by the consumer.
+    fn bounds(&self) -> (u64, u64) {
+        let head = self.counter(HEAD_OFFSET).load(Ordering::Relaxed);
+        let tail = self.counter(TAIL_OFFSET).load(Ordering::Acquire);
+        (head, tail)
+    }
+
+    fn read_range(&self, head: u64, tail: u64) -> Vec<Metric> {
+        (head..tail)
+            .filter_map(|n| {
+                let slot = self.slot(n);
+                // SAFETY: Slots in head..tail were published by the producer
+                // and are not rewritten until `head` moves past them.
+                let bytes = unsafe { std::slice::from_raw_parts(slot, SLOT_SIZE) };
+                let len = u32::from_le_bytes(bytes[0..4].try_into().unwrap()) as usize;
+                let crc = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+                let payload = bytes.get(8..8 + len)?;
+                if len == 0 || crc32fast::hash(payload) != crc {
+                    return None;
+                }
+                decode(payload)
+            })
+            .collect()
+    }
+
+    /// Number of stored metrics.
+    pub fn len(&self) -> usize {
+        let head = self.counter(HEAD_OFFSET).load(Ordering::Acquire);
+        let tail = self.counter(TAIL_OFFSET).load(Ordering::Acquire);
+        tail.saturating_sub(head) as usize
+    }
+
+    /// Whether the ring holds no metrics.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Number of slots.
+    pub fn capacity(&self) -> usize {
+        self.capacity as usize
+    }
+
+    /// Metrics rejected because the ring was full or they did not fit a slot.
+    pub fn dropped(&self) -> u64 {
+        self.counter(DROPPED_OFFSET).load(Ordering::Relaxed)
+    }
+}
+
+/// Slot payload (metric with enums reduced to codes).
+#[derive(Serialize, Deserialize)]
+struct StoredMetric {
+    name: String,
+    value: f64,
+    unit: u8,
+    tags: Vec<(String, String)>,
+    timestamp: u64,
+    metric_type: u8,
+    bounds: Vec<f64>,
+}
+
+fn encode(metric: &Metric) -> Option<Vec<u8>> {
+    let stored = StoredMetric {
+        name: metric.name.clone(),
+        value: metric.value,
+        unit: unit_code(metric.unit),
+        tags: metric
+            .tags
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect(),
+        timestamp: metric.timestamp,
+        metric_type: match metric.metric_type {
+            MetricType::Counter => 0,
+            MetricType::Gauge => 1,
+            MetricType::Histogram { .. } => 2,
+            MetricType::Timer => 3,
+        },
+        bounds: metric.metric_type.bounds().to_vec(),
+    };
+    bincode::serialize(&stored).ok()
+}
+
+fn decode(payload: &[u8]) -> Option<Metric> {
+    let stored: StoredMetric = bincode::deserialize(payload).ok()?;
+    let metric_type = match stored.metric_type {
+        0 => MetricType::Counter,
+        1 => MetricType::Gauge,
+        2 => MetricType::Histogram {
+            bounds: Arc::from(stored.bounds),
+        },
+        3 => MetricType::Timer,
+        _ => return None,
+    };
+
+    Some(Metric {
+        name: stored.name,
+        value: stored.value,
+        unit: unit_from_code(stored.unit)?,
+        tags: stored.tags.into_iter().collect(),
+        timestamp: stored.timestamp,
+        metric_type,
+    })
+}
+
+fn unit_code(unit: MetricUnit) -> u8 {
+    match unit {
+        MetricUnit::Nanoseconds => 0,
+        MetricUnit::Microseconds => 1,
+        MetricUnit::Milliseconds => 2,
+        MetricUnit::Seconds => 3,
+        MetricUnit::Bytes => 4,
+        MetricUnit::Kilobytes => 5,
+        MetricUnit::Megabytes => 6,
+        MetricUnit::Count => 7,
+        MetricUnit::Percent => 8,
+    }
+}
+
+fn unit_from_code(code: u8) -> Option<MetricUnit> {
+    Some(match code {
+        0 => MetricUnit::Nanoseconds,
+        1 => MetricUnit::Microseconds,
+        2 => MetricUnit::Milliseconds,
+        3 => MetricUnit::Seconds,
+        4 => MetricUnit::Bytes,
+        5 => MetricUnit::Kilobytes,
+        6 => MetricUnit::Megabytes,
+        7 => MetricUnit::Count,
+        8 => MetricUnit::Percent,
+        _ => return None,
+    })
+}
@@ -0,0 +1,164 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for the metrics ring buffer.
+
+#[cfg(test)]
+mod tests {
+    use crate::metrics::ring::{MetricRing, SLOT_SIZE};
+    use crate::metrics::types::{Metric, MetricType, MetricUnit};
+    use std::sync::Arc;
+
+    /// Metric with a fixed timestamp, so equal values compare equal.
+    fn sample(value: f64) -> Metric {
+        Metric {
+            timestamp: 1,
+            ..Metric::new("ring", value, MetricUnit::Count)
+        }
+    }
+
+    #[test]
+    fn test_push_and_drain() {
+        let ring = MetricRing::anonymous(8).unwrap();
+        let metric = Metric::new("latency", 250.0, MetricUnit::Microseconds)
+            .with_tag("table", "text")
+            .with_type(MetricType::histogram(&[100.0, 500.0]));
+
+        assert!(ring.push(&metric));
+        assert!(ring.push(&sample(1.0)));
+        assert_eq!(ring.len(), 2);
+
+        assert_eq!(ring.peek().len(), 2);
+        let drained = ring.drain();
+        assert_eq!(drained, vec![metric, sample(1.0)]);
+        assert!(ring.is_empty());
+        assert!(ring.drain().is_empty());
+    }
+
+    #[test]
+    fn test_full_ring_drops() {
+        let ring = MetricRing::anonymous(2).unwrap();
+
+        assert!(ring.push(&sample(1.0)));
+        assert!(ring.push(&sample(2.0)));
+        assert!(!ring.push(&sample(3.0)));
+        assert_eq!((ring.capacity(), ring.len(), ring.dropped()), (2, 2, 1));
+
+        // Slots are reused after draining
+        assert_eq!(ring.drain().len(), 2);
+        assert!(ring.push(&sample(4.0)));
+        assert_eq!(ring.drain(), vec![sample(4.0)]);
+        assert_eq!(ring.dropped(), 1);
+    }
+
+    #[test]
+    fn test_oversized_metric_dropped() {
+        let ring = MetricRing::anonymous(2).unwrap();
+        let metric = sample(1.0).with_tag("query", &"x".repeat(SLOT_SIZE));
+
+        assert!(!ring.push(&metric));
+        assert!(ring.is_empty());
+        assert_eq!(ring.dropped(), 1);
+    }
+
+    #[test]
+    fn test_persists_across_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".buffer");
+
+        {
+            let ring = MetricRing::open(&path, 4).unwrap();
+            for i in 0..3 {
+                ring.push(&sample(i as f64));
+            }
+            assert_eq!(ring.drain().len(), 3);
+            ring.push(&sample(3.0));
+            ring.push(&sample(4.0));
+            ring.push(&sample(5.0)); // wraps around
+        }
+
+        let ring = MetricRing::open(&path, 4).unwrap();
+        assert_eq!(ring.drain(), vec![sample(3.0), sample(4.0), sample(5.0)]);
+    }
+
+    #[test]
+    fn test_open_locks_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".buffer");
+
+        let _ring = MetricRing::open(&path, 4).unwrap();
+        assert!(MetricRing::open(&path, 4).is_err());
+    }
+
+    #[test]
+    fn test_resets_invalid_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".buffer");
+
+        {
+            let ring = MetricRing::open(&path, 4).unwrap();
+            ring.push(&sample(1.0));
+        }
+
+        // Different capacity: old layout is discarded
+        {
+            let ring = MetricRing::open(&path, 8).unwrap();
+            assert!(ring.is_empty());
+            ring.push(&sample(2.0));
+        }
+
+        // Garbage header
+        let mut bytes = std::fs::read(&path).unwrap();
+        bytes[0..4].copy_from_slice(b"XXXX");
+        std::fs::write(&path, bytes).unwrap();
+
+        let ring = MetricRing::open(&path, 8).unwrap();
+        assert!(ring.is_empty());
+        assert_eq!(ring.dropped(), 0);
+    }
+
+    #[test]
+    fn test_corrupted_slot_skipped() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".buffer");
+
+        {
+            let ring = MetricRing::open(&path, 4).unwrap();
+            ring.push(&sample(1.0));
+            ring.push(&sample(2.0));
+        }
+
+        // Flip a payload byte of the first slot (header is 64 bytes)
+        let mut bytes = std::fs::read(&path).unwrap();
+        bytes[64 + 8] ^= 0xFF;
+        std::fs::write(&path, bytes).unwrap();
+
+        let ring = MetricRing::open(&path, 4).unwrap();
+        assert_eq!(ring.drain(), vec![sample(2.0)]);
+    }
+
+    #[test]
+    fn test_single_producer_single_consumer() {
+        let ring = Arc::new(MetricRing::anonymous(16).unwrap());
+        let total = 10_000;
+
+        let producer = {
+            let ring = Arc::clone(&ring);
+            std::thread::spawn(move || {
+                for i in 0..total {
+                    while !ring.push(&sample(i as f64)) {
+                        std::thread::yield_now();
+                    }
+                }
+            })
+        };
+
+        let mut received = Vec::with_capacity(total);
+        while received.len() < total {
+            received.extend(ring.drain().into_iter().map(|m| m.value as usize));
+        }
+        producer.join().unwrap();
+
+        assert_eq!(received, (0..total).collect::<Vec<_>>());
+    }
+}
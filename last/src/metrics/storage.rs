@@ -15,12 +15,24 @@
 //! 1730000000000000000|1250.50|μs|table=text,operation=get
 //! 1730000001000000000|980.25|μs|table=routes,operation=get
 //! ```
+//!
+//! ## Rotation
+//! Files above a size limit are rotated to `{metric_name}.{timestamp}.csv.gz`
+//! (gzip, timestamp in nanoseconds) and replaced by a header-only file.
 
-use std::fs::{self, OpenOptions};
+use std::fs::{self, File, OpenOptions};
 use std::io::{self, BufWriter, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use flate2::write::GzEncoder;
+use flate2::Compression;
 
-use super::types::Metric;
+use super::types::{Metric, MetricType};
+use crate::error::{ReedError, ReedResult};
+
+/// Header line of every metric CSV file.
+const METRIC_CSV_HEADER: &str = "timestamp|value|unit|tags";
 
 /// CSV-based metrics storage.
 pub struct MetricsStorage {
@@ -100,7 +112,7 @@ impl MetricsStorage {
 
         // Write header if file is new
         if !file_exists {
-            writeln!(writer, "timestamp|value|unit|tags")?;
+            writeln!(writer, "{}", METRIC_CSV_HEADER)?;
         }
 
         // Write metrics
@@ -196,6 +208,7 @@ impl MetricsStorage {
             unit,
             tags,
             timestamp,
+            metric_type: MetricType::Gauge, // Not stored in the CSV
         })
     }
 
@@ -268,3 +281,155 @@ impl Default for MetricsStorage {
         Self::new()
     }
 }
+
+/// Rotates a metric file once it exceeds a size limit.
+///
+/// The current `{metric_name}.csv` is gzip-compressed to
+/// `{metric_name}.{timestamp}.csv.gz` and replaced by a header-only file,
+/// so subsequent writes start fresh.
+///
+/// ## Input
+/// - `base_path`: Path to ReedBase directory (metrics live in `metrics/`)
+/// - `metric_name`: Metric to check
+/// - `max_size_bytes`: Rotate when the file is larger than this
+///
+/// ## Output
+/// - `ReedResult<usize>`: Number of rotated files (0 or 1)
+///
+/// ## Performance
+/// - O(n) where n = file size (compression), < 100ms for 10 MB
+/// - O(1) when no rotation is needed
+///
+/// ## Error Conditions
+/// - IoError: Cannot read, compress or replace the metric file
+///
+/// ## Example Usage
+/// ```no_run
+/// use reedbase_last::metrics::storage::rotate_metric_files;
+/// use std::path::Path;
+///
+/// let rotated = rotate_metric_files(Path::new(".reedbase"), "query_duration", 10 * 1024 * 1024)?;
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn rotate_metric_files(
+    base_path: &Path,
+    metric_name: &str,
+    max_size_bytes: u64,
+) -> ReedResult<usize> {
+    let file_path = base_path
+        .join("metrics")
+        .join(format!("{}.csv", metric_name));
+
+    let size = match fs::metadata(&file_path) {
+        Ok(metadata) => metadata.len(),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(0),
+        Err(e) => return Err(io_error("stat_metric_file", e)),
+    };
+    if size <= max_size_bytes {
+        return Ok(0);
+    }
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    compress_metric_file(&file_path, metric_name, timestamp)?;
+
+    fs::write(&file_path, format!("{}\n", METRIC_CSV_HEADER))
+        .map_err(|e| io_error("reset_metric_file", e))?;
+
+    Ok(1)
+}
+
+/// Compresses uncompressed metric files not modified for a number of days.
+///
+/// Each matching `{metric_name}.csv` is written to
+/// `{metric_name}.{timestamp}.csv.gz` (timestamp = last modification) and
+/// removed. Active metrics get a fresh file on their next write.
+///
+/// ## Input
+/// - `base_path`: Path to ReedBase directory (metrics live in `metrics/`)
+/// - `older_than_days`: Minimum age since last modification
+///
+/// ## Output
+/// - `ReedResult<usize>`: Number of compressed files
+///
+/// ## Performance
+/// - O(n) where n = total size of compressed files
+///
+/// ## Error Conditions
+/// - IoError: Cannot read metrics directory or compress a file
+///
+/// ## Example Usage
+/// ```no_run
+/// use reedbase_last::metrics::storage::compress_old_metrics;
+/// use std::path::Path;
+///
+/// let compressed = compress_old_metrics(Path::new(".reedbase"), 7)?;
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn compress_old_metrics(base_path: &Path, older_than_days: u64) -> ReedResult<usize> {
+    let metrics_dir = base_path.join("metrics");
+    if !metrics_dir.exists() {
+        return Ok(0);
+    }
+
+    let cutoff = SystemTime::now()
+        .checked_sub(Duration::from_secs(older_than_days * 24 * 60 * 60))
+        .unwrap_or(UNIX_EPOCH);
+
+    let entries = fs::read_dir(&metrics_dir).map_err(|e| io_error("read_metrics_dir", e))?;
+    let mut compressed = 0;
+
+    for entry in entries.filter_map(|e| e.ok()) {
+        let path = entry.path();
+        if path.extension().and_then(|s| s.to_str()) != Some("csv") {
+            continue;
+        }
+        let Some(metric_name) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+
+        let modified = entry
+            .metadata()
+            .and_then(|m| m.modified())
+            .map_err(|e| io_error("stat_metric_file", e))?;
+        if modified >= cutoff {
+            continue;
+        }
+
+        let timestamp = modified
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        compress_metric_file(&path, metric_name, timestamp)?;
+        fs::remove_file(&path).map_err(|e| io_error("remove_metric_file", e))?;
+        compressed += 1;
+    }
+
+    Ok(compressed)
+}
+
+/// Writes a gzip copy of a metric file next to it.
+fn compress_metric_file(file_path: &Path, metric_name: &str, timestamp: u128) -> ReedResult<()> {
+    let gz_path = file_path.with_file_name(format!("{}.{}.csv.gz", metric_name, timestamp));
+
+    let mut input = File::open(file_path).map_err(|e| io_error("open_metric_file", e))?;
+    let output = File::create(&gz_path).map_err(|e| io_error("create_metric_archive", e))?;
+
+    let mut encoder = GzEncoder::new(output, Compression::default());
+    io::copy(&mut input, &mut encoder).map_err(|e| io_error("compress_metric_file", e))?;
+    encoder
+        .finish()
+        .and_then(|file| file.sync_all())
+        .map_err(|e| io_error("compress_metric_file", e))?;
+
+    Ok(())
+}
+
+fn io_error(operation: &str, e: io::Error) -> ReedError {
+    ReedError::IoError {
+        operation: operation.to_string(),
+        reason: e.to_string(),
+    }
+}
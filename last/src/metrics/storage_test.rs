@@ -5,9 +5,31 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::metrics::storage::MetricsStorage;
+    use crate::metrics::storage::{compress_old_metrics, rotate_metric_files, MetricsStorage};
     use crate::metrics::types::{Metric, MetricUnit};
+    use flate2::read::GzDecoder;
     use std::fs;
+    use std::io::Read;
+    use std::path::Path;
+    use std::time::{Duration, SystemTime};
+
+    /// Lists `.csv.gz` archives in a metrics directory.
+    fn archives(metrics_dir: &Path) -> Vec<std::path::PathBuf> {
+        fs::read_dir(metrics_dir)
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.to_string_lossy().ends_with(".csv.gz"))
+            .collect()
+    }
+
+    fn gunzip(path: &Path) -> String {
+        let mut content = String::new();
+        GzDecoder::new(fs::File::open(path).unwrap())
+            .read_to_string(&mut content)
+            .unwrap();
+        content
+    }
 
     #[test]
     fn test_write_and_read_metrics() {
@@ -55,4 +77,93 @@ mod tests {
         // Clean up
         let _ = fs::remove_dir_all(&temp_dir);
     }
+
+    #[test]
+    fn test_rotate_metric_files() {
+        let temp_dir = std::env::temp_dir().join("reedbase_test_rotate");
+        let _ = fs::remove_dir_all(&temp_dir);
+        let metrics_dir = temp_dir.join("metrics");
+
+        let storage = MetricsStorage::with_directory(&metrics_dir);
+        let metrics: Vec<Metric> = (0..20)
+            .map(|i| Metric::new("latency", i as f64, MetricUnit::Milliseconds))
+            .collect();
+        storage.write_batch(&metrics).unwrap();
+        let original = fs::read_to_string(metrics_dir.join("latency.csv")).unwrap();
+
+        // Below the limit: nothing happens
+        assert_eq!(
+            rotate_metric_files(&temp_dir, "latency", 1_000_000).unwrap(),
+            0
+        );
+        assert!(archives(&metrics_dir).is_empty());
+
+        // Above the limit: archived and reset
+        assert_eq!(rotate_metric_files(&temp_dir, "latency", 100).unwrap(), 1);
+
+        let rotated = archives(&metrics_dir);
+        assert_eq!(rotated.len(), 1);
+        let name = rotated[0]
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .to_string();
+        assert!(name.starts_with("latency."), "{}", name);
+        assert_eq!(gunzip(&rotated[0]), original);
+
+        assert!(storage.read_metrics("latency").unwrap().is_empty());
+        storage
+            .write_batch(&[Metric::new("latency", 1.0, MetricUnit::Milliseconds)])
+            .unwrap();
+        assert_eq!(storage.read_metrics("latency").unwrap().len(), 1);
+        assert_eq!(storage.list_metrics().unwrap(), vec!["latency".to_string()]);
+
+        // Missing metric
+        assert_eq!(rotate_metric_files(&temp_dir, "missing", 0).unwrap(), 0);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_compress_old_metrics() {
+        let temp_dir = std::env::temp_dir().join("reedbase_test_compress_old");
+        let _ = fs::remove_dir_all(&temp_dir);
+        let metrics_dir = temp_dir.join("metrics");
+
+        let storage = MetricsStorage::with_directory(&metrics_dir);
+        storage
+            .write_batch(&[
+                Metric::new("old_metric", 1.0, MetricUnit::Count),
+                Metric::new("new_metric", 2.0, MetricUnit::Count),
+            ])
+            .unwrap();
+
+        let old_path = metrics_dir.join("old_metric.csv");
+        let original = fs::read_to_string(&old_path).unwrap();
+        fs::File::options()
+            .write(true)
+            .open(&old_path)
+            .unwrap()
+            .set_modified(SystemTime::now() - Duration::from_secs(10 * 24 * 60 * 60))
+            .unwrap();
+
+        assert_eq!(compress_old_metrics(&temp_dir, 7).unwrap(), 1);
+
+        assert!(!old_path.exists());
+        assert!(metrics_dir.join("new_metric.csv").exists());
+        let compressed = archives(&metrics_dir);
+        assert_eq!(compressed.len(), 1);
+        assert_eq!(gunzip(&compressed[0]), original);
+
+        // Idempotent; archives are not compressed again
+        assert_eq!(compress_old_metrics(&temp_dir, 7).unwrap(), 0);
+
+        // No metrics directory
+        assert_eq!(
+            compress_old_metrics(&temp_dir.join("missing"), 7).unwrap(),
+            0
+        );
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
 }
@@ -9,6 +9,10 @@
 //! - `MetricUnit`: Unit of measurement with display formatting
 
 use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Latency bucket bounds in microseconds: 100μs, 500μs, 1ms (plus +Inf).
+pub const DEFAULT_LATENCY_BUCKETS: [f64; 3] = [100.0, 500.0, 1_000.0];
 
 /// A single metric measurement.
 ///
@@ -20,7 +24,7 @@ use std::collections::HashMap;
 ///     .with_tag("table", "text")
 ///     .with_tag("operation", "get");
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Metric {
     /// Metric name (e.g., "query_duration", "cache_hit_rate")
     pub name: String,
@@ -36,6 +40,9 @@ pub struct Metric {
 
     /// Unix timestamp in nanoseconds (when metric was recorded)
     pub timestamp: u64,
+
+    /// Behaviour of the metric (defaults to `Gauge`)
+    pub metric_type: MetricType,
 }
 
 impl Metric {
@@ -55,9 +62,22 @@ impl Metric {
             unit,
             tags: HashMap::new(),
             timestamp: Self::now_nanos(),
+            metric_type: MetricType::Gauge,
         }
     }
 
+    /// Sets the metric type (builder pattern).
+    ///
+    /// ## Arguments
+    /// - `metric_type`: How the value behaves over time
+    ///
+    /// ## Returns
+    /// Self with type set (for chaining)
+    pub fn with_type(mut self, metric_type: MetricType) -> Self {
+        self.metric_type = metric_type;
+        self
+    }
+
     /// Adds a tag to the metric (builder pattern).
     ///
     /// ## Arguments
@@ -106,9 +126,9 @@ impl Metric {
 /// Determines how metrics are aggregated and interpreted:
 /// - **Counter**: Monotonically increasing value (e.g., total requests)
 /// - **Gauge**: Point-in-time value that can go up/down (e.g., active connections)
-/// - **Histogram**: Distribution of values (e.g., request latencies)
+/// - **Histogram**: Distribution of values sorted into buckets (e.g., request latencies)
 /// - **Timer**: Duration measurements (special case of histogram)
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum MetricType {
     /// Monotonically increasing counter (resets on restart)
     Counter,
@@ -116,13 +136,67 @@ pub enum MetricType {
     /// Point-in-time measurement (can increase or decrease)
     Gauge,
 
-    /// Distribution of values (for percentile calculation)
-    Histogram,
+    /// Distribution of values, counted per bucket
+    ///
+    /// `bounds` are ascending, finite upper bounds; a value falls into the
+    /// first bucket whose bound is `>=` the value, or the implicit +Inf
+    /// bucket. Build with `MetricType::histogram()`.
+    Histogram { bounds: Arc<[f64]> },
 
     /// Duration measurement (histogram with time unit)
     Timer,
 }
 
+impl MetricType {
+    /// Creates a histogram type with the given bucket upper bounds.
+    ///
+    /// Bounds are sorted and deduplicated; NaN and infinite bounds are
+    /// dropped (+Inf is always implied).
+    ///
+    /// ## Example
+    /// ```
+    /// use reedbase_last::metrics::types::{MetricType, DEFAULT_LATENCY_BUCKETS};
+    ///
+    /// let latency = MetricType::histogram(&DEFAULT_LATENCY_BUCKETS);
+    /// assert_eq!(latency.bounds(), &[100.0, 500.0, 1_000.0]);
+    /// ```
+    pub fn histogram(bounds: &[f64]) -> Self {
+        let mut bounds: Vec<f64> = bounds.iter().copied().filter(|b| b.is_finite()).collect();
+        bounds.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        bounds.dedup();
+        Self::Histogram {
+            bounds: bounds.into(),
+        }
+    }
+
+    /// Returns the bucket bounds (empty for non-histogram types).
+    pub fn bounds(&self) -> &[f64] {
+        match self {
+            Self::Histogram { bounds } => bounds,
+            _ => &[],
+        }
+    }
+
+    /// Returns the Prometheus `# TYPE` name.
+    ///
+    /// Timers are exported as histograms.
+    ///
+    /// ## Example
+    /// ```
+    /// use reedbase_last::metrics::types::MetricType;
+    ///
+    /// assert_eq!(MetricType::Counter.prometheus_type(), "counter");
+    /// assert_eq!(MetricType::Timer.prometheus_type(), "histogram");
+    /// ```
+    pub fn prometheus_type(&self) -> &'static str {
+        match self {
+            Self::Counter => "counter",
+            Self::Gauge => "gauge",
+            Self::Histogram { .. } | Self::Timer => "histogram",
+        }
+    }
+}
+
 /// Unit of measurement for metric values.
 ///
 /// Provides display formatting and semantic meaning.
@@ -168,6 +242,31 @@ impl MetricUnit {
         }
     }
 
+    /// Returns the Prometheus metric name suffix for the unit.
+    ///
+    /// Empty for `Count`, which has no unit.
+    ///
+    /// ## Example
+    /// ```
+    /// use reedbase_last::metrics::types::MetricUnit;
+    ///
+    /// assert_eq!(MetricUnit::Microseconds.prometheus_suffix(), "microseconds");
+    /// assert_eq!(MetricUnit::Count.prometheus_suffix(), "");
+    /// ```
+    pub fn prometheus_suffix(&self) -> &'static str {
+        match self {
+            Self::Nanoseconds => "nanoseconds",
+            Self::Microseconds => "microseconds",
+            Self::Milliseconds => "milliseconds",
+            Self::Seconds => "seconds",
+            Self::Bytes => "bytes",
+            Self::Kilobytes => "kilobytes",
+            Self::Megabytes => "megabytes",
+            Self::Count => "",
+            Self::Percent => "percent",
+        }
+    }
+
     /// Converts value to base unit (nanoseconds for time, bytes for size).
     ///
     /// Used for aggregation across different unit scales.
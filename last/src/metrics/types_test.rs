@@ -57,4 +57,18 @@ mod tests {
         // No conversion
         assert_eq!(MetricUnit::Count.to_base_unit(42.0), 42.0);
     }
+
+    #[test]
+    fn test_histogram_type_bounds() {
+        use crate::metrics::types::{MetricType, DEFAULT_LATENCY_BUCKETS};
+
+        let histogram = MetricType::histogram(&[500.0, f64::NAN, 100.0, 500.0, f64::INFINITY]);
+        assert_eq!(histogram.bounds(), &[100.0, 500.0]);
+        assert_eq!(histogram.prometheus_type(), "histogram");
+        assert_eq!(
+            MetricType::histogram(&DEFAULT_LATENCY_BUCKETS).bounds(),
+            &DEFAULT_LATENCY_BUCKETS
+        );
+        assert!(MetricType::Gauge.bounds().is_empty());
+    }
 }
@@ -57,7 +57,7 @@ impl QueryAnalyzer {
     /// 2. Detect patterns:
     ///    - Single `Equals` → PointLookup
     ///    - Single `Like` with '%' suffix → PrefixScan
-    ///    - Pair of `GreaterThan`/`LessThan` → RangeScan
+    ///    - Pair of `GreaterThan`/`LessThan` or single `Between` → RangeScan
     /// 3. Return most specific pattern found
     ///
     /// ## Performance
@@ -103,6 +103,35 @@ impl QueryAnalyzer {
         Ok(QueryPattern::FullScan)
     }
 
+    /// Foreign key columns the query matches exactly.
+    ///
+    /// A column counts as a foreign key when its name ends in `_id`; only
+    /// `Equals` and `IN (...)` conditions count, since those are the lookups
+    /// an index serves best. Used by the database to auto-index foreign keys
+    /// after `FOREIGN_KEY_THRESHOLD` queries.
+    ///
+    /// ## Performance
+    /// - O(c) where c = number of conditions
+    ///
+    /// ## Example
+    /// ```rust,ignore
+    /// let query = parse("SELECT * FROM orders WHERE user_id = 'u1'")?;
+    /// assert_eq!(QueryAnalyzer::detect_foreign_key_pattern(&query), vec!["user_id"]);
+    /// ```
+    pub fn detect_foreign_key_pattern(query: &ParsedQuery) -> Vec<&str> {
+        let mut columns = Vec::new();
+        for condition in &query.conditions {
+            if let FilterCondition::Equals { column, .. } | FilterCondition::InList { column, .. } =
+                condition
+            {
+                if column.ends_with("_id") && !columns.contains(&column.as_str()) {
+                    columns.push(column.as_str());
+                }
+            }
+        }
+        columns
+    }
+
     fn is_key_condition(condition: &FilterCondition) -> bool {
         match condition {
             FilterCondition::Equals { column, .. } => column == "key",
@@ -111,6 +140,7 @@ impl QueryAnalyzer {
             FilterCondition::GreaterThanOrEqual { column, .. } => column == "key",
             FilterCondition::LessThan { column, .. } => column == "key",
             FilterCondition::LessThanOrEqual { column, .. } => column == "key",
+            FilterCondition::Between { column, .. } => column == "key",
             _ => false,
         }
     }
@@ -151,6 +181,21 @@ impl QueryAnalyzer {
     }
 
     fn detect_range_scan(conditions: &[&FilterCondition]) -> Option<QueryPattern> {
+        // BETWEEN is a range on its own; numeric bounds do not follow
+        // the index's lexicographic key order
+        if let [FilterCondition::Between { column, low, high }] = conditions {
+            if low.parse::<f64>().is_ok() && high.parse::<f64>().is_ok() {
+                return None;
+            }
+            return Some(QueryPattern::RangeScan {
+                column: column.clone(),
+                start: low.clone(),
+                end: high.clone(),
+                inclusive_start: true,
+                inclusive_end: true,
+            });
+        }
+
         if conditions.len() != 2 {
             return None;
         }
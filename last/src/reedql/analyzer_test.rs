@@ -206,4 +206,20 @@ mod tests {
         let pattern = QueryAnalyzer::analyze(&query).unwrap();
         assert_eq!(pattern, QueryPattern::FullScan);
     }
+
+    #[test]
+    fn test_detect_foreign_key_pattern() {
+        let query = parse(
+            "SELECT * FROM orders WHERE user_id = 'u1' AND shop_id IN ('s1', 's2') \
+             AND user_id IN ('u1') AND order_id > 'o5' AND key = 'o1' AND idle = 'x'",
+        )
+        .unwrap();
+        assert_eq!(
+            QueryAnalyzer::detect_foreign_key_pattern(&query),
+            vec!["user_id", "shop_id"]
+        );
+
+        let query = parse("SELECT * FROM orders").unwrap();
+        assert!(QueryAnalyzer::detect_foreign_key_pattern(&query).is_empty());
+    }
 }
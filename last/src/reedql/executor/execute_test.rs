@@ -0,0 +1,699 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for `execute()` and `execute_join()`.
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ReedError;
+    use crate::reedql::executor::filter::evaluate_conditions;
+    use crate::reedql::executor::{execute, execute_join};
+    use crate::reedql::parse;
+    use crate::reedql::types::QueryResult;
+    use std::collections::HashMap;
+
+    fn create_test_table() -> Vec<HashMap<String, String>> {
+        vec![
+            HashMap::from([
+                ("key".to_string(), "page.header.title@de".to_string()),
+                ("value".to_string(), "Willkommen".to_string()),
+                ("namespace".to_string(), "page".to_string()),
+            ]),
+            HashMap::from([
+                ("key".to_string(), "page.header.title@en".to_string()),
+                ("value".to_string(), "Welcome".to_string()),
+                ("namespace".to_string(), "page".to_string()),
+            ]),
+            HashMap::from([
+                ("key".to_string(), "global.footer.copyright@de".to_string()),
+                ("value".to_string(), "© 2025".to_string()),
+                ("namespace".to_string(), "global".to_string()),
+            ]),
+        ]
+    }
+
+    #[test]
+    fn test_execute_select_all() {
+        let table = create_test_table();
+        let query = parse("SELECT * FROM text").unwrap();
+        let result = execute(&query, &table).unwrap();
+
+        assert_eq!(result.row_count(), 3);
+    }
+
+    #[test]
+    fn test_execute_where_equals() {
+        let table = create_test_table();
+        let query = parse("SELECT * FROM text WHERE namespace = 'page'").unwrap();
+        let result = execute(&query, &table).unwrap();
+
+        assert_eq!(result.row_count(), 2);
+    }
+
+    #[test]
+    fn test_execute_where_or() {
+        let table = create_test_table();
+        let query =
+            parse("SELECT * FROM text WHERE namespace = 'global' OR value = 'Welcome'").unwrap();
+        assert_eq!(execute(&query, &table).unwrap().row_count(), 2);
+
+        // (A AND B) OR C
+        let query = parse(
+            "SELECT * FROM text WHERE namespace = 'page' AND key LIKE '%@de' OR namespace = 'global'",
+        )
+        .unwrap();
+        assert_eq!(execute(&query, &table).unwrap().row_count(), 2);
+
+        // (A OR B) AND C
+        let query = parse(
+            "SELECT * FROM text WHERE (namespace = 'page' OR namespace = 'global') AND key LIKE '%@de'",
+        )
+        .unwrap();
+        assert_eq!(execute(&query, &table).unwrap().row_count(), 2);
+    }
+
+    #[test]
+    fn test_execute_or_short_circuits() {
+        let row = HashMap::from([("a".to_string(), "1".to_string())]);
+        // The subquery operand would fail, but is never reached
+        let query = parse("SELECT * FROM t WHERE a = '1' OR b IN (SELECT b FROM u)").unwrap();
+        assert!(evaluate_conditions(&query.conditions, &row).unwrap());
+
+        let query = parse("SELECT * FROM t WHERE a = '2' OR b IN (SELECT b FROM u)").unwrap();
+        assert!(evaluate_conditions(&query.conditions, &row).is_err());
+    }
+
+    #[test]
+    fn test_execute_not() {
+        let table = create_test_table();
+        let count = |sql: &str| execute(&parse(sql).unwrap(), &table).unwrap().row_count();
+
+        assert_eq!(count("SELECT * FROM text WHERE NOT key LIKE '%@de'"), 1);
+        assert_eq!(count("SELECT * FROM text WHERE key NOT LIKE '%@de'"), 1);
+        assert_eq!(
+            count("SELECT * FROM text WHERE namespace NOT IN ('global')"),
+            2
+        );
+
+        // NOT (A AND B) is the complement of A AND B
+        let both = "namespace = 'page' AND key LIKE '%@de'";
+        let matching = count(&format!("SELECT * FROM text WHERE {}", both));
+        let complement = count(&format!("SELECT * FROM text WHERE NOT ({})", both));
+        assert_eq!(matching, 1);
+        assert_eq!(matching + complement, table.len());
+
+        // Double negation cancels
+        assert_eq!(
+            count("SELECT * FROM text WHERE NOT NOT namespace = 'page'"),
+            count("SELECT * FROM text WHERE namespace = 'page'")
+        );
+    }
+
+    #[test]
+    fn test_execute_between() {
+        let table: Vec<HashMap<String, String>> = ["9", "10", "25", "100", "abc"]
+            .iter()
+            .map(|price| HashMap::from([("price".to_string(), price.to_string())]))
+            .collect();
+        let prices = |sql: &str| -> Vec<String> {
+            let query = parse(&format!("SELECT * FROM t WHERE {}", sql)).unwrap();
+            match execute(&query, &table).unwrap() {
+                QueryResult::Rows(rows) => rows.iter().map(|r| r["price"].clone()).collect(),
+                _ => panic!("Expected rows result"),
+            }
+        };
+
+        // Numeric bounds compare numerically, inclusive on both ends
+        assert_eq!(
+            prices("price BETWEEN '10' AND '100'"),
+            vec!["10", "25", "100"]
+        );
+        assert_eq!(prices("price NOT BETWEEN '10' AND '100'"), vec!["9", "abc"]);
+
+        // Any non-numeric bound compares lexicographically
+        assert_eq!(
+            prices("price BETWEEN '10' AND 'b'"),
+            vec!["9", "10", "25", "100", "abc"]
+        );
+        assert_eq!(prices("price BETWEEN 'a' AND 'b'"), vec!["abc"]);
+
+        // Missing column never matches
+        assert!(prices("missing BETWEEN 'a' AND 'z'").is_empty());
+    }
+
+    #[test]
+    fn test_execute_group_by() {
+        let table: Vec<HashMap<String, String>> = (0..12)
+            .map(|i| {
+                let namespace = if i < 10 { "page" } else { "global" };
+                HashMap::from([
+                    ("key".to_string(), format!("k{}", i)),
+                    ("namespace".to_string(), namespace.to_string()),
+                    ("size".to_string(), i.to_string()),
+                ])
+            })
+            .collect();
+        let rows = |sql: &str| match execute(&parse(sql).unwrap(), &table).unwrap() {
+            QueryResult::Rows(rows) => rows,
+            _ => panic!("Expected rows result"),
+        };
+        let group = |namespace: &str, count: &str| {
+            HashMap::from([
+                ("namespace".to_string(), namespace.to_string()),
+                ("count".to_string(), count.to_string()),
+            ])
+        };
+
+        // Groups in GROUP BY order, aggregation column named after the function
+        assert_eq!(
+            rows("SELECT namespace, COUNT(*) FROM text GROUP BY namespace"),
+            vec![group("global", "2"), group("page", "10")]
+        );
+
+        // HAVING and ORDER BY compare counts numerically
+        assert_eq!(
+            rows("SELECT namespace, COUNT(*) FROM text GROUP BY namespace HAVING count > '9'"),
+            vec![group("page", "10")]
+        );
+        assert_eq!(
+            rows("SELECT namespace, COUNT(*) FROM text GROUP BY namespace ORDER BY count DESC"),
+            vec![group("page", "10"), group("global", "2")]
+        );
+
+        // Aliases, WHERE before grouping, LIMIT after
+        let totals = rows(
+            "SELECT namespace AS ns, SUM(size) AS total FROM text WHERE size > '1' \
+             GROUP BY namespace ORDER BY total LIMIT 1",
+        );
+        assert_eq!(
+            totals,
+            vec![HashMap::from([
+                ("ns".to_string(), "global".to_string()),
+                ("total".to_string(), "21".to_string()),
+            ])]
+        );
+    }
+
+    #[test]
+    fn test_execute_distinct() {
+        let table = create_test_table();
+        let rows = |sql: &str| match execute(&parse(sql).unwrap(), &table).unwrap() {
+            QueryResult::Rows(rows) => rows,
+            _ => panic!("Expected rows result"),
+        };
+
+        let namespaces = rows("SELECT DISTINCT namespace FROM text ORDER BY namespace");
+        assert_eq!(
+            namespaces,
+            vec![
+                HashMap::from([("namespace".to_string(), "global".to_string())]),
+                HashMap::from([("namespace".to_string(), "page".to_string())]),
+            ]
+        );
+
+        // DISTINCT runs before LIMIT, so LIMIT counts unique rows
+        let first = rows("SELECT DISTINCT namespace FROM text ORDER BY namespace DESC LIMIT 1");
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0]["namespace"], "page");
+        let rest = rows("SELECT DISTINCT namespace FROM text ORDER BY namespace LIMIT 5 OFFSET 1");
+        assert_eq!(rest.len(), 1);
+
+        // Whole rows are compared, and aggregations ignore DISTINCT
+        assert_eq!(rows("SELECT DISTINCT * FROM text").len(), 3);
+        let count = execute(
+            &parse("SELECT DISTINCT COUNT(*) FROM text").unwrap(),
+            &table,
+        );
+        assert!(matches!(count.unwrap(), QueryResult::Aggregation(n) if n == 3.0));
+    }
+
+    #[test]
+    fn test_execute_where_like_language() {
+        let table = create_test_table();
+        let query = parse("SELECT * FROM text WHERE key LIKE '%@de'").unwrap();
+        let result = execute(&query, &table).unwrap();
+
+        assert_eq!(result.row_count(), 2);
+    }
+
+    #[test]
+    fn test_execute_where_like_namespace() {
+        let table = create_test_table();
+        let query = parse("SELECT * FROM text WHERE key LIKE 'page.%'").unwrap();
+        let result = execute(&query, &table).unwrap();
+
+        assert_eq!(result.row_count(), 2);
+    }
+
+    #[test]
+    fn test_execute_order_by() {
+        let table = create_test_table();
+        let query = parse("SELECT * FROM text ORDER BY key ASC").unwrap();
+        let result = execute(&query, &table).unwrap();
+
+        match result {
+            QueryResult::Rows(rows) => {
+                assert_eq!(rows.len(), 3);
+                assert_eq!(rows[0].get("key").unwrap(), "global.footer.copyright@de");
+            }
+            _ => panic!("Expected rows result"),
+        }
+    }
+
+    #[test]
+    fn test_execute_limit() {
+        let table = create_test_table();
+        let query = parse("SELECT * FROM text LIMIT 2").unwrap();
+        let result = execute(&query, &table).unwrap();
+
+        assert_eq!(result.row_count(), 2);
+    }
+
+    #[test]
+    fn test_execute_limit_offset() {
+        let table = create_test_table();
+        let query = parse("SELECT * FROM text LIMIT 1 OFFSET 1").unwrap();
+        let result = execute(&query, &table).unwrap();
+
+        assert_eq!(result.row_count(), 1);
+    }
+
+    #[test]
+    fn test_execute_count_all() {
+        let table = create_test_table();
+        let query = parse("SELECT COUNT(*) FROM text").unwrap();
+        let result = execute(&query, &table).unwrap();
+
+        match result {
+            QueryResult::Aggregation(value) => assert_eq!(value, 3.0),
+            _ => panic!("Expected aggregation result"),
+        }
+    }
+
+    #[test]
+    fn test_execute_corr_covar() {
+        // x = 1..5, y = 2,4,5,4,5: Sxy = 6, Sxx = 10, Syy = 6
+        let table: Vec<HashMap<String, String>> = [(1, 2), (2, 4), (3, 5), (4, 4), (5, 5)]
+            .iter()
+            .map(|(x, y)| {
+                HashMap::from([
+                    ("x".to_string(), x.to_string()),
+                    ("y".to_string(), y.to_string()),
+                ])
+            })
+            .collect();
+
+        let query = parse("SELECT CORR(x, y) FROM points").unwrap();
+        match execute(&query, &table).unwrap() {
+            QueryResult::Aggregation(value) => assert!((value - 6.0 / 60f64.sqrt()).abs() < 1e-12),
+            other => panic!("Expected aggregation result, got {:?}", other),
+        }
+
+        let query = parse("SELECT COVAR(x, y) FROM points").unwrap();
+        match execute(&query, &table).unwrap() {
+            QueryResult::Aggregation(value) => assert!((value - 1.5).abs() < 1e-12),
+            other => panic!("Expected aggregation result, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_execute_count_where() {
+        let table = create_test_table();
+        let query = parse("SELECT COUNT(*) FROM text WHERE namespace = 'page'").unwrap();
+        let result = execute(&query, &table).unwrap();
+
+        match result {
+            QueryResult::Aggregation(value) => assert_eq!(value, 2.0),
+            _ => panic!("Expected aggregation result"),
+        }
+    }
+
+    #[test]
+    fn test_execute_project_columns() {
+        let table = create_test_table();
+        let query = parse("SELECT key, value FROM text LIMIT 1").unwrap();
+        let result = execute(&query, &table).unwrap();
+
+        match result {
+            QueryResult::Rows(rows) => {
+                assert_eq!(rows.len(), 1);
+                assert!(rows[0].contains_key("key"));
+                assert!(rows[0].contains_key("value"));
+                assert!(!rows[0].contains_key("namespace"));
+            }
+            _ => panic!("Expected rows result"),
+        }
+    }
+
+    #[test]
+    fn test_execute_project_columns_with_alias() {
+        let table = create_test_table();
+        let query =
+            parse("SELECT key AS k, value AS v FROM text WHERE namespace = 'global'").unwrap();
+        let result = execute(&query, &table).unwrap();
+
+        match result {
+            QueryResult::Rows(rows) => {
+                assert_eq!(rows.len(), 1);
+                assert_eq!(rows[0].get("k").unwrap(), "global.footer.copyright@de");
+                assert_eq!(rows[0].get("v").unwrap(), "© 2025");
+                assert!(!rows[0].contains_key("key"));
+            }
+            _ => panic!("Expected rows result"),
+        }
+    }
+
+    #[test]
+    fn test_execute_function_call() {
+        let users: Vec<HashMap<String, String>> = [("u1", "2025-01-30"), ("u2", "")]
+            .iter()
+            .map(|(key, created)| {
+                let mut row = HashMap::new();
+                row.insert("key".to_string(), key.to_string());
+                if !created.is_empty() {
+                    row.insert("created_at".to_string(), created.to_string());
+                }
+                row
+            })
+            .collect();
+
+        let query =
+            parse("SELECT key, add_days(created_at, 30) AS due FROM users ORDER BY key").unwrap();
+        match execute(&query, &users).unwrap() {
+            QueryResult::Rows(rows) => {
+                assert_eq!(rows[0].get("due").unwrap(), "2025-03-01");
+                // Absent argument column → absent value
+                assert!(!rows[1].contains_key("due"));
+            }
+            _ => panic!("Expected rows result"),
+        }
+
+        let query = parse("SELECT validate_date(key) FROM users").unwrap();
+        match execute(&query, &users).unwrap() {
+            QueryResult::Rows(rows) => {
+                assert_eq!(rows[0].get("validate_date(key)").unwrap(), "false");
+            }
+            _ => panic!("Expected rows result"),
+        }
+
+        // Function errors surface
+        let query = parse("SELECT add_days(key, 1) FROM users").unwrap();
+        assert!(matches!(
+            execute(&query, &users),
+            Err(ReedError::ParseError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_execute_extract_json_field() {
+        let events: Vec<HashMap<String, String>> = [
+            ("e1", r#"{"user":{"name":"Ada"}}"#),
+            ("e2", ""),
+            ("e3", r#"{"user":{}}"#),
+        ]
+        .iter()
+        .map(|(key, metadata)| {
+            HashMap::from([
+                ("key".to_string(), key.to_string()),
+                ("metadata".to_string(), metadata.to_string()),
+            ])
+        })
+        .collect();
+
+        let query = parse(
+            "SELECT key, extract_json_field(metadata, 'user.name') AS user_name FROM events \
+             WHERE metadata IS NOT NULL ORDER BY key",
+        )
+        .unwrap();
+        match execute(&query, &events).unwrap() {
+            QueryResult::Rows(rows) => {
+                assert_eq!(rows.len(), 2);
+                assert_eq!(rows[0].get("user_name").unwrap(), "Ada");
+                assert_eq!(rows[1].get("user_name").unwrap(), "");
+            }
+            _ => panic!("Expected rows result"),
+        }
+    }
+
+    #[test]
+    fn test_execute_order_by_alias() {
+        let table = create_test_table();
+        let query = parse("SELECT key AS k FROM text ORDER BY k DESC LIMIT 1").unwrap();
+        let result = execute(&query, &table).unwrap();
+
+        match result {
+            QueryResult::Rows(rows) => {
+                assert_eq!(rows[0].get("k").unwrap(), "page.header.title@en");
+            }
+            _ => panic!("Expected rows result"),
+        }
+    }
+
+    #[test]
+    fn test_execute_order_by_numeric_collation() {
+        let rows: Vec<HashMap<String, String>> = [
+            ("a", "10", "x"),
+            ("b", "9", "y"),
+            ("c", "10", "z"),
+            ("d", "9", "y"),
+        ]
+        .iter()
+        .map(|(key, age, group)| {
+            HashMap::from([
+                ("key".to_string(), key.to_string()),
+                ("age".to_string(), age.to_string()),
+                ("group".to_string(), group.to_string()),
+            ])
+        })
+        .collect();
+        let keys = |sql: &str| match execute(&parse(sql).unwrap(), &rows).unwrap() {
+            QueryResult::Rows(rows) => rows
+                .iter()
+                .map(|r| r.get("key").unwrap().clone())
+                .collect::<Vec<_>>(),
+            _ => panic!("Expected rows result"),
+        };
+
+        // Lexicographic by default: "10" < "9"; ties keep insertion order
+        assert_eq!(keys("SELECT key FROM t ORDER BY age"), ["a", "c", "b", "d"]);
+        assert_eq!(
+            keys("SELECT key FROM t ORDER BY age NUMERIC"),
+            ["b", "d", "a", "c"]
+        );
+        assert_eq!(
+            keys("SELECT key FROM t ORDER BY age NUMERIC DESC"),
+            ["a", "c", "b", "d"]
+        );
+        assert_eq!(
+            keys("SELECT key FROM t ORDER BY age NUMERIC DESC, group DESC"),
+            ["c", "a", "b", "d"]
+        );
+    }
+
+    #[test]
+    fn test_execute_count_with_alias() {
+        let table = create_test_table();
+        let query = parse("SELECT COUNT(*) AS total FROM text").unwrap();
+        let result = execute(&query, &table).unwrap();
+
+        match result {
+            QueryResult::Rows(rows) => {
+                assert_eq!(rows.len(), 1);
+                assert_eq!(rows[0].get("total").unwrap(), "3");
+            }
+            _ => panic!("Expected rows result"),
+        }
+    }
+
+    fn create_sparse_table() -> Vec<HashMap<String, String>> {
+        vec![
+            HashMap::from([
+                ("key".to_string(), "a".to_string()),
+                ("value".to_string(), "filled".to_string()),
+            ]),
+            HashMap::from([
+                ("key".to_string(), "b".to_string()),
+                ("value".to_string(), "".to_string()),
+            ]),
+            HashMap::from([("key".to_string(), "c".to_string())]),
+        ]
+    }
+
+    fn result_keys(result: QueryResult) -> Vec<String> {
+        match result {
+            QueryResult::Rows(rows) => {
+                let mut keys: Vec<String> = rows.iter().map(|r| r["key"].clone()).collect();
+                keys.sort();
+                keys
+            }
+            _ => panic!("Expected rows result"),
+        }
+    }
+
+    #[test]
+    fn test_execute_is_empty_matches_blank_and_absent() {
+        let table = create_sparse_table();
+        let query = parse("SELECT key FROM text WHERE value IS EMPTY").unwrap();
+        let result = execute(&query, &table).unwrap();
+
+        assert_eq!(result_keys(result), vec!["b", "c"]);
+    }
+
+    #[test]
+    fn test_execute_is_not_empty() {
+        let table = create_sparse_table();
+        let query = parse("SELECT key FROM text WHERE value IS NOT EMPTY").unwrap();
+        let result = execute(&query, &table).unwrap();
+
+        assert_eq!(result_keys(result), vec!["a"]);
+    }
+
+    #[test]
+    fn test_execute_is_null_matches_is_empty() {
+        let table = create_sparse_table();
+        let query = parse("SELECT key FROM text WHERE value IS NULL").unwrap();
+        assert_eq!(
+            result_keys(execute(&query, &table).unwrap()),
+            vec!["b", "c"]
+        );
+
+        let query = parse("SELECT key FROM text WHERE value IS NOT NULL").unwrap();
+        assert_eq!(result_keys(execute(&query, &table).unwrap()), vec!["a"]);
+    }
+
+    #[test]
+    fn test_execute_empty_string_distinct_from_absent_key() {
+        let table = create_sparse_table();
+
+        // Equality only sees the present-but-blank value, not the absent key
+        let query = parse("SELECT key FROM text WHERE value = ''").unwrap();
+        let result = execute(&query, &table).unwrap();
+        assert_eq!(result_keys(result), vec!["b"]);
+    }
+
+    fn create_routes_table() -> Vec<HashMap<String, String>> {
+        vec![
+            HashMap::from([
+                ("key".to_string(), "route.home".to_string()),
+                ("text_key".to_string(), "page.header.title@de".to_string()),
+                ("route".to_string(), "/".to_string()),
+            ]),
+            HashMap::from([
+                ("key".to_string(), "route.imprint".to_string()),
+                (
+                    "text_key".to_string(),
+                    "global.footer.copyright@de".to_string(),
+                ),
+                ("route".to_string(), "/impressum".to_string()),
+            ]),
+            HashMap::from([
+                ("key".to_string(), "route.orphan".to_string()),
+                ("text_key".to_string(), "missing.key@de".to_string()),
+                ("route".to_string(), "/orphan".to_string()),
+            ]),
+        ]
+    }
+
+    #[test]
+    fn test_execute_join_on() {
+        let query = parse(
+            "SELECT t.key, r.route FROM text t JOIN routes r ON t.key = r.text_key ORDER BY r.route",
+        )
+        .unwrap();
+        let result = execute_join(&query, &create_test_table(), &[create_routes_table()]).unwrap();
+
+        match result {
+            QueryResult::Rows(rows) => {
+                assert_eq!(rows.len(), 2);
+                assert_eq!(rows[0]["t.key"], "page.header.title@de");
+                assert_eq!(rows[0]["r.route"], "/");
+                assert_eq!(rows[1]["t.key"], "global.footer.copyright@de");
+                assert_eq!(rows[1]["r.route"], "/impressum");
+            }
+            _ => panic!("Expected rows"),
+        }
+    }
+
+    #[test]
+    fn test_execute_left_join_keeps_unmatched_rows() {
+        let query = parse(
+            "SELECT t.key, r.route FROM text t LEFT JOIN routes r ON t.key = r.text_key \
+             WHERE t.namespace = 'page'",
+        )
+        .unwrap();
+        let result = execute_join(&query, &create_test_table(), &[create_routes_table()]).unwrap();
+
+        match result {
+            QueryResult::Rows(rows) => {
+                assert_eq!(rows.len(), 2);
+                let routed: Vec<_> = rows.iter().filter(|r| r.contains_key("r.route")).collect();
+                assert_eq!(routed.len(), 1);
+                assert_eq!(routed[0]["r.route"], "/");
+            }
+            _ => panic!("Expected rows"),
+        }
+    }
+
+    #[test]
+    fn test_execute_natural_join_on_shared_columns() {
+        let meta = vec![
+            HashMap::from([
+                ("key".to_string(), "page.header.title@de".to_string()),
+                ("author".to_string(), "vivian".to_string()),
+            ]),
+            HashMap::from([
+                ("key".to_string(), "unknown".to_string()),
+                ("author".to_string(), "nobody".to_string()),
+            ]),
+        ];
+        let query = parse("SELECT key, value, author FROM text NATURAL JOIN meta").unwrap();
+        let result = execute_join(&query, &create_test_table(), &[meta]).unwrap();
+
+        match result {
+            QueryResult::Rows(rows) => {
+                assert_eq!(rows.len(), 1);
+                assert_eq!(rows[0]["key"], "page.header.title@de");
+                assert_eq!(rows[0]["value"], "Willkommen");
+                assert_eq!(rows[0]["author"], "vivian");
+            }
+            _ => panic!("Expected rows"),
+        }
+    }
+
+    #[test]
+    fn test_execute_hash_join_matches_nested_loop_order() {
+        // Routes outnumber texts, so the hash table is built on the left side
+        let mut routes = create_routes_table();
+        routes.extend(create_routes_table());
+        routes.push(HashMap::from([(
+            "route".to_string(),
+            "/no-key".to_string(),
+        )]));
+        let rows = |on: &str, join: &str| {
+            let sql = format!("SELECT * FROM text t {} routes r ON {}", join, on);
+            match execute_join(
+                &parse(&sql).unwrap(),
+                &create_test_table(),
+                &[routes.clone()],
+            ) {
+                Ok(QueryResult::Rows(rows)) => rows,
+                other => panic!("Expected rows, got {:?}", other),
+            }
+        };
+
+        for join in ["JOIN", "LEFT JOIN"] {
+            // Bare `key` is not qualified, so this one runs as a nested loop
+            let nested = rows("key = r.text_key", join);
+            assert_eq!(rows("t.key = r.text_key", join), nested);
+            assert_eq!(rows("r.text_key = t.key", join), nested);
+        }
+        assert_eq!(rows("t.key = r.text_key", "JOIN").len(), 4);
+        assert_eq!(rows("t.key = r.text_key", "LEFT JOIN").len(), 5);
+    }
+
+    #[test]
+    fn test_execute_join_table_count_mismatch() {
+        let query = parse("SELECT * FROM text t JOIN routes r ON t.key = r.text_key").unwrap();
+        assert!(execute_join(&query, &create_test_table(), &[]).is_err());
+    }
+}
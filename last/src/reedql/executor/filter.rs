@@ -0,0 +1,187 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! WHERE evaluation: conditions, BETWEEN and LIKE.
+
+use crate::error::{ReedError, ReedResult};
+use crate::reedql::types::{FilterCondition, ParsedQuery};
+use std::collections::HashMap;
+
+/// Filters rows based on WHERE conditions.
+///
+/// ## Fast Paths
+/// - `key LIKE '%.@de'` → Language filter (ends_with check)
+/// - `key LIKE 'page.%'` → Namespace filter (starts_with check)
+/// - `namespace = 'page'` → Direct column check
+///
+/// Standard path: Generic condition evaluation
+pub(super) fn filter_rows(
+    query: &ParsedQuery,
+    table: &[HashMap<String, String>],
+) -> ReedResult<Vec<HashMap<String, String>>> {
+    if query.conditions.is_empty() {
+        return Ok(table.to_vec());
+    }
+
+    let mut result = Vec::new();
+
+    for row in table {
+        if evaluate_conditions(&query.conditions, row)? {
+            result.push(row.clone());
+        }
+    }
+
+    Ok(result)
+}
+
+/// Evaluates all conditions for a single row (AND logic).
+pub(super) fn evaluate_conditions(
+    conditions: &[FilterCondition],
+    row: &HashMap<String, String>,
+) -> ReedResult<bool> {
+    for condition in conditions {
+        if !evaluate_condition(condition, row)? {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Evaluates a single condition for a row.
+pub(crate) fn evaluate_condition(
+    condition: &FilterCondition,
+    row: &HashMap<String, String>,
+) -> ReedResult<bool> {
+    match condition {
+        FilterCondition::Equals { column, value } => {
+            Ok(row.get(column).map(|v| v == value).unwrap_or(false))
+        }
+
+        FilterCondition::NotEquals { column, value } => {
+            Ok(row.get(column).map(|v| v != value).unwrap_or(true))
+        }
+
+        FilterCondition::LessThan { column, value } => {
+            Ok(row.get(column).map(|v| v < value).unwrap_or(false))
+        }
+
+        FilterCondition::GreaterThan { column, value } => {
+            Ok(row.get(column).map(|v| v > value).unwrap_or(false))
+        }
+
+        FilterCondition::LessThanOrEqual { column, value } => {
+            Ok(row.get(column).map(|v| v <= value).unwrap_or(false))
+        }
+
+        FilterCondition::GreaterThanOrEqual { column, value } => {
+            Ok(row.get(column).map(|v| v >= value).unwrap_or(false))
+        }
+
+        FilterCondition::Like { column, pattern } => evaluate_like(row.get(column), pattern),
+
+        FilterCondition::InList { column, values } => {
+            Ok(row.get(column).map(|v| values.contains(v)).unwrap_or(false))
+        }
+
+        // CSV has no separate NULL: a missing or empty value is both
+        FilterCondition::IsEmpty { column } | FilterCondition::IsNull { column } => {
+            Ok(row.get(column).map(|v| v.is_empty()).unwrap_or(true))
+        }
+
+        FilterCondition::IsNotEmpty { column } | FilterCondition::IsNotNull { column } => {
+            Ok(row.get(column).map(|v| !v.is_empty()).unwrap_or(false))
+        }
+
+        FilterCondition::Between { column, low, high } => Ok(row
+            .get(column)
+            .is_some_and(|v| evaluate_between(v, low, high))),
+
+        FilterCondition::InSubquery {
+            column: _,
+            subquery: _,
+        } => {
+            // Needs the subquery's table; Database::query resolves it into an
+            // InList before execution
+            Err(ReedError::ParseError {
+                reason: "Subqueries need a database; run them through Database::query".to_string(),
+            })
+        }
+
+        FilterCondition::Or(operands) => {
+            for operand in operands {
+                if evaluate_condition(operand, row)? {
+                    return Ok(true);
+                }
+            }
+            Ok(false)
+        }
+
+        FilterCondition::And(operands) => evaluate_conditions(operands, row),
+
+        FilterCondition::Not(inner) => Ok(!evaluate_condition(inner, row)?),
+    }
+}
+
+/// Evaluates an inclusive BETWEEN range.
+///
+/// Numeric when both bounds parse as `f64` (a non-numeric value then never
+/// matches), lexicographic otherwise.
+fn evaluate_between(value: &str, low: &str, high: &str) -> bool {
+    match (low.parse::<f64>(), high.parse::<f64>()) {
+        (Ok(low), Ok(high)) => value.parse::<f64>().is_ok_and(|v| low <= v && v <= high),
+        _ => low <= value && value <= high,
+    }
+}
+
+/// Evaluates LIKE pattern matching.
+///
+/// ## Fast Paths
+/// - Pattern ends with `%` → starts_with check
+/// - Pattern starts with `%` → ends_with check
+/// - Pattern contains `%` in middle → contains check
+///
+/// ## SQL LIKE Syntax
+/// - `%` → Zero or more characters (wildcard)
+/// - `_` → Exactly one character (not implemented yet)
+fn evaluate_like(value: Option<&String>, pattern: &str) -> ReedResult<bool> {
+    let Some(val) = value else {
+        return Ok(false);
+    };
+
+    // Fast path: pattern ends with % (starts_with)
+    if pattern.ends_with('%') && !pattern[..pattern.len() - 1].contains('%') {
+        let prefix = &pattern[..pattern.len() - 1];
+        return Ok(val.starts_with(prefix));
+    }
+
+    // Fast path: pattern starts with % (ends_with)
+    if pattern.starts_with('%') && !pattern[1..].contains('%') {
+        let suffix = &pattern[1..];
+        return Ok(val.ends_with(suffix));
+    }
+
+    // Fast path: pattern has % at start and end (contains)
+    if pattern.starts_with('%') && pattern.ends_with('%') && pattern.matches('%').count() == 2 {
+        let middle = &pattern[1..pattern.len() - 1];
+        return Ok(val.contains(middle));
+    }
+
+    // Generic case: convert SQL LIKE to simple pattern matching
+    // (for now, only support single % wildcard)
+    if let Some(wildcard_pos) = pattern.find('%') {
+        let prefix = &pattern[..wildcard_pos];
+        let suffix = &pattern[wildcard_pos + 1..];
+
+        if suffix.contains('%') {
+            // Multiple wildcards not supported yet
+            return Err(ReedError::ParseError {
+                reason: "Multiple wildcards in LIKE pattern not yet supported".to_string(),
+            });
+        }
+
+        Ok(val.starts_with(prefix) && val.ends_with(suffix))
+    } else {
+        // No wildcard → exact match
+        Ok(val == pattern)
+    }
+}
@@ -0,0 +1,239 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! ReedQL Query Executor
+//!
+//! Executes parsed ReedQL queries against ReedBase tables.
+//!
+//! ## Performance Strategy
+//! - **Fast paths**: Direct string operations for key patterns (10x faster)
+//! - **Index integration**: Use Smart Indices for namespace/language filters
+//! - **Lazy evaluation**: Filter before sorting/limiting
+//! - **Zero-copy**: Work with references where possible
+//!
+//! ## Execution Pipeline
+//! 1. **Filter**: Apply WHERE conditions (use fast paths when possible)
+//! 2. **Sort**: Apply ORDER BY (if specified)
+//! 3. **Limit**: Apply LIMIT/OFFSET
+//! 4. **Project**: Select requested columns
+//! 5. **Aggregate**: Apply aggregation function (if specified)
+//!
+//! ## Layout
+//! This module runs the pipeline, sorting and projection; `filter`
+//! evaluates WHERE conditions, `aggregate` GROUP BY, HAVING and the
+//! aggregate functions, `join` the JOIN clauses and `optimized` the
+//! index-aware `OptimizedExecutor`.
+
+mod aggregate;
+#[cfg(test)]
+mod execute_test;
+mod filter;
+mod join;
+mod optimized;
+
+pub(crate) use filter::evaluate_condition;
+pub use join::execute_join;
+pub use optimized::OptimizedExecutor;
+
+use aggregate::{aggregate, aggregation_result, execute_grouped};
+use filter::filter_rows;
+
+use crate::error::ReedResult;
+use crate::reedql::types::{OrderBy, ParsedQuery, QueryResult};
+use crate::schema::{ComputedArg, ComputedExpr};
+use std::collections::{HashMap, HashSet};
+
+/// Executes a parsed ReedQL query against a table.
+///
+/// ## Input
+/// - `query`: Parsed query AST
+/// - `table`: CSV table data (vector of rows, each row is a map of column → value)
+///
+/// ## Output
+/// - `Ok(QueryResult)`: Query result (rows or aggregation)
+/// - `Err(ReedError)`: Execution error
+///
+/// ## Performance
+/// - Fast path (key LIKE pattern): < 1ms for 10k rows
+/// - Simple filter: < 10ms for 10k rows
+/// - Subquery: < 20ms for 10k + 10k rows
+///
+/// ## Example
+/// ```rust,ignore
+/// let table = load_table("text")?;
+/// let query = parse("SELECT * FROM text WHERE key LIKE '%.@de' LIMIT 10")?;
+/// let result = execute(&query, &table)?;
+/// ```
+pub fn execute(query: &ParsedQuery, table: &[HashMap<String, String>]) -> ReedResult<QueryResult> {
+    // Step 1: Apply WHERE conditions (with fast path optimization)
+    let filtered = filter_rows(query, table)?;
+
+    // Step 2: Handle GROUP BY or a single aggregation (if specified)
+    if query.has_grouping() {
+        return execute_grouped(filtered, query);
+    }
+    if let Some(agg) = &query.aggregation {
+        let value = aggregate(&filtered, agg, query)?;
+        return Ok(aggregation_result(value, agg));
+    }
+
+    // Step 3: Apply ORDER BY
+    let mut sorted = filtered;
+    if !query.order_by.is_empty() {
+        sort_rows(&mut sorted, query);
+    }
+
+    // Step 4: Apply DISTINCT, LIMIT/OFFSET and project columns
+    let projected = limit_and_project(sorted, query)?;
+
+    Ok(QueryResult::Rows(projected))
+}
+
+/// Sorts rows based on ORDER BY clauses, using each clause's collation.
+fn sort_rows(rows: &mut [HashMap<String, String>], query: &ParsedQuery) {
+    sort_rows_by(rows, query, |order, a, b| order.collation.compare(a, b));
+}
+
+/// Sorts rows by ORDER BY clauses using `compare` for column values.
+///
+/// The sort is stable: rows equal on every ORDER BY column keep their
+/// input (insertion) order.
+fn sort_rows_by(
+    rows: &mut [HashMap<String, String>],
+    query: &ParsedQuery,
+    compare: impl Fn(&OrderBy, &str, &str) -> std::cmp::Ordering,
+) {
+    if query.order_by.is_empty() {
+        return;
+    }
+
+    rows.sort_by(|a, b| {
+        for order in &query.order_by {
+            let a_val = a.get(&order.column).map(|s| s.as_str()).unwrap_or("");
+            let b_val = b.get(&order.column).map(|s| s.as_str()).unwrap_or("");
+
+            let cmp = compare(order, a_val, b_val);
+
+            if cmp != std::cmp::Ordering::Equal {
+                return match order.direction {
+                    crate::reedql::types::SortDirection::Ascending => cmp,
+                    crate::reedql::types::SortDirection::Descending => cmp.reverse(),
+                };
+            }
+        }
+        std::cmp::Ordering::Equal
+    });
+}
+
+/// Applies LIMIT and OFFSET to rows.
+fn apply_limit(
+    rows: Vec<HashMap<String, String>>,
+    offset: usize,
+    limit: usize,
+) -> Vec<HashMap<String, String>> {
+    rows.into_iter().skip(offset).take(limit).collect()
+}
+
+/// Projects requested columns from rows.
+///
+/// Output keys are the column aliases where given (`key AS k` → `k`).
+/// Function calls are evaluated per row; a call reading an absent column
+/// yields an absent key, like a missing plain column.
+fn project_columns(
+    rows: &[HashMap<String, String>],
+    query: &ParsedQuery,
+) -> ReedResult<Vec<HashMap<String, String>>> {
+    // SELECT * → return all columns
+    if query.is_select_all() {
+        return Ok(rows.to_vec());
+    }
+
+    // Project specific columns
+    let mut result = Vec::new();
+
+    for row in rows {
+        let mut projected_row = HashMap::new();
+
+        for (i, column) in query.columns.iter().enumerate() {
+            let value = match query.expressions.get(i) {
+                Some(Some(expr)) => evaluate_expression(expr, row)?,
+                _ => row.get(column).cloned(),
+            };
+            if let Some(value) = value {
+                projected_row.insert(query.output_name(i).to_string(), value);
+            }
+            // Note: Missing columns result in absent keys (not NULL)
+        }
+
+        result.push(projected_row);
+    }
+
+    Ok(result)
+}
+
+/// Evaluates a select-list function call on one row.
+///
+/// ## Output
+/// - `Ok(None)`: A column argument is absent from the row
+///
+/// ## Error Conditions
+/// - Errors of the called function (e.g. `ParseError` for a bad date)
+fn evaluate_expression(
+    expr: &ComputedExpr,
+    row: &HashMap<String, String>,
+) -> ReedResult<Option<String>> {
+    let mut args = Vec::with_capacity(expr.args.len());
+    for arg in &expr.args {
+        match arg {
+            ComputedArg::Column(name) => match row.get(name) {
+                Some(value) => args.push(value.as_str()),
+                None => return Ok(None),
+            },
+            ComputedArg::Literal(value) => args.push(value.as_str()),
+        }
+    }
+    expr.call(&args).map(Some)
+}
+
+/// Applies LIMIT/OFFSET and projects columns of sorted rows.
+///
+/// With DISTINCT, rows are projected and deduplicated first, so DISTINCT runs
+/// after ORDER BY but before LIMIT: `SELECT DISTINCT key FROM text ORDER BY
+/// key LIMIT 10` returns the first 10 unique keys. The first occurrence of
+/// each row is kept.
+fn limit_and_project(
+    mut rows: Vec<HashMap<String, String>>,
+    query: &ParsedQuery,
+) -> ReedResult<Vec<HashMap<String, String>>> {
+    if query.distinct {
+        rows = distinct_rows(project_columns(&rows, query)?);
+        if let Some(limit) = &query.limit {
+            rows = apply_limit(rows, limit.offset, limit.limit);
+        }
+        return Ok(rows);
+    }
+
+    if let Some(limit) = &query.limit {
+        rows = apply_limit(rows, limit.offset, limit.limit);
+    }
+    project_columns(&rows, query)
+}
+
+/// Removes duplicate rows, keeping the first occurrence.
+///
+/// Rows are compared by their column/value pairs in sorted column order, so
+/// an absent column differs from an empty one.
+fn distinct_rows(rows: Vec<HashMap<String, String>>) -> Vec<HashMap<String, String>> {
+    let mut seen: HashSet<Vec<String>> = HashSet::new();
+    rows.into_iter()
+        .filter(|row| {
+            let mut columns: Vec<&String> = row.keys().collect();
+            columns.sort();
+            let signature = columns
+                .into_iter()
+                .flat_map(|column| [column.clone(), row[column].clone()])
+                .collect();
+            seen.insert(signature)
+        })
+        .collect()
+}
@@ -0,0 +1,400 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Index-aware execution (`OptimizedExecutor`): point lookups, range scans
+//! and covering indices chosen by the query planner.
+
+use super::aggregate::{aggregate, aggregation_result, execute_grouped};
+use super::filter::evaluate_condition;
+use super::{execute, limit_and_project, sort_rows};
+use crate::error::{ReedError, ReedResult};
+use crate::indices::builder::CoveredRows;
+use crate::indices::Index;
+use crate::reedql::analyzer::QueryAnalyzer;
+use crate::reedql::planner::{ExecutionPlan, IndexStatistics, QueryPlanner, Statistics};
+use crate::reedql::types::{FilterCondition, ParsedQuery, QueryResult};
+use std::collections::HashMap;
+
+/// Extended executor with index-based optimization.
+///
+/// This executor automatically detects query patterns and uses B+-Tree indices
+/// when available and cost-effective.
+///
+/// ## Example
+/// ```rust,ignore
+/// use reedbase_last::reedql::{parse, OptimizedExecutor};
+/// use reedbase_last::indices::BTreeIndex;
+///
+/// // Create executor with index
+/// let hierarchy_index = BTreeIndex::open("hierarchy.idx", Order::new(100)?)?;
+/// let executor = OptimizedExecutor::new(vec![
+///     ("hierarchy_index".to_string(), Box::new(hierarchy_index)),
+/// ]);
+///
+/// // Execute optimized query
+/// let query = parse("SELECT * FROM text WHERE key LIKE 'page.%'")?;
+/// let result = executor.execute_optimized(&query, &table)?;
+/// ```
+pub struct OptimizedExecutor {
+    /// Available indices for optimization.
+    indices: Vec<(String, Box<dyn Index<String, Vec<usize>>>)>,
+
+    /// Covering indices (see `with_covering_index()`).
+    covering: Vec<CoveringIndex>,
+
+    /// Table statistics for the planner (see `with_statistics()`).
+    statistics: Option<Statistics>,
+}
+
+/// Covering index with the columns its entries store.
+struct CoveringIndex {
+    name: String,
+    covered: Vec<String>,
+    index: Box<dyn Index<String, CoveredRows>>,
+}
+
+/// Key counts straight from the executor's indices.
+impl IndexStatistics for OptimizedExecutor {
+    fn count_keys_in_range(&self, index_name: &str, start: &str, end: &str) -> Option<usize> {
+        let (start, end) = (start.to_string(), end.to_string());
+        if let Some(covering) = self.find_covering(index_name) {
+            return covering.index.count_keys_in_range(&start, &end).ok();
+        }
+        let (_, index) = self.indices.iter().find(|(name, _)| name == index_name)?;
+        index.count_keys_in_range(&start, &end).ok()
+    }
+
+    fn key_count(&self, index_name: &str) -> Option<usize> {
+        if let Some(covering) = self.find_covering(index_name) {
+            return covering.index.key_count().ok();
+        }
+        let (_, index) = self.indices.iter().find(|(name, _)| name == index_name)?;
+        index.key_count().ok()
+    }
+}
+
+impl OptimizedExecutor {
+    /// Create executor with available indices.
+    ///
+    /// ## Arguments
+    /// - `indices`: List of (name, index) pairs for optimization
+    ///
+    /// ## Example
+    /// ```rust,ignore
+    /// let executor = OptimizedExecutor::new(vec![
+    ///     ("hierarchy_index".to_string(), Box::new(btree_index)),
+    /// ]);
+    /// ```
+    pub fn new(indices: Vec<(String, Box<dyn Index<String, Vec<usize>>>)>) -> Self {
+        Self {
+            indices,
+            covering: Vec::new(),
+            statistics: None,
+        }
+    }
+
+    /// Plan with table statistics gathered by `ANALYZE TABLE`.
+    ///
+    /// ## Example
+    /// ```rust,ignore
+    /// let executor = OptimizedExecutor::new(indices).with_statistics(stats);
+    /// ```
+    pub fn with_statistics(mut self, statistics: Statistics) -> Self {
+        self.statistics = Some(statistics);
+        self
+    }
+
+    /// Add a covering index on `key` storing the values of `covered`.
+    ///
+    /// Point lookups whose projection, conditions and ordering only use
+    /// `key` and covered columns are answered from the index without
+    /// reading table rows; other queries use its row IDs.
+    ///
+    /// ## Arguments
+    /// - `name`: Index name
+    /// - `covered`: Columns stored in the index entries
+    /// - `index`: Index built by `IndexBuilder::build_covering()`
+    ///
+    /// ## Example
+    /// ```rust,ignore
+    /// let config = IndexConfig::covering("key".to_string(), vec!["value".to_string()]);
+    /// let index = IndexBuilder::new(config.clone()).build_covering(&table)?;
+    /// let executor = OptimizedExecutor::new(vec![])
+    ///     .with_covering_index("key_value", config.covered, index);
+    /// ```
+    pub fn with_covering_index(
+        mut self,
+        name: &str,
+        covered: Vec<String>,
+        index: Box<dyn Index<String, CoveredRows>>,
+    ) -> Self {
+        self.covering.push(CoveringIndex {
+            name: name.to_string(),
+            covered,
+            index,
+        });
+        self
+    }
+
+    fn find_covering(&self, index_name: &str) -> Option<&CoveringIndex> {
+        self.covering.iter().find(|c| c.name == index_name)
+    }
+
+    /// Execute query with automatic optimization.
+    ///
+    /// ## Algorithm
+    /// 1. Analyze query for patterns (point lookup, prefix scan, range scan)
+    /// 2. Plan execution strategy (cost-based: index vs full scan)
+    /// 3. Execute using indices if beneficial
+    /// 4. Fall back to full scan otherwise
+    ///
+    /// ## Performance
+    /// - Point lookup: <100μs (index)
+    /// - Range scan: <10ms for 1000 rows (index)
+    /// - Full scan: ~10ms for 1M rows (fallback)
+    ///
+    /// ## Example
+    /// ```rust,ignore
+    /// let query = parse("SELECT * FROM text WHERE key = 'page.title'")?;
+    /// let result = executor.execute_optimized(&query, &table)?;
+    /// // Uses index point lookup if available
+    /// ```
+    pub fn execute_optimized(
+        &self,
+        query: &ParsedQuery,
+        table: &[HashMap<String, String>],
+    ) -> ReedResult<QueryResult> {
+        // 1. Analyze query
+        let pattern = QueryAnalyzer::analyze(query)?;
+
+        // 2. Plan execution
+        let mut planner = QueryPlanner::new(
+            self.indices
+                .iter()
+                .map(|(name, _)| name)
+                .chain(self.covering.iter().map(|c| &c.name))
+                .map(|name| (name.clone(), "key".to_string()))
+                .collect(),
+        );
+        if let Some(statistics) = &self.statistics {
+            planner = planner.with_statistics(statistics.clone());
+        }
+        let plan =
+            planner.plan_with_conditions(&pattern, &query.conditions, table.len(), Some(self))?;
+
+        // 3. Execute plan
+        match plan {
+            ExecutionPlan::FullScan => {
+                // Original executor logic
+                self.execute_full_scan(query, table)
+            }
+
+            ExecutionPlan::IndexPointLookup { index_name, key } => {
+                self.execute_point_lookup(&index_name, &key, query, table)
+            }
+
+            ExecutionPlan::IndexRangeScan {
+                index_name,
+                start,
+                end,
+            } => match self.execute_range_scan(&index_name, &start, &end, query, table) {
+                // Hash indices cannot serve ranges
+                Err(ReedError::IndexOperationUnsupported { .. }) => {
+                    self.execute_full_scan(query, table)
+                }
+                result => result,
+            },
+        }
+    }
+
+    fn execute_point_lookup(
+        &self,
+        index_name: &str,
+        key: &str,
+        query: &ParsedQuery,
+        table: &[HashMap<String, String>],
+    ) -> ReedResult<QueryResult> {
+        if let Some(covering) = self.find_covering(index_name) {
+            let entries = covering.index.get(&key.to_string())?.unwrap_or_default();
+
+            let mut rows: Vec<HashMap<String, String>> =
+                if Self::is_covered(query, &covering.covered) {
+                    // Answer from the index alone
+                    entries
+                        .into_iter()
+                        .map(|(_, mut values)| {
+                            values.insert("key".to_string(), key.to_string());
+                            values
+                        })
+                        .collect()
+                } else {
+                    entries
+                        .iter()
+                        .filter_map(|(id, _)| table.get(*id).cloned())
+                        .collect()
+                };
+
+            rows.retain(|row| Self::matches_all_conditions(row, &query.conditions));
+            return Self::apply_post_processing(rows, query);
+        }
+
+        // Find index
+        let index = self
+            .indices
+            .iter()
+            .find(|(name, _)| name == index_name)
+            .ok_or_else(|| ReedError::IndexNotFound {
+                name: index_name.to_string(),
+            })?;
+
+        // Lookup row IDs
+        let row_ids = index.1.get(&key.to_string())?.unwrap_or_default();
+
+        // Fetch rows
+        let mut rows: Vec<HashMap<String, String>> = row_ids
+            .iter()
+            .filter_map(|&id| table.get(id).cloned())
+            .collect();
+
+        // Apply remaining filters (non-key conditions)
+        rows.retain(|row| Self::matches_all_conditions(row, &query.conditions));
+
+        // Apply ORDER BY, LIMIT, projections
+        Self::apply_post_processing(rows, query)
+    }
+
+    fn execute_range_scan(
+        &self,
+        index_name: &str,
+        start: &str,
+        end: &str,
+        query: &ParsedQuery,
+        table: &[HashMap<String, String>],
+    ) -> ReedResult<QueryResult> {
+        let (start, end) = (start.to_string(), end.to_string());
+
+        // range() excludes `end`; fetch it for inclusive bounds (<=, BETWEEN).
+        // Rows the query does not want are dropped by the filters below.
+        let row_ids: Vec<usize> = if let Some(covering) = self.find_covering(index_name) {
+            let mut entries: CoveredRows = covering
+                .index
+                .range(&start, &end)?
+                .into_iter()
+                .flat_map(|(_, entries)| entries)
+                .collect();
+            entries.extend(covering.index.get(&end)?.unwrap_or_default());
+            entries.into_iter().map(|(id, _)| id).collect()
+        } else {
+            // Find index
+            let index = self
+                .indices
+                .iter()
+                .find(|(name, _)| name == index_name)
+                .ok_or_else(|| ReedError::IndexNotFound {
+                    name: index_name.to_string(),
+                })?;
+
+            // Range scan, flattening row IDs
+            let mut row_ids: Vec<usize> = index
+                .1
+                .range(&start, &end)?
+                .into_iter()
+                .flat_map(|(_, ids)| ids)
+                .collect();
+            row_ids.extend(index.1.get(&end)?.unwrap_or_default());
+            row_ids
+        };
+
+        // Fetch rows
+        let mut rows: Vec<HashMap<String, String>> = row_ids
+            .iter()
+            .filter_map(|&id| table.get(id).cloned())
+            .collect();
+
+        // Apply remaining filters
+        rows.retain(|row| Self::matches_all_conditions(row, &query.conditions));
+
+        // Apply ORDER BY, LIMIT, projections
+        Self::apply_post_processing(rows, query)
+    }
+
+    fn execute_full_scan(
+        &self,
+        query: &ParsedQuery,
+        table: &[HashMap<String, String>],
+    ) -> ReedResult<QueryResult> {
+        // Original REED-19-12 logic (unchanged)
+        execute(query, table)
+    }
+
+    /// Whether `key` and `covered` hold every column the query reads.
+    fn is_covered(query: &ParsedQuery, covered: &[String]) -> bool {
+        let available = |column: &str| column == "key" || covered.iter().any(|c| c == column);
+
+        !query.is_select_all()
+            && query.joins.is_empty()
+            && query.having.is_none()
+            && query
+                .columns
+                .iter()
+                .enumerate()
+                .all(|(i, c)| match query.expressions.get(i) {
+                    Some(Some(expr)) => expr.columns().all(available),
+                    _ => available(c),
+                })
+            && query.group_by.iter().all(|c| available(c))
+            && query.order_by.iter().all(|o| available(&o.column))
+            && query.aggregation.as_ref().is_none_or(|agg| {
+                (agg.column == "*" || available(&agg.column))
+                    && agg.second_column.as_deref().is_none_or(available)
+            })
+            && query
+                .conditions
+                .iter()
+                .all(|c| Self::condition_covered(c, &available))
+    }
+
+    fn condition_covered(condition: &FilterCondition, available: &impl Fn(&str) -> bool) -> bool {
+        match condition {
+            FilterCondition::Or(operands) | FilterCondition::And(operands) => operands
+                .iter()
+                .all(|operand| Self::condition_covered(operand, available)),
+            FilterCondition::Not(inner) => Self::condition_covered(inner, available),
+            FilterCondition::InSubquery { .. } => false,
+            other => available(other.column()),
+        }
+    }
+
+    fn matches_all_conditions(
+        row: &HashMap<String, String>,
+        conditions: &[FilterCondition],
+    ) -> bool {
+        conditions
+            .iter()
+            .all(|cond| evaluate_condition(cond, row).unwrap_or(false))
+    }
+
+    fn apply_post_processing(
+        mut rows: Vec<HashMap<String, String>>,
+        query: &ParsedQuery,
+    ) -> ReedResult<QueryResult> {
+        // Handle GROUP BY or a single aggregation (if specified)
+        if query.has_grouping() {
+            return execute_grouped(rows, query);
+        }
+        if let Some(agg) = &query.aggregation {
+            let value = aggregate(&rows, agg, query)?;
+            return Ok(aggregation_result(value, agg));
+        }
+
+        // Apply ORDER BY
+        if !query.order_by.is_empty() {
+            sort_rows(&mut rows, query);
+        }
+
+        // Apply DISTINCT, LIMIT/OFFSET and project columns
+        let projected = limit_and_project(rows, query)?;
+
+        Ok(QueryResult::Rows(projected))
+    }
+}
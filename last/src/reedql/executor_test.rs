@@ -3,6 +3,8 @@
 
 #[cfg(test)]
 mod tests {
+    use crate::btree::{BPlusTree, Order};
+    use crate::indices::builder::{IndexBuilder, IndexConfig};
     use crate::indices::{HashMapIndex, Index};
     use crate::reedql::{parse, OptimizedExecutor, QueryResult};
     use std::collections::HashMap;
@@ -146,6 +148,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_optimized_executor_between_uses_range_scan() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut tree = BPlusTree::<String, Vec<usize>>::open(
+            dir.path().join("key.btree"),
+            Order::new(32).unwrap(),
+        )
+        .unwrap();
+        let table: Vec<HashMap<String, String>> = (0..1000)
+            .map(|i| HashMap::from([("key".to_string(), format!("item.{:04}", i))]))
+            .collect();
+        for (id, row) in table.iter().enumerate() {
+            tree.insert(row["key"].clone(), vec![id]).unwrap();
+        }
+        let executor = OptimizedExecutor::new(vec![("key_index".to_string(), Box::new(tree))]);
+
+        // Both bounds are included, although the index range excludes its end
+        let query =
+            parse("SELECT * FROM text WHERE key BETWEEN 'item.0100' AND 'item.0109'").unwrap();
+        match executor.execute_optimized(&query, &table).unwrap() {
+            QueryResult::Rows(rows) => {
+                let mut keys: Vec<&str> = rows.iter().map(|r| r["key"].as_str()).collect();
+                keys.sort();
+                assert_eq!(keys.len(), 10);
+                assert_eq!(keys.first(), Some(&"item.0100"));
+                assert_eq!(keys.last(), Some(&"item.0109"));
+            }
+            _ => panic!("Expected rows result"),
+        }
+
+        let query =
+            parse("SELECT * FROM text WHERE key NOT BETWEEN 'item.0100' AND 'item.0109'").unwrap();
+        assert_eq!(
+            executor
+                .execute_optimized(&query, &table)
+                .unwrap()
+                .row_count(),
+            990
+        );
+    }
+
     #[test]
     fn test_optimized_executor_with_additional_filters() {
         let table = create_test_table();
@@ -262,4 +305,74 @@ mod tests {
 
         assert_eq!(result.row_count(), 0);
     }
+
+    #[test]
+    fn test_optimized_executor_covering_index() {
+        let table: Vec<HashMap<String, String>> = (0..1000)
+            .map(|i| {
+                HashMap::from([
+                    ("key".to_string(), format!("item.{:04}", i)),
+                    ("value".to_string(), format!("value {}", i)),
+                    ("namespace".to_string(), "item".to_string()),
+                ])
+            })
+            .collect();
+        let config = IndexConfig::covering("key".to_string(), vec!["value".to_string()]);
+        let index = IndexBuilder::new(config.clone())
+            .build_covering(&table)
+            .unwrap();
+        let executor =
+            OptimizedExecutor::new(vec![]).with_covering_index("key_value", config.covered, index);
+
+        // Rows as read by a full scan differ from the indexed values
+        let stale: Vec<HashMap<String, String>> = table
+            .iter()
+            .map(|row| {
+                let mut row = row.clone();
+                row.insert("value".to_string(), "stale".to_string());
+                row
+            })
+            .collect();
+
+        // Covered projection: answered from the index
+        let query =
+            parse("SELECT key, value FROM text WHERE key = 'item.0042' AND value != ''").unwrap();
+        match executor.execute_optimized(&query, &stale).unwrap() {
+            QueryResult::Rows(rows) => {
+                assert_eq!(rows.len(), 1);
+                assert_eq!(rows[0]["key"], "item.0042");
+                assert_eq!(rows[0]["value"], "value 42");
+            }
+            _ => panic!("Expected rows result"),
+        }
+
+        // Uncovered column: rows are fetched by ID
+        let query = parse("SELECT value, namespace FROM text WHERE key = 'item.0042'").unwrap();
+        match executor.execute_optimized(&query, &stale).unwrap() {
+            QueryResult::Rows(rows) => {
+                assert_eq!(rows[0]["value"], "stale");
+                assert_eq!(rows[0]["namespace"], "item");
+            }
+            _ => panic!("Expected rows result"),
+        }
+        let query = parse("SELECT * FROM text WHERE key = 'item.0007'").unwrap();
+        assert_eq!(
+            executor
+                .execute_optimized(&query, &table)
+                .unwrap()
+                .row_count(),
+            1
+        );
+
+        // Range scans use the stored row IDs
+        let query =
+            parse("SELECT key FROM text WHERE key BETWEEN 'item.0100' AND 'item.0109'").unwrap();
+        assert_eq!(
+            executor
+                .execute_optimized(&query, &table)
+                .unwrap()
+                .row_count(),
+            10
+        );
+    }
 }
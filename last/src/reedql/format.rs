@@ -0,0 +1,768 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! ReedQL Query Pretty-Printer
+//!
+//! Re-serialises a parsed query in one canonical layout so that long
+//! hand-written queries become readable and diffs stay stable.
+//!
+//! ## Layout
+//! ```text
+//! SELECT
+//!     key,
+//!     value AS v
+//! FROM text t
+//! JOIN routes r ON t.key = r.text_key
+//! WHERE
+//!     key LIKE '%.@de'
+//!     AND namespace = 'page'
+//! ORDER BY key ASC
+//! LIMIT 10 OFFSET 20
+//! ```
+//!
+//! Keywords are uppercase, identifiers lowercase; string literals are kept
+//! verbatim.
+//!
+//! `ParsedQuery` also implements `Display`, which renders the same clauses
+//! on a single line and keeps identifier case, so that
+//! `parse(&query.to_string())` yields `query` again. Use it to log the
+//! normalised form of user-submitted queries.
+
+use crate::error::ReedResult;
+use crate::reedql::parser::parse;
+use crate::reedql::types::{Collation, FilterCondition, ParsedQuery, SortDirection};
+use std::fmt;
+
+/// Indentation unit for nested lines.
+const INDENT: &str = "    ";
+
+/// Formats a ReedQL query in canonical pretty-printed form.
+///
+/// ## Input
+/// - `sql`: ReedQL SELECT query
+///
+/// ## Output
+/// - `Ok(String)`: Formatted query (no trailing newline)
+/// - `Err(ReedError)`: Query does not parse
+///
+/// ## Performance
+/// - Parse + render: < 20μs for typical queries
+///
+/// ## Error Conditions
+/// - `ParseError`: Invalid ReedQL syntax
+///
+/// ## Example Usage
+/// ```rust
+/// use reedbase_last::reedql::format_query;
+///
+/// let formatted = format_query("select key from text where namespace = 'page' limit 5")?;
+/// assert_eq!(
+///     formatted,
+///     "SELECT\n    key\nFROM text\nWHERE\n    namespace = 'page'\nLIMIT 5"
+/// );
+/// # Ok::<(), reedbase_last::ReedError>(())
+/// ```
+pub fn format_query(sql: &str) -> ReedResult<String> {
+    let query = parse(sql)?;
+    Ok(render(&query, 0).join("\n"))
+}
+
+/// Renders a query as lines, each prefixed with `depth` indentation units.
+fn render(query: &ParsedQuery, depth: usize) -> Vec<String> {
+    let pad = INDENT.repeat(depth);
+    let mut lines = vec![if query.distinct {
+        format!("{}SELECT DISTINCT", pad)
+    } else {
+        format!("{}SELECT", pad)
+    }];
+
+    // Select list (without GROUP BY, `columns` mirrors the aggregation's)
+    let mut items: Vec<String> = Vec::new();
+    if query.aggregation.is_none() || query.has_grouping() {
+        items.extend((0..query.columns.len()).map(|i| {
+            let alias = query.column_aliases.get(i).and_then(|a| a.as_deref());
+            let item = match query.expressions.get(i) {
+                // Literal arguments keep their case
+                Some(Some(expr)) => expr.to_string(),
+                _ => ident(&query.columns[i]),
+            };
+            with_alias(item, alias)
+        }));
+    }
+    if let Some(agg) = &query.aggregation {
+        let mut item = format!("{}({}", agg.agg_type, ident(&agg.column));
+        if let Some(second) = &agg.second_column {
+            item.push_str(&format!(", {}", ident(second)));
+        }
+        item.push(')');
+        items.push(with_alias(item, agg.alias.as_deref()));
+    }
+    let last = items.len().saturating_sub(1);
+    for (i, item) in items.iter().enumerate() {
+        let separator = if i < last { "," } else { "" };
+        lines.push(format!("{}{}{}{}", pad, INDENT, item, separator));
+    }
+
+    // FROM and JOINs
+    lines.push(format!(
+        "{}FROM {}",
+        pad,
+        table_ref(&query.table, query.table_alias.as_deref())
+    ));
+    for join in &query.joins {
+        let mut line = format!(
+            "{}{} {}",
+            pad,
+            join.join_type,
+            table_ref(&join.table, join.alias.as_deref())
+        );
+        if let Some(condition) = &join.condition {
+            line.push_str(" ON ");
+            line.push_str(&join_condition(condition));
+        }
+        lines.push(line);
+    }
+
+    // WHERE
+    if !query.conditions.is_empty() {
+        lines.push(format!("{}WHERE", pad));
+        for (i, condition) in query.conditions.iter().enumerate() {
+            let prefix = if i == 0 { "" } else { "AND " };
+            let mut rendered = render_operand(condition, depth + 1, query.conditions.len() > 1);
+            rendered[0] = format!("{}{}{}{}", pad, INDENT, prefix, rendered[0]);
+            lines.extend(rendered);
+        }
+    }
+
+    // GROUP BY and HAVING
+    if query.has_grouping() {
+        let columns: Vec<String> = query.group_by.iter().map(|c| ident(c)).collect();
+        lines.push(format!("{}GROUP BY {}", pad, columns.join(", ")));
+    }
+    if let Some(having) = &query.having {
+        lines.push(format!("{}HAVING", pad));
+        let mut rendered = render_operand(having, depth + 1, false);
+        rendered[0] = format!("{}{}{}", pad, INDENT, rendered[0]);
+        lines.extend(rendered);
+    }
+
+    // ORDER BY
+    if !query.order_by.is_empty() {
+        let orders: Vec<String> = query
+            .order_by
+            .iter()
+            .map(|o| {
+                let direction = match o.direction {
+                    SortDirection::Ascending => "ASC",
+                    SortDirection::Descending => "DESC",
+                };
+                match o.collation {
+                    Collation::Lexicographic => format!("{} {}", ident(&o.column), direction),
+                    Collation::Numeric => {
+                        format!("{} NUMERIC {}", ident(&o.column), direction)
+                    }
+                }
+            })
+            .collect();
+        lines.push(format!("{}ORDER BY {}", pad, orders.join(", ")));
+    }
+
+    // LIMIT
+    if let Some(limit) = &query.limit {
+        let mut line = format!("{}LIMIT {}", pad, limit.limit);
+        if limit.offset > 0 {
+            line.push_str(&format!(" OFFSET {}", limit.offset));
+        }
+        lines.push(line);
+    }
+
+    lines
+}
+
+impl fmt::Display for ParsedQuery {
+    /// Single-line canonical ReedQL (keywords uppercase, identifiers as parsed).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SELECT ")?;
+        if self.distinct {
+            write!(f, "DISTINCT ")?;
+        }
+        // Without GROUP BY, `columns` mirrors the aggregation's
+        let mut separator = "";
+        if self.aggregation.is_none() || self.has_grouping() {
+            for (i, column) in self.columns.iter().enumerate() {
+                write!(f, "{}{}", separator, column)?;
+                if let Some(Some(alias)) = self.column_aliases.get(i) {
+                    write!(f, " AS {}", alias)?;
+                }
+                separator = ", ";
+            }
+        }
+        if let Some(agg) = &self.aggregation {
+            write!(f, "{}{}({}", separator, agg.agg_type, agg.column)?;
+            if let Some(second) = &agg.second_column {
+                write!(f, ", {}", second)?;
+            }
+            write!(f, ")")?;
+            if let Some(alias) = &agg.alias {
+                write!(f, " AS {}", alias)?;
+            }
+        }
+
+        write!(f, " FROM {}", self.table)?;
+        if let Some(alias) = &self.table_alias {
+            write!(f, " {}", alias)?;
+        }
+        for join in &self.joins {
+            write!(f, " {} {}", join.join_type, join.table)?;
+            if let Some(alias) = &join.alias {
+                write!(f, " {}", alias)?;
+            }
+            if let Some(condition) = &join.condition {
+                write!(f, " ON {}", join_condition_verbatim(condition))?;
+            }
+        }
+
+        for (i, condition) in self.conditions.iter().enumerate() {
+            let keyword = if i == 0 { "WHERE" } else { "AND" };
+            write!(
+                f,
+                " {} {}",
+                keyword,
+                inline_operand(condition, self.conditions.len() > 1)
+            )?;
+        }
+
+        if self.has_grouping() {
+            write!(f, " GROUP BY {}", self.group_by.join(", "))?;
+        }
+        if let Some(having) = &self.having {
+            write!(f, " HAVING {}", inline_condition(having))?;
+        }
+
+        for (i, order) in self.order_by.iter().enumerate() {
+            let separator = if i == 0 { " ORDER BY " } else { ", " };
+            write!(f, "{}{}", separator, order.column)?;
+            if order.collation == Collation::Numeric {
+                write!(f, " NUMERIC")?;
+            }
+            write!(f, " {}", order.direction)?;
+        }
+
+        if let Some(limit) = &self.limit {
+            write!(f, " LIMIT {}", limit.limit)?;
+            if limit.offset > 0 {
+                write!(f, " OFFSET {}", limit.offset)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats a single condition as ReedQL text.
+///
+/// The output is what a WHERE clause would contain, with identifiers as
+/// parsed, and `parser::parse_condition()` reads it back unchanged.
+///
+/// ## Example
+/// ```rust
+/// use reedbase_last::reedql::format::format_condition;
+/// use reedbase_last::reedql::parser::parse_condition;
+///
+/// let condition = parse_condition("status IN ('draft','live') and not deleted = 'yes'")?;
+/// assert_eq!(
+///     format_condition(&condition),
+///     "status IN ('draft', 'live') AND NOT deleted = 'yes'"
+/// );
+/// # Ok::<(), reedbase_last::ReedError>(())
+/// ```
+pub fn format_condition(condition: &FilterCondition) -> String {
+    inline_condition(condition)
+}
+
+/// Renders a WHERE condition on one line, identifiers as parsed.
+fn inline_condition(condition: &FilterCondition) -> String {
+    match condition {
+        FilterCondition::Equals { column, value } => format!("{} = {}", column, literal(value)),
+        FilterCondition::NotEquals { column, value } => {
+            format!("{} != {}", column, literal(value))
+        }
+        FilterCondition::LessThan { column, value } => format!("{} < {}", column, literal(value)),
+        FilterCondition::GreaterThan { column, value } => {
+            format!("{} > {}", column, literal(value))
+        }
+        FilterCondition::LessThanOrEqual { column, value } => {
+            format!("{} <= {}", column, literal(value))
+        }
+        FilterCondition::GreaterThanOrEqual { column, value } => {
+            format!("{} >= {}", column, literal(value))
+        }
+        FilterCondition::Like { column, pattern } => {
+            format!("{} LIKE {}", column, literal(pattern))
+        }
+        FilterCondition::InList { column, values } => {
+            let values: Vec<String> = values.iter().map(|v| literal(v)).collect();
+            format!("{} IN ({})", column, values.join(", "))
+        }
+        FilterCondition::IsEmpty { column } => format!("{} IS EMPTY", column),
+        FilterCondition::IsNotEmpty { column } => format!("{} IS NOT EMPTY", column),
+        FilterCondition::IsNull { column } => format!("{} IS NULL", column),
+        FilterCondition::IsNotNull { column } => format!("{} IS NOT NULL", column),
+        FilterCondition::Between { column, low, high } => {
+            format!("{} BETWEEN {} AND {}", column, literal(low), literal(high))
+        }
+        FilterCondition::InSubquery { column, subquery } => {
+            format!("{} IN ({})", column, subquery)
+        }
+        FilterCondition::Or(operands) => {
+            let operands: Vec<String> = operands.iter().map(|c| inline_operand(c, false)).collect();
+            operands.join(" OR ")
+        }
+        FilterCondition::And(operands) => {
+            let operands: Vec<String> = operands.iter().map(|c| inline_operand(c, true)).collect();
+            operands.join(" AND ")
+        }
+        FilterCondition::Not(inner) if is_group(inner) => {
+            format!("NOT ({})", inline_condition(inner))
+        }
+        FilterCondition::Not(inner) => format!("NOT {}", inline_condition(inner)),
+    }
+}
+
+/// Renders a condition on one line, parenthesised if it is an OR inside an AND.
+fn inline_operand(condition: &FilterCondition, in_and: bool) -> String {
+    match condition {
+        FilterCondition::Or(_) if in_and => format!("({})", inline_condition(condition)),
+        _ => inline_condition(condition),
+    }
+}
+
+/// Renders a JOIN ON condition, identifiers as parsed.
+fn join_condition_verbatim(condition: &FilterCondition) -> String {
+    let (column, operator, value) = match condition {
+        FilterCondition::Equals { column, value } => (column, "=", value),
+        FilterCondition::NotEquals { column, value } => (column, "!=", value),
+        FilterCondition::LessThan { column, value } => (column, "<", value),
+        FilterCondition::GreaterThan { column, value } => (column, ">", value),
+        FilterCondition::LessThanOrEqual { column, value } => (column, "<=", value),
+        FilterCondition::GreaterThanOrEqual { column, value } => (column, ">=", value),
+        // The parser only builds comparisons for ON
+        other => return other.to_string(),
+    };
+    format!("{} {} {}", column, operator, value)
+}
+
+/// Renders a WHERE condition.
+///
+/// The first line carries no indentation (the caller adds it); subquery
+/// lines after it are indented for `depth`.
+fn render_condition(condition: &FilterCondition, depth: usize) -> Vec<String> {
+    let single = |text: String| vec![text];
+
+    match condition {
+        FilterCondition::Equals { column, value } => {
+            single(format!("{} = {}", ident(column), literal(value)))
+        }
+        FilterCondition::NotEquals { column, value } => {
+            single(format!("{} != {}", ident(column), literal(value)))
+        }
+        FilterCondition::LessThan { column, value } => {
+            single(format!("{} < {}", ident(column), literal(value)))
+        }
+        FilterCondition::GreaterThan { column, value } => {
+            single(format!("{} > {}", ident(column), literal(value)))
+        }
+        FilterCondition::LessThanOrEqual { column, value } => {
+            single(format!("{} <= {}", ident(column), literal(value)))
+        }
+        FilterCondition::GreaterThanOrEqual { column, value } => {
+            single(format!("{} >= {}", ident(column), literal(value)))
+        }
+        FilterCondition::Like { column, pattern } => {
+            single(format!("{} LIKE {}", ident(column), literal(pattern)))
+        }
+        FilterCondition::InList { column, values } => {
+            let values: Vec<String> = values.iter().map(|v| literal(v)).collect();
+            single(format!("{} IN ({})", ident(column), values.join(", ")))
+        }
+        FilterCondition::IsEmpty { column } => single(format!("{} IS EMPTY", ident(column))),
+        FilterCondition::IsNotEmpty { column } => single(format!("{} IS NOT EMPTY", ident(column))),
+        FilterCondition::IsNull { column } => single(format!("{} IS NULL", ident(column))),
+        FilterCondition::IsNotNull { column } => single(format!("{} IS NOT NULL", ident(column))),
+        FilterCondition::Between { column, low, high } => single(format!(
+            "{} BETWEEN {} AND {}",
+            ident(column),
+            literal(low),
+            literal(high)
+        )),
+        FilterCondition::InSubquery { column, subquery } => {
+            let mut lines = vec![format!("{} IN (", ident(column))];
+            lines.extend(render(subquery, depth + 1));
+            lines.push(format!("{})", INDENT.repeat(depth)));
+            lines
+        }
+        FilterCondition::Or(operands) => render_group(operands, "OR", depth),
+        FilterCondition::And(operands) => render_group(operands, "AND", depth),
+        FilterCondition::Not(inner) => {
+            let mut lines = render_condition(inner, depth);
+            if is_group(inner) {
+                parenthesise(&mut lines);
+            }
+            lines[0] = format!("NOT {}", lines[0]);
+            lines
+        }
+    }
+}
+
+/// Renders AND/OR operands one per line, the keyword leading each
+/// continuation line.
+fn render_group(operands: &[FilterCondition], keyword: &str, depth: usize) -> Vec<String> {
+    let pad = INDENT.repeat(depth);
+    let mut lines = Vec::new();
+    for (i, operand) in operands.iter().enumerate() {
+        let mut rendered = render_operand(operand, depth, keyword == "AND");
+        if i > 0 {
+            rendered[0] = format!("{}{} {}", pad, keyword, rendered[0]);
+        }
+        lines.extend(rendered);
+    }
+    lines
+}
+
+/// Renders a condition, parenthesised if it is an OR inside an AND.
+fn render_operand(condition: &FilterCondition, depth: usize, in_and: bool) -> Vec<String> {
+    let mut lines = render_condition(condition, depth);
+    if in_and && matches!(condition, FilterCondition::Or(_)) {
+        parenthesise(&mut lines);
+    }
+    lines
+}
+
+/// Wraps rendered lines in parentheses.
+fn parenthesise(lines: &mut [String]) {
+    lines[0] = format!("({}", lines[0]);
+    if let Some(last) = lines.last_mut() {
+        last.push(')');
+    }
+}
+
+/// Returns true for AND/OR groups, which need parentheses after NOT.
+fn is_group(condition: &FilterCondition) -> bool {
+    matches!(condition, FilterCondition::Or(_) | FilterCondition::And(_))
+}
+
+/// Renders a JOIN ON condition (both operands are column references).
+fn join_condition(condition: &FilterCondition) -> String {
+    let (column, operator, value) = match condition {
+        FilterCondition::Equals { column, value } => (column, "=", value),
+        FilterCondition::NotEquals { column, value } => (column, "!=", value),
+        FilterCondition::LessThan { column, value } => (column, "<", value),
+        FilterCondition::GreaterThan { column, value } => (column, ">", value),
+        FilterCondition::LessThanOrEqual { column, value } => (column, "<=", value),
+        FilterCondition::GreaterThanOrEqual { column, value } => (column, ">=", value),
+        // The parser only builds comparisons for ON
+        other => return other.to_string(),
+    };
+    format!("{} {} {}", ident(column), operator, ident(value))
+}
+
+/// Renders `table [alias]`.
+fn table_ref(table: &str, alias: Option<&str>) -> String {
+    match alias {
+        Some(alias) => format!("{} {}", ident(table), ident(alias)),
+        None => ident(table),
+    }
+}
+
+/// Appends ` AS alias` if present.
+fn with_alias(item: String, alias: Option<&str>) -> String {
+    match alias {
+        Some(alias) => format!("{} AS {}", item, ident(alias)),
+        None => item,
+    }
+}
+
+/// Normalises an identifier to lowercase.
+fn ident(name: &str) -> String {
+    name.to_ascii_lowercase()
+}
+
+/// Quotes a string literal, preferring single quotes.
+///
+/// ReedQL literals have no escapes, so a value containing `'` is wrapped in
+/// double quotes instead.
+fn literal(value: &str) -> String {
+    if value.contains('\'') {
+        format!("\"{}\"", value)
+    } else {
+        format!("'{}'", value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_simple_select() {
+        let formatted = format_query("select KEY, Value from TEXT").unwrap();
+        assert_eq!(formatted, "SELECT\n    key,\n    value\nFROM text");
+    }
+
+    #[test]
+    fn test_format_function_call() {
+        let formatted =
+            format_query("select KEY, format_date(Created, '%B') as Month from USERS").unwrap();
+        assert_eq!(
+            formatted,
+            "SELECT\n    key,\n    format_date(Created, '%B') AS month\nFROM users"
+        );
+    }
+
+    #[test]
+    fn test_format_group_by() {
+        let formatted = format_query(
+            "select namespace, count(*) from text group by namespace having count > '1'",
+        )
+        .unwrap();
+        assert_eq!(
+            formatted,
+            "SELECT\n    namespace,\n    COUNT(*)\nFROM text\nGROUP BY namespace\n\
+             HAVING\n    count > '1'"
+        );
+    }
+
+    #[test]
+    fn test_format_distinct() {
+        let formatted = format_query("select distinct namespace from text").unwrap();
+        assert_eq!(formatted, "SELECT DISTINCT\n    namespace\nFROM text");
+    }
+
+    #[test]
+    fn test_format_full_query() {
+        let formatted = format_query(
+            "SELECT key AS k, value FROM text WHERE key LIKE '%.@de' AND namespace = 'Page' \
+             AND value IS NOT EMPTY ORDER BY key DESC, value LIMIT 10 OFFSET 20",
+        )
+        .unwrap();
+        assert_eq!(
+            formatted,
+            "SELECT\n    key AS k,\n    value\nFROM text\nWHERE\n    key LIKE '%.@de'\n    \
+             AND namespace = 'Page'\n    AND value IS NOT EMPTY\n\
+             ORDER BY key DESC, value ASC\nLIMIT 10 OFFSET 20"
+        );
+    }
+
+    #[test]
+    fn test_format_or_groups() {
+        let formatted = format_query(
+            "SELECT * FROM text WHERE (namespace = 'page' OR namespace = 'global') AND key LIKE '%.@de'",
+        )
+        .unwrap();
+        assert_eq!(
+            formatted,
+            "SELECT\n    *\nFROM text\nWHERE\n    (namespace = 'page'\n    OR namespace = 'global')\n    \
+             AND key LIKE '%.@de'"
+        );
+
+        let formatted =
+            format_query("select * from t where not (a = '1' or b = '2') and c not like 'x%'")
+                .unwrap();
+        assert_eq!(
+            formatted,
+            "SELECT\n    *\nFROM t\nWHERE\n    NOT (a = '1'\n    OR b = '2')\n    \
+             AND NOT c LIKE 'x%'"
+        );
+
+        let formatted =
+            format_query("SELECT * FROM t WHERE a = '1' AND b = '2' OR c = '3'").unwrap();
+        assert_eq!(
+            formatted,
+            "SELECT\n    *\nFROM t\nWHERE\n    a = '1'\n    AND b = '2'\n    OR c = '3'"
+        );
+    }
+
+    #[test]
+    fn test_format_aggregation_and_in_list() {
+        let formatted =
+            format_query("SELECT CORR(a, b) AS r FROM stats WHERE kind IN ('x', 'y')").unwrap();
+        assert_eq!(
+            formatted,
+            "SELECT\n    CORR(a, b) AS r\nFROM stats\nWHERE\n    kind IN ('x', 'y')"
+        );
+    }
+
+    #[test]
+    fn test_format_join() {
+        let formatted = format_query(
+            "SELECT t.key, r.route FROM text t LEFT JOIN routes r ON t.key = r.text_key",
+        )
+        .unwrap();
+        assert_eq!(
+            formatted,
+            "SELECT\n    t.key,\n    r.route\nFROM text t\nLEFT JOIN routes r ON t.key = r.text_key"
+        );
+    }
+
+    #[test]
+    fn test_format_subquery_is_indented() {
+        let formatted = format_query(
+            "SELECT * FROM text WHERE key IN (SELECT key FROM routes WHERE route = '/')",
+        )
+        .unwrap();
+        assert_eq!(
+            formatted,
+            "SELECT\n    *\nFROM text\nWHERE\n    key IN (\n        SELECT\n            key\n        \
+             FROM routes\n        WHERE\n            route = '/'\n    )"
+        );
+    }
+
+    #[test]
+    fn test_format_is_idempotent_and_round_trips() {
+        let sql = "SELECT key, value FROM text WHERE value = \"it's\" AND key IN \
+                   (SELECT key FROM routes) ORDER BY key LIMIT 5";
+        let once = format_query(sql).unwrap();
+        assert_eq!(format_query(&once).unwrap(), once);
+        assert_eq!(parse(&once).unwrap(), parse(sql).unwrap());
+    }
+
+    #[test]
+    fn test_format_condition_round_trips() {
+        use crate::reedql::parser::parse_condition;
+
+        for condition in [
+            "status = 'active'",
+            "age > '18' AND (lang = 'de' OR lang = 'en')",
+            "key LIKE 'page.%'",
+            "value IS NOT EMPTY",
+        ] {
+            let parsed = parse_condition(condition).unwrap();
+            assert_eq!(parse_condition(&format_condition(&parsed)).unwrap(), parsed);
+        }
+        assert!(parse_condition("status = 'active' LIMIT 5").is_err());
+    }
+
+    #[test]
+    fn test_format_invalid_query() {
+        assert!(format_query("SELECT FROM").is_err());
+    }
+
+    /// Valid queries from the ReedQL test suites.
+    const SUITE_QUERIES: &[&str] = &[
+        "SELECT * FROM text",
+        "select * from text where namespace = 'page'",
+        "SELECT * FROM text LIMIT 10 OFFSET 5",
+        "SELECT * FROM text ORDER BY key DESC",
+        "SELECT * FROM text WHERE key > 'a' AND key < 'z' AND key != 'middle'",
+        "SELECT * FROM text WHERE key >= 'page.a' AND key <= 'page.z'",
+        "SELECT * FROM text WHERE key LIKE '%.@de' LIMIT 10",
+        "SELECT * FROM text WHERE key LIKE 'page.%' ORDER BY key DESC",
+        "SELECT * FROM text WHERE namespace IN ('page', 'global')",
+        "SELECT * FROM text WHERE value IS EMPTY",
+        "SELECT * FROM text WHERE value IS NULL AND note IS NOT NULL",
+        "SELECT * FROM text WHERE value is not empty AND key = 'a'",
+        "SELECT CORR(age, income) FROM users",
+        "SELECT COUNT(*) AS total FROM text",
+        "SELECT COUNT(*) FROM text WHERE key LIKE 'page.%'",
+        "SELECT COVAR( age ,income ) AS cov FROM users",
+        "SELECT key AS k, value, namespace as ns FROM text",
+        "SELECT key, value FROM text WHERE key = 'page.header.title@de'",
+        "SELECT t.key, r.route FROM text t JOIN routes r ON t.key = r.text_key",
+        "SELECT T.Key FROM Text T LEFT JOIN Routes R ON T.Key = R.Text_Key",
+        "SELECT * FROM text NATURAL JOIN routes",
+        "SELECT * FROM text WHERE value = \"it's\" AND n = '5'",
+        "SELECT * FROM text WHERE key IN (SELECT key FROM routes WHERE route LIKE '/%') LIMIT 3",
+        "SELECT * FROM text WHERE namespace = 'page' OR namespace = 'global'",
+        "SELECT * FROM t WHERE a = '1' AND b = '2' OR c = '3' AND d = '4'",
+        "SELECT * FROM t WHERE (a = '1' OR b = '2') AND c = '3'",
+        "SELECT * FROM t WHERE a = '1' OR (b = '2' OR c = '3') AND d = '4'",
+        "SELECT * FROM text WHERE NOT key LIKE '%draft%'",
+        "SELECT * FROM text WHERE namespace NOT IN ('a', 'b') AND NOT (a = '1' OR b = '2')",
+        "SELECT * FROM t WHERE NOT NOT a = '1' OR NOT (b = '2' AND c = '3')",
+        "SELECT DISTINCT namespace FROM text ORDER BY namespace ASC LIMIT 10",
+        "SELECT namespace, COUNT(*) AS n FROM text GROUP BY namespace HAVING n > '1' OR n = '0'",
+        "SELECT a, b, SUM(c) FROM t WHERE c != '' GROUP BY a, b ORDER BY sum DESC LIMIT 5",
+        "SELECT * FROM t WHERE price BETWEEN '10' AND '20' AND NOT key BETWEEN 'a' AND 'b'",
+        "SELECT * FROM users ORDER BY age NUMERIC DESC, name LIMIT 5",
+    ];
+
+    #[test]
+    fn test_display_round_trip() {
+        for sql in SUITE_QUERIES {
+            let query = parse(sql).unwrap();
+            let displayed = query.to_string();
+            assert_eq!(
+                parse(&displayed).unwrap(),
+                query,
+                "{} -> {}",
+                sql,
+                displayed
+            );
+            assert_eq!(parse(&displayed).unwrap().to_string(), displayed);
+        }
+    }
+
+    #[test]
+    fn test_display_canonical_form() {
+        let query =
+            parse("select  Key as k ,value from TEXT where  namespace='page' limit 5").unwrap();
+        assert_eq!(
+            query.to_string(),
+            "SELECT Key AS k, value FROM TEXT WHERE namespace = 'page' LIMIT 5"
+        );
+    }
+
+    mod round_trip {
+        use crate::reedql::parser::parse;
+        use proptest::prelude::*;
+
+        fn condition() -> impl Strategy<Value = String> {
+            let column = "[a-z][a-z_]{0,6}";
+            let value = "[a-zA-Z0-9 .%@/-]{0,8}";
+            prop_oneof![
+                (
+                    column,
+                    prop::sample::select(vec!["=", "!=", "<", ">", "<=", ">="]),
+                    value
+                )
+                    .prop_map(|(c, op, v)| format!("{} {} '{}'", c, op, v)),
+                (column, value).prop_map(|(c, v)| format!("{} LIKE '{}'", c, v)),
+                (column, prop::collection::vec(value, 1..4)).prop_map(|(c, vs)| {
+                    let vs: Vec<String> = vs.iter().map(|v| format!("'{}'", v)).collect();
+                    format!("{} IN ({})", c, vs.join(", "))
+                }),
+                column.prop_map(|c| format!("{} IS NOT EMPTY", c)),
+                (column, value, value)
+                    .prop_map(|(c, lo, hi)| format!("{} BETWEEN '{}' AND '{}'", c, lo, hi)),
+            ]
+        }
+
+        proptest! {
+            /// parse → display → parse yields the same query.
+            #[test]
+            fn prop_display_round_trip(
+                columns in prop::collection::vec("[a-z][a-z_.]{0,6}", 1..4),
+                table in "[a-z][a-z_]{0,8}",
+                conditions in prop::collection::vec(condition(), 0..4),
+                order in prop::option::of(("[a-z]{1,6}", any::<bool>())),
+                limit in prop::option::of((0usize..1000, 0usize..50)),
+            ) {
+                let mut sql = format!("SELECT {} FROM {}", columns.join(", "), table);
+                for (i, c) in conditions.iter().enumerate() {
+                    sql.push_str(if i == 0 { " WHERE " } else { " AND " });
+                    sql.push_str(c);
+                }
+                if let Some((column, desc)) = order {
+                    sql.push_str(&format!(" ORDER BY {} {}", column, if desc { "DESC" } else { "ASC" }));
+                }
+                if let Some((limit, offset)) = limit {
+                    sql.push_str(&format!(" LIMIT {} OFFSET {}", limit, offset));
+                }
+
+                if let Ok(query) = parse(&sql) {
+                    prop_assert_eq!(parse(&query.to_string()).unwrap(), query);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,370 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! ReedQL Lint Pass
+//!
+//! Soft quality checks for ReedQL statements. The parser either accepts a
+//! query or rejects it; the linter flags queries that are valid but likely
+//! to surprise the author.
+//!
+//! ## Checks
+//! - `SELECT *` (prefer explicit columns for a stable result shape)
+//! - UPDATE/DELETE without WHERE (affects every row)
+//! - LIMIT without ORDER BY (non-deterministic subset)
+//! - Column names that shadow SQL keywords
+//! - WHERE on a high-cardinality column without an index (needs `LintContext`)
+//!
+//! The linter works on tokens rather than the parsed AST so that it covers
+//! SELECT, INSERT, UPDATE and DELETE alike and still reports useful warnings
+//! for statements the parser would reject.
+
+use crate::reedql::types::{LintWarning, WarnLevel};
+
+/// Words treated as SQL keywords for the shadowing check.
+const KEYWORDS: &[&str] = &[
+    "ALL", "AND", "AS", "ASC", "AVG", "BETWEEN", "BY", "COUNT", "CREATE", "DELETE", "DESC",
+    "DISTINCT", "DROP", "FROM", "GROUP", "HAVING", "IN", "INDEX", "INSERT", "INTO", "IS", "JOIN",
+    "LIKE", "LIMIT", "MAX", "MIN", "NOT", "NULL", "OFFSET", "ON", "OR", "ORDER", "SELECT", "SET",
+    "SUM", "TABLE", "UPDATE", "VALUES", "WHERE",
+];
+
+/// Keywords that may follow a column name in a WHERE condition.
+const CONDITION_KEYWORDS: &[&str] = &["BETWEEN", "IN", "IS", "LIKE", "NOT"];
+
+/// Comparison operators that may follow a column name in a WHERE condition.
+const COMPARISON_OPERATORS: &[&str] = &["=", "!=", "<>", "<", ">", "<=", ">="];
+
+/// Schema knowledge used by checks that depend on the database.
+///
+/// Implemented by `Database`; `lint()` uses a context that knows nothing and
+/// therefore skips the index check.
+pub trait LintContext {
+    /// Returns true if `table.column` has an index.
+    fn is_indexed(&self, table: &str, column: &str) -> bool;
+
+    /// Returns true if `table.column` has enough distinct values that a
+    /// full scan to filter it is expensive.
+    fn is_high_cardinality(&self, table: &str, column: &str) -> bool;
+}
+
+/// Context without schema knowledge (disables the index checks).
+struct NoContext;
+
+impl LintContext for NoContext {
+    fn is_indexed(&self, _table: &str, _column: &str) -> bool {
+        false
+    }
+
+    fn is_high_cardinality(&self, _table: &str, _column: &str) -> bool {
+        false
+    }
+}
+
+/// Lints a ReedQL statement without schema knowledge.
+///
+/// ## Input
+/// - `sql`: ReedQL statement (SELECT, INSERT, UPDATE or DELETE)
+///
+/// ## Output
+/// - `Vec<LintWarning>`: Warnings ordered by position (empty = no findings)
+///
+/// ## Performance
+/// - O(n) where n = statement length
+/// - < 10μs for typical queries
+///
+/// ## Example Usage
+/// ```rust
+/// use reedbase_last::reedql::{lint, WarnLevel};
+///
+/// let warnings = lint("DELETE FROM text");
+/// assert_eq!(warnings.len(), 1);
+/// assert_eq!(warnings[0].level, WarnLevel::Warning);
+/// ```
+pub fn lint(sql: &str) -> Vec<LintWarning> {
+    lint_with_context(sql, &NoContext)
+}
+
+/// Lints a ReedQL statement using schema knowledge from `context`.
+///
+/// ## Input
+/// - `sql`: ReedQL statement
+/// - `context`: Index and cardinality information
+///
+/// ## Output
+/// - `Vec<LintWarning>`: Warnings ordered by position
+///
+/// ## Performance
+/// - O(n) plus one `context` lookup per WHERE column
+pub fn lint_with_context(sql: &str, context: &dyn LintContext) -> Vec<LintWarning> {
+    let tokens = tokenize(sql);
+    let mut warnings = Vec::new();
+
+    let statement = match tokens.first() {
+        Some(token) if token.kind == TokenKind::Word => token.text.to_ascii_uppercase(),
+        _ => return warnings,
+    };
+
+    let from = find_keyword(&tokens, "FROM", 0);
+    let where_start = find_keyword(&tokens, "WHERE", 0);
+
+    let table = match statement.as_str() {
+        "UPDATE" => word_at(&tokens, 1),
+        "INSERT" => find_keyword(&tokens, "INTO", 0).and_then(|i| word_at(&tokens, i + 1)),
+        _ => from.and_then(|i| word_at(&tokens, i + 1)),
+    };
+
+    match statement.as_str() {
+        "SELECT" => {
+            let list_end = from.unwrap_or(tokens.len());
+            check_select_star(&tokens[1..list_end], &mut warnings);
+            check_select_list_keywords(&tokens[1..list_end], &mut warnings);
+            check_limit_without_order(&tokens, &mut warnings);
+        }
+        "UPDATE" | "DELETE" => {
+            if where_start.is_none() {
+                warnings.push(LintWarning {
+                    level: WarnLevel::Warning,
+                    message: format!(
+                        "{} without WHERE affects every row in '{}'",
+                        statement,
+                        table.unwrap_or("?")
+                    ),
+                    position: tokens[0].position,
+                });
+            }
+            if statement == "UPDATE" {
+                if let Some(set) = find_keyword(&tokens, "SET", 0) {
+                    let set_end = where_start.unwrap_or(tokens.len());
+                    check_assignment_keywords(&tokens[set + 1..set_end], &mut warnings);
+                }
+            }
+        }
+        "INSERT" => check_insert_columns(&tokens, &mut warnings),
+        _ => {}
+    }
+
+    if let Some(where_start) = where_start {
+        let where_end = ["ORDER", "GROUP", "LIMIT"]
+            .iter()
+            .filter_map(|kw| find_keyword(&tokens, kw, where_start))
+            .min()
+            .unwrap_or(tokens.len());
+
+        for column in condition_columns(&tokens[where_start + 1..where_end]) {
+            check_keyword_shadowing(column, &mut warnings);
+
+            if let Some(table) = table {
+                if context.is_high_cardinality(table, column.text)
+                    && !context.is_indexed(table, column.text)
+                {
+                    warnings.push(LintWarning {
+                        level: WarnLevel::Info,
+                        message: format!(
+                            "filter on high-cardinality column '{}' has no index (full table scan); \
+                             consider an index on {}.{}",
+                            column.text, table, column.text
+                        ),
+                        position: column.position,
+                    });
+                }
+            }
+        }
+    }
+
+    warnings.sort_by_key(|w| w.position);
+    warnings
+}
+
+/// Flags `*` in the select list (but not inside `COUNT(*)`).
+fn check_select_star(select_list: &[Token<'_>], warnings: &mut Vec<LintWarning>) {
+    for (i, token) in select_list.iter().enumerate() {
+        let in_call = i > 0 && select_list[i - 1].text == "(";
+        if token.kind == TokenKind::Symbol && token.text == "*" && !in_call {
+            warnings.push(LintWarning {
+                level: WarnLevel::Info,
+                message:
+                    "SELECT * returns all columns; list columns explicitly for a stable result"
+                        .to_string(),
+                position: token.position,
+            });
+        }
+    }
+}
+
+/// Flags selected columns that are SQL keywords.
+fn check_select_list_keywords(select_list: &[Token<'_>], warnings: &mut Vec<LintWarning>) {
+    for (i, token) in select_list.iter().enumerate() {
+        let is_call = select_list.get(i + 1).is_some_and(|next| next.text == "(");
+        let is_modifier = token.is_keyword("DISTINCT") || token.is_keyword("AS");
+        if token.kind == TokenKind::Word && !is_call && !is_modifier {
+            check_keyword_shadowing(token, warnings);
+        }
+    }
+}
+
+/// Flags LIMIT in a query without ORDER BY.
+fn check_limit_without_order(tokens: &[Token<'_>], warnings: &mut Vec<LintWarning>) {
+    if find_keyword(tokens, "ORDER", 0).is_some() {
+        return;
+    }
+
+    if let Some(limit) = find_keyword(tokens, "LIMIT", 0) {
+        warnings.push(LintWarning {
+            level: WarnLevel::Warning,
+            message: "LIMIT without ORDER BY returns a non-deterministic subset of rows"
+                .to_string(),
+            position: tokens[limit].position,
+        });
+    }
+}
+
+/// Flags assigned columns (`col = value`) that are SQL keywords.
+fn check_assignment_keywords(assignments: &[Token<'_>], warnings: &mut Vec<LintWarning>) {
+    for pair in assignments.windows(2) {
+        if pair[0].kind == TokenKind::Word && pair[1].text == "=" {
+            check_keyword_shadowing(&pair[0], warnings);
+        }
+    }
+}
+
+/// Flags INSERT column list entries that are SQL keywords.
+fn check_insert_columns(tokens: &[Token<'_>], warnings: &mut Vec<LintWarning>) {
+    let values = find_keyword(tokens, "VALUES", 0).unwrap_or(tokens.len());
+    let columns = tokens[..values]
+        .iter()
+        .skip_while(|t| t.text != "(")
+        .take_while(|t| t.text != ")");
+
+    for token in columns.filter(|t| t.kind == TokenKind::Word) {
+        check_keyword_shadowing(token, warnings);
+    }
+}
+
+/// Adds a warning if `column` is spelled like a SQL keyword.
+fn check_keyword_shadowing(column: &Token<'_>, warnings: &mut Vec<LintWarning>) {
+    if let Some(keyword) = KEYWORDS.iter().find(|kw| column.is_keyword(kw)) {
+        warnings.push(LintWarning {
+            level: WarnLevel::Warning,
+            message: format!(
+                "column '{}' shadows SQL keyword {}; rename it to avoid ambiguous queries",
+                column.text, keyword
+            ),
+            position: column.position,
+        });
+    }
+}
+
+/// Returns column tokens of a WHERE clause (word followed by an operator).
+fn condition_columns<'a, 'b>(conditions: &'b [Token<'a>]) -> Vec<&'b Token<'a>> {
+    conditions
+        .windows(2)
+        .filter(|pair| {
+            let next = &pair[1];
+            pair[0].kind == TokenKind::Word
+                && ((next.kind == TokenKind::Symbol && COMPARISON_OPERATORS.contains(&next.text))
+                    || CONDITION_KEYWORDS.iter().any(|kw| next.is_keyword(kw)))
+        })
+        .map(|pair| &pair[0])
+        .collect()
+}
+
+/// Returns index of first keyword token at or after `start`.
+fn find_keyword(tokens: &[Token<'_>], keyword: &str, start: usize) -> Option<usize> {
+    tokens
+        .iter()
+        .skip(start)
+        .position(|t| t.is_keyword(keyword))
+        .map(|i| i + start)
+}
+
+/// Returns text of word token at `index`.
+fn word_at<'a>(tokens: &[Token<'a>], index: usize) -> Option<&'a str> {
+    tokens
+        .get(index)
+        .filter(|t| t.kind == TokenKind::Word)
+        .map(|t| t.text)
+}
+
+/// Lexical token kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    /// Keyword, identifier or number
+    Word,
+
+    /// Quoted string literal
+    Literal,
+
+    /// Operator or punctuation
+    Symbol,
+}
+
+/// Lexical token with byte position.
+#[derive(Debug, Clone)]
+struct Token<'a> {
+    kind: TokenKind,
+    text: &'a str,
+    position: usize,
+}
+
+impl Token<'_> {
+    /// Checks if token is the given keyword (case-insensitive).
+    fn is_keyword(&self, keyword: &str) -> bool {
+        self.kind == TokenKind::Word && self.text.eq_ignore_ascii_case(keyword)
+    }
+}
+
+/// Splits a statement into words, literals and symbols.
+///
+/// Never fails: unterminated literals run to the end of input.
+fn tokenize(sql: &str) -> Vec<Token<'_>> {
+    let bytes = sql.as_bytes();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+
+    while pos < bytes.len() {
+        let ch = bytes[pos];
+        let start = pos;
+
+        if ch.is_ascii_whitespace() {
+            pos += 1;
+            continue;
+        }
+
+        let kind = if ch == b'\'' || ch == b'"' {
+            pos += 1;
+            while pos < bytes.len() && bytes[pos] != ch {
+                pos += 1;
+            }
+            pos = (pos + 1).min(bytes.len());
+            TokenKind::Literal
+        } else if is_word_byte(ch) {
+            while pos < bytes.len() && is_word_byte(bytes[pos]) {
+                pos += 1;
+            }
+            TokenKind::Word
+        } else {
+            let two_char = bytes.get(pos..pos + 2);
+            pos += match two_char {
+                Some(b"!=") | Some(b"<=") | Some(b">=") | Some(b"<>") => 2,
+                _ => 1,
+            };
+            TokenKind::Symbol
+        };
+
+        tokens.push(Token {
+            kind,
+            text: &sql[start..pos],
+            position: start,
+        });
+    }
+
+    tokens
+}
+
+/// Checks if byte can be part of a word (identifiers, numbers, RBKS keys).
+///
+/// Non-ASCII bytes count as word bytes so that tokens never split a UTF-8
+/// character.
+fn is_word_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'.' | b'@' | b'%' | b'-') || byte >= 0x80
+}
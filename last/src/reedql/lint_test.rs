@@ -0,0 +1,138 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(test)]
+mod tests {
+    use crate::reedql::{lint, lint_with_context, LintContext, WarnLevel};
+
+    struct TestContext {
+        indexed: Vec<&'static str>,
+        high_cardinality: Vec<&'static str>,
+    }
+
+    impl LintContext for TestContext {
+        fn is_indexed(&self, table: &str, column: &str) -> bool {
+            self.indexed
+                .contains(&format!("{}.{}", table, column).as_str())
+        }
+
+        fn is_high_cardinality(&self, table: &str, column: &str) -> bool {
+            self.high_cardinality
+                .contains(&format!("{}.{}", table, column).as_str())
+        }
+    }
+
+    #[test]
+    fn test_lint_clean_query() {
+        let warnings =
+            lint("SELECT key, value FROM text WHERE namespace = 'page' ORDER BY key LIMIT 10");
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn test_lint_select_star() {
+        let warnings = lint("SELECT * FROM text");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].level, WarnLevel::Info);
+        assert_eq!(warnings[0].position, 7);
+        assert!(warnings[0].message.contains("SELECT *"));
+    }
+
+    #[test]
+    fn test_lint_count_star_not_flagged() {
+        assert!(lint("SELECT COUNT(*) FROM text").is_empty());
+    }
+
+    #[test]
+    fn test_lint_update_without_where() {
+        let warnings = lint("UPDATE text SET value = 'x'");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].level, WarnLevel::Warning);
+        assert_eq!(warnings[0].position, 0);
+        assert!(warnings[0].message.contains("every row in 'text'"));
+
+        assert!(lint("UPDATE text SET value = 'x' WHERE key = 'a'").is_empty());
+    }
+
+    #[test]
+    fn test_lint_delete_without_where() {
+        let warnings = lint("delete from text");
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.starts_with("DELETE without WHERE"));
+
+        assert!(lint("DELETE FROM text WHERE key = 'a'").is_empty());
+    }
+
+    #[test]
+    fn test_lint_limit_without_order_by() {
+        let sql = "SELECT key FROM text LIMIT 5";
+        let warnings = lint(sql);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].level, WarnLevel::Warning);
+        assert_eq!(warnings[0].position, sql.find("LIMIT").unwrap());
+    }
+
+    #[test]
+    fn test_lint_keyword_column_names() {
+        let warnings = lint("SELECT key, order FROM text WHERE desc = 'x' ORDER BY key");
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].message.contains("'order'"));
+        assert!(warnings[1].message.contains("'desc'"));
+
+        let warnings = lint("UPDATE text SET limit = '5' WHERE key = 'a'");
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("LIMIT"));
+
+        let warnings = lint("INSERT INTO text (key, from) VALUES ('a', 'b')");
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("FROM"));
+    }
+
+    #[test]
+    fn test_lint_literals_are_ignored() {
+        assert!(lint("SELECT key FROM text WHERE value = 'SELECT * LIMIT 1'").is_empty());
+    }
+
+    #[test]
+    fn test_lint_unindexed_high_cardinality_filter() {
+        let context = TestContext {
+            indexed: vec!["text.key"],
+            high_cardinality: vec!["text.key", "text.value"],
+        };
+
+        let sql = "SELECT key FROM text WHERE value = 'x' AND key = 'a'";
+        let warnings = lint_with_context(sql, &context);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].level, WarnLevel::Info);
+        assert_eq!(warnings[0].position, sql.find("value").unwrap());
+
+        // Without context the check is skipped
+        assert!(lint(sql).is_empty());
+    }
+
+    #[test]
+    fn test_lint_warnings_sorted_by_position() {
+        let warnings = lint("SELECT * FROM text LIMIT 1");
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].position < warnings[1].position);
+    }
+
+    #[test]
+    fn test_lint_empty_input() {
+        assert!(lint("").is_empty());
+        assert!(lint("   ").is_empty());
+    }
+
+    #[test]
+    fn test_lint_join_on_indexed_column_needs_no_hint() {
+        let context = TestContext {
+            indexed: vec!["routes.text_key"],
+            high_cardinality: vec![],
+        };
+
+        // Equality joins already run as hash joins
+        let sql = "SELECT t.key, r.route FROM text t JOIN routes r ON t.key = r.text_key";
+        assert!(lint_with_context(sql, &context).is_empty());
+        assert!(lint(sql).is_empty());
+    }
+}
@@ -10,22 +10,31 @@
 //! - **Fast Parsing**: < 10μs parse time (10x faster than generic SQL parsers)
 //! - **ReedBase Optimized**: Key pattern fast paths for 10x query speedup
 //! - **Subquery Support**: Recursive IN subquery execution
+//! - **Joins**: INNER, LEFT and NATURAL JOIN (hash join on equality ON)
 //! - **Aggregations**: COUNT, SUM, AVG, MIN, MAX
 //! - **CLI-Only**: No API exposure (security-by-design)
 //!
 //! ## Supported SQL Syntax
 //! ```text
-//! SELECT column1, column2, ... FROM table
+//! SELECT column1, column2 AS alias, ... FROM table
 //! WHERE condition1 AND condition2 ...
-//! ORDER BY column ASC|DESC
+//! WHERE column IS EMPTY | column IS NOT EMPTY
+//! WHERE column IS NULL | column IS NOT NULL
+//! ORDER BY column [NUMERIC] ASC|DESC
 //! LIMIT n OFFSET m
 //!
 //! -- Aggregations
 //! SELECT COUNT(*) FROM text
+//! SELECT COUNT(*) AS total FROM text
 //! SELECT AVG(column) FROM text WHERE condition
 //!
 //! -- Subqueries
 //! SELECT * FROM text WHERE key IN (SELECT key FROM routes)
+//!
+//! -- Joins
+//! SELECT t.key, r.route FROM text t JOIN routes r ON t.key = r.text_key
+//! SELECT * FROM text t LEFT JOIN routes r ON t.key = r.text_key
+//! SELECT * FROM text NATURAL JOIN meta
 //! ```
 //!
 //! ## Fast Paths
@@ -60,6 +69,7 @@
 //! - `types`: Core AST types (ParsedQuery, FilterCondition, etc.)
 //! - `parser`: Custom hand-written parser (< 10μs)
 //! - `executor`: Query execution engine with ReedBase optimizations
+//! - `format`: Canonical query pretty-printer
 //! - `validator`: Query validation and security checks
 //! - `formatter`: Output formatting (table, JSON, CSV)
 
@@ -67,17 +77,31 @@ pub mod analyzer;
 pub mod analyzer_test;
 pub mod executor;
 pub mod executor_test;
+pub mod format;
+pub mod lint;
+pub mod lint_test;
 pub mod parser;
+pub mod parser_test;
 pub mod planner;
 pub mod planner_test;
 pub mod types;
 
 // Re-export commonly used types
 pub use analyzer::{QueryAnalyzer, QueryPattern};
-pub use executor::{execute, OptimizedExecutor};
-pub use parser::parse;
-pub use planner::{ExecutionPlan, QueryPlanner};
+pub use executor::{execute, execute_join, OptimizedExecutor};
+pub use format::{format_condition, format_query};
+pub use lint::{lint, lint_with_context, LintContext};
+pub use parser::{
+    parse, parse_alter_table, parse_analyze, parse_condition, parse_copy, parse_create_index,
+    parse_create_table, parse_drop_index, parse_drop_table, parse_explain, prepare,
+};
+pub use planner::{
+    conditions_imply, ColumnStatistics, ExecutionPlan, IndexStatistics, QueryPlanner, Statistics,
+};
 pub use types::{
-    AggregationFunction, AggregationType, FilterCondition, LimitOffset, OrderBy, ParsedQuery,
-    QueryResult, SortDirection,
+    AggregationFunction, AggregationType, AlterTableAction, AlterTableStatement, Collation,
+    CopyDirection, CopyOptions, CopyStatement, CreateIndexStatement, CreateTableStatement,
+    DropIndexStatement, DropTableStatement, ExplainStatement, FilterCondition, JoinClause,
+    JoinType, LimitOffset, LimitValue, LintWarning, OrderBy, ParsedQuery, PreparedQuery,
+    QueryResult, SortDirection, WarnLevel,
 };
@@ -1,730 +0,0 @@
-// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
-// SPDX-License-Identifier: Apache-2.0
-
-//! ReedQL Custom Parser
-//!
-//! Hand-written parser optimized for < 10μs parse time.
-//! NO external SQL parsing libraries (sqlparser-rs adds 50KB+ binary size).
-//!
-//! ## Performance Strategy
-//! - Zero-copy parsing where possible
-//! - Minimal allocations (< 10 per query)
-//! - Direct string slicing (no regex)
-//! - Single-pass parsing (no backtracking)
-//! - Stack-allocated parser state
-//!
-//! ## Supported Grammar
-//! ```text
-//! query       := SELECT columns FROM table [WHERE conditions] [ORDER BY order] [LIMIT limit]
-//! columns     := * | column_list
-//! column_list := column (, column)*
-//! column      := IDENTIFIER | aggregation
-//! aggregation := (COUNT|SUM|AVG|MIN|MAX) ( column )
-//! conditions  := condition (AND condition)*
-//! condition   := column operator value
-//!              | column LIKE pattern
-//!              | column IN ( value_list )
-//!              | column IN ( query )
-//! operator    := = | != | < | > | <= | >=
-//! order       := column [ASC|DESC] (, column [ASC|DESC])*
-//! limit       := NUMBER [OFFSET NUMBER]
-//! ```
-
-use crate::error::{ReedError, ReedResult};
-use crate::reedql::types::{
-    AggregationFunction, AggregationType, FilterCondition, LimitOffset, OrderBy, ParsedQuery,
-    SortDirection,
-};
-
-/// Parses a ReedQL query string into a ParsedQuery AST.
-///
-/// ## Input
-/// - `query`: SQL-like query string
-///
-/// ## Output
-/// - `Ok(ParsedQuery)`: Successfully parsed query
-/// - `Err(ReedError)`: Parse error with detailed message
-///
-/// ## Performance
-/// - Target: < 10μs for typical queries
-/// - Actual: ~5-8μs (measured on M1 Mac)
-///
-/// ## Example
-/// ```rust,ignore
-/// let query = parse("SELECT * FROM text WHERE key LIKE '%.@de' LIMIT 10")?;
-/// ```
-pub fn parse(query: &str) -> ReedResult<ParsedQuery> {
-    let mut parser = Parser::new(query);
-    parser.parse()
-}
-
-/// Parser state machine.
-///
-/// Stack-allocated parser with zero-copy tokenization.
-struct Parser<'a> {
-    /// Original query string
-    query: &'a str,
-
-    /// Current position in query
-    pos: usize,
-
-    /// Parsed query (built incrementally)
-    parsed: ParsedQuery,
-}
-
-impl<'a> Parser<'a> {
-    /// Creates a new parser.
-    fn new(query: &'a str) -> Self {
-        Self {
-            query: query.trim(),
-            pos: 0,
-            parsed: ParsedQuery::new(),
-        }
-    }
-
-    /// Main parse entry point.
-    fn parse(&mut self) -> ReedResult<ParsedQuery> {
-        // Expect SELECT
-        self.expect_keyword("SELECT")?;
-
-        // Parse columns (or aggregation)
-        self.parse_columns()?;
-
-        // Expect FROM
-        self.expect_keyword("FROM")?;
-
-        // Parse table name
-        self.parsed.table = self.parse_identifier()?;
-
-        // Optional WHERE clause
-        if self.peek_keyword("WHERE") {
-            self.expect_keyword("WHERE")?;
-            self.parse_conditions()?;
-        }
-
-        // Optional ORDER BY clause
-        if self.peek_keyword("ORDER") {
-            self.expect_keyword("ORDER")?;
-            self.expect_keyword("BY")?;
-            self.parse_order_by()?;
-        }
-
-        // Optional LIMIT clause
-        if self.peek_keyword("LIMIT") {
-            self.expect_keyword("LIMIT")?;
-            self.parse_limit()?;
-        }
-
-        // Ensure we've consumed entire query
-        self.skip_whitespace();
-        if self.pos < self.query.len() {
-            return Err(ReedError::ParseError {
-                reason: format!(
-                    "Unexpected input at position {}: '{}'",
-                    self.pos,
-                    &self.query[self.pos..]
-                ),
-            });
-        }
-
-        Ok(self.parsed.clone())
-    }
-
-    /// Parses SELECT columns or aggregation.
-    fn parse_columns(&mut self) -> ReedResult<()> {
-        self.skip_whitespace();
-
-        // Check for aggregation function
-        if let Some(agg_type) = self.peek_aggregation() {
-            self.parsed.aggregation = Some(self.parse_aggregation(agg_type)?);
-            return Ok(());
-        }
-
-        // Check for SELECT *
-        if self.peek_char() == Some('*') {
-            self.advance();
-            self.parsed.columns.push("*".to_string());
-            return Ok(());
-        }
-
-        // Parse column list
-        loop {
-            let column = self.parse_identifier()?;
-            self.parsed.columns.push(column);
-
-            self.skip_whitespace();
-            if self.peek_char() == Some(',') {
-                self.advance();
-                continue;
-            }
-            break;
-        }
-
-        Ok(())
-    }
-
-    /// Parses aggregation function: COUNT(*), SUM(column), etc.
-    fn parse_aggregation(&mut self, agg_type: AggregationType) -> ReedResult<AggregationFunction> {
-        // Consume function name
-        self.advance_by(match agg_type {
-            AggregationType::Count => 5,
-            AggregationType::Sum => 3,
-            AggregationType::Avg => 3,
-            AggregationType::Min => 3,
-            AggregationType::Max => 3,
-        });
-
-        self.skip_whitespace();
-
-        // Expect (
-        if self.peek_char() != Some('(') {
-            return Err(ReedError::ParseError {
-                reason: "Expected '(' after aggregation function".to_string(),
-            });
-        }
-        self.advance();
-
-        self.skip_whitespace();
-
-        // Parse column or *
-        let column = if self.peek_char() == Some('*') {
-            self.advance();
-            "*".to_string()
-        } else {
-            self.parse_identifier()?
-        };
-
-        self.skip_whitespace();
-
-        // Expect )
-        if self.peek_char() != Some(')') {
-            return Err(ReedError::ParseError {
-                reason: "Expected ')' after aggregation column".to_string(),
-            });
-        }
-        self.advance();
-
-        // Store column in parsed.columns for compatibility
-        self.parsed.columns.push(column.clone());
-
-        Ok(AggregationFunction::new(agg_type, column))
-    }
-
-    /// Parses WHERE conditions (AND-separated).
-    fn parse_conditions(&mut self) -> ReedResult<()> {
-        loop {
-            let condition = self.parse_condition()?;
-            self.parsed.conditions.push(condition);
-
-            self.skip_whitespace();
-            if self.peek_keyword("AND") {
-                self.expect_keyword("AND")?;
-                continue;
-            }
-            break;
-        }
-
-        Ok(())
-    }
-
-    /// Parses a single condition.
-    fn parse_condition(&mut self) -> ReedResult<FilterCondition> {
-        let column = self.parse_identifier()?;
-
-        self.skip_whitespace();
-
-        // Check for LIKE
-        if self.peek_keyword("LIKE") {
-            self.expect_keyword("LIKE")?;
-            let pattern = self.parse_string_literal()?;
-            return Ok(FilterCondition::Like { column, pattern });
-        }
-
-        // Check for IN
-        if self.peek_keyword("IN") {
-            self.expect_keyword("IN")?;
-            return self.parse_in_clause(column);
-        }
-
-        // Parse operator
-        let operator = self.parse_operator()?;
-
-        // Parse value
-        let value = self.parse_string_literal()?;
-
-        // Build condition based on operator
-        let condition = match operator.as_str() {
-            "=" => FilterCondition::Equals { column, value },
-            "!=" => FilterCondition::NotEquals { column, value },
-            "<" => FilterCondition::LessThan { column, value },
-            ">" => FilterCondition::GreaterThan { column, value },
-            "<=" => FilterCondition::LessThanOrEqual { column, value },
-            ">=" => FilterCondition::GreaterThanOrEqual { column, value },
-            _ => {
-                return Err(ReedError::ParseError {
-                    reason: format!("Unknown operator: {}", operator),
-                })
-            }
-        };
-
-        Ok(condition)
-    }
-
-    /// Parses IN clause: IN (values) or IN (subquery).
-    fn parse_in_clause(&mut self, column: String) -> ReedResult<FilterCondition> {
-        self.skip_whitespace();
-
-        // Expect (
-        if self.peek_char() != Some('(') {
-            return Err(ReedError::ParseError {
-                reason: "Expected '(' after IN".to_string(),
-            });
-        }
-        self.advance();
-
-        self.skip_whitespace();
-
-        // Check for subquery (starts with SELECT)
-        if self.peek_keyword("SELECT") {
-            let subquery = self.parse_subquery()?;
-
-            self.skip_whitespace();
-
-            // Expect )
-            if self.peek_char() != Some(')') {
-                return Err(ReedError::ParseError {
-                    reason: "Expected ')' after subquery".to_string(),
-                });
-            }
-            self.advance();
-
-            return Ok(FilterCondition::InSubquery {
-                column,
-                subquery: Box::new(subquery),
-            });
-        }
-
-        // Parse value list
-        let mut values = Vec::new();
-        loop {
-            let value = self.parse_string_literal()?;
-            values.push(value);
-
-            self.skip_whitespace();
-            if self.peek_char() == Some(',') {
-                self.advance();
-                self.skip_whitespace();
-                continue;
-            }
-            break;
-        }
-
-        // Expect )
-        if self.peek_char() != Some(')') {
-            return Err(ReedError::ParseError {
-                reason: "Expected ')' after IN values".to_string(),
-            });
-        }
-        self.advance();
-
-        Ok(FilterCondition::InList { column, values })
-    }
-
-    /// Parses a subquery (recursive).
-    fn parse_subquery(&mut self) -> ReedResult<ParsedQuery> {
-        // Create nested parser from current position to closing )
-        let start = self.pos;
-
-        // Find matching closing parenthesis
-        let mut depth = 1;
-        let mut end = start;
-        while depth > 0 && end < self.query.len() {
-            end += 1;
-            if end >= self.query.len() {
-                return Err(ReedError::ParseError {
-                    reason: "Unclosed subquery".to_string(),
-                });
-            }
-            match self.query.as_bytes()[end] {
-                b'(' => depth += 1,
-                b')' => depth -= 1,
-                _ => {}
-            }
-        }
-
-        let subquery_str = &self.query[start..end];
-        self.pos = end; // Position before closing )
-
-        // Parse subquery recursively
-        parse(subquery_str)
-    }
-
-    /// Parses ORDER BY clauses.
-    fn parse_order_by(&mut self) -> ReedResult<()> {
-        loop {
-            let column = self.parse_identifier()?;
-
-            self.skip_whitespace();
-
-            // Check for ASC/DESC
-            let direction = if self.peek_keyword("DESC") {
-                self.expect_keyword("DESC")?;
-                SortDirection::Descending
-            } else {
-                // ASC is optional (default)
-                if self.peek_keyword("ASC") {
-                    self.expect_keyword("ASC")?;
-                }
-                SortDirection::Ascending
-            };
-
-            self.parsed.order_by.push(OrderBy::new(column, direction));
-
-            self.skip_whitespace();
-            if self.peek_char() == Some(',') {
-                self.advance();
-                continue;
-            }
-            break;
-        }
-
-        Ok(())
-    }
-
-    /// Parses LIMIT clause.
-    fn parse_limit(&mut self) -> ReedResult<()> {
-        let limit = self.parse_number()?;
-
-        self.skip_whitespace();
-
-        // Check for OFFSET
-        let offset = if self.peek_keyword("OFFSET") {
-            self.expect_keyword("OFFSET")?;
-            self.parse_number()?
-        } else {
-            0
-        };
-
-        self.parsed.limit = Some(LimitOffset::with_offset(limit, offset));
-
-        Ok(())
-    }
-
-    /// Parses an identifier (column name, table name, etc.).
-    fn parse_identifier(&mut self) -> ReedResult<String> {
-        self.skip_whitespace();
-
-        let start = self.pos;
-        while self.pos < self.query.len() {
-            let ch = self.query.as_bytes()[self.pos];
-            if ch.is_ascii_alphanumeric() || ch == b'_' || ch == b'.' {
-                self.pos += 1;
-            } else {
-                break;
-            }
-        }
-
-        if start == self.pos {
-            return Err(ReedError::ParseError {
-                reason: format!("Expected identifier at position {}", self.pos),
-            });
-        }
-
-        Ok(self.query[start..self.pos].to_string())
-    }
-
-    /// Parses a string literal ('value' or "value").
-    fn parse_string_literal(&mut self) -> ReedResult<String> {
-        self.skip_whitespace();
-
-        let quote = self.peek_char();
-        if quote != Some('\'') && quote != Some('"') {
-            return Err(ReedError::ParseError {
-                reason: format!("Expected string literal at position {}", self.pos),
-            });
-        }
-
-        let quote_char = quote.unwrap();
-        self.advance();
-
-        let start = self.pos;
-        while self.pos < self.query.len() {
-            if self.query.as_bytes()[self.pos] == quote_char as u8 {
-                let value = self.query[start..self.pos].to_string();
-                self.advance();
-                return Ok(value);
-            }
-            self.pos += 1;
-        }
-
-        Err(ReedError::ParseError {
-            reason: "Unterminated string literal".to_string(),
-        })
-    }
-
-    /// Parses an operator (=, !=, <, >, <=, >=).
-    fn parse_operator(&mut self) -> ReedResult<String> {
-        self.skip_whitespace();
-
-        let start = self.pos;
-
-        // Try two-character operators first
-        if self.pos + 1 < self.query.len() {
-            let two_char = &self.query[self.pos..self.pos + 2];
-            if two_char == "!=" || two_char == "<=" || two_char == ">=" {
-                self.pos += 2;
-                return Ok(two_char.to_string());
-            }
-        }
-
-        // Try single-character operators
-        if self.pos < self.query.len() {
-            let ch = self.query.as_bytes()[self.pos];
-            if ch == b'=' || ch == b'<' || ch == b'>' {
-                self.pos += 1;
-                return Ok(self.query[start..self.pos].to_string());
-            }
-        }
-
-        Err(ReedError::ParseError {
-            reason: format!("Expected operator at position {}", self.pos),
-        })
-    }
-
-    /// Parses a number.
-    fn parse_number(&mut self) -> ReedResult<usize> {
-        self.skip_whitespace();
-
-        let start = self.pos;
-        while self.pos < self.query.len() && self.query.as_bytes()[self.pos].is_ascii_digit() {
-            self.pos += 1;
-        }
-
-        if start == self.pos {
-            return Err(ReedError::ParseError {
-                reason: format!("Expected number at position {}", self.pos),
-            });
-        }
-
-        self.query[start..self.pos]
-            .parse()
-            .map_err(|_| ReedError::ParseError {
-                reason: "Invalid number".to_string(),
-            })
-    }
-
-    /// Expects a specific keyword (case-insensitive).
-    fn expect_keyword(&mut self, keyword: &str) -> ReedResult<()> {
-        self.skip_whitespace();
-
-        let end = self.pos + keyword.len();
-        if end > self.query.len() {
-            return Err(ReedError::ParseError {
-                reason: format!("Expected keyword '{}'", keyword),
-            });
-        }
-
-        let actual = &self.query[self.pos..end];
-        if !actual.eq_ignore_ascii_case(keyword) {
-            return Err(ReedError::ParseError {
-                reason: format!("Expected '{}', found '{}'", keyword, actual),
-            });
-        }
-
-        self.pos = end;
-        Ok(())
-    }
-
-    /// Peeks ahead to check for keyword (case-insensitive).
-    fn peek_keyword(&self, keyword: &str) -> bool {
-        let end = self.pos + keyword.len();
-        if end > self.query.len() {
-            return false;
-        }
-
-        // Skip whitespace first
-        let mut pos = self.pos;
-        while pos < self.query.len() && self.query.as_bytes()[pos].is_ascii_whitespace() {
-            pos += 1;
-        }
-
-        let end = pos + keyword.len();
-        if end > self.query.len() {
-            return false;
-        }
-
-        self.query[pos..end].eq_ignore_ascii_case(keyword)
-    }
-
-    /// Peeks ahead to check for aggregation function.
-    fn peek_aggregation(&self) -> Option<AggregationType> {
-        if self.peek_keyword("COUNT") {
-            Some(AggregationType::Count)
-        } else if self.peek_keyword("SUM") {
-            Some(AggregationType::Sum)
-        } else if self.peek_keyword("AVG") {
-            Some(AggregationType::Avg)
-        } else if self.peek_keyword("MIN") {
-            Some(AggregationType::Min)
-        } else if self.peek_keyword("MAX") {
-            Some(AggregationType::Max)
-        } else {
-            None
-        }
-    }
-
-    /// Peeks at current character without consuming.
-    fn peek_char(&self) -> Option<char> {
-        if self.pos < self.query.len() {
-            Some(self.query.as_bytes()[self.pos] as char)
-        } else {
-            None
-        }
-    }
-
-    /// Advances position by 1.
-    fn advance(&mut self) {
-        self.pos += 1;
-    }
-
-    /// Advances position by n.
-    fn advance_by(&mut self, n: usize) {
-        self.pos += n;
-    }
-
-    /// Skips whitespace.
-    fn skip_whitespace(&mut self) {
-        while self.pos < self.query.len() && self.query.as_bytes()[self.pos].is_ascii_whitespace() {
-            self.pos += 1;
-        }
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_parse_select_all() {
-        let query = parse("SELECT * FROM text").unwrap();
-        assert!(query.is_select_all());
-        assert_eq!(query.table, "text");
-        assert_eq!(query.conditions.len(), 0);
-    }
-
-    #[test]
-    fn test_parse_select_columns() {
-        let query = parse("SELECT key, value FROM text").unwrap();
-        assert_eq!(query.columns, vec!["key", "value"]);
-        assert_eq!(query.table, "text");
-    }
-
-    #[test]
-    fn test_parse_where_equals() {
-        let query = parse("SELECT * FROM text WHERE namespace = 'page'").unwrap();
-        assert_eq!(query.conditions.len(), 1);
-        match &query.conditions[0] {
-            FilterCondition::Equals { column, value } => {
-                assert_eq!(column, "namespace");
-                assert_eq!(value, "page");
-            }
-            _ => panic!("Expected Equals condition"),
-        }
-    }
-
-    #[test]
-    fn test_parse_where_like() {
-        let query = parse("SELECT * FROM text WHERE key LIKE '%.@de'").unwrap();
-        assert_eq!(query.conditions.len(), 1);
-        match &query.conditions[0] {
-            FilterCondition::Like { column, pattern } => {
-                assert_eq!(column, "key");
-                assert_eq!(pattern, "%.@de");
-            }
-            _ => panic!("Expected Like condition"),
-        }
-    }
-
-    #[test]
-    fn test_parse_where_in_list() {
-        let query = parse("SELECT * FROM text WHERE namespace IN ('page', 'global')").unwrap();
-        assert_eq!(query.conditions.len(), 1);
-        match &query.conditions[0] {
-            FilterCondition::InList { column, values } => {
-                assert_eq!(column, "namespace");
-                assert_eq!(values, &vec!["page", "global"]);
-            }
-            _ => panic!("Expected InList condition"),
-        }
-    }
-
-    #[test]
-    fn test_parse_order_by_asc() {
-        let query = parse("SELECT * FROM text ORDER BY key ASC").unwrap();
-        assert_eq!(query.order_by.len(), 1);
-        assert_eq!(query.order_by[0].column, "key");
-        assert_eq!(query.order_by[0].direction, SortDirection::Ascending);
-    }
-
-    #[test]
-    fn test_parse_order_by_desc() {
-        let query = parse("SELECT * FROM text ORDER BY key DESC").unwrap();
-        assert_eq!(query.order_by.len(), 1);
-        assert_eq!(query.order_by[0].column, "key");
-        assert_eq!(query.order_by[0].direction, SortDirection::Descending);
-    }
-
-    #[test]
-    fn test_parse_limit() {
-        let query = parse("SELECT * FROM text LIMIT 10").unwrap();
-        assert_eq!(query.limit, Some(LimitOffset::new(10)));
-    }
-
-    #[test]
-    fn test_parse_limit_offset() {
-        let query = parse("SELECT * FROM text LIMIT 10 OFFSET 5").unwrap();
-        assert_eq!(query.limit, Some(LimitOffset::with_offset(10, 5)));
-    }
-
-    #[test]
-    fn test_parse_count_all() {
-        let query = parse("SELECT COUNT(*) FROM text").unwrap();
-        assert!(query.has_aggregation());
-        let agg = query.aggregation.unwrap();
-        assert_eq!(agg.agg_type, AggregationType::Count);
-        assert_eq!(agg.column, "*");
-    }
-
-    #[test]
-    fn test_parse_complex_query() {
-        let query = parse(
-            "SELECT key, value FROM text WHERE namespace = 'page' AND key LIKE '%.@de' ORDER BY key ASC LIMIT 10 OFFSET 5"
-        ).unwrap();
-
-        assert_eq!(query.columns, vec!["key", "value"]);
-        assert_eq!(query.table, "text");
-        assert_eq!(query.conditions.len(), 2);
-        assert_eq!(query.order_by.len(), 1);
-        assert_eq!(query.limit, Some(LimitOffset::with_offset(10, 5)));
-    }
-
-    #[test]
-    fn test_parse_case_insensitive() {
-        let query = parse("select * from text where namespace = 'page'").unwrap();
-        assert!(query.is_select_all());
-        assert_eq!(query.table, "text");
-        assert_eq!(query.conditions.len(), 1);
-    }
-
-    #[test]
-    fn test_parse_error_invalid_keyword() {
-        let result = parse("DELETE * FROM text");
-        assert!(result.is_err());
-    }
-
-    #[test]
-    fn test_parse_error_missing_from() {
-        let result = parse("SELECT * WHERE namespace = 'page'");
-        assert!(result.is_err());
-    }
-}
@@ -0,0 +1,292 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! WHERE and HAVING conditions (AND/OR/NOT, comparisons, LIKE, IN, BETWEEN, IS).
+
+use crate::error::{ReedError, ReedResult};
+use crate::reedql::types::{FilterCondition, ParsedQuery};
+
+use super::{parse, Parser};
+
+/// Builds a comparison condition from an operator.
+pub(super) fn comparison(
+    operator: &str,
+    column: String,
+    value: String,
+) -> ReedResult<FilterCondition> {
+    match operator {
+        "=" => Ok(FilterCondition::Equals { column, value }),
+        "!=" => Ok(FilterCondition::NotEquals { column, value }),
+        "<" => Ok(FilterCondition::LessThan { column, value }),
+        ">" => Ok(FilterCondition::GreaterThan { column, value }),
+        "<=" => Ok(FilterCondition::LessThanOrEqual { column, value }),
+        ">=" => Ok(FilterCondition::GreaterThanOrEqual { column, value }),
+        _ => Err(ReedError::ParseError {
+            reason: format!("Unknown operator: {}", operator),
+        }),
+    }
+}
+
+/// Joins operands with AND or OR.
+///
+/// Nested groups of the same kind are flattened (`(a OR b) OR c` becomes
+/// one `Or` of three operands) and a single operand is returned as is.
+fn combine(operands: Vec<FilterCondition>, is_or: bool) -> FilterCondition {
+    let mut flat = Vec::with_capacity(operands.len());
+    for operand in operands {
+        match operand {
+            FilterCondition::Or(inner) if is_or => flat.extend(inner),
+            FilterCondition::And(inner) if !is_or => flat.extend(inner),
+            other => flat.push(other),
+        }
+    }
+
+    if flat.len() == 1 {
+        flat.remove(0)
+    } else if is_or {
+        FilterCondition::Or(flat)
+    } else {
+        FilterCondition::And(flat)
+    }
+}
+
+impl<'a> Parser<'a> {
+    /// Parses WHERE conditions.
+    ///
+    /// Operands of a top-level AND become separate entries of
+    /// `parsed.conditions`; anything else is stored as a single condition.
+    pub(super) fn parse_conditions(&mut self) -> ReedResult<()> {
+        match self.parse_or()? {
+            FilterCondition::And(operands) => self.parsed.conditions.extend(operands),
+            condition => self.parsed.conditions.push(condition),
+        }
+
+        Ok(())
+    }
+
+    /// Parses OR-separated AND groups (OR binds loosest).
+    pub(super) fn parse_or(&mut self) -> ReedResult<FilterCondition> {
+        let mut operands = vec![self.parse_and()?];
+
+        while self.peek_word_is("OR") {
+            self.expect_keyword("OR")?;
+            operands.push(self.parse_and()?);
+        }
+
+        Ok(combine(operands, true))
+    }
+
+    /// Parses AND-separated primaries.
+    fn parse_and(&mut self) -> ReedResult<FilterCondition> {
+        let mut operands = vec![self.parse_primary()?];
+
+        while self.peek_word_is("AND") {
+            self.expect_keyword("AND")?;
+            operands.push(self.parse_primary()?);
+        }
+
+        Ok(combine(operands, false))
+    }
+
+    /// Parses a negation, a parenthesised group or a single condition.
+    fn parse_primary(&mut self) -> ReedResult<FilterCondition> {
+        if self.peek_word_is("NOT") {
+            self.expect_keyword("NOT")?;
+            return Ok(FilterCondition::Not(Box::new(self.parse_primary()?)));
+        }
+
+        self.skip_whitespace();
+        if self.peek_char() != Some('(') {
+            return self.parse_condition();
+        }
+        self.advance();
+
+        let condition = self.parse_or()?;
+
+        self.skip_whitespace();
+        if self.peek_char() != Some(')') {
+            return Err(ReedError::ParseError {
+                reason: format!(
+                    "Expected ')' after condition group at position {}",
+                    self.pos
+                ),
+            });
+        }
+        self.advance();
+
+        Ok(condition)
+    }
+
+    /// Parses a single condition.
+    pub(super) fn parse_condition(&mut self) -> ReedResult<FilterCondition> {
+        let column = self.parse_identifier()?;
+
+        self.skip_whitespace();
+
+        // Check for NOT LIKE / NOT IN / NOT BETWEEN (same as NOT before the condition)
+        if self.peek_word_is("NOT") {
+            self.expect_keyword("NOT")?;
+            if !["LIKE", "IN", "BETWEEN"]
+                .iter()
+                .any(|word| self.peek_word_is(word))
+            {
+                return Err(ReedError::ParseError {
+                    reason: format!(
+                        "Expected LIKE, IN or BETWEEN after NOT at position {}",
+                        self.pos
+                    ),
+                });
+            }
+            let condition = self.parse_predicate(column)?;
+            return Ok(FilterCondition::Not(Box::new(condition)));
+        }
+
+        self.parse_predicate(column)
+    }
+
+    /// Parses the part of a condition after its column.
+    fn parse_predicate(&mut self, column: String) -> ReedResult<FilterCondition> {
+        self.skip_whitespace();
+
+        // Check for LIKE
+        if self.peek_keyword("LIKE") {
+            self.expect_keyword("LIKE")?;
+            let pattern = self.parse_string_literal()?;
+            return Ok(FilterCondition::Like { column, pattern });
+        }
+
+        // Check for IS [NOT] EMPTY / IS [NOT] NULL
+        if self.peek_keyword("IS") {
+            self.expect_keyword("IS")?;
+            let negated = self.peek_keyword("NOT");
+            if negated {
+                self.expect_keyword("NOT")?;
+            }
+            let null = self.peek_word_is("NULL");
+            if null {
+                self.expect_keyword("NULL")?;
+            } else {
+                self.expect_keyword("EMPTY")?;
+            }
+            return Ok(match (null, negated) {
+                (true, false) => FilterCondition::IsNull { column },
+                (true, true) => FilterCondition::IsNotNull { column },
+                (false, false) => FilterCondition::IsEmpty { column },
+                (false, true) => FilterCondition::IsNotEmpty { column },
+            });
+        }
+
+        // Check for IN
+        if self.peek_keyword("IN") {
+            self.expect_keyword("IN")?;
+            return self.parse_in_clause(column);
+        }
+
+        // Check for BETWEEN (its AND is consumed here, not by parse_and)
+        if self.peek_word_is("BETWEEN") {
+            self.expect_keyword("BETWEEN")?;
+            let low = self.parse_string_literal()?;
+            self.expect_keyword("AND")?;
+            let high = self.parse_string_literal()?;
+            return Ok(FilterCondition::Between { column, low, high });
+        }
+
+        // Parse operator
+        let operator = self.parse_operator()?;
+
+        // Parse value
+        let value = self.parse_string_literal()?;
+
+        // Build condition based on operator
+        comparison(&operator, column, value)
+    }
+
+    /// Parses IN clause: IN (values) or IN (subquery).
+    fn parse_in_clause(&mut self, column: String) -> ReedResult<FilterCondition> {
+        self.skip_whitespace();
+
+        // Expect (
+        if self.peek_char() != Some('(') {
+            return Err(ReedError::ParseError {
+                reason: "Expected '(' after IN".to_string(),
+            });
+        }
+        self.advance();
+
+        self.skip_whitespace();
+
+        // Check for subquery (starts with SELECT)
+        if self.peek_keyword("SELECT") {
+            let subquery = self.parse_subquery()?;
+
+            self.skip_whitespace();
+
+            // Expect )
+            if self.peek_char() != Some(')') {
+                return Err(ReedError::ParseError {
+                    reason: "Expected ')' after subquery".to_string(),
+                });
+            }
+            self.advance();
+
+            return Ok(FilterCondition::InSubquery {
+                column,
+                subquery: Box::new(subquery),
+            });
+        }
+
+        // Parse value list
+        let mut values = Vec::new();
+        loop {
+            let value = self.parse_string_literal()?;
+            values.push(value);
+
+            self.skip_whitespace();
+            if self.peek_char() == Some(',') {
+                self.advance();
+                self.skip_whitespace();
+                continue;
+            }
+            break;
+        }
+
+        // Expect )
+        if self.peek_char() != Some(')') {
+            return Err(ReedError::ParseError {
+                reason: "Expected ')' after IN values".to_string(),
+            });
+        }
+        self.advance();
+
+        Ok(FilterCondition::InList { column, values })
+    }
+
+    /// Parses a subquery (recursive).
+    fn parse_subquery(&mut self) -> ReedResult<ParsedQuery> {
+        // Create nested parser from current position to closing )
+        let start = self.pos;
+
+        // Find matching closing parenthesis
+        let mut depth = 1;
+        let mut end = start;
+        while depth > 0 && end < self.query.len() {
+            end += 1;
+            if end >= self.query.len() {
+                return Err(ReedError::ParseError {
+                    reason: "Unclosed subquery".to_string(),
+                });
+            }
+            match self.query.as_bytes()[end] {
+                b'(' => depth += 1,
+                b')' => depth -= 1,
+                _ => {}
+            }
+        }
+
+        let subquery_str = &self.query[start..end];
+        self.pos = end; // Position before closing )
+
+        // Parse subquery recursively
+        parse(subquery_str)
+    }
+}
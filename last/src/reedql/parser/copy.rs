@@ -0,0 +1,150 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! COPY FROM / COPY TO statements.
+
+use crate::error::{ReedError, ReedResult};
+use crate::reedql::types::{CopyDirection, CopyOptions, CopyStatement};
+
+use super::Parser;
+
+/// Parses a PostgreSQL-style `COPY` statement.
+///
+/// `COPY table FROM 'file'` imports a CSV file, `COPY table TO 'file'`
+/// exports the table; `Database::execute()` runs it as
+/// `ExecuteStatement::Copy`. Only `FORMAT CSV` is supported. The delimiter
+/// is a single character, `'\t'` standing for a tab. `HEADER` alone means
+/// `HEADER true`.
+///
+/// ## Input
+/// - `statement`: Statement text, optionally ending with `;`
+///
+/// ## Output
+/// - `Ok(CopyStatement)`: Direction, table, path and options
+/// - `Err(ReedError)`: Not a valid COPY statement
+///
+/// ## Example
+/// ```rust
+/// use reedbase_last::reedql::parser::parse_copy;
+/// use reedbase_last::reedql::CopyDirection;
+///
+/// let copy = parse_copy("COPY text FROM 'text.csv' WITH (FORMAT CSV, HEADER true)")?;
+/// assert_eq!(copy.direction, CopyDirection::From);
+/// assert!(copy.options.header);
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn parse_copy(statement: &str) -> ReedResult<CopyStatement> {
+    let statement = statement.trim().trim_end_matches(';');
+    let mut parser = Parser::new(statement);
+    parser.expect_keyword("COPY")?;
+    let table = parser.parse_identifier()?;
+
+    let direction = if parser.peek_word_is("FROM") {
+        CopyDirection::From
+    } else if parser.peek_word_is("TO") {
+        CopyDirection::To
+    } else {
+        return Err(ReedError::ParseError {
+            reason: format!("Expected FROM or TO at position {}", parser.pos),
+        });
+    };
+    parser.parse_identifier()?;
+
+    let path = parser.parse_string_literal()?;
+    if path.is_empty() {
+        return Err(ReedError::ParseError {
+            reason: "COPY path must not be empty".to_string(),
+        });
+    }
+
+    let mut options = CopyOptions::default();
+    if parser.peek_word_is("WITH") {
+        parser.expect_keyword("WITH")?;
+    }
+    parser.skip_whitespace();
+    if parser.peek_char() == Some('(') {
+        parser.advance();
+        loop {
+            parser.parse_copy_option(&mut options)?;
+            parser.skip_whitespace();
+            match parser.peek_char() {
+                Some(',') => parser.advance(),
+                Some(')') => {
+                    parser.advance();
+                    break;
+                }
+                _ => {
+                    return Err(ReedError::ParseError {
+                        reason: "Expected ',' or ')' in COPY options".to_string(),
+                    })
+                }
+            }
+        }
+    }
+
+    parser.skip_whitespace();
+    if parser.pos < parser.query.len() {
+        return Err(ReedError::ParseError {
+            reason: format!(
+                "Unexpected input at position {}: '{}'",
+                parser.pos,
+                &parser.query[parser.pos..]
+            ),
+        });
+    }
+
+    Ok(CopyStatement {
+        direction,
+        table,
+        path,
+        options,
+    })
+}
+
+impl<'a> Parser<'a> {
+    /// Parses one COPY option into `options`.
+    fn parse_copy_option(&mut self, options: &mut CopyOptions) -> ReedResult<()> {
+        let name = self.parse_identifier()?;
+        match name.to_uppercase().as_str() {
+            "FORMAT" => {
+                let format = self.parse_identifier()?;
+                if !format.eq_ignore_ascii_case("CSV") {
+                    return Err(ReedError::ParseError {
+                        reason: format!("Unsupported COPY format: {}", format),
+                    });
+                }
+            }
+            "DELIMITER" => {
+                let delimiter = self.parse_string_literal()?;
+                options.delimiter = match delimiter.as_bytes() {
+                    b"\\t" => b'\t',
+                    [byte] if !matches!(byte, b'"' | b'\n' | b'\r') => *byte,
+                    _ => {
+                        return Err(ReedError::ParseError {
+                            reason: format!("Invalid COPY delimiter: '{}'", delimiter),
+                        })
+                    }
+                };
+            }
+            "HEADER" => {
+                options.header = match self.peek_word() {
+                    Some(word) if word.eq_ignore_ascii_case("TRUE") => {
+                        self.parse_identifier()?;
+                        true
+                    }
+                    Some(word) if word.eq_ignore_ascii_case("FALSE") => {
+                        self.parse_identifier()?;
+                        false
+                    }
+                    _ => true,
+                };
+            }
+            _ => {
+                return Err(ReedError::ParseError {
+                    reason: format!("Unknown COPY option: {}", name),
+                })
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! ANALYZE TABLE and EXPLAIN statements.
+
+use crate::error::{ReedError, ReedResult};
+use crate::reedql::types::ExplainStatement;
+
+use super::{parse, Parser};
+
+/// Parses `ANALYZE TABLE tablename` and returns the table name.
+///
+/// `ParsedQuery` describes SELECT queries only, so ANALYZE has its own
+/// entry point; `Database::execute()` runs it as
+/// `ExecuteStatement::AnalyzeTable`.
+///
+/// ## Input
+/// - `statement`: Statement text, optionally ending with `;`
+///
+/// ## Output
+/// - `Ok(String)`: Table name
+/// - `Err(ReedError)`: Not an ANALYZE TABLE statement
+///
+/// ## Example
+/// ```rust
+/// use reedbase_last::reedql::parser::parse_analyze;
+///
+/// assert_eq!(parse_analyze("analyze table text;")?, "text");
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn parse_analyze(statement: &str) -> ReedResult<String> {
+    let statement = statement.trim().trim_end_matches(';');
+    let mut parser = Parser::new(statement);
+    parser.expect_keyword("ANALYZE")?;
+    parser.expect_keyword("TABLE")?;
+    let table = parser.parse_identifier()?;
+
+    parser.skip_whitespace();
+    if parser.pos < parser.query.len() {
+        return Err(ReedError::ParseError {
+            reason: format!(
+                "Unexpected input at position {}: '{}'",
+                parser.pos,
+                &parser.query[parser.pos..]
+            ),
+        });
+    }
+
+    Ok(table)
+}
+
+/// Parses `EXPLAIN [ANALYZE] SELECT ...`.
+///
+/// The query after the keywords is parsed by `parse()`; `Database::query()`
+/// answers EXPLAIN with the execution plan instead of the query's rows.
+///
+/// ## Input
+/// - `statement`: Statement text, optionally ending with `;`
+///
+/// ## Output
+/// - `Ok(ExplainStatement)`: ANALYZE flag and parsed query
+/// - `Err(ReedError)`: Not an EXPLAIN statement, or an invalid query
+///
+/// ## Example
+/// ```rust
+/// use reedbase_last::reedql::parser::parse_explain;
+///
+/// let explain = parse_explain("EXPLAIN ANALYZE SELECT * FROM text WHERE key = 'a'")?;
+/// assert!(explain.analyze);
+/// assert_eq!(explain.query.table, "text");
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn parse_explain(statement: &str) -> ReedResult<ExplainStatement> {
+    let statement = statement.trim().trim_end_matches(';');
+    let mut parser = Parser::new(statement);
+    parser.expect_keyword("EXPLAIN")?;
+
+    let analyze = parser.peek_word_is("ANALYZE");
+    if analyze {
+        parser.expect_keyword("ANALYZE")?;
+    }
+
+    Ok(ExplainStatement {
+        analyze,
+        query: parse(&statement[parser.pos..])?,
+    })
+}
@@ -0,0 +1,139 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! CREATE INDEX and DROP INDEX statements.
+
+use crate::error::{ReedError, ReedResult};
+use crate::reedql::types::{CreateIndexStatement, DropIndexStatement};
+
+use super::Parser;
+
+/// Parses `CREATE INDEX [indexname] ON tablename (column, ...)`.
+///
+/// ## Input
+/// - `statement`: Statement text, optionally ending with `;`
+///
+/// ## Output
+/// - `Ok(CreateIndexStatement)`: Optional index name, table and columns
+/// - `Err(ReedError)`: Not a CREATE INDEX statement
+///
+/// ## Example
+/// ```rust
+/// use reedbase_last::reedql::parser::parse_create_index;
+///
+/// let create = parse_create_index("CREATE INDEX by_city ON users (city)")?;
+/// assert_eq!(create.name.as_deref(), Some("by_city"));
+/// assert_eq!(create.columns, vec!["city".to_string()]);
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn parse_create_index(statement: &str) -> ReedResult<CreateIndexStatement> {
+    let statement = statement.trim().trim_end_matches(';');
+    let mut parser = Parser::new(statement);
+    parser.expect_keyword("CREATE")?;
+    parser.expect_keyword("INDEX")?;
+
+    let name = if parser.peek_word_is("ON") {
+        None
+    } else {
+        Some(parser.parse_identifier()?)
+    };
+    let (table, columns) = parser.parse_index_on()?;
+
+    parser.skip_whitespace();
+    if parser.pos < parser.query.len() {
+        return Err(ReedError::ParseError {
+            reason: format!(
+                "Unexpected input at position {}: '{}'",
+                parser.pos,
+                &parser.query[parser.pos..]
+            ),
+        });
+    }
+
+    Ok(CreateIndexStatement {
+        name,
+        table,
+        columns,
+    })
+}
+
+/// Parses `DROP INDEX indexname` or `DROP INDEX ON tablename (column, ...)`.
+///
+/// ## Input
+/// - `statement`: Statement text, optionally ending with `;`
+///
+/// ## Output
+/// - `Ok(DropIndexStatement)`: Index name, or table and columns
+/// - `Err(ReedError)`: Not a DROP INDEX statement
+///
+/// ## Example
+/// ```rust
+/// use reedbase_last::reedql::parser::parse_drop_index;
+/// use reedbase_last::reedql::DropIndexStatement;
+///
+/// let drop = parse_drop_index("DROP INDEX by_city")?;
+/// assert_eq!(drop, DropIndexStatement::Named("by_city".to_string()));
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn parse_drop_index(statement: &str) -> ReedResult<DropIndexStatement> {
+    let statement = statement.trim().trim_end_matches(';');
+    let mut parser = Parser::new(statement);
+    parser.expect_keyword("DROP")?;
+    parser.expect_keyword("INDEX")?;
+
+    let drop = if parser.peek_word_is("ON") {
+        let (table, columns) = parser.parse_index_on()?;
+        DropIndexStatement::On { table, columns }
+    } else {
+        DropIndexStatement::Named(parser.parse_identifier()?)
+    };
+
+    parser.skip_whitespace();
+    if parser.pos < parser.query.len() {
+        return Err(ReedError::ParseError {
+            reason: format!(
+                "Unexpected input at position {}: '{}'",
+                parser.pos,
+                &parser.query[parser.pos..]
+            ),
+        });
+    }
+
+    Ok(drop)
+}
+
+impl<'a> Parser<'a> {
+    /// Parses `ON table (col1, col2)` of CREATE/DROP INDEX.
+    fn parse_index_on(&mut self) -> ReedResult<(String, Vec<String>)> {
+        self.expect_keyword("ON")?;
+        let table = self.parse_identifier()?;
+
+        self.skip_whitespace();
+        if self.peek_char() != Some('(') {
+            return Err(ReedError::ParseError {
+                reason: "Expected '(' after table name".to_string(),
+            });
+        }
+        self.advance();
+
+        let mut columns = Vec::new();
+        loop {
+            columns.push(self.parse_identifier()?);
+            self.skip_whitespace();
+            match self.peek_char() {
+                Some(',') => self.advance(),
+                Some(')') => {
+                    self.advance();
+                    break;
+                }
+                _ => {
+                    return Err(ReedError::ParseError {
+                        reason: format!("Expected ',' or ')' at position {}", self.pos),
+                    })
+                }
+            }
+        }
+
+        Ok((table, columns))
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Table aliases and JOIN clauses.
+
+use crate::error::{ReedError, ReedResult};
+use crate::reedql::types::{FilterCondition, JoinClause, JoinType};
+
+use super::conditions::comparison;
+use super::Parser;
+
+/// Words that end a table reference, so they are never taken for an alias.
+const TABLE_REFERENCE_TERMINATORS: &[&str] = &[
+    "WHERE", "GROUP", "HAVING", "ORDER", "LIMIT", "JOIN", "INNER", "LEFT", "OUTER", "NATURAL", "ON",
+];
+
+impl<'a> Parser<'a> {
+    /// Parses optional table alias: `text t` or `text AS t`.
+    pub(super) fn parse_table_alias(&mut self) -> ReedResult<Option<String>> {
+        if let Some(alias) = self.parse_alias()? {
+            return Ok(Some(alias));
+        }
+
+        match self.peek_word() {
+            Some(word)
+                if !TABLE_REFERENCE_TERMINATORS
+                    .iter()
+                    .any(|kw| word.eq_ignore_ascii_case(kw)) =>
+            {
+                Ok(Some(self.parse_identifier()?))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Parses JOIN clauses following the FROM table.
+    pub(super) fn parse_joins(&mut self) -> ReedResult<()> {
+        loop {
+            let join_type = match self.peek_word().map(|w| w.to_ascii_uppercase()).as_deref() {
+                Some("JOIN") => JoinType::Inner,
+                Some("INNER") => {
+                    self.expect_keyword("INNER")?;
+                    JoinType::Inner
+                }
+                Some("LEFT") => {
+                    self.expect_keyword("LEFT")?;
+                    if self
+                        .peek_word()
+                        .is_some_and(|w| w.eq_ignore_ascii_case("OUTER"))
+                    {
+                        self.expect_keyword("OUTER")?;
+                    }
+                    JoinType::Left
+                }
+                Some("NATURAL") => {
+                    self.expect_keyword("NATURAL")?;
+                    JoinType::Natural
+                }
+                _ => break,
+            };
+
+            self.expect_keyword("JOIN")?;
+            let table = self.parse_identifier()?;
+            let alias = self.parse_table_alias()?;
+
+            let condition = if join_type == JoinType::Natural {
+                None
+            } else {
+                if !self
+                    .peek_word()
+                    .is_some_and(|w| w.eq_ignore_ascii_case("ON"))
+                {
+                    return Err(ReedError::ParseError {
+                        reason: format!("{} {} requires an ON condition", join_type, table),
+                    });
+                }
+                self.expect_keyword("ON")?;
+                Some(self.parse_join_condition()?)
+            };
+
+            self.parsed.joins.push(JoinClause {
+                table,
+                alias,
+                condition,
+                join_type,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Parses a JOIN ON condition: `column operator column`.
+    fn parse_join_condition(&mut self) -> ReedResult<FilterCondition> {
+        let left = self.parse_identifier()?;
+        let operator = self.parse_operator()?;
+        let right = self.parse_identifier()?;
+        comparison(&operator, left, right)
+    }
+}
@@ -0,0 +1,206 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tokens: identifiers, literals, operators, numbers and keywords.
+
+use crate::error::{ReedError, ReedResult};
+
+use super::Parser;
+
+impl<'a> Parser<'a> {
+    /// Parses an identifier (column name, table name, etc.).
+    pub(super) fn parse_identifier(&mut self) -> ReedResult<String> {
+        self.skip_whitespace();
+
+        let start = self.pos;
+        while self.pos < self.query.len() {
+            let ch = self.query.as_bytes()[self.pos];
+            if ch.is_ascii_alphanumeric() || ch == b'_' || ch == b'.' {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+
+        if start == self.pos {
+            return Err(ReedError::ParseError {
+                reason: format!("Expected identifier at position {}", self.pos),
+            });
+        }
+
+        Ok(self.query[start..self.pos].to_string())
+    }
+
+    /// Parses a string literal ('value' or "value").
+    pub(super) fn parse_string_literal(&mut self) -> ReedResult<String> {
+        self.skip_whitespace();
+
+        let quote = self.peek_char();
+        if quote != Some('\'') && quote != Some('"') {
+            return Err(ReedError::ParseError {
+                reason: format!("Expected string literal at position {}", self.pos),
+            });
+        }
+
+        let quote_char = quote.unwrap();
+        self.advance();
+
+        let start = self.pos;
+        while self.pos < self.query.len() {
+            if self.query.as_bytes()[self.pos] == quote_char as u8 {
+                let value = self.query[start..self.pos].to_string();
+                self.advance();
+                return Ok(value);
+            }
+            self.pos += 1;
+        }
+
+        Err(ReedError::ParseError {
+            reason: "Unterminated string literal".to_string(),
+        })
+    }
+
+    /// Parses an operator (=, !=, <, >, <=, >=).
+    pub(super) fn parse_operator(&mut self) -> ReedResult<String> {
+        self.skip_whitespace();
+
+        let start = self.pos;
+
+        // Try two-character operators first
+        if self.pos + 1 < self.query.len() {
+            let two_char = &self.query[self.pos..self.pos + 2];
+            if two_char == "!=" || two_char == "<=" || two_char == ">=" {
+                self.pos += 2;
+                return Ok(two_char.to_string());
+            }
+        }
+
+        // Try single-character operators
+        if self.pos < self.query.len() {
+            let ch = self.query.as_bytes()[self.pos];
+            if ch == b'=' || ch == b'<' || ch == b'>' {
+                self.pos += 1;
+                return Ok(self.query[start..self.pos].to_string());
+            }
+        }
+
+        Err(ReedError::ParseError {
+            reason: format!("Expected operator at position {}", self.pos),
+        })
+    }
+
+    /// Parses a number.
+    pub(super) fn parse_number(&mut self) -> ReedResult<usize> {
+        self.skip_whitespace();
+
+        let start = self.pos;
+        while self.pos < self.query.len() && self.query.as_bytes()[self.pos].is_ascii_digit() {
+            self.pos += 1;
+        }
+
+        if start == self.pos {
+            return Err(ReedError::ParseError {
+                reason: format!("Expected number at position {}", self.pos),
+            });
+        }
+
+        self.query[start..self.pos]
+            .parse()
+            .map_err(|_| ReedError::ParseError {
+                reason: "Invalid number".to_string(),
+            })
+    }
+
+    /// Expects a specific keyword (case-insensitive).
+    pub(super) fn expect_keyword(&mut self, keyword: &str) -> ReedResult<()> {
+        self.skip_whitespace();
+
+        let end = self.pos + keyword.len();
+        if end > self.query.len() {
+            return Err(ReedError::ParseError {
+                reason: format!("Expected keyword '{}'", keyword),
+            });
+        }
+
+        let actual = &self.query[self.pos..end];
+        if !actual.eq_ignore_ascii_case(keyword) {
+            return Err(ReedError::ParseError {
+                reason: format!("Expected '{}', found '{}'", keyword, actual),
+            });
+        }
+
+        self.pos = end;
+        Ok(())
+    }
+
+    /// Peeks ahead to check for keyword (case-insensitive).
+    pub(super) fn peek_keyword(&self, keyword: &str) -> bool {
+        let end = self.pos + keyword.len();
+        if end > self.query.len() {
+            return false;
+        }
+
+        // Skip whitespace first
+        let mut pos = self.pos;
+        while pos < self.query.len() && self.query.as_bytes()[pos].is_ascii_whitespace() {
+            pos += 1;
+        }
+
+        let end = pos + keyword.len();
+        if end > self.query.len() {
+            return false;
+        }
+
+        self.query[pos..end].eq_ignore_ascii_case(keyword)
+    }
+
+    /// Checks whether the next whole word is `word` (case-insensitive).
+    ///
+    /// Unlike `peek_keyword`, `OR` does not match the start of `ORDER`.
+    pub(super) fn peek_word_is(&self, word: &str) -> bool {
+        self.peek_word()
+            .is_some_and(|w| w.eq_ignore_ascii_case(word))
+    }
+
+    /// Peeks at the next whole word (identifier characters) without consuming.
+    pub(super) fn peek_word(&self) -> Option<&'a str> {
+        let bytes = self.query.as_bytes();
+        let mut start = self.pos;
+        while start < bytes.len() && bytes[start].is_ascii_whitespace() {
+            start += 1;
+        }
+
+        let mut end = start;
+        while end < bytes.len() && (bytes[end].is_ascii_alphanumeric() || bytes[end] == b'_') {
+            end += 1;
+        }
+
+        (end > start).then(|| &self.query[start..end])
+    }
+
+    /// Peeks at current character without consuming.
+    pub(super) fn peek_char(&self) -> Option<char> {
+        if self.pos < self.query.len() {
+            Some(self.query.as_bytes()[self.pos] as char)
+        } else {
+            None
+        }
+    }
+
+    /// Advances position by 1.
+    pub(super) fn advance(&mut self) {
+        self.pos += 1;
+    }
+
+    /// Advances position by n.
+    pub(super) fn advance_by(&mut self, n: usize) {
+        self.pos += n;
+    }
+
+    /// Skips whitespace.
+    pub(super) fn skip_whitespace(&mut self) {
+        while self.pos < self.query.len() && self.query.as_bytes()[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+    }
+}
@@ -0,0 +1,276 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! ReedQL Custom Parser
+//!
+//! Hand-written parser optimized for < 10μs parse time.
+//! NO external SQL parsing libraries (sqlparser-rs adds 50KB+ binary size).
+//!
+//! ## Performance Strategy
+//! - Zero-copy parsing where possible
+//! - Minimal allocations (< 10 per query)
+//! - Direct string slicing (no regex)
+//! - Single-pass parsing (no backtracking)
+//! - Stack-allocated parser state
+//!
+//! ## Supported Grammar
+//! ```text
+//! query       := SELECT [DISTINCT] columns FROM table_ref join* [WHERE conditions]
+//!                [GROUP BY column_list] [HAVING conditions] [ORDER BY order] [LIMIT limit]
+//! table_ref   := IDENTIFIER [[AS] alias]
+//! join        := [INNER] JOIN table_ref ON column operator column
+//!              | LEFT [OUTER] JOIN table_ref ON column operator column
+//!              | NATURAL JOIN table_ref
+//! columns     := * | select_list
+//! select_list := select_item (, select_item)*
+//! select_item := IDENTIFIER [AS alias] | function [AS alias] | aggregation [AS alias]
+//! function    := IDENTIFIER ( argument (, argument)* )   (computed column functions)
+//! column_list := IDENTIFIER (, IDENTIFIER)*
+//! aggregation := (COUNT|SUM|AVG|MIN|MAX) ( column ) | (CORR|COVAR) ( column , column )
+//! conditions  := and_expr (OR and_expr)*
+//! and_expr    := primary (AND primary)*
+//! primary     := NOT primary | ( conditions ) | condition
+//! condition   := column operator value
+//!              | column [NOT] LIKE pattern
+//!              | column [NOT] IN ( value_list )
+//!              | column [NOT] IN ( query )
+//!              | column [NOT] BETWEEN value AND value
+//!              | column IS [NOT] (EMPTY | NULL)
+//! operator    := = | != | < | > | <= | >=
+//! order       := order_item (, order_item)*
+//! order_item  := column [NUMERIC] [ASC|DESC]
+//! limit       := count [OFFSET count]
+//! count       := NUMBER | $IDENTIFIER   (placeholders only via prepare())
+//! analyze     := ANALYZE TABLE IDENTIFIER   (via parse_analyze())
+//! explain     := EXPLAIN [ANALYZE] query   (via parse_explain())
+//! copy        := COPY IDENTIFIER (FROM|TO) 'path' [[WITH] ( option (, option)* )]
+//! option      := FORMAT CSV | DELIMITER 'c' | HEADER [TRUE|FALSE]   (via parse_copy())
+//! create      := CREATE TABLE IDENTIFIER ( column_def (, column_def)* )   (via parse_create_table())
+//! column_def  := IDENTIFIER type [( NUMBER )] constraint*
+//! constraint  := PRIMARY KEY | NOT NULL | NULL | UNIQUE | REFERENCES IDENTIFIER ( IDENTIFIER )
+//! drop        := DROP TABLE [IF EXISTS] IDENTIFIER   (via parse_drop_table())
+//! alter       := ALTER TABLE IDENTIFIER RENAME COLUMN IDENTIFIER TO IDENTIFIER   (via parse_alter_table())
+//! index       := CREATE INDEX [IDENTIFIER] index_on   (via parse_create_index())
+//! drop_index  := DROP INDEX (IDENTIFIER | index_on)   (via parse_drop_index())
+//! index_on    := ON IDENTIFIER ( column_list )
+//! ```
+//!
+//! ## Layout
+//! `select`, `joins`, `conditions` and `lexer` extend `Parser` for SELECT
+//! queries; `explain`, `copy`, `table_ddl` and `index_ddl` hold the entry
+//! points of the other statements.
+
+mod conditions;
+mod copy;
+mod explain;
+mod index_ddl;
+mod joins;
+mod lexer;
+mod select;
+mod table_ddl;
+
+pub use copy::parse_copy;
+pub use explain::{parse_analyze, parse_explain};
+pub use index_ddl::{parse_create_index, parse_drop_index};
+pub use table_ddl::{parse_alter_table, parse_create_table, parse_drop_table};
+
+use crate::error::{ReedError, ReedResult};
+use crate::reedql::types::{FilterCondition, LimitValue, ParsedQuery, PreparedQuery};
+
+/// Parses a ReedQL query string into a ParsedQuery AST.
+///
+/// ## Input
+/// - `query`: SQL-like query string
+///
+/// ## Output
+/// - `Ok(ParsedQuery)`: Successfully parsed query
+/// - `Err(ReedError)`: Parse error with detailed message
+///
+/// ## Performance
+/// - Target: < 10μs for typical queries
+/// - Actual: ~5-8μs (measured on M1 Mac)
+///
+/// ## Example
+/// ```rust,ignore
+/// let query = parse("SELECT * FROM text WHERE key LIKE '%.@de' LIMIT 10")?;
+/// ```
+pub fn parse(query: &str) -> ReedResult<ParsedQuery> {
+    let mut parser = Parser::new(query);
+    parser.parse()
+}
+
+/// Parses a ReedQL query once for repeated execution.
+///
+/// Like `parse()`, but `LIMIT` and `OFFSET` may be named placeholders
+/// (`$name`) that are bound later with `PreparedQuery::bind()`.
+///
+/// ## Input
+/// - `query`: SQL-like query string, optionally with placeholders
+///
+/// ## Output
+/// - `Ok(PreparedQuery)`: Parsed query with unbound placeholders
+/// - `Err(ReedError)`: Parse error with detailed message
+///
+/// ## Performance
+/// - Same as `parse()`; binding afterwards is O(1) and does not re-parse
+///
+/// ## Example
+/// ```rust,ignore
+/// let prepared = prepare("SELECT * FROM text ORDER BY key LIMIT $limit OFFSET $offset")?;
+/// ```
+pub fn prepare(query: &str) -> ReedResult<PreparedQuery> {
+    let mut parser = Parser::new(query);
+    parser.allow_placeholders = true;
+    let parsed = parser.parse()?;
+
+    let (limit, offset) = match parser.limit_values.take() {
+        Some((limit, offset)) => (Some(limit), offset),
+        None => (None, LimitValue::Value(0)),
+    };
+    Ok(PreparedQuery {
+        query: parsed,
+        limit,
+        offset,
+    })
+}
+
+/// Parses a standalone condition, as written after WHERE.
+///
+/// Top-level AND operands are returned as one `FilterCondition::And`.
+///
+/// ## Input
+/// - `condition`: Condition text, e.g. `environment = 'prod' AND deleted IS EMPTY`
+///
+/// ## Output
+/// - `Ok(FilterCondition)`: Parsed condition
+/// - `Err(ReedError)`: Parse error, or input beyond the condition
+///
+/// ## Example
+/// ```rust
+/// use reedbase_last::reedql::parser::parse_condition;
+/// use reedbase_last::reedql::FilterCondition;
+///
+/// assert_eq!(
+///     parse_condition("environment = 'prod'")?,
+///     FilterCondition::Equals {
+///         column: "environment".to_string(),
+///         value: "prod".to_string(),
+///     }
+/// );
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn parse_condition(condition: &str) -> ReedResult<FilterCondition> {
+    let mut parser = Parser::new(condition);
+    let parsed = parser.parse_or()?;
+
+    parser.skip_whitespace();
+    if parser.pos < parser.query.len() {
+        return Err(ReedError::ParseError {
+            reason: format!(
+                "Unexpected input at position {}: '{}'",
+                parser.pos,
+                &parser.query[parser.pos..]
+            ),
+        });
+    }
+
+    Ok(parsed)
+}
+
+/// Parser state machine.
+///
+/// Stack-allocated parser with zero-copy tokenization.
+struct Parser<'a> {
+    /// Original query string
+    query: &'a str,
+
+    /// Current position in query
+    pos: usize,
+
+    /// Parsed query (built incrementally)
+    parsed: ParsedQuery,
+
+    /// Whether `$name` placeholders are accepted (see `prepare()`)
+    allow_placeholders: bool,
+
+    /// LIMIT and OFFSET as written, including placeholders
+    limit_values: Option<(LimitValue, LimitValue)>,
+}
+
+impl<'a> Parser<'a> {
+    /// Creates a new parser.
+    fn new(query: &'a str) -> Self {
+        Self {
+            query: query.trim(),
+            pos: 0,
+            parsed: ParsedQuery::new(),
+            allow_placeholders: false,
+            limit_values: None,
+        }
+    }
+
+    /// Main parse entry point.
+    fn parse(&mut self) -> ReedResult<ParsedQuery> {
+        // Expect SELECT
+        self.expect_keyword("SELECT")?;
+
+        // Parse columns (or aggregation)
+        self.parse_columns()?;
+
+        // Expect FROM
+        self.expect_keyword("FROM")?;
+
+        // Parse table name, optional alias and JOIN clauses
+        self.parsed.table = self.parse_identifier()?;
+        self.parsed.table_alias = self.parse_table_alias()?;
+        self.parse_joins()?;
+
+        // Optional WHERE clause
+        if self.peek_keyword("WHERE") {
+            self.expect_keyword("WHERE")?;
+            self.parse_conditions()?;
+        }
+
+        // Optional GROUP BY clause
+        if self.peek_word_is("GROUP") {
+            self.expect_keyword("GROUP")?;
+            self.expect_keyword("BY")?;
+            self.parse_group_by()?;
+        }
+
+        // Optional HAVING clause
+        if self.peek_word_is("HAVING") {
+            self.expect_keyword("HAVING")?;
+            self.parsed.having = Some(self.parse_or()?);
+        }
+
+        // Optional ORDER BY clause
+        if self.peek_keyword("ORDER") {
+            self.expect_keyword("ORDER")?;
+            self.expect_keyword("BY")?;
+            self.parse_order_by()?;
+        }
+
+        // Optional LIMIT clause
+        if self.peek_keyword("LIMIT") {
+            self.expect_keyword("LIMIT")?;
+            self.parse_limit()?;
+        }
+
+        // Ensure we've consumed entire query
+        self.skip_whitespace();
+        if self.pos < self.query.len() {
+            return Err(ReedError::ParseError {
+                reason: format!(
+                    "Unexpected input at position {}: '{}'",
+                    self.pos,
+                    &self.query[self.pos..]
+                ),
+            });
+        }
+
+        self.check_grouping()?;
+
+        Ok(self.parsed.clone())
+    }
+}
@@ -0,0 +1,382 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Select list, aggregations, GROUP BY, ORDER BY and LIMIT of a query.
+
+use crate::error::{ReedError, ReedResult};
+use crate::reedql::types::{
+    AggregationFunction, AggregationType, Collation, LimitOffset, LimitValue, OrderBy,
+    SortDirection,
+};
+use crate::schema::ComputedExpr;
+
+use super::Parser;
+
+impl<'a> Parser<'a> {
+    /// Validates the select list against GROUP BY.
+    ///
+    /// Without GROUP BY an aggregation must be the only select item; its
+    /// column(s) are then kept in `columns` for compatibility. With GROUP BY
+    /// every plain column must be a group column.
+    pub(super) fn check_grouping(&mut self) -> ReedResult<()> {
+        if self.parsed.group_by.is_empty() {
+            if self.parsed.having.is_some() {
+                return Err(ReedError::ParseError {
+                    reason: "HAVING requires GROUP BY".to_string(),
+                });
+            }
+            if let Some(agg) = &self.parsed.aggregation {
+                if let Some(column) = self.parsed.columns.first() {
+                    return Err(ReedError::ParseError {
+                        reason: format!("Column '{}' must appear in GROUP BY", column),
+                    });
+                }
+                self.parsed.columns.push(agg.column.clone());
+                self.parsed.columns.extend(agg.second_column.clone());
+            }
+            return Ok(());
+        }
+
+        if self.parsed.is_select_all() {
+            return Err(ReedError::ParseError {
+                reason: "SELECT * cannot be used with GROUP BY".to_string(),
+            });
+        }
+        if let Some(column) = self
+            .parsed
+            .columns
+            .iter()
+            .find(|c| !self.parsed.group_by.contains(c))
+        {
+            return Err(ReedError::ParseError {
+                reason: format!("Column '{}' must appear in GROUP BY", column),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Parses SELECT columns or aggregation.
+    pub(super) fn parse_columns(&mut self) -> ReedResult<()> {
+        // Optional DISTINCT
+        if self.peek_word_is("DISTINCT") {
+            self.expect_keyword("DISTINCT")?;
+            self.parsed.distinct = true;
+        }
+
+        self.skip_whitespace();
+
+        // Check for SELECT *
+        if self.peek_char() == Some('*') {
+            self.advance();
+            self.parsed.columns.push("*".to_string());
+            return Ok(());
+        }
+
+        // Parse select list: columns and at most one aggregation, each with
+        // optional AS alias
+        loop {
+            self.skip_whitespace();
+            if let Some(agg_type) = self.peek_aggregation() {
+                if self.parsed.aggregation.is_some() {
+                    return Err(ReedError::ParseError {
+                        reason: "Only one aggregation per query is supported".to_string(),
+                    });
+                }
+                let mut aggregation = self.parse_aggregation(agg_type)?;
+                aggregation.alias = self.parse_alias()?;
+                self.parsed.aggregation = Some(aggregation);
+            } else {
+                let mut column = self.parse_identifier()?;
+                let expression = self.parse_function_call(&column)?;
+                if let Some(expr) = &expression {
+                    column = expr.to_string();
+                }
+                let alias = self.parse_alias()?;
+                self.parsed.columns.push(column);
+                self.parsed.column_aliases.push(alias);
+                self.parsed.expressions.push(expression);
+            }
+
+            self.skip_whitespace();
+            if self.peek_char() == Some(',') {
+                self.advance();
+                continue;
+            }
+            break;
+        }
+
+        Ok(())
+    }
+
+    /// Parses the argument list of a scalar function in the select list.
+    ///
+    /// `name` has already been read; without a following `(` it is a plain
+    /// column. Functions and arguments follow computed columns (see
+    /// `ComputedExpr`), e.g. `add_days(created_at, 30)`.
+    fn parse_function_call(&mut self, name: &str) -> ReedResult<Option<ComputedExpr>> {
+        self.skip_whitespace();
+        if self.peek_char() != Some('(') {
+            return Ok(None);
+        }
+
+        let start = self.pos;
+        let mut quote = None;
+        while let Some(ch) = self.peek_char() {
+            self.advance();
+            match (quote, ch) {
+                (None, '\'' | '"') => quote = Some(ch),
+                (Some(q), _) if ch == q => quote = None,
+                (None, ')') => {
+                    let call = format!("{}{}", name, &self.query[start..self.pos]);
+                    return ComputedExpr::parse(&call).map(Some).map_err(|e| {
+                        ReedError::ParseError {
+                            reason: e.to_string(),
+                        }
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        Err(ReedError::ParseError {
+            reason: format!("Unclosed argument list of '{}'", name),
+        })
+    }
+
+    /// Parses optional `AS alias` after a column or aggregation.
+    pub(super) fn parse_alias(&mut self) -> ReedResult<Option<String>> {
+        self.skip_whitespace();
+
+        // Require whitespace after AS so that e.g. `ASC` is not taken for it
+        let is_alias = self.peek_keyword("AS")
+            && self
+                .query
+                .as_bytes()
+                .get(self.pos + 2)
+                .is_some_and(|b| b.is_ascii_whitespace());
+        if !is_alias {
+            return Ok(None);
+        }
+
+        self.expect_keyword("AS")?;
+        Ok(Some(self.parse_identifier()?))
+    }
+
+    /// Parses aggregation function: COUNT(*), SUM(column), CORR(a, b), etc.
+    fn parse_aggregation(&mut self, agg_type: AggregationType) -> ReedResult<AggregationFunction> {
+        // Consume function name
+        self.advance_by(match agg_type {
+            AggregationType::Count => 5,
+            AggregationType::Sum => 3,
+            AggregationType::Avg => 3,
+            AggregationType::Min => 3,
+            AggregationType::Max => 3,
+            AggregationType::Corr => 4,
+            AggregationType::Covar => 5,
+        });
+
+        self.skip_whitespace();
+
+        // Expect (
+        if self.peek_char() != Some('(') {
+            return Err(ReedError::ParseError {
+                reason: "Expected '(' after aggregation function".to_string(),
+            });
+        }
+        self.advance();
+
+        self.skip_whitespace();
+
+        // Parse column or *
+        let column = if self.peek_char() == Some('*') {
+            self.advance();
+            "*".to_string()
+        } else {
+            self.parse_identifier()?
+        };
+
+        self.skip_whitespace();
+
+        // Second column for CORR/COVAR
+        let second_column = if agg_type.takes_two_columns() {
+            if self.peek_char() != Some(',') {
+                return Err(ReedError::ParseError {
+                    reason: format!("{} expects two columns", agg_type),
+                });
+            }
+            self.advance();
+            self.skip_whitespace();
+            let second = self.parse_identifier()?;
+            self.skip_whitespace();
+            Some(second)
+        } else {
+            None
+        };
+
+        // Expect )
+        if self.peek_char() != Some(')') {
+            return Err(ReedError::ParseError {
+                reason: "Expected ')' after aggregation column".to_string(),
+            });
+        }
+        self.advance();
+
+        match second_column {
+            Some(second) => Ok(AggregationFunction::pair(agg_type, column, second)),
+            None => Ok(AggregationFunction::new(agg_type, column)),
+        }
+    }
+
+    /// Parses GROUP BY column list.
+    pub(super) fn parse_group_by(&mut self) -> ReedResult<()> {
+        loop {
+            let column = self.parse_identifier()?;
+            self.parsed.group_by.push(column);
+
+            self.skip_whitespace();
+            if self.peek_char() == Some(',') {
+                self.advance();
+                continue;
+            }
+            break;
+        }
+
+        Ok(())
+    }
+
+    /// Parses ORDER BY clauses.
+    pub(super) fn parse_order_by(&mut self) -> ReedResult<()> {
+        loop {
+            let column = self.parse_identifier()?;
+
+            self.skip_whitespace();
+
+            // Optional NUMERIC collation
+            let collation = if self.peek_word_is("NUMERIC") {
+                self.expect_keyword("NUMERIC")?;
+                self.skip_whitespace();
+                Collation::Numeric
+            } else {
+                Collation::Lexicographic
+            };
+
+            // Check for ASC/DESC
+            let direction = if self.peek_keyword("DESC") {
+                self.expect_keyword("DESC")?;
+                SortDirection::Descending
+            } else {
+                // ASC is optional (default)
+                if self.peek_keyword("ASC") {
+                    self.expect_keyword("ASC")?;
+                }
+                SortDirection::Ascending
+            };
+
+            // ORDER BY may reference a column alias; sort on the source column
+            let column = self
+                .parsed
+                .column_aliases
+                .iter()
+                .position(|alias| alias.as_deref() == Some(column.as_str()))
+                .map(|i| self.parsed.columns[i].clone())
+                .unwrap_or(column);
+
+            self.parsed
+                .order_by
+                .push(OrderBy::new(column, direction).with_collation(collation));
+
+            self.skip_whitespace();
+            if self.peek_char() == Some(',') {
+                self.advance();
+                continue;
+            }
+            break;
+        }
+
+        Ok(())
+    }
+
+    /// Parses LIMIT clause.
+    ///
+    /// Placeholders leave `parsed.limit` unset; `PreparedQuery` fills it in
+    /// once they are bound.
+    pub(super) fn parse_limit(&mut self) -> ReedResult<()> {
+        let limit = self.parse_limit_value()?;
+
+        self.skip_whitespace();
+
+        // Check for OFFSET
+        let offset = if self.peek_keyword("OFFSET") {
+            self.expect_keyword("OFFSET")?;
+            self.parse_limit_value()?
+        } else {
+            LimitValue::Value(0)
+        };
+
+        if let (LimitValue::Value(limit), LimitValue::Value(offset)) = (&limit, &offset) {
+            self.parsed.limit = Some(LimitOffset::with_offset(*limit, *offset));
+        }
+        self.limit_values = Some((limit, offset));
+
+        Ok(())
+    }
+
+    /// Parses a LIMIT/OFFSET number or, when preparing, a `$name` placeholder.
+    fn parse_limit_value(&mut self) -> ReedResult<LimitValue> {
+        self.skip_whitespace();
+        if self.peek_char() != Some('$') {
+            return Ok(LimitValue::Value(self.parse_number()?));
+        }
+
+        if !self.allow_placeholders {
+            return Err(ReedError::ParseError {
+                reason: format!(
+                    "Placeholder at position {} requires a prepared query",
+                    self.pos
+                ),
+            });
+        }
+        self.advance();
+
+        let start = self.pos;
+        while self.pos < self.query.len()
+            && (self.query.as_bytes()[self.pos].is_ascii_alphanumeric()
+                || self.query.as_bytes()[self.pos] == b'_')
+        {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return Err(ReedError::ParseError {
+                reason: format!("Expected placeholder name at position {}", self.pos),
+            });
+        }
+
+        Ok(LimitValue::Placeholder(
+            self.query[start..self.pos].to_string(),
+        ))
+    }
+
+    /// Peeks ahead to check for aggregation function.
+    ///
+    /// Only a function call counts: `count` or `summary` alone are columns.
+    fn peek_aggregation(&self) -> Option<AggregationType> {
+        let word = self.peek_word()?;
+        let agg_type = match word.to_ascii_uppercase().as_str() {
+            "COUNT" => AggregationType::Count,
+            "SUM" => AggregationType::Sum,
+            "AVG" => AggregationType::Avg,
+            "MIN" => AggregationType::Min,
+            "MAX" => AggregationType::Max,
+            "CORR" => AggregationType::Corr,
+            "COVAR" => AggregationType::Covar,
+            _ => return None,
+        };
+
+        let rest = self.query[self.pos..].trim_start();
+        rest[word.len()..]
+            .trim_start()
+            .starts_with('(')
+            .then_some(agg_type)
+    }
+}
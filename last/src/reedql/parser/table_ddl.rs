@@ -0,0 +1,278 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! CREATE TABLE, DROP TABLE and ALTER TABLE statements.
+
+use crate::error::{ReedError, ReedResult};
+use crate::reedql::types::{
+    AlterTableAction, AlterTableStatement, CreateTableStatement, DropTableStatement,
+};
+use crate::schema::{ColumnDef, Schema};
+
+use super::Parser;
+
+/// Parses `CREATE TABLE name (column type constraints, ...)`.
+///
+/// SQL type names map to the schema column types: INT/INTEGER/BIGINT/
+/// SMALLINT → `integer`; FLOAT/REAL/DOUBLE [PRECISION]/NUMERIC/DECIMAL →
+/// `float`; TEXT/STRING/VARCHAR/CHAR → `string` (a length becomes
+/// `max_length`); BOOL/BOOLEAN → `boolean`; TIMESTAMP/DATETIME →
+/// `timestamp`. `NOT NULL` marks a column required, `REFERENCES t(c)` adds
+/// a foreign key. The schema is strict.
+///
+/// ## Input
+/// - `statement`: Statement text, optionally ending with `;`
+///
+/// ## Output
+/// - `Ok(CreateTableStatement)`: Table name and schema
+/// - `Err(ReedError)`: Malformed statement or unknown type
+///
+/// ## Example
+/// ```rust
+/// use reedbase_last::reedql::parser::parse_create_table;
+///
+/// let create = parse_create_table("CREATE TABLE users (id integer PRIMARY KEY, name text NOT NULL)")?;
+/// assert_eq!(create.table, "users");
+/// assert!(create.schema.columns[1].required);
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn parse_create_table(statement: &str) -> ReedResult<CreateTableStatement> {
+    let statement = statement.trim().trim_end_matches(';');
+    let mut parser = Parser::new(statement);
+    parser.expect_keyword("CREATE")?;
+    parser.expect_keyword("TABLE")?;
+    let table = parser.parse_identifier()?;
+
+    parser.skip_whitespace();
+    if parser.peek_char() != Some('(') {
+        return Err(ReedError::ParseError {
+            reason: "Expected '(' after table name".to_string(),
+        });
+    }
+    parser.advance();
+
+    let mut columns = Vec::new();
+    loop {
+        columns.push(parser.parse_column_def()?);
+        parser.skip_whitespace();
+        match parser.peek_char() {
+            Some(',') => parser.advance(),
+            Some(')') => {
+                parser.advance();
+                break;
+            }
+            _ => {
+                return Err(ReedError::ParseError {
+                    reason: format!("Expected ',' or ')' at position {}", parser.pos),
+                })
+            }
+        }
+    }
+
+    parser.skip_whitespace();
+    if parser.pos < parser.query.len() {
+        return Err(ReedError::ParseError {
+            reason: format!(
+                "Unexpected input at position {}: '{}'",
+                parser.pos,
+                &parser.query[parser.pos..]
+            ),
+        });
+    }
+
+    Ok(CreateTableStatement {
+        table,
+        schema: Schema::new("1.0".to_string(), true, columns),
+    })
+}
+
+/// Parses `DROP TABLE [IF EXISTS] tablename`.
+///
+/// ## Input
+/// - `statement`: Statement text, optionally ending with `;`
+///
+/// ## Output
+/// - `Ok(DropTableStatement)`: Table name and IF EXISTS flag
+/// - `Err(ReedError)`: Not a DROP TABLE statement
+///
+/// ## Example
+/// ```rust
+/// use reedbase_last::reedql::parser::parse_drop_table;
+///
+/// let drop = parse_drop_table("DROP TABLE IF EXISTS sessions")?;
+/// assert_eq!(drop.table, "sessions");
+/// assert!(drop.if_exists);
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn parse_drop_table(statement: &str) -> ReedResult<DropTableStatement> {
+    let statement = statement.trim().trim_end_matches(';');
+    let mut parser = Parser::new(statement);
+    parser.expect_keyword("DROP")?;
+    parser.expect_keyword("TABLE")?;
+
+    let if_exists = parser.peek_word_is("IF");
+    if if_exists {
+        parser.expect_keyword("IF")?;
+        parser.expect_keyword("EXISTS")?;
+    }
+    let table = parser.parse_identifier()?;
+
+    parser.skip_whitespace();
+    if parser.pos < parser.query.len() {
+        return Err(ReedError::ParseError {
+            reason: format!(
+                "Unexpected input at position {}: '{}'",
+                parser.pos,
+                &parser.query[parser.pos..]
+            ),
+        });
+    }
+
+    Ok(DropTableStatement { table, if_exists })
+}
+
+/// Parses `ALTER TABLE tablename RENAME COLUMN old TO new`.
+///
+/// ## Input
+/// - `statement`: Statement text, optionally ending with `;`
+///
+/// ## Output
+/// - `Ok(AlterTableStatement)`: Table name and change
+/// - `Err(ReedError)`: Not a supported ALTER TABLE statement
+///
+/// ## Example
+/// ```rust
+/// use reedbase_last::reedql::parser::parse_alter_table;
+/// use reedbase_last::reedql::AlterTableAction;
+///
+/// let alter = parse_alter_table("ALTER TABLE users RENAME COLUMN mail TO email")?;
+/// assert_eq!(
+///     alter.action,
+///     AlterTableAction::RenameColumn { from: "mail".to_string(), to: "email".to_string() }
+/// );
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn parse_alter_table(statement: &str) -> ReedResult<AlterTableStatement> {
+    let statement = statement.trim().trim_end_matches(';');
+    let mut parser = Parser::new(statement);
+    parser.expect_keyword("ALTER")?;
+    parser.expect_keyword("TABLE")?;
+    let table = parser.parse_identifier()?;
+
+    parser.expect_keyword("RENAME")?;
+    parser.expect_keyword("COLUMN")?;
+    let from = parser.parse_identifier()?;
+    if !parser.peek_word_is("TO") {
+        return Err(ReedError::ParseError {
+            reason: format!("Expected TO at position {}", parser.pos),
+        });
+    }
+    parser.expect_keyword("TO")?;
+    let to = parser.parse_identifier()?;
+
+    parser.skip_whitespace();
+    if parser.pos < parser.query.len() {
+        return Err(ReedError::ParseError {
+            reason: format!(
+                "Unexpected input at position {}: '{}'",
+                parser.pos,
+                &parser.query[parser.pos..]
+            ),
+        });
+    }
+
+    Ok(AlterTableStatement {
+        table,
+        action: AlterTableAction::RenameColumn { from, to },
+    })
+}
+
+/// Maps an SQL type name to a schema column type.
+fn column_type(sql_type: &str) -> Option<&'static str> {
+    match sql_type.to_uppercase().as_str() {
+        "INT" | "INTEGER" | "BIGINT" | "SMALLINT" => Some("integer"),
+        "FLOAT" | "REAL" | "DOUBLE" | "NUMERIC" | "DECIMAL" => Some("float"),
+        "TEXT" | "STRING" | "VARCHAR" | "CHAR" => Some("string"),
+        "BOOL" | "BOOLEAN" => Some("boolean"),
+        "TIMESTAMP" | "DATETIME" => Some("timestamp"),
+        _ => None,
+    }
+}
+
+impl<'a> Parser<'a> {
+    /// Parses one column definition of CREATE TABLE.
+    fn parse_column_def(&mut self) -> ReedResult<ColumnDef> {
+        let name = self.parse_identifier()?;
+        let sql_type = self.parse_identifier()?;
+        let col_type = column_type(&sql_type).ok_or_else(|| ReedError::ParseError {
+            reason: format!("Unknown type '{}' for column '{}'", sql_type, name),
+        })?;
+        if sql_type.eq_ignore_ascii_case("DOUBLE") && self.peek_word_is("PRECISION") {
+            self.expect_keyword("PRECISION")?;
+        }
+
+        let mut column = ColumnDef::new(name, col_type.to_string());
+
+        // Length or precision, e.g. VARCHAR(64) or DECIMAL(10, 2)
+        self.skip_whitespace();
+        if self.peek_char() == Some('(') {
+            self.advance();
+            let length = self.parse_number()?;
+            self.skip_whitespace();
+            if self.peek_char() == Some(',') {
+                self.advance();
+                self.parse_number()?;
+                self.skip_whitespace();
+            }
+            if self.peek_char() != Some(')') {
+                return Err(ReedError::ParseError {
+                    reason: format!("Expected ')' after length of '{}'", column.name),
+                });
+            }
+            self.advance();
+            if col_type == "string" {
+                column = column.with_max_length(length);
+            }
+        }
+
+        loop {
+            if self.peek_word_is("PRIMARY") {
+                self.expect_keyword("PRIMARY")?;
+                self.expect_keyword("KEY")?;
+                column.primary_key = true;
+                column.required = true;
+                column.unique = true;
+            } else if self.peek_word_is("NOT") {
+                self.expect_keyword("NOT")?;
+                self.expect_keyword("NULL")?;
+                column.required = true;
+            } else if self.peek_word_is("NULL") {
+                self.expect_keyword("NULL")?;
+            } else if self.peek_word_is("UNIQUE") {
+                self.expect_keyword("UNIQUE")?;
+                column.unique = true;
+            } else if self.peek_word_is("REFERENCES") {
+                self.expect_keyword("REFERENCES")?;
+                let table = self.parse_identifier()?;
+                self.skip_whitespace();
+                if self.peek_char() != Some('(') {
+                    return Err(ReedError::ParseError {
+                        reason: format!("Expected '(' after REFERENCES {}", table),
+                    });
+                }
+                self.advance();
+                let target = self.parse_identifier()?;
+                self.skip_whitespace();
+                if self.peek_char() != Some(')') {
+                    return Err(ReedError::ParseError {
+                        reason: format!("Expected ')' after REFERENCES {}({}", table, target),
+                    });
+                }
+                self.advance();
+                column = column.with_foreign_key(format!("{}.{}", table, target));
+            } else {
+                return Ok(column);
+            }
+        }
+    }
+}
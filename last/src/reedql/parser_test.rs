@@ -0,0 +1,782 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ReedError;
+    use crate::reedql::parser::{
+        parse, parse_alter_table, parse_analyze, parse_copy, parse_create_index,
+        parse_create_table, parse_drop_index, parse_drop_table, parse_explain, prepare,
+    };
+    use crate::reedql::types::{
+        AggregationType, AlterTableAction, AlterTableStatement, Collation, CopyDirection,
+        CopyOptions, CreateIndexStatement, DropIndexStatement, DropTableStatement, FilterCondition,
+        JoinClause, JoinType, LimitOffset, LimitValue, SortDirection,
+    };
+
+    #[test]
+    fn test_parse_select_all() {
+        let query = parse("SELECT * FROM text").unwrap();
+        assert!(query.is_select_all());
+        assert_eq!(query.table, "text");
+        assert_eq!(query.conditions.len(), 0);
+    }
+
+    #[test]
+    fn test_parse_select_columns() {
+        let query = parse("SELECT key, value FROM text").unwrap();
+        assert_eq!(query.columns, vec!["key", "value"]);
+        assert_eq!(query.table, "text");
+    }
+
+    #[test]
+    fn test_parse_where_equals() {
+        let query = parse("SELECT * FROM text WHERE namespace = 'page'").unwrap();
+        assert_eq!(query.conditions.len(), 1);
+        match &query.conditions[0] {
+            FilterCondition::Equals { column, value } => {
+                assert_eq!(column, "namespace");
+                assert_eq!(value, "page");
+            }
+            _ => panic!("Expected Equals condition"),
+        }
+    }
+
+    #[test]
+    fn test_parse_where_like() {
+        let query = parse("SELECT * FROM text WHERE key LIKE '%.@de'").unwrap();
+        assert_eq!(query.conditions.len(), 1);
+        match &query.conditions[0] {
+            FilterCondition::Like { column, pattern } => {
+                assert_eq!(column, "key");
+                assert_eq!(pattern, "%.@de");
+            }
+            _ => panic!("Expected Like condition"),
+        }
+    }
+
+    #[test]
+    fn test_parse_where_in_list() {
+        let query = parse("SELECT * FROM text WHERE namespace IN ('page', 'global')").unwrap();
+        assert_eq!(query.conditions.len(), 1);
+        match &query.conditions[0] {
+            FilterCondition::InList { column, values } => {
+                assert_eq!(column, "namespace");
+                assert_eq!(values, &vec!["page", "global"]);
+            }
+            _ => panic!("Expected InList condition"),
+        }
+    }
+
+    #[test]
+    fn test_parse_corr_covar() {
+        let query = parse("SELECT CORR(age, income) FROM users").unwrap();
+        let agg = query.aggregation.unwrap();
+        assert_eq!(agg.agg_type, AggregationType::Corr);
+        assert_eq!(agg.column, "age");
+        assert_eq!(agg.second_column, Some("income".to_string()));
+
+        let query = parse("SELECT COVAR( age ,income ) AS cov FROM users").unwrap();
+        let agg = query.aggregation.unwrap();
+        assert_eq!(agg.agg_type, AggregationType::Covar);
+        assert_eq!(agg.second_column, Some("income".to_string()));
+        assert_eq!(agg.alias, Some("cov".to_string()));
+
+        assert!(parse("SELECT CORR(age) FROM users").is_err());
+        assert!(parse("SELECT SUM(age, income) FROM users").is_err());
+    }
+
+    #[test]
+    fn test_parse_order_by_asc() {
+        let query = parse("SELECT * FROM text ORDER BY key ASC").unwrap();
+        assert_eq!(query.order_by.len(), 1);
+        assert_eq!(query.order_by[0].column, "key");
+        assert_eq!(query.order_by[0].direction, SortDirection::Ascending);
+    }
+
+    #[test]
+    fn test_parse_order_by_desc() {
+        let query = parse("SELECT * FROM text ORDER BY key DESC").unwrap();
+        assert_eq!(query.order_by.len(), 1);
+        assert_eq!(query.order_by[0].column, "key");
+        assert_eq!(query.order_by[0].direction, SortDirection::Descending);
+    }
+
+    #[test]
+    fn test_parse_order_by_numeric() {
+        let query = parse("SELECT * FROM users ORDER BY age NUMERIC DESC, name").unwrap();
+        assert_eq!(query.order_by.len(), 2);
+        assert_eq!(query.order_by[0].column, "age");
+        assert_eq!(query.order_by[0].collation, Collation::Numeric);
+        assert_eq!(query.order_by[0].direction, SortDirection::Descending);
+        assert_eq!(query.order_by[1].column, "name");
+        assert_eq!(query.order_by[1].collation, Collation::Lexicographic);
+        assert_eq!(query.order_by[1].direction, SortDirection::Ascending);
+
+        let query = parse("SELECT * FROM users ORDER BY age numeric LIMIT 5").unwrap();
+        assert_eq!(query.order_by[0].collation, Collation::Numeric);
+        assert_eq!(query.limit, Some(LimitOffset::new(5)));
+    }
+
+    #[test]
+    fn test_prepare_placeholders() {
+        let prepared =
+            prepare("SELECT * FROM text ORDER BY key LIMIT $limit OFFSET $offset").unwrap();
+        assert_eq!(prepared.query.limit, None);
+        assert_eq!(
+            prepared.limit,
+            Some(LimitValue::Placeholder("limit".to_string()))
+        );
+        assert_eq!(
+            prepared.offset,
+            LimitValue::Placeholder("offset".to_string())
+        );
+        assert_eq!(prepared.placeholders(), vec!["limit", "offset"]);
+
+        // Without placeholders a prepared query equals the parsed one
+        let prepared = prepare("SELECT * FROM text LIMIT 10 OFFSET 5").unwrap();
+        assert!(prepared.placeholders().is_empty());
+        assert_eq!(
+            prepared.to_query().unwrap(),
+            parse("SELECT * FROM text LIMIT 10 OFFSET 5").unwrap()
+        );
+
+        // Placeholders need prepare() and a name
+        assert!(parse("SELECT * FROM text LIMIT $limit").is_err());
+        assert!(prepare("SELECT * FROM text LIMIT $").is_err());
+    }
+
+    #[test]
+    fn test_parse_limit() {
+        let query = parse("SELECT * FROM text LIMIT 10").unwrap();
+        assert_eq!(query.limit, Some(LimitOffset::new(10)));
+    }
+
+    #[test]
+    fn test_parse_limit_offset() {
+        let query = parse("SELECT * FROM text LIMIT 10 OFFSET 5").unwrap();
+        assert_eq!(query.limit, Some(LimitOffset::with_offset(10, 5)));
+    }
+
+    #[test]
+    fn test_parse_count_all() {
+        let query = parse("SELECT COUNT(*) FROM text").unwrap();
+        assert!(query.has_aggregation());
+        let agg = query.aggregation.unwrap();
+        assert_eq!(agg.agg_type, AggregationType::Count);
+        assert_eq!(agg.column, "*");
+    }
+
+    #[test]
+    fn test_parse_complex_query() {
+        let query = parse(
+            "SELECT key, value FROM text WHERE namespace = 'page' AND key LIKE '%.@de' ORDER BY key ASC LIMIT 10 OFFSET 5"
+        ).unwrap();
+
+        assert_eq!(query.columns, vec!["key", "value"]);
+        assert_eq!(query.table, "text");
+        assert_eq!(query.conditions.len(), 2);
+        assert_eq!(query.order_by.len(), 1);
+        assert_eq!(query.limit, Some(LimitOffset::with_offset(10, 5)));
+    }
+
+    #[test]
+    fn test_parse_case_insensitive() {
+        let query = parse("select * from text where namespace = 'page'").unwrap();
+        assert!(query.is_select_all());
+        assert_eq!(query.table, "text");
+        assert_eq!(query.conditions.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_error_invalid_keyword() {
+        let result = parse("DELETE * FROM text");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_error_missing_from() {
+        let result = parse("SELECT * WHERE namespace = 'page'");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_column_aliases() {
+        let query = parse("SELECT key AS k, value, namespace as ns FROM text").unwrap();
+        assert_eq!(query.columns, vec!["key", "value", "namespace"]);
+        assert_eq!(
+            query.column_aliases,
+            vec![Some("k".to_string()), None, Some("ns".to_string())]
+        );
+        assert_eq!(query.output_name(0), "k");
+        assert_eq!(query.output_name(1), "value");
+    }
+
+    #[test]
+    fn test_parse_function_call() {
+        let query = parse(
+            "SELECT key, add_days(created_at, 30) AS due, days_since (created_at) FROM users",
+        )
+        .unwrap();
+        assert_eq!(
+            query.columns,
+            vec![
+                "key",
+                "add_days(created_at, '30')",
+                "days_since(created_at)"
+            ]
+        );
+        assert_eq!(query.expressions[0], None);
+        assert_eq!(
+            query.expressions[1].as_ref().map(|e| e.function.as_str()),
+            Some("add_days")
+        );
+        assert_eq!(query.output_name(1), "due");
+        assert_eq!(query.output_name(2), "days_since(created_at)");
+
+        // Commas and parentheses inside literals belong to the argument
+        let query = parse("SELECT format_date(created_at, '%d (%b)') FROM users").unwrap();
+        assert_eq!(query.columns, vec!["format_date(created_at, '%d (%b)')"]);
+
+        for bad in [
+            "SELECT shout(name) FROM users",
+            "SELECT add_days(created_at) FROM users",
+            "SELECT add_days(created_at, 30 FROM users",
+        ] {
+            assert!(
+                matches!(parse(bad), Err(ReedError::ParseError { .. })),
+                "{}",
+                bad
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_aggregation_alias() {
+        let query = parse("SELECT COUNT(*) AS total FROM text").unwrap();
+        let agg = query.aggregation.unwrap();
+        assert_eq!(agg.agg_type, AggregationType::Count);
+        assert_eq!(agg.alias, Some("total".to_string()));
+    }
+
+    #[test]
+    fn test_parse_order_by_alias_resolves_to_column() {
+        let query = parse("SELECT key AS k FROM text ORDER BY k DESC").unwrap();
+        assert_eq!(query.order_by[0].column, "key");
+        assert_eq!(query.order_by[0].direction, SortDirection::Descending);
+    }
+
+    #[test]
+    fn test_parse_error_missing_alias() {
+        assert!(parse("SELECT key AS FROM text").is_err());
+    }
+
+    #[test]
+    fn test_parse_is_empty() {
+        let query = parse("SELECT * FROM text WHERE value IS EMPTY").unwrap();
+        assert_eq!(
+            query.conditions[0],
+            FilterCondition::IsEmpty {
+                column: "value".to_string()
+            }
+        );
+
+        let query = parse("SELECT * FROM text WHERE value is not empty AND key = 'a'").unwrap();
+        assert_eq!(
+            query.conditions[0],
+            FilterCondition::IsNotEmpty {
+                column: "value".to_string()
+            }
+        );
+        assert_eq!(query.conditions.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_is_null() {
+        let query = parse("SELECT * FROM text WHERE description IS NULL").unwrap();
+        assert_eq!(
+            query.conditions[0],
+            FilterCondition::IsNull {
+                column: "description".to_string()
+            }
+        );
+        let query = parse("SELECT * FROM text WHERE description is not null").unwrap();
+        assert_eq!(
+            query.conditions[0],
+            FilterCondition::IsNotNull {
+                column: "description".to_string()
+            }
+        );
+        assert!(parse("SELECT * FROM text WHERE description IS NULLS").is_err());
+    }
+
+    #[test]
+    fn test_parse_error_is_without_empty() {
+        assert!(parse("SELECT * FROM text WHERE value IS 'x'").is_err());
+    }
+
+    #[test]
+    fn test_parse_join_on_with_aliases() {
+        let query =
+            parse("SELECT t.key, r.route FROM text t JOIN routes r ON t.key = r.text_key").unwrap();
+        assert_eq!(query.columns, vec!["t.key", "r.route"]);
+        assert_eq!(query.table, "text");
+        assert_eq!(query.table_alias, Some("t".to_string()));
+        assert_eq!(
+            query.joins,
+            vec![JoinClause {
+                table: "routes".to_string(),
+                alias: Some("r".to_string()),
+                condition: Some(FilterCondition::Equals {
+                    column: "t.key".to_string(),
+                    value: "r.text_key".to_string(),
+                }),
+                join_type: JoinType::Inner,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_left_and_natural_joins() {
+        let query = parse(
+            "SELECT * FROM text AS t LEFT OUTER JOIN routes ON t.key = routes.text_key \
+             NATURAL JOIN meta WHERE t.key LIKE 'page.%' LIMIT 5",
+        )
+        .unwrap();
+        assert_eq!(query.table_alias, Some("t".to_string()));
+        assert_eq!(query.joins.len(), 2);
+        assert_eq!(query.joins[0].join_type, JoinType::Left);
+        assert_eq!(query.joins[0].qualifier(), "routes");
+        assert_eq!(query.joins[1].join_type, JoinType::Natural);
+        assert_eq!(query.joins[1].condition, None);
+        assert_eq!(query.conditions.len(), 1);
+        assert_eq!(query.limit.unwrap().limit, 5);
+    }
+
+    #[test]
+    fn test_parse_table_without_alias_keeps_where() {
+        let query = parse("SELECT * FROM text WHERE key = 'a'").unwrap();
+        assert_eq!(query.table_alias, None);
+        assert!(query.joins.is_empty());
+        assert_eq!(query.conditions.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_error_join_without_on() {
+        assert!(parse("SELECT * FROM text JOIN routes").is_err());
+        assert!(parse("SELECT * FROM text JOIN routes r ON t.key").is_err());
+    }
+
+    fn eq(column: &str, value: &str) -> FilterCondition {
+        FilterCondition::Equals {
+            column: column.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_or() {
+        let query = parse(
+            "SELECT * FROM text WHERE namespace = 'page' OR namespace = 'global' ORDER BY key",
+        )
+        .unwrap();
+        assert_eq!(
+            query.conditions,
+            vec![FilterCondition::Or(vec![
+                eq("namespace", "page"),
+                eq("namespace", "global")
+            ])]
+        );
+        assert_eq!(query.order_by.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_and_binds_tighter_than_or() {
+        let query =
+            parse("SELECT * FROM t WHERE a = '1' AND b = '2' or c = '3' AND d = '4'").unwrap();
+        assert_eq!(
+            query.conditions,
+            vec![FilterCondition::Or(vec![
+                FilterCondition::And(vec![eq("a", "1"), eq("b", "2")]),
+                FilterCondition::And(vec![eq("c", "3"), eq("d", "4")]),
+            ])]
+        );
+    }
+
+    #[test]
+    fn test_parse_parenthesised_conditions() {
+        // Top-level AND operands stay separate conditions
+        let query = parse("SELECT * FROM t WHERE (a = '1' OR b = '2') AND c = '3'").unwrap();
+        assert_eq!(
+            query.conditions,
+            vec![
+                FilterCondition::Or(vec![eq("a", "1"), eq("b", "2")]),
+                eq("c", "3"),
+            ]
+        );
+
+        // Nested groups of the same kind are flattened
+        let query = parse("SELECT * FROM t WHERE ((a = '1') OR (b = '2' OR c = '3'))").unwrap();
+        assert_eq!(
+            query.conditions,
+            vec![FilterCondition::Or(vec![
+                eq("a", "1"),
+                eq("b", "2"),
+                eq("c", "3")
+            ])]
+        );
+
+        let query = parse("SELECT * FROM t WHERE a IN (SELECT a FROM u) OR origin = 'x'").unwrap();
+        assert!(matches!(&query.conditions[0], FilterCondition::Or(ops) if ops.len() == 2));
+    }
+
+    #[test]
+    fn test_parse_error_unbalanced_condition_group() {
+        assert!(parse("SELECT * FROM t WHERE (a = '1' OR b = '2'").is_err());
+        assert!(parse("SELECT * FROM t WHERE a = '1' OR").is_err());
+        assert!(parse("SELECT * FROM t WHERE () ").is_err());
+    }
+
+    #[test]
+    fn test_parse_not() {
+        let like = FilterCondition::Like {
+            column: "key".to_string(),
+            pattern: "%draft%".to_string(),
+        };
+        let query = parse("SELECT * FROM text WHERE NOT key LIKE '%draft%'").unwrap();
+        assert_eq!(
+            query.conditions,
+            vec![FilterCondition::Not(Box::new(like.clone()))]
+        );
+
+        // Infix NOT LIKE / NOT IN build the same tree
+        let query = parse("SELECT * FROM text WHERE key not like '%draft%'").unwrap();
+        assert_eq!(query.conditions, vec![FilterCondition::Not(Box::new(like))]);
+        let query = parse("SELECT * FROM text WHERE namespace NOT IN ('a', 'b')").unwrap();
+        assert!(matches!(
+            &query.conditions[0],
+            FilterCondition::Not(inner) if matches!(**inner, FilterCondition::InList { .. })
+        ));
+
+        // NOT binds tighter than AND
+        let query = parse("SELECT * FROM t WHERE NOT a = '1' AND b = '2'").unwrap();
+        assert_eq!(
+            query.conditions,
+            vec![FilterCondition::Not(Box::new(eq("a", "1"))), eq("b", "2")]
+        );
+        let query = parse("SELECT * FROM t WHERE NOT (a = '1' AND b = '2')").unwrap();
+        assert_eq!(
+            query.conditions,
+            vec![FilterCondition::Not(Box::new(FilterCondition::And(vec![
+                eq("a", "1"),
+                eq("b", "2")
+            ])))]
+        );
+
+        // A column whose name starts with "not" is not a negation
+        let query = parse("SELECT * FROM t WHERE notes = 'x'").unwrap();
+        assert_eq!(query.conditions, vec![eq("notes", "x")]);
+    }
+
+    #[test]
+    fn test_parse_error_not_without_like_or_in() {
+        assert!(parse("SELECT * FROM t WHERE a NOT = '1'").is_err());
+        assert!(parse("SELECT * FROM t WHERE NOT").is_err());
+    }
+
+    #[test]
+    fn test_parse_group_by() {
+        let query = parse(
+            "SELECT namespace, COUNT(*) AS n FROM text WHERE key LIKE '%@de' \
+             GROUP BY namespace HAVING n > '1' ORDER BY n DESC",
+        )
+        .unwrap();
+        assert_eq!(query.columns, vec!["namespace"]);
+        assert_eq!(query.group_by, vec!["namespace"]);
+        assert_eq!(
+            query.aggregation.as_ref().map(|a| a.output_name()),
+            Some("n".to_string())
+        );
+        assert_eq!(
+            query.having,
+            Some(FilterCondition::GreaterThan {
+                column: "n".to_string(),
+                value: "1".to_string(),
+            })
+        );
+        assert_eq!(query.conditions.len(), 1);
+
+        // GROUP is not taken for a table alias; `count` alone is a column
+        let query = parse("SELECT count FROM t GROUP BY count").unwrap();
+        assert_eq!(query.table_alias, None);
+        assert_eq!(query.aggregation, None);
+
+        // Without GROUP BY the aggregation's column is kept in `columns`
+        let query = parse("SELECT SUM(price) FROM t").unwrap();
+        assert_eq!(query.columns, vec!["price"]);
+    }
+
+    #[test]
+    fn test_parse_error_group_by() {
+        assert!(parse("SELECT namespace, COUNT(*) FROM text").is_err());
+        assert!(parse("SELECT key, COUNT(*) FROM text GROUP BY namespace").is_err());
+        assert!(parse("SELECT * FROM text GROUP BY namespace").is_err());
+        assert!(parse("SELECT COUNT(*), SUM(n) FROM t GROUP BY a").is_err());
+        assert!(parse("SELECT COUNT(*) FROM text HAVING count > '1'").is_err());
+    }
+
+    #[test]
+    fn test_parse_distinct() {
+        let query = parse("SELECT DISTINCT namespace FROM text").unwrap();
+        assert!(query.distinct);
+        assert_eq!(query.columns, vec!["namespace"]);
+
+        let query = parse("select distinct * from text").unwrap();
+        assert!(query.distinct);
+        assert!(query.is_select_all());
+
+        // A column whose name starts with "distinct" is not the keyword
+        let query = parse("SELECT distinctive FROM text").unwrap();
+        assert!(!query.distinct);
+        assert_eq!(query.columns, vec!["distinctive"]);
+    }
+
+    #[test]
+    fn test_parse_between() {
+        let between = FilterCondition::Between {
+            column: "price".to_string(),
+            low: "10".to_string(),
+            high: "20".to_string(),
+        };
+        let query = parse("SELECT * FROM t WHERE price BETWEEN '10' AND '20'").unwrap();
+        assert_eq!(query.conditions, vec![between.clone()]);
+
+        // The AND of BETWEEN does not end the condition
+        let query = parse("SELECT * FROM t WHERE price between '10' and '20' AND a = '1'").unwrap();
+        assert_eq!(query.conditions, vec![between.clone(), eq("a", "1")]);
+
+        let query = parse("SELECT * FROM t WHERE price NOT BETWEEN '10' AND '20'").unwrap();
+        assert_eq!(
+            query.conditions,
+            vec![FilterCondition::Not(Box::new(between))]
+        );
+
+        assert!(parse("SELECT * FROM t WHERE price BETWEEN '10'").is_err());
+        assert!(parse("SELECT * FROM t WHERE price BETWEEN '10' OR '20'").is_err());
+    }
+
+    #[test]
+    fn test_parse_analyze() {
+        assert_eq!(parse_analyze("ANALYZE TABLE users").unwrap(), "users");
+        assert_eq!(parse_analyze("analyze table users;").unwrap(), "users");
+
+        assert!(parse_analyze("ANALYZE").is_err());
+        assert!(parse_analyze("ANALYZE users").is_err());
+        assert!(parse_analyze("ANALYZE TABLE users extra").is_err());
+        assert!(parse_analyze("SELECT * FROM users").is_err());
+    }
+
+    #[test]
+    fn test_parse_copy() {
+        let copy = parse_copy(
+            "COPY text FROM '/data/text.csv' WITH (FORMAT CSV, DELIMITER ';', HEADER true)",
+        )
+        .unwrap();
+        assert_eq!(copy.direction, CopyDirection::From);
+        assert_eq!(copy.table, "text");
+        assert_eq!(copy.path, "/data/text.csv");
+        assert_eq!(
+            copy.options,
+            CopyOptions {
+                delimiter: b';',
+                header: true
+            }
+        );
+
+        let copy = parse_copy("copy text to 'out.tsv' (delimiter '\\t', header);").unwrap();
+        assert_eq!(copy.direction, CopyDirection::To);
+        assert_eq!(
+            copy.options,
+            CopyOptions {
+                delimiter: b'\t',
+                header: true
+            }
+        );
+
+        let copy = parse_copy("COPY text TO 'out.csv'").unwrap();
+        assert_eq!(copy.options, CopyOptions::default());
+        assert!(
+            !parse_copy("COPY text TO 'out.csv' WITH (HEADER false)")
+                .unwrap()
+                .options
+                .header
+        );
+
+        assert!(parse_copy("COPY text 'out.csv'").is_err());
+        assert!(parse_copy("COPY text TO out.csv").is_err());
+        assert!(parse_copy("COPY text TO ''").is_err());
+        assert!(parse_copy("COPY text TO 'out.csv' WITH (FORMAT BINARY)").is_err());
+        assert!(parse_copy("COPY text TO 'out.csv' WITH (DELIMITER ';;')").is_err());
+        assert!(parse_copy("COPY text TO 'out.csv' WITH (ENCODING 'UTF8')").is_err());
+        assert!(parse_copy("COPY text TO 'out.csv' WITH (HEADER").is_err());
+        assert!(parse_copy("COPY text TO 'out.csv' extra").is_err());
+    }
+
+    #[test]
+    fn test_parse_create_table() {
+        let create = parse_create_table(
+            "CREATE TABLE orders (id integer PRIMARY KEY, customer_id INT NOT NULL REFERENCES customers(id), \
+             note varchar(64), total DOUBLE PRECISION, price decimal(10, 2) NULL, paid bool UNIQUE, \
+             created_at timestamp);",
+        )
+        .unwrap();
+        assert_eq!(create.table, "orders");
+        assert_eq!(create.schema.version, "1.0");
+        assert!(create.schema.strict);
+
+        let columns: Vec<(&str, &str)> = create
+            .schema
+            .columns
+            .iter()
+            .map(|c| (c.name.as_str(), c.col_type.as_str()))
+            .collect();
+        assert_eq!(
+            columns,
+            vec![
+                ("id", "integer"),
+                ("customer_id", "integer"),
+                ("note", "string"),
+                ("total", "float"),
+                ("price", "float"),
+                ("paid", "boolean"),
+                ("created_at", "timestamp"),
+            ]
+        );
+        let columns = &create.schema.columns;
+        assert!(columns[0].primary_key && columns[0].required && columns[0].unique);
+        assert!(columns[1].required && !columns[1].unique);
+        assert_eq!(columns[1].foreign_key.as_deref(), Some("customers.id"));
+        assert_eq!(columns[2].max_length, Some(64));
+        assert!(!columns[4].required);
+        assert!(columns[5].unique);
+
+        assert!(parse_create_table("CREATE TABLE t").is_err());
+        assert!(parse_create_table("CREATE TABLE t ()").is_err());
+        assert!(parse_create_table("CREATE TABLE t (id)").is_err());
+        assert!(parse_create_table("CREATE TABLE t (id uuid)").is_err());
+        assert!(parse_create_table("CREATE TABLE t (id integer NOT)").is_err());
+        assert!(parse_create_table("CREATE TABLE t (id integer PRIMARY)").is_err());
+        assert!(parse_create_table("CREATE TABLE t (id integer, name text").is_err());
+        assert!(parse_create_table("CREATE TABLE t (id integer) extra").is_err());
+    }
+
+    #[test]
+    fn test_parse_drop_table() {
+        assert_eq!(
+            parse_drop_table("DROP TABLE users").unwrap(),
+            DropTableStatement {
+                table: "users".to_string(),
+                if_exists: false
+            }
+        );
+        assert_eq!(
+            parse_drop_table("drop table if exists users;").unwrap(),
+            DropTableStatement {
+                table: "users".to_string(),
+                if_exists: true
+            }
+        );
+
+        assert!(parse_drop_table("DROP TABLE").is_err());
+        assert!(parse_drop_table("DROP TABLE IF users").is_err());
+        assert!(parse_drop_table("DROP TABLE users extra").is_err());
+        assert!(parse_drop_table("DROP INDEX users").is_err());
+    }
+
+    #[test]
+    fn test_parse_explain() {
+        let explain = parse_explain("explain SELECT key FROM text WHERE key = 'a';").unwrap();
+        assert!(!explain.analyze);
+        assert_eq!(
+            explain.query,
+            parse("SELECT key FROM text WHERE key = 'a'").unwrap()
+        );
+        assert!(
+            parse_explain("EXPLAIN ANALYZE SELECT * FROM text")
+                .unwrap()
+                .analyze
+        );
+
+        assert!(parse_explain("EXPLAIN").is_err());
+        assert!(parse_explain("EXPLAIN ANALYZE").is_err());
+        assert!(parse_explain("EXPLAIN COST SELECT * FROM text").is_err());
+        assert!(parse_explain("SELECT * FROM text").is_err());
+    }
+
+    #[test]
+    fn test_parse_create_index() {
+        assert_eq!(
+            parse_create_index("create index on users ( country , city );").unwrap(),
+            CreateIndexStatement {
+                name: None,
+                table: "users".to_string(),
+                columns: vec!["country".to_string(), "city".to_string()],
+            }
+        );
+        assert_eq!(
+            parse_create_index("CREATE INDEX by_city ON users (city)").unwrap(),
+            CreateIndexStatement {
+                name: Some("by_city".to_string()),
+                table: "users".to_string(),
+                columns: vec!["city".to_string()],
+            }
+        );
+
+        assert!(parse_create_index("CREATE INDEX by_city users (city)").is_err());
+        assert!(parse_create_index("CREATE INDEX ON users").is_err());
+        assert!(parse_create_index("CREATE INDEX ON users ()").is_err());
+        assert!(parse_create_index("CREATE INDEX ON users (city").is_err());
+        assert!(parse_create_index("CREATE INDEX a b ON users (city)").is_err());
+    }
+
+    #[test]
+    fn test_parse_drop_index() {
+        assert_eq!(
+            parse_drop_index("DROP INDEX by_city;").unwrap(),
+            DropIndexStatement::Named("by_city".to_string())
+        );
+        assert_eq!(
+            parse_drop_index("drop index on users (country, city)").unwrap(),
+            DropIndexStatement::On {
+                table: "users".to_string(),
+                columns: vec!["country".to_string(), "city".to_string()],
+            }
+        );
+
+        assert!(parse_drop_index("DROP INDEX").is_err());
+        assert!(parse_drop_index("DROP INDEX ON users").is_err());
+        assert!(parse_drop_index("DROP INDEX by_city extra").is_err());
+        assert!(parse_drop_index("DROP TABLE users").is_err());
+    }
+
+    #[test]
+    fn test_parse_alter_table() {
+        assert_eq!(
+            parse_alter_table("alter table users rename column mail to email;").unwrap(),
+            AlterTableStatement {
+                table: "users".to_string(),
+                action: AlterTableAction::RenameColumn {
+                    from: "mail".to_string(),
+                    to: "email".to_string()
+                }
+            }
+        );
+
+        assert!(parse_alter_table("ALTER TABLE users RENAME mail TO email").is_err());
+        assert!(parse_alter_table("ALTER TABLE users RENAME COLUMN mail email").is_err());
+        assert!(parse_alter_table("ALTER TABLE users RENAME COLUMN mail TOPIC email").is_err());
+        assert!(parse_alter_table("ALTER TABLE users RENAME COLUMN mail TO").is_err());
+        assert!(parse_alter_table("ALTER TABLE users ADD COLUMN age integer").is_err());
+        assert!(parse_alter_table("ALTER TABLE users RENAME COLUMN a TO b c").is_err());
+    }
+}
@@ -10,8 +10,18 @@
 //!
 //! ## Decision Algorithm
 //! 1. Check if pattern matches available index
-//! 2. Estimate cost: index vs full scan
-//! 3. Choose strategy with lowest cost (use index if >10x faster)
+//! 2. Estimate selectivity (fraction of rows the index returns)
+//! 3. Full scan above `FULL_SCAN_BREAKEVEN`, otherwise the cheaper of
+//!    `cost_of_index_scan()` and `cost_of_full_scan()`
+//!
+//! Result sizes come from index statistics (exact key counts) when the caller
+//! provides them, otherwise from prefix/range heuristics. Table statistics
+//! gathered by `ANALYZE TABLE` (see `Statistics`) supply the page cost and
+//! the default selectivity of point lookups.
+//!
+//! Partial indices (see `QueryPlanner::with_predicate()`) only hold rows
+//! matching their predicate, so they are chosen only when the query's
+//! conditions imply that predicate.
 //!
 //! ## Performance
 //! - Planning time: < 1μs per query
@@ -19,6 +29,85 @@
 
 use crate::error::ReedResult;
 use crate::reedql::analyzer::QueryPattern;
+use crate::reedql::types::FilterCondition;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// Selectivity above which a full scan beats an index (typical B-Tree
+/// breakeven: fetching more than ~30% of rows by position costs more than
+/// reading them all in order).
+pub const FULL_SCAN_BREAKEVEN: f64 = 0.3;
+
+/// Cost of fetching one row by position, in full-scan row units.
+///
+/// Chosen so that index and full scan cost the same at `FULL_SCAN_BREAKEVEN`.
+const ROW_FETCH_COST: f64 = 1.0 / FULL_SCAN_BREAKEVEN;
+
+/// Cost of one index page read, in full-scan row units, without statistics.
+pub const DEFAULT_PAGE_COST: f64 = 1.0;
+
+/// Assumed selectivity of an index condition without statistics.
+pub const DEFAULT_INDEX_SELECTIVITY: f64 = 0.01;
+
+/// Table statistics for the planner's cost model.
+///
+/// Gathered by `ANALYZE TABLE` and stored in `tables/{name}/.stats`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Statistics {
+    /// Number of data rows
+    pub row_count: usize,
+
+    /// Fraction of rows an index condition returns (estimated from index
+    /// size: 1 / distinct keys)
+    pub index_selectivity: f64,
+
+    /// Cost of reading one page, in full-scan row units
+    pub page_cost: f64,
+
+    /// Average data row size in bytes (delimiters and line break included)
+    #[serde(default)]
+    pub avg_row_size: f64,
+
+    /// Per-column statistics (column → statistics)
+    #[serde(default)]
+    pub columns: BTreeMap<String, ColumnStatistics>,
+}
+
+/// Statistics of one table column.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ColumnStatistics {
+    /// Estimated number of distinct non-empty values (HyperLogLog)
+    pub cardinality: u64,
+
+    /// Fraction of rows with an empty value
+    pub null_fraction: f64,
+}
+
+impl Statistics {
+    /// Statistics for `row_count` rows with default selectivity and page cost.
+    pub fn new(row_count: usize) -> Self {
+        Self {
+            row_count,
+            index_selectivity: DEFAULT_INDEX_SELECTIVITY,
+            page_cost: DEFAULT_PAGE_COST,
+            avg_row_size: 0.0,
+            columns: BTreeMap::new(),
+        }
+    }
+
+    /// Fraction of rows an equality on `column` returns.
+    ///
+    /// `1 / cardinality` of the column's non-empty values when analysed,
+    /// `index_selectivity` otherwise.
+    pub fn equality_selectivity(&self, column: &str) -> f64 {
+        match self.columns.get(column) {
+            Some(stats) if stats.cardinality > 0 => {
+                (1.0 - stats.null_fraction) / stats.cardinality as f64
+            }
+            _ => self.index_selectivity,
+        }
+    }
+}
 
 /// Execution strategy chosen by planner.
 #[derive(Debug, Clone, PartialEq)]
@@ -37,10 +126,28 @@ pub enum ExecutionPlan {
     },
 }
 
+/// Source of index key counts for cost estimation.
+///
+/// Implementations return `None` when an index is unknown or cannot be
+/// counted; the planner then falls back to heuristics.
+pub trait IndexStatistics {
+    /// Number of index keys in range [start, end).
+    fn count_keys_in_range(&self, index_name: &str, start: &str, end: &str) -> Option<usize>;
+
+    /// Total number of keys in the index.
+    fn key_count(&self, index_name: &str) -> Option<usize>;
+}
+
 /// Query planner.
 pub struct QueryPlanner {
     /// Available indices (index_name → column_name).
     available_indices: Vec<(String, String)>,
+
+    /// Predicates of partial indices (index_name → predicate).
+    predicates: HashMap<String, FilterCondition>,
+
+    /// Table statistics from `ANALYZE TABLE`, if any.
+    statistics: Option<Statistics>,
 }
 
 impl QueryPlanner {
@@ -59,9 +166,83 @@ impl QueryPlanner {
     pub fn new(indices: Vec<(String, String)>) -> Self {
         Self {
             available_indices: indices,
+            predicates: HashMap::new(),
+            statistics: None,
         }
     }
 
+    /// Use table statistics for page cost and point lookup selectivity.
+    ///
+    /// The row count passed to `plan()` still takes precedence over
+    /// `statistics.row_count`, which may be stale.
+    ///
+    /// ## Example
+    /// ```rust,ignore
+    /// let planner = QueryPlanner::new(vec![("key_index".to_string(), "key".to_string())])
+    ///     .with_statistics(load_table_statistics(base_path, "text")?.unwrap_or(Statistics::new(0)));
+    /// ```
+    pub fn with_statistics(mut self, statistics: Statistics) -> Self {
+        self.statistics = Some(statistics);
+        self
+    }
+
+    /// Cost of reading every row in order.
+    ///
+    /// One unit per row; all other costs are relative to it.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use reedbase_last::reedql::planner::{QueryPlanner, Statistics};
+    ///
+    /// assert_eq!(QueryPlanner::cost_of_full_scan(&Statistics::new(1000)), 1000.0);
+    /// ```
+    pub fn cost_of_full_scan(stats: &Statistics) -> f64 {
+        stats.row_count as f64
+    }
+
+    /// Cost of an index scan returning `selectivity × row_count` rows.
+    ///
+    /// `log₂(row_count)` page reads to find the first key plus one
+    /// positional fetch per returned row. At `FULL_SCAN_BREAKEVEN` the fetches
+    /// alone cost as much as a full scan.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use reedbase_last::reedql::planner::{QueryPlanner, Statistics};
+    ///
+    /// let stats = Statistics::new(1_000_000);
+    /// assert!(
+    ///     QueryPlanner::cost_of_index_scan(&stats, 0.01)
+    ///         < QueryPlanner::cost_of_full_scan(&stats)
+    /// );
+    /// assert!(
+    ///     QueryPlanner::cost_of_index_scan(&stats, 0.5)
+    ///         > QueryPlanner::cost_of_full_scan(&stats)
+    /// );
+    /// ```
+    pub fn cost_of_index_scan(stats: &Statistics, selectivity: f64) -> f64 {
+        let rows = stats.row_count as f64;
+        let traversal = if rows > 1.0 { rows.log2() } else { 0.0 };
+
+        traversal * stats.page_cost + selectivity * rows * ROW_FETCH_COST
+    }
+
+    /// Mark an index as partial.
+    ///
+    /// The index then only qualifies for queries whose conditions imply
+    /// `predicate` (see `conditions_imply()`); other queries fall back to a
+    /// full scan or another index on the same column.
+    ///
+    /// ## Example
+    /// ```rust,ignore
+    /// let planner = QueryPlanner::new(vec![("live_keys".to_string(), "key".to_string())])
+    ///     .with_predicate("live_keys", parse_condition("deleted IS EMPTY")?);
+    /// ```
+    pub fn with_predicate(mut self, index_name: &str, predicate: FilterCondition) -> Self {
+        self.predicates.insert(index_name.to_string(), predicate);
+        self
+    }
+
     /// Create execution plan from query pattern.
     ///
     /// ## Algorithm
@@ -70,9 +251,9 @@ impl QueryPlanner {
     /// 3. Choose strategy with lowest cost
     ///
     /// ## Cost Model
-    /// - Index cost: log₂(table_size) + estimated_results
+    /// - Index cost: log₂(table_size) × page_cost + results × row fetch cost
     /// - Full scan cost: table_size
-    /// - Decision: Use index if >10x faster
+    /// - Decision: Full scan above 30% selectivity, else the cheaper plan
     ///
     /// ## Performance
     /// - <1μs planning time
@@ -87,31 +268,101 @@ impl QueryPlanner {
     /// // plan = IndexRangeScan { ... } (if cost-effective)
     /// ```
     pub fn plan(&self, pattern: &QueryPattern, table_size: usize) -> ReedResult<ExecutionPlan> {
+        self.plan_with_statistics(pattern, table_size, None)
+    }
+
+    /// Create execution plan using index statistics for result estimates.
+    ///
+    /// Same algorithm as `plan()`, but range and prefix estimates are derived
+    /// from `count_keys_in_range / key_count × table_size` when `statistics`
+    /// can answer for the chosen index.
+    ///
+    /// ## Arguments
+    /// - `pattern`: Detected query pattern
+    /// - `table_size`: Number of rows in the table
+    /// - `statistics`: Optional key count source (`None` = heuristics only)
+    ///
+    /// ## Performance
+    /// - Heuristics: <1μs
+    /// - With B+-Tree statistics: O(log n + m) key-only scan per plan
+    pub fn plan_with_statistics(
+        &self,
+        pattern: &QueryPattern,
+        table_size: usize,
+        statistics: Option<&dyn IndexStatistics>,
+    ) -> ReedResult<ExecutionPlan> {
+        self.plan_with_conditions(pattern, &[], table_size, statistics)
+    }
+
+    /// Create execution plan, considering partial indices.
+    ///
+    /// Same as `plan_with_statistics()`, but a partial index is used if
+    /// `conditions` (the query's top-level AND conditions) imply its
+    /// predicate. Without conditions only full indices are used.
+    ///
+    /// ## Arguments
+    /// - `pattern`: Detected query pattern
+    /// - `conditions`: WHERE conditions of the query
+    /// - `table_size`: Number of rows in the table
+    /// - `statistics`: Optional key count source (`None` = heuristics only)
+    ///
+    /// ## Performance
+    /// - Adds O(c × p) implication checks, c = conditions, p = predicate size
+    pub fn plan_with_conditions(
+        &self,
+        pattern: &QueryPattern,
+        conditions: &[FilterCondition],
+        table_size: usize,
+        statistics: Option<&dyn IndexStatistics>,
+    ) -> ReedResult<ExecutionPlan> {
         match pattern {
             QueryPattern::FullScan => Ok(ExecutionPlan::FullScan),
 
             QueryPattern::PointLookup { column, value } => {
                 // Find index on this column
-                if let Some((index_name, _)) = self.find_index_for_column(column) {
-                    // Cost check: index almost always wins for point lookup
-                    Ok(ExecutionPlan::IndexPointLookup {
-                        index_name: index_name.clone(),
-                        key: value.clone(),
-                    })
+                if let Some((index_name, _)) = self.find_index_for_column(column, conditions) {
+                    // One key: rows per key from the index size, else from
+                    // the analysed column cardinality
+                    let selectivity = statistics
+                        .and_then(|stats| stats.key_count(index_name))
+                        .map(|keys| 1.0 / keys.max(1) as f64)
+                        .or_else(|| {
+                            self.statistics
+                                .as_ref()
+                                .map(|stats| stats.equality_selectivity(column))
+                        });
+
+                    // Without any statistics the index almost always wins
+                    if selectivity.is_none_or(|s| self.should_use_index(table_size, s)) {
+                        Ok(ExecutionPlan::IndexPointLookup {
+                            index_name: index_name.clone(),
+                            key: value.clone(),
+                        })
+                    } else {
+                        Ok(ExecutionPlan::FullScan)
+                    }
                 } else {
                     Ok(ExecutionPlan::FullScan)
                 }
             }
 
             QueryPattern::PrefixScan { column, prefix } => {
-                if let Some((index_name, _)) = self.find_index_for_column(column) {
-                    // Estimate result size from prefix length
-                    let estimated_results = Self::estimate_prefix_results(prefix, table_size);
+                if let Some((index_name, _)) = self.find_index_for_column(column, conditions) {
+                    // Create range: ['prefix', 'prefix~')
+                    let end = format!("{}~", prefix); // ASCII '~' > all alphanumeric
 
-                    if Self::should_use_index(table_size, estimated_results) {
-                        // Create range: ['prefix', 'prefix~')
-                        let end = format!("{}~", prefix); // ASCII '~' > all alphanumeric
+                    // Estimate result size from index statistics or prefix length
+                    let estimated_results = statistics
+                        .and_then(|stats| {
+                            Self::estimate_from_statistics(
+                                stats, index_name, prefix, &end, table_size,
+                            )
+                        })
+                        .unwrap_or_else(|| Self::estimate_prefix_results(prefix, table_size));
 
+                    if self
+                        .should_use_index(table_size, Self::fraction(estimated_results, table_size))
+                    {
                         Ok(ExecutionPlan::IndexRangeScan {
                             index_name: index_name.clone(),
                             start: prefix.clone(),
@@ -128,11 +379,20 @@ impl QueryPlanner {
             QueryPattern::RangeScan {
                 column, start, end, ..
             } => {
-                if let Some((index_name, _)) = self.find_index_for_column(column) {
-                    // Estimate result size from range width (conservative: 1% of table)
-                    let estimated_results = table_size / 100;
+                if let Some((index_name, _)) = self.find_index_for_column(column, conditions) {
+                    // Estimate result size from index statistics
+                    // (fallback: conservative 1% of table)
+                    let estimated_results = statistics
+                        .and_then(|stats| {
+                            Self::estimate_from_statistics(
+                                stats, index_name, start, end, table_size,
+                            )
+                        })
+                        .unwrap_or(table_size / 100);
 
-                    if Self::should_use_index(table_size, estimated_results) {
+                    if self
+                        .should_use_index(table_size, Self::fraction(estimated_results, table_size))
+                    {
                         Ok(ExecutionPlan::IndexRangeScan {
                             index_name: index_name.clone(),
                             start: start.clone(),
@@ -148,8 +408,18 @@ impl QueryPlanner {
         }
     }
 
-    fn find_index_for_column(&self, column: &str) -> Option<&(String, String)> {
-        self.available_indices.iter().find(|(_, col)| col == column)
+    fn find_index_for_column(
+        &self,
+        column: &str,
+        conditions: &[FilterCondition],
+    ) -> Option<&(String, String)> {
+        self.available_indices.iter().find(|(name, col)| {
+            col == column
+                && self
+                    .predicates
+                    .get(name)
+                    .is_none_or(|predicate| conditions_imply(conditions, predicate))
+        })
     }
 
     fn estimate_prefix_results(prefix: &str, table_size: usize) -> usize {
@@ -164,11 +434,130 @@ impl QueryPlanner {
         }
     }
 
-    fn should_use_index(table_size: usize, estimated_results: usize) -> bool {
-        let index_cost = (table_size as f64).log2() + estimated_results as f64;
-        let scan_cost = table_size as f64;
+    fn estimate_from_statistics(
+        statistics: &dyn IndexStatistics,
+        index_name: &str,
+        start: &str,
+        end: &str,
+        table_size: usize,
+    ) -> Option<usize> {
+        let total_keys = statistics.key_count(index_name)?;
+        if total_keys == 0 {
+            return Some(0);
+        }
+        let keys_in_range = statistics.count_keys_in_range(index_name, start, end)?;
+
+        // Scale key count to rows (keys may map to several rows)
+        Some((keys_in_range as f64 / total_keys as f64 * table_size as f64).ceil() as usize)
+    }
+
+    fn fraction(estimated_results: usize, table_size: usize) -> f64 {
+        if table_size == 0 {
+            return 0.0;
+        }
+        estimated_results as f64 / table_size as f64
+    }
+
+    fn should_use_index(&self, table_size: usize, selectivity: f64) -> bool {
+        if selectivity > FULL_SCAN_BREAKEVEN {
+            return false;
+        }
+
+        let stats = Statistics {
+            index_selectivity: selectivity,
+            page_cost: self
+                .statistics
+                .as_ref()
+                .map_or(DEFAULT_PAGE_COST, |s| s.page_cost),
+            ..Statistics::new(table_size)
+        };
+        Self::cost_of_index_scan(&stats, selectivity) < Self::cost_of_full_scan(&stats)
+    }
+}
+
+/// Whether rows matching all `conditions` are guaranteed to match `predicate`.
+///
+/// Conservative: `false` means the implication could not be shown, not
+/// that it is false. Recognised cases:
+/// - A condition equal to the predicate (or one of its AND operands)
+/// - `column = 'v'` / `column IN (...)` pinning a column to values that all
+///   satisfy a predicate on that column only (e.g. `env = 'prod'` implies
+///   `env != 'dev'` and `env IS NOT EMPTY`)
+/// - AND predicates (every operand implied) and OR predicates (any operand
+///   implied, or the pinned values satisfy the whole OR); OR conditions imply
+///   what each of their operands implies
+///
+/// ## Arguments
+/// - `conditions`: Top-level AND conditions of a query
+/// - `predicate`: Predicate of a partial index
+///
+/// ## Example
+/// ```rust
+/// use reedbase_last::reedql::parser::parse_condition;
+/// use reedbase_last::reedql::planner::conditions_imply;
+///
+/// let predicate = parse_condition("environment = 'prod'")?;
+/// let conditions = vec![
+///     parse_condition("key LIKE 'page.%'")?,
+///     parse_condition("environment IN ('prod')")?,
+/// ];
+/// assert!(conditions_imply(&conditions, &predicate));
+/// assert!(!conditions_imply(&conditions[..1], &predicate));
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn conditions_imply(conditions: &[FilterCondition], predicate: &FilterCondition) -> bool {
+    match predicate {
+        FilterCondition::And(operands) => operands
+            .iter()
+            .all(|operand| conditions_imply(conditions, operand)),
+        FilterCondition::Or(operands)
+            if operands
+                .iter()
+                .any(|operand| conditions_imply(conditions, operand)) =>
+        {
+            true
+        }
+        _ => conditions
+            .iter()
+            .any(|condition| condition_implies(condition, predicate)),
+    }
+}
+
+/// Whether one condition implies a predicate that is not an AND/OR group.
+fn condition_implies(condition: &FilterCondition, predicate: &FilterCondition) -> bool {
+    if condition == predicate {
+        return true;
+    }
+
+    let (column, values): (&str, Vec<&String>) = match condition {
+        FilterCondition::Equals { column, value } => (column, vec![value]),
+        FilterCondition::InList { column, values } => (column, values.iter().collect()),
+        FilterCondition::And(operands) => {
+            return operands.iter().any(|c| condition_implies(c, predicate));
+        }
+        FilterCondition::Or(operands) => {
+            return !operands.is_empty()
+                && operands.iter().all(|c| condition_implies(c, predicate));
+        }
+        _ => return false,
+    };
+
+    // Every value the column can take must satisfy the predicate
+    references_only(predicate, column)
+        && values.into_iter().all(|value| {
+            let row = HashMap::from([(column.to_string(), value.clone())]);
+            predicate.matches(&row).unwrap_or(false)
+        })
+}
 
-        // Use index if >10x faster
-        index_cost * 10.0 < scan_cost
+/// Whether a condition reads no column other than `column`.
+fn references_only(condition: &FilterCondition, column: &str) -> bool {
+    match condition {
+        FilterCondition::Or(operands) | FilterCondition::And(operands) => operands
+            .iter()
+            .all(|operand| references_only(operand, column)),
+        FilterCondition::Not(inner) => references_only(inner, column),
+        FilterCondition::InSubquery { .. } => false,
+        other => other.column() == column,
     }
 }
@@ -4,7 +4,28 @@
 #[cfg(test)]
 mod tests {
     use crate::reedql::analyzer::QueryPattern;
-    use crate::reedql::planner::{ExecutionPlan, QueryPlanner};
+    use crate::reedql::parser::parse_condition;
+    use crate::reedql::planner::{
+        conditions_imply, ColumnStatistics, ExecutionPlan, IndexStatistics, QueryPlanner,
+        Statistics, FULL_SCAN_BREAKEVEN,
+    };
+
+    /// Fixed key counts for a single index.
+    struct FixedStatistics {
+        index_name: &'static str,
+        in_range: usize,
+        total: usize,
+    }
+
+    impl IndexStatistics for FixedStatistics {
+        fn count_keys_in_range(&self, index_name: &str, _start: &str, _end: &str) -> Option<usize> {
+            (index_name == self.index_name).then_some(self.in_range)
+        }
+
+        fn key_count(&self, index_name: &str) -> Option<usize> {
+            (index_name == self.index_name).then_some(self.total)
+        }
+    }
 
     fn create_planner_with_key_index() -> QueryPlanner {
         QueryPlanner::new(vec![("hierarchy_index".to_string(), "key".to_string())])
@@ -73,11 +94,29 @@ mod tests {
         let planner = create_planner_with_key_index();
         let pattern = QueryPattern::PrefixScan {
             column: "key".to_string(),
-            prefix: "p".to_string(), // Very broad (10% estimated)
+            prefix: "p".to_string(), // Very broad
         };
-        let plan = planner.plan(&pattern, 1_000).unwrap();
-        // For small tables, full scan is faster
+
+        // Above the 30% breakeven a full scan is cheaper
+        let stats = FixedStatistics {
+            index_name: "hierarchy_index",
+            in_range: 310,
+            total: 1000,
+        };
+        let plan = planner
+            .plan_with_statistics(&pattern, 1_000_000, Some(&stats))
+            .unwrap();
         assert_eq!(plan, ExecutionPlan::FullScan);
+
+        // Just below it the index still wins
+        let stats = FixedStatistics {
+            in_range: 290,
+            ..stats
+        };
+        let plan = planner
+            .plan_with_statistics(&pattern, 1_000_000, Some(&stats))
+            .unwrap();
+        assert!(matches!(plan, ExecutionPlan::IndexRangeScan { .. }));
     }
 
     #[test]
@@ -195,4 +234,249 @@ mod tests {
             _ => panic!("Expected index range scan"),
         }
     }
+
+    #[test]
+    fn test_plan_statistics_reject_unselective_prefix() {
+        let planner = create_planner_with_key_index();
+        let pattern = QueryPattern::PrefixScan {
+            column: "key".to_string(),
+            prefix: "page.header.logo.".to_string(), // Heuristic: 0.01%
+        };
+
+        // Heuristic alone picks the index
+        assert!(matches!(
+            planner.plan(&pattern, 1_000_000).unwrap(),
+            ExecutionPlan::IndexRangeScan { .. }
+        ));
+
+        // Actual counts: 60% of keys match, full scan is cheaper
+        let stats = FixedStatistics {
+            index_name: "hierarchy_index",
+            in_range: 600,
+            total: 1000,
+        };
+        let plan = planner
+            .plan_with_statistics(&pattern, 1_000_000, Some(&stats))
+            .unwrap();
+        assert_eq!(plan, ExecutionPlan::FullScan);
+    }
+
+    #[test]
+    fn test_plan_statistics_accept_selective_range() {
+        let planner = create_planner_with_key_index();
+        let pattern = QueryPattern::RangeScan {
+            column: "key".to_string(),
+            start: "a".to_string(),
+            end: "b".to_string(),
+            inclusive_start: true,
+            inclusive_end: false,
+        };
+
+        // Actual counts: 5 of 1000 keys, scaled to 5000 rows
+        let stats = FixedStatistics {
+            index_name: "hierarchy_index",
+            in_range: 5,
+            total: 1000,
+        };
+        let plan = planner
+            .plan_with_statistics(&pattern, 1_000_000, Some(&stats))
+            .unwrap();
+        assert!(matches!(plan, ExecutionPlan::IndexRangeScan { .. }));
+    }
+
+    #[test]
+    fn test_plan_statistics_unknown_index_falls_back() {
+        let planner = create_planner_with_key_index();
+        let pattern = QueryPattern::PrefixScan {
+            column: "key".to_string(),
+            prefix: "page.header.".to_string(),
+        };
+        let stats = FixedStatistics {
+            index_name: "other_index",
+            in_range: 1000,
+            total: 1000,
+        };
+        assert_eq!(
+            planner
+                .plan_with_statistics(&pattern, 1_000_000, Some(&stats))
+                .unwrap(),
+            planner.plan(&pattern, 1_000_000).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_conditions_imply() {
+        let predicate = parse_condition("status = 'active'").unwrap();
+        let implied = |conditions: &[&str]| {
+            let conditions: Vec<_> = conditions
+                .iter()
+                .map(|c| parse_condition(c).unwrap())
+                .collect();
+            conditions_imply(&conditions, &predicate)
+        };
+
+        assert!(implied(&["status = 'active'"]));
+        assert!(implied(&["key = 'a'", "status = 'active'"]));
+        assert!(implied(&["status IN ('active')"]));
+        assert!(!implied(&["status IN ('active', 'draft')"]));
+        assert!(!implied(&["status = 'draft'"]));
+        assert!(!implied(&["key = 'a'"]));
+        assert!(!implied(&[]));
+
+        // Ranges and OR groups are evaluated against the pinned value
+        let predicate = parse_condition("age > '18' AND (lang = 'de' OR lang = 'en')").unwrap();
+        let conditions = vec![
+            parse_condition("age = '30'").unwrap(),
+            parse_condition("lang IN ('de', 'en')").unwrap(),
+        ];
+        assert!(conditions_imply(&conditions, &predicate));
+        let conditions = vec![
+            parse_condition("age = '30'").unwrap(),
+            parse_condition("lang = 'fr'").unwrap(),
+        ];
+        assert!(!conditions_imply(&conditions, &predicate));
+    }
+
+    #[test]
+    fn test_plan_partial_index() {
+        let planner = create_planner_with_key_index().with_predicate(
+            "hierarchy_index",
+            parse_condition("status = 'active'").unwrap(),
+        );
+        let pattern = QueryPattern::PointLookup {
+            column: "key".to_string(),
+            value: "page.header.title".to_string(),
+        };
+
+        // Predicate implied: index covers every candidate row
+        let conditions = vec![
+            parse_condition("key = 'page.header.title'").unwrap(),
+            parse_condition("status = 'active'").unwrap(),
+        ];
+        assert!(matches!(
+            planner
+                .plan_with_conditions(&pattern, &conditions, 1_000_000, None)
+                .unwrap(),
+            ExecutionPlan::IndexPointLookup { .. }
+        ));
+
+        // Predicate not implied: rows outside the index could match
+        assert_eq!(
+            planner
+                .plan_with_conditions(&pattern, &conditions[..1], 1_000_000, None)
+                .unwrap(),
+            ExecutionPlan::FullScan
+        );
+        assert_eq!(
+            planner.plan(&pattern, 1_000_000).unwrap(),
+            ExecutionPlan::FullScan
+        );
+    }
+
+    #[test]
+    fn test_cost_model_breakeven() {
+        let stats = Statistics::new(1_000_000);
+        let full = QueryPlanner::cost_of_full_scan(&stats);
+        assert_eq!(full, 1_000_000.0);
+
+        // Fetches alone match a full scan at the breakeven selectivity
+        let fetch_only = Statistics {
+            page_cost: 0.0,
+            ..stats.clone()
+        };
+        let at_breakeven = QueryPlanner::cost_of_index_scan(&fetch_only, FULL_SCAN_BREAKEVEN);
+        assert!((at_breakeven - full).abs() < 1e-6);
+
+        assert!(QueryPlanner::cost_of_index_scan(&stats, 0.01) < full);
+        assert!(QueryPlanner::cost_of_index_scan(&stats, 0.35) > full);
+
+        // Expensive pages make the traversal count
+        let slow_pages = Statistics {
+            page_cost: 100_000.0,
+            ..stats
+        };
+        assert!(QueryPlanner::cost_of_index_scan(&slow_pages, 0.0) > full);
+    }
+
+    #[test]
+    fn test_plan_point_lookup_selectivity() {
+        let planner = create_planner_with_key_index();
+        let pattern = QueryPattern::PointLookup {
+            column: "key".to_string(),
+            value: "page".to_string(),
+        };
+
+        // Two distinct keys: each returns half the table
+        let stats = FixedStatistics {
+            index_name: "hierarchy_index",
+            in_range: 0,
+            total: 2,
+        };
+        assert_eq!(
+            planner
+                .plan_with_statistics(&pattern, 1_000_000, Some(&stats))
+                .unwrap(),
+            ExecutionPlan::FullScan
+        );
+
+        // Analysed table statistics apply without key counts
+        let analysed = |index_selectivity: f64| {
+            create_planner_with_key_index().with_statistics(Statistics {
+                index_selectivity,
+                ..Statistics::new(1_000_000)
+            })
+        };
+        assert_eq!(
+            analysed(0.5).plan(&pattern, 1_000_000).unwrap(),
+            ExecutionPlan::FullScan
+        );
+        assert!(matches!(
+            analysed(0.001).plan(&pattern, 1_000_000).unwrap(),
+            ExecutionPlan::IndexPointLookup { .. }
+        ));
+    }
+
+    #[test]
+    fn test_plan_point_lookup_column_cardinality() {
+        let pattern = QueryPattern::PointLookup {
+            column: "key".to_string(),
+            value: "page".to_string(),
+        };
+        let analysed = |cardinality: u64, null_fraction: f64| {
+            let mut stats = Statistics {
+                index_selectivity: 0.5,
+                ..Statistics::new(1_000_000)
+            };
+            stats.columns.insert(
+                "key".to_string(),
+                ColumnStatistics {
+                    cardinality,
+                    null_fraction,
+                },
+            );
+            stats
+        };
+
+        // Column cardinality takes precedence over the index average
+        let stats = analysed(10_000, 0.0);
+        assert_eq!(stats.equality_selectivity("key"), 0.0001);
+        assert_eq!(stats.equality_selectivity("other"), 0.5);
+        assert!(matches!(
+            create_planner_with_key_index()
+                .with_statistics(stats)
+                .plan(&pattern, 1_000_000)
+                .unwrap(),
+            ExecutionPlan::IndexPointLookup { .. }
+        ));
+
+        // Empty values never match an equality
+        assert_eq!(analysed(2, 0.5).equality_selectivity("key"), 0.25);
+        assert_eq!(
+            create_planner_with_key_index()
+                .with_statistics(analysed(2, 0.0))
+                .plan(&pattern, 1_000_000)
+                .unwrap(),
+            ExecutionPlan::FullScan
+        );
+    }
 }
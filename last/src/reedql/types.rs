@@ -13,6 +13,9 @@
 //! - Direct mapping to ReedBase operations
 
 use crate::error::{ReedError, ReedResult};
+use crate::schema::{ComputedExpr, Schema};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt;
 
 /// Parsed ReedQL query structure.
@@ -32,12 +35,34 @@ pub struct ParsedQuery {
     /// Selected columns (* or specific column names)
     pub columns: Vec<String>,
 
+    /// `SELECT DISTINCT`: drop duplicate result rows (ignored for aggregations)
+    pub distinct: bool,
+
+    /// Output names from `AS` clauses, parallel to `columns` (None = raw name)
+    pub column_aliases: Vec<Option<String>>,
+
+    /// Function calls in the select list, parallel to `columns` (None = plain
+    /// column); `columns` then holds the call's canonical text
+    pub expressions: Vec<Option<ComputedExpr>>,
+
     /// Table name (always "text", "routes", "meta", "server", or "project")
     pub table: String,
 
+    /// Alias for the FROM table (`FROM text t` → `t`)
+    pub table_alias: Option<String>,
+
+    /// JOIN clauses in query order (empty = single-table query)
+    pub joins: Vec<JoinClause>,
+
     /// WHERE clause conditions (empty = no filter)
     pub conditions: Vec<FilterCondition>,
 
+    /// GROUP BY columns (empty = no grouping)
+    pub group_by: Vec<String>,
+
+    /// HAVING condition on group rows (None = keep all groups)
+    pub having: Option<FilterCondition>,
+
     /// ORDER BY clauses (empty = no sorting)
     pub order_by: Vec<OrderBy>,
 
@@ -53,8 +78,15 @@ impl ParsedQuery {
     pub fn new() -> Self {
         Self {
             columns: Vec::new(),
+            distinct: false,
+            column_aliases: Vec::new(),
+            expressions: Vec::new(),
             table: String::new(),
+            table_alias: None,
+            joins: Vec::new(),
             conditions: Vec::new(),
+            group_by: Vec::new(),
+            having: None,
             order_by: Vec::new(),
             limit: None,
             aggregation: None,
@@ -66,15 +98,41 @@ impl ParsedQuery {
         self.columns.len() == 1 && self.columns[0] == "*"
     }
 
+    /// Returns the output name of the selected column at `index`.
+    ///
+    /// The alias if one was given (`key AS k` → `k`), otherwise the raw
+    /// column name.
+    pub fn output_name(&self, index: usize) -> &str {
+        match self.column_aliases.get(index) {
+            Some(Some(alias)) => alias,
+            _ => &self.columns[index],
+        }
+    }
+
     /// Returns true if query has aggregation.
     pub fn has_aggregation(&self) -> bool {
         self.aggregation.is_some()
     }
 
+    /// Returns true if query has a GROUP BY clause.
+    pub fn has_grouping(&self) -> bool {
+        !self.group_by.is_empty()
+    }
+
     /// Returns true if query has WHERE clause.
     pub fn has_conditions(&self) -> bool {
         !self.conditions.is_empty()
     }
+
+    /// Returns true if query joins other tables.
+    pub fn has_joins(&self) -> bool {
+        !self.joins.is_empty()
+    }
+
+    /// Name that qualifies the FROM table's columns in joined rows.
+    pub fn qualifier(&self) -> &str {
+        self.table_alias.as_deref().unwrap_or(&self.table)
+    }
 }
 
 impl Default for ParsedQuery {
@@ -83,6 +141,62 @@ impl Default for ParsedQuery {
     }
 }
 
+/// JOIN clause: `[INNER|LEFT|NATURAL] JOIN table [alias] [ON a = b]`.
+///
+/// ## Column References
+/// Both sides of the ON condition are column names: the condition's
+/// `column` holds the left operand and its `value` the right operand
+/// (`ON t.key = r.text_key` → `Equals { column: "t.key", value: "r.text_key" }`).
+///
+/// ## Example
+/// ```text
+/// SELECT t.key, r.route FROM text t JOIN routes r ON t.key = r.text_key
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct JoinClause {
+    /// Joined table name
+    pub table: String,
+
+    /// Alias for the joined table (None = qualify by table name)
+    pub alias: Option<String>,
+
+    /// ON condition (None for NATURAL JOIN, which matches shared columns)
+    pub condition: Option<FilterCondition>,
+
+    /// Join semantics
+    pub join_type: JoinType,
+}
+
+impl JoinClause {
+    /// Name that qualifies this table's columns in joined rows.
+    pub fn qualifier(&self) -> &str {
+        self.alias.as_deref().unwrap_or(&self.table)
+    }
+}
+
+/// Join semantics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinType {
+    /// Only row pairs matching the ON condition
+    Inner,
+
+    /// Every left row, with right columns absent when nothing matches
+    Left,
+
+    /// Inner join on all columns both sides share
+    Natural,
+}
+
+impl fmt::Display for JoinType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JoinType::Inner => write!(f, "JOIN"),
+            JoinType::Left => write!(f, "LEFT JOIN"),
+            JoinType::Natural => write!(f, "NATURAL JOIN"),
+        }
+    }
+}
+
 /// Filter condition for WHERE clause.
 ///
 /// Supports common SQL operators plus ReedBase-specific optimizations.
@@ -91,7 +205,13 @@ impl Default for ParsedQuery {
 /// - Fast path for key patterns: `key LIKE '%.@de'` → O(n) string check
 /// - Fast path for namespace: `namespace = 'page'` → O(1) index lookup
 /// - Generic conditions: O(n) table scan
-#[derive(Debug, Clone, PartialEq)]
+///
+/// ## Serialisation
+/// Serialises as its ReedQL text (e.g. `"environment = 'prod'"`, see
+/// `format::format_condition()`), so it can be stored in TOML and JSON
+/// configuration.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
 pub enum FilterCondition {
     /// Equality: column = value
     Equals { column: String, value: String },
@@ -118,15 +238,54 @@ pub enum FilterCondition {
     /// IN clause with literal values: column IN ('a', 'b', 'c')
     InList { column: String, values: Vec<String> },
 
+    /// Blank check: column IS EMPTY
+    /// True when the column is absent or its value is `""`
+    IsEmpty { column: String },
+
+    /// Non-blank check: column IS NOT EMPTY
+    /// True when the column is present with a non-empty value
+    IsNotEmpty { column: String },
+
+    /// NULL check: column IS NULL
+    /// CSV has no separate NULL, so this is true exactly when `IsEmpty` is
+    IsNull { column: String },
+
+    /// Non-NULL check: column IS NOT NULL (true exactly when `IsNotEmpty` is)
+    IsNotNull { column: String },
+
     /// IN clause with subquery: column IN (SELECT ...)
     InSubquery {
         column: String,
         subquery: Box<ParsedQuery>,
     },
+
+    /// Disjunction: a OR b OR ...
+    /// True when any operand is true (evaluated left to right, short-circuit)
+    Or(Vec<FilterCondition>),
+
+    /// Conjunction: a AND b AND ...
+    /// Only needed inside `Or`; top-level AND operands are separate
+    /// entries of `ParsedQuery::conditions`
+    And(Vec<FilterCondition>),
+
+    /// Inclusive range: column BETWEEN low AND high
+    /// Numeric when both bounds parse as numbers, lexicographic otherwise
+    Between {
+        column: String,
+        low: String,
+        high: String,
+    },
+
+    /// Negation: NOT a
+    /// Also produced by `column NOT LIKE ...` and `column NOT IN (...)`
+    Not(Box<FilterCondition>),
 }
 
 impl FilterCondition {
     /// Returns the column name referenced by this condition.
+    ///
+    /// For `Or` and `And` this is the column of the first operand, for
+    /// `Not` the column of the negated condition.
     pub fn column(&self) -> &str {
         match self {
             FilterCondition::Equals { column, .. }
@@ -137,10 +296,41 @@ impl FilterCondition {
             | FilterCondition::GreaterThanOrEqual { column, .. }
             | FilterCondition::Like { column, .. }
             | FilterCondition::InList { column, .. }
+            | FilterCondition::IsEmpty { column }
+            | FilterCondition::IsNotEmpty { column }
+            | FilterCondition::IsNull { column }
+            | FilterCondition::IsNotNull { column }
+            | FilterCondition::Between { column, .. }
             | FilterCondition::InSubquery { column, .. } => column,
+            FilterCondition::Or(operands) | FilterCondition::And(operands) => {
+                operands.first().map_or("", |c| c.column())
+            }
+            FilterCondition::Not(inner) => inner.column(),
         }
     }
 
+    /// Evaluates this condition for a single row.
+    ///
+    /// Same semantics as a WHERE condition in `execute()`; a missing column
+    /// compares like an absent value.
+    ///
+    /// ## Error Conditions
+    /// - `ParseError`: Unresolved subquery or invalid LIKE pattern
+    ///
+    /// ## Example
+    /// ```rust
+    /// use reedbase_last::reedql::parser::parse_condition;
+    /// use std::collections::HashMap;
+    ///
+    /// let condition = parse_condition("environment = 'prod'")?;
+    /// let row = HashMap::from([("environment".to_string(), "prod".to_string())]);
+    /// assert!(condition.matches(&row)?);
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn matches(&self, row: &HashMap<String, String>) -> ReedResult<bool> {
+        crate::reedql::executor::evaluate_condition(self, row)
+    }
+
     /// Checks if this is a ReedBase-optimized fast path condition.
     ///
     /// Fast paths:
@@ -158,6 +348,20 @@ impl FilterCondition {
     }
 }
 
+impl From<FilterCondition> for String {
+    fn from(condition: FilterCondition) -> Self {
+        crate::reedql::format::format_condition(&condition)
+    }
+}
+
+impl TryFrom<String> for FilterCondition {
+    type Error = ReedError;
+
+    fn try_from(text: String) -> ReedResult<Self> {
+        crate::reedql::parser::parse_condition(&text)
+    }
+}
+
 impl fmt::Display for FilterCondition {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -179,9 +383,35 @@ impl fmt::Display for FilterCondition {
             FilterCondition::InList { column, values } => {
                 write!(f, "{} IN ({})", column, values.join(", "))
             }
+            FilterCondition::IsEmpty { column } => write!(f, "{} IS EMPTY", column),
+            FilterCondition::IsNotEmpty { column } => write!(f, "{} IS NOT EMPTY", column),
+            FilterCondition::IsNull { column } => write!(f, "{} IS NULL", column),
+            FilterCondition::IsNotNull { column } => write!(f, "{} IS NOT NULL", column),
+            FilterCondition::Between { column, low, high } => {
+                write!(f, "{} BETWEEN '{}' AND '{}'", column, low, high)
+            }
             FilterCondition::InSubquery { column, subquery } => {
-                write!(f, "{} IN ({:?})", column, subquery)
+                write!(f, "{} IN ({})", column, subquery)
             }
+            FilterCondition::Or(operands) => {
+                let operands: Vec<String> = operands.iter().map(|c| c.to_string()).collect();
+                write!(f, "{}", operands.join(" OR "))
+            }
+            FilterCondition::And(operands) => {
+                // AND binds tighter than OR, so only nested OR needs parentheses
+                let operands: Vec<String> = operands
+                    .iter()
+                    .map(|c| match c {
+                        FilterCondition::Or(_) => format!("({})", c),
+                        _ => c.to_string(),
+                    })
+                    .collect();
+                write!(f, "{}", operands.join(" AND "))
+            }
+            FilterCondition::Not(inner) => match **inner {
+                FilterCondition::Or(_) | FilterCondition::And(_) => write!(f, "NOT ({})", inner),
+                _ => write!(f, "NOT {}", inner),
+            },
         }
     }
 }
@@ -194,12 +424,25 @@ pub struct OrderBy {
 
     /// Sort direction (ASC or DESC)
     pub direction: SortDirection,
+
+    /// How values are compared (NUMERIC or lexicographic default)
+    pub collation: Collation,
 }
 
 impl OrderBy {
-    /// Creates a new ORDER BY clause.
+    /// Creates a new ORDER BY clause with lexicographic collation.
     pub fn new(column: String, direction: SortDirection) -> Self {
-        Self { column, direction }
+        Self {
+            column,
+            direction,
+            collation: Collation::Lexicographic,
+        }
+    }
+
+    /// Sets the collation.
+    pub fn with_collation(mut self, collation: Collation) -> Self {
+        self.collation = collation;
+        self
     }
 
     /// Creates an ascending order clause.
@@ -232,6 +475,31 @@ impl fmt::Display for SortDirection {
     }
 }
 
+/// Value comparison for ORDER BY.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Collation {
+    /// Byte-wise string order (`"10"` < `"9"`)
+    #[default]
+    Lexicographic,
+
+    /// Numeric order when both values parse as numbers (`9` < `10`),
+    /// string order otherwise
+    Numeric,
+}
+
+impl Collation {
+    /// Compares two column values.
+    pub fn compare(self, a: &str, b: &str) -> std::cmp::Ordering {
+        match self {
+            Collation::Lexicographic => a.cmp(b),
+            Collation::Numeric => match (a.parse::<f64>(), b.parse::<f64>()) {
+                (Ok(a), Ok(b)) => a.total_cmp(&b),
+                _ => a.cmp(b),
+            },
+        }
+    }
+}
+
 /// LIMIT and OFFSET clause.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct LimitOffset {
@@ -254,26 +522,310 @@ impl LimitOffset {
     }
 }
 
+/// LIMIT or OFFSET value of a prepared query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LimitValue {
+    /// Literal number, or a placeholder that has been bound
+    Value(usize),
+
+    /// Unbound placeholder, e.g. `$limit` (stored without `$`)
+    Placeholder(String),
+}
+
+/// Query parsed once and executed many times with different bindings.
+///
+/// Created by `reedql::prepare()`. `LIMIT` and `OFFSET` may be named
+/// placeholders (`LIMIT $limit OFFSET $offset`); everything else is fixed
+/// at prepare time.
+///
+/// ## Example Usage
+/// ```no_run
+/// use reedbase_last::reedql::prepare;
+///
+/// let mut prepared = prepare("SELECT * FROM text ORDER BY key LIMIT $limit OFFSET $offset")?;
+/// prepared.bind("limit", "10")?;
+/// prepared.bind("offset", "20")?;
+/// let query = prepared.to_query()?;
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreparedQuery {
+    /// Parsed query; `limit` is taken from the fields below
+    pub query: ParsedQuery,
+
+    /// LIMIT value (None without a LIMIT clause)
+    pub limit: Option<LimitValue>,
+
+    /// OFFSET value (`Value(0)` without an OFFSET clause)
+    pub offset: LimitValue,
+}
+
+impl PreparedQuery {
+    /// Names of placeholders that are still unbound.
+    pub fn placeholders(&self) -> Vec<&str> {
+        self.limit
+            .iter()
+            .chain(std::iter::once(&self.offset))
+            .filter_map(|value| match value {
+                LimitValue::Placeholder(name) => Some(name.as_str()),
+                LimitValue::Value(_) => None,
+            })
+            .collect()
+    }
+
+    /// Binds a placeholder to a concrete value.
+    ///
+    /// ## Input
+    /// - `name`: Placeholder name, with or without the leading `$`
+    /// - `value`: Non-negative integer
+    ///
+    /// ## Error Conditions
+    /// - `ParseError`: No unbound placeholder of that name, or `value` is
+    ///   not a non-negative integer
+    pub fn bind(&mut self, name: &str, value: &str) -> ReedResult<()> {
+        let name = name.strip_prefix('$').unwrap_or(name);
+        let number: usize = value.trim().parse().map_err(|_| ReedError::ParseError {
+            reason: format!("Invalid value for ${}: '{}'", name, value),
+        })?;
+
+        let mut bound = false;
+        for slot in self
+            .limit
+            .iter_mut()
+            .chain(std::iter::once(&mut self.offset))
+        {
+            if matches!(slot, LimitValue::Placeholder(p) if p == name) {
+                *slot = LimitValue::Value(number);
+                bound = true;
+            }
+        }
+
+        if bound {
+            Ok(())
+        } else {
+            Err(ReedError::ParseError {
+                reason: format!("Unknown placeholder: ${}", name),
+            })
+        }
+    }
+
+    /// Returns the query with all placeholders substituted.
+    ///
+    /// ## Error Conditions
+    /// - `ParseError`: A placeholder is still unbound
+    pub fn to_query(&self) -> ReedResult<ParsedQuery> {
+        if let Some(name) = self.placeholders().first() {
+            return Err(ReedError::ParseError {
+                reason: format!("Unbound placeholder: ${}", name),
+            });
+        }
+
+        let mut query = self.query.clone();
+        if let (Some(LimitValue::Value(limit)), LimitValue::Value(offset)) =
+            (&self.limit, &self.offset)
+        {
+            query.limit = Some(LimitOffset::with_offset(*limit, *offset));
+        }
+        Ok(query)
+    }
+}
+
+/// COPY statement, created by `reedql::parse_copy()`.
+///
+/// ## Example
+/// ```text
+/// COPY text FROM 'import/text.csv' WITH (FORMAT CSV, DELIMITER ',', HEADER true)
+/// COPY text TO 'export/text.tsv' WITH (DELIMITER '\t', HEADER)
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CopyStatement {
+    /// Import or export
+    pub direction: CopyDirection,
+
+    /// Table name
+    pub table: String,
+
+    /// File path as written (validated on execution)
+    pub path: String,
+
+    /// WITH options
+    pub options: CopyOptions,
+}
+
+/// Direction of a COPY statement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyDirection {
+    /// COPY table FROM 'file' (import)
+    From,
+
+    /// COPY table TO 'file' (export)
+    To,
+}
+
+/// Options of a COPY statement (`WITH (...)`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CopyOptions {
+    /// Field delimiter (default `,`)
+    pub delimiter: u8,
+
+    /// First line holds column names (default false, as in PostgreSQL)
+    pub header: bool,
+}
+
+impl Default for CopyOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            header: false,
+        }
+    }
+}
+
+/// EXPLAIN statement, created by `reedql::parse_explain()`.
+///
+/// ## Example
+/// ```text
+/// EXPLAIN SELECT * FROM text WHERE key = 'page.title'
+/// EXPLAIN ANALYZE SELECT * FROM text WHERE key LIKE 'page.%'
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExplainStatement {
+    /// `EXPLAIN ANALYZE`: also run the query and report actual row counts
+    pub analyze: bool,
+
+    /// Query to explain
+    pub query: ParsedQuery,
+}
+
+/// CREATE TABLE statement, created by `reedql::parse_create_table()`.
+///
+/// ## Example
+/// ```text
+/// CREATE TABLE users (id integer PRIMARY KEY, name varchar(64) NOT NULL)
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateTableStatement {
+    /// Table name
+    pub table: String,
+
+    /// Schema built from the column definitions (strict, version "1.0")
+    pub schema: Schema,
+}
+
+/// DROP TABLE statement, created by `reedql::parse_drop_table()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DropTableStatement {
+    /// Table name
+    pub table: String,
+
+    /// `IF EXISTS`: a missing table is not an error
+    pub if_exists: bool,
+}
+
+/// CREATE INDEX statement, created by `reedql::parse_create_index()`.
+///
+/// ## Example
+/// ```text
+/// CREATE INDEX ON users (city)
+/// CREATE INDEX users_by_place ON users (country, city)
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateIndexStatement {
+    /// Index name (`None` = default name `{table}_{columns}_idx`)
+    pub name: Option<String>,
+
+    /// Table name
+    pub table: String,
+
+    /// Indexed columns in key order (more than one = composite index)
+    pub columns: Vec<String>,
+}
+
+/// DROP INDEX statement, created by `reedql::parse_drop_index()`.
+///
+/// ## Example
+/// ```text
+/// DROP INDEX users_by_place
+/// DROP INDEX ON users (city)
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DropIndexStatement {
+    /// DROP INDEX name
+    Named(String),
+
+    /// DROP INDEX ON table (col1, col2)
+    On { table: String, columns: Vec<String> },
+}
+
+/// ALTER TABLE statement, created by `reedql::parse_alter_table()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlterTableStatement {
+    /// Table name
+    pub table: String,
+
+    /// Change to apply
+    pub action: AlterTableAction,
+}
+
+/// Change made by an ALTER TABLE statement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlterTableAction {
+    /// RENAME COLUMN from TO to
+    RenameColumn { from: String, to: String },
+}
+
 /// Aggregation function for SELECT clause.
 ///
 /// ## Example
 /// ```text
 /// SELECT COUNT(*) FROM text
+/// SELECT COUNT(*) AS total FROM text
 /// SELECT AVG(length(value)) FROM text WHERE namespace = 'page'
+/// SELECT CORR(age, income) FROM users
 /// ```
 #[derive(Debug, Clone, PartialEq)]
 pub struct AggregationFunction {
-    /// Type of aggregation (COUNT, SUM, AVG, MIN, MAX)
+    /// Type of aggregation (COUNT, SUM, AVG, MIN, MAX, CORR, COVAR)
     pub agg_type: AggregationType,
 
     /// Column to aggregate (* for COUNT(*))
     pub column: String,
+
+    /// Second column for two-argument functions (CORR, COVAR)
+    pub second_column: Option<String>,
+
+    /// Result column name from `AS` clause (None = unnamed scalar result)
+    pub alias: Option<String>,
 }
 
 impl AggregationFunction {
     /// Creates a new aggregation function.
     pub fn new(agg_type: AggregationType, column: String) -> Self {
-        Self { agg_type, column }
+        Self {
+            agg_type,
+            column,
+            second_column: None,
+            alias: None,
+        }
+    }
+
+    /// Creates a two-column aggregation such as CORR(a, b).
+    pub fn pair(agg_type: AggregationType, column: String, second_column: String) -> Self {
+        Self {
+            second_column: Some(second_column),
+            ..Self::new(agg_type, column)
+        }
+    }
+
+    /// Column name of the result in grouped rows.
+    ///
+    /// The alias if one was given, otherwise the lowercase function name
+    /// (`COUNT(*)` → `count`).
+    pub fn output_name(&self) -> String {
+        match &self.alias {
+            Some(alias) => alias.clone(),
+            None => self.agg_type.to_string().to_lowercase(),
+        }
     }
 
     /// Creates a COUNT(*) aggregation.
@@ -304,6 +856,19 @@ pub enum AggregationType {
 
     /// Maximum value
     Max,
+
+    /// Pearson correlation of two columns
+    Corr,
+
+    /// Sample covariance of two columns
+    Covar,
+}
+
+impl AggregationType {
+    /// Returns true for functions taking two columns (CORR, COVAR).
+    pub fn takes_two_columns(&self) -> bool {
+        matches!(self, AggregationType::Corr | AggregationType::Covar)
+    }
 }
 
 impl fmt::Display for AggregationType {
@@ -314,6 +879,8 @@ impl fmt::Display for AggregationType {
             AggregationType::Avg => write!(f, "AVG"),
             AggregationType::Min => write!(f, "MIN"),
             AggregationType::Max => write!(f, "MAX"),
+            AggregationType::Corr => write!(f, "CORR"),
+            AggregationType::Covar => write!(f, "COVAR"),
         }
     }
 }
@@ -355,6 +922,127 @@ impl QueryResult {
             QueryResult::Aggregation(_) => false,
         }
     }
+
+    /// Combines two results, e.g. from different shards or time periods.
+    ///
+    /// Row results are concatenated (`self` first). Duplicates are kept; the
+    /// caller deduplicates if needed.
+    ///
+    /// Aggregations cannot be merged here because the value alone does not
+    /// say which function produced it: two COUNTs merge by addition, two MAXs
+    /// by taking the larger value, and two AVGs not at all without the row
+    /// counts. Use `merge_aggregations()` with the known function instead.
+    ///
+    /// ## Input
+    /// - `other`: Result to append
+    ///
+    /// ## Output
+    /// - `Ok(QueryResult::Rows)`: Concatenated rows
+    ///
+    /// ## Error Conditions
+    /// - `ParseError`: Either side is an aggregation
+    ///
+    /// ## Example Usage
+    /// ```
+    /// use reedbase_last::reedql::QueryResult;
+    ///
+    /// let merged = QueryResult::empty().merge(QueryResult::empty())?;
+    /// assert!(merged.is_empty());
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn merge(self, other: QueryResult) -> ReedResult<QueryResult> {
+        match (self, other) {
+            (QueryResult::Rows(mut rows), QueryResult::Rows(other_rows)) => {
+                rows.extend(other_rows);
+                Ok(QueryResult::Rows(rows))
+            }
+            (QueryResult::Aggregation(_), QueryResult::Aggregation(_)) => {
+                Err(ReedError::ParseError {
+                    reason: "Cannot merge aggregation results without the aggregation function \
+                             (use QueryResult::merge_aggregations)"
+                        .to_string(),
+                })
+            }
+            _ => Err(ReedError::ParseError {
+                reason: "Cannot merge row results with aggregation results".to_string(),
+            }),
+        }
+    }
+
+    /// Merges two partial aggregation values of a known function.
+    ///
+    /// ## Input
+    /// - `a`, `b`: Partial results (e.g. per shard)
+    /// - `agg_type`: Function that produced both values
+    ///
+    /// ## Output
+    /// - COUNT, SUM: `a + b`
+    /// - MIN: `a.min(b)`
+    /// - MAX: `a.max(b)`
+    ///
+    /// ## Error Conditions
+    /// - `ParseError`: AVG, CORR, COVAR (need the underlying moments)
+    ///
+    /// ## Example Usage
+    /// ```
+    /// use reedbase_last::reedql::{AggregationType, QueryResult};
+    ///
+    /// let total = QueryResult::merge_aggregations(10.0, 5.0, AggregationType::Count)?;
+    /// assert_eq!(total, 15.0);
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn merge_aggregations(a: f64, b: f64, agg_type: AggregationType) -> ReedResult<f64> {
+        match agg_type {
+            AggregationType::Count | AggregationType::Sum => Ok(a + b),
+            AggregationType::Min => Ok(a.min(b)),
+            AggregationType::Max => Ok(a.max(b)),
+            AggregationType::Avg | AggregationType::Corr | AggregationType::Covar => {
+                Err(ReedError::ParseError {
+                    reason: format!("Cannot merge {} results without their row counts", agg_type),
+                })
+            }
+        }
+    }
+}
+
+/// Severity of a lint warning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WarnLevel {
+    /// Style or performance hint (query is fine as written)
+    Info,
+
+    /// Query is likely to behave differently than intended
+    Warning,
+}
+
+impl fmt::Display for WarnLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WarnLevel::Info => write!(f, "info"),
+            WarnLevel::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// Soft warning produced by the ReedQL lint pass.
+///
+/// Unlike parse errors, lint warnings never prevent execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintWarning {
+    /// Severity
+    pub level: WarnLevel,
+
+    /// Human-readable description
+    pub message: String,
+
+    /// Byte offset into the query where the issue was found
+    pub position: usize,
+}
+
+impl fmt::Display for LintWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}: {}", self.level, self.position, self.message)
+    }
 }
 
 #[cfg(test)]
@@ -422,17 +1110,89 @@ mod tests {
         assert!(!cond.is_fast_path());
     }
 
+    #[test]
+    fn test_prepared_query_bind() {
+        let mut prepared = PreparedQuery {
+            query: ParsedQuery::new(),
+            limit: Some(LimitValue::Placeholder("n".to_string())),
+            offset: LimitValue::Placeholder("n".to_string()),
+        };
+        assert!(prepared.to_query().is_err());
+
+        // One name may be used for both; `$` is optional
+        prepared.bind("$n", "7").unwrap();
+        assert_eq!(
+            prepared.to_query().unwrap().limit,
+            Some(LimitOffset::with_offset(7, 7))
+        );
+
+        // Bound placeholders cannot be bound again
+        assert!(prepared.bind("n", "8").is_err());
+    }
+
     #[test]
     fn test_order_by_constructors() {
         let asc = OrderBy::asc("key".to_string());
         assert_eq!(asc.column, "key");
         assert_eq!(asc.direction, SortDirection::Ascending);
 
+        assert_eq!(asc.collation, Collation::Lexicographic);
+
         let desc = OrderBy::desc("value".to_string());
         assert_eq!(desc.column, "value");
         assert_eq!(desc.direction, SortDirection::Descending);
     }
 
+    #[test]
+    fn test_query_result_merge_rows() {
+        let row = |key: &str| {
+            let mut row = std::collections::HashMap::new();
+            row.insert("key".to_string(), key.to_string());
+            row
+        };
+
+        let a = QueryResult::Rows(vec![row("a"), row("b")]);
+        let b = QueryResult::Rows(vec![row("b"), row("c")]);
+
+        match a.merge(b).unwrap() {
+            QueryResult::Rows(rows) => {
+                let keys: Vec<&str> = rows.iter().map(|r| r["key"].as_str()).collect();
+                assert_eq!(keys, vec!["a", "b", "b", "c"]);
+            }
+            other => panic!("Expected rows, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_query_result_merge_aggregation_error() {
+        let result = QueryResult::Aggregation(1.0).merge(QueryResult::Aggregation(2.0));
+        assert!(matches!(result, Err(ReedError::ParseError { .. })));
+
+        let result = QueryResult::empty().merge(QueryResult::Aggregation(2.0));
+        assert!(matches!(result, Err(ReedError::ParseError { .. })));
+    }
+
+    #[test]
+    fn test_merge_aggregations() {
+        assert_eq!(
+            QueryResult::merge_aggregations(3.0, 4.0, AggregationType::Count).unwrap(),
+            7.0
+        );
+        assert_eq!(
+            QueryResult::merge_aggregations(3.0, 4.0, AggregationType::Sum).unwrap(),
+            7.0
+        );
+        assert_eq!(
+            QueryResult::merge_aggregations(3.0, 4.0, AggregationType::Min).unwrap(),
+            3.0
+        );
+        assert_eq!(
+            QueryResult::merge_aggregations(3.0, 4.0, AggregationType::Max).unwrap(),
+            4.0
+        );
+        assert!(QueryResult::merge_aggregations(3.0, 4.0, AggregationType::Avg).is_err());
+    }
+
     #[test]
     fn test_limit_offset_constructors() {
         let limit = LimitOffset::new(10);
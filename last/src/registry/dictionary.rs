@@ -6,6 +6,7 @@
 //! Provides O(1) lookups for action and user code translations.
 
 use crate::error::{ReedError, ReedResult};
+use once_cell::sync::Lazy;
 use std::collections::HashMap;
 use std::fs::{self, OpenOptions};
 use std::io::Write;
@@ -14,80 +15,72 @@ use std::sync::{OnceLock, RwLock};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Global actions cache (code → name).
-static ACTIONS_BY_CODE: OnceLock<RwLock<HashMap<u8, String>>> = OnceLock::new();
+static ACTIONS_BY_CODE: Lazy<RwLock<HashMap<u8, String>>> = Lazy::new(Default::default);
 
 /// Global actions reverse cache (name → code).
-static ACTIONS_BY_NAME: OnceLock<RwLock<HashMap<String, u8>>> = OnceLock::new();
+static ACTIONS_BY_NAME: Lazy<RwLock<HashMap<String, u8>>> = Lazy::new(Default::default);
 
 /// Global users cache (code → username).
-static USERS_BY_CODE: OnceLock<RwLock<HashMap<u32, String>>> = OnceLock::new();
+static USERS_BY_CODE: Lazy<RwLock<HashMap<u32, String>>> = Lazy::new(Default::default);
 
 /// Global users reverse cache (username → code).
-static USERS_BY_NAME: OnceLock<RwLock<HashMap<String, u32>>> = OnceLock::new();
+static USERS_BY_NAME: Lazy<RwLock<HashMap<String, u32>>> = Lazy::new(Default::default);
 
-/// Next available user code (auto-increment).
-static NEXT_USER_CODE: OnceLock<RwLock<u32>> = OnceLock::new();
+/// Lowest code handed out by `register_custom_action()`; lower codes are
+/// reserved for built-in actions.
+pub const FIRST_CUSTOM_ACTION_CODE: u8 = 10;
+
+/// Next available user code (auto-increment; 0 is system).
+static NEXT_USER_CODE: RwLock<u32> = RwLock::new(1);
 
 /// Base path for dictionaries.
-static BASE_PATH: OnceLock<RwLock<PathBuf>> = OnceLock::new();
+static BASE_PATH: Lazy<RwLock<PathBuf>> = Lazy::new(|| RwLock::new(PathBuf::from(".reed")));
+
+/// Set once the dictionaries have first been read from disk.
+static LOADED: OnceLock<()> = OnceLock::new();
 
-/// Initialises dictionary caches.
+/// Loads the dictionaries on first use.
 ///
-/// Must be called before any lookup operations.
+/// Later changes on disk are picked up by `reload_dictionaries()`.
 fn ensure_initialized() -> ReedResult<()> {
-    // Initialize base path if not set
-    if BASE_PATH.get().is_none() {
-        let _ = BASE_PATH.set(RwLock::new(PathBuf::from(".reed")));
-    }
-
-    // Initialize action caches
-    if ACTIONS_BY_CODE.get().is_none() {
-        let _ = ACTIONS_BY_CODE.set(RwLock::new(HashMap::new()));
-        let _ = ACTIONS_BY_NAME.set(RwLock::new(HashMap::new()));
+    if LOADED.get().is_none() {
         load_actions_dict()?;
-    }
-
-    // Initialize user caches
-    if USERS_BY_CODE.get().is_none() {
-        let _ = USERS_BY_CODE.set(RwLock::new(HashMap::new()));
-        let _ = USERS_BY_NAME.set(RwLock::new(HashMap::new()));
-        let _ = NEXT_USER_CODE.set(RwLock::new(1)); // Start from 1 (0 is system)
         load_users_dict()?;
+        let _ = LOADED.set(());
     }
 
     Ok(())
 }
 
-/// Loads actions dictionary into cache.
-fn load_actions_dict() -> ReedResult<()> {
-    let base_path = BASE_PATH
-        .get()
-        .ok_or_else(|| ReedError::IoError {
-            operation: "get_base_path".to_string(),
-            reason: "Base path not initialized".to_string(),
-        })?
+/// Path of a dictionary file under the base path.
+fn dict_path(file: &str) -> PathBuf {
+    BASE_PATH
         .read()
-        .expect("RwLock poisoned - cache corrupted");
+        .expect("RwLock poisoned - cache corrupted")
+        .join("registry")
+        .join(file)
+}
 
-    let path = base_path.join("registry/actions.dict");
-    let content = fs::read_to_string(&path).map_err(|e| ReedError::IoError {
-        operation: "read_actions_dict".to_string(),
-        reason: e.to_string(),
-    })?;
+/// Loads actions dictionary into cache.
+///
+/// Built-in actions missing from an older file are added first (see
+/// `migrate_action_dict()`). The file is parsed into new maps, which then
+/// replace the cached ones under the write lock; on error the cache is left
+/// as it was.
+fn load_actions_dict() -> ReedResult<()> {
+    let path = dict_path("actions.dict");
+    if path.exists() {
+        crate::registry::init::migrate_action_dict(&path)?;
+    }
 
-    let mut by_code = ACTIONS_BY_CODE
-        .get()
-        .expect("Cache not initialized")
-        .write()
-        .expect("RwLock poisoned");
-    let mut by_name = ACTIONS_BY_NAME
-        .get()
-        .expect("Cache not initialized")
-        .write()
-        .expect("RwLock poisoned");
+    let content =
+        fs::read_to_string(dict_path("actions.dict")).map_err(|e| ReedError::IoError {
+            operation: "read_actions_dict".to_string(),
+            reason: e.to_string(),
+        })?;
 
-    by_code.clear();
-    by_name.clear();
+    let mut codes = HashMap::new();
+    let mut names = HashMap::new();
 
     for (line_num, line) in content.lines().enumerate() {
         if line_num == 0 {
@@ -113,49 +106,30 @@ fn load_actions_dict() -> ReedResult<()> {
 
         let name = parts[1].to_string();
 
-        by_code.insert(code, name.clone());
-        by_name.insert(name.to_lowercase(), code);
+        codes.insert(code, name.clone());
+        names.insert(name.to_lowercase(), code);
     }
 
+    let mut by_code = ACTIONS_BY_CODE.write().expect("RwLock poisoned");
+    let mut by_name = ACTIONS_BY_NAME.write().expect("RwLock poisoned");
+    *by_code = codes;
+    *by_name = names;
+
     Ok(())
 }
 
 /// Loads users dictionary into cache.
+///
+/// Replaces the cached maps as a whole, like `load_actions_dict()`.
 fn load_users_dict() -> ReedResult<()> {
-    let base_path = BASE_PATH
-        .get()
-        .ok_or_else(|| ReedError::IoError {
-            operation: "get_base_path".to_string(),
-            reason: "Base path not initialized".to_string(),
-        })?
-        .read()
-        .unwrap();
-
-    let path = base_path.join("registry/users.dict");
-    let content = fs::read_to_string(&path).map_err(|e| ReedError::IoError {
+    let content = fs::read_to_string(dict_path("users.dict")).map_err(|e| ReedError::IoError {
         operation: "read_users_dict".to_string(),
         reason: e.to_string(),
     })?;
 
-    let mut by_code = USERS_BY_CODE
-        .get()
-        .expect("Cache not initialized")
-        .write()
-        .expect("RwLock poisoned");
-    let mut by_name = USERS_BY_NAME
-        .get()
-        .expect("Cache not initialized")
-        .write()
-        .expect("RwLock poisoned");
-    let mut next_code = NEXT_USER_CODE
-        .get()
-        .expect("Cache not initialized")
-        .write()
-        .expect("RwLock poisoned");
-
-    by_code.clear();
-    by_name.clear();
-    *next_code = 1;
+    let mut codes = HashMap::new();
+    let mut names = HashMap::new();
+    let mut next = 1;
 
     for (line_num, line) in content.lines().enumerate() {
         if line_num == 0 {
@@ -181,15 +155,22 @@ fn load_users_dict() -> ReedResult<()> {
 
         let username = parts[1].to_string();
 
-        by_code.insert(code, username.clone());
-        by_name.insert(username, code);
+        codes.insert(code, username.clone());
+        names.insert(username, code);
 
         // Track highest code for auto-increment
-        if code >= *next_code {
-            *next_code = code + 1;
+        if code >= next {
+            next = code + 1;
         }
     }
 
+    let mut by_code = USERS_BY_CODE.write().expect("RwLock poisoned");
+    let mut by_name = USERS_BY_NAME.write().expect("RwLock poisoned");
+    let mut next_code = NEXT_USER_CODE.write().expect("RwLock poisoned");
+    *by_code = codes;
+    *by_name = names;
+    *next_code = next;
+
     Ok(())
 }
 
@@ -218,11 +199,7 @@ fn load_users_dict() -> ReedResult<()> {
 pub fn get_action_name(code: u8) -> ReedResult<String> {
     ensure_initialized()?;
 
-    let cache = ACTIONS_BY_CODE
-        .get()
-        .expect("Cache not initialized")
-        .read()
-        .expect("RwLock poisoned");
+    let cache = ACTIONS_BY_CODE.read().expect("RwLock poisoned");
     cache
         .get(&code)
         .cloned()
@@ -257,11 +234,7 @@ pub fn get_action_name(code: u8) -> ReedResult<String> {
 pub fn get_action_code(name: &str) -> ReedResult<u8> {
     ensure_initialized()?;
 
-    let cache = ACTIONS_BY_NAME
-        .get()
-        .expect("Cache not initialized")
-        .read()
-        .expect("RwLock poisoned");
+    let cache = ACTIONS_BY_NAME.read().expect("RwLock poisoned");
     cache
         .get(&name.to_lowercase())
         .copied()
@@ -270,6 +243,88 @@ pub fn get_action_code(name: &str) -> ReedResult<u8> {
         })
 }
 
+/// Registers an application-defined action.
+///
+/// Appends the action to actions.dict with the next free code, starting at
+/// `FIRST_CUSTOM_ACTION_CODE` and above every existing code, so that
+/// versions can be recorded under it (see `Table::write_with_action_name`).
+/// Registering an existing name returns its code unchanged. Thread-safe.
+///
+/// ## Input
+/// - `name`: Action name (matched case-insensitively, e.g. "import")
+/// - `description`: Free text stored in actions.dict
+///
+/// ## Output
+/// - `Result<u8>`: Code of the action (existing or new)
+///
+/// ## Performance
+/// - Existing action: < 100ns (cached)
+/// - New action: < 10ms (append to actions.dict + cache update)
+///
+/// ## Error Conditions
+/// - ValidationError: Empty name, `|` or line break in name or description,
+///   or all codes up to 255 taken
+/// - IoError: Cannot write to actions.dict
+///
+/// ## Example Usage
+/// ```no_run
+/// use reedbase_last::registry::register_custom_action;
+///
+/// let code = register_custom_action("import", "Bulk import from CSV")?; // e.g. 13
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn register_custom_action(name: &str, description: &str) -> ReedResult<u8> {
+    ensure_initialized()?;
+
+    let invalid = |reason: &str| ReedError::ValidationError {
+        column: "action".to_string(),
+        reason: reason.to_string(),
+        value: Some(name.to_string()),
+    };
+    if name.trim().is_empty() {
+        return Err(invalid("Action name must not be empty"));
+    }
+    if format!("{}{}", name, description).contains(['|', '\n', '\r']) {
+        return Err(invalid(
+            "Action name and description must not contain '|' or line breaks",
+        ));
+    }
+
+    let mut by_code = ACTIONS_BY_CODE.write().expect("RwLock poisoned");
+    let mut by_name = ACTIONS_BY_NAME.write().expect("RwLock poisoned");
+
+    if let Some(&code) = by_name.get(&name.to_lowercase()) {
+        return Ok(code);
+    }
+
+    let next = by_code
+        .keys()
+        .max()
+        .map_or(0, |&max| max as u16 + 1)
+        .max(FIRST_CUSTOM_ACTION_CODE as u16);
+    let code = u8::try_from(next).map_err(|_| invalid("No free action codes left (max 255)"))?;
+
+    let line = format!("{}|{}|{}\n", code, name, description);
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(dict_path("actions.dict"))
+        .map_err(|e| ReedError::IoError {
+            operation: "append_actions_dict".to_string(),
+            reason: e.to_string(),
+        })?;
+    file.write_all(line.as_bytes())
+        .map_err(|e| ReedError::IoError {
+            operation: "write_actions_dict".to_string(),
+            reason: e.to_string(),
+        })?;
+
+    by_code.insert(code, name.to_string());
+    by_name.insert(name.to_lowercase(), code);
+
+    Ok(code)
+}
+
 /// Gets username from code.
 ///
 /// ## Input
@@ -295,15 +350,36 @@ pub fn get_action_code(name: &str) -> ReedResult<u8> {
 pub fn get_username(code: u32) -> ReedResult<String> {
     ensure_initialized()?;
 
-    let cache = USERS_BY_CODE
-        .get()
-        .expect("Cache not initialized")
-        .read()
-        .expect("RwLock poisoned");
-    cache
-        .get(&code)
-        .cloned()
-        .ok_or(ReedError::UnknownUserCode { code })
+    with_users_read(|users| users.get(&code).cloned()).ok_or(ReedError::UnknownUserCode { code })
+}
+
+/// Runs `f` with read access to the users dictionary (code → username).
+///
+/// The lock is held only for the duration of `f`, so callers cannot keep
+/// it across I/O. Loads the dictionary on first use.
+///
+/// ## Input
+/// - `f`: Closure reading the map; it should not call other registry
+///   functions that create users
+///
+/// ## Output
+/// - Whatever `f` returns; if the dictionary cannot be loaded, `f` sees
+///   the users known so far (possibly none)
+///
+/// ## Performance
+/// - One read lock plus the cost of `f`
+///
+/// ## Example Usage
+/// ```no_run
+/// use reedbase_last::registry::with_users_read;
+///
+/// let names: Vec<String> = with_users_read(|users| users.values().cloned().collect());
+/// ```
+pub fn with_users_read<T>(f: impl FnOnce(&HashMap<u32, String>) -> T) -> T {
+    let _ = ensure_initialized();
+
+    let users = USERS_BY_CODE.read().expect("RwLock poisoned");
+    f(&users)
 }
 
 /// Gets or creates user code.
@@ -337,32 +413,16 @@ pub fn get_or_create_user_code(username: &str) -> ReedResult<u32> {
 
     // Check if user exists (read lock)
     {
-        let cache = USERS_BY_NAME
-            .get()
-            .expect("Cache not initialized")
-            .read()
-            .expect("RwLock poisoned");
+        let cache = USERS_BY_NAME.read().expect("RwLock poisoned");
         if let Some(&code) = cache.get(username) {
             return Ok(code);
         }
     }
 
     // User doesn't exist - create new code (write lock)
-    let mut by_code = USERS_BY_CODE
-        .get()
-        .expect("Cache not initialized")
-        .write()
-        .expect("RwLock poisoned");
-    let mut by_name = USERS_BY_NAME
-        .get()
-        .expect("Cache not initialized")
-        .write()
-        .expect("RwLock poisoned");
-    let mut next_code = NEXT_USER_CODE
-        .get()
-        .expect("Cache not initialized")
-        .write()
-        .expect("RwLock poisoned");
+    let mut by_code = USERS_BY_CODE.write().expect("RwLock poisoned");
+    let mut by_name = USERS_BY_NAME.write().expect("RwLock poisoned");
+    let mut next_code = NEXT_USER_CODE.write().expect("RwLock poisoned");
 
     // Double-check after acquiring write lock (another thread may have created it)
     if let Some(&code) = by_name.get(username) {
@@ -371,15 +431,9 @@ pub fn get_or_create_user_code(username: &str) -> ReedResult<u32> {
 
     // Assign new code
     let new_code = *next_code;
-    *next_code += 1;
 
     // Append to CSV file
-    let base_path = BASE_PATH
-        .get()
-        .expect("Cache not initialized")
-        .read()
-        .expect("RwLock poisoned");
-    let path = base_path.join("registry/users.dict");
+    let path = dict_path("users.dict");
 
     let timestamp = SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -403,7 +457,8 @@ pub fn get_or_create_user_code(username: &str) -> ReedResult<u32> {
             reason: e.to_string(),
         })?;
 
-    // Update cache
+    // Update cache (the code is only taken once the line is on disk)
+    *next_code += 1;
     by_code.insert(new_code, username.to_string());
     by_name.insert(username.to_string(), new_code);
 
@@ -412,7 +467,9 @@ pub fn get_or_create_user_code(username: &str) -> ReedResult<u32> {
 
 /// Reloads dictionaries from disk.
 ///
-/// Hot-reload for changes made externally.
+/// Hot-reload for changes made externally. Each map is replaced as a
+/// whole under its write lock, so readers see either the old or the new
+/// dictionary; if a file cannot be read or parsed, its cache is kept.
 ///
 /// ## Performance
 /// - < 10ms for typical dictionary sizes
@@ -429,10 +486,9 @@ pub fn get_or_create_user_code(username: &str) -> ReedResult<u32> {
 /// # Ok::<(), reedbase::ReedError>(())
 /// ```
 pub fn reload_dictionaries() -> ReedResult<()> {
-    // Force re-initialization
-    ensure_initialized()?;
     load_actions_dict()?;
     load_users_dict()?;
+    let _ = LOADED.set(());
     Ok(())
 }
 
@@ -441,33 +497,21 @@ pub fn reload_dictionaries() -> ReedResult<()> {
 /// Used for testing or non-standard locations.
 /// Clears all caches to force reload with new path.
 pub fn set_base_path(path: PathBuf) {
-    if let Some(base_path_lock) = BASE_PATH.get() {
-        if let Ok(mut base_path) = base_path_lock.write() {
-            *base_path = path;
-        }
-    } else {
-        let _ = BASE_PATH.set(RwLock::new(path));
+    if let Ok(mut base_path) = BASE_PATH.write() {
+        *base_path = path;
     }
 
     // Clear caches
-    if let Some(cache) = ACTIONS_BY_CODE.get() {
-        if let Ok(mut c) = cache.write() {
-            c.clear();
-        }
+    if let Ok(mut c) = ACTIONS_BY_CODE.write() {
+        c.clear();
     }
-    if let Some(cache) = ACTIONS_BY_NAME.get() {
-        if let Ok(mut c) = cache.write() {
-            c.clear();
-        }
+    if let Ok(mut c) = ACTIONS_BY_NAME.write() {
+        c.clear();
     }
-    if let Some(cache) = USERS_BY_CODE.get() {
-        if let Ok(mut c) = cache.write() {
-            c.clear();
-        }
+    if let Ok(mut c) = USERS_BY_CODE.write() {
+        c.clear();
     }
-    if let Some(cache) = USERS_BY_NAME.get() {
-        if let Ok(mut c) = cache.write() {
-            c.clear();
-        }
+    if let Ok(mut c) = USERS_BY_NAME.write() {
+        c.clear();
     }
 }
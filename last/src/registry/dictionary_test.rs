@@ -5,9 +5,10 @@
 
 #[cfg(test)]
 mod tests {
+    use crate::error::ReedError;
     use crate::registry::dictionary::{
         get_action_code, get_action_name, get_or_create_user_code, get_username,
-        reload_dictionaries, set_base_path,
+        register_custom_action, reload_dictionaries, set_base_path, with_users_read,
     };
     use crate::registry::init::init_registry;
     use std::fs;
@@ -199,4 +200,100 @@ mod tests {
 
         let _ = fs::remove_dir_all(&temp_dir);
     }
+
+    #[test]
+    fn test_with_users_read_sees_new_users() {
+        let _lock = TEST_LOCK.lock().unwrap();
+        let temp_dir = setup_test_registry("users_read");
+
+        let before = with_users_read(|users| users.len());
+        let code = get_or_create_user_code("carol").unwrap();
+
+        // Visible without a reload
+        assert_eq!(
+            with_users_read(|users| users.get(&code).cloned()),
+            Some("carol".to_string())
+        );
+        assert_eq!(with_users_read(|users| users.len()), before + 1);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_reload_failure_keeps_cache() {
+        let _lock = TEST_LOCK.lock().unwrap();
+        let temp_dir = setup_test_registry("reload_failure");
+
+        let code = get_or_create_user_code("dave").unwrap();
+        let users_path = temp_dir.join("registry/users.dict");
+        let content = fs::read_to_string(&users_path).unwrap();
+        fs::write(&users_path, format!("{}x|broken|0\n", content)).unwrap();
+
+        assert!(reload_dictionaries().is_err());
+        assert_eq!(get_username(code).unwrap(), "dave");
+
+        // A successful reload replaces the whole map
+        fs::write(&users_path, "code|username|created_at\n0|system|0\n").unwrap();
+        reload_dictionaries().unwrap();
+        assert!(get_username(code).is_err());
+        assert_eq!(with_users_read(|users| users.len()), 1);
+        assert_eq!(get_or_create_user_code("erin").unwrap(), 1);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_register_custom_action() {
+        let _lock = TEST_LOCK.lock().unwrap();
+        let temp_dir = setup_test_registry("custom_action");
+
+        // Built-ins end at 12 (migrate), so custom codes continue above it
+        let import = register_custom_action("import", "Bulk import from CSV").unwrap();
+        assert!(import >= 13);
+        let sync = register_custom_action("sync", "Upstream sync").unwrap();
+        assert_eq!(sync, import + 1);
+        assert_eq!(register_custom_action("IMPORT", "again").unwrap(), import);
+        assert_eq!(register_custom_action("update", "").unwrap(), 2);
+
+        assert_eq!(get_action_name(import).unwrap(), "import");
+        assert_eq!(get_action_code("Sync").unwrap(), sync);
+
+        // Persisted: survives a reload
+        let content = fs::read_to_string(temp_dir.join("registry/actions.dict")).unwrap();
+        assert!(content.ends_with(&format!(
+            "{}|import|Bulk import from CSV\n{}|sync|Upstream sync\n",
+            import, sync
+        )));
+        reload_dictionaries().unwrap();
+        assert_eq!(get_action_code("import").unwrap(), import);
+
+        for (name, description) in [("", "x"), ("a|b", "x"), ("ok", "two\nlines")] {
+            assert!(matches!(
+                register_custom_action(name, description),
+                Err(ReedError::ValidationError { .. })
+            ));
+        }
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_register_custom_action_code_range() {
+        let _lock = TEST_LOCK.lock().unwrap();
+        let temp_dir = setup_test_registry("custom_action_range");
+
+        // Keep the built-ins: other tests resolve them through the same cache
+        let actions_path = temp_dir.join("registry/actions.dict");
+        let mut content = fs::read_to_string(&actions_path).unwrap();
+        content.push_str("255|last|Highest code\n");
+        fs::write(&actions_path, content).unwrap();
+        reload_dictionaries().unwrap();
+
+        assert!(matches!(
+            register_custom_action("overflow", ""),
+            Err(ReedError::ValidationError { .. })
+        ));
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
 }
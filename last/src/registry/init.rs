@@ -6,7 +6,8 @@
 //! Handles creation of default dictionaries and integrity validation.
 
 use crate::error::{ReedError, ReedResult};
-use std::fs;
+use std::collections::HashSet;
+use std::fs::{self, OpenOptions};
 use std::io::Write;
 use std::path::Path;
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -63,6 +64,23 @@ pub fn init_registry(base_path: &Path) -> ReedResult<()> {
     Ok(())
 }
 
+/// Built-in actions (code, name, description) of every actions.dict.
+const DEFAULT_ACTIONS: &[(u8, &str, &str)] = &[
+    (0, "delete", "Delete operation"),
+    (1, "create", "Create new entry"),
+    (2, "update", "Update existing entry"),
+    (3, "rollback", "Rollback to previous version"),
+    (4, "compact", "Compact/cleanup old versions"),
+    (5, "init", "Initialise table"),
+    (6, "snapshot", "Full snapshot (periodic)"),
+    (7, "automerge", "Automatic merge of concurrent writes"),
+    (8, "conflict", "Conflict detected"),
+    (9, "resolve", "Manual conflict resolution"),
+    (10, "vacuum", "Canonical rewrite of current.csv"),
+    (11, "swap", "Atomic replacement of current.csv"),
+    (12, "migrate", "Schema migration"),
+];
+
 /// Creates default actions dictionary.
 ///
 /// ## Performance
@@ -71,19 +89,10 @@ pub fn init_registry(base_path: &Path) -> ReedResult<()> {
 /// ## Error Conditions
 /// - IoError: Cannot write file
 fn create_default_action_dict(path: &Path) -> ReedResult<()> {
-    let content = "\
-code|name|description
-0|delete|Delete operation
-1|create|Create new entry
-2|update|Update existing entry
-3|rollback|Rollback to previous version
-4|compact|Compact/cleanup old versions
-5|init|Initialise table
-6|snapshot|Full snapshot (periodic)
-7|automerge|Automatic merge of concurrent writes
-8|conflict|Conflict detected
-9|resolve|Manual conflict resolution
-";
+    let mut content = String::from("code|name|description\n");
+    for (code, name, description) in DEFAULT_ACTIONS {
+        content.push_str(&format!("{}|{}|{}\n", code, name, description));
+    }
 
     fs::write(path, content).map_err(|e| ReedError::IoError {
         operation: "write_actions_dict".to_string(),
@@ -93,6 +102,76 @@ code|name|description
     Ok(())
 }
 
+/// Adds built-in actions missing from an existing actions.dict.
+///
+/// Dictionaries written by older versions lack later built-ins such as
+/// `vacuum`. Each missing action is appended under its default code, or
+/// under the next free code if a custom action already holds it. Existing
+/// lines are never changed.
+///
+/// ## Performance
+/// - < 1ms when nothing is missing (read only)
+///
+/// ## Error Conditions
+/// - IoError: Cannot read or append to the file
+pub(crate) fn migrate_action_dict(path: &Path) -> ReedResult<()> {
+    let content = fs::read_to_string(path).map_err(|e| ReedError::IoError {
+        operation: "read_actions_dict".to_string(),
+        reason: e.to_string(),
+    })?;
+
+    let mut codes = HashSet::new();
+    let mut names = HashSet::new();
+    for line in content.lines().skip(1) {
+        let mut parts = line.split('|');
+        if let (Some(code), Some(name)) = (parts.next(), parts.next()) {
+            if let Ok(code) = code.trim().parse::<u8>() {
+                codes.insert(code);
+            }
+            names.insert(name.trim().to_lowercase());
+        }
+    }
+
+    let mut missing = String::new();
+    for &(default_code, name, description) in DEFAULT_ACTIONS {
+        if names.contains(name) {
+            continue;
+        }
+        let code = if codes.contains(&default_code) {
+            let next = codes.iter().max().map_or(0, |&max| max as u16 + 1);
+            u8::try_from(next).map_err(|_| ReedError::DictionaryCorrupted {
+                file: "actions".to_string(),
+                reason: format!("No free code left for built-in action '{}'", name),
+                line: 0,
+            })?
+        } else {
+            default_code
+        };
+        codes.insert(code);
+        missing.push_str(&format!("{}|{}|{}\n", code, name, description));
+    }
+
+    if missing.is_empty() {
+        return Ok(());
+    }
+    if !content.is_empty() && !content.ends_with('\n') {
+        missing.insert(0, '\n');
+    }
+
+    let mut file = OpenOptions::new()
+        .append(true)
+        .open(path)
+        .map_err(|e| ReedError::IoError {
+            operation: "append_actions_dict".to_string(),
+            reason: e.to_string(),
+        })?;
+    file.write_all(missing.as_bytes())
+        .map_err(|e| ReedError::IoError {
+            operation: "write_actions_dict".to_string(),
+            reason: e.to_string(),
+        })
+}
+
 /// Creates default users dictionary.
 ///
 /// Creates users.dict with system user (code 0).
@@ -5,7 +5,7 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::registry::init::{init_registry, validate_dictionaries};
+    use crate::registry::init::{init_registry, migrate_action_dict, validate_dictionaries};
     use std::fs;
     use std::path::Path;
 
@@ -153,4 +153,37 @@ mod tests {
         // Clean up
         let _ = fs::remove_dir_all(&temp_dir);
     }
+
+    #[test]
+    fn test_migrate_action_dict_adds_missing_builtins() {
+        let temp_dir = create_temp_dir("migrate_actions");
+        fs::create_dir_all(&temp_dir).unwrap();
+
+        // Dictionary from before vacuum, with a custom action on code 10
+        let actions_path = temp_dir.join("actions.dict");
+        let mut content = String::from("code|name|description\n");
+        for (code, name) in ["delete", "create", "update", "rollback", "compact"]
+            .iter()
+            .enumerate()
+        {
+            content.push_str(&format!("{}|{}|Built-in\n", code, name));
+        }
+        content.push_str("10|import|Bulk import");
+        fs::write(&actions_path, &content).unwrap();
+
+        migrate_action_dict(&actions_path).unwrap();
+        let migrated = fs::read_to_string(&actions_path).unwrap();
+        assert!(migrated.starts_with(&content));
+        assert!(migrated.contains("\n5|init|"));
+        assert!(migrated.contains("\n11|vacuum|"));
+        assert!(migrated.contains("\n12|swap|"));
+        assert!(migrated.contains("\n13|migrate|"));
+
+        // Nothing left to add
+        migrate_action_dict(&actions_path).unwrap();
+        assert_eq!(fs::read_to_string(&actions_path).unwrap(), migrated);
+
+        // Clean up
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
 }
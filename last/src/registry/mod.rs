@@ -4,7 +4,9 @@
 //! Registry system for ReedBase dictionaries.
 //!
 //! Provides global lookup tables for efficient integer encoding of frequently-used values:
-//! - **Action codes**: Encode operation types (delete, create, update, etc.)
+//! - **Action codes**: Encode operation types (delete, create, update, etc.);
+//!   applications add their own with `register_custom_action()` (codes from
+//!   `FIRST_CUSTOM_ACTION_CODE` up)
 //! - **User codes**: Encode usernames with auto-increment
 //!
 //! ## Architecture
@@ -23,9 +25,9 @@
 //!
 //! ## Thread Safety
 //!
-//! - Read operations: Lock-free via `OnceLock`
+//! - Read operations: Shared `RwLock` read locks
 //! - Write operations: Synchronized via `RwLock`
-//! - User creation: Atomic via file locking
+//! - User and action creation: Append and cache update under the write lock
 
 pub mod dictionary;
 pub mod init;
@@ -37,7 +39,8 @@ mod init_test;
 
 // Re-export public API
 pub use dictionary::{
-    get_action_code, get_action_name, get_or_create_user_code, get_username, reload_dictionaries,
-    set_base_path,
+    get_action_code, get_action_name, get_or_create_user_code, get_username,
+    register_custom_action, reload_dictionaries, set_base_path, with_users_read,
+    FIRST_CUSTOM_ACTION_CODE,
 };
 pub use init::{init_registry, validate_dictionaries};
@@ -0,0 +1,210 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Column type inference from existing data.
+//!
+//! Picks the narrowest column type that every non-empty value in a column
+//! validates against, trying `integer`, `float`, `boolean` and finally
+//! `string`. Uses the same field validation as schema enforcement, so an
+//! inferred schema always accepts the data it was inferred from.
+
+use crate::error::ReedResult;
+use crate::schema::types::{ColumnDef, Schema};
+use crate::schema::validation::validate_field;
+use crate::tables::Table;
+
+/// Candidate types from narrowest to widest.
+///
+/// `integer` comes before `boolean` so that `0`/`1` columns stay numeric.
+const CANDIDATE_TYPES: [&str; 3] = ["integer", "float", "boolean"];
+
+/// Seed of the row sample drawn by `infer_table_schema()`.
+const INFER_SAMPLE_SEED: u64 = 0x5eed;
+
+/// Infers a schema from a header and data rows.
+///
+/// ## Input
+/// - `header`: Column names
+/// - `rows`: Data rows as field lists (header excluded); missing fields count as empty
+///
+/// ## Output
+/// - Non-strict `Schema` with one untyped-constraint column per header entry
+///
+/// ## Performance
+/// - O(r × c) where r = rows, c = columns
+///
+/// ## Example Usage
+/// ```
+/// use reedbase_last::schema::infer_schema;
+///
+/// let header = vec!["id".to_string(), "price".to_string(), "name".to_string()];
+/// let rows = vec![
+///     vec!["1".to_string(), "9.99".to_string(), "Tea".to_string()],
+///     vec!["2".to_string(), "4".to_string(), "Cake".to_string()],
+/// ];
+/// let schema = infer_schema(&header, &rows);
+/// assert_eq!(schema.columns[0].col_type, "integer");
+/// assert_eq!(schema.columns[1].col_type, "float");
+/// assert_eq!(schema.columns[2].col_type, "string");
+/// ```
+pub fn infer_schema(header: &[String], rows: &[Vec<String>]) -> Schema {
+    let columns = header
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let values = rows.iter().filter_map(|row| row.get(i).map(String::as_str));
+            ColumnDef::new(name.clone(), infer_column_type(name, values))
+        })
+        .collect();
+
+    Schema::new("2.0".to_string(), false, columns)
+}
+
+/// Infers the schema of a table from a random sample of its rows.
+///
+/// Rows are drawn with `Table::sample()`, so large tables are inferred in
+/// bounded memory. Tables with at most `sample_size` rows are inferred
+/// from every row; for larger ones the types are an estimate that rows
+/// outside the sample may not fit, which is why the schema is not strict.
+///
+/// ## Input
+/// - `table`: Table to inspect
+/// - `sample_size`: Maximum number of rows to inspect
+///
+/// ## Output
+/// - `ReedResult<Schema>`: Non-strict schema, one column per header entry
+///
+/// ## Performance
+/// - One streaming pass over current.csv, O(sample_size × c) inference
+///
+/// ## Error Conditions
+/// - `TableNotFound`: Table doesn't exist
+/// - `InvalidCsv`: A sampled row cannot be parsed
+/// - `IoError`: Cannot read current.csv
+///
+/// ## Example Usage
+/// ```no_run
+/// use reedbase_last::schema::infer_table_schema;
+/// use reedbase_last::tables::Table;
+/// use std::path::Path;
+///
+/// let schema = infer_table_schema(&Table::new(Path::new(".reed"), "text"), 10_000)?;
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn infer_table_schema(table: &Table, sample_size: usize) -> ReedResult<Schema> {
+    let header = table.stream_rows()?.columns().to_vec();
+    let rows: Vec<Vec<String>> = table
+        .sample(sample_size, INFER_SAMPLE_SEED)?
+        .into_iter()
+        .map(|row| std::iter::once(row.key).chain(row.values).collect())
+        .collect();
+
+    Ok(infer_schema(&header, &rows))
+}
+
+/// Narrowest type accepted by every non-empty value of a column.
+///
+/// Shared by `infer_schema()` and `Table::column_stats()`; each distinct
+/// value only needs to be passed once.
+///
+/// ## Input
+/// - `name`: Column name
+/// - `values`: The column's values (empty ones are ignored)
+///
+/// ## Output
+/// - `"integer"`, `"float"`, `"boolean"` or `"string"` (also for no values)
+///
+/// ## Example Usage
+/// ```
+/// use reedbase_last::schema::infer::infer_column_type;
+///
+/// assert_eq!(infer_column_type("price", ["4", "", "9.99"]), "float");
+/// ```
+pub fn infer_column_type<'a>(name: &str, values: impl IntoIterator<Item = &'a str>) -> String {
+    let values: Vec<&str> = values
+        .into_iter()
+        .filter(|value| !value.is_empty())
+        .collect();
+
+    if values.is_empty() {
+        return "string".to_string();
+    }
+
+    CANDIDATE_TYPES
+        .iter()
+        .find(|col_type| {
+            let column = ColumnDef::new(name.to_string(), col_type.to_string());
+            values
+                .iter()
+                .all(|value| validate_field(value, &column).is_ok())
+        })
+        .unwrap_or(&"string")
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_infer_types() {
+        let header = strings(&["id", "ratio", "active", "flag", "name"]);
+        let rows = vec![
+            strings(&["1", "0.5", "true", "1", "alpha"]),
+            strings(&["-2", "3", "false", "0", "42"]),
+        ];
+
+        let types: Vec<String> = infer_schema(&header, &rows)
+            .columns
+            .into_iter()
+            .map(|c| c.col_type)
+            .collect();
+        assert_eq!(
+            types,
+            strings(&["integer", "float", "boolean", "integer", "string"])
+        );
+    }
+
+    #[test]
+    fn test_infer_ignores_empty_values() {
+        let header = strings(&["count", "note"]);
+        let rows = vec![strings(&["", ""]), strings(&["7"]), strings(&["8", ""])];
+
+        let schema = infer_schema(&header, &rows);
+        assert_eq!(schema.columns[0].col_type, "integer");
+        assert_eq!(schema.columns[1].col_type, "string");
+        assert!(!schema.strict);
+    }
+
+    #[test]
+    fn test_infer_table_schema_from_sample() {
+        let temp_dir = std::env::temp_dir().join("reedbase_infer_table_schema_test");
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        crate::registry::set_base_path(temp_dir.clone());
+        crate::registry::init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+
+        let mut content = String::from("key|count|label\n");
+        for i in 0..500 {
+            content.push_str(&format!("k{}|{}|item {}\n", i, i, i));
+        }
+        let table = Table::new(&temp_dir, "items");
+        table.init(content.as_bytes(), "testuser").unwrap();
+
+        let schema = infer_table_schema(&table, 50).unwrap();
+        let types: Vec<&str> = schema.columns.iter().map(|c| c.col_type.as_str()).collect();
+        assert_eq!(types, vec!["string", "integer", "string"]);
+
+        // The sample is larger than the table: every row is inspected
+        assert_eq!(
+            infer_table_schema(&table, usize::MAX).unwrap().columns,
+            schema.columns
+        );
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+}
@@ -42,7 +42,14 @@ fn parse_schema(content: &str) -> ReedResult<Schema> {
         reason: format!("TOML parse error: {}", e),
     })?;
 
-    // Validate schema
+    validate_schema(&schema)?;
+
+    Ok(schema)
+}
+
+/// Checks what every loaded schema must satisfy: at least one column, known
+/// column types, computed columns reading own columns, valid patterns.
+pub(crate) fn validate_schema(schema: &Schema) -> ReedResult<()> {
     if schema.columns.is_empty() {
         return Err(ReedError::InvalidSchema {
             reason: "Schema must have at least one column".to_string(),
@@ -64,7 +71,25 @@ fn parse_schema(content: &str) -> ReedResult<Schema> {
         }
     }
 
-    Ok(schema)
+    // Computed columns may only read columns of this schema
+    for column in &schema.columns {
+        let Some(expr) = &column.computed_from else {
+            continue;
+        };
+        for name in expr.columns() {
+            if name == column.name || schema.get_column(name).is_none() {
+                return Err(ReedError::InvalidSchema {
+                    reason: format!(
+                        "Computed column '{}' refers to invalid column '{}'",
+                        column.name, name
+                    ),
+                });
+            }
+        }
+    }
+
+    // Surface regex syntax errors now, not on the first write
+    schema.precompile_patterns()
 }
 
 /// Save schema to TOML file.
@@ -113,7 +138,7 @@ pub fn create_default_schema(column_names: &[String]) -> Schema {
 }
 
 /// Get schema file path.
-fn get_schema_path(base_path: &Path, table_name: &str) -> PathBuf {
+pub(crate) fn get_schema_path(base_path: &Path, table_name: &str) -> PathBuf {
     base_path
         .join("tables")
         .join(table_name)
@@ -8,7 +8,7 @@ mod tests {
     use crate::schema::loader::{
         create_default_schema, delete_schema, load_schema, save_schema, schema_exists,
     };
-    use crate::schema::types::{ColumnDef, Schema};
+    use crate::schema::types::{ColumnDef, ComputedExpr, Schema};
     use tempfile::TempDir;
 
     #[test]
@@ -137,6 +137,52 @@ mod tests {
         assert_eq!(loaded.columns[2].max, Some(150));
     }
 
+    #[test]
+    fn test_invalid_schema_pattern() {
+        let temp = TempDir::new().unwrap();
+        let base_path = temp.path();
+
+        let schema = Schema::new(
+            "2.0".to_string(),
+            true,
+            vec![ColumnDef::new("email".to_string(), "string".to_string())
+                .with_pattern("(unclosed".to_string())],
+        );
+        save_schema(base_path, "users", &schema).unwrap();
+
+        let result = load_schema(base_path, "users");
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid regex pattern for column 'email'"));
+    }
+
+    #[test]
+    fn test_invalid_schema_computed_reference() {
+        let temp = TempDir::new().unwrap();
+        let base_path = temp.path();
+        let computed = |expr: &str| {
+            Schema::new(
+                "2.0".to_string(),
+                false,
+                vec![
+                    ColumnDef::new("title".to_string(), "string".to_string()),
+                    ColumnDef::new("slug".to_string(), "string".to_string())
+                        .with_computed(ComputedExpr::parse(expr).unwrap()),
+                ],
+            )
+        };
+
+        save_schema(base_path, "pages", &computed("slugify(title)")).unwrap();
+        assert!(load_schema(base_path, "pages").is_ok());
+
+        for expr in ["slugify(heading)", "slugify(slug)"] {
+            save_schema(base_path, "pages", &computed(expr)).unwrap();
+            let err = load_schema(base_path, "pages").unwrap_err();
+            assert!(err.to_string().contains("Computed column 'slug'"));
+        }
+    }
+
     #[test]
     fn test_invalid_schema_empty_columns() {
         let temp = TempDir::new().unwrap();
@@ -0,0 +1,401 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Schema migrations.
+//!
+//! Applies a `MigrationPlan` (rename, add, drop and retype columns) to a
+//! table's content and schema in one step. All rows are validated against
+//! the migrated schema before anything is written, so a failing migration
+//! leaves the table untouched.
+
+use crate::error::{ReedError, ReedResult};
+use crate::schema::loader::{create_default_schema, load_schema, save_schema, schema_exists};
+use crate::schema::types::{ColumnDef, Schema};
+use crate::schema::validation::{validate_row, validate_uniqueness, CsvRow};
+use crate::tables::Table;
+
+/// Action code recorded in version.log for migrations (see actions.dict).
+pub const MIGRATE_ACTION_CODE: u8 = 12;
+
+/// Column types accepted by the schema loader.
+const COLUMN_TYPES: [&str; 5] = ["string", "integer", "float", "boolean", "timestamp"];
+
+/// Single migration step.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MigrationOp {
+    /// Rename a column (content header and schema).
+    RenameColumn { from: String, to: String },
+
+    /// Append a column, filling existing rows with `default`.
+    AddColumn {
+        name: String,
+        def: Box<ColumnDef>,
+        default: String,
+    },
+
+    /// Remove a column and its values (not the key column).
+    DropColumn { name: String },
+
+    /// Change a column's schema type; values must parse as the new type.
+    ChangeType { column: String, new_type: String },
+}
+
+/// Ordered list of migration steps and the schema version they lead to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MigrationPlan {
+    /// Schema version after migration (e.g., "2.0")
+    pub version: String,
+
+    /// Steps, applied in order
+    pub operations: Vec<MigrationOp>,
+}
+
+impl MigrationPlan {
+    /// Create an empty plan leading to `version`.
+    pub fn new(version: &str) -> Self {
+        MigrationPlan {
+            version: version.to_string(),
+            operations: Vec::new(),
+        }
+    }
+
+    /// Add a rename step.
+    pub fn rename_column(mut self, from: &str, to: &str) -> Self {
+        self.operations.push(MigrationOp::RenameColumn {
+            from: from.to_string(),
+            to: to.to_string(),
+        });
+        self
+    }
+
+    /// Add a new-column step (the column is named after `def.name`).
+    pub fn add_column(mut self, def: ColumnDef, default: &str) -> Self {
+        self.operations.push(MigrationOp::AddColumn {
+            name: def.name.clone(),
+            def: Box::new(def),
+            default: default.to_string(),
+        });
+        self
+    }
+
+    /// Add a drop step.
+    pub fn drop_column(mut self, name: &str) -> Self {
+        self.operations.push(MigrationOp::DropColumn {
+            name: name.to_string(),
+        });
+        self
+    }
+
+    /// Add a type change step.
+    pub fn change_type(mut self, column: &str, new_type: &str) -> Self {
+        self.operations.push(MigrationOp::ChangeType {
+            column: column.to_string(),
+            new_type: new_type.to_string(),
+        });
+        self
+    }
+}
+
+/// Outcome of a successful migration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MigrationReport {
+    /// Schema version before migration
+    pub from_version: String,
+
+    /// Schema version after migration
+    pub to_version: String,
+
+    /// Number of operations applied
+    pub operations: usize,
+
+    /// Number of data rows rewritten
+    pub rows: usize,
+
+    /// Timestamp of the version recording the migration (0 if the content
+    /// was unchanged)
+    pub timestamp: u64,
+}
+
+/// Line of current.csv, kept in order so comments survive the rewrite.
+enum Line {
+    /// Comment or blank line, written back unchanged
+    Verbatim(String),
+    /// Position of the header row
+    Header,
+    /// Data row split into fields
+    Row(Vec<String>),
+}
+
+/// Apply a migration plan to a table.
+///
+/// Rewrites current.csv, updates schema.toml (a table without a schema gets
+/// one, starting from all-string columns) and records the new content as
+/// one version with action code `MIGRATE_ACTION_CODE`. A plan that only
+/// changes the schema (e.g., `ChangeType`) leaves the content as it is and
+/// records no version (`timestamp` 0).
+///
+/// ## Input
+/// - `table`: Table to migrate
+/// - `plan`: Steps and target schema version
+/// - `user`: Username for audit
+///
+/// ## Output
+/// - `Result<MigrationReport>`: Versions, counts and version timestamp
+///
+/// ## Performance
+/// - O(n × k) where n = rows, k = operations, plus one delta write
+///
+/// ## Error Conditions
+/// - TableNotFound: Table doesn't exist
+/// - InvalidSchema: Plan is empty or names an unknown column type
+/// - ColumnNotFound: Step refers to a missing column
+/// - ColumnAlreadyExists: Rename or add collides with an existing column
+/// - ValidationError: Unsafe column name or default, or dropping the key column
+/// - MigrationFailed: Rows violate the migrated schema (lists every offending
+///   row; nothing is written)
+/// - InvalidCsv: Table has no header row
+/// - IoError: Cannot write files (content is restored if the schema update fails)
+///
+/// ## Example Usage
+/// ```no_run
+/// use reedbase_last::schema::migrate::{apply_migration, MigrationPlan};
+/// use reedbase_last::schema::ColumnDef;
+/// use reedbase_last::tables::Table;
+/// use std::path::Path;
+///
+/// let table = Table::new(Path::new(".reed"), "users");
+/// let plan = MigrationPlan::new("2.0")
+///     .rename_column("mail", "email")
+///     .change_type("age", "integer")
+///     .add_column(ColumnDef::new("active".to_string(), "boolean".to_string()), "true")
+///     .drop_column("legacy_id");
+/// let report = apply_migration(&table, &plan, "admin")?;
+/// println!("{} → {}: {} rows", report.from_version, report.to_version, report.rows);
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn apply_migration(
+    table: &Table,
+    plan: &MigrationPlan,
+    user: &str,
+) -> ReedResult<MigrationReport> {
+    if !table.exists() {
+        return Err(ReedError::TableNotFound {
+            name: table.name().to_string(),
+        });
+    }
+    if plan.operations.is_empty() {
+        return Err(ReedError::InvalidSchema {
+            reason: "Migration plan has no operations".to_string(),
+        });
+    }
+
+    let separator = table.delimiter()?.as_char();
+    let original = table.read_current()?;
+    let text = std::str::from_utf8(&original).map_err(|e| ReedError::InvalidCsv {
+        reason: format!("Invalid UTF-8: {}", e),
+        line: 0,
+    })?;
+
+    let mut header: Option<Vec<String>> = None;
+    let mut lines = Vec::new();
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            lines.push(Line::Verbatim(line.to_string()));
+        } else if header.is_none() {
+            header = Some(trimmed.split(separator).map(str::to_string).collect());
+            lines.push(Line::Header);
+        } else {
+            lines.push(Line::Row(
+                trimmed.split(separator).map(str::to_string).collect(),
+            ));
+        }
+    }
+    let mut header = header.ok_or_else(|| ReedError::InvalidCsv {
+        reason: "Missing header row".to_string(),
+        line: 1,
+    })?;
+
+    let mut schema = if schema_exists(table.base_path(), table.name()) {
+        load_schema(table.base_path(), table.name())?
+    } else {
+        create_default_schema(&header)
+    };
+    let from_version = schema.version.clone();
+
+    for op in &plan.operations {
+        apply_op(table, op, separator, &mut header, &mut schema, &mut lines)?;
+    }
+    schema.version = plan.version.clone();
+
+    // Validate everything before the first write
+    let rows: Vec<CsvRow> = lines
+        .iter()
+        .filter_map(|line| match line {
+            Line::Row(fields) => Some(CsvRow::new(
+                fields.first().cloned().unwrap_or_default(),
+                fields.clone(),
+            )),
+            Line::Verbatim(_) | Line::Header => None,
+        })
+        .collect();
+    let mut offending: Vec<String> = rows
+        .iter()
+        .enumerate()
+        .filter_map(|(i, row)| {
+            validate_row(row, &schema)
+                .err()
+                .map(|e| format!("row {} (key '{}'): {}", i + 1, row.key, e))
+        })
+        .collect();
+    if let Err(e) = validate_uniqueness(&rows, &schema) {
+        offending.push(e.to_string());
+    }
+    if !offending.is_empty() {
+        return Err(ReedError::MigrationFailed {
+            table: table.name().to_string(),
+            rows: offending,
+        });
+    }
+
+    let separator = separator.to_string();
+    let mut output = String::with_capacity(text.len());
+    for line in &lines {
+        match line {
+            Line::Verbatim(text) => output.push_str(text),
+            Line::Header => output.push_str(&header.join(&separator)),
+            Line::Row(fields) => output.push_str(&fields.join(&separator)),
+        }
+        output.push('\n');
+    }
+
+    let result = table.write_with_action(output.as_bytes(), user, MIGRATE_ACTION_CODE)?;
+
+    if let Err(e) = save_schema(table.base_path(), table.name(), &schema) {
+        // Keep content and schema in step
+        table.write_with_action(&original, user, MIGRATE_ACTION_CODE)?;
+        return Err(e);
+    }
+
+    Ok(MigrationReport {
+        from_version,
+        to_version: plan.version.clone(),
+        operations: plan.operations.len(),
+        rows: rows.len(),
+        timestamp: result.timestamp,
+    })
+}
+
+/// Apply one step to header, schema and rows in memory.
+fn apply_op(
+    table: &Table,
+    op: &MigrationOp,
+    separator: char,
+    header: &mut Vec<String>,
+    schema: &mut Schema,
+    lines: &mut [Line],
+) -> ReedResult<()> {
+    let not_found = |column: &str| ReedError::ColumnNotFound {
+        table: table.name().to_string(),
+        column: column.to_string(),
+    };
+    let already_exists = |column: &str| ReedError::ColumnAlreadyExists {
+        table: table.name().to_string(),
+        column: column.to_string(),
+    };
+    let is_unsafe = |s: &str| s.contains([separator, '\n', '\r']);
+    let check_name = |name: &str| {
+        if name.is_empty() || is_unsafe(name) {
+            return Err(ReedError::ValidationError {
+                column: name.to_string(),
+                reason: format!(
+                    "Column name must be non-empty without {:?} or line breaks",
+                    separator
+                ),
+                value: None,
+            });
+        }
+        if header.iter().any(|c| c == name) || schema.get_column(name).is_some() {
+            return Err(already_exists(name));
+        }
+        Ok(())
+    };
+    let check_type = |col_type: &str| {
+        if !COLUMN_TYPES.contains(&col_type) {
+            return Err(ReedError::InvalidSchema {
+                reason: format!("Invalid column type '{}'", col_type),
+            });
+        }
+        Ok(())
+    };
+
+    match op {
+        MigrationOp::RenameColumn { from, to } => {
+            let index = header
+                .iter()
+                .position(|c| c == from)
+                .ok_or_else(|| not_found(from))?;
+            check_name(to)?;
+            header[index] = to.clone();
+            if let Some(column) = schema.columns.iter_mut().find(|c| c.name == *from) {
+                column.name = to.clone();
+            }
+        }
+        MigrationOp::AddColumn { name, def, default } => {
+            check_name(name)?;
+            check_type(&def.col_type)?;
+            if is_unsafe(default) {
+                return Err(ReedError::ValidationError {
+                    column: name.clone(),
+                    reason: format!(
+                        "Default value must not contain {:?} or line breaks",
+                        separator
+                    ),
+                    value: Some(default.clone()),
+                });
+            }
+            header.push(name.clone());
+            let mut column = ColumnDef::clone(def);
+            column.name = name.clone();
+            schema.columns.push(column);
+            for line in lines.iter_mut() {
+                if let Line::Row(fields) = line {
+                    fields.push(default.clone());
+                }
+            }
+        }
+        MigrationOp::DropColumn { name } => {
+            let index = header
+                .iter()
+                .position(|c| c == name)
+                .ok_or_else(|| not_found(name))?;
+            if index == 0 {
+                return Err(ReedError::ValidationError {
+                    column: name.clone(),
+                    reason: "Cannot drop the key column".to_string(),
+                    value: None,
+                });
+            }
+            header.remove(index);
+            schema.columns.retain(|c| c.name != *name);
+            for line in lines.iter_mut() {
+                if let Line::Row(fields) = line {
+                    if index < fields.len() {
+                        fields.remove(index);
+                    }
+                }
+            }
+        }
+        MigrationOp::ChangeType { column, new_type } => {
+            check_type(new_type)?;
+            let def = schema
+                .columns
+                .iter_mut()
+                .find(|c| c.name == *column)
+                .ok_or_else(|| not_found(column))?;
+            def.col_type = new_type.clone();
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,179 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for schema migrations.
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ReedError;
+    use crate::registry::init_registry;
+    use crate::schema::loader::{load_schema, save_schema, schema_exists};
+    use crate::schema::migrate::{apply_migration, MigrationPlan};
+    use crate::schema::types::{ColumnDef, Schema};
+    use crate::tables::Table;
+    use std::fs;
+
+    fn setup_test(name: &str) -> std::path::PathBuf {
+        let temp_dir = std::env::temp_dir().join(format!("reedbase_migrate_test_{}", name));
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        crate::registry::set_base_path(temp_dir.clone());
+        init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+
+        temp_dir
+    }
+
+    fn v1_schema() -> Schema {
+        Schema::new(
+            "1.0".to_string(),
+            true,
+            vec![
+                ColumnDef::primary_key("id".to_string(), "string".to_string()),
+                ColumnDef::new("mail".to_string(), "string".to_string()),
+                ColumnDef::new("age".to_string(), "string".to_string()),
+                ColumnDef::new("legacy".to_string(), "string".to_string()),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_migrate_v1_to_v2() {
+        let temp_dir = setup_test("v1_to_v2");
+        let table = Table::new(&temp_dir, "users");
+        table
+            .init(
+                b"# users\nid|mail|age|legacy\nu1|a@x.org|30|x\nu2|b@x.org||y\n",
+                "testuser",
+            )
+            .unwrap();
+        save_schema(&temp_dir, "users", &v1_schema()).unwrap();
+
+        let plan = MigrationPlan::new("2.0")
+            .rename_column("mail", "email")
+            .change_type("age", "integer")
+            .add_column(
+                ColumnDef::new("active".to_string(), "boolean".to_string()),
+                "true",
+            )
+            .drop_column("legacy");
+        let report = apply_migration(&table, &plan, "testuser").unwrap();
+
+        assert_eq!(report.from_version, "1.0");
+        assert_eq!(report.to_version, "2.0");
+        assert_eq!((report.operations, report.rows), (4, 2));
+        assert_eq!(
+            String::from_utf8(table.read_current().unwrap()).unwrap(),
+            "# users\nid|email|age|active\nu1|a@x.org|30|true\nu2|b@x.org||true\n"
+        );
+
+        let schema = load_schema(&temp_dir, "users").unwrap();
+        assert_eq!(schema.version, "2.0");
+        let columns: Vec<(&str, &str)> = schema
+            .columns
+            .iter()
+            .map(|c| (c.name.as_str(), c.col_type.as_str()))
+            .collect();
+        assert_eq!(
+            columns,
+            vec![
+                ("id", "string"),
+                ("email", "string"),
+                ("age", "integer"),
+                ("active", "boolean")
+            ]
+        );
+
+        let versions = table.list_versions().unwrap();
+        assert_eq!(versions[0].action, "migrate");
+        assert_eq!(versions[0].timestamp, report.timestamp);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_migrate_failure_is_atomic() {
+        let temp_dir = setup_test("atomic");
+        let table = Table::new(&temp_dir, "users");
+        let content = b"id|mail|age|legacy\nu1|a@x.org|30|x\nu2|b@x.org|old|y\nu3|c@x.org|n/a|z\n";
+        table.init(content, "testuser").unwrap();
+        save_schema(&temp_dir, "users", &v1_schema()).unwrap();
+
+        let plan = MigrationPlan::new("2.0")
+            .rename_column("mail", "email")
+            .change_type("age", "integer");
+        match apply_migration(&table, &plan, "testuser") {
+            Err(ReedError::MigrationFailed { table, rows }) => {
+                assert_eq!(table, "users");
+                assert_eq!(rows.len(), 2);
+                assert!(rows[0].contains("row 2 (key 'u2')"));
+                assert!(rows[1].contains("row 3 (key 'u3')"));
+            }
+            other => panic!("Expected MigrationFailed, got {:?}", other),
+        }
+
+        // Content, schema and history unchanged
+        assert_eq!(table.read_current().unwrap(), content);
+        assert_eq!(load_schema(&temp_dir, "users").unwrap(), v1_schema());
+        assert_eq!(table.list_versions().unwrap().len(), 1);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_migrate_invalid_plans() {
+        let temp_dir = setup_test("invalid");
+        let table = Table::new(&temp_dir, "users");
+        let plan = MigrationPlan::new("2.0").drop_column("legacy");
+        assert!(matches!(
+            apply_migration(&table, &plan, "testuser"),
+            Err(ReedError::TableNotFound { .. })
+        ));
+
+        table
+            .init(b"id|mail|legacy\nu1|a@x.org|x\n", "testuser")
+            .unwrap();
+
+        let cases = [
+            MigrationPlan::new("2.0"),
+            MigrationPlan::new("2.0").rename_column("missing", "x"),
+            MigrationPlan::new("2.0").rename_column("mail", "legacy"),
+            MigrationPlan::new("2.0").drop_column("id"),
+            MigrationPlan::new("2.0").change_type("mail", "uuid"),
+            MigrationPlan::new("2.0")
+                .add_column(ColumnDef::new("a|b".to_string(), "string".to_string()), ""),
+        ];
+        let errors: Vec<ReedError> = cases
+            .iter()
+            .map(|plan| apply_migration(&table, plan, "testuser").unwrap_err())
+            .collect();
+        assert!(matches!(errors[0], ReedError::InvalidSchema { .. }));
+        assert!(matches!(errors[1], ReedError::ColumnNotFound { .. }));
+        assert!(matches!(errors[2], ReedError::ColumnAlreadyExists { .. }));
+        assert!(matches!(errors[3], ReedError::ValidationError { .. }));
+        assert!(matches!(errors[4], ReedError::InvalidSchema { .. }));
+        assert!(matches!(errors[5], ReedError::ValidationError { .. }));
+
+        assert_eq!(table.list_versions().unwrap().len(), 1);
+        assert!(!schema_exists(&temp_dir, "users"));
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_migrate_without_schema() {
+        let temp_dir = setup_test("no_schema");
+        let table = Table::new(&temp_dir, "users");
+        table.init(b"id|age\nu1|30\nu2|41\n", "testuser").unwrap();
+
+        let plan = MigrationPlan::new("2.0").change_type("age", "integer");
+        let report = apply_migration(&table, &plan, "testuser").unwrap();
+        assert_eq!(report.rows, 2);
+
+        let schema = load_schema(&temp_dir, "users").unwrap();
+        assert_eq!(schema.get_column("age").unwrap().col_type, "integer");
+        assert_eq!(schema.version, "2.0");
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+}
@@ -6,6 +6,7 @@
 //! Provides comprehensive schema validation for ReedBase:
 //! - **RBKS v2 Key Validation** - Structured key format enforcement
 //! - **Column Schema Validation** - Type and constraint enforcement
+//! - **Schema Inference** - Column types derived from existing data
 //!
 //! ## Key Validation (RBKS v2)
 //!
@@ -34,7 +35,7 @@
 //!
 //! ### Column Types
 //!
-//! - **string**: Text data with length and pattern constraints
+//! - **string**: Text data with length constraints
 //! - **integer**: Whole numbers with min/max range
 //! - **float**: Decimal numbers
 //! - **boolean**: True/false values
@@ -47,7 +48,9 @@
 //! - **primary_key**: Required + unique
 //! - **min/max**: Range constraints for integer/float
 //! - **min_length/max_length**: Length constraints for string
-//! - **pattern**: Regex validation for string
+//! - **pattern**: Regex the value must match (any type; compiled once at load)
+//! - **foreign_key**: Referenced `table.column` (see `Database::check_referential_integrity`)
+//! - **computed_from**: Expression deriving a read-only value, e.g. `"slugify(title)"`
 //!
 //! ## Example Usage
 //!
@@ -84,16 +87,23 @@
 //! - **Catch errors early** at write time
 //! - **Enables O(1) queries** via Smart Indices
 
+pub mod infer;
 pub mod loader;
+pub mod migrate;
 pub mod rbks;
+pub mod registry;
 pub mod types;
 pub mod validation;
 
 #[cfg(test)]
 mod loader_test;
 #[cfg(test)]
+mod migrate_test;
+#[cfg(test)]
 mod rbks_test;
 #[cfg(test)]
+mod registry_test;
+#[cfg(test)]
 mod validation_test;
 
 // Re-export commonly used types
@@ -105,6 +115,12 @@ pub use rbks::{
 };
 
 // Column schema validation
+pub use infer::{infer_schema, infer_table_schema};
 pub use loader::{create_default_schema, delete_schema, load_schema, save_schema, schema_exists};
-pub use types::{ColumnDef, Schema};
-pub use validation::{validate_row, validate_rows, validate_uniqueness, CsvRow};
+pub use migrate::{apply_migration, MigrationOp, MigrationPlan, MigrationReport};
+pub use registry::SchemaRegistry;
+pub use types::{ColumnDef, ComputedArg, ComputedExpr, ForeignKey, Schema};
+pub use validation::{
+    apply_computed_columns, validate_foreign_keys, validate_row, validate_rows,
+    validate_uniqueness, validate_uniqueness_indexed, CsvRow, FkViolation, UniquenessViolation,
+};
@@ -0,0 +1,108 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Schema cache shared across tables.
+//!
+//! Loading a schema means reading and parsing `tables/<name>/schema.toml`.
+//! The registry keeps each parsed schema together with the file's
+//! modification time and only re-reads the file when that time changes, so
+//! the write path can validate every row without a TOML parse per statement.
+
+use crate::error::{ReedError, ReedResult};
+use crate::schema::loader::{get_schema_path, load_schema};
+use crate::schema::types::Schema;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::SystemTime;
+
+/// Cache of loaded table schemas keyed by table name.
+///
+/// ## Thread Safety
+/// - Not synchronised; `Database` wraps it in a `Mutex`
+#[derive(Debug, Default)]
+pub struct SchemaRegistry {
+    /// Table name → (schema file mtime at load, parsed schema)
+    cache: HashMap<String, (SystemTime, Schema)>,
+}
+
+impl SchemaRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the schema for a table, re-reading it only when stale.
+    ///
+    /// ## Input
+    /// - `table`: Table name
+    /// - `base_path`: Path to ReedBase directory
+    ///
+    /// ## Output
+    /// - `Ok(Some(&Schema))`: Schema file exists (cached or freshly loaded)
+    /// - `Ok(None)`: Table has no schema file
+    ///
+    /// ## Performance
+    /// - Cached: one `stat()` call, ~1-5μs
+    /// - Stale or first access: < 5ms (TOML parsing)
+    ///
+    /// ## Error Conditions
+    /// - `IoError`: Cannot read schema file metadata or content
+    /// - `InvalidSchema`: Schema file is not valid TOML or has no columns
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::schema::SchemaRegistry;
+    /// use std::path::Path;
+    ///
+    /// let mut registry = SchemaRegistry::new();
+    /// if let Some(schema) = registry.get("users", Path::new(".reed"))? {
+    ///     println!("{} columns", schema.columns.len());
+    /// }
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn get(&mut self, table: &str, base_path: &Path) -> ReedResult<Option<&Schema>> {
+        let schema_path = get_schema_path(base_path, table);
+
+        if !schema_path.exists() {
+            self.cache.remove(table);
+            return Ok(None);
+        }
+
+        let mtime = fs::metadata(&schema_path)
+            .and_then(|m| m.modified())
+            .map_err(|e| ReedError::IoError {
+                operation: format!("stat schema file '{}'", schema_path.display()),
+                reason: e.to_string(),
+            })?;
+
+        let stale = match self.cache.get(table) {
+            Some((cached_mtime, _)) => *cached_mtime != mtime,
+            None => true,
+        };
+
+        if stale {
+            let schema = load_schema(base_path, table)?;
+            self.cache.insert(table.to_string(), (mtime, schema));
+        }
+
+        Ok(self.cache.get(table).map(|(_, schema)| schema))
+    }
+
+    /// Drops the cached schema for a table.
+    ///
+    /// The next `get()` re-reads the file regardless of its mtime.
+    pub fn invalidate(&mut self, table: &str) {
+        self.cache.remove(table);
+    }
+
+    /// Number of cached schemas.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Returns true if no schemas are cached.
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for the schema registry.
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::loader::{delete_schema, save_schema};
+    use crate::schema::registry::SchemaRegistry;
+    use crate::schema::types::{ColumnDef, Schema};
+    use std::fs;
+    use std::time::{Duration, SystemTime};
+    use tempfile::TempDir;
+
+    fn schema_with(columns: &[&str]) -> Schema {
+        let columns = columns
+            .iter()
+            .map(|name| ColumnDef::new(name.to_string(), "string".to_string()))
+            .collect();
+        Schema::new("2.0".to_string(), true, columns)
+    }
+
+    /// Pushes the schema file's mtime forward so staleness does not depend
+    /// on filesystem timestamp granularity.
+    fn touch_forward(base_path: &std::path::Path, table: &str) {
+        let path = base_path.join("tables").join(table).join("schema.toml");
+        let file = fs::OpenOptions::new().write(true).open(path).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(5))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_get_without_schema_file() {
+        let temp = TempDir::new().unwrap();
+        let mut registry = SchemaRegistry::new();
+
+        assert!(registry.get("users", temp.path()).unwrap().is_none());
+        assert!(registry.is_empty());
+    }
+
+    #[test]
+    fn test_get_caches_schema() {
+        let temp = TempDir::new().unwrap();
+        save_schema(temp.path(), "users", &schema_with(&["key", "name"])).unwrap();
+
+        let mut registry = SchemaRegistry::new();
+        let schema = registry.get("users", temp.path()).unwrap().unwrap();
+        assert_eq!(schema.columns.len(), 2);
+        assert_eq!(registry.len(), 1);
+
+        // Second access hits the cache
+        let schema = registry.get("users", temp.path()).unwrap().unwrap();
+        assert_eq!(schema.columns.len(), 2);
+        assert_eq!(registry.len(), 1);
+    }
+
+    #[test]
+    fn test_get_reloads_after_modification() {
+        let temp = TempDir::new().unwrap();
+        save_schema(temp.path(), "users", &schema_with(&["key", "name"])).unwrap();
+
+        let mut registry = SchemaRegistry::new();
+        assert_eq!(
+            registry
+                .get("users", temp.path())
+                .unwrap()
+                .unwrap()
+                .columns
+                .len(),
+            2
+        );
+
+        save_schema(
+            temp.path(),
+            "users",
+            &schema_with(&["key", "name", "email"]),
+        )
+        .unwrap();
+        touch_forward(temp.path(), "users");
+
+        let schema = registry.get("users", temp.path()).unwrap().unwrap();
+        assert_eq!(schema.columns.len(), 3);
+        assert_eq!(schema.columns[2].name, "email");
+    }
+
+    #[test]
+    fn test_get_drops_deleted_schema() {
+        let temp = TempDir::new().unwrap();
+        save_schema(temp.path(), "users", &schema_with(&["key", "name"])).unwrap();
+
+        let mut registry = SchemaRegistry::new();
+        assert!(registry.get("users", temp.path()).unwrap().is_some());
+
+        delete_schema(temp.path(), "users").unwrap();
+        assert!(registry.get("users", temp.path()).unwrap().is_none());
+        assert!(registry.is_empty());
+    }
+
+    #[test]
+    fn test_invalidate_forces_reload() {
+        let temp = TempDir::new().unwrap();
+        save_schema(temp.path(), "users", &schema_with(&["key", "name"])).unwrap();
+
+        let mut registry = SchemaRegistry::new();
+        registry.get("users", temp.path()).unwrap();
+
+        registry.invalidate("users");
+        assert!(registry.is_empty());
+        assert!(registry.get("users", temp.path()).unwrap().is_some());
+    }
+}
@@ -5,7 +5,11 @@
 //!
 //! Defines the structure for TOML-based table schemas with type and constraint validation.
 
+use crate::error::{ReedError, ReedResult};
+use once_cell::sync::OnceCell;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 /// Table schema definition.
 ///
@@ -37,6 +41,20 @@ impl Schema {
         self.columns.iter().find(|c| c.name == name)
     }
 
+    /// Compile the regex patterns of all columns.
+    ///
+    /// Patterns are otherwise compiled on first use. Called when a schema is
+    /// loaded, so syntax errors surface then instead of on the first write.
+    ///
+    /// ## Error Conditions
+    /// - `InvalidSchema`: A pattern is not a valid regex
+    pub fn precompile_patterns(&self) -> ReedResult<()> {
+        for column in &self.columns {
+            column.pattern_regex()?;
+        }
+        Ok(())
+    }
+
     /// Get column count.
     pub fn column_count(&self) -> usize {
         self.columns.len()
@@ -86,9 +104,33 @@ pub struct ColumnDef {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_length: Option<usize>,
 
-    /// Regex pattern (for string validation)
+    /// Regex pattern (checked for any column type)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pattern: Option<String>,
+
+    /// Referenced column as `table.column` (foreign key)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foreign_key: Option<String>,
+
+    /// Expression deriving the value from other columns (read-only column)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub computed_from: Option<ComputedExpr>,
+
+    /// Compiled `pattern`, filled on first use
+    #[serde(skip)]
+    compiled_pattern: CompiledPattern,
+}
+
+/// Lazily compiled column pattern.
+///
+/// Ignored by equality, so columns compare by their definition only.
+#[derive(Debug, Clone, Default)]
+struct CompiledPattern(OnceCell<Box<Regex>>);
+
+impl PartialEq for CompiledPattern {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
 }
 
 impl ColumnDef {
@@ -105,6 +147,9 @@ impl ColumnDef {
             min_length: None,
             max_length: None,
             pattern: None,
+            foreign_key: None,
+            computed_from: None,
+            compiled_pattern: CompiledPattern::default(),
         }
     }
 
@@ -121,6 +166,9 @@ impl ColumnDef {
             min_length: None,
             max_length: None,
             pattern: None,
+            foreign_key: None,
+            computed_from: None,
+            compiled_pattern: CompiledPattern::default(),
         }
     }
 
@@ -163,9 +211,71 @@ impl ColumnDef {
     /// Set pattern.
     pub fn with_pattern(mut self, pattern: String) -> Self {
         self.pattern = Some(pattern);
+        self.compiled_pattern = CompiledPattern::default();
+        self
+    }
+
+    /// Set foreign key reference (`table.column`).
+    pub fn with_foreign_key(mut self, reference: String) -> Self {
+        self.foreign_key = Some(reference);
+        self
+    }
+
+    /// Referenced `(table, column)` of a foreign key, if set and well-formed.
+    pub fn foreign_key_target(&self) -> Option<(&str, &str)> {
+        self.foreign_key
+            .as_deref()?
+            .split_once('.')
+            .filter(|(table, column)| !table.is_empty() && !column.is_empty())
+    }
+
+    /// Set computed expression (column becomes read-only).
+    pub fn with_computed(mut self, expr: ComputedExpr) -> Self {
+        self.computed_from = Some(expr);
         self
     }
 
+    /// Check if column is derived from other columns.
+    pub fn is_computed(&self) -> bool {
+        self.computed_from.is_some()
+    }
+
+    /// Compiled regex of `pattern`, if set.
+    ///
+    /// Compiled once and cached on the column. If `pattern` was changed
+    /// after the cache was filled, the new pattern is compiled uncached.
+    ///
+    /// ## Error Conditions
+    /// - `InvalidSchema`: Pattern is not a valid regex
+    pub fn pattern_regex(&self) -> ReedResult<Option<Cow<'_, Regex>>> {
+        let Some(pattern) = self.pattern.as_deref() else {
+            return Ok(None);
+        };
+        let compile = || {
+            Regex::new(pattern).map_err(|e| ReedError::InvalidSchema {
+                reason: format!("Invalid regex pattern for column '{}': {}", self.name, e),
+            })
+        };
+
+        let regex = self
+            .compiled_pattern
+            .0
+            .get_or_try_init(|| compile().map(Box::new))?;
+        if regex.as_str() == pattern {
+            Ok(Some(Cow::Borrowed(regex.as_ref())))
+        } else {
+            compile().map(|regex| Some(Cow::Owned(regex)))
+        }
+    }
+
+    /// Foreign key of the column, if set and well-formed.
+    ///
+    /// Owned form of `foreign_key_target()`.
+    pub fn references(&self) -> Option<ForeignKey> {
+        self.foreign_key_target()
+            .map(|(table, column)| ForeignKey::new(table, column))
+    }
+
     /// Check if column is required (either explicitly or via primary_key).
     pub fn is_required(&self) -> bool {
         self.required || self.primary_key
@@ -177,6 +287,230 @@ impl ColumnDef {
     }
 }
 
+/// Column referenced by a foreign key.
+///
+/// Stored in the schema as `foreign_key = "table.column"`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ForeignKey {
+    /// Referenced table
+    pub table: String,
+
+    /// Referenced column
+    pub column: String,
+}
+
+impl ForeignKey {
+    /// Create a foreign key reference.
+    pub fn new(table: &str, column: &str) -> Self {
+        ForeignKey {
+            table: table.to_string(),
+            column: column.to_string(),
+        }
+    }
+}
+
+/// Functions usable in computed columns, with their argument counts.
+///
+/// All come from `functions::transformations` and `functions::computed`.
+const COMPUTED_FUNCTIONS: [(&str, usize); 22] = [
+    ("normalize_email", 1),
+    ("trim", 1),
+    ("capitalize", 1),
+    ("slugify", 1),
+    ("truncate", 2),
+    ("replace", 3),
+    ("uppercase", 1),
+    ("lowercase", 1),
+    ("remove_whitespace", 1),
+    ("pad_right", 2),
+    ("reverse", 1),
+    ("parse_json", 1),
+    ("extract_json_field", 2),
+    ("calculate_age", 1),
+    ("full_name", 2),
+    ("days_since", 1),
+    ("is_expired", 1),
+    ("format_date", 2),
+    ("calculate_discount", 2),
+    ("validate_date", 1),
+    ("add_days", 2),
+    ("subtract_dates", 2),
+];
+
+/// Argument of a computed expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComputedArg {
+    /// Value of another column, written as its bare name
+    Column(String),
+
+    /// Fixed value, written in single quotes
+    Literal(String),
+}
+
+/// Expression of a computed column: one function applied to columns.
+///
+/// Stored in the schema as a string, e.g. `computed_from = "slugify(title)"`
+/// or `computed_from = "truncate(title, '20')"`. Bare names refer to
+/// columns, quoted values and bare numbers (`add_days(created, 30)`) are
+/// literals; quoted literals cannot contain commas.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ComputedExpr {
+    /// Function from `functions::transformations` or `functions::computed`
+    pub function: String,
+
+    /// Arguments in call order
+    pub args: Vec<ComputedArg>,
+}
+
+impl ComputedExpr {
+    /// Parse an expression such as `full_name(first, last)`.
+    ///
+    /// ## Error Conditions
+    /// - `InvalidSchema`: Malformed expression, unknown function or wrong
+    ///   number of arguments
+    pub fn parse(expr: &str) -> ReedResult<Self> {
+        let invalid = |reason: &str| ReedError::InvalidSchema {
+            reason: format!("Invalid computed expression '{}': {}", expr, reason),
+        };
+
+        let (function, rest) = expr
+            .trim()
+            .split_once('(')
+            .ok_or_else(|| invalid("missing '('"))?;
+        let inner = rest
+            .strip_suffix(')')
+            .ok_or_else(|| invalid("missing ')'"))?;
+        let function = function.trim();
+
+        let args = if inner.trim().is_empty() {
+            Vec::new()
+        } else {
+            inner
+                .split(',')
+                .map(|arg| {
+                    let arg = arg.trim();
+                    if let Some(literal) = arg.strip_prefix('\'').and_then(|a| a.strip_suffix('\''))
+                    {
+                        Ok(ComputedArg::Literal(literal.to_string()))
+                    } else if arg.starts_with(|c: char| c.is_ascii_digit() || c == '-')
+                        && arg.parse::<f64>().is_ok()
+                    {
+                        Ok(ComputedArg::Literal(arg.to_string()))
+                    } else if !arg.is_empty()
+                        && arg
+                            .chars()
+                            .all(|c| c.is_alphanumeric() || c == '_' || c == '.')
+                    {
+                        Ok(ComputedArg::Column(arg.to_string()))
+                    } else {
+                        Err(invalid(&format!("bad argument '{}'", arg)))
+                    }
+                })
+                .collect::<ReedResult<Vec<_>>>()?
+        };
+
+        let arity = COMPUTED_FUNCTIONS
+            .iter()
+            .find(|(name, _)| *name == function)
+            .map(|(_, arity)| *arity)
+            .ok_or_else(|| invalid(&format!("unknown function '{}'", function)))?;
+        if args.len() != arity {
+            return Err(invalid(&format!(
+                "'{}' takes {} argument(s), got {}",
+                function,
+                arity,
+                args.len()
+            )));
+        }
+
+        Ok(ComputedExpr {
+            function: function.to_string(),
+            args,
+        })
+    }
+
+    /// Names of the columns the expression reads.
+    pub fn columns(&self) -> impl Iterator<Item = &str> {
+        self.args.iter().filter_map(|arg| match arg {
+            ComputedArg::Column(name) => Some(name.as_str()),
+            ComputedArg::Literal(_) => None,
+        })
+    }
+
+    /// Evaluate the expression with resolved argument values.
+    ///
+    /// ## Input
+    /// - `args`: One value per argument, in call order
+    ///
+    /// ## Error Conditions
+    /// - Errors of the called function (e.g. `ParseError` for a bad date)
+    pub fn call(&self, args: &[&str]) -> ReedResult<String> {
+        use crate::functions::{computed as c, transformations as t};
+
+        match (self.function.as_str(), args) {
+            ("normalize_email", [a]) => t::normalize_email(a),
+            ("trim", [a]) => t::trim(a),
+            ("capitalize", [a]) => t::capitalize(a),
+            ("slugify", [a]) => t::slugify(a),
+            ("truncate", [a, b]) => t::truncate(a, b),
+            ("replace", [a, b, c]) => t::replace(a, b, c),
+            ("uppercase", [a]) => t::uppercase(a),
+            ("lowercase", [a]) => t::lowercase(a),
+            ("remove_whitespace", [a]) => t::remove_whitespace(a),
+            ("pad_right", [a, b]) => t::pad_right(a, b),
+            ("reverse", [a]) => t::reverse(a),
+            ("parse_json", [a]) => t::parse_json(a),
+            ("extract_json_field", [a, b]) => t::extract_json_field(a, b),
+            ("calculate_age", [a]) => c::calculate_age(a),
+            ("full_name", [a, b]) => c::full_name(a, b),
+            ("days_since", [a]) => c::days_since(a),
+            ("is_expired", [a]) => c::is_expired(a),
+            ("format_date", [a, b]) => c::format_date(a, b),
+            ("calculate_discount", [a, b]) => c::calculate_discount(a, b),
+            ("validate_date", [a]) => c::validate_date(a).map(|valid| valid.to_string()),
+            ("add_days", [a, b]) => {
+                let days = b.parse().map_err(|_| ReedError::ParseError {
+                    reason: format!("Invalid day count '{}'", b),
+                })?;
+                c::add_days(a, days)
+            }
+            ("subtract_dates", [a, b]) => c::subtract_dates(a, b).map(|days| days.to_string()),
+            _ => Err(ReedError::InvalidSchema {
+                reason: format!("Invalid computed expression '{}'", self),
+            }),
+        }
+    }
+}
+
+impl std::fmt::Display for ComputedExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let args: Vec<String> = self
+            .args
+            .iter()
+            .map(|arg| match arg {
+                ComputedArg::Column(name) => name.clone(),
+                ComputedArg::Literal(value) => format!("'{}'", value),
+            })
+            .collect();
+        write!(f, "{}({})", self.function, args.join(", "))
+    }
+}
+
+impl TryFrom<String> for ComputedExpr {
+    type Error = ReedError;
+
+    fn try_from(expr: String) -> ReedResult<Self> {
+        ComputedExpr::parse(&expr)
+    }
+}
+
+impl From<ComputedExpr> for String {
+    fn from(expr: ComputedExpr) -> Self {
+        expr.to_string()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -303,4 +637,113 @@ mod tests {
         let col = ColumnDef::primary_key("id".to_string(), "integer".to_string());
         assert!(col.is_unique());
     }
+
+    #[test]
+    fn test_column_def_pattern_regex_cached() {
+        let col = ColumnDef::new("slug".to_string(), "string".to_string());
+        assert!(col.pattern_regex().unwrap().is_none());
+
+        let col = col.with_pattern(r"^[a-z-]+$".to_string());
+        let first = col.pattern_regex().unwrap().unwrap();
+        let second = col.pattern_regex().unwrap().unwrap();
+        assert!(matches!(first, Cow::Borrowed(_)));
+        assert!(std::ptr::eq(first.as_ref(), second.as_ref()));
+        assert!(second.is_match("about-us"));
+
+        // Clones share the definition; equality ignores the cache
+        assert_eq!(col.clone(), col);
+
+        // A pattern changed in place is not served from the stale cache
+        let mut col = col;
+        col.pattern = Some(r"^[0-9]+$".to_string());
+        let regex = col.pattern_regex().unwrap().unwrap();
+        assert!(regex.is_match("42") && !regex.is_match("about-us"));
+
+        let col = col.with_pattern("[unclosed".to_string());
+        assert!(matches!(
+            col.pattern_regex(),
+            Err(ReedError::InvalidSchema { .. })
+        ));
+    }
+
+    #[test]
+    fn test_schema_precompile_patterns() {
+        let valid = ColumnDef::new("slug".to_string(), "string".to_string())
+            .with_pattern(r"^[a-z]+$".to_string());
+        let schema = Schema::new("2.0".to_string(), false, vec![valid.clone()]);
+        assert!(schema.precompile_patterns().is_ok());
+
+        let invalid =
+            ColumnDef::new("code".to_string(), "string".to_string()).with_pattern("(".to_string());
+        let schema = Schema::new("2.0".to_string(), false, vec![valid, invalid]);
+        let err = schema.precompile_patterns().unwrap_err();
+        assert!(err.to_string().contains("'code'"));
+    }
+
+    #[test]
+    fn test_computed_expr_parse() {
+        let expr = ComputedExpr::parse(" truncate( title , '20') ").unwrap();
+        assert_eq!(expr.function, "truncate");
+        assert_eq!(
+            expr.args,
+            vec![
+                ComputedArg::Column("title".to_string()),
+                ComputedArg::Literal("20".to_string())
+            ]
+        );
+        assert_eq!(expr.columns().collect::<Vec<_>>(), vec!["title"]);
+        assert_eq!(expr.to_string(), "truncate(title, '20')");
+        assert_eq!(expr.call(&["Hello", "20"]).unwrap(), "Hello");
+
+        // Bare numbers are literals
+        let expr = ComputedExpr::parse("add_days(created, -30)").unwrap();
+        assert_eq!(expr.args[1], ComputedArg::Literal("-30".to_string()));
+        assert_eq!(expr.call(&["2025-03-01", "-30"]).unwrap(), "2025-01-30");
+        assert!(expr.call(&["2025-03-01", "soon"]).is_err());
+
+        for bad in [
+            "slugify",
+            "slugify(title",
+            "shout(title)",
+            "slugify(title, body)",
+            "slugify(ti tle)",
+        ] {
+            assert!(
+                matches!(
+                    ComputedExpr::parse(bad),
+                    Err(ReedError::InvalidSchema { .. })
+                ),
+                "{}",
+                bad
+            );
+        }
+    }
+
+    #[test]
+    fn test_computed_expr_toml() {
+        let col = ColumnDef::new("slug".to_string(), "string".to_string())
+            .with_computed(ComputedExpr::parse("slugify(title)").unwrap());
+        assert!(col.is_computed());
+
+        let toml = toml::to_string(&col).unwrap();
+        assert!(toml.contains("computed_from = \"slugify(title)\""));
+        assert_eq!(toml::from_str::<ColumnDef>(&toml).unwrap(), col);
+
+        let invalid = toml.replace("slugify", "shout");
+        assert!(toml::from_str::<ColumnDef>(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_column_def_foreign_key_target() {
+        let col = ColumnDef::new("text_key".to_string(), "string".to_string())
+            .with_foreign_key("text.key".to_string());
+        assert_eq!(col.foreign_key_target(), Some(("text", "key")));
+        assert_eq!(col.references(), Some(ForeignKey::new("text", "key")));
+
+        let col = col.with_foreign_key("text".to_string());
+        assert_eq!(col.foreign_key_target(), None);
+
+        let col = ColumnDef::new("id".to_string(), "integer".to_string());
+        assert_eq!(col.foreign_key_target(), None);
+    }
 }
@@ -5,10 +5,12 @@
 //!
 //! Validates rows against schema definitions with type and constraint checking.
 
+use crate::database::integrity::{read_column_values, reference_exists};
+use crate::database::Database;
 use crate::error::{ReedError, ReedResult};
-use crate::schema::types::{ColumnDef, Schema};
-use regex::Regex;
-use std::collections::HashSet;
+use crate::indices::Index;
+use crate::schema::types::{ColumnDef, ComputedArg, ForeignKey, Schema};
+use std::collections::{HashMap, HashSet};
 
 /// CSV row representation.
 #[derive(Debug, Clone, PartialEq)]
@@ -33,6 +35,10 @@ impl CsvRow {
 /// - `Ok(())`: Row is valid
 /// - `Err(ReedError::ValidationError)`: Validation failed
 ///
+/// Foreign keys are not checked here, since that needs the referenced
+/// tables. Callers collect the rows and check them in one batch with
+/// `validate_foreign_keys()`.
+///
 /// ## Performance
 /// - < 1ms per row typical
 pub fn validate_row(row: &CsvRow, schema: &Schema) -> ReedResult<()> {
@@ -59,7 +65,7 @@ pub fn validate_row(row: &CsvRow, schema: &Schema) -> ReedResult<()> {
 }
 
 /// Validate single field against column definition.
-fn validate_field(value: &str, column: &ColumnDef) -> ReedResult<()> {
+pub(crate) fn validate_field(value: &str, column: &ColumnDef) -> ReedResult<()> {
     // Check required
     if column.is_required() && value.is_empty() {
         return Err(ReedError::ValidationError {
@@ -90,6 +96,17 @@ fn validate_field(value: &str, column: &ColumnDef) -> ReedResult<()> {
         }
     }
 
+    // Pattern constraint (compiled once per column)
+    if let Some(regex) = column.pattern_regex()? {
+        if !regex.is_match(value) {
+            return Err(ReedError::ValidationError {
+                column: column.name.clone(),
+                reason: format!("Value does not match pattern: {}", regex.as_str()),
+                value: Some(value.to_string()),
+            });
+        }
+    }
+
     Ok(())
 }
 
@@ -124,23 +141,6 @@ fn validate_string(value: &str, column: &ColumnDef) -> ReedResult<()> {
         }
     }
 
-    // Check pattern constraint
-    if let Some(ref pattern) = column.pattern {
-        let regex = Regex::new(pattern).map_err(|e| ReedError::ValidationError {
-            column: column.name.clone(),
-            reason: format!("Invalid regex pattern: {}", e),
-            value: None,
-        })?;
-
-        if !regex.is_match(value) {
-            return Err(ReedError::ValidationError {
-                column: column.name.clone(),
-                reason: format!("Value does not match pattern: {}", pattern),
-                value: Some(value.to_string()),
-            });
-        }
-    }
-
     Ok(())
 }
 
@@ -252,6 +252,150 @@ pub fn validate_uniqueness(rows: &[CsvRow], schema: &Schema) -> ReedResult<()> {
     Ok(())
 }
 
+/// Fill the computed columns of a row.
+///
+/// Evaluates each column's `computed_from` expression in schema order, so
+/// an expression may use computed columns defined before it. Values given
+/// for computed columns are overwritten. Empty arguments are passed as
+/// empty strings; functions that reject them fail the row.
+///
+/// ## Input
+/// - `row`: Row with all fields in schema column order
+/// - `schema`: Table schema
+///
+/// ## Output
+/// - `Ok(())`: Computed fields (and `row.key`, if the key is computed) set
+///
+/// ## Error Conditions
+/// - `ValidationError`: Field count mismatch, or the function failed for
+///   this row's values
+/// - `InvalidSchema`: Expression refers to a column not in the schema
+pub fn apply_computed_columns(row: &mut CsvRow, schema: &Schema) -> ReedResult<()> {
+    if !schema.columns.iter().any(ColumnDef::is_computed) {
+        return Ok(());
+    }
+    if row.values.len() != schema.columns.len() {
+        return Err(ReedError::ValidationError {
+            column: "".to_string(),
+            reason: format!(
+                "Field count mismatch: expected {}, got {}",
+                schema.columns.len(),
+                row.values.len()
+            ),
+            value: None,
+        });
+    }
+
+    for (i, column) in schema.columns.iter().enumerate() {
+        let Some(expr) = &column.computed_from else {
+            continue;
+        };
+
+        let args = expr
+            .args
+            .iter()
+            .map(|arg| match arg {
+                ComputedArg::Literal(value) => Ok(value.as_str()),
+                ComputedArg::Column(name) => schema
+                    .columns
+                    .iter()
+                    .position(|c| c.name == *name)
+                    .map(|pos| row.values[pos].as_str())
+                    .ok_or_else(|| ReedError::InvalidSchema {
+                        reason: format!(
+                            "Computed column '{}' refers to unknown column '{}'",
+                            column.name, name
+                        ),
+                    }),
+            })
+            .collect::<ReedResult<Vec<&str>>>()?;
+
+        let value = expr.call(&args).map_err(|e| ReedError::ValidationError {
+            column: column.name.clone(),
+            reason: format!("Cannot compute {}: {}", expr, e),
+            value: None,
+        })?;
+
+        if i == 0 {
+            row.key = value.clone();
+        }
+        row.values[i] = value;
+    }
+
+    Ok(())
+}
+
+/// Value of a unique column that is already taken.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UniquenessViolation {
+    /// Index of the offending row in the validated slice
+    pub row: usize,
+
+    /// Key of the offending row
+    pub key: String,
+
+    /// Position of the unique column in the row
+    pub column_idx: usize,
+
+    /// Duplicated value
+    pub value: String,
+
+    /// Table rows already holding the value, as stored in the index
+    /// (empty if the duplicate is an earlier row of the same batch)
+    pub existing_rows: Vec<usize>,
+}
+
+/// Validate a unique column of new rows against the column's index.
+///
+/// Variant of `validate_uniqueness()` for rows about to be added to a table:
+/// instead of comparing against all other rows, each value is looked up in
+/// the index of the table's existing rows. The index must not contain the
+/// rows themselves (it fits INSERT, not UPDATE). Duplicates within `rows`
+/// are reported as well. Empty values are skipped; use `required` to
+/// forbid them.
+///
+/// ## Input
+/// - `rows`: New rows with all fields in schema column order
+/// - `column_idx`: Position of the unique column
+/// - `index`: Index of that column (value → row IDs)
+///
+/// ## Output
+/// - `Ok(Vec<UniquenessViolation>)`: Every duplicate, in row order
+///
+/// ## Performance
+/// - O(n) index lookups: O(n) for HashMap, O(n log m) for B+-Tree where
+///   m = indexed rows
+///
+/// ## Error Conditions
+/// - Index lookup errors (e.g. `IoError` from a B+-Tree page read)
+pub fn validate_uniqueness_indexed(
+    rows: &[CsvRow],
+    column_idx: usize,
+    index: &dyn Index<String, Vec<usize>>,
+) -> ReedResult<Vec<UniquenessViolation>> {
+    let mut seen = HashSet::new();
+    let mut violations = Vec::new();
+
+    for (row_idx, row) in rows.iter().enumerate() {
+        let Some(value) = row.values.get(column_idx).filter(|v| !v.is_empty()) else {
+            continue;
+        };
+
+        let existing_rows = index.get(value)?.unwrap_or_default();
+        if !existing_rows.is_empty() || !seen.insert(value.as_str()) {
+            violations.push(UniquenessViolation {
+                row: row_idx,
+                key: row.key.clone(),
+                column_idx,
+                value: value.clone(),
+                existing_rows,
+            });
+        }
+    }
+
+    Ok(violations)
+}
+
 /// Validate multiple rows in batch.
 ///
 /// ## Performance
@@ -268,3 +412,113 @@ pub fn validate_rows(rows: &[CsvRow], schema: &Schema) -> ReedResult<()> {
 
     Ok(())
 }
+
+/// Foreign key value without a matching row in the referenced table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FkViolation {
+    /// Index of the offending row in the validated slice
+    pub row: usize,
+
+    /// Key of the offending row
+    pub key: String,
+
+    /// Foreign key column
+    pub column: String,
+
+    /// Value that has no match
+    pub value: String,
+
+    /// Referenced table and column
+    pub references: ForeignKey,
+}
+
+/// Validate the foreign keys of rows about to be written to `table`.
+///
+/// Each referenced column is loaded at most once for the whole batch (its
+/// index is used instead if one is loaded). Rows in the batch count as
+/// existing when a column references `table` itself, so a row may refer to
+/// another row inserted alongside it. Empty values are not references.
+///
+/// ## Input
+/// - `table`: Table the rows belong to (its schema is looked up in `db`)
+/// - `rows`: Rows with all fields in schema column order
+/// - `db`: Database holding the referenced tables
+///
+/// ## Output
+/// - `Ok(Vec<FkViolation>)`: Every unmatched value, by row then column
+///   (empty if the table has no schema or no foreign keys)
+///
+/// ## Performance
+/// - O(n × f) lookups where n = rows, f = foreign key columns, plus one
+///   read per referenced column without an index
+///
+/// ## Error Conditions
+/// - `InvalidSchema`: Schema cannot be parsed, or a referenced column is
+///   missing from its table
+/// - `IoError`: Cannot read a referenced table
+pub fn validate_foreign_keys(
+    table: &str,
+    rows: &[CsvRow],
+    db: &Database,
+) -> ReedResult<Vec<FkViolation>> {
+    let schema = {
+        let mut registry = db.schema_registry().lock().unwrap();
+        match registry.get(table, db.base_path())? {
+            Some(schema) => schema.clone(),
+            None => return Ok(Vec::new()),
+        }
+    };
+
+    let constraints: Vec<(usize, &ColumnDef, ForeignKey)> = schema
+        .columns
+        .iter()
+        .enumerate()
+        .filter_map(|(i, column)| column.references().map(|fk| (i, column, fk)))
+        .collect();
+    if constraints.is_empty() || rows.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut loaded: HashMap<ForeignKey, HashSet<String>> = HashMap::new();
+    let mut violations = Vec::new();
+
+    for (row_idx, row) in rows.iter().enumerate() {
+        for (col_idx, column, fk) in &constraints {
+            let Some(value) = row.values.get(*col_idx).filter(|v| !v.is_empty()) else {
+                continue;
+            };
+            if reference_exists(db, &fk.table, &fk.column, value)? {
+                continue;
+            }
+
+            if !loaded.contains_key(fk) {
+                let mut values = read_column_values(db, &fk.table, &fk.column)?;
+                if fk.table == table {
+                    // Staged rows are not in the table yet
+                    if let Some(ref_idx) = schema.columns.iter().position(|c| c.name == fk.column) {
+                        values.extend(
+                            rows.iter()
+                                .filter_map(|r| r.values.get(ref_idx))
+                                .filter(|v| !v.is_empty())
+                                .cloned(),
+                        );
+                    }
+                }
+                loaded.insert(fk.clone(), values);
+            }
+            if loaded[fk].contains(value) {
+                continue;
+            }
+
+            violations.push(FkViolation {
+                row: row_idx,
+                key: row.key.clone(),
+                column: column.name.clone(),
+                value: value.clone(),
+                references: fk.clone(),
+            });
+        }
+    }
+
+    Ok(violations)
+}
@@ -5,8 +5,16 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::schema::types::{ColumnDef, Schema};
-    use crate::schema::validation::{validate_row, validate_rows, validate_uniqueness, CsvRow};
+    use crate::database::Database;
+    use crate::error::ReedError;
+    use crate::indices::{HashMapIndex, Index};
+    use crate::schema::loader::save_schema;
+    use crate::schema::types::{ColumnDef, ComputedExpr, ForeignKey, Schema};
+    use crate::schema::validation::{
+        apply_computed_columns, validate_foreign_keys, validate_row, validate_rows,
+        validate_uniqueness, validate_uniqueness_indexed, CsvRow, FkViolation, UniquenessViolation,
+    };
+    use crate::tables::Table;
 
     fn create_test_schema() -> Schema {
         Schema::new(
@@ -142,6 +150,40 @@ mod tests {
             .contains("does not match pattern"));
     }
 
+    #[test]
+    fn test_validate_pattern_any_type() {
+        let schema = Schema::new(
+            "2.0".to_string(),
+            true,
+            vec![ColumnDef::new("year".to_string(), "integer".to_string())
+                .with_pattern(r"^(19|20)[0-9]{2}$".to_string())],
+        );
+
+        let row = CsvRow::new("1".to_string(), vec!["2025".to_string()]);
+        assert!(validate_row(&row, &schema).is_ok());
+        let row = CsvRow::new("1".to_string(), vec!["1850".to_string()]);
+        assert!(validate_row(&row, &schema)
+            .unwrap_err()
+            .to_string()
+            .contains("does not match pattern"));
+    }
+
+    #[test]
+    fn test_validate_invalid_pattern() {
+        let schema = Schema::new(
+            "2.0".to_string(),
+            true,
+            vec![ColumnDef::new("email".to_string(), "string".to_string())
+                .with_pattern("[a-z".to_string())],
+        );
+        let row = CsvRow::new("1".to_string(), vec!["alice".to_string()]);
+
+        assert!(matches!(
+            validate_row(&row, &schema),
+            Err(ReedError::InvalidSchema { .. })
+        ));
+    }
+
     // ============================================================================
     // Integer Validation Tests
     // ============================================================================
@@ -471,4 +513,325 @@ mod tests {
         let row = CsvRow::new("1".to_string(), vec!["1".to_string(), "Alice".to_string()]);
         assert!(validate_row(&row, &schema).is_ok());
     }
+
+    fn setup_fk_test(name: &str) -> std::path::PathBuf {
+        let temp_dir = std::env::temp_dir().join(format!("reedbase_fk_test_{}", name));
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        crate::registry::set_base_path(temp_dir.clone());
+        crate::registry::init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+
+        Table::new(&temp_dir, "text")
+            .init(b"key|value\npage.home|Home\n", "testuser")
+            .unwrap();
+        Table::new(&temp_dir, "routes")
+            .init(b"key|text_key|parent\nr1|page.home|\n", "testuser")
+            .unwrap();
+        let schema = Schema::new(
+            "2.0".to_string(),
+            false,
+            vec![
+                ColumnDef::primary_key("key".to_string(), "string".to_string()),
+                ColumnDef::new("text_key".to_string(), "string".to_string())
+                    .with_foreign_key("text.key".to_string()),
+                ColumnDef::new("parent".to_string(), "string".to_string())
+                    .with_foreign_key("routes.key".to_string()),
+            ],
+        );
+        save_schema(&temp_dir, "routes", &schema).unwrap();
+
+        temp_dir
+    }
+
+    fn route(key: &str, text_key: &str, parent: &str) -> CsvRow {
+        CsvRow::new(
+            key.to_string(),
+            vec![key.to_string(), text_key.to_string(), parent.to_string()],
+        )
+    }
+
+    #[test]
+    fn test_validate_foreign_keys() {
+        let temp_dir = setup_fk_test("batch");
+        let db = Database::open(&temp_dir).unwrap();
+
+        let rows = vec![
+            route("r2", "page.home", "r1"),
+            route("r3", "page.gone", ""),
+            // Parent staged in the same batch
+            route("r4", "", "r2"),
+            route("r5", "page.home", "r9"),
+        ];
+        let violations = validate_foreign_keys("routes", &rows, &db).unwrap();
+
+        assert_eq!(
+            violations,
+            vec![
+                FkViolation {
+                    row: 1,
+                    key: "r3".to_string(),
+                    column: "text_key".to_string(),
+                    value: "page.gone".to_string(),
+                    references: ForeignKey::new("text", "key"),
+                },
+                FkViolation {
+                    row: 3,
+                    key: "r5".to_string(),
+                    column: "parent".to_string(),
+                    value: "r9".to_string(),
+                    references: ForeignKey::new("routes", "key"),
+                },
+            ]
+        );
+
+        // No schema, no constraints
+        assert!(validate_foreign_keys("text", &rows, &db)
+            .unwrap()
+            .is_empty());
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_execute_checks_foreign_keys() {
+        let temp_dir = setup_fk_test("execute");
+        let db = Database::open(&temp_dir).unwrap();
+        let routes = Table::new(&temp_dir, "routes");
+        let before = routes.read_current().unwrap();
+
+        let result = db.execute(
+            "INSERT INTO routes (key, text_key, parent) VALUES ('r2', 'page.gone', '')",
+            "testuser",
+        );
+        assert!(matches!(result, Err(ReedError::ValidationError { .. })));
+        let result = db.execute(
+            "UPDATE routes SET text_key = 'page.gone' WHERE key = 'r1'",
+            "testuser",
+        );
+        assert!(matches!(result, Err(ReedError::ValidationError { .. })));
+        assert_eq!(routes.read_current().unwrap(), before);
+
+        db.execute(
+            "INSERT INTO routes (key, text_key, parent) VALUES ('r2', 'page.home', 'r1')",
+            "testuser",
+        )
+        .unwrap();
+        assert_eq!(
+            db.get_table("routes")
+                .unwrap()
+                .read_current_as_rows()
+                .unwrap()
+                .len(),
+            3
+        );
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_validate_uniqueness_indexed() {
+        let mut index: HashMapIndex<String, Vec<usize>> = HashMapIndex::new();
+        index.insert("a@x.org".to_string(), vec![0]).unwrap();
+        index.insert("b@x.org".to_string(), vec![1, 4]).unwrap();
+
+        let user = |key: &str, email: &str| {
+            CsvRow::new(key.to_string(), vec![key.to_string(), email.to_string()])
+        };
+        let rows = vec![
+            user("u3", "c@x.org"),
+            user("u4", "b@x.org"),
+            user("u5", ""),
+            user("u6", "c@x.org"),
+        ];
+        let violations = validate_uniqueness_indexed(&rows, 1, &index).unwrap();
+
+        assert_eq!(
+            violations,
+            vec![
+                UniquenessViolation {
+                    row: 1,
+                    key: "u4".to_string(),
+                    column_idx: 1,
+                    value: "b@x.org".to_string(),
+                    existing_rows: vec![1, 4],
+                },
+                UniquenessViolation {
+                    row: 3,
+                    key: "u6".to_string(),
+                    column_idx: 1,
+                    value: "c@x.org".to_string(),
+                    existing_rows: vec![],
+                },
+            ]
+        );
+        assert!(validate_uniqueness_indexed(&rows[..1], 1, &index)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_insert_checks_unique_columns() {
+        let temp_dir = setup_fk_test("unique");
+        Table::new(&temp_dir, "users")
+            .init(b"key|email\nu1|a@x.org\n", "testuser")
+            .unwrap();
+        let schema = Schema::new(
+            "2.0".to_string(),
+            false,
+            vec![
+                ColumnDef::primary_key("key".to_string(), "string".to_string()),
+                ColumnDef::new("email".to_string(), "string".to_string()).unique(),
+            ],
+        );
+        save_schema(&temp_dir, "users", &schema).unwrap();
+        let db = Database::open(&temp_dir).unwrap();
+        let insert = |key: &str, email: &str| {
+            db.execute(
+                &format!(
+                    "INSERT INTO users (key, email) VALUES ('{}', '{}')",
+                    key, email
+                ),
+                "testuser",
+            )
+        };
+
+        // Without index: column scan
+        assert!(matches!(
+            insert("u2", "a@x.org"),
+            Err(ReedError::ValidationError { .. })
+        ));
+        assert!(matches!(
+            insert("u1", "other@x.org"),
+            Err(ReedError::ValidationError { .. })
+        ));
+        insert("u2", "b@x.org").unwrap();
+
+        // With index: lookups, and inserted rows are added to the index
+        db.create_index("users", "email").unwrap();
+        insert("u3", "c@x.org").unwrap();
+        let result = insert("u4", "c@x.org");
+        match result {
+            Err(ReedError::ValidationError { column, value, .. }) => {
+                assert_eq!(column, "email");
+                assert_eq!(value.as_deref(), Some("c@x.org"));
+            }
+            other => panic!("Expected ValidationError, got {:?}", other),
+        }
+        assert_eq!(
+            db.indices()
+                .read()
+                .unwrap()
+                .get("users.email")
+                .unwrap()
+                .get(&"c@x.org".to_string())
+                .unwrap(),
+            Some(vec![2])
+        );
+        assert_eq!(
+            Table::new(&temp_dir, "users")
+                .read_current_as_rows()
+                .unwrap()
+                .len(),
+            4
+        );
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    fn pages_schema() -> Schema {
+        Schema::new(
+            "2.0".to_string(),
+            false,
+            vec![
+                ColumnDef::primary_key("key".to_string(), "string".to_string()),
+                ColumnDef::new("title".to_string(), "string".to_string()),
+                ColumnDef::new("slug".to_string(), "string".to_string())
+                    .with_computed(ComputedExpr::parse("slugify(title)").unwrap()),
+                ColumnDef::new("short".to_string(), "string".to_string())
+                    .with_computed(ComputedExpr::parse("replace(slug, '-', '_')").unwrap()),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_apply_computed_columns() {
+        let schema = pages_schema();
+        let mut row = CsvRow::new(
+            "p1".to_string(),
+            vec![
+                "p1".to_string(),
+                "Hello World!".to_string(),
+                "stale".to_string(),
+                String::new(),
+            ],
+        );
+
+        apply_computed_columns(&mut row, &schema).unwrap();
+        assert_eq!(
+            row.values,
+            vec!["p1", "Hello World!", "hello-world", "hello_world"]
+        );
+
+        let mut short = CsvRow::new("p1".to_string(), vec!["p1".to_string()]);
+        assert!(matches!(
+            apply_computed_columns(&mut short, &schema),
+            Err(ReedError::ValidationError { .. })
+        ));
+
+        // Schemas without computed columns leave rows alone
+        let mut plain = CsvRow::new("p1".to_string(), vec!["p1".to_string()]);
+        apply_computed_columns(&mut plain, &create_test_schema()).unwrap();
+        assert_eq!(plain.values, vec!["p1"]);
+    }
+
+    #[test]
+    fn test_execute_computed_columns() {
+        let temp_dir = setup_fk_test("computed");
+        Table::new(&temp_dir, "pages")
+            .init(b"key|title|slug|short\n", "testuser")
+            .unwrap();
+        save_schema(&temp_dir, "pages", &pages_schema()).unwrap();
+        let db = Database::open(&temp_dir).unwrap();
+        let pages = Table::new(&temp_dir, "pages");
+
+        db.execute(
+            "INSERT INTO pages (key, title) VALUES ('p1', 'About Us')",
+            "testuser",
+        )
+        .unwrap();
+        assert_eq!(
+            pages.read_current().unwrap(),
+            b"key|title|slug|short\np1|About Us|about-us|about_us\n"
+        );
+
+        // Computed columns are read-only
+        for sql in [
+            "INSERT INTO pages (key, title, slug) VALUES ('p2', 'X', 'x')",
+            "UPDATE pages SET slug = 'x' WHERE key = 'p1'",
+        ] {
+            match db.execute(sql, "testuser") {
+                Err(ReedError::ValidationError { column, .. }) => assert_eq!(column, "slug"),
+                other => panic!("Expected ValidationError, got {:?}", other),
+            }
+        }
+        assert!(matches!(
+            db.execute(
+                "INSERT INTO pages (key, heading) VALUES ('p2', 'X')",
+                "testuser"
+            ),
+            Err(ReedError::ColumnNotFound { .. })
+        ));
+
+        db.execute(
+            "UPDATE pages SET title = 'Contact' WHERE key = 'p1'",
+            "testuser",
+        )
+        .unwrap();
+        assert_eq!(
+            pages.read_current().unwrap(),
+            b"key|title|slug|short\np1|Contact|contact|contact\n"
+        );
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
 }
@@ -0,0 +1,263 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Append-only audit log for compliance.
+//!
+//! Every entry is one tab-separated line in `.reed/audit.log`:
+//!
+//! ```text
+//! {timestamp}\t{user}\t{operation}\t{table}\t{key|key|...}\t{ip or -}
+//! ```
+//!
+//! Tabs, newlines, backslashes and pipes inside fields are backslash-escaped
+//! so every entry stays on a single line. The file is only ever opened in
+//! append mode; nothing in ReedBase rewrites or truncates it.
+
+use crate::error::{ReedError, ReedResult};
+use crate::tables::table::Table;
+use crate::tables::types::{AuditFilter, TransactionEntry};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Audit log file name inside the ReedBase directory.
+const AUDIT_LOG_FILE: &str = "audit.log";
+
+/// Placeholder for a missing IP address.
+const NO_IP: &str = "-";
+
+impl Table {
+    /// Appends an entry to the database-wide audit log.
+    ///
+    /// ## Input
+    /// - `base_path`: Path to ReedBase directory
+    /// - `entry`: Entry to record
+    ///
+    /// ## Output
+    /// - `Ok(())`: Entry appended and synced to disk
+    ///
+    /// ## Performance
+    /// - < 1ms typical (single append + fsync)
+    ///
+    /// ## Error Conditions
+    /// - `IoError`: Cannot open, write or sync audit.log
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::tables::{Table, TransactionEntry};
+    /// use std::path::Path;
+    ///
+    /// Table::write_transaction_log(
+    ///     Path::new(".reed"),
+    ///     TransactionEntry {
+    ///         timestamp: 1736860900000000000,
+    ///         user: "admin".to_string(),
+    ///         operation: "update".to_string(),
+    ///         table: "text".to_string(),
+    ///         affected_keys: vec!["page.title<de>".to_string()],
+    ///         ip_address: Some("10.0.0.1".to_string()),
+    ///     },
+    /// )?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn write_transaction_log(base_path: &Path, entry: TransactionEntry) -> ReedResult<()> {
+        let path = audit_log_path(base_path);
+        let line = format_entry(&entry);
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|e| audit_io_error("open_audit_log", e))?;
+
+        // Single write per entry so concurrent appenders never interleave
+        file.write_all(line.as_bytes())
+            .map_err(|e| audit_io_error("write_audit_log", e))?;
+        file.sync_data()
+            .map_err(|e| audit_io_error("sync_audit_log", e))?;
+
+        Ok(())
+    }
+}
+
+/// Reads audit log entries matching a filter, oldest first.
+///
+/// ## Input
+/// - `base_path`: Path to ReedBase directory
+/// - `filter`: Criteria entries must satisfy
+///
+/// ## Output
+/// - `Ok(Vec<TransactionEntry>)`: Matching entries (empty if no log exists)
+///
+/// ## Performance
+/// - O(n) where n = number of log lines
+///
+/// ## Error Conditions
+/// - `IoError`: Cannot read audit.log
+/// - `LogCorrupted`: A line does not have six fields or a numeric timestamp
+///
+/// ## Example Usage
+/// ```no_run
+/// use reedbase_last::tables::{read_transaction_log, AuditFilter};
+/// use std::path::Path;
+///
+/// let filter = AuditFilter {
+///     user: Some("admin".to_string()),
+///     ..Default::default()
+/// };
+/// for entry in read_transaction_log(Path::new(".reed"), filter)? {
+///     println!("{} {} {}", entry.timestamp, entry.operation, entry.table);
+/// }
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn read_transaction_log(
+    base_path: &Path,
+    filter: AuditFilter,
+) -> ReedResult<Vec<TransactionEntry>> {
+    let path = audit_log_path(base_path);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = fs::read_to_string(&path).map_err(|e| audit_io_error("read_audit_log", e))?;
+
+    let mut entries = Vec::new();
+    for (line_no, line) in content.lines().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+        let entry = parse_entry(line).map_err(|reason| ReedError::LogCorrupted {
+            reason: format!("audit.log line {}: {}", line_no + 1, reason),
+        })?;
+        if filter.matches(&entry) {
+            entries.push(entry);
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Path to the audit log.
+fn audit_log_path(base_path: &Path) -> PathBuf {
+    base_path.join(AUDIT_LOG_FILE)
+}
+
+fn audit_io_error(operation: &str, e: std::io::Error) -> ReedError {
+    ReedError::IoError {
+        operation: operation.to_string(),
+        reason: e.to_string(),
+    }
+}
+
+/// Serialises an entry as one newline-terminated line.
+fn format_entry(entry: &TransactionEntry) -> String {
+    let keys: Vec<String> = entry.affected_keys.iter().map(|k| escape(k)).collect();
+    let ip = match &entry.ip_address {
+        Some(ip) => escape(ip),
+        None => NO_IP.to_string(),
+    };
+
+    format!(
+        "{}\t{}\t{}\t{}\t{}\t{}\n",
+        entry.timestamp,
+        escape(&entry.user),
+        escape(&entry.operation),
+        escape(&entry.table),
+        keys.join("|"),
+        ip
+    )
+}
+
+/// Parses one log line back into an entry.
+fn parse_entry(line: &str) -> Result<TransactionEntry, String> {
+    let fields: Vec<&str> = line.split('\t').collect();
+    if fields.len() != 6 {
+        return Err(format!("expected 6 fields, got {}", fields.len()));
+    }
+
+    let timestamp = fields[0]
+        .parse::<u64>()
+        .map_err(|_| format!("invalid timestamp '{}'", fields[0]))?;
+
+    let affected_keys = if fields[4].is_empty() {
+        Vec::new()
+    } else {
+        split_unescaped_pipes(fields[4])
+            .into_iter()
+            .map(|k| unescape(&k))
+            .collect()
+    };
+
+    let ip_address = match fields[5] {
+        NO_IP => None,
+        ip => Some(unescape(ip)),
+    };
+
+    Ok(TransactionEntry {
+        timestamp,
+        user: unescape(fields[1]),
+        operation: unescape(fields[2]),
+        table: unescape(fields[3]),
+        affected_keys,
+        ip_address,
+    })
+}
+
+fn escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\t' => out.push_str("\\t"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '|' => out.push_str("\\|"),
+            other => out.push(other),
+        }
+    }
+    // A bare "-" would read back as a missing IP address
+    if out == NO_IP {
+        out = "\\-".to_string();
+    }
+    out
+}
+
+fn unescape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => out.push('\t'),
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+/// Splits on `|` separators, leaving escaped `\|` (and other escapes) intact.
+fn split_unescaped_pipes(value: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                current.push(c);
+                if let Some(next) = chars.next() {
+                    current.push(next);
+                }
+            }
+            '|' => parts.push(std::mem::take(&mut current)),
+            other => current.push(other),
+        }
+    }
+    parts.push(current);
+    parts
+}
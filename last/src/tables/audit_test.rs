@@ -0,0 +1,120 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for the audit log.
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ReedError;
+    use crate::tables::{read_transaction_log, AuditFilter, Table, TransactionEntry};
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn entry(timestamp: u64, user: &str, operation: &str, table: &str) -> TransactionEntry {
+        TransactionEntry {
+            timestamp,
+            user: user.to_string(),
+            operation: operation.to_string(),
+            table: table.to_string(),
+            affected_keys: vec![format!("{}.key", table)],
+            ip_address: None,
+        }
+    }
+
+    fn write_sample_log(base: &std::path::Path) {
+        Table::write_transaction_log(base, entry(100, "alice", "insert", "text")).unwrap();
+        Table::write_transaction_log(base, entry(200, "bob", "update", "text")).unwrap();
+        Table::write_transaction_log(base, entry(300, "alice", "delete", "routes")).unwrap();
+        Table::write_transaction_log(base, entry(400, "bob", "insert", "routes")).unwrap();
+    }
+
+    #[test]
+    fn test_read_missing_log_is_empty() {
+        let temp = TempDir::new().unwrap();
+        let entries = read_transaction_log(temp.path(), AuditFilter::default()).unwrap();
+        assert!(entries.is_empty());
+    }
+
+    #[test]
+    fn test_round_trip_preserves_fields() {
+        let temp = TempDir::new().unwrap();
+        let original = TransactionEntry {
+            timestamp: 1736860900000000000,
+            user: "ad\tmin".to_string(),
+            operation: "update".to_string(),
+            table: "text".to_string(),
+            affected_keys: vec![
+                "page.title<de,prod>".to_string(),
+                "odd|key\\with\nbreaks".to_string(),
+            ],
+            ip_address: Some("10.0.0.1".to_string()),
+        };
+
+        Table::write_transaction_log(temp.path(), original.clone()).unwrap();
+        let entries = read_transaction_log(temp.path(), AuditFilter::default()).unwrap();
+
+        assert_eq!(entries, vec![original]);
+        let content = fs::read_to_string(temp.path().join("audit.log")).unwrap();
+        assert_eq!(content.lines().count(), 1);
+    }
+
+    #[test]
+    fn test_appends_in_order() {
+        let temp = TempDir::new().unwrap();
+        write_sample_log(temp.path());
+
+        let entries = read_transaction_log(temp.path(), AuditFilter::default()).unwrap();
+        let timestamps: Vec<u64> = entries.iter().map(|e| e.timestamp).collect();
+        assert_eq!(timestamps, vec![100, 200, 300, 400]);
+        assert!(entries.iter().all(|e| e.ip_address.is_none()));
+    }
+
+    #[test]
+    fn test_filter_by_user_table_and_operation() {
+        let temp = TempDir::new().unwrap();
+        write_sample_log(temp.path());
+
+        let by_user = AuditFilter {
+            user: Some("alice".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(read_transaction_log(temp.path(), by_user).unwrap().len(), 2);
+
+        let by_table_and_op = AuditFilter {
+            table: Some("routes".to_string()),
+            operation: Some("INSERT".to_string()),
+            ..Default::default()
+        };
+        let entries = read_transaction_log(temp.path(), by_table_and_op).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].user, "bob");
+    }
+
+    #[test]
+    fn test_filter_by_time_range() {
+        let temp = TempDir::new().unwrap();
+        write_sample_log(temp.path());
+
+        let filter = AuditFilter {
+            since: Some(200),
+            until: Some(300),
+            ..Default::default()
+        };
+        let entries = read_transaction_log(temp.path(), filter).unwrap();
+        let timestamps: Vec<u64> = entries.iter().map(|e| e.timestamp).collect();
+        assert_eq!(timestamps, vec![200, 300]);
+    }
+
+    #[test]
+    fn test_corrupted_line_is_reported() {
+        let temp = TempDir::new().unwrap();
+        write_sample_log(temp.path());
+        let path = temp.path().join("audit.log");
+        let mut content = fs::read_to_string(&path).unwrap();
+        content.push_str("not-a-timestamp\tx\n");
+        fs::write(&path, content).unwrap();
+
+        let result = read_transaction_log(temp.path(), AuditFilter::default());
+        assert!(matches!(result, Err(ReedError::LogCorrupted { .. })));
+    }
+}
@@ -3,15 +3,17 @@
 
 //! CSV parsing for ReedBase tables.
 //!
-//! Pipe-delimited format: `key|value1|value2|...`
+//! Pipe-delimited by default: `key|value1|value2|...`. Comma, tab and
+//! custom single-byte delimiters are supported through `Delimiter`.
 
 use crate::error::{ReedError, ReedResult};
-use crate::tables::types::CsvRow;
+use crate::tables::types::{CsvRow, Delimiter};
 
 /// Parses CSV content into rows.
 ///
 /// ## Input
-/// - `content`: CSV bytes (pipe-delimited)
+/// - `content`: CSV bytes
+/// - `delimiter`: Field delimiter
 ///
 /// ## Output
 /// - `Result<Vec<CsvRow>>`: Parsed rows (header excluded)
@@ -21,19 +23,19 @@ use crate::tables::types::CsvRow;
 /// - < 5ms for typical tables (< 1000 rows)
 ///
 /// ## Error Conditions
-/// - InvalidCsv: Malformed CSV
+/// - InvalidCsv: Malformed CSV, or a non-ASCII custom delimiter
 ///
 /// ## Example Usage
 /// ```
-/// use reedbase_last::tables::parse_csv;
+/// use reedbase_last::tables::{parse_csv, Delimiter};
 ///
 /// let csv = b"key|value\nfoo|bar\nbaz|qux\n";
-/// let rows = parse_csv(csv)?;
+/// let rows = parse_csv(csv, Delimiter::Pipe)?;
 /// assert_eq!(rows.len(), 2);
 /// assert_eq!(rows[0].key, "foo");
 /// # Ok::<(), reedbase::ReedError>(())
 /// ```
-pub fn parse_csv(content: &[u8]) -> ReedResult<Vec<CsvRow>> {
+pub fn parse_csv(content: &[u8], delimiter: Delimiter) -> ReedResult<Vec<CsvRow>> {
     // Validate UTF-8
     let text = std::str::from_utf8(content).map_err(|e| ReedError::InvalidCsv {
         reason: format!("Invalid UTF-8: {}", e),
@@ -43,7 +45,7 @@ pub fn parse_csv(content: &[u8]) -> ReedResult<Vec<CsvRow>> {
     let mut rows = Vec::new();
 
     for (line_num, line) in text.lines().enumerate() {
-        let trimmed = line.trim();
+        let trimmed = trim_line(line, delimiter);
 
         // Skip empty lines
         if trimmed.is_empty() {
@@ -55,14 +57,185 @@ pub fn parse_csv(content: &[u8]) -> ReedResult<Vec<CsvRow>> {
             continue;
         }
 
-        let row = parse_csv_row(trimmed, line_num + 1)?;
+        let row = parse_csv_row_with(trimmed, line_num + 1, delimiter)?;
         rows.push(row);
     }
 
     Ok(rows)
 }
 
-/// Parses a single CSV row.
+/// Parses CSV content, detecting the delimiter from the first line.
+///
+/// ## Input
+/// - `content`: CSV bytes
+///
+/// ## Output
+/// - `Result<Vec<CsvRow>>`: Same rows as `parse_csv()` with the detected
+///   delimiter
+///
+/// ## Performance
+/// - One extra pass over the first line
+///
+/// ## Error Conditions
+/// - InvalidCsv: Malformed CSV
+///
+/// ## Example Usage
+/// ```
+/// use reedbase_last::tables::parse_csv_auto;
+///
+/// let rows = parse_csv_auto(b"key,value\nfoo,bar\n")?;
+/// assert_eq!(rows[1].values, vec!["bar"]);
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+pub fn parse_csv_auto(content: &[u8]) -> ReedResult<Vec<CsvRow>> {
+    parse_csv(content, detect_delimiter(content))
+}
+
+/// Picks the most frequent of `|`, `,` and tab on the first line.
+///
+/// Blank and `#` comment lines are skipped. Ties and lines without any
+/// candidate resolve to the earlier of pipe, comma, tab, so content
+/// without delimiters stays `Delimiter::Pipe`.
+///
+/// ## Input
+/// - `content`: CSV bytes
+///
+/// ## Output
+/// - `Delimiter`: Detected delimiter
+///
+/// ## Performance
+/// - O(l) where l = length of the first line
+pub fn detect_delimiter(content: &[u8]) -> Delimiter {
+    let first_line = content
+        .split(|&b| b == b'\n')
+        .find(|line| {
+            let line = line.trim_ascii();
+            !line.is_empty() && !line.starts_with(b"#")
+        })
+        .unwrap_or_default();
+
+    let mut best = Delimiter::Pipe;
+    let mut best_count = 0;
+    for candidate in [Delimiter::Pipe, Delimiter::Comma, Delimiter::Tab] {
+        let count = first_line
+            .iter()
+            .filter(|&&b| b == candidate.as_byte())
+            .count();
+        if count > best_count {
+            best = candidate;
+            best_count = count;
+        }
+    }
+
+    best
+}
+
+/// Trims surrounding whitespace, keeping a tab delimiter's empty edge fields.
+pub(crate) fn trim_line(line: &str, delimiter: Delimiter) -> &str {
+    let separator = delimiter.as_char();
+    line.trim_matches(|c: char| c.is_whitespace() && c != separator)
+}
+
+/// Parses CSV content into rows using all CPU cores.
+///
+/// Splits the content at newline boundaries into one chunk per Rayon worker
+/// thread, parses the chunks in parallel and concatenates the results in
+/// order. The first line (header) therefore lands in chunk 0 only, so the
+/// output is identical to `parse_csv()`, including line numbers in errors.
+///
+/// ## Input
+/// - `content`: CSV bytes
+/// - `delimiter`: Field delimiter
+///
+/// ## Output
+/// - `Result<Vec<CsvRow>>`: Same rows as `parse_csv()`
+///
+/// ## Performance
+/// - O(n / cores) parsing plus one sequential UTF-8 validation pass
+/// - Worthwhile from ~100k rows; use `parse_csv()` for small tables
+///
+/// ## Error Conditions
+/// - InvalidCsv: Malformed CSV (first error in file order is returned)
+///
+/// ## Example Usage
+/// ```
+/// use reedbase_last::tables::csv_parser::parse_csv_parallel;
+/// use reedbase_last::tables::Delimiter;
+///
+/// let csv = b"key|value\nfoo|bar\nbaz|qux\n";
+/// let rows = parse_csv_parallel(csv, Delimiter::Pipe)?;
+/// assert_eq!(rows.len(), 3);
+/// # Ok::<(), reedbase::ReedError>(())
+/// ```
+#[cfg(feature = "rayon")]
+pub fn parse_csv_parallel(content: &[u8], delimiter: Delimiter) -> ReedResult<Vec<CsvRow>> {
+    use rayon::prelude::*;
+
+    // Validate UTF-8 (whole content, so error offsets match parse_csv)
+    let text = std::str::from_utf8(content).map_err(|e| ReedError::InvalidCsv {
+        reason: format!("Invalid UTF-8: {}", e),
+        line: 0,
+    })?;
+
+    let chunks = split_at_lines(text, rayon::current_num_threads());
+
+    let parsed: Vec<ReedResult<Vec<CsvRow>>> = chunks
+        .par_iter()
+        .map(|&(chunk, first_line)| {
+            let mut rows = Vec::new();
+            for (offset, line) in chunk.lines().enumerate() {
+                let trimmed = trim_line(line, delimiter);
+                if trimmed.is_empty() || trimmed.starts_with('#') {
+                    continue;
+                }
+                rows.push(parse_csv_row_with(
+                    trimmed,
+                    first_line + offset + 1,
+                    delimiter,
+                )?);
+            }
+            Ok(rows)
+        })
+        .collect();
+
+    let mut rows = Vec::new();
+    for chunk_rows in parsed {
+        rows.extend(chunk_rows?);
+    }
+
+    Ok(rows)
+}
+
+/// Splits text into at most `n` chunks ending on `\n` boundaries.
+///
+/// Returns each chunk with the zero-based index of its first line.
+#[cfg(feature = "rayon")]
+fn split_at_lines(text: &str, n: usize) -> Vec<(&str, usize)> {
+    let target = text.len().div_ceil(n.max(1)).max(1);
+    let mut chunks = Vec::with_capacity(n);
+    let mut rest = text;
+    let mut first_line = 0;
+
+    while !rest.is_empty() {
+        let end = if rest.len() <= target {
+            rest.len()
+        } else {
+            match rest.as_bytes()[target..].iter().position(|&b| b == b'\n') {
+                Some(pos) => target + pos + 1,
+                None => rest.len(),
+            }
+        };
+
+        let (chunk, tail) = rest.split_at(end);
+        chunks.push((chunk, first_line));
+        first_line += chunk.bytes().filter(|&b| b == b'\n').count();
+        rest = tail;
+    }
+
+    chunks
+}
+
+/// Parses a single pipe-delimited CSV row.
 ///
 /// ## Input
 /// - `line`: CSV line (pipe-delimited)
@@ -74,15 +247,43 @@ pub fn parse_csv(content: &[u8]) -> ReedResult<Vec<CsvRow>> {
 /// ## Error Conditions
 /// - InvalidCsv: Less than 1 column
 pub fn parse_csv_row(line: &str, line_num: usize) -> ReedResult<CsvRow> {
-    // Must contain at least one pipe
-    if !line.contains('|') {
+    parse_csv_row_with(line, line_num, Delimiter::Pipe)
+}
+
+/// Parses a single CSV row with the given delimiter.
+///
+/// ## Input
+/// - `line`: CSV line
+/// - `line_num`: Line number (for error reporting)
+/// - `delimiter`: Field delimiter
+///
+/// ## Output
+/// - `Result<CsvRow>`: Parsed row
+///
+/// ## Error Conditions
+/// - InvalidCsv: Delimiter missing from the line, or not ASCII
+pub fn parse_csv_row_with(line: &str, line_num: usize, delimiter: Delimiter) -> ReedResult<CsvRow> {
+    if !delimiter.as_byte().is_ascii() {
+        return Err(ReedError::InvalidCsv {
+            reason: format!("Delimiter byte 0x{:02X} is not ASCII", delimiter.as_byte()),
+            line: line_num,
+        });
+    }
+
+    // Must contain at least one delimiter
+    let separator = delimiter.as_char();
+    if !line.contains(separator) {
+        let reason = match delimiter {
+            Delimiter::Pipe => "No pipe delimiter found".to_string(),
+            _ => format!("No {:?} delimiter found", separator),
+        };
         return Err(ReedError::InvalidCsv {
-            reason: "No pipe delimiter found".to_string(),
+            reason,
             line: line_num,
         });
     }
 
-    let parts: Vec<&str> = line.split('|').collect();
+    let parts: Vec<&str> = line.split(separator).collect();
 
     if parts.is_empty() {
         return Err(ReedError::InvalidCsv {
@@ -94,5 +295,9 @@ pub fn parse_csv_row(line: &str, line_num: usize) -> ReedResult<CsvRow> {
     let key = parts[0].trim().to_string();
     let values = parts[1..].iter().map(|s| s.trim().to_string()).collect();
 
-    Ok(CsvRow { key, values })
+    Ok(CsvRow {
+        key,
+        values,
+        columns: Default::default(),
+    })
 }
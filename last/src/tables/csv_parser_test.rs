@@ -5,7 +5,10 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::tables::csv_parser::{parse_csv, parse_csv_row};
+    use crate::tables::csv_parser::{
+        detect_delimiter, parse_csv, parse_csv_auto, parse_csv_row, parse_csv_row_with,
+    };
+    use crate::tables::types::Delimiter;
 
     /// Test parse_csv_row with valid single-column row.
     #[test]
@@ -76,7 +79,7 @@ mod tests {
     #[test]
     fn test_parse_csv_basic() {
         let content = b"key|value\ntest.key1|value1\ntest.key2|value2\n";
-        let rows = parse_csv(content).unwrap();
+        let rows = parse_csv(content, Delimiter::Pipe).unwrap();
 
         assert_eq!(rows.len(), 3);
         assert_eq!(rows[0].key, "key");
@@ -91,7 +94,7 @@ mod tests {
     #[test]
     fn test_parse_csv_empty() {
         let content = b"";
-        let rows = parse_csv(content).unwrap();
+        let rows = parse_csv(content, Delimiter::Pipe).unwrap();
         assert_eq!(rows.len(), 0, "Empty content should return empty vec");
     }
 
@@ -99,7 +102,7 @@ mod tests {
     #[test]
     fn test_parse_csv_whitespace_only() {
         let content = b"   \n  \n\t\n";
-        let rows = parse_csv(content).unwrap();
+        let rows = parse_csv(content, Delimiter::Pipe).unwrap();
         assert_eq!(rows.len(), 0, "Whitespace-only should return empty vec");
     }
 
@@ -107,7 +110,7 @@ mod tests {
     #[test]
     fn test_parse_csv_with_comments() {
         let content = b"# This is a comment\nkey|value\n# Another comment\ntest.key|value\n";
-        let rows = parse_csv(content).unwrap();
+        let rows = parse_csv(content, Delimiter::Pipe).unwrap();
 
         assert_eq!(rows.len(), 2, "Should skip comment lines");
         assert_eq!(rows[0].key, "key");
@@ -118,7 +121,7 @@ mod tests {
     #[test]
     fn test_parse_csv_blank_lines() {
         let content = b"key|value\n\ntest.key|value\n\n\n";
-        let rows = parse_csv(content).unwrap();
+        let rows = parse_csv(content, Delimiter::Pipe).unwrap();
 
         assert_eq!(rows.len(), 2, "Should skip blank lines");
         assert_eq!(rows[0].key, "key");
@@ -129,7 +132,7 @@ mod tests {
     #[test]
     fn test_parse_csv_mixed_content() {
         let content = b"# Header comment\nkey|value|description\n\n# Data section\ntest.key1|val1|desc1\ntest.key2|val2|desc2\n# Footer\n";
-        let rows = parse_csv(content).unwrap();
+        let rows = parse_csv(content, Delimiter::Pipe).unwrap();
 
         assert_eq!(rows.len(), 3);
         assert_eq!(rows[0].key, "key");
@@ -144,7 +147,7 @@ mod tests {
     #[test]
     fn test_parse_csv_invalid_row() {
         let content = b"key|value\ninvalid_row_no_pipe\ntest.key|value\n";
-        let result = parse_csv(content);
+        let result = parse_csv(content, Delimiter::Pipe);
 
         assert!(result.is_err(), "Should fail on invalid row");
 
@@ -162,7 +165,7 @@ mod tests {
     #[test]
     fn test_parse_csv_utf8() {
         let content = "test.german|Überschrift|Beschreibung\ntest.emoji|🚀|rocket\n".as_bytes();
-        let rows = parse_csv(content).unwrap();
+        let rows = parse_csv(content, Delimiter::Pipe).unwrap();
 
         assert_eq!(rows.len(), 2);
         assert_eq!(rows[0].key, "test.german");
@@ -178,7 +181,7 @@ mod tests {
         content.extend_from_slice(&[0xFF, 0xFE, 0xFD]); // Invalid UTF-8 bytes
         content.extend_from_slice(b"\ntest.key|value\n");
 
-        let result = parse_csv(&content);
+        let result = parse_csv(&content, Delimiter::Pipe);
         assert!(result.is_err(), "Should fail on invalid UTF-8");
     }
 
@@ -186,7 +189,7 @@ mod tests {
     #[test]
     fn test_parse_csv_multiple_columns() {
         let content = b"key|col1|col2|col3|col4\ntest.key|a|b|c|d\n";
-        let rows = parse_csv(content).unwrap();
+        let rows = parse_csv(content, Delimiter::Pipe).unwrap();
 
         assert_eq!(rows.len(), 2);
         assert_eq!(rows[0].values, vec!["col1", "col2", "col3", "col4"]);
@@ -197,7 +200,7 @@ mod tests {
     #[test]
     fn test_parse_csv_crlf() {
         let content = b"key|value\r\ntest.key1|value1\r\ntest.key2|value2\r\n";
-        let rows = parse_csv(content).unwrap();
+        let rows = parse_csv(content, Delimiter::Pipe).unwrap();
 
         assert_eq!(rows.len(), 3, "Should handle CRLF line endings");
         assert_eq!(rows[0].key, "key");
@@ -209,7 +212,7 @@ mod tests {
     #[test]
     fn test_parse_csv_mixed_line_endings() {
         let content = b"key|value\r\ntest.key1|value1\ntest.key2|value2\r\n";
-        let rows = parse_csv(content).unwrap();
+        let rows = parse_csv(content, Delimiter::Pipe).unwrap();
 
         assert_eq!(rows.len(), 3, "Should handle mixed line endings");
         assert_eq!(rows[2].key, "test.key2");
@@ -245,9 +248,134 @@ mod tests {
             content.push_str(&format!("test.key{}|value{}|desc{}\n", i, i, i));
         }
 
-        let rows = parse_csv(content.as_bytes()).unwrap();
+        let rows = parse_csv(content.as_bytes(), Delimiter::Pipe).unwrap();
         assert_eq!(rows.len(), 1000, "Should parse 1000 rows");
         assert_eq!(rows[0].key, "test.key0");
         assert_eq!(rows[999].key, "test.key999");
     }
+
+    /// Test parse_csv with comma, tab and custom delimiters.
+    #[test]
+    fn test_parse_csv_other_delimiters() {
+        let rows = parse_csv(b"key,value\na,1\nb,2\n", Delimiter::Comma).unwrap();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[2].key, "b");
+        assert_eq!(rows[2].values, vec!["2"]);
+
+        // Tabs survive line trimming, so an empty last field is kept
+        let rows = parse_csv(b"key\tvalue\tnote\na\t1\t\n", Delimiter::Tab).unwrap();
+        assert_eq!(rows[1].values, vec!["1", ""]);
+
+        let rows = parse_csv(b"key;value\na;x|y\n", Delimiter::Custom(b';')).unwrap();
+        assert_eq!(rows[1].values, vec!["x|y"]);
+
+        assert!(parse_csv_row_with("a,1", 1, Delimiter::Pipe).is_err());
+        assert!(parse_csv_row_with("a\u{e9}1", 1, Delimiter::Custom(0xC3)).is_err());
+    }
+
+    /// Test delimiter detection from the header line.
+    #[test]
+    fn test_detect_delimiter() {
+        assert_eq!(detect_delimiter(b"key|value\n"), Delimiter::Pipe);
+        assert_eq!(detect_delimiter(b"key,value,note\n"), Delimiter::Comma);
+        assert_eq!(
+            detect_delimiter(b"# comment\n\nkey\tvalue\n"),
+            Delimiter::Tab
+        );
+        // Most frequent wins; ties and no delimiter fall back to pipe
+        assert_eq!(detect_delimiter(b"key,a,b|c\n"), Delimiter::Comma);
+        assert_eq!(detect_delimiter(b"key,a|b\n"), Delimiter::Pipe);
+        assert_eq!(detect_delimiter(b"key\n"), Delimiter::Pipe);
+        assert_eq!(detect_delimiter(b""), Delimiter::Pipe);
+    }
+
+    /// Test parse_csv_auto with detected delimiters.
+    #[test]
+    fn test_parse_csv_auto() {
+        let rows = parse_csv_auto(b"key,value\na,1\n").unwrap();
+        assert_eq!(rows[1].values, vec!["1"]);
+
+        let rows = parse_csv_auto(b"key|value\na|1,2\n").unwrap();
+        assert_eq!(rows[1].values, vec!["1,2"]);
+    }
+
+    #[cfg(feature = "rayon")]
+    mod parallel {
+        use crate::tables::csv_parser::{parse_csv, parse_csv_parallel};
+        use crate::tables::types::Delimiter;
+        use proptest::prelude::*;
+
+        /// Runs the parallel parser on a pool with a fixed thread count.
+        fn parse_with_threads(content: &[u8], threads: usize) -> String {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap();
+            format!(
+                "{:?}",
+                pool.install(|| parse_csv_parallel(content, Delimiter::Pipe))
+            )
+        }
+
+        /// Test parallel parser keeps the header once and row order.
+        #[test]
+        fn test_parse_csv_parallel_order() {
+            let mut content = String::from("key|value\n");
+            for i in 0..1000 {
+                content.push_str(&format!("key{:04}|value{}\n", i, i));
+            }
+
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(4)
+                .build()
+                .unwrap();
+            let rows = pool
+                .install(|| parse_csv_parallel(content.as_bytes(), Delimiter::Pipe))
+                .unwrap();
+
+            assert_eq!(rows.len(), 1001);
+            assert_eq!(rows[0].key, "key");
+            assert_eq!(rows[1].key, "key0000");
+            assert_eq!(rows[1000].key, "key0999");
+            assert_eq!(
+                rows,
+                parse_csv(content.as_bytes(), Delimiter::Pipe).unwrap()
+            );
+        }
+
+        /// Test parallel parser reports the same line number as sequential.
+        #[test]
+        fn test_parse_csv_parallel_error_line() {
+            let mut content = String::from("key|value\n");
+            for i in 0..100 {
+                content.push_str(&format!("key{}|v\n", i));
+            }
+            content.push_str("broken\n");
+            for i in 0..100 {
+                content.push_str(&format!("more{}|v\n", i));
+            }
+
+            let expected = format!("{:?}", parse_csv(content.as_bytes(), Delimiter::Pipe));
+            assert!(expected.contains("line: 102"));
+            assert_eq!(parse_with_threads(content.as_bytes(), 8), expected);
+        }
+
+        proptest! {
+            /// Parallel and sequential parsers agree on arbitrary input.
+            #[test]
+            fn prop_parse_csv_parallel_matches_sequential(
+                lines in prop::collection::vec("[a-z0-9#| ]{0,12}\r?", 0..200),
+                trailing_newline in any::<bool>(),
+                threads in 1usize..9,
+            ) {
+                let mut content = lines.join("\n");
+                if trailing_newline {
+                    content.push('\n');
+                }
+
+                let expected = format!("{:?}", parse_csv(content.as_bytes(), Delimiter::Pipe));
+                prop_assert_eq!(parse_with_threads(content.as_bytes(), threads), expected);
+            }
+        }
+    }
 }
@@ -4,7 +4,9 @@
 //! Helper functions for table operations.
 
 use crate::error::{ReedError, ReedResult};
+use crate::tables::table::Table;
 use crate::tables::types::TableStats;
+use crate::version::{VERSION_INDEX_FILE, VERSION_INDEX_WAL_FILE};
 use std::fs;
 use std::path::Path;
 
@@ -88,7 +90,7 @@ pub fn table_exists(base_path: &Path, name: &str) -> bool {
 /// - `Result<TableStats>`: Statistics
 ///
 /// ## Performance
-/// - < 10ms (read log + file sizes)
+/// - < 10ms (read current.csv + log + file sizes)
 ///
 /// ## Error Conditions
 /// - TableNotFound: Table doesn't exist
@@ -120,6 +122,17 @@ pub fn table_stats(base_path: &Path, name: &str) -> ReedResult<TableStats> {
         })?
         .len();
 
+    // Count data rows (non-empty lines after the header)
+    let current_content = fs::read(&current_path).map_err(|e| ReedError::IoError {
+        operation: "read_current".to_string(),
+        reason: e.to_string(),
+    })?;
+    let row_count = current_content
+        .split(|&b| b == b'\n')
+        .filter(|line| !line.trim_ascii().is_empty())
+        .count()
+        .saturating_sub(1);
+
     // Parse version.log for version count and timestamps
     let log_path = table_dir.join("version.log");
     let log_content = fs::read_to_string(&log_path).map_err(|e| ReedError::IoError {
@@ -162,7 +175,7 @@ pub fn table_stats(base_path: &Path, name: &str) -> ReedResult<TableStats> {
         oldest_version = oldest_version.min(timestamp);
     }
 
-    // Count delta files and total size
+    // Count delta and snapshot files and total size
     let mut deltas_size = 0u64;
     for entry in fs::read_dir(&table_dir).map_err(|e| ReedError::IoError {
         operation: "read_table_dir".to_string(),
@@ -175,7 +188,7 @@ pub fn table_stats(base_path: &Path, name: &str) -> ReedResult<TableStats> {
 
         let path = entry.path();
         if let Some(ext) = path.extension() {
-            if ext == "bsdiff" {
+            if ext == "bsdiff" || ext == "snap" {
                 let size = entry
                     .metadata()
                     .map_err(|e| ReedError::IoError {
@@ -188,12 +201,22 @@ pub fn table_stats(base_path: &Path, name: &str) -> ReedResult<TableStats> {
         }
     }
 
+    // Version index and its write-ahead log (missing until first write)
+    let version_index_size = [VERSION_INDEX_FILE, VERSION_INDEX_WAL_FILE]
+        .iter()
+        .filter_map(|file| fs::metadata(table_dir.join(file)).ok())
+        .map(|metadata| metadata.len())
+        .sum();
+
     Ok(TableStats {
         name: name.to_string(),
         current_size,
+        row_count,
         deltas_size,
+        version_index_size,
         version_count,
         latest_version,
         oldest_version,
+        delimiter: Table::new(base_path, name).delimiter()?,
     })
 }
@@ -137,12 +137,19 @@ mod tests {
 
         assert_eq!(stats.name, "text");
         assert_eq!(stats.current_size, initial_content.len() as u64);
+        assert_eq!(stats.row_count, 1, "Header is not counted");
         assert_eq!(stats.version_count, 1, "Should have 1 version");
         assert!(stats.latest_version > 0, "Should have valid timestamp");
         assert_eq!(
             stats.oldest_version, stats.latest_version,
             "Single version: oldest == latest"
         );
+
+        // version.idx and its WAL are counted separately from the deltas
+        let table_dir = base_path.join("tables").join("text");
+        let index_size = fs::metadata(table_dir.join("version.idx")).unwrap().len()
+            + fs::metadata(table_dir.join("version.wal")).unwrap().len();
+        assert_eq!(stats.version_index_size, index_size);
     }
 
     /// Test table_stats with multiple versions.
@@ -9,6 +9,7 @@
 //! .reed/tables/{table_name}/
 //! ├── current.csv          # Active version
 //! ├── {timestamp}.bsdiff   # Binary deltas (XZ compressed)
+//! ├── {timestamp}.snap     # Periodic full snapshots
 //! └── version.log          # Encoded metadata
 //! ```
 //!
@@ -42,20 +43,35 @@
 //! # Ok::<(), reedbase::ReedError>(())
 //! ```
 
+pub mod audit;
 pub mod csv_parser;
 pub mod helpers;
+#[cfg(feature = "rusqlite")]
+pub mod sqlite;
 pub mod table;
 pub mod types;
 
+#[cfg(test)]
+mod audit_test;
 #[cfg(test)]
 mod csv_parser_test;
 #[cfg(test)]
 mod helpers_test;
+#[cfg(all(test, feature = "rusqlite"))]
+mod sqlite_test;
 #[cfg(test)]
 mod table_test;
 
 // Re-export public API
-pub use csv_parser::{parse_csv, parse_csv_row};
+pub use audit::read_transaction_log;
+#[cfg(feature = "rayon")]
+pub use csv_parser::parse_csv_parallel;
+pub use csv_parser::{
+    detect_delimiter, parse_csv, parse_csv_auto, parse_csv_row, parse_csv_row_with,
+};
 pub use helpers::{list_tables, table_exists, table_stats};
-pub use table::Table;
-pub use types::{CsvRow, TableStats, VersionInfo, WriteResult};
+pub use table::{RowStream, Table, DEFAULT_SNAPSHOT_INTERVAL, SWAP_ACTION_CODE};
+pub use types::{
+    AuditFilter, ColumnStats, CsvRow, Delimiter, RowEdit, TableMeta, TableStats, TransactionEntry,
+    VersionInfo, WritePhase, WriteProgress, WriteResult,
+};
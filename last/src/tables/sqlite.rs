@@ -0,0 +1,277 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! SQLite export and import (requires the `rusqlite` feature).
+//!
+//! Export copies the current version of a table into a SQLite database file
+//! so it can be inspected with standard SQL tooling. Column types are
+//! inferred with `schema::infer_schema()`:
+//!
+//! | Inferred type | SQLite type |
+//! |---------------|-------------|
+//! | `integer`     | `INTEGER`   |
+//! | `float`       | `REAL`      |
+//! | `boolean`     | `INTEGER` (0/1) |
+//! | `string`      | `TEXT`      |
+//!
+//! Empty cells become `NULL`. An existing SQLite table of the same name is
+//! replaced; each export runs inside a single transaction, so a failure
+//! leaves the destination file unchanged.
+//!
+//! Import reads a SQLite table back as string fields for
+//! `Database::import_from_sqlite()`; `NULL` becomes an empty field.
+
+use crate::error::{ReedError, ReedResult};
+use crate::schema::infer_schema;
+use crate::tables::csv_parser::parse_csv;
+use crate::tables::table::Table;
+use crate::tables::types::Delimiter;
+use rusqlite::types::{Value, ValueRef};
+use rusqlite::{params_from_iter, Connection, OpenFlags, Transaction};
+use std::path::Path;
+
+impl Table {
+    /// Exports the current table version into a SQLite database file.
+    ///
+    /// ## Input
+    /// - `dest`: SQLite database file (created if missing)
+    /// - `table_name`: Name of the SQLite table to create (replaced if present)
+    ///
+    /// ## Output
+    /// - `Ok(usize)`: Number of data rows exported (header excluded)
+    ///
+    /// ## Performance
+    /// - O(n) rows, single transaction: ~100k rows/s typical
+    ///
+    /// ## Error Conditions
+    /// - `TableNotFound`: Table does not exist
+    /// - `InvalidCsv`: current.csv is malformed or a row has more fields than the header
+    /// - `IoError`: Cannot open or write the SQLite file
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::tables::Table;
+    /// use std::path::Path;
+    ///
+    /// let table = Table::new(Path::new(".reed"), "text");
+    /// let rows = table.export_to_sqlite(Path::new("export.db"), "text")?;
+    /// println!("Exported {} rows", rows);
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn export_to_sqlite(&self, dest: &Path, table_name: &str) -> ReedResult<usize> {
+        let content = self.read_current()?;
+
+        let mut conn = open_sqlite(dest)?;
+        let tx = conn
+            .transaction()
+            .map_err(|e| sqlite_error("sqlite_begin", e))?;
+        let count = export_content(&tx, table_name, &content, self.delimiter()?)?;
+        tx.commit().map_err(|e| sqlite_error("sqlite_commit", e))?;
+
+        Ok(count)
+    }
+}
+
+/// Opens (or creates) a SQLite database file.
+pub(crate) fn open_sqlite(dest: &Path) -> ReedResult<Connection> {
+    Connection::open(dest).map_err(|e| sqlite_error("sqlite_open", e))
+}
+
+/// Writes table content into `table_name` within an open transaction.
+///
+/// Returns the number of data rows inserted. Nothing is committed here so
+/// callers can group several tables into one atomic export.
+pub(crate) fn export_content(
+    tx: &Transaction,
+    table_name: &str,
+    content: &[u8],
+    delimiter: Delimiter,
+) -> ReedResult<usize> {
+    let rows = parse_csv(content, delimiter)?;
+    let Some((header_row, data_rows)) = rows.split_first() else {
+        return Err(ReedError::InvalidCsv {
+            reason: "Table has no header row".to_string(),
+            line: 0,
+        });
+    };
+
+    let header: Vec<String> = std::iter::once(header_row.key.clone())
+        .chain(header_row.values.iter().cloned())
+        .collect();
+    let records: Vec<Vec<String>> = data_rows
+        .iter()
+        .map(|row| {
+            std::iter::once(row.key.clone())
+                .chain(row.values.iter().cloned())
+                .collect()
+        })
+        .collect();
+
+    if let Some((index, record)) = records
+        .iter()
+        .enumerate()
+        .find(|(_, record)| record.len() > header.len())
+    {
+        return Err(ReedError::InvalidCsv {
+            reason: format!(
+                "Row has {} fields, header has {}",
+                record.len(),
+                header.len()
+            ),
+            line: index + 2,
+        });
+    }
+
+    let types: Vec<String> = infer_schema(&header, &records)
+        .columns
+        .into_iter()
+        .map(|column| column.col_type)
+        .collect();
+
+    let column_defs: Vec<String> = header
+        .iter()
+        .zip(&types)
+        .map(|(name, col_type)| format!("{} {}", quote_ident(name), sqlite_type(col_type)))
+        .collect();
+    let quoted_table = quote_ident(table_name);
+
+    tx.execute_batch(&format!(
+        "DROP TABLE IF EXISTS {};\nCREATE TABLE {} ({});",
+        quoted_table,
+        quoted_table,
+        column_defs.join(", ")
+    ))
+    .map_err(|e| sqlite_error("sqlite_create_table", e))?;
+
+    let placeholders = vec!["?"; header.len()].join(", ");
+    let mut insert = tx
+        .prepare(&format!(
+            "INSERT INTO {} VALUES ({})",
+            quoted_table, placeholders
+        ))
+        .map_err(|e| sqlite_error("sqlite_prepare", e))?;
+
+    for record in &records {
+        let values = types.iter().enumerate().map(|(i, col_type)| {
+            record
+                .get(i)
+                .map_or(Value::Null, |field| sqlite_value(field, col_type))
+        });
+        insert
+            .execute(params_from_iter(values))
+            .map_err(|e| sqlite_error("sqlite_insert", e))?;
+    }
+
+    Ok(records.len())
+}
+
+/// Reads all rows of a SQLite table as string fields.
+///
+/// ## Input
+/// - `src`: Existing SQLite database file (opened read-only)
+/// - `sqlite_table`: Table to read
+///
+/// ## Output
+/// - `Ok((columns, rows))`: Column names and rows in `SELECT *` order
+///
+/// ## Error Conditions
+/// - `IoError`: File missing, table missing or query failed
+/// - `ValidationError`: A value contains `|` or a line break, or a BLOB is not UTF-8
+pub(crate) fn read_sqlite_table(
+    src: &Path,
+    sqlite_table: &str,
+) -> ReedResult<(Vec<String>, Vec<Vec<String>>)> {
+    let conn = Connection::open_with_flags(src, OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .map_err(|e| sqlite_error("sqlite_open", e))?;
+    let mut select = conn
+        .prepare(&format!("SELECT * FROM {}", quote_ident(sqlite_table)))
+        .map_err(|e| sqlite_error("sqlite_prepare", e))?;
+
+    let columns: Vec<String> = select
+        .column_names()
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+    let mut rows = Vec::new();
+    let mut result = select
+        .query([])
+        .map_err(|e| sqlite_error("sqlite_select", e))?;
+    while let Some(row) = result
+        .next()
+        .map_err(|e| sqlite_error("sqlite_select", e))?
+    {
+        let mut fields = Vec::with_capacity(columns.len());
+        for (i, column) in columns.iter().enumerate() {
+            let value = row
+                .get_ref(i)
+                .map_err(|e| sqlite_error("sqlite_select", e))?;
+            fields.push(field_from_sqlite(value, column)?);
+        }
+        rows.push(fields);
+    }
+
+    Ok((columns, rows))
+}
+
+/// Converts a SQLite value to a CSV field.
+fn field_from_sqlite(value: ValueRef, column: &str) -> ReedResult<String> {
+    let field = match value {
+        ValueRef::Null => String::new(),
+        ValueRef::Integer(i) => i.to_string(),
+        ValueRef::Real(f) => f.to_string(),
+        ValueRef::Text(bytes) | ValueRef::Blob(bytes) => String::from_utf8(bytes.to_vec())
+            .map_err(|_| ReedError::ValidationError {
+                column: column.to_string(),
+                reason: "Value is not valid UTF-8".to_string(),
+                value: None,
+            })?,
+    };
+
+    if field.contains(['|', '\n', '\r']) {
+        return Err(ReedError::ValidationError {
+            column: column.to_string(),
+            reason: "Value contains a field delimiter or line break".to_string(),
+            value: Some(field),
+        });
+    }
+
+    Ok(field)
+}
+
+/// Maps an inferred schema type to its SQLite column type.
+fn sqlite_type(col_type: &str) -> &'static str {
+    match col_type {
+        "integer" | "boolean" => "INTEGER",
+        "float" => "REAL",
+        _ => "TEXT",
+    }
+}
+
+/// Converts a field to a typed SQLite value.
+///
+/// Fields already passed type inference, so parsing cannot fail except for
+/// empty cells, which become `NULL`.
+fn sqlite_value(field: &str, col_type: &str) -> Value {
+    if field.is_empty() {
+        return Value::Null;
+    }
+    match col_type {
+        "integer" => field.parse().map_or(Value::Null, Value::Integer),
+        "float" => field.parse().map_or(Value::Null, Value::Real),
+        "boolean" => Value::Integer(matches!(field, "true" | "1") as i64),
+        _ => Value::Text(field.to_string()),
+    }
+}
+
+/// Quotes an SQL identifier, doubling embedded quotes.
+fn quote_ident(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+fn sqlite_error(operation: &str, e: rusqlite::Error) -> ReedError {
+    ReedError::IoError {
+        operation: operation.to_string(),
+        reason: e.to_string(),
+    }
+}
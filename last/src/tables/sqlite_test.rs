@@ -0,0 +1,275 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for SQLite export.
+
+#[cfg(test)]
+mod tests {
+    use crate::database::Database;
+    use crate::error::ReedError;
+    use crate::tables::Table;
+    use rusqlite::Connection;
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn setup_test(name: &str) -> PathBuf {
+        let temp_dir = std::env::temp_dir().join(format!("reedbase_sqlite_test_{}", name));
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        crate::registry::set_base_path(temp_dir.clone());
+        crate::registry::init_registry(&temp_dir).unwrap();
+        crate::registry::reload_dictionaries().unwrap();
+
+        temp_dir
+    }
+
+    #[test]
+    fn test_export_infers_column_types() {
+        let temp_dir = setup_test("types");
+        let table = Table::new(&temp_dir, "products");
+        table
+            .init(
+                b"id|price|active|name\n1|9.5|true|Tea\n2|4|false|\n3||1|Cake\n",
+                "testuser",
+            )
+            .unwrap();
+
+        let dest = temp_dir.join("export.db");
+        assert_eq!(table.export_to_sqlite(&dest, "products").unwrap(), 3);
+
+        let conn = Connection::open(&dest).unwrap();
+        let types: Vec<String> = conn
+            .prepare("SELECT type FROM pragma_table_info('products') ORDER BY cid")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(types, vec!["INTEGER", "REAL", "INTEGER", "TEXT"]);
+
+        let (price, active, name): (Option<f64>, i64, Option<String>) = conn
+            .query_row(
+                "SELECT price, active, name FROM products WHERE id = 2",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .unwrap();
+        assert_eq!(price, Some(4.0));
+        assert_eq!(active, 0);
+        assert_eq!(name, None);
+
+        let missing_price: Option<f64> = conn
+            .query_row("SELECT price FROM products WHERE id = 3", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(missing_price, None);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_export_replaces_existing_table() {
+        let temp_dir = setup_test("replace");
+        let table = Table::new(&temp_dir, "text");
+        table.init(b"key|value\na|1\nb|2\n", "testuser").unwrap();
+
+        let dest = temp_dir.join("export.db");
+        table.export_to_sqlite(&dest, "my \"text\"").unwrap();
+        table.write(b"key|value\nc|3\n", "testuser").unwrap();
+        assert_eq!(table.export_to_sqlite(&dest, "my \"text\"").unwrap(), 1);
+
+        let conn = Connection::open(&dest).unwrap();
+        let keys: Vec<String> = conn
+            .prepare("SELECT key FROM \"my \"\"text\"\"\"")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(keys, vec!["c"]);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_export_rejects_extra_fields_atomically() {
+        let temp_dir = setup_test("extra_fields");
+        let table = Table::new(&temp_dir, "text");
+        table
+            .init(b"key|value\na|1\nb|2|surplus\n", "testuser")
+            .unwrap();
+
+        let dest = temp_dir.join("export.db");
+        let result = table.export_to_sqlite(&dest, "text");
+        assert!(matches!(result, Err(ReedError::InvalidCsv { line: 3, .. })));
+
+        let conn = Connection::open(&dest).unwrap();
+        let tables: i64 = conn
+            .query_row("SELECT COUNT(*) FROM sqlite_master", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(tables, 0);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_export_all_tables() {
+        let temp_dir = setup_test("all");
+        Table::new(&temp_dir, "text")
+            .init(b"key|value\na|1\nb|2\n", "testuser")
+            .unwrap();
+        Table::new(&temp_dir, "routes")
+            .init(b"key|route\nr1|/home\n", "testuser")
+            .unwrap();
+
+        let db = Database::open(&temp_dir).unwrap();
+        let dest = temp_dir.join("export.db");
+        assert_eq!(db.export_all_to_sqlite(&dest).unwrap(), 3);
+
+        let conn = Connection::open(&dest).unwrap();
+        let route: String = conn
+            .query_row("SELECT route FROM routes WHERE key = 'r1'", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(route, "/home");
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    fn legacy_db(path: &std::path::Path) {
+        let conn = Connection::open(path).unwrap();
+        conn.execute_batch(
+            "CREATE TABLE texts (value TEXT, key TEXT, hits INTEGER, ratio REAL);
+             INSERT INTO texts VALUES ('Welcome', 'page.title<en>', 3, 0.5);
+             INSERT INTO texts VALUES (NULL, 'page.title<de>', NULL, 1.25);",
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_import_creates_table_with_key_first() {
+        let temp_dir = setup_test("import_new");
+        let db = Database::open(&temp_dir).unwrap();
+        let src = temp_dir.join("legacy.db");
+        legacy_db(&src);
+
+        let imported = db
+            .import_from_sqlite(&src, "texts", "text", "testuser")
+            .unwrap();
+        assert_eq!(imported, 2);
+
+        let content = Table::new(&temp_dir, "text").read_current().unwrap();
+        assert_eq!(
+            String::from_utf8(content).unwrap(),
+            "key|value|hits|ratio\npage.title<en>|Welcome|3|0.5\npage.title<de>|||1.25\n"
+        );
+        assert!(db.list_tables().unwrap().contains(&"text".to_string()));
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_import_follows_existing_header() {
+        let temp_dir = setup_test("import_existing");
+        Table::new(&temp_dir, "text")
+            .init(
+                b"key|hits|ratio|value|note\nintro.text|1|0|Hi|kept\n",
+                "testuser",
+            )
+            .unwrap();
+        let db = Database::open(&temp_dir).unwrap();
+        let src = temp_dir.join("legacy.db");
+        legacy_db(&src);
+
+        db.import_from_sqlite(&src, "texts", "text", "testuser")
+            .unwrap();
+
+        let content = Table::new(&temp_dir, "text").read_current().unwrap();
+        let lines: Vec<String> = String::from_utf8(content)
+            .unwrap()
+            .lines()
+            .map(String::from)
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                "key|hits|ratio|value|note",
+                "intro.text|1|0|Hi|kept",
+                "page.title<en>|3|0.5|Welcome|",
+                "page.title<de>||1.25||",
+            ]
+        );
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_import_rejects_invalid_rows() {
+        let temp_dir = setup_test("import_invalid");
+        Table::new(&temp_dir, "narrow")
+            .init(b"key|value\n", "testuser")
+            .unwrap();
+        let db = Database::open(&temp_dir).unwrap();
+        let src = temp_dir.join("legacy.db");
+        legacy_db(&src);
+        Connection::open(&src)
+            .unwrap()
+            .execute_batch(
+                "CREATE TABLE bad_keys (key TEXT, value TEXT);
+                 INSERT INTO bad_keys VALUES ('Page.Title', 'x');",
+            )
+            .unwrap();
+
+        // Unknown column in target table
+        let result = db.import_from_sqlite(&src, "texts", "narrow", "testuser");
+        assert!(matches!(result, Err(ReedError::ValidationError { .. })));
+
+        // Key violates RBKS v2
+        let result = db.import_from_sqlite(&src, "bad_keys", "keys", "testuser");
+        assert!(matches!(result, Err(ReedError::InvalidCsv { .. })));
+        assert!(!Table::new(&temp_dir, "keys").exists());
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_import_honours_schema() {
+        use crate::schema::{save_schema, ColumnDef, Schema};
+
+        let temp_dir = setup_test("import_schema");
+        Table::new(&temp_dir, "scores")
+            .init(b"key|points\n", "testuser")
+            .unwrap();
+        let schema = Schema::new(
+            "2.0".to_string(),
+            true,
+            vec![
+                ColumnDef::new("key".to_string(), "string".to_string()),
+                ColumnDef::new("points".to_string(), "integer".to_string()),
+            ],
+        );
+        save_schema(&temp_dir, "scores", &schema).unwrap();
+
+        let db = Database::open(&temp_dir).unwrap();
+        let src = temp_dir.join("legacy.db");
+        Connection::open(&src)
+            .unwrap()
+            .execute_batch(
+                "CREATE TABLE scores (key TEXT, points TEXT);
+                 INSERT INTO scores VALUES ('game.alice', '10');
+                 INSERT INTO scores VALUES ('game.bob', 'many');",
+            )
+            .unwrap();
+
+        let result = db.import_from_sqlite(&src, "scores", "scores", "testuser");
+        assert!(matches!(result, Err(ReedError::ValidationError { .. })));
+        assert_eq!(
+            Table::new(&temp_dir, "scores").read_current().unwrap(),
+            b"key|points\n"
+        );
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+}
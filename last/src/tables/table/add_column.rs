@@ -0,0 +1,170 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Adding a column with a default value (`Table::add_column_with_default()`).
+
+use crate::error::{ReedError, ReedResult};
+use crate::schema::{
+    load_schema, save_schema, schema_exists, validate_row, ColumnDef, CsvRow as SchemaRow, Schema,
+};
+use crate::tables::types::WriteResult;
+
+use super::Table;
+
+impl Table {
+    /// Adds a column to every row, filled with a default value.
+    ///
+    /// Appends `column` to the header and `default_value` to each data row,
+    /// then writes the result as a single new version. If the table has a
+    /// schema, the new column is appended to it and every row is validated
+    /// against the extended schema before anything is written.
+    ///
+    /// ## Input
+    /// - `column`: New column name
+    /// - `col_type`: Schema type ("string", "integer", "float", "boolean", "timestamp")
+    /// - `default_value`: Value for existing rows (may be empty)
+    /// - `user`: Username for audit
+    ///
+    /// ## Output
+    /// - `Result<WriteResult>`: Metadata of the new version
+    ///
+    /// ## Performance
+    /// - O(n) where n = number of rows, plus one delta write (< 5ms typical)
+    ///
+    /// ## Error Conditions
+    /// - TableNotFound: Table doesn't exist
+    /// - ColumnAlreadyExists: Column is already in the header or schema
+    /// - ValidationError: Column name or default contains `|` or a line break,
+    ///   or a row violates the extended schema
+    /// - InvalidCsv: Table has no header row
+    /// - IoError: Cannot write files (content is restored if the schema update fails)
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::tables::Table;
+    /// use std::path::Path;
+    ///
+    /// let table = Table::new(Path::new(".reed"), "users");
+    /// table.add_column_with_default("active", "boolean", "true", "admin")?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn add_column_with_default(
+        &self,
+        column: &str,
+        col_type: &str,
+        default_value: &str,
+        user: &str,
+    ) -> ReedResult<WriteResult> {
+        if !self.exists() {
+            return Err(ReedError::TableNotFound {
+                name: self.name.clone(),
+            });
+        }
+
+        let separator = self.delimiter()?.as_char();
+        let is_unsafe = |s: &str| s.contains([separator, '\n', '\r']);
+        if column.is_empty() || is_unsafe(column) {
+            return Err(ReedError::ValidationError {
+                column: column.to_string(),
+                reason: format!(
+                    "Column name must be non-empty without {:?} or line breaks",
+                    separator
+                ),
+                value: None,
+            });
+        }
+        if is_unsafe(default_value) {
+            return Err(ReedError::ValidationError {
+                column: column.to_string(),
+                reason: format!(
+                    "Default value must not contain {:?} or line breaks",
+                    separator
+                ),
+                value: Some(default_value.to_string()),
+            });
+        }
+
+        let original = self.read_current()?;
+        let text = std::str::from_utf8(&original).map_err(|e| ReedError::InvalidCsv {
+            reason: format!("Invalid UTF-8: {}", e),
+            line: 0,
+        })?;
+
+        let already_exists = || ReedError::ColumnAlreadyExists {
+            table: self.name.clone(),
+            column: column.to_string(),
+        };
+
+        let mut output = String::with_capacity(text.len() + column.len());
+        let mut rows: Vec<Vec<String>> = Vec::new();
+        let mut header_seen = false;
+
+        for line in text.lines() {
+            let trimmed = line.trim();
+
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                output.push_str(line);
+                output.push('\n');
+                continue;
+            }
+
+            output.push_str(line);
+            output.push(separator);
+
+            if !header_seen {
+                header_seen = true;
+                if trimmed.split(separator).any(|name| name == column) {
+                    return Err(already_exists());
+                }
+                output.push_str(column);
+            } else {
+                let mut fields: Vec<String> =
+                    trimmed.split(separator).map(str::to_string).collect();
+                fields.push(default_value.to_string());
+                rows.push(fields);
+                output.push_str(default_value);
+            }
+
+            output.push('\n');
+        }
+
+        if !header_seen {
+            return Err(ReedError::InvalidCsv {
+                reason: "Missing header row".to_string(),
+                line: 1,
+            });
+        }
+
+        let new_column = ColumnDef::new(column.to_string(), col_type.to_string());
+        let schema = if schema_exists(&self.base_path, &self.name) {
+            let mut schema = load_schema(&self.base_path, &self.name)?;
+            if schema.columns.iter().any(|c| c.name == column) {
+                return Err(already_exists());
+            }
+            schema.columns.push(new_column);
+            for fields in rows {
+                let key = fields.first().cloned().unwrap_or_default();
+                validate_row(&SchemaRow::new(key, fields), &schema)?;
+            }
+            Some(schema)
+        } else {
+            // No schema to extend; still reject a default of the wrong type
+            let single = Schema::new("2.0".to_string(), false, vec![new_column]);
+            let row = SchemaRow::new(default_value.to_string(), vec![default_value.to_string()]);
+            validate_row(&row, &single)?;
+            None
+        };
+
+        let result = self.write(output.as_bytes(), user)?;
+
+        if let Some(schema) = schema {
+            if let Err(e) = save_schema(&self.base_path, &self.name, &schema) {
+                // Keep content and schema in step
+                self.write(&original, user)?;
+                return Err(e);
+            }
+        }
+
+        Ok(result)
+    }
+}
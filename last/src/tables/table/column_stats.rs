@@ -0,0 +1,131 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Per-column statistics (`Table::column_stats()`).
+
+use crate::error::{ReedError, ReedResult};
+use crate::schema::infer::infer_column_type;
+use crate::tables::types::ColumnStats;
+use std::collections::HashMap;
+
+use super::Table;
+
+impl Table {
+    /// Computes value distribution statistics for one column.
+    ///
+    /// Scans current.csv once, building a frequency map from which all
+    /// figures are derived. Empty cells count as nulls.
+    ///
+    /// ## Input
+    /// - `column`: Column name as it appears in the header
+    /// - `top_n`: Number of most frequent values to report
+    ///
+    /// ## Output
+    /// - `Result<ColumnStats>`: Count, nulls, distinct, min/max and top values
+    ///
+    /// ## Performance
+    /// - O(n) single pass, O(d) memory where d = distinct values
+    /// - < 10ms for typical tables (< 10k rows)
+    ///
+    /// ## Error Conditions
+    /// - TableNotFound: Table doesn't exist
+    /// - ColumnNotFound: Column not in header
+    /// - InvalidCsv: File is not valid UTF-8 or has no header
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::tables::Table;
+    /// use std::path::Path;
+    ///
+    /// let table = Table::new(Path::new(".reed"), "text");
+    /// let stats = table.column_stats("key", 10)?;
+    /// println!("{} distinct of {} rows", stats.distinct_count, stats.count);
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn column_stats(&self, column: &str, top_n: usize) -> ReedResult<ColumnStats> {
+        let content = self.read_current()?;
+        let separator = self.delimiter()?.as_char();
+        let text = std::str::from_utf8(&content).map_err(|e| ReedError::InvalidCsv {
+            reason: format!("Invalid UTF-8: {}", e),
+            line: 0,
+        })?;
+
+        let mut lines = text
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with('#'));
+
+        let header = lines.next().ok_or_else(|| ReedError::InvalidCsv {
+            reason: "Missing header row".to_string(),
+            line: 1,
+        })?;
+        let col_idx = header
+            .split(separator)
+            .position(|name| name == column)
+            .ok_or_else(|| ReedError::ColumnNotFound {
+                table: self.name.clone(),
+                column: column.to_string(),
+            })?;
+
+        let mut count = 0;
+        let mut null_count = 0;
+        let mut frequencies: HashMap<&str, usize> = HashMap::new();
+
+        for line in lines {
+            count += 1;
+            match line.split(separator).nth(col_idx) {
+                Some(value) if !value.is_empty() => *frequencies.entry(value).or_insert(0) += 1,
+                _ => null_count += 1,
+            }
+        }
+
+        // Same type inference as schema::infer_schema(), once per distinct value
+        let inferred_type = infer_column_type(column, frequencies.keys().copied());
+
+        // Numeric ordering for numeric columns
+        let numeric: Option<Vec<(f64, &str)>> =
+            matches!(inferred_type.as_str(), "integer" | "float")
+                .then(|| {
+                    frequencies
+                        .keys()
+                        .map(|v| v.parse::<f64>().ok().map(|n| (n, *v)))
+                        .collect()
+                })
+                .flatten();
+        let (min_value, max_value) = match numeric {
+            Some(values) if !values.is_empty() => {
+                let min = values.iter().min_by(|a, b| a.0.total_cmp(&b.0)).unwrap();
+                let max = values.iter().max_by(|a, b| a.0.total_cmp(&b.0)).unwrap();
+                (min.1.to_string(), max.1.to_string())
+            }
+            _ => (
+                frequencies
+                    .keys()
+                    .min()
+                    .map(|v| v.to_string())
+                    .unwrap_or_default(),
+                frequencies
+                    .keys()
+                    .max()
+                    .map(|v| v.to_string())
+                    .unwrap_or_default(),
+            ),
+        };
+
+        let mut top_n_values: Vec<(String, usize)> = frequencies
+            .iter()
+            .map(|(value, freq)| (value.to_string(), *freq))
+            .collect();
+        top_n_values.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        top_n_values.truncate(top_n);
+
+        Ok(ColumnStats {
+            count,
+            null_count,
+            distinct_count: frequencies.len(),
+            inferred_type,
+            min_value,
+            max_value,
+            top_n_values,
+        })
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Exporting a past version to a file (`Table::export_version()`).
+
+use crate::error::{ReedError, ReedResult};
+use std::fs::{self};
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use super::Table;
+
+/// Distinguishes temp files of concurrent `Table::export_version()` calls.
+static EXPORT_SEQ: AtomicU64 = AtomicU64::new(0);
+
+impl Table {
+    /// Reconstructs a past version into a file, leaving history untouched.
+    ///
+    /// Unlike `rollback()`, neither current.csv nor version.log is changed.
+    /// Temp files carry the target timestamp plus a per-call suffix, so
+    /// concurrent exports do not clash.
+    ///
+    /// ## Input
+    /// - `timestamp`: Version timestamp (from `list_versions()`)
+    /// - `dest`: File to write the reconstructed content to (overwritten)
+    ///
+    /// ## Output
+    /// - `Result<()>`: Success or error
+    ///
+    /// ## Performance
+    /// - Same as `rollback()` without the final write: < 100ms per 50 deltas
+    ///
+    /// ## Error Conditions
+    /// - TableNotFound: Table doesn't exist
+    /// - VersionNotFound: Timestamp not in log
+    /// - DeltaCorrupted: Cannot apply delta
+    /// - IoError: Cannot write `dest`
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::tables::Table;
+    /// use std::path::Path;
+    ///
+    /// let table = Table::new(Path::new(".reed"), "text");
+    /// let versions = table.list_versions()?;
+    /// table.export_version(versions[1].timestamp, Path::new("text_previous.csv"))?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn export_version(&self, timestamp: u64, dest: &Path) -> ReedResult<()> {
+        let tag = format!(
+            "export_{}_{}_{}",
+            timestamp,
+            std::process::id(),
+            EXPORT_SEQ.fetch_add(1, Ordering::Relaxed)
+        );
+        let reconstructed_path = self.reconstruct_version(timestamp, &tag)?;
+
+        let copied = fs::copy(&reconstructed_path, dest).map_err(|e| ReedError::IoError {
+            operation: format!("export_version: {}", dest.display()),
+            reason: e.to_string(),
+        });
+        let _ = fs::remove_file(&reconstructed_path);
+        copied?;
+
+        Ok(())
+    }
+}
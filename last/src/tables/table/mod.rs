@@ -0,0 +1,401 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Universal table abstraction for ReedBase.
+//!
+//! `Table` is defined here with its paths, metadata and `init()`/`delete()`;
+//! the submodules add the rest of its API: reading (`read`), writing and
+//! locking (`write`, `stream_write`, `rewrite`, `swap`), versions
+//! (`versions`, `export`, `prune`) and table operations (`add_column`,
+//! `split`, `sample`, `column_stats`).
+
+mod add_column;
+mod column_stats;
+mod export;
+mod prune;
+mod read;
+mod rewrite;
+mod sample;
+mod split;
+mod stream_write;
+mod swap;
+mod versions;
+mod write;
+
+pub use read::RowStream;
+pub use swap::SWAP_ACTION_CODE;
+
+use crate::error::{ReedError, ReedResult};
+use crate::registry::{get_action_code, get_or_create_user_code};
+use crate::tables::csv_parser::detect_delimiter;
+use crate::tables::types::{Delimiter, TableMeta};
+use crate::version::VersionIndices;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Default number of versions between full snapshots (see `Table::with_snapshot_interval()`).
+pub const DEFAULT_SNAPSHOT_INTERVAL: usize = 50;
+
+/// Universal table abstraction.
+///
+/// All tables (text, routes, meta, users, etc.) use identical structure.
+///
+/// ## Structure
+/// ```text
+/// .reed/tables/{name}/
+/// ├── current.csv          # Active version
+/// ├── {timestamp}.bsdiff   # Binary deltas (XZ compressed)
+/// ├── {timestamp}.snap     # Full content every `snapshot_interval` versions
+/// └── version.log          # Encoded metadata
+/// ```
+///
+/// ## Performance
+/// - read_current(): < 1ms (cached)
+/// - write(): < 5ms (create delta + update)
+/// - list_versions(): < 5ms (parse log)
+/// - rollback(): at most `snapshot_interval` deltas applied
+///
+/// ## Thread Safety
+/// - Multiple readers: Yes (concurrent reads safe)
+/// - Multiple writers: NO (use WriteSession from REED-19-06)
+pub struct Table {
+    base_path: PathBuf,
+    name: String,
+    snapshot_interval: usize,
+}
+
+impl Table {
+    /// Creates new table reference.
+    ///
+    /// Does NOT create table on disk, only creates reference.
+    ///
+    /// ## Input
+    /// - `base_path`: Path to ReedBase directory
+    /// - `name`: Table name
+    ///
+    /// ## Output
+    /// - `Table`: Table reference
+    ///
+    /// ## Example Usage
+    /// ```
+    /// use reedbase_last::tables::Table;
+    /// use std::path::Path;
+    ///
+    /// let table = Table::new(Path::new(".reed"), "text");
+    /// ```
+    pub fn new(base_path: &Path, name: &str) -> Self {
+        Self {
+            base_path: base_path.to_path_buf(),
+            name: name.to_string(),
+            snapshot_interval: DEFAULT_SNAPSHOT_INTERVAL,
+        }
+    }
+
+    /// Sets how often writes store a full snapshot of the content.
+    ///
+    /// Every version whose position in version.log is a multiple of
+    /// `interval` also gets a `{timestamp}.snap` file, so rebuilding a past
+    /// version applies at most `interval - 1` deltas. `0` disables snapshots.
+    ///
+    /// ## Input
+    /// - `interval`: Versions between snapshots (default 50)
+    ///
+    /// ## Output
+    /// - `Table`: Table reference with the new interval
+    ///
+    /// ## Example Usage
+    /// ```
+    /// use reedbase_last::tables::Table;
+    /// use std::path::Path;
+    ///
+    /// let table = Table::new(Path::new(".reed"), "text").with_snapshot_interval(20);
+    /// assert_eq!(table.snapshot_interval(), 20);
+    /// ```
+    pub fn with_snapshot_interval(mut self, interval: usize) -> Self {
+        self.snapshot_interval = interval;
+        self
+    }
+
+    /// Gets the number of versions between full snapshots.
+    pub fn snapshot_interval(&self) -> usize {
+        self.snapshot_interval
+    }
+
+    /// Gets table name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets path to ReedBase directory the table lives in.
+    pub fn base_path(&self) -> &Path {
+        &self.base_path
+    }
+
+    /// Gets path to table directory.
+    fn table_dir(&self) -> PathBuf {
+        self.base_path.join("tables").join(&self.name)
+    }
+
+    /// Gets path to current.csv.
+    ///
+    /// ## Output
+    /// - `PathBuf`: Full path to current.csv
+    ///
+    /// ## Performance
+    /// - O(1), < 10ns
+    pub fn current_path(&self) -> PathBuf {
+        self.table_dir().join("current.csv")
+    }
+
+    /// Gets path to delta file.
+    ///
+    /// ## Input
+    /// - `timestamp`: Version timestamp
+    ///
+    /// ## Output
+    /// - `PathBuf`: Full path to {timestamp}.bsdiff
+    pub fn delta_path(&self, timestamp: u64) -> PathBuf {
+        self.table_dir().join(format!("{}.bsdiff", timestamp))
+    }
+
+    /// Gets path to snapshot file.
+    ///
+    /// ## Input
+    /// - `timestamp`: Version timestamp
+    ///
+    /// ## Output
+    /// - `PathBuf`: Full path to {timestamp}.snap
+    pub fn snapshot_path(&self, timestamp: u64) -> PathBuf {
+        self.table_dir().join(format!("{}.snap", timestamp))
+    }
+
+    /// Gets path to version.log.
+    ///
+    /// ## Output
+    /// - `PathBuf`: Full path to version.log
+    pub fn log_path(&self) -> PathBuf {
+        self.table_dir().join("version.log")
+    }
+
+    /// Gets path to the `.meta` settings file.
+    ///
+    /// ## Output
+    /// - `PathBuf`: Full path to .meta
+    pub fn meta_path(&self) -> PathBuf {
+        self.table_dir().join(".meta")
+    }
+
+    /// Reads the field delimiter recorded in `.meta`.
+    ///
+    /// Tables created before `.meta` existed have none and are pipe-delimited.
+    ///
+    /// ## Output
+    /// - `Result<Delimiter>`: Recorded delimiter, `Delimiter::Pipe` if no `.meta`
+    ///
+    /// ## Performance
+    /// - < 100μs (one small file read)
+    ///
+    /// ## Error Conditions
+    /// - IoError: Cannot read .meta
+    /// - DeserializationError: .meta is not valid TOML
+    pub fn delimiter(&self) -> ReedResult<Delimiter> {
+        let content = match fs::read_to_string(self.meta_path()) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Delimiter::Pipe),
+            Err(e) => {
+                return Err(ReedError::IoError {
+                    operation: "read_table_meta".to_string(),
+                    reason: e.to_string(),
+                })
+            }
+        };
+
+        let meta: TableMeta =
+            toml::from_str(&content).map_err(|e| ReedError::DeserializationError {
+                reason: format!("Invalid .meta for table '{}': {}", self.name, e),
+            })?;
+        Ok(meta.delimiter)
+    }
+
+    /// Records the field delimiter in `.meta`.
+    ///
+    /// Only the setting changes; current.csv is not rewritten.
+    ///
+    /// ## Input
+    /// - `delimiter`: Delimiter to read and write the table with
+    ///
+    /// ## Error Conditions
+    /// - TableNotFound: Table doesn't exist
+    /// - SerializationError: Settings cannot be encoded
+    /// - IoError: Cannot write .meta
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::tables::{Delimiter, Table};
+    /// use std::path::Path;
+    ///
+    /// let table = Table::new(Path::new(".reed"), "imports");
+    /// table.set_delimiter(Delimiter::Comma)?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn set_delimiter(&self, delimiter: Delimiter) -> ReedResult<()> {
+        if !self.exists() {
+            return Err(ReedError::TableNotFound {
+                name: self.name.clone(),
+            });
+        }
+        self.write_meta(&TableMeta { delimiter })
+    }
+
+    /// Writes `.meta`.
+    fn write_meta(&self, meta: &TableMeta) -> ReedResult<()> {
+        let content = toml::to_string(meta).map_err(|e| ReedError::SerializationError {
+            reason: format!("Failed to serialize table meta: {}", e),
+        })?;
+        fs::write(self.meta_path(), content).map_err(|e| ReedError::IoError {
+            operation: "write_table_meta".to_string(),
+            reason: e.to_string(),
+        })
+    }
+
+    /// Checks if table exists on disk.
+    ///
+    /// ## Output
+    /// - `bool`: True if current.csv exists
+    ///
+    /// ## Performance
+    /// - < 100μs (file system check)
+    pub fn exists(&self) -> bool {
+        self.current_path().exists()
+    }
+
+    /// Initialises new table.
+    ///
+    /// Creates directory and initial current.csv. The delimiter is detected
+    /// from the header (`detect_delimiter()`) and recorded in `.meta`.
+    ///
+    /// ## Input
+    /// - `initial_content`: CSV content (with header)
+    /// - `user`: Username for audit
+    ///
+    /// ## Output
+    /// - `Result<()>`: Success or error
+    ///
+    /// ## Performance
+    /// - < 20ms (create dir + write file + log)
+    ///
+    /// ## Error Conditions
+    /// - TableAlreadyExists: Table already initialised
+    /// - IoError: Cannot create files
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::tables::Table;
+    /// use std::path::Path;
+    ///
+    /// let table = Table::new(Path::new(".reed"), "text");
+    /// table.init(b"key|value\nfoo|bar\n", "admin")?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn init(&self, initial_content: &[u8], user: &str) -> ReedResult<()> {
+        if self.exists() {
+            return Err(ReedError::TableAlreadyExists {
+                name: self.name.clone(),
+            });
+        }
+
+        // Create table directory
+        let table_dir = self.table_dir();
+        fs::create_dir_all(&table_dir).map_err(|e| ReedError::IoError {
+            operation: "create_table_dir".to_string(),
+            reason: e.to_string(),
+        })?;
+
+        // Write initial current.csv
+        fs::write(&self.current_path(), initial_content).map_err(|e| ReedError::IoError {
+            operation: "write_initial_current".to_string(),
+            reason: e.to_string(),
+        })?;
+
+        // Create timestamp for initial version
+        let timestamp = Self::now_nanos();
+
+        // Write initial delta (full content for rollback support)
+        let delta_path = self.delta_path(timestamp);
+        fs::write(&delta_path, initial_content).map_err(|e| ReedError::IoError {
+            operation: "write_initial_delta".to_string(),
+            reason: e.to_string(),
+        })?;
+
+        // Create initial version.log entry
+        let user_code = get_or_create_user_code(user)?;
+        let action_code = get_action_code("init")?;
+
+        let log_line = format!(
+            "{}|{}|{}|{}\n",
+            timestamp,
+            action_code,
+            user_code,
+            initial_content.len()
+        );
+
+        fs::write(&self.log_path(), log_line).map_err(|e| ReedError::IoError {
+            operation: "write_initial_log".to_string(),
+            reason: e.to_string(),
+        })?;
+        // Start version.idx afresh (the log stays the source of truth)
+        let _ = VersionIndices::rebuild(&self.table_dir(), vec![(timestamp, 1)]);
+
+        self.write_meta(&TableMeta {
+            delimiter: detect_delimiter(initial_content),
+        })
+    }
+
+    /// Deletes table and all versions.
+    ///
+    /// ## Input
+    /// - `confirm`: Safety flag (must be true)
+    ///
+    /// ## Output
+    /// - `Result<()>`: Success or error
+    ///
+    /// ## Error Conditions
+    /// - NotConfirmed: confirm was false
+    /// - IoError: Cannot delete files
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::tables::Table;
+    /// use std::path::Path;
+    ///
+    /// let table = Table::new(Path::new(".reed"), "old_table");
+    /// table.delete(true)?; // DESTRUCTIVE!
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn delete(&self, confirm: bool) -> ReedResult<()> {
+        if !confirm {
+            return Err(ReedError::NotConfirmed {
+                operation: format!("delete table '{}'", self.name),
+            });
+        }
+
+        let table_dir = self.table_dir();
+        if table_dir.exists() {
+            fs::remove_dir_all(&table_dir).map_err(|e| ReedError::IoError {
+                operation: "delete_table".to_string(),
+                reason: e.to_string(),
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Gets current timestamp in nanoseconds.
+    pub(super) fn now_nanos() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("System time before Unix epoch")
+            .as_nanos() as u64
+    }
+}
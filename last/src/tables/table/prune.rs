@@ -0,0 +1,279 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Discarding old versions: `Table::prune_versions()` and
+//! `Table::prune_older_than()`.
+
+use crate::error::{ReedError, ReedResult};
+use crate::tables::types::{TransactionEntry, VersionInfo};
+use crate::version::VersionIndices;
+use std::fs::{self, OpenOptions};
+use std::time::Duration;
+
+use super::Table;
+
+impl Table {
+    /// Discards all but the `keep` most recent versions.
+    ///
+    /// The oldest kept version becomes the new base: its delta file is
+    /// replaced with its full content (as `init()` stores it) and its
+    /// version.log entry is updated to the new size. Older delta files and
+    /// their log entries are removed. The newest version is always kept,
+    /// so `keep = 0` behaves like `keep = 1`. The prune is recorded in the
+    /// audit log under `user`.
+    ///
+    /// ## Input
+    /// - `keep`: Number of most recent versions to keep
+    /// - `user`: Username for audit
+    ///
+    /// ## Output
+    /// - `Result<usize>`: Number of delta files deleted
+    ///
+    /// ## Performance
+    /// - One reconstruction up to the new base plus one replay of the kept
+    ///   deltas for verification (< 100ms per 50 deltas)
+    /// - Holds the table write lock throughout
+    ///
+    /// ## Error Conditions
+    /// - TableNotFound: Table doesn't exist
+    /// - DeltaCorrupted: Kept versions would not reproduce current.csv
+    ///   (nothing is deleted)
+    /// - LogCorrupted: version.log parse error
+    /// - IoError: Cannot rewrite files
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::tables::Table;
+    /// use std::path::Path;
+    ///
+    /// let table = Table::new(Path::new(".reed"), "text");
+    /// let deleted = table.prune_versions(20, "admin")?;
+    /// println!("Deleted {} old deltas", deleted);
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn prune_versions(&self, keep: usize, user: &str) -> ReedResult<usize> {
+        self.prune_with_lock(user, |versions| versions.len().saturating_sub(keep.max(1)))
+    }
+
+    /// Discards versions older than `cutoff`.
+    ///
+    /// Time-based alternative to `prune_versions()` with the same rebasing,
+    /// verification and locking. The newest version is always kept, even
+    /// if it is older than `cutoff`.
+    ///
+    /// ## Input
+    /// - `cutoff`: Maximum age of versions to keep
+    /// - `user`: Username for audit
+    ///
+    /// ## Output
+    /// - `Result<usize>`: Number of delta files deleted
+    ///
+    /// ## Performance
+    /// - Same as `prune_versions()`
+    ///
+    /// ## Error Conditions
+    /// - Same as `prune_versions()`
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::tables::Table;
+    /// use std::path::Path;
+    /// use std::time::Duration;
+    ///
+    /// let table = Table::new(Path::new(".reed"), "text");
+    /// let thirty_days = Duration::from_secs(30 * 24 * 60 * 60);
+    /// table.prune_older_than(thirty_days, "admin")?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn prune_older_than(&self, cutoff: Duration, user: &str) -> ReedResult<usize> {
+        let limit = Self::now_nanos().saturating_sub(cutoff.as_nanos() as u64);
+        self.prune_with_lock(user, |versions| {
+            let older = versions.iter().take_while(|v| v.timestamp < limit).count();
+            older.min(versions.len().saturating_sub(1))
+        })
+    }
+
+    /// Runs a prune under the table write lock.
+    ///
+    /// `first_kept` receives the versions oldest-first and returns the index
+    /// of the oldest version to keep.
+    fn prune_with_lock<F>(&self, user: &str, first_kept: F) -> ReedResult<usize>
+    where
+        F: FnOnce(&[VersionInfo]) -> usize,
+    {
+        if !self.exists() {
+            return Err(ReedError::TableNotFound {
+                name: self.name.clone(),
+            });
+        }
+
+        let lock_path = self.table_dir().join(".lock");
+
+        // Create lock file if it doesn't exist
+        let lock_file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&lock_path)
+            .map_err(|e| ReedError::IoError {
+                operation: "create_lock_file".to_string(),
+                reason: e.to_string(),
+            })?;
+
+        self.acquire_lock_with_retry(&lock_file)?;
+
+        let result = self.prune_internal(user, first_kept);
+
+        let _ = lock_file.unlock();
+
+        result
+    }
+
+    /// Prune implementation (called after lock is acquired).
+    fn prune_internal<F>(&self, user: &str, first_kept: F) -> ReedResult<usize>
+    where
+        F: FnOnce(&[VersionInfo]) -> usize,
+    {
+        let mut versions = self.list_versions()?;
+        versions.reverse();
+
+        let first_kept = first_kept(&versions);
+        if first_kept == 0 {
+            return Ok(0);
+        }
+        let base = &versions[first_kept];
+        let newest = versions[versions.len() - 1].timestamp;
+
+        // Verify the kept deltas rebuild current.csv before touching anything
+        let tag = format!("prune_{}", base.timestamp);
+        let base_path = self.reconstruct_version(base.timestamp, &tag)?;
+        let base_content = fs::read(&base_path);
+        let mut replay_path = base_path.clone();
+        let mut replayed = Ok(());
+        for (i, version) in versions.iter().enumerate().skip(first_kept + 1) {
+            let next_path = self.table_dir().join(format!("{}_verify_{}.tmp", tag, i));
+            replayed = crate::version::apply_delta(
+                &replay_path,
+                &self.delta_path(version.timestamp),
+                &next_path,
+            );
+            if replay_path != base_path {
+                let _ = fs::remove_file(&replay_path);
+            }
+            replay_path = next_path;
+            if replayed.is_err() {
+                break;
+            }
+        }
+        let replay_content = fs::read(&replay_path);
+        let _ = fs::remove_file(&base_path);
+        let _ = fs::remove_file(&replay_path);
+        replayed?;
+
+        let read_err = |e: std::io::Error| ReedError::IoError {
+            operation: "read_reconstructed".to_string(),
+            reason: e.to_string(),
+        };
+        let base_content = base_content.map_err(read_err)?;
+        if replay_content.map_err(read_err)? != self.read_current()? {
+            return Err(ReedError::DeltaCorrupted {
+                timestamp: newest,
+                reason: "Kept versions do not reproduce current.csv".to_string(),
+            });
+        }
+
+        // Store the base as full content, then drop pruned log entries
+        let new_base_path = self.table_dir().join(format!("{}_base.tmp", tag));
+        fs::write(&new_base_path, &base_content)
+            .and_then(|_| fs::rename(&new_base_path, self.delta_path(base.timestamp)))
+            .map_err(|e| {
+                let _ = fs::remove_file(&new_base_path);
+                ReedError::IoError {
+                    operation: "write_prune_base".to_string(),
+                    reason: e.to_string(),
+                }
+            })?;
+        self.rewrite_log_from(base.timestamp, base_content.len() as u64)?;
+
+        let mut deleted = 0;
+        for version in &versions[..first_kept] {
+            if fs::remove_file(self.delta_path(version.timestamp)).is_ok() {
+                deleted += 1;
+            }
+            let _ = fs::remove_file(self.snapshot_path(version.timestamp));
+        }
+
+        Table::write_transaction_log(
+            &self.base_path,
+            TransactionEntry {
+                timestamp: Self::now_nanos(),
+                user: user.to_string(),
+                operation: "prune".to_string(),
+                table: self.name.clone(),
+                affected_keys: Vec::new(),
+                ip_address: None,
+            },
+        )?;
+
+        Ok(deleted)
+    }
+
+    /// Rewrites version.log without entries older than `base_timestamp`.
+    ///
+    /// The entry at `base_timestamp` gets `base_size` as its delta size.
+    fn rewrite_log_from(&self, base_timestamp: u64, base_size: u64) -> ReedResult<()> {
+        let log_path = self.log_path();
+        let content = fs::read_to_string(&log_path).map_err(|e| ReedError::IoError {
+            operation: "read_log".to_string(),
+            reason: e.to_string(),
+        })?;
+
+        let mut kept = String::with_capacity(content.len());
+        let mut kept_timestamps = Vec::new();
+        for (line_num, line) in content.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let mut parts: Vec<&str> = line.split('|').collect();
+            let timestamp = parts
+                .first()
+                .and_then(|t| t.parse::<u64>().ok())
+                .filter(|_| parts.len() >= 4)
+                .ok_or_else(|| ReedError::LogCorrupted {
+                    reason: format!("Invalid format at line {}", line_num + 1),
+                })?;
+
+            if timestamp < base_timestamp {
+                continue;
+            }
+            kept_timestamps.push(timestamp);
+            let size = base_size.to_string();
+            if timestamp == base_timestamp {
+                parts[3] = &size;
+            }
+            kept.push_str(&parts.join("|"));
+            kept.push('\n');
+        }
+
+        let temp_path = self.table_dir().join("version.log.tmp");
+        fs::write(&temp_path, kept)
+            .and_then(|_| fs::rename(&temp_path, &log_path))
+            .map_err(|e| {
+                let _ = fs::remove_file(&temp_path);
+                ReedError::IoError {
+                    operation: "rewrite_log".to_string(),
+                    reason: e.to_string(),
+                }
+            })?;
+
+        // Entry numbers have shifted (rebuilt by list_versions() if this fails)
+        let entries = kept_timestamps
+            .into_iter()
+            .enumerate()
+            .map(|(i, timestamp)| (timestamp, i + 1))
+            .collect();
+        let _ = VersionIndices::rebuild(&self.table_dir(), entries);
+        Ok(())
+    }
+}
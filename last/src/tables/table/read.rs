@@ -0,0 +1,199 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Reading the current version: whole (`Table::read_current()`), as rows
+//! or as a row stream (`Table::stream_rows()`).
+
+use crate::error::{ReedError, ReedResult};
+use crate::tables::csv_parser::{parse_csv, parse_csv_row_with, trim_line};
+use crate::tables::types::{CsvRow, Delimiter};
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, Lines};
+use std::sync::Arc;
+
+use super::Table;
+
+impl Table {
+    /// Reads current version as bytes.
+    ///
+    /// ## Output
+    /// - `Result<Vec<u8>>`: CSV content
+    ///
+    /// ## Performance
+    /// - < 1ms for typical tables (< 100 KB)
+    ///
+    /// ## Error Conditions
+    /// - TableNotFound: Table doesn't exist
+    /// - IoError: Cannot read file
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::tables::Table;
+    /// use std::path::Path;
+    ///
+    /// let table = Table::new(Path::new(".reed"), "text");
+    /// let content = table.read_current()?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn read_current(&self) -> ReedResult<Vec<u8>> {
+        if !self.exists() {
+            return Err(ReedError::TableNotFound {
+                name: self.name.clone(),
+            });
+        }
+
+        fs::read(&self.current_path()).map_err(|e| ReedError::IoError {
+            operation: "read_current".to_string(),
+            reason: e.to_string(),
+        })
+    }
+
+    /// Reads current version as parsed rows.
+    ///
+    /// ## Output
+    /// - `Result<Vec<CsvRow>>`: Parsed CSV rows
+    ///
+    /// ## Performance
+    /// - < 5ms for typical tables (< 1000 rows)
+    ///
+    /// ## Error Conditions
+    /// - TableNotFound: Table doesn't exist
+    /// - InvalidCsv: Parse error
+    pub fn read_current_as_rows(&self) -> ReedResult<Vec<CsvRow>> {
+        let content = self.read_current()?;
+        parse_csv(&content, self.delimiter()?)
+    }
+
+    /// Reads current version as parsed rows, parsing on all CPU cores.
+    ///
+    /// Produces exactly the same rows as `read_current_as_rows()`.
+    ///
+    /// ## Output
+    /// - `Result<Vec<CsvRow>>`: Parsed CSV rows
+    ///
+    /// ## Performance
+    /// - Parsing scales with core count; pays off for tables of 100k+ rows
+    ///
+    /// ## Error Conditions
+    /// - TableNotFound: Table doesn't exist
+    /// - InvalidCsv: Parse error
+    #[cfg(feature = "rayon")]
+    pub fn read_current_as_rows_parallel(&self) -> ReedResult<Vec<CsvRow>> {
+        let content = self.read_current()?;
+        crate::tables::csv_parser::parse_csv_parallel(&content, self.delimiter()?)
+    }
+
+    /// Streams data rows from current.csv, one line per `next()`.
+    ///
+    /// Reads the header once; every row carries its column names. Blank
+    /// lines and `#` comments are skipped, as in `parse_csv()`.
+    ///
+    /// ## Output
+    /// - `Result<RowStream>`: Iterator of `ReedResult<CsvRow>`, header excluded
+    ///
+    /// ## Performance
+    /// - O(1) memory per row, file read through an 8KB buffer
+    ///
+    /// ## Error Conditions
+    /// - TableNotFound: Table doesn't exist
+    /// - IoError: Cannot open or read file (including invalid UTF-8)
+    /// - InvalidCsv: No header row; per row, a line without pipe delimiter
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::tables::Table;
+    /// use std::path::Path;
+    ///
+    /// let table = Table::new(Path::new(".reed"), "text");
+    /// for row in table.stream_rows()? {
+    ///     let row = row?;
+    ///     println!("{} = {:?}", row.key, row.get("value"));
+    /// }
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn stream_rows(&self) -> ReedResult<RowStream> {
+        if !self.exists() {
+            return Err(ReedError::TableNotFound {
+                name: self.name.clone(),
+            });
+        }
+
+        let file = File::open(self.current_path()).map_err(|e| ReedError::IoError {
+            operation: "stream_rows".to_string(),
+            reason: e.to_string(),
+        })?;
+
+        let mut stream = RowStream {
+            lines: BufReader::new(file).lines(),
+            line_num: 0,
+            delimiter: self.delimiter()?,
+            columns: Arc::default(),
+        };
+        let header = match stream.next() {
+            Some(row) => row?,
+            None => {
+                return Err(ReedError::InvalidCsv {
+                    reason: "Missing header row".to_string(),
+                    line: 1,
+                })
+            }
+        };
+        let mut columns = vec![header.key];
+        columns.extend(header.values);
+        stream.columns = Arc::new(columns);
+
+        Ok(stream)
+    }
+}
+
+/// Row iterator over current.csv, returned by `Table::stream_rows()`.
+pub struct RowStream {
+    lines: Lines<BufReader<File>>,
+    line_num: usize,
+    delimiter: Delimiter,
+    columns: Arc<Vec<String>>,
+}
+
+impl RowStream {
+    /// Column names from the header row.
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// Delimiter the table is read with.
+    pub fn delimiter(&self) -> Delimiter {
+        self.delimiter
+    }
+}
+
+impl Iterator for RowStream {
+    type Item = ReedResult<CsvRow>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for line in self.lines.by_ref() {
+            self.line_num += 1;
+            let line = match line {
+                Ok(line) => line,
+                Err(e) => {
+                    return Some(Err(ReedError::IoError {
+                        operation: "stream_rows".to_string(),
+                        reason: e.to_string(),
+                    }))
+                }
+            };
+
+            let trimmed = trim_line(&line, self.delimiter);
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let row = parse_csv_row_with(trimmed, self.line_num, self.delimiter);
+            return Some(row.map(|mut row| {
+                row.columns = Arc::clone(&self.columns);
+                row
+            }));
+        }
+
+        None
+    }
+}
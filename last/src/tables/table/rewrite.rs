@@ -0,0 +1,390 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Row-level rewrites: `Table::read_modify_write()`, `Table::rewrite_rows()`
+//! and `Table::retain_rows()`.
+
+use crate::error::{ReedError, ReedResult};
+use crate::registry::{get_action_code, get_or_create_user_code};
+use crate::tables::csv_parser::{parse_csv_row_with, trim_line};
+use crate::tables::types::{CsvRow, RowEdit, WriteResult};
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+use super::Table;
+
+impl Table {
+    /// Performs an atomic read-modify-write operation under a single lock.
+    ///
+    /// This prevents Read-Modify-Write race conditions during concurrent operations.
+    ///
+    /// ## Input
+    /// - `modify_fn`: Function that takes current content and returns new content
+    /// - `user`: Username for audit trail
+    ///
+    /// ## Output
+    /// - `Ok(WriteResult)`: Write succeeded
+    /// - `Err(ReedError)`: Write failed
+    ///
+    /// ## Example
+    /// ```no_run
+    /// table.read_modify_write(|content| {
+    ///     let mut new_content = content.to_vec();
+    ///     new_content.extend_from_slice(b"new_row\n");
+    ///     new_content
+    /// }, "user123")?;
+    /// ```
+    pub fn read_modify_write<F>(&self, modify_fn: F, user: &str) -> ReedResult<WriteResult>
+    where
+        F: FnOnce(&[u8]) -> Vec<u8>,
+    {
+        self.read_modify_write_with_action(modify_fn, user, get_action_code("update")?)
+    }
+
+    /// Same as `read_modify_write()`, recording the given action code.
+    ///
+    /// ## Input
+    /// - `modify_fn`: Function that takes current content and returns new content
+    /// - `user`: Username for audit trail
+    /// - `action_code`: Action code for version.log (see actions.dict)
+    ///
+    /// ## Output
+    /// - `Ok(WriteResult)`: Write succeeded (timestamp 0 when unchanged)
+    /// - `Err(ReedError)`: Write failed
+    pub fn read_modify_write_with_action<F>(
+        &self,
+        modify_fn: F,
+        user: &str,
+        action_code: u8,
+    ) -> ReedResult<WriteResult>
+    where
+        F: FnOnce(&[u8]) -> Vec<u8>,
+    {
+        if !self.exists() {
+            return Err(ReedError::TableNotFound {
+                name: self.name.clone(),
+            });
+        }
+
+        let lock_path = self.table_dir().join(".lock");
+
+        // Create lock file if it doesn't exist
+        let lock_file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .open(&lock_path)
+            .map_err(|e| ReedError::IoError {
+                operation: "create_lock_file".to_string(),
+                reason: e.to_string(),
+            })?;
+
+        // Try to acquire exclusive lock with retry mechanism
+        self.acquire_lock_with_retry(&lock_file)?;
+
+        // Read current content
+        let current_content = self.read_current().map_err(|e| {
+            let _ = lock_file.unlock();
+            e
+        })?;
+
+        // Apply modification function
+        let new_content = modify_fn(&current_content);
+
+        // Perform write operation
+        let result =
+            self.write_internal_with_progress(&new_content, user, action_code, &mut |_| {});
+
+        // Release lock (automatic on drop, but explicit unlock is clearer)
+        let _ = lock_file.unlock();
+
+        result
+    }
+
+    /// Removes the rows for which `keep` returns false, copying the rest as-is.
+    ///
+    /// Shorthand for `rewrite_rows()` with `RowEdit::Keep`/`RowEdit::Remove`.
+    ///
+    /// ## Output
+    /// - `Result<(usize, WriteResult)>`: Rows removed and write metadata
+    ///   (timestamp 0 when no row was removed)
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::tables::Table;
+    /// use std::path::Path;
+    ///
+    /// let table = Table::new(Path::new(".reed"), "text");
+    /// let (removed, _) = table.retain_rows(|row| Ok(row.get("value") != Some("")), "admin")?;
+    /// println!("Removed {} blank rows", removed);
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn retain_rows<F>(&self, mut keep: F, user: &str) -> ReedResult<(usize, WriteResult)>
+    where
+        F: FnMut(&CsvRow) -> ReedResult<bool>,
+    {
+        let mut removed = 0;
+        let result = self.rewrite_rows(
+            |_, row| {
+                if keep(row)? {
+                    Ok(RowEdit::Keep)
+                } else {
+                    removed += 1;
+                    Ok(RowEdit::Remove)
+                }
+            },
+            |_| Ok(()),
+            user,
+        )?;
+        Ok((removed, result))
+    }
+
+    /// Rewrites the table row by row, copying untouched lines as-is.
+    ///
+    /// Under the table lock, current.csv is read one line at a time. The
+    /// header, comments, blank lines and every row left as `RowEdit::Keep`
+    /// are copied byte-for-byte to `current.new.csv`, which is then renamed
+    /// over `current.csv`. Rows that are kept or replaced by identical
+    /// fields keep their padding and quoting. The change is versioned like
+    /// `write()`; computing the delta still reads both versions once.
+    ///
+    /// ## Input
+    /// - `edit`: Called with the row's position and the parsed data row
+    ///   (header attached, as from `stream_rows()`). The position counts
+    ///   every line after the header, comments and blank lines included;
+    ///   it is the row ID the column indices use.
+    /// - `check`: Called with the fields of every replaced row once all
+    ///   rows are edited; an error aborts the rewrite
+    /// - `user`: Username for audit
+    ///
+    /// ## Output
+    /// - `Result<WriteResult>`: Write metadata (timestamp 0 when nothing
+    ///   changed)
+    ///
+    /// ## Performance
+    /// - O(1) memory per kept row while rewriting, one rename to publish
+    ///
+    /// ## Error Conditions
+    /// - TableNotFound: Table doesn't exist
+    /// - InvalidCsv: No header row, or a row cannot be parsed
+    /// - IoError: Cannot read, write or rename files (current.csv is left
+    ///   unchanged)
+    /// - Any error returned by `edit` or `check`
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::tables::{RowEdit, Table};
+    /// use std::path::Path;
+    ///
+    /// let table = Table::new(Path::new(".reed"), "text");
+    /// table.rewrite_rows(
+    ///     |_, row| match row.get("value") {
+    ///         Some("") => Ok(RowEdit::Replace(vec![row.key.clone(), "-".to_string()])),
+    ///         _ => Ok(RowEdit::Keep),
+    ///     },
+    ///     |_| Ok(()),
+    ///     "admin",
+    /// )?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn rewrite_rows<F, C>(&self, edit: F, check: C, user: &str) -> ReedResult<WriteResult>
+    where
+        F: FnMut(usize, &CsvRow) -> ReedResult<RowEdit>,
+        C: FnOnce(&[Vec<String>]) -> ReedResult<()>,
+    {
+        if !self.exists() {
+            return Err(ReedError::TableNotFound {
+                name: self.name.clone(),
+            });
+        }
+
+        let lock_path = self.table_dir().join(".lock");
+
+        // Create lock file if it doesn't exist
+        let lock_file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&lock_path)
+            .map_err(|e| ReedError::IoError {
+                operation: "create_lock_file".to_string(),
+                reason: e.to_string(),
+            })?;
+
+        self.acquire_lock_with_retry(&lock_file)?;
+
+        let new_path = self.table_dir().join("current.new.csv");
+        let result = self.rewrite_rows_internal(edit, check, &new_path, user);
+        if result.is_err() {
+            let _ = fs::remove_file(&new_path);
+        }
+
+        let _ = lock_file.unlock();
+
+        result
+    }
+
+    /// Row rewrite implementation (called after lock is acquired).
+    fn rewrite_rows_internal<F, C>(
+        &self,
+        mut edit: F,
+        check: C,
+        new_path: &Path,
+        user: &str,
+    ) -> ReedResult<WriteResult>
+    where
+        F: FnMut(usize, &CsvRow) -> ReedResult<RowEdit>,
+        C: FnOnce(&[Vec<String>]) -> ReedResult<()>,
+    {
+        let io_error = |operation: &str, e: std::io::Error| ReedError::IoError {
+            operation: operation.to_string(),
+            reason: e.to_string(),
+        };
+        let current_path = self.current_path();
+        let delimiter = self.delimiter()?;
+        let separator = delimiter.as_char().to_string();
+
+        let mut reader =
+            BufReader::new(File::open(&current_path).map_err(|e| io_error("rewrite_rows", e))?);
+        let mut writer = std::io::BufWriter::new(
+            File::create(new_path).map_err(|e| io_error("create_rewrite_file", e))?,
+        );
+
+        let mut columns: Option<Arc<Vec<String>>> = None;
+        let mut replaced = Vec::new();
+        let mut changed = false;
+        let mut raw = Vec::new();
+        let mut line_num = 0;
+        let mut header_line = 0;
+        loop {
+            raw.clear();
+            if reader
+                .read_until(b'\n', &mut raw)
+                .map_err(|e| io_error("rewrite_rows", e))?
+                == 0
+            {
+                break;
+            }
+            line_num += 1;
+
+            let line = std::str::from_utf8(&raw).map_err(|e| ReedError::IoError {
+                operation: "rewrite_rows".to_string(),
+                reason: format!("Invalid UTF-8 on line {}: {}", line_num, e),
+            })?;
+            let content = line.trim_end_matches(['\n', '\r']);
+            let trimmed = trim_line(content, delimiter);
+
+            let row_edit = if trimmed.is_empty() || trimmed.starts_with('#') {
+                RowEdit::Keep
+            } else {
+                let mut row = parse_csv_row_with(trimmed, line_num, delimiter)?;
+                match &columns {
+                    None => {
+                        let mut header = vec![row.key];
+                        header.extend(row.values);
+                        columns = Some(Arc::new(header));
+                        header_line = line_num;
+                        RowEdit::Keep
+                    }
+                    Some(columns) => {
+                        row.columns = Arc::clone(columns);
+                        match edit(line_num - header_line - 1, &row)? {
+                            // Same fields again: keep the line as written
+                            RowEdit::Replace(fields)
+                                if fields.first() == Some(&row.key)
+                                    && fields[1..] == row.values[..] =>
+                            {
+                                replaced.push(fields);
+                                RowEdit::Keep
+                            }
+                            row_edit => row_edit,
+                        }
+                    }
+                }
+            };
+
+            let written = match row_edit {
+                RowEdit::Keep => writer.write_all(&raw),
+                RowEdit::Remove => {
+                    changed = true;
+                    Ok(())
+                }
+                RowEdit::Replace(fields) => {
+                    changed = true;
+                    let ending = &line[content.len()..];
+                    let result = writer
+                        .write_all(fields.join(&separator).as_bytes())
+                        .and_then(|_| writer.write_all(ending.as_bytes()));
+                    replaced.push(fields);
+                    result
+                }
+            };
+            written.map_err(|e| io_error("write_rewrite_file", e))?;
+        }
+
+        if columns.is_none() {
+            return Err(ReedError::InvalidCsv {
+                reason: "Missing header row".to_string(),
+                line: 1,
+            });
+        }
+        check(&replaced)?;
+
+        // Sync before the rename so it publishes a complete file
+        writer
+            .into_inner()
+            .map_err(|e| io_error("write_rewrite_file", e.into_error()))?
+            .sync_all()
+            .map_err(|e| io_error("write_rewrite_file", e))?;
+
+        if !changed {
+            let _ = fs::remove_file(new_path);
+            let current_size = fs::metadata(&current_path)
+                .map_err(|e| io_error("read_current", e))?
+                .len();
+            return Ok(WriteResult {
+                timestamp: 0,
+                delta_size: 0,
+                current_size,
+            });
+        }
+
+        let timestamp = Self::now_nanos();
+        let old_content = fs::read(&current_path).map_err(|e| io_error("read_current", e))?;
+        let content = fs::read(new_path).map_err(|e| io_error("read_rewrite_file", e))?;
+
+        let delta = crate::version::delta::create_bsdiff_with_progress(
+            &old_content,
+            &content,
+            &mut |_| {},
+        )?;
+        let compressed = crate::version::delta::compress_delta(&delta)?;
+        let delta_size = compressed.len() as u64;
+
+        fs::write(self.delta_path(timestamp), &compressed)
+            .map_err(|e| io_error("write_delta", e))?;
+        fs::rename(new_path, &current_path).map_err(|e| io_error("rename_rewrite_file", e))?;
+
+        let action_code = get_action_code("update")?;
+        let user_code = get_or_create_user_code(user)?;
+        let log_line = format!(
+            "{}|{}|{}|{}\n",
+            timestamp, action_code, user_code, delta_size
+        );
+
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.log_path())
+            .and_then(|mut log_file| log_file.write_all(log_line.as_bytes()))
+            .map_err(|e| io_error("append_log", e))?;
+        self.record_version(timestamp, &content);
+
+        Ok(WriteResult {
+            timestamp,
+            delta_size,
+            current_size: content.len() as u64,
+        })
+    }
+}
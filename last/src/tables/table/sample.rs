@@ -0,0 +1,109 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Reservoir sampling of rows (`Table::sample()`).
+
+use crate::error::{ReedError, ReedResult};
+use crate::tables::csv_parser::{parse_csv_row_with, trim_line};
+use crate::tables::types::CsvRow;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::sync::Arc;
+
+use super::Table;
+
+impl Table {
+    /// Draws a uniform random sample of data rows.
+    ///
+    /// Streams current.csv line by line and keeps a reservoir of `n` rows
+    /// (Algorithm R), so memory is bounded by the sample size rather than
+    /// the table size. The header row, blank lines and comments are skipped;
+    /// sampled rows carry the header, as from `stream_rows()`. The same
+    /// `seed` always yields the same sample for unchanged content.
+    ///
+    /// ## Input
+    /// - `n`: Sample size
+    /// - `seed`: Random seed
+    ///
+    /// ## Output
+    /// - `Result<Vec<CsvRow>>`: Up to `n` rows; all rows in file order if the
+    ///   table has fewer than `n`
+    ///
+    /// ## Performance
+    /// - O(r) single pass where r = number of rows, O(min(n, r)) memory
+    /// - < 10ms for typical tables (< 10k rows)
+    ///
+    /// ## Error Conditions
+    /// - TableNotFound: Table doesn't exist
+    /// - IoError: Cannot read file (including invalid UTF-8)
+    /// - InvalidCsv: Row without pipe delimiter
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::tables::Table;
+    /// use std::path::Path;
+    ///
+    /// let table = Table::new(Path::new(".reed"), "text");
+    /// let rows = table.sample(100, 42)?;
+    /// println!("{} sampled rows", rows.len());
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn sample(&self, n: usize, seed: u64) -> ReedResult<Vec<CsvRow>> {
+        if !self.exists() {
+            return Err(ReedError::TableNotFound {
+                name: self.name.clone(),
+            });
+        }
+
+        let file = File::open(self.current_path()).map_err(|e| ReedError::IoError {
+            operation: "sample".to_string(),
+            reason: e.to_string(),
+        })?;
+
+        let delimiter = self.delimiter()?;
+        let mut rng = SmallRng::seed_from_u64(seed);
+        // Grows with the rows found: `n` may far exceed the table
+        let mut reservoir = Vec::new();
+        let mut header: Option<Arc<Vec<String>>> = None;
+        let mut seen = 0;
+
+        for (line_num, line) in BufReader::new(file).lines().enumerate() {
+            let line = line.map_err(|e| ReedError::IoError {
+                operation: "sample".to_string(),
+                reason: e.to_string(),
+            })?;
+            let trimmed = trim_line(&line, delimiter);
+
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            let Some(columns) = &header else {
+                let row = parse_csv_row_with(trimmed, line_num + 1, delimiter)?;
+                header = Some(Arc::new(
+                    std::iter::once(row.key).chain(row.values).collect(),
+                ));
+                continue;
+            };
+            let parse = || {
+                let mut row = parse_csv_row_with(trimmed, line_num + 1, delimiter)?;
+                row.columns = Arc::clone(columns);
+                Ok::<_, ReedError>(row)
+            };
+
+            seen += 1;
+            if reservoir.len() < n {
+                reservoir.push(parse()?);
+            } else {
+                // Replace with probability n / seen
+                let slot = rng.gen_range(0..seen);
+                if slot < n {
+                    reservoir[slot] = parse()?;
+                }
+            }
+        }
+
+        Ok(reservoir)
+    }
+}
@@ -0,0 +1,177 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Moving rows into a new table (`Table::split()`).
+
+use crate::error::{ReedError, ReedResult};
+use crate::registry::get_action_code;
+use crate::schema::{load_schema, save_schema, schema_exists};
+use crate::tables::csv_parser::{parse_csv_row_with, trim_line};
+use crate::tables::types::CsvRow;
+use std::fs::{self};
+
+use super::Table;
+
+impl Table {
+    /// Splits table by moving matching rows into a new table.
+    ///
+    /// Rows for which `predicate` returns true are moved to `new_table_name`
+    /// (created with the same header and schema); all other rows stay here
+    /// and are written back as a new version. Row lines are copied verbatim;
+    /// comment lines stay in the original table. Both tables stay locked for
+    /// the whole split, and the target is created before the source is
+    /// written, so a failure leaves the source untouched.
+    ///
+    /// ## Input
+    /// - `predicate`: Selects rows to move (header row is never passed)
+    /// - `new_table_name`: Name of table to create
+    /// - `user`: Username for audit
+    ///
+    /// ## Output
+    /// - `Result<(usize, usize)>`: (kept_count, moved_count)
+    ///
+    /// ## Performance
+    /// - O(n) where n = number of rows
+    /// - < 20ms for typical tables (one write + one init)
+    ///
+    /// ## Error Conditions
+    /// - TableNotFound: Source table doesn't exist
+    /// - TableAlreadyExists: Target table already exists
+    /// - InvalidCsv: Source has no header row
+    /// - IoError: Cannot write files (the target is removed again)
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::tables::Table;
+    /// use std::path::Path;
+    ///
+    /// let table = Table::new(Path::new(".reed"), "text");
+    /// let (kept, moved) = table.split(|row| row.key.ends_with("@de"), "text_de", "admin")?;
+    /// println!("{} rows kept, {} rows moved", kept, moved);
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn split<F>(
+        &self,
+        predicate: F,
+        new_table_name: &str,
+        user: &str,
+    ) -> ReedResult<(usize, usize)>
+    where
+        F: Fn(&CsvRow) -> bool,
+    {
+        if !self.exists() {
+            return Err(ReedError::TableNotFound {
+                name: self.name.clone(),
+            });
+        }
+
+        let _source_lock = self.lock_exclusive()?;
+
+        let target = Table::new(&self.base_path, new_table_name);
+        let target_dir_existed = target.table_dir().exists();
+        fs::create_dir_all(target.table_dir()).map_err(|e| ReedError::IoError {
+            operation: "create_table_dir".to_string(),
+            reason: e.to_string(),
+        })?;
+        let _target_lock = target.lock_exclusive()?;
+        let discard_target = || {
+            if !target_dir_existed {
+                let _ = fs::remove_dir_all(target.table_dir());
+            }
+        };
+        if target.exists() {
+            return Err(ReedError::TableAlreadyExists {
+                name: new_table_name.to_string(),
+            });
+        }
+
+        let split = self.split_rows(&predicate);
+        let (kept, moved, kept_count, moved_count) = match split {
+            Ok(split) => split,
+            Err(e) => {
+                discard_target();
+                return Err(e);
+            }
+        };
+
+        let created = target.init(moved.as_bytes(), user).and_then(|_| {
+            target.set_delimiter(self.delimiter()?)?;
+            if schema_exists(&self.base_path, &self.name) {
+                let schema = load_schema(&self.base_path, &self.name)?;
+                save_schema(&self.base_path, new_table_name, &schema)?;
+            }
+            let action_code = get_action_code("update")?;
+            self.write_internal_with_progress(kept.as_bytes(), user, action_code, &mut |_| {})
+        });
+
+        if let Err(e) = created {
+            // Only the target was touched so far: the split is all-or-nothing
+            discard_target();
+            return Err(e);
+        }
+
+        Ok((kept_count, moved_count))
+    }
+
+    /// Splits current.csv into the content kept and moved by `split()`.
+    ///
+    /// Returns (kept content, moved content, kept rows, moved rows); the
+    /// header line starts both contents.
+    fn split_rows<F>(&self, predicate: &F) -> ReedResult<(String, String, usize, usize)>
+    where
+        F: Fn(&CsvRow) -> bool,
+    {
+        let delimiter = self.delimiter()?;
+        let original = self.read_current()?;
+        let text = std::str::from_utf8(&original).map_err(|e| ReedError::InvalidCsv {
+            reason: format!("Invalid UTF-8: {}", e),
+            line: 0,
+        })?;
+
+        let mut kept = String::with_capacity(text.len());
+        let mut moved = String::new();
+        let mut header_seen = false;
+        let mut kept_count = 0;
+        let mut moved_count = 0;
+
+        for (line_num, line) in text.lines().enumerate() {
+            let trimmed = trim_line(line, delimiter);
+
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                kept.push_str(line);
+                kept.push('\n');
+                continue;
+            }
+
+            // Header goes to both tables
+            if !header_seen {
+                header_seen = true;
+                kept.push_str(line);
+                kept.push('\n');
+                moved.push_str(line);
+                moved.push('\n');
+                continue;
+            }
+
+            let row = parse_csv_row_with(trimmed, line_num + 1, delimiter)?;
+            if predicate(&row) {
+                moved.push_str(line);
+                moved.push('\n');
+                moved_count += 1;
+            } else {
+                kept.push_str(line);
+                kept.push('\n');
+                kept_count += 1;
+            }
+        }
+
+        if !header_seen {
+            return Err(ReedError::InvalidCsv {
+                reason: "Missing header row".to_string(),
+                line: 1,
+            });
+        }
+
+        Ok((kept, moved, kept_count, moved_count))
+    }
+}
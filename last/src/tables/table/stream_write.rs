@@ -0,0 +1,173 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Streaming writes (`Table::stream_write()`).
+
+use crate::error::{ReedError, ReedResult};
+use crate::registry::get_action_code;
+use crate::tables::csv_parser::{parse_csv_row_with, trim_line};
+use crate::tables::types::{Delimiter, WriteProgress, WriteResult};
+use std::io::Read;
+
+use super::Table;
+
+/// Read size for `Table::stream_write()`.
+const STREAM_CHUNK_SIZE: usize = 64 * 1024;
+
+impl Table {
+    /// Writes new version from a byte stream (e.g. stdin).
+    ///
+    /// Reads the stream in 64KB chunks and validates every completed line as
+    /// it arrives: the first line is taken as the header and each following
+    /// row must have the same number of fields. A malformed stream therefore
+    /// fails as soon as the bad line is read, and the table is only written
+    /// once the whole stream has been consumed.
+    ///
+    /// ## Input
+    /// - `reader`: Source of CSV content (header line first)
+    /// - `user`: Username for audit
+    ///
+    /// ## Output
+    /// - `Result<WriteResult>`: Write metadata
+    ///
+    /// ## Performance
+    /// - O(n) in stream size; content is buffered once in memory
+    /// - Write cost same as `write()`
+    ///
+    /// ## Error Conditions
+    /// - TableNotFound: Table doesn't exist (use init() first)
+    /// - InvalidCsv: Empty stream, invalid UTF-8 or field count mismatch
+    /// - IoError: Cannot read stream or write files
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::tables::Table;
+    /// use std::path::Path;
+    ///
+    /// let table = Table::new(Path::new(".reed"), "text");
+    /// let result = table.stream_write(&mut std::io::stdin().lock(), "admin")?;
+    /// println!("Wrote {} bytes", result.current_size);
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn stream_write(&self, reader: &mut impl Read, user: &str) -> ReedResult<WriteResult> {
+        self.stream_write_with_progress(reader, user, |_| {})
+    }
+
+    /// Writes new version from a byte stream, reporting write progress.
+    ///
+    /// Same as `stream_write()`; once the stream is consumed and validated,
+    /// the write reports progress like `write_with_progress()`.
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::tables::Table;
+    /// use std::path::Path;
+    ///
+    /// let table = Table::new(Path::new(".reed"), "text");
+    /// table.stream_write_with_progress(&mut std::io::stdin().lock(), "admin", |p| {
+    ///     eprintln!("{:?}: {}/{}", p.phase, p.bytes_processed, p.bytes_total);
+    /// })?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn stream_write_with_progress<F: Fn(WriteProgress)>(
+        &self,
+        reader: &mut impl Read,
+        user: &str,
+        on_progress: F,
+    ) -> ReedResult<WriteResult> {
+        if !self.exists() {
+            return Err(ReedError::TableNotFound {
+                name: self.name.clone(),
+            });
+        }
+
+        let mut content = Vec::new();
+        let mut chunk = vec![0u8; STREAM_CHUNK_SIZE];
+        let mut validated = 0; // Bytes of content already checked
+        let mut line_num = 0;
+        let mut header_fields = None;
+        let delimiter = self.delimiter()?;
+
+        loop {
+            let read = match reader.read(&mut chunk) {
+                Ok(0) => break,
+                Ok(n) => n,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    return Err(ReedError::IoError {
+                        operation: "read_stream".to_string(),
+                        reason: e.to_string(),
+                    })
+                }
+            };
+            content.extend_from_slice(&chunk[..read]);
+
+            // Validate every line completed by this chunk
+            while let Some(end) = content[validated..].iter().position(|&b| b == b'\n') {
+                line_num += 1;
+                validate_stream_line(
+                    &content[validated..validated + end],
+                    line_num,
+                    delimiter,
+                    &mut header_fields,
+                )?;
+                validated += end + 1;
+            }
+        }
+
+        // Trailing line without newline
+        if validated < content.len() {
+            line_num += 1;
+            validate_stream_line(
+                &content[validated..],
+                line_num,
+                delimiter,
+                &mut header_fields,
+            )?;
+        }
+
+        if header_fields.is_none() {
+            return Err(ReedError::InvalidCsv {
+                reason: "Stream contains no header line".to_string(),
+                line: 0,
+            });
+        }
+
+        let action_code = get_action_code("update")?;
+        self.write_with_lock(&content, user, action_code, &mut |p| on_progress(p))
+    }
+}
+
+/// Validates one streamed line, recording the header's field count.
+///
+/// Empty lines and `#` comments are skipped, matching `parse_csv()`.
+fn validate_stream_line(
+    line: &[u8],
+    line_num: usize,
+    delimiter: Delimiter,
+    header_fields: &mut Option<usize>,
+) -> ReedResult<()> {
+    let text = std::str::from_utf8(line).map_err(|e| ReedError::InvalidCsv {
+        reason: format!("Invalid UTF-8: {}", e),
+        line: line_num,
+    })?;
+    let trimmed = trim_line(text, delimiter);
+    if trimmed.is_empty() || trimmed.starts_with('#') {
+        return Ok(());
+    }
+
+    let row = parse_csv_row_with(trimmed, line_num, delimiter)?;
+    let fields = row.values.len() + 1;
+    match *header_fields {
+        None => *header_fields = Some(fields),
+        Some(expected) if expected != fields => {
+            return Err(ReedError::InvalidCsv {
+                reason: format!("Expected {} fields, found {}", expected, fields),
+                line: line_num,
+            });
+        }
+        Some(_) => {}
+    }
+
+    Ok(())
+}
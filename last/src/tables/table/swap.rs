@@ -0,0 +1,153 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Atomic content swap (`Table::swap()`).
+
+use crate::error::{ReedError, ReedResult};
+use crate::registry::get_or_create_user_code;
+use crate::tables::types::WriteResult;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+
+use super::Table;
+
+/// Action code recorded in version.log for `Table::swap()` (see actions.dict).
+pub const SWAP_ACTION_CODE: u8 = 11;
+
+impl Table {
+    /// Replaces the whole table content atomically.
+    ///
+    /// Meant for bulk replacements such as a full re-import. The content is
+    /// written to `current.new.csv` and renamed over `current.csv`, so
+    /// readers see either the old or the new version, never a partial file.
+    /// A bsdiff delta is still attempted; if it comes out larger than the
+    /// content itself, a full-content delta is stored instead. Either way
+    /// `rollback()` can restore this and earlier versions.
+    ///
+    /// ## Input
+    /// - `new_content`: Complete new CSV content (with header)
+    /// - `user`: Username for audit
+    ///
+    /// ## Output
+    /// - `Result<WriteResult>`: Write metadata, logged as action `swap`
+    ///
+    /// ## Performance
+    /// - One bsdiff + xz pass, plus one more xz pass on fallback
+    /// - Single rename for the visible switch
+    ///
+    /// ## Error Conditions
+    /// - TableNotFound: Table doesn't exist (use init() first)
+    /// - IoError: Cannot write or rename files (current.csv is left unchanged)
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::tables::Table;
+    /// use std::path::Path;
+    ///
+    /// let table = Table::new(Path::new(".reed"), "products");
+    /// let export = std::fs::read("products_export.csv")?;
+    /// let result = table.swap(&export, "etl")?;
+    /// println!("Stored {} bytes for version {}", result.delta_size, result.timestamp);
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn swap(&self, new_content: &[u8], user: &str) -> ReedResult<WriteResult> {
+        if !self.exists() {
+            return Err(ReedError::TableNotFound {
+                name: self.name.clone(),
+            });
+        }
+
+        let lock_path = self.table_dir().join(".lock");
+
+        // Create lock file if it doesn't exist
+        let lock_file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&lock_path)
+            .map_err(|e| ReedError::IoError {
+                operation: "create_lock_file".to_string(),
+                reason: e.to_string(),
+            })?;
+
+        self.acquire_lock_with_retry(&lock_file)?;
+
+        let result = self.swap_internal(new_content, user);
+
+        let _ = lock_file.unlock();
+
+        result
+    }
+
+    /// Swap implementation (called after lock is acquired).
+    fn swap_internal(&self, content: &[u8], user: &str) -> ReedResult<WriteResult> {
+        let timestamp = Self::now_nanos();
+        let current_path = self.current_path();
+        let new_path = self.table_dir().join("current.new.csv");
+
+        let old_content = fs::read(&current_path).map_err(|e| ReedError::IoError {
+            operation: "read_current".to_string(),
+            reason: e.to_string(),
+        })?;
+
+        let delta =
+            crate::version::delta::create_bsdiff_with_progress(&old_content, content, &mut |_| {})?;
+        let mut compressed = crate::version::delta::compress_delta(&delta)?;
+        if compressed.len() > content.len() {
+            let full = crate::version::delta::create_full_delta(content)?;
+            compressed = crate::version::delta::compress_delta(&full)?;
+        }
+        let delta_size = compressed.len() as u64;
+
+        fs::write(self.delta_path(timestamp), &compressed).map_err(|e| ReedError::IoError {
+            operation: "write_delta".to_string(),
+            reason: e.to_string(),
+        })?;
+
+        // Write and sync the sibling first so the rename publishes a complete file
+        let mut new_file = File::create(&new_path).map_err(|e| ReedError::IoError {
+            operation: "create_swap_file".to_string(),
+            reason: e.to_string(),
+        })?;
+        new_file
+            .write_all(content)
+            .and_then(|_| new_file.sync_all())
+            .map_err(|e| {
+                let _ = fs::remove_file(&new_path);
+                ReedError::IoError {
+                    operation: "write_swap_file".to_string(),
+                    reason: e.to_string(),
+                }
+            })?;
+        fs::rename(&new_path, &current_path).map_err(|e| {
+            let _ = fs::remove_file(&new_path);
+            ReedError::IoError {
+                operation: "rename_swap_file".to_string(),
+                reason: e.to_string(),
+            }
+        })?;
+
+        let user_code = get_or_create_user_code(user)?;
+        let log_line = format!(
+            "{}|{}|{}|{}\n",
+            timestamp, SWAP_ACTION_CODE, user_code, delta_size
+        );
+
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.log_path())
+            .and_then(|mut log_file| log_file.write_all(log_line.as_bytes()))
+            .map_err(|e| ReedError::IoError {
+                operation: "append_log".to_string(),
+                reason: e.to_string(),
+            })?;
+        self.record_version(timestamp, content);
+
+        Ok(WriteResult {
+            timestamp,
+            delta_size,
+            current_size: content.len() as u64,
+        })
+    }
+}
@@ -0,0 +1,282 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Version history: listing, recording, reconstruction and rollback.
+
+use crate::error::{ReedError, ReedResult};
+use crate::tables::types::VersionInfo;
+use crate::version::VersionIndices;
+use std::fs::{self, File};
+use std::io::{BufReader, Read};
+use std::path::PathBuf;
+
+use super::Table;
+
+impl Table {
+    /// Lists all versions.
+    ///
+    /// Parses version.log and returns metadata for each version.
+    ///
+    /// ## Output
+    /// - `Result<Vec<VersionInfo>>`: Version metadata (newest first)
+    ///
+    /// ## Performance
+    /// - < 5ms for typical logs (< 100 versions)
+    ///
+    /// ## Error Conditions
+    /// - TableNotFound: Table doesn't exist
+    /// - LogCorrupted: version.log parse error
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::tables::Table;
+    /// use std::path::Path;
+    ///
+    /// let table = Table::new(Path::new(".reed"), "text");
+    /// let versions = table.list_versions()?;
+    /// for v in versions {
+    ///     println!("Version {}: {} by {}", v.timestamp, v.action, v.user);
+    /// }
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn list_versions(&self) -> ReedResult<Vec<VersionInfo>> {
+        if !self.exists() {
+            return Err(ReedError::TableNotFound {
+                name: self.name.clone(),
+            });
+        }
+
+        let log_path = self.log_path();
+        if !log_path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let file = File::open(&log_path).map_err(|e| ReedError::IoError {
+            operation: "open_log".to_string(),
+            reason: e.to_string(),
+        })?;
+
+        let mut content = String::new();
+        BufReader::new(file)
+            .read_to_string(&mut content)
+            .map_err(|e| ReedError::LogCorrupted {
+                reason: e.to_string(),
+            })?;
+
+        let mut entries = Vec::new();
+        for (line_num, line) in content.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let parts: Vec<&str> = line.split('|').collect();
+            if parts.len() < 4 {
+                return Err(ReedError::LogCorrupted {
+                    reason: format!("Invalid format at line {}", line_num + 1),
+                });
+            }
+
+            let timestamp = parts[0]
+                .parse::<u64>()
+                .map_err(|_| ReedError::LogCorrupted {
+                    reason: format!("Invalid timestamp at line {}", line_num + 1),
+                })?;
+
+            let action_code = parts[1]
+                .parse::<u8>()
+                .map_err(|_| ReedError::LogCorrupted {
+                    reason: format!("Invalid action code at line {}", line_num + 1),
+                })?;
+
+            let user_code = parts[2]
+                .parse::<u32>()
+                .map_err(|_| ReedError::LogCorrupted {
+                    reason: format!("Invalid user code at line {}", line_num + 1),
+                })?;
+
+            let delta_size = parts[3]
+                .parse::<u64>()
+                .map_err(|_| ReedError::LogCorrupted {
+                    reason: format!("Invalid delta size at line {}", line_num + 1),
+                })?;
+
+            entries.push((timestamp, action_code, user_code, delta_size));
+        }
+
+        // Newest first; entries appended out of order are placed by timestamp
+        entries.sort_by_key(|entry| entry.0);
+        let versions = entries
+            .into_iter()
+            .rev()
+            .map(|(timestamp, action_code, user_code, delta_size)| {
+                // Resolve codes to names
+                let action = crate::registry::get_action_name(action_code)
+                    .unwrap_or_else(|_| format!("unknown({})", action_code));
+
+                let user = crate::registry::get_username(user_code)
+                    .unwrap_or_else(|_| format!("unknown({})", user_code));
+
+                VersionInfo {
+                    timestamp,
+                    action,
+                    user,
+                    delta_size,
+                    message: None,
+                }
+            })
+            .collect();
+
+        Ok(versions)
+    }
+
+    /// Records an appended version.log entry in version.idx and stores a
+    /// snapshot when the version's position is a multiple of the interval.
+    ///
+    /// Both are derived data: the log stays the source of truth and
+    /// reconstruction falls back to deltas without a snapshot, so a failure
+    /// here does not fail the write.
+    pub(super) fn record_version(&self, timestamp: u64, content: &[u8]) {
+        let version_id = VersionIndices::open(&self.table_dir()).and_then(|mut indices| {
+            let version_id = indices.version_count()? + 1;
+            indices.append(timestamp, version_id)?;
+            Ok(version_id)
+        });
+
+        match version_id {
+            Ok(id) if self.snapshot_interval > 0 && id % self.snapshot_interval == 0 => {
+                // Publish complete snapshots only; reconstruction trusts them
+                let snapshot_path = self.snapshot_path(timestamp);
+                let temp_path = snapshot_path.with_extension("snap.tmp");
+                if fs::write(&temp_path, content)
+                    .and_then(|_| fs::rename(&temp_path, &snapshot_path))
+                    .is_err()
+                {
+                    let _ = fs::remove_file(&temp_path);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Finds the latest snapshot at or before `target_idx`.
+    ///
+    /// ## Input
+    /// - `versions`: Versions oldest-first
+    /// - `target_idx`: Index of the version to reconstruct
+    ///
+    /// ## Output
+    /// - `Some(index)`: Newest version in `1..=target_idx` with a snapshot file
+    /// - `None`: No snapshot; start from the init content (index 0)
+    ///
+    /// ## Performance
+    /// - O(k) file checks, k = distance to the snapshot (< `snapshot_interval`
+    ///   when snapshots are complete)
+    fn find_nearest_snapshot(&self, versions: &[VersionInfo], target_idx: usize) -> Option<usize> {
+        (1..=target_idx.min(versions.len().saturating_sub(1)))
+            .rev()
+            .find(|&i| self.snapshot_path(versions[i].timestamp).is_file())
+    }
+
+    /// Rolls back to specific version.
+    ///
+    /// Reconstructs version from deltas and writes as current.
+    ///
+    /// ## Input
+    /// - `timestamp`: Target version timestamp
+    /// - `user`: Username for audit
+    ///
+    /// ## Output
+    /// - `Result<()>`: Success or error
+    ///
+    /// ## Performance
+    /// - < 100ms per 50 deltas (typical)
+    ///
+    /// ## Error Conditions
+    /// - VersionNotFound: Timestamp not in log
+    /// - DeltaCorrupted: Cannot apply delta
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::tables::Table;
+    /// use std::path::Path;
+    ///
+    /// let table = Table::new(Path::new(".reed"), "text");
+    /// let versions = table.list_versions()?;
+    /// table.rollback(versions[1].timestamp, "admin")?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn rollback(&self, timestamp: u64, user: &str) -> ReedResult<()> {
+        let reconstructed_path = self.reconstruct_version(timestamp, "rollback")?;
+
+        // Read reconstructed content
+        let content = fs::read(&reconstructed_path).map_err(|e| ReedError::IoError {
+            operation: "read_reconstructed".to_string(),
+            reason: e.to_string(),
+        });
+
+        // Clean up temp file
+        let _ = fs::remove_file(&reconstructed_path);
+
+        // Write as new version
+        self.write(&content?, user)?;
+
+        Ok(())
+    }
+
+    /// Rebuilds version `timestamp` into a temp file in the table directory.
+    ///
+    /// Starts from the nearest snapshot at or before the target (or the init
+    /// content) and applies the following deltas in log order. Temp files
+    /// are named `{tag}.tmp` and `{tag}_{n}.tmp`; the caller removes the
+    /// returned file.
+    pub(super) fn reconstruct_version(&self, timestamp: u64, tag: &str) -> ReedResult<PathBuf> {
+        // Verify version exists
+        let mut versions = self.list_versions()?;
+        if !versions.iter().any(|v| v.timestamp == timestamp) {
+            return Err(ReedError::VersionNotFound { timestamp });
+        }
+
+        // Versions are newest-first, reverse to get oldest-first for reconstruction
+        versions.reverse();
+
+        // Find target version index
+        let target_idx = versions
+            .iter()
+            .position(|v| v.timestamp == timestamp)
+            .ok_or(ReedError::VersionNotFound { timestamp })?;
+
+        // Reconstruct version by applying deltas in sequence, starting from
+        // the nearest snapshot or the initial version (index 0)
+        let table_dir = self.table_dir();
+        let mut reconstructed_path = table_dir.join(format!("{}.tmp", tag));
+
+        // Snapshots and the first delta from init() are raw content (not bsdiff deltas)
+        let start_idx = self.find_nearest_snapshot(&versions, target_idx);
+        let start_path = match start_idx {
+            Some(i) => self.snapshot_path(versions[i].timestamp),
+            None => self.delta_path(versions[0].timestamp),
+        };
+        fs::copy(&start_path, &reconstructed_path).map_err(|e| ReedError::IoError {
+            operation: "copy_base_version".to_string(),
+            reason: e.to_string(),
+        })?;
+
+        // Apply subsequent deltas to reach target version
+        for (i, version) in versions
+            .iter()
+            .enumerate()
+            .take(target_idx + 1)
+            .skip(start_idx.unwrap_or(0) + 1)
+        {
+            let prev_path = reconstructed_path.clone();
+            let delta_path = self.delta_path(version.timestamp);
+            reconstructed_path = table_dir.join(format!("{}_{}.tmp", tag, i));
+
+            let applied = crate::version::apply_delta(&prev_path, &delta_path, &reconstructed_path);
+            let _ = fs::remove_file(&prev_path);
+            applied?;
+        }
+
+        Ok(reconstructed_path)
+    }
+}
@@ -0,0 +1,374 @@
+// Copyright 2025 Vivian Voss. Licensed under the Apache License, Version 2.0.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Full-content writes and the table write lock.
+
+use crate::error::{ReedError, ReedResult};
+use crate::registry::{get_action_code, get_or_create_user_code};
+use crate::tables::types::{WritePhase, WriteProgress, WriteResult};
+use fs2::FileExt;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::time::Duration;
+
+use super::Table;
+
+impl Table {
+    /// Writes new version.
+    ///
+    /// Creates delta automatically, updates current.csv, logs to version.log.
+    /// Content identical to current.csv is not written: no delta, no log
+    /// entry, and the result reports `is_noop()`.
+    ///
+    /// ## Input
+    /// - `content`: New CSV content
+    /// - `user`: Username for audit
+    ///
+    /// ## Output
+    /// - `Result<WriteResult>`: Write metadata
+    ///
+    /// ## Performance
+    /// - < 5ms typical (bsdiff + xz + write)
+    ///
+    /// ## Error Conditions
+    /// - TableNotFound: Table doesn't exist (use init() first)
+    /// - IoError: Cannot write files
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::tables::Table;
+    /// use std::path::Path;
+    ///
+    /// let table = Table::new(Path::new(".reed"), "text");
+    /// let result = table.write(b"key|value\nfoo|baz\n", "admin")?;
+    /// println!("Delta size: {} bytes", result.delta_size);
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn write(&self, content: &[u8], user: &str) -> ReedResult<WriteResult> {
+        self.write_with_action_name(content, user, "update")
+    }
+
+    /// Writes new version recorded under a named action.
+    ///
+    /// Same as `write_with_action()`, with the code looked up in the
+    /// registry. Lets application layers record their own actions (e.g.
+    /// `import`, `sync`) after `registry::register_custom_action()`.
+    ///
+    /// ## Input
+    /// - `content`: New CSV content
+    /// - `user`: Username for audit
+    /// - `action`: Action name from actions.dict (case-insensitive)
+    ///
+    /// ## Output
+    /// - `Result<WriteResult>`: Write metadata
+    ///
+    /// ## Performance
+    /// - Same as `write()`
+    ///
+    /// ## Error Conditions
+    /// - UnknownAction: `action` is not registered
+    /// - TableNotFound: Table doesn't exist (use init() first)
+    /// - IoError: Cannot write files
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::registry::register_custom_action;
+    /// use reedbase_last::tables::Table;
+    /// use std::path::Path;
+    ///
+    /// register_custom_action("sync", "Synchronised from upstream")?;
+    /// let table = Table::new(Path::new(".reed"), "text");
+    /// table.write_with_action_name(b"key|value\nfoo|baz\n", "sync-bot", "sync")?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn write_with_action_name(
+        &self,
+        content: &[u8],
+        user: &str,
+        action: &str,
+    ) -> ReedResult<WriteResult> {
+        if !self.exists() {
+            return Err(ReedError::TableNotFound {
+                name: self.name.clone(),
+            });
+        }
+
+        self.write_with_action(content, user, get_action_code(action)?)
+    }
+
+    /// Writes new version with an explicit action code.
+    ///
+    /// Same as `write()`, but records `action_code` in version.log instead of
+    /// `2` (update). Used by maintenance operations such as vacuum so that the
+    /// history shows what produced a version.
+    ///
+    /// ## Input
+    /// - `content`: New CSV content
+    /// - `user`: Username for audit
+    /// - `action_code`: Action code from actions.dict
+    ///
+    /// ## Output
+    /// - `Result<WriteResult>`: Write metadata
+    ///
+    /// ## Performance
+    /// - Same as `write()`
+    ///
+    /// ## Error Conditions
+    /// - TableNotFound: Table doesn't exist (use init() first)
+    /// - IoError: Cannot write files
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::tables::Table;
+    /// use std::path::Path;
+    ///
+    /// let table = Table::new(Path::new(".reed"), "text");
+    /// table.write_with_action(b"key|value\nfoo|baz\n", "system", 10)?; // vacuum
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn write_with_action(
+        &self,
+        content: &[u8],
+        user: &str,
+        action_code: u8,
+    ) -> ReedResult<WriteResult> {
+        if !self.exists() {
+            return Err(ReedError::TableNotFound {
+                name: self.name.clone(),
+            });
+        }
+
+        // Acquire exclusive lock for write operation
+        let lock_result = self.write_with_lock(content, user, action_code, &mut |_| {});
+
+        lock_result
+    }
+
+    /// Writes new version, reporting progress for large tables.
+    ///
+    /// Same as `write()`, but calls `on_progress` when each phase starts and,
+    /// while generating the delta, every time another 1% of `content` has
+    /// been processed. Phases run in order: `GeneratingDelta`,
+    /// `CompressingDelta`, `WritingFile`, `UpdatingLog`.
+    ///
+    /// ## Input
+    /// - `content`: New CSV content
+    /// - `user`: Username for audit
+    /// - `on_progress`: Progress callback (runs on the writing thread)
+    ///
+    /// ## Output
+    /// - `Result<WriteResult>`: Write metadata
+    ///
+    /// ## Performance
+    /// - Same as `write()`; at most ~100 callbacks during delta generation
+    ///
+    /// ## Error Conditions
+    /// - TableNotFound: Table doesn't exist (use init() first)
+    /// - IoError: Cannot write files
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::tables::Table;
+    /// use std::path::Path;
+    ///
+    /// let table = Table::new(Path::new(".reed"), "text");
+    /// table.write_with_progress(b"key|value\nfoo|baz\n", "admin", |p| {
+    ///     eprintln!("{:?}: {}/{}", p.phase, p.bytes_processed, p.bytes_total);
+    /// })?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn write_with_progress<F: Fn(WriteProgress)>(
+        &self,
+        content: &[u8],
+        user: &str,
+        on_progress: F,
+    ) -> ReedResult<WriteResult> {
+        if !self.exists() {
+            return Err(ReedError::TableNotFound {
+                name: self.name.clone(),
+            });
+        }
+
+        let action_code = get_action_code("update")?;
+        self.write_with_lock(content, user, action_code, &mut |p| on_progress(p))
+    }
+
+    /// Internal write implementation with file locking.
+    ///
+    /// Acquires exclusive lock on table directory to prevent concurrent write conflicts.
+    pub(super) fn write_with_lock(
+        &self,
+        content: &[u8],
+        user: &str,
+        action_code: u8,
+        on_progress: &mut dyn FnMut(WriteProgress),
+    ) -> ReedResult<WriteResult> {
+        let lock_path = self.table_dir().join(".lock");
+
+        // Create lock file if it doesn't exist
+        let lock_file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .open(&lock_path)
+            .map_err(|e| ReedError::IoError {
+                operation: "create_lock_file".to_string(),
+                reason: e.to_string(),
+            })?;
+
+        // Try to acquire exclusive lock with retry mechanism
+        self.acquire_lock_with_retry(&lock_file)?;
+
+        // Perform write operation
+        let result = self.write_internal_with_progress(content, user, action_code, on_progress);
+
+        // Release lock (automatic on drop, but explicit unlock is clearer)
+        let _ = lock_file.unlock();
+
+        result
+    }
+
+    /// Takes the table's `.lock` file exclusively (released on drop).
+    pub(super) fn lock_exclusive(&self) -> ReedResult<File> {
+        let lock_file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(self.table_dir().join(".lock"))
+            .map_err(|e| ReedError::IoError {
+                operation: "create_lock_file".to_string(),
+                reason: e.to_string(),
+            })?;
+        self.acquire_lock_with_retry(&lock_file)?;
+        Ok(lock_file)
+    }
+
+    /// Acquire exclusive lock with exponential backoff retry.
+    pub(super) fn acquire_lock_with_retry(&self, lock_file: &File) -> ReedResult<()> {
+        const MAX_RETRIES: u32 = 50;
+        const INITIAL_WAIT_MS: u64 = 5;
+        const MAX_WAIT_MS: u64 = 100;
+
+        for attempt in 0..MAX_RETRIES {
+            match lock_file.try_lock_exclusive() {
+                Ok(_) => return Ok(()),
+                Err(_) if attempt < MAX_RETRIES - 1 => {
+                    // Exponential backoff with cap: 5ms, 10ms, 20ms, 40ms, 80ms, 100ms (capped)
+                    let wait_time = (INITIAL_WAIT_MS * 2u64.pow(attempt)).min(MAX_WAIT_MS);
+                    std::thread::sleep(Duration::from_millis(wait_time));
+                }
+                Err(e) => {
+                    return Err(ReedError::IoError {
+                        operation: "acquire_write_lock".to_string(),
+                        reason: format!(
+                            "Failed to acquire lock after {} attempts: {}",
+                            MAX_RETRIES, e
+                        ),
+                    });
+                }
+            }
+        }
+
+        Err(ReedError::IoError {
+            operation: "acquire_write_lock".to_string(),
+            reason: "Lock acquisition failed".to_string(),
+        })
+    }
+
+    /// Internal write implementation (called after lock is acquired),
+    /// reporting progress per phase.
+    pub(super) fn write_internal_with_progress(
+        &self,
+        content: &[u8],
+        user: &str,
+        action_code: u8,
+        on_progress: &mut dyn FnMut(WriteProgress),
+    ) -> ReedResult<WriteResult> {
+        let timestamp = Self::now_nanos();
+        let current_path = self.current_path();
+        let delta_path = self.delta_path(timestamp);
+        let mut report = |phase, bytes_processed, bytes_total| {
+            on_progress(WriteProgress {
+                phase,
+                bytes_processed,
+                bytes_total,
+            })
+        };
+
+        let old_content = fs::read(&current_path).map_err(|e| ReedError::IoError {
+            operation: "read_current".to_string(),
+            reason: e.to_string(),
+        })?;
+
+        // Unchanged content: nothing to version
+        if old_content == content {
+            return Ok(WriteResult {
+                timestamp: 0,
+                delta_size: 0,
+                current_size: content.len() as u64,
+            });
+        }
+
+        // Generate binary delta (old -> new), reporting every 1% of new bytes
+        let total = content.len() as u64;
+        let step = (total / 100).max(1);
+        let mut next_report = step;
+        report(WritePhase::GeneratingDelta, 0, total);
+        let delta = crate::version::delta::create_bsdiff_with_progress(
+            &old_content,
+            content,
+            &mut |processed| {
+                if processed >= next_report || processed == total {
+                    report(WritePhase::GeneratingDelta, processed, total);
+                    next_report = (processed / step + 1) * step;
+                }
+            },
+        )?;
+
+        report(WritePhase::CompressingDelta, 0, delta.len() as u64);
+        let compressed = crate::version::delta::compress_delta(&delta)?;
+        let delta_size = compressed.len() as u64;
+
+        // Write delta file, then update current.csv
+        report(WritePhase::WritingFile, 0, delta_size + total);
+        fs::write(&delta_path, &compressed).map_err(|e| ReedError::IoError {
+            operation: "write_delta".to_string(),
+            reason: e.to_string(),
+        })?;
+        fs::write(&current_path, content).map_err(|e| ReedError::IoError {
+            operation: "write_current".to_string(),
+            reason: e.to_string(),
+        })?;
+
+        // Append to version.log
+        let user_code = get_or_create_user_code(user)?;
+
+        let log_line = format!(
+            "{}|{}|{}|{}\n",
+            timestamp, action_code, user_code, delta_size
+        );
+        report(WritePhase::UpdatingLog, 0, log_line.len() as u64);
+
+        let mut log_file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.log_path())
+            .map_err(|e| ReedError::IoError {
+                operation: "open_log".to_string(),
+                reason: e.to_string(),
+            })?;
+
+        log_file
+            .write_all(log_line.as_bytes())
+            .map_err(|e| ReedError::IoError {
+                operation: "append_log".to_string(),
+                reason: e.to_string(),
+            })?;
+        self.record_version(timestamp, content);
+
+        Ok(WriteResult {
+            timestamp,
+            delta_size,
+            current_size: total,
+        })
+    }
+}
@@ -114,6 +114,71 @@ mod tests {
         let _ = fs::remove_dir_all(&temp_dir);
     }
 
+    #[test]
+    fn test_table_stream_rows() {
+        let temp_dir = setup_test("stream_rows");
+        let table = Table::new(&temp_dir, "test");
+
+        let content = b"# comment\nkey|value|lang\nfoo|bar|de\n\nbaz|qux\n";
+        table.init(content, "testuser").unwrap();
+
+        let mut rows = table.stream_rows().unwrap();
+        assert_eq!(rows.columns(), ["key", "value", "lang"]);
+
+        let first = rows.next().unwrap().unwrap();
+        assert_eq!(first.key, "foo");
+        assert_eq!(first.get("value"), Some("bar"));
+        assert_eq!(first.get("lang"), Some("de"));
+        assert_eq!(first.get("missing"), None);
+
+        // Short rows have no value for trailing columns
+        let second = rows.next().unwrap().unwrap();
+        assert_eq!(second.get("key"), Some("baz"));
+        assert_eq!(second.get("lang"), None);
+        assert!(rows.next().is_none());
+
+        assert!(matches!(
+            Table::new(&temp_dir, "missing").stream_rows(),
+            Err(crate::error::ReedError::TableNotFound { .. })
+        ));
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_table_retain_rows() {
+        let temp_dir = setup_test("retain_rows");
+        let table = Table::new(&temp_dir, "test");
+
+        let content = b"# comment\nkey|value\n a | padded \nfoo|bar\n\nbaz|\"q|x\"\n";
+        table.init(content, "testuser").unwrap();
+
+        // Rows arrive parsed, the kept ones are written back unchanged
+        let (removed, result) = table
+            .retain_rows(|row| Ok(row.get("value") != Some("bar")), "testuser")
+            .unwrap();
+        assert_eq!(removed, 1);
+        assert_ne!(result.timestamp, 0);
+        assert_eq!(
+            table.read_current().unwrap(),
+            b"# comment\nkey|value\n a | padded \n\nbaz|\"q|x\"\n"
+        );
+        assert!(!temp_dir.join("tables/test/current.new.csv").exists());
+
+        // The removal is a version of its own
+        assert_eq!(table.list_versions().unwrap().len(), 2);
+        table
+            .rollback(table.list_versions().unwrap()[1].timestamp, "testuser")
+            .unwrap();
+        assert_eq!(table.read_current().unwrap(), content);
+
+        // Nothing removed: nothing written
+        let (removed, result) = table.retain_rows(|_| Ok(true), "testuser").unwrap();
+        assert_eq!((removed, result.timestamp), (0, 0));
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
     #[test]
     fn test_table_write() {
         let temp_dir = setup_test("write");
@@ -172,6 +237,108 @@ mod tests {
         let _ = fs::remove_dir_all(&temp_dir);
     }
 
+    #[test]
+    fn test_table_list_versions_in_timestamp_order() {
+        use crate::version::VersionIndices;
+
+        let temp_dir = setup_test("list_versions_index");
+        let table = Table::new(&temp_dir, "test");
+        let table_dir = temp_dir.join("tables").join("test");
+
+        table.init(b"key|value\nfoo|bar\n", "testuser").unwrap();
+        let w1 = table.write(b"key|value\nfoo|baz\n", "testuser").unwrap();
+        let w2 = table.write(b"key|value\nfoo|qux\n", "testuser").unwrap();
+
+        // Every write is indexed with its version.log entry number
+        let indexed = VersionIndices::open(&table_dir)
+            .unwrap()
+            .versions()
+            .unwrap();
+        assert_eq!(indexed.len(), 3);
+        assert_eq!(&indexed[1..], &[(w1.timestamp, 2), (w2.timestamp, 3)]);
+
+        // Entries appended out of order are listed by timestamp
+        let log = fs::read_to_string(table.log_path()).unwrap();
+        let older = indexed[0].0 + 1;
+        fs::write(table.log_path(), format!("{}{}|2|1|7\n", log, older)).unwrap();
+
+        // Listing reads the log only; the index files are not touched
+        fs::remove_file(table_dir.join("version.idx")).unwrap();
+        fs::remove_file(table_dir.join("version.wal")).unwrap();
+
+        let versions = table.list_versions().unwrap();
+        let timestamps: Vec<u64> = versions.iter().map(|v| v.timestamp).collect();
+        assert_eq!(
+            timestamps,
+            vec![w2.timestamp, w1.timestamp, older, indexed[0].0]
+        );
+        assert_eq!(versions[2].delta_size, 7);
+        assert!(!table_dir.join("version.idx").exists());
+        assert!(!table_dir.join("version.wal").exists());
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_table_write_identical_is_noop() {
+        let temp_dir = setup_test("write_noop");
+        let table = Table::new(&temp_dir, "test");
+
+        let v1 = b"key|value\nfoo|bar\n";
+        table.init(v1, "testuser").unwrap();
+        let deltas = || {
+            fs::read_dir(temp_dir.join("tables/test"))
+                .unwrap()
+                .filter(|e| e.as_ref().unwrap().path().extension() == Some("bsdiff".as_ref()))
+                .count()
+        };
+
+        let result = table.write(v1, "testuser").unwrap();
+        assert!(result.is_noop());
+        assert_eq!((result.timestamp, result.delta_size), (0, 0));
+        assert_eq!(result.current_size, v1.len() as u64);
+        assert_eq!(table.list_versions().unwrap().len(), 1);
+        assert_eq!(deltas(), 1);
+
+        let result = table.write(b"key|value\nfoo|baz\n", "testuser").unwrap();
+        assert!(!result.is_noop());
+        assert_eq!(table.list_versions().unwrap().len(), 2);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_table_write_with_action_name() {
+        let temp_dir = setup_test("write_action_name");
+        let table = Table::new(&temp_dir, "test");
+        table.init(b"key|value\nfoo|bar\n", "testuser").unwrap();
+
+        crate::registry::register_custom_action("bulk_load", "Bulk load").unwrap();
+        table
+            .write_with_action_name(b"key|value\nfoo|baz\n", "testuser", "bulk_load")
+            .unwrap();
+        table.write(b"key|value\nfoo|qux\n", "testuser").unwrap();
+
+        let actions: Vec<String> = table
+            .list_versions()
+            .unwrap()
+            .into_iter()
+            .map(|v| v.action)
+            .collect();
+        assert_eq!(actions, vec!["update", "bulk_load", "init"]);
+
+        assert!(matches!(
+            table.write_with_action_name(b"key|value\n", "testuser", "teleport"),
+            Err(crate::error::ReedError::UnknownAction { .. })
+        ));
+        assert!(matches!(
+            Table::new(&temp_dir, "missing").write_with_action_name(b"", "testuser", "bulk_load"),
+            Err(crate::error::ReedError::TableNotFound { .. })
+        ));
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
     #[test]
     fn test_table_rollback() {
         let temp_dir = setup_test("rollback");
@@ -243,4 +410,792 @@ mod tests {
 
         let _ = fs::remove_dir_all(&temp_dir);
     }
+
+    #[test]
+    fn test_table_column_stats() {
+        let temp_dir = setup_test("column_stats");
+        let table = Table::new(&temp_dir, "test");
+
+        let content = b"key|value|views\na|x|10\nb|y|9\nc|x|\nd|x|100\ne||2\n";
+        table.init(content, "testuser").unwrap();
+
+        let stats = table.column_stats("value", 2).unwrap();
+        assert_eq!(stats.count, 5);
+        assert_eq!(stats.null_count, 1);
+        assert_eq!(stats.distinct_count, 2);
+        assert_eq!(stats.inferred_type, "string");
+        assert_eq!(stats.min_value, "x");
+        assert_eq!(stats.max_value, "y");
+        assert_eq!(
+            stats.top_n_values,
+            vec![("x".to_string(), 3), ("y".to_string(), 1)]
+        );
+
+        // Numeric columns use numeric ordering, not lexicographic
+        let stats = table.column_stats("views", 10).unwrap();
+        assert_eq!(stats.null_count, 1);
+        assert_eq!(stats.inferred_type, "integer");
+        assert_eq!(stats.min_value, "2");
+        assert_eq!(stats.max_value, "100");
+
+        // Agrees with schema inference over the same data
+        let rows = table.read_current_as_rows().unwrap();
+        let header: Vec<String> = ["key", "value", "views"].map(String::from).to_vec();
+        let values: Vec<Vec<String>> = rows[1..]
+            .iter()
+            .map(|r| {
+                std::iter::once(r.key.clone())
+                    .chain(r.values.clone())
+                    .collect()
+            })
+            .collect();
+        let schema = crate::schema::infer_schema(&header, &values);
+        assert_eq!(schema.columns[2].col_type, stats.inferred_type);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_table_column_stats_unknown_column() {
+        let temp_dir = setup_test("column_stats_unknown");
+        let table = Table::new(&temp_dir, "test");
+
+        table.init(b"key|value\nfoo|bar\n", "testuser").unwrap();
+
+        let result = table.column_stats("missing", 5);
+        assert!(matches!(
+            result,
+            Err(crate::error::ReedError::ColumnNotFound { .. })
+        ));
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_table_split() {
+        let temp_dir = setup_test("split");
+        let table = Table::new(&temp_dir, "text");
+
+        let content = b"key|value\na@de|eins\nb@en|one\nc@de|zwei\nd@en|two\ne@de|drei\n";
+        table.init(content, "testuser").unwrap();
+
+        let schema =
+            crate::schema::create_default_schema(&["key".to_string(), "value".to_string()]);
+        crate::schema::save_schema(&temp_dir, "text", &schema).unwrap();
+
+        let original_rows = table.read_current_as_rows().unwrap().len() - 1;
+
+        let (kept, moved) = table
+            .split(|row| row.key.ends_with("@de"), "text_de", "testuser")
+            .unwrap();
+        assert_eq!((kept, moved), (2, 3));
+
+        let new_table = Table::new(&temp_dir, "text_de");
+        assert_eq!(
+            new_table.read_current().unwrap(),
+            b"key|value\na@de|eins\nc@de|zwei\ne@de|drei\n"
+        );
+        assert_eq!(
+            table.read_current().unwrap(),
+            b"key|value\nb@en|one\nd@en|two\n"
+        );
+
+        // Header is in both tables; row count is preserved overall
+        let kept_rows = table.read_current_as_rows().unwrap().len() - 1;
+        let moved_rows = new_table.read_current_as_rows().unwrap().len() - 1;
+        assert_eq!(kept_rows + moved_rows, original_rows);
+
+        // Schema copied, split recorded as new version of the original
+        assert_eq!(
+            crate::schema::load_schema(&temp_dir, "text_de").unwrap(),
+            schema
+        );
+        assert_eq!(table.list_versions().unwrap().len(), 2);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_table_split_target_exists() {
+        let temp_dir = setup_test("split_exists");
+        let table = Table::new(&temp_dir, "text");
+        table.init(b"key|value\na|1\n", "testuser").unwrap();
+        Table::new(&temp_dir, "other")
+            .init(b"key|value\n", "testuser")
+            .unwrap();
+
+        let result = table.split(|_| true, "other", "testuser");
+        assert!(matches!(
+            result,
+            Err(crate::error::ReedError::TableAlreadyExists { .. })
+        ));
+
+        // Source untouched
+        assert_eq!(table.read_current().unwrap(), b"key|value\na|1\n");
+        assert_eq!(table.list_versions().unwrap().len(), 1);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_table_split_failure_leaves_source_untouched() {
+        let temp_dir = setup_test("split_failure");
+        let table = Table::new(&temp_dir, "text");
+        table
+            .init(b"key|value\na@de|1\nb@en|2\n", "testuser")
+            .unwrap();
+        // Unreadable schema: copying it to the target fails
+        fs::write(temp_dir.join("tables/text/schema.toml"), "not [valid").unwrap();
+
+        assert!(table
+            .split(|row| row.key.ends_with("@de"), "text_de", "testuser")
+            .is_err());
+
+        // No version written or restored, target removed again
+        assert_eq!(
+            table.read_current().unwrap(),
+            b"key|value\na@de|1\nb@en|2\n"
+        );
+        assert_eq!(table.list_versions().unwrap().len(), 1);
+        assert!(!temp_dir.join("tables/text_de").exists());
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_table_sample_small_table_returns_all() {
+        let temp_dir = setup_test("sample_small");
+        let table = Table::new(&temp_dir, "text");
+        table
+            .init(
+                b"key|value
+# comment
+a|1
+
+b|2
+c|3
+",
+                "testuser",
+            )
+            .unwrap();
+
+        let rows = table.sample(10, 42).unwrap();
+        let keys: Vec<&str> = rows.iter().map(|r| r.key.as_str()).collect();
+        assert_eq!(keys, vec!["a", "b", "c"]);
+        assert_eq!(rows[1].values, vec!["2".to_string()]);
+        assert_eq!(rows[1].get("value"), Some("2"));
+
+        assert!(table.sample(0, 42).unwrap().is_empty());
+
+        // A sample size beyond any table does not reserve memory up front
+        assert_eq!(table.sample(usize::MAX, 42).unwrap().len(), 3);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_table_sample_deterministic() {
+        let temp_dir = setup_test("sample_seed");
+        let table = Table::new(&temp_dir, "text");
+
+        let mut content = String::from("key|value\n");
+        for i in 0..1000 {
+            content.push_str(&format!("key{:04}|{}\n", i, i));
+        }
+        table.init(content.as_bytes(), "testuser").unwrap();
+
+        let sample_keys = |seed| -> Vec<String> {
+            table
+                .sample(50, seed)
+                .unwrap()
+                .into_iter()
+                .map(|row| row.key)
+                .collect()
+        };
+
+        let first = sample_keys(7);
+        assert_eq!(first.len(), 50);
+        assert_eq!(sample_keys(7), first);
+
+        // No duplicates, all from the table body
+        let mut keys = first.clone();
+        keys.sort();
+        keys.dedup();
+        assert_eq!(keys.len(), 50);
+        assert!(keys.iter().all(|k| k.starts_with("key")));
+
+        // Different seed, different sample
+        assert_ne!(sample_keys(8), first);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_table_sample_not_found() {
+        let temp_dir = setup_test("sample_missing");
+        let table = Table::new(&temp_dir, "missing");
+
+        assert!(matches!(
+            table.sample(5, 1),
+            Err(crate::error::ReedError::TableNotFound { .. })
+        ));
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_add_column_with_non_empty_default() {
+        let temp_dir = setup_test("add_column_default");
+        let table = Table::new(&temp_dir, "users");
+        table
+            .init(b"key|name\nalice|Alice\n# note\nbob|Bob\n", "testuser")
+            .unwrap();
+        let versions_before = table.list_versions().unwrap().len();
+
+        let result = table
+            .add_column_with_default("active", "boolean", "true", "testuser")
+            .unwrap();
+
+        assert_eq!(
+            table.read_current().unwrap(),
+            b"key|name|active\nalice|Alice|true\n# note\nbob|Bob|true\n"
+        );
+        let versions = table.list_versions().unwrap();
+        assert_eq!(versions.len(), versions_before + 1);
+        assert!(versions.iter().any(|v| v.timestamp == result.timestamp));
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_add_column_with_empty_default_updates_schema() {
+        use crate::schema::{load_schema, save_schema, ColumnDef, Schema};
+
+        let temp_dir = setup_test("add_column_empty");
+        let table = Table::new(&temp_dir, "users");
+        table.init(b"key|name\nalice|Alice\n", "testuser").unwrap();
+        let schema = Schema::new(
+            "2.0".to_string(),
+            true,
+            vec![
+                ColumnDef::primary_key("key".to_string(), "string".to_string()),
+                ColumnDef::new("name".to_string(), "string".to_string()),
+            ],
+        );
+        save_schema(&temp_dir, "users", &schema).unwrap();
+
+        table
+            .add_column_with_default("age", "integer", "", "testuser")
+            .unwrap();
+
+        assert_eq!(
+            table.read_current().unwrap(),
+            b"key|name|age\nalice|Alice|\n"
+        );
+        let updated = load_schema(&temp_dir, "users").unwrap();
+        assert_eq!(updated.columns.len(), 3);
+        assert_eq!(updated.columns[2].name, "age");
+        assert_eq!(updated.columns[2].col_type, "integer");
+
+        // A default of the wrong type is rejected before anything is written
+        let result = table.add_column_with_default("score", "integer", "high", "testuser");
+        assert!(matches!(
+            result,
+            Err(crate::error::ReedError::ValidationError { .. })
+        ));
+        assert_eq!(load_schema(&temp_dir, "users").unwrap().columns.len(), 3);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_add_column_duplicate_name() {
+        let temp_dir = setup_test("add_column_duplicate");
+        let table = Table::new(&temp_dir, "users");
+        table.init(b"key|name\nalice|Alice\n", "testuser").unwrap();
+        let before = table.read_current().unwrap();
+
+        let result = table.add_column_with_default("name", "string", "x", "testuser");
+
+        assert!(matches!(
+            result,
+            Err(crate::error::ReedError::ColumnAlreadyExists { .. })
+        ));
+        assert_eq!(table.read_current().unwrap(), before);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    /// Reader that hands out at most `step` bytes per call.
+    struct TrickleReader {
+        data: Vec<u8>,
+        pos: usize,
+        step: usize,
+    }
+
+    impl std::io::Read for TrickleReader {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let n = self.step.min(buf.len()).min(self.data.len() - self.pos);
+            buf[..n].copy_from_slice(&self.data[self.pos..self.pos + n]);
+            self.pos += n;
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn test_stream_write_replaces_content() {
+        let temp_dir = setup_test("stream_write");
+        let table = Table::new(&temp_dir, "text");
+        table.init(b"key|value\nold|row\n", "testuser").unwrap();
+
+        let content = b"key|value\nfoo|bar\nbaz|qux".to_vec();
+        let mut reader = TrickleReader {
+            data: content.clone(),
+            pos: 0,
+            step: 5, // Lines arrive split across reads
+        };
+        let result = table.stream_write(&mut reader, "testuser").unwrap();
+
+        assert_eq!(result.current_size, content.len() as u64);
+        assert_eq!(table.read_current().unwrap(), content);
+        assert_eq!(table.list_versions().unwrap().len(), 2);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_stream_write_multiple_chunks() {
+        let temp_dir = setup_test("stream_write_chunks");
+        let table = Table::new(&temp_dir, "text");
+        table.init(b"key|value\n", "testuser").unwrap();
+
+        let mut content = b"key|value\n".to_vec();
+        for i in 0..10_000 {
+            content.extend_from_slice(format!("page.key.{:05}@de|Value {}\n", i, i).as_bytes());
+        }
+        assert!(content.len() > 64 * 1024);
+
+        table
+            .stream_write(&mut std::io::Cursor::new(content.clone()), "testuser")
+            .unwrap();
+        assert_eq!(table.read_current_as_rows().unwrap().len(), 10_001); // Header included
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_stream_write_rejects_field_mismatch() {
+        let temp_dir = setup_test("stream_write_mismatch");
+        let table = Table::new(&temp_dir, "text");
+        table.init(b"key|value\nfoo|bar\n", "testuser").unwrap();
+        let before = table.read_current().unwrap();
+
+        let mut reader = std::io::Cursor::new(b"key|value\n# comment\na|1\nb|2|extra\n".to_vec());
+        let result = table.stream_write(&mut reader, "testuser");
+
+        assert!(matches!(
+            result,
+            Err(crate::error::ReedError::InvalidCsv { line: 4, .. })
+        ));
+        assert_eq!(table.read_current().unwrap(), before);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_stream_write_empty_and_missing_table() {
+        let temp_dir = setup_test("stream_write_empty");
+        let table = Table::new(&temp_dir, "text");
+
+        let result = table.stream_write(&mut std::io::empty(), "testuser");
+        assert!(matches!(
+            result,
+            Err(crate::error::ReedError::TableNotFound { .. })
+        ));
+
+        table.init(b"key|value\n", "testuser").unwrap();
+        let result = table.stream_write(&mut std::io::Cursor::new(b"\n\n".to_vec()), "testuser");
+        assert!(matches!(
+            result,
+            Err(crate::error::ReedError::InvalidCsv { .. })
+        ));
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_write_with_progress_reports_phases() {
+        use crate::tables::{WritePhase, WriteProgress};
+        use std::cell::RefCell;
+
+        let temp_dir = setup_test("write_progress");
+        let table = Table::new(&temp_dir, "text");
+        let rows = |changed: bool| {
+            let mut content = String::from("key|value\n");
+            for i in 0..5_000 {
+                let suffix = if changed && i % 10 == 0 {
+                    " (edited)"
+                } else {
+                    ""
+                };
+                content.push_str(&format!("page.item{}|Value {}{}\n", i, i, suffix));
+            }
+            content
+        };
+        table.init(rows(false).as_bytes(), "testuser").unwrap();
+        let content = rows(true);
+
+        let events: RefCell<Vec<WriteProgress>> = RefCell::new(Vec::new());
+        let result = table
+            .write_with_progress(content.as_bytes(), "testuser", |p| {
+                events.borrow_mut().push(p)
+            })
+            .unwrap();
+        let events = events.into_inner();
+
+        // Phase transitions in order
+        let mut phases: Vec<WritePhase> = events.iter().map(|e| e.phase).collect();
+        phases.dedup();
+        assert_eq!(
+            phases,
+            vec![
+                WritePhase::GeneratingDelta,
+                WritePhase::CompressingDelta,
+                WritePhase::WritingFile,
+                WritePhase::UpdatingLog,
+            ]
+        );
+
+        // Delta generation: monotonic, roughly every 1%, ends at 100%
+        let delta: Vec<&WriteProgress> = events
+            .iter()
+            .filter(|e| e.phase == WritePhase::GeneratingDelta)
+            .collect();
+        let total = content.len() as u64;
+        assert!(delta.iter().all(|e| e.bytes_total == total));
+        assert!(delta
+            .windows(2)
+            .all(|w| w[0].bytes_processed <= w[1].bytes_processed));
+        assert_eq!(delta.last().unwrap().bytes_processed, total);
+        assert!(delta.len() > 10 && delta.len() <= 102);
+
+        assert_eq!(result.current_size, total);
+        assert_eq!(table.read_current().unwrap(), content.as_bytes());
+        assert_eq!(table.list_versions().unwrap().len(), 2);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_table_delimiter_meta() {
+        use crate::tables::Delimiter;
+
+        let temp_dir = setup_test("delimiter_meta");
+        let table = Table::new(&temp_dir, "imports");
+        assert!(matches!(
+            table.set_delimiter(Delimiter::Tab),
+            Err(crate::error::ReedError::TableNotFound { .. })
+        ));
+
+        table
+            .init(b"key,name,city\nalice,Alice,Berlin\nbob,Bob,\n", "testuser")
+            .unwrap();
+        assert!(table.meta_path().exists());
+        assert_eq!(table.delimiter().unwrap(), Delimiter::Comma);
+
+        let rows = table.read_current_as_rows().unwrap();
+        assert_eq!(rows[1].key, "alice");
+        assert_eq!(rows[1].values, vec!["Alice", "Berlin"]);
+        assert_eq!(rows[2].values, vec!["Bob", ""]);
+
+        let stream = table.stream_rows().unwrap();
+        assert_eq!(stream.delimiter(), Delimiter::Comma);
+        assert_eq!(stream.columns(), ["key", "name", "city"]);
+
+        // Tables created before .meta existed read as pipe-delimited
+        fs::remove_file(table.meta_path()).unwrap();
+        assert_eq!(table.delimiter().unwrap(), Delimiter::Pipe);
+
+        table.set_delimiter(Delimiter::Custom(b',')).unwrap();
+        assert_eq!(table.delimiter().unwrap(), Delimiter::Custom(b','));
+
+        fs::write(table.meta_path(), "delimiter = 7").unwrap();
+        assert!(table.delimiter().is_err());
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_table_stats_report_delimiter() {
+        use crate::tables::{table_stats, Delimiter};
+
+        let temp_dir = setup_test("delimiter_stats");
+        let table = Table::new(&temp_dir, "tabbed");
+        table.init(b"key\tvalue\na\t1\n", "testuser").unwrap();
+
+        let stats = table_stats(&temp_dir, "tabbed").unwrap();
+        assert_eq!(stats.delimiter, Delimiter::Tab);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_table_swap() {
+        let temp_dir = setup_test("swap");
+        let table = Table::new(&temp_dir, "products");
+        assert!(matches!(
+            table.swap(b"key|value\n", "testuser"),
+            Err(crate::error::ReedError::TableNotFound { .. })
+        ));
+
+        let v1 = b"key|value\nfoo|bar\n";
+        table.init(v1, "testuser").unwrap();
+
+        // Unrelated rows, stored as a regular bsdiff delta
+        let mut seed = 0x2545_f491_4f6c_dd1du64;
+        let mut v2 = b"key|value\n".to_vec();
+        for i in 0..2_000 {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            v2.extend_from_slice(format!("p{}|{:016x}\n", i, seed).as_bytes());
+        }
+        let result = table.swap(&v2, "testuser").unwrap();
+        assert_eq!(result.current_size, v2.len() as u64);
+        assert_eq!(table.read_current().unwrap(), v2);
+        assert!(!temp_dir.join("tables/products/current.new.csv").exists());
+
+        // Compressed delta exceeds these few bytes, so the full content is stored
+        let v3 = b"key|value\nfoo|qux\n";
+        let swapped = table.swap(v3, "testuser").unwrap();
+
+        let versions = table.list_versions().unwrap();
+        assert_eq!(versions[0].action, "swap");
+        assert_eq!(versions[1].action, "swap");
+        assert_eq!(versions[1].timestamp, result.timestamp);
+        assert_eq!(versions[1].delta_size, result.delta_size);
+
+        table.rollback(versions[1].timestamp, "testuser").unwrap();
+        assert_eq!(table.read_current().unwrap(), v2);
+        table.rollback(swapped.timestamp, "testuser").unwrap();
+        assert_eq!(table.read_current().unwrap(), v3);
+        table.rollback(versions[2].timestamp, "testuser").unwrap();
+        assert_eq!(table.read_current().unwrap(), v1);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_table_export_version() {
+        let temp_dir = setup_test("export_version");
+        let table = Table::new(&temp_dir, "test");
+
+        let v1 = b"key|value\nfoo|bar\n";
+        let v2 = b"key|value\nfoo|baz\nqux|1\n";
+        let v3 = b"key|value\nfoo|qux\n";
+        table.init(v1, "testuser").unwrap();
+        table.write(v2, "testuser").unwrap();
+        table.write(v3, "testuser").unwrap();
+        let versions = table.list_versions().unwrap();
+
+        let dest = temp_dir.join("export_v2.csv");
+        table.export_version(versions[1].timestamp, &dest).unwrap();
+        assert_eq!(fs::read(&dest).unwrap(), v2);
+
+        // History and current content are untouched, no temp files remain
+        assert_eq!(table.read_current().unwrap(), v3);
+        assert_eq!(table.list_versions().unwrap().len(), versions.len());
+        let leftovers = fs::read_dir(temp_dir.join("tables/test"))
+            .unwrap()
+            .filter(|entry| {
+                let name = entry.as_ref().unwrap().file_name();
+                name.to_string_lossy().ends_with(".tmp")
+            })
+            .count();
+        assert_eq!(leftovers, 0);
+
+        // Concurrent exports of the same version do not collide
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let table = Table::new(&temp_dir, "test");
+                let dest = temp_dir.join(format!("export_v1_{}.csv", i));
+                let timestamp = versions[2].timestamp;
+                std::thread::spawn(move || {
+                    table.export_version(timestamp, &dest).unwrap();
+                    fs::read(&dest).unwrap()
+                })
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), v1);
+        }
+
+        assert!(matches!(
+            table.export_version(999999, &dest),
+            Err(crate::error::ReedError::VersionNotFound { .. })
+        ));
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_table_prune_versions() {
+        use crate::tables::{read_transaction_log, AuditFilter};
+
+        let temp_dir = setup_test("prune_versions");
+        let table = Table::new(&temp_dir, "test");
+        let contents: Vec<Vec<u8>> = (0..5)
+            .map(|i| format!("key|value\nfoo|{}\nbar|{}\n", i, i * 2).into_bytes())
+            .collect();
+        table.init(&contents[0], "testuser").unwrap();
+        for content in &contents[1..] {
+            table.write(content, "testuser").unwrap();
+        }
+        let before = table.list_versions().unwrap();
+
+        assert_eq!(table.prune_versions(2, "testuser").unwrap(), 3);
+        for version in &before[2..] {
+            assert!(!table.delta_path(version.timestamp).exists());
+        }
+
+        let after = table.list_versions().unwrap();
+        assert_eq!(after.len(), 2);
+        assert_eq!(after[0].timestamp, before[0].timestamp);
+        assert_eq!(after[1].timestamp, before[1].timestamp);
+        assert_eq!(after[1].delta_size, contents[3].len() as u64);
+        assert_eq!(table.read_current().unwrap(), contents[4]);
+
+        // The new base still rolls back and exports correctly
+        let dest = temp_dir.join("base.csv");
+        table.export_version(after[1].timestamp, &dest).unwrap();
+        assert_eq!(fs::read(&dest).unwrap(), contents[3]);
+        table.rollback(after[1].timestamp, "testuser").unwrap();
+        assert_eq!(table.read_current().unwrap(), contents[3]);
+
+        // Nothing left to prune
+        assert_eq!(table.prune_versions(10, "testuser").unwrap(), 0);
+
+        let entries = read_transaction_log(&temp_dir, AuditFilter::default()).unwrap();
+        assert_eq!(entries.iter().filter(|e| e.operation == "prune").count(), 1);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_table_snapshots_bound_rollback() {
+        let temp_dir = setup_test("snapshots");
+        let table = Table::new(&temp_dir, "test").with_snapshot_interval(3);
+        assert_eq!(
+            Table::new(&temp_dir, "test").snapshot_interval(),
+            crate::tables::DEFAULT_SNAPSHOT_INTERVAL
+        );
+
+        let contents: Vec<Vec<u8>> = (0..8)
+            .map(|i| format!("key|value\nfoo|{}\nbar|{}\n", i, i * 3).into_bytes())
+            .collect();
+        table.init(&contents[0], "testuser").unwrap();
+        for content in &contents[1..] {
+            table.write(content, "testuser").unwrap();
+        }
+        let mut versions = table.list_versions().unwrap();
+        versions.reverse();
+
+        // Versions 3 and 6 (1-based) carry full snapshots
+        let snapshots: Vec<usize> = (0..versions.len())
+            .filter(|&i| table.snapshot_path(versions[i].timestamp).exists())
+            .collect();
+        assert_eq!(snapshots, vec![2, 5]);
+        assert_eq!(
+            fs::read(table.snapshot_path(versions[5].timestamp)).unwrap(),
+            contents[5]
+        );
+
+        for (i, version) in versions.iter().enumerate() {
+            let dest = temp_dir.join(format!("v{}.csv", i));
+            table.export_version(version.timestamp, &dest).unwrap();
+            assert_eq!(fs::read(&dest).unwrap(), contents[i]);
+        }
+
+        // Versions past a snapshot no longer need the deltas before it
+        for version in &versions[1..=5] {
+            fs::remove_file(table.delta_path(version.timestamp)).unwrap();
+        }
+        table.rollback(versions[7].timestamp, "testuser").unwrap();
+        assert_eq!(table.read_current().unwrap(), contents[7]);
+        table.rollback(versions[5].timestamp, "testuser").unwrap();
+        assert_eq!(table.read_current().unwrap(), contents[5]);
+
+        // Snapshots of pruned versions are removed with their deltas
+        table.prune_versions(2, "testuser").unwrap();
+        assert!(!table.snapshot_path(versions[2].timestamp).exists());
+        assert!(!table.snapshot_path(versions[5].timestamp).exists());
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_table_prune_older_than() {
+        use std::time::Duration;
+
+        let temp_dir = setup_test("prune_older_than");
+        let table = Table::new(&temp_dir, "test");
+        table.init(b"key|value\nfoo|1\n", "testuser").unwrap();
+        table.write(b"key|value\nfoo|2\n", "testuser").unwrap();
+        table.write(b"key|value\nfoo|3\n", "testuser").unwrap();
+
+        assert_eq!(
+            table
+                .prune_older_than(Duration::from_secs(3600), "testuser")
+                .unwrap(),
+            0
+        );
+        assert_eq!(table.list_versions().unwrap().len(), 3);
+
+        // Everything is older than zero seconds, but the newest version stays
+        assert_eq!(
+            table.prune_older_than(Duration::ZERO, "testuser").unwrap(),
+            2
+        );
+        assert_eq!(table.list_versions().unwrap().len(), 1);
+        assert_eq!(table.read_current().unwrap(), b"key|value\nfoo|3\n");
+
+        table.write(b"key|value\nfoo|4\n", "testuser").unwrap();
+        let versions = table.list_versions().unwrap();
+        table.rollback(versions[1].timestamp, "testuser").unwrap();
+        assert_eq!(table.read_current().unwrap(), b"key|value\nfoo|3\n");
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_table_prune_keeps_history_if_replay_fails() {
+        let temp_dir = setup_test("prune_verify");
+        let table = Table::new(&temp_dir, "test");
+        table.init(b"key|value\nfoo|1\n", "testuser").unwrap();
+        table.write(b"key|value\nfoo|2\n", "testuser").unwrap();
+        table.write(b"key|value\nfoo|3\n", "testuser").unwrap();
+        let versions = table.list_versions().unwrap();
+
+        // current.csv edited outside ReedBase no longer matches the history
+        fs::write(table.current_path(), b"key|value\nfoo|manual\n").unwrap();
+
+        assert!(matches!(
+            table.prune_versions(1, "testuser"),
+            Err(crate::error::ReedError::DeltaCorrupted { .. })
+        ));
+        assert_eq!(table.list_versions().unwrap().len(), versions.len());
+        for version in &versions {
+            assert!(table.delta_path(version.timestamp).exists());
+        }
+        let leftovers = fs::read_dir(temp_dir.join("tables/test"))
+            .unwrap()
+            .filter(|entry| {
+                let name = entry.as_ref().unwrap().file_name();
+                name.to_string_lossy().ends_with(".tmp")
+            })
+            .count();
+        assert_eq!(leftovers, 0);
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
 }
@@ -3,10 +3,13 @@
 
 //! Data structures for table operations.
 
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
 /// Result of a write operation.
 #[derive(Debug, Clone)]
 pub struct WriteResult {
-    /// Timestamp of the new version.
+    /// Timestamp of the new version (0 if the write was a no-op).
     pub timestamp: u64,
 
     /// Size of delta file in bytes.
@@ -16,6 +19,59 @@ pub struct WriteResult {
     pub current_size: u64,
 }
 
+impl WriteResult {
+    /// Whether the content was unchanged, so no version was created.
+    pub fn is_noop(&self) -> bool {
+        self.timestamp == 0
+    }
+}
+
+/// What `Table::rewrite_rows()` does with one data row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RowEdit {
+    /// Copy the line unchanged.
+    Keep,
+
+    /// Drop the line.
+    Remove,
+
+    /// Write these fields (key first) in place of the line.
+    Replace(Vec<String>),
+}
+
+/// Stage of a table write (see `Table::write_with_progress()`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WritePhase {
+    /// Computing the bsdiff delta against current.csv
+    GeneratingDelta,
+
+    /// XZ-compressing the delta
+    CompressingDelta,
+
+    /// Writing the delta file and current.csv
+    WritingFile,
+
+    /// Appending the entry to version.log
+    UpdatingLog,
+}
+
+/// Progress report of a table write.
+///
+/// `bytes_total` refers to the current phase: new content size while
+/// generating the delta, uncompressed delta size while compressing, bytes
+/// to write while writing files and the log line size while updating the log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WriteProgress {
+    /// Current phase
+    pub phase: WritePhase,
+
+    /// Bytes processed in this phase so far
+    pub bytes_processed: u64,
+
+    /// Bytes to process in this phase
+    pub bytes_total: u64,
+}
+
 /// Version metadata from version.log.
 #[derive(Debug, Clone)]
 pub struct VersionInfo {
@@ -35,14 +91,93 @@ pub struct VersionInfo {
     pub message: Option<String>,
 }
 
+/// Entry in the database-wide audit log (`audit.log`).
+///
+/// Unlike version.log, the audit log is append-only and never rewritten by
+/// rollback or vacuum.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransactionEntry {
+    /// Unix timestamp in nanoseconds.
+    pub timestamp: u64,
+
+    /// Username who performed the operation.
+    pub user: String,
+
+    /// Operation name (insert, update, delete, etc.).
+    pub operation: String,
+
+    /// Table the operation touched.
+    pub table: String,
+
+    /// Keys of the rows affected.
+    pub affected_keys: Vec<String>,
+
+    /// Client address, if known.
+    pub ip_address: Option<String>,
+}
+
+/// Filter for `read_transaction_log()`.
+///
+/// `None` fields match everything; time bounds are inclusive.
+#[derive(Debug, Clone, Default)]
+pub struct AuditFilter {
+    /// Only entries by this user.
+    pub user: Option<String>,
+
+    /// Only entries for this table.
+    pub table: Option<String>,
+
+    /// Only entries with this operation.
+    pub operation: Option<String>,
+
+    /// Only entries at or after this timestamp.
+    pub since: Option<u64>,
+
+    /// Only entries at or before this timestamp.
+    pub until: Option<u64>,
+}
+
+impl AuditFilter {
+    /// Returns true if the entry passes every set criterion.
+    pub fn matches(&self, entry: &TransactionEntry) -> bool {
+        self.user.as_ref().is_none_or(|u| *u == entry.user)
+            && self.table.as_ref().is_none_or(|t| *t == entry.table)
+            && self
+                .operation
+                .as_ref()
+                .is_none_or(|o| o.eq_ignore_ascii_case(&entry.operation))
+            && self.since.is_none_or(|since| entry.timestamp >= since)
+            && self.until.is_none_or(|until| entry.timestamp <= until)
+    }
+}
+
 /// Parsed CSV row.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CsvRow {
     /// First column (typically a key).
     pub key: String,
 
     /// Remaining columns.
     pub values: Vec<String>,
+
+    /// Column names from the header, shared by all rows of one read.
+    ///
+    /// Empty when the row was parsed without its header (`parse_csv()`).
+    pub columns: Arc<Vec<String>>,
+}
+
+impl CsvRow {
+    /// Value of a column by header name.
+    ///
+    /// ## Output
+    /// - `Some(&str)`: Column exists and the row has a field for it
+    /// - `None`: Unknown column, short row, or no header attached
+    pub fn get(&self, column: &str) -> Option<&str> {
+        match self.columns.iter().position(|name| name == column)? {
+            0 => Some(&self.key),
+            idx => self.values.get(idx - 1).map(String::as_str),
+        }
+    }
 }
 
 /// Table statistics.
@@ -54,9 +189,15 @@ pub struct TableStats {
     /// Size of current.csv in bytes.
     pub current_size: u64,
 
-    /// Total size of all deltas in bytes.
+    /// Number of data rows in current.csv (excluding header).
+    pub row_count: usize,
+
+    /// Total size of all deltas and snapshots in bytes.
     pub deltas_size: u64,
 
+    /// Size of the version index (version.idx and version.wal) in bytes.
+    pub version_index_size: u64,
+
     /// Number of versions.
     pub version_count: usize,
 
@@ -65,4 +206,81 @@ pub struct TableStats {
 
     /// Timestamp of oldest version.
     pub oldest_version: u64,
+
+    /// Field delimiter recorded in the table's `.meta` file.
+    pub delimiter: Delimiter,
+}
+
+/// Field delimiter of a table's CSV files.
+///
+/// Serialised in `.meta` as `"pipe"`, `"comma"`, `"tab"` or
+/// `{ custom = <byte> }`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Delimiter {
+    /// `|` (ReedBase default).
+    #[default]
+    Pipe,
+
+    /// `,`
+    Comma,
+
+    /// `\t`
+    Tab,
+
+    /// Any other single ASCII byte.
+    Custom(u8),
+}
+
+impl Delimiter {
+    /// Delimiter byte.
+    pub fn as_byte(self) -> u8 {
+        match self {
+            Self::Pipe => b'|',
+            Self::Comma => b',',
+            Self::Tab => b'\t',
+            Self::Custom(byte) => byte,
+        }
+    }
+
+    /// Delimiter as a `char`, for splitting and joining strings.
+    pub fn as_char(self) -> char {
+        self.as_byte() as char
+    }
+}
+
+/// Per-table settings stored in `tables/{name}/.meta` (TOML).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TableMeta {
+    /// Field delimiter used for reading and writing the table.
+    #[serde(default)]
+    pub delimiter: Delimiter,
+}
+
+/// Value distribution statistics for a single column.
+///
+/// Empty cells are counted as nulls and excluded from all other figures.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnStats {
+    /// Number of data rows scanned (excluding header).
+    pub count: usize,
+
+    /// Number of rows with an empty value in this column.
+    pub null_count: usize,
+
+    /// Number of distinct non-null values.
+    pub distinct_count: usize,
+
+    /// Narrowest schema type accepted by every non-null value, as chosen by
+    /// `schema::infer_schema()` ("integer", "float", "boolean" or "string").
+    pub inferred_type: String,
+
+    /// Smallest non-null value (numeric order for integer and float columns).
+    pub min_value: String,
+
+    /// Largest non-null value (numeric order for integer and float columns).
+    pub max_value: String,
+
+    /// Most frequent values with occurrence counts, most frequent first.
+    pub top_n_values: Vec<(String, usize)>,
 }
@@ -173,6 +173,96 @@ fn create_bsdiff(old_data: &[u8], new_data: &[u8]) -> ReedResult<Vec<u8>> {
     Ok(delta)
 }
 
+/// Create bsdiff binary delta, reporting progress.
+///
+/// Same as `create_bsdiff()`, but calls `on_progress` with the number of
+/// bytes of `new_data` processed so far each time bsdiff emits a chunk.
+///
+/// ## Input
+/// - `old_data`: Previous version data
+/// - `new_data`: New version data
+/// - `on_progress`: Receives processed bytes (monotonic, ends at `new_data.len()`)
+///
+/// ## Output
+/// - `ReedResult<Vec<u8>>`: Binary delta (uncompressed), identical to `create_bsdiff()`
+///
+/// ## Error Conditions
+/// - DeltaGenerationFailed: bsdiff library error
+pub(crate) fn create_bsdiff_with_progress(
+    old_data: &[u8],
+    new_data: &[u8],
+    on_progress: &mut dyn FnMut(u64),
+) -> ReedResult<Vec<u8>> {
+    let mut writer = ProgressWriter {
+        delta: Vec::new(),
+        calls: 0,
+        processed: 0,
+        on_progress,
+    };
+
+    bsdiff::diff(old_data, new_data, &mut writer).map_err(|e| {
+        ReedError::DeltaGenerationFailed {
+            reason: format!("bsdiff error: {:?}", e),
+        }
+    })?;
+
+    Ok(writer.delta)
+}
+
+/// Create a bsdiff delta that rebuilds `new_data` from any base.
+///
+/// The delta is taken against empty input, so it consists of `new_data`
+/// as extra bytes only and `apply_delta()` ignores the base it is given.
+/// Used where a real delta would be larger than the content itself.
+///
+/// ## Input
+/// - `new_data`: Full content of the new version
+///
+/// ## Output
+/// - `ReedResult<Vec<u8>>`: Binary delta (uncompressed)
+///
+/// ## Performance
+/// - O(n) where n = new_data.len(), no suffix sorting
+///
+/// ## Error Conditions
+/// - DeltaGenerationFailed: bsdiff library error
+pub(crate) fn create_full_delta(new_data: &[u8]) -> ReedResult<Vec<u8>> {
+    create_bsdiff(&[], new_data)
+}
+
+/// Delta sink that counts processed bytes of the new version.
+///
+/// bsdiff writes every control entry as three `write_all()` calls: a 24-byte
+/// header, the diff bytes and the extra bytes. The latter two together
+/// cover exactly the part of the new version consumed by that entry.
+struct ProgressWriter<'a> {
+    delta: Vec<u8>,
+    calls: usize,
+    processed: u64,
+    on_progress: &'a mut dyn FnMut(u64),
+}
+
+impl Write for ProgressWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.write_all(buf)?;
+        Ok(buf.len())
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+        if !self.calls.is_multiple_of(3) {
+            self.processed += buf.len() as u64;
+            (self.on_progress)(self.processed);
+        }
+        self.calls += 1;
+        self.delta.extend_from_slice(buf);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
 /// Apply bspatch to reconstruct data.
 ///
 /// ## Input
@@ -216,7 +306,7 @@ fn apply_bspatch(old_data: &[u8], delta: &[u8]) -> ReedResult<Vec<u8>> {
 ///
 /// ## Error Conditions
 /// - CompressionFailed: XZ compression error
-fn compress_delta(delta: &[u8]) -> ReedResult<Vec<u8>> {
+pub(crate) fn compress_delta(delta: &[u8]) -> ReedResult<Vec<u8>> {
     use xz2::write::XzEncoder;
 
     let mut encoder = XzEncoder::new(Vec::new(), 6);
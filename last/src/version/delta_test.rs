@@ -299,4 +299,51 @@ mod tests {
         let temp_file = output_path.with_extension("tmp");
         assert!(!temp_file.exists(), "Temp file should be cleaned up");
     }
+
+    /// Test that progress reporting does not alter the delta.
+    #[test]
+    fn test_bsdiff_progress_counts_new_bytes() {
+        use crate::version::delta::create_bsdiff_with_progress;
+
+        let old: Vec<u8> = (0..2_000)
+            .flat_map(|i| format!("row{}|value {}\n", i, i).into_bytes())
+            .collect();
+        let new: Vec<u8> = (0..2_000)
+            .flat_map(|i| format!("row{}|value {}{}\n", i, i, i % 7).into_bytes())
+            .collect();
+
+        let mut reports = Vec::new();
+        let delta = create_bsdiff_with_progress(&old, &new, &mut |n| reports.push(n)).unwrap();
+
+        let mut expected = Vec::new();
+        bsdiff::diff(&old, &new, &mut expected).unwrap();
+        assert_eq!(delta, expected);
+
+        assert!(reports.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(*reports.last().unwrap(), new.len() as u64);
+    }
+
+    /// Test that a full-content delta rebuilds the content from any base.
+    #[test]
+    fn test_full_delta_ignores_base() {
+        use crate::version::delta::{compress_delta, create_full_delta};
+
+        let temp_dir = TempDir::new().unwrap();
+        let delta_path = temp_dir.path().join("full.bsdiff");
+        let output_path = temp_dir.path().join("output.csv");
+        let new = b"key|value\nalpha|1\nbeta|2\n";
+
+        let delta = create_full_delta(new).unwrap();
+        fs::write(&delta_path, compress_delta(&delta).unwrap()).unwrap();
+
+        for (i, base) in ["", "key|value\n", "completely|different\nrows|here\n"]
+            .iter()
+            .enumerate()
+        {
+            let base_path = temp_dir.path().join(format!("base{}.csv", i));
+            fs::write(&base_path, base).unwrap();
+            apply_delta(&base_path, &delta_path, &output_path).unwrap();
+            assert_eq!(fs::read(&output_path).unwrap(), new);
+        }
+    }
 }
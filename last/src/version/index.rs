@@ -33,9 +33,17 @@
 //!     &"2025-10-28T09:00:00.000Z".to_string(),
 //! )?;
 //! ```
+//!
+//! ## Table Version Index
+//! Each table keeps `version.idx` next to its version.log: a B+-Tree
+//! mapping version timestamp (nanoseconds) → entry number in the log,
+//! with its write-ahead log in `version.wal`.
+//! Open it with `VersionIndices::open()` and add entries with `append()`.
 
-use crate::error::ReedResult;
-use crate::indices::Index;
+use crate::btree::{BPlusTree, Order};
+use crate::error::{ReedError, ReedResult};
+use crate::indices::{HashMapIndex, Index};
+use std::fs;
 use std::path::Path;
 
 /// RFC3339 timestamp string (e.g., "2025-10-28T08:15:23.001Z").
@@ -47,6 +55,15 @@ pub type FrameId = String;
 /// Version ID (1-based sequential counter).
 pub type VersionId = usize;
 
+/// File name of a table's version index.
+pub const VERSION_INDEX_FILE: &str = "version.idx";
+
+/// File name of the version index's write-ahead log (next to version.idx).
+pub const VERSION_INDEX_WAL_FILE: &str = "version.wal";
+
+/// Node order of the table version index.
+const VERSION_INDEX_ORDER: u16 = 128;
+
 /// Version log indices (timestamp + frame).
 ///
 /// Maintains two B+-Tree indices for fast version lookups:
@@ -58,6 +75,9 @@ pub struct VersionIndices {
 
     /// Index: FrameId → Vec<VersionId>
     pub(crate) frame_index: Box<dyn Index<FrameId, Vec<VersionId>>>,
+
+    /// Table version index: timestamp → VersionId (set by `open()`)
+    pub(crate) log_index: Option<BPlusTree<u64, VersionId>>,
 }
 
 impl VersionIndices {
@@ -79,8 +99,6 @@ impl VersionIndices {
     /// )?;
     /// ```
     pub fn open_or_create<P: AsRef<Path>>(timestamp_path: P, frame_path: P) -> ReedResult<Self> {
-        use crate::indices::HashMapIndex;
-
         // For now, use HashMap for testing until B+-Tree persistence is stable
         // TODO: Switch back to BTreeIndex once file handling is fixed
         let timestamp_index: Box<dyn Index<Timestamp, Vec<VersionId>>> =
@@ -91,9 +109,137 @@ impl VersionIndices {
         Ok(Self {
             timestamp_index,
             frame_index,
+            log_index: None,
         })
     }
 
+    /// Open or create the version index of a table.
+    ///
+    /// The B+-Tree lives at `{table_dir}/version.idx` (WAL: `version.wal`)
+    /// and maps each version timestamp to its entry number in version.log.
+    /// Timestamp and frame indices of the returned value start empty.
+    ///
+    /// ## Input
+    /// - `table_dir`: Table directory (e.g., `.reed/tables/text`)
+    ///
+    /// ## Output
+    /// - `Ok(VersionIndices)`: Indices backed by the table's version.idx
+    ///
+    /// ## Performance
+    /// - New file: ~10ms (allocate 1MB)
+    /// - Existing file: ~5ms (mmap, WAL replay)
+    ///
+    /// ## Error Conditions
+    /// - IoError: Table directory missing or file cannot be mapped
+    /// - Corrupted file (invalid magic bytes)
+    ///
+    /// ## Example Usage
+    /// ```no_run
+    /// use reedbase_last::version::VersionIndices;
+    /// use std::path::Path;
+    ///
+    /// let mut indices = VersionIndices::open(Path::new(".reed/tables/text"))?;
+    /// indices.append(1_736_860_900_000_000_000, 1)?;
+    /// # Ok::<(), reedbase::ReedError>(())
+    /// ```
+    pub fn open(table_dir: &Path) -> ReedResult<Self> {
+        let order = Order::new(VERSION_INDEX_ORDER)?;
+        let log_index = BPlusTree::open(table_dir.join(VERSION_INDEX_FILE), order)?;
+
+        Ok(Self {
+            timestamp_index: Box::new(HashMapIndex::new()),
+            frame_index: Box::new(HashMapIndex::new()),
+            log_index: Some(log_index),
+        })
+    }
+
+    /// Replace the table version index with `entries`.
+    ///
+    /// Deletes version.idx and its WAL, then bulk-loads the entries, so a
+    /// damaged or stale index is recovered from the log.
+    ///
+    /// ## Input
+    /// - `table_dir`: Table directory
+    /// - `entries`: (timestamp, VersionId) pairs in any order
+    ///
+    /// ## Output
+    /// - `Ok(VersionIndices)`: Indices holding exactly `entries`
+    ///
+    /// ## Performance
+    /// - O(n log n) sort plus one bulk load, < 50ms for 10k versions
+    ///
+    /// ## Error Conditions
+    /// - IndexOperationUnsupported: Two entries share a timestamp
+    /// - IoError: Files cannot be removed or written
+    pub fn rebuild(table_dir: &Path, mut entries: Vec<(u64, VersionId)>) -> ReedResult<Self> {
+        for path in [
+            table_dir.join(VERSION_INDEX_FILE),
+            table_dir.join(VERSION_INDEX_WAL_FILE),
+        ] {
+            if path.exists() {
+                fs::remove_file(&path).map_err(|e| ReedError::IoError {
+                    operation: format!("remove_version_index: {}", path.display()),
+                    reason: e.to_string(),
+                })?;
+            }
+        }
+
+        let mut indices = Self::open(table_dir)?;
+        entries.sort_unstable();
+        indices.log_tree_mut()?.bulk_load(entries)?;
+        Ok(indices)
+    }
+
+    /// Record a new table version.
+    ///
+    /// ## Input
+    /// - `timestamp`: Version timestamp (nanoseconds)
+    /// - `version_id`: Entry number in version.log (1-based)
+    ///
+    /// ## Output
+    /// - `Ok(())`: Entry written (an existing timestamp is overwritten)
+    ///
+    /// ## Performance
+    /// - O(log n) + WAL write, < 2ms
+    ///
+    /// ## Error Conditions
+    /// - IndexOperationUnsupported: Indices not opened with `open()`
+    /// - IoError: WAL or page write failed
+    pub fn append(&mut self, timestamp: u64, version_id: VersionId) -> ReedResult<()> {
+        self.log_tree_mut()?.insert(timestamp, version_id)
+    }
+
+    /// All table versions in ascending timestamp order.
+    ///
+    /// ## Output
+    /// - `Ok(Vec<(timestamp, VersionId)>)`: Sorted by timestamp
+    ///
+    /// ## Performance
+    /// - O(n) leaf scan, no sorting
+    ///
+    /// ## Error Conditions
+    /// - IndexOperationUnsupported: Indices not opened with `open()`
+    pub fn versions(&self) -> ReedResult<Vec<(u64, VersionId)>> {
+        let tree = self.log_tree()?;
+        Ok(tree.iter().collect())
+    }
+
+    /// Number of table versions in the index.
+    ///
+    /// ## Error Conditions
+    /// - IndexOperationUnsupported: Indices not opened with `open()`
+    pub fn version_count(&self) -> ReedResult<usize> {
+        Ok(self.log_tree()?.len() as usize)
+    }
+
+    fn log_tree(&self) -> ReedResult<&BPlusTree<u64, VersionId>> {
+        self.log_index.as_ref().ok_or_else(no_log_index)
+    }
+
+    fn log_tree_mut(&mut self) -> ReedResult<&mut BPlusTree<u64, VersionId>> {
+        self.log_index.as_mut().ok_or_else(no_log_index)
+    }
+
     /// Add version to indices.
     ///
     /// ## Arguments
@@ -239,6 +385,14 @@ impl VersionIndices {
     }
 }
 
+fn no_log_index() -> ReedError {
+    ReedError::IndexOperationUnsupported {
+        operation: "version_index".to_string(),
+        backend: "btree".to_string(),
+        reason: "Indices were not opened for a table (use VersionIndices::open)".to_string(),
+    }
+}
+
 /// Index statistics.
 #[derive(Debug, Clone, PartialEq)]
 pub struct IndexStats {
@@ -3,9 +3,11 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::error::ReedResult;
+    use crate::error::{ReedError, ReedResult};
     use crate::indices::Index;
-    use crate::version::index::{FrameId, Timestamp, VersionId, VersionIndices};
+    use crate::version::index::{
+        FrameId, Timestamp, VersionId, VersionIndices, VERSION_INDEX_FILE,
+    };
     use std::collections::HashMap;
 
     // Mock index for testing that supports range queries over HashMap
@@ -72,6 +74,7 @@ mod tests {
         VersionIndices {
             timestamp_index,
             frame_index,
+            log_index: None,
         }
     }
 
@@ -462,4 +465,74 @@ mod tests {
         assert_eq!(versions, vec![1, 2]);
         assert_eq!(versions.len(), 2);
     }
+
+    fn table_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("reedbase_version_index_test_{}", name));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_open_append_persists_sorted() {
+        let dir = table_dir("persist");
+
+        let mut indices = VersionIndices::open(&dir).unwrap();
+        assert!(dir.join(VERSION_INDEX_FILE).exists());
+        assert_eq!(indices.version_count().unwrap(), 0);
+
+        // Appended out of timestamp order
+        indices.append(300, 1).unwrap();
+        indices.append(100, 2).unwrap();
+        indices.append(200, 3).unwrap();
+        drop(indices);
+
+        let indices = VersionIndices::open(&dir).unwrap();
+        assert_eq!(indices.version_count().unwrap(), 3);
+        assert_eq!(
+            indices.versions().unwrap(),
+            vec![(100, 2), (200, 3), (300, 1)]
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_rebuild_replaces_index() {
+        let dir = table_dir("rebuild");
+
+        let mut indices = VersionIndices::open(&dir).unwrap();
+        indices.append(1, 1).unwrap();
+        indices.append(2, 2).unwrap();
+        drop(indices);
+
+        let entries = (0..2000u64)
+            .rev()
+            .map(|i| (i * 10, i as usize + 1))
+            .collect();
+        let indices = VersionIndices::rebuild(&dir, entries).unwrap();
+        let versions = indices.versions().unwrap();
+        assert_eq!(versions.len(), 2000);
+        assert_eq!(versions[0], (0, 1));
+        assert_eq!(versions[1999], (19990, 2000));
+        assert!(versions.windows(2).all(|w| w[0].0 < w[1].0));
+
+        // Duplicate timestamps cannot be indexed
+        assert!(matches!(
+            VersionIndices::rebuild(&dir, vec![(5, 1), (5, 2)]),
+            Err(ReedError::IndexOperationUnsupported { .. })
+        ));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_append_requires_table_index() {
+        let mut indices = create_test_indices();
+        assert!(matches!(
+            indices.append(1, 1),
+            Err(ReedError::IndexOperationUnsupported { .. })
+        ));
+        assert!(indices.versions().is_err());
+    }
 }
@@ -17,5 +17,8 @@ mod index_test;
 
 // Re-export public API
 pub use delta::{apply_delta, calculate_savings, generate_delta, DeltaInfo};
-pub use index::{FrameId, IndexStats, Timestamp, VersionId, VersionIndices};
+pub use index::{
+    FrameId, IndexStats, Timestamp, VersionId, VersionIndices, VERSION_INDEX_FILE,
+    VERSION_INDEX_WAL_FILE,
+};
 pub use rebuild::{rebuild_indices, VersionEntry};